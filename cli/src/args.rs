@@ -0,0 +1,1011 @@
+//! Command-line flags of `kubeowler` (clap), mapped onto the option types of `kubeowler_core::cli`.
+
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser};
+use clap::{Command, Parser, Subcommand, ValueEnum};
+
+use kubeowler_core::cli::{
+    ActiveProbe, BrandOverrides, ClusterAccess, HtmlTheme, Lang, NodeCollectMode, OptionValue,
+    ReportSection, ScoringProfileName,
+};
+
+#[derive(Parser)]
+#[command(author, version, about = "Kubernetes cluster inspection tool", long_about = None)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Commands,
+
+    #[command(flatten)]
+    pub access: ClusterAccessArgs,
+
+    /// Export traces and metrics of this run (spans per module and API request, durations, error counts) to an OTLP/HTTP collector, e.g. http://otel-collector:4318
+    #[arg(long = "otel-endpoint", global = true, value_name = "URL")]
+    pub otel_endpoint: Option<String>,
+}
+
+// Parsed once per process; boxing `check`'s options would only obscure the derive.
+#[allow(clippy::large_enum_variant)]
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Run cluster inspection
+    Check {
+        /// Cluster name for the report title (default: from kubeconfig or "default")
+        #[arg(long = "cluster-name", value_name = "NAME")]
+        cluster_name: Option<String>,
+
+        /// Namespace(s) scope for inspection: only resources in this namespace are inspected. When unset, all namespaces are inspected.
+        #[arg(short, long, value_name = "NAMESPACE")]
+        namespace: Option<String>,
+
+        /// Label selector (e.g. `app.kubernetes.io/part-of=checkout`) applied to every LIST of namespaced objects reported on (Pods, workloads, Services, PVCs, Ingresses, Secrets, ...), for per-application reports in shared namespaces; objects they reference are looked up in full
+        #[arg(long, value_name = "SELECTOR")]
+        selector: Option<String>,
+
+        /// Namespace where kubeowler-node-inspector DaemonSet runs; used only for node-level data collection. Default: kubeowler.
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Output file path for the report; if not set, defaults to {cluster-name}-kubernetes-inspection-report-{YYYY-MM-DD-HHMMSS}.{ext}
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Output format: md (default), json, csv, html, or xlsx
+        #[arg(short, long, default_value = "md")]
+        format: ReportFormat,
+
+        /// CSV layout: summary (overview, namespace ranking and issue rows from the report tables), issues (one row per issue) or checks (one row per check)
+        #[arg(long = "csv-layout", value_name = "LAYOUT", default_value = "summary")]
+        csv_layout: CsvLayout,
+
+        /// Language of headings, labels and issue titles in Markdown/HTML reports: en, zh-CN or ja
+        #[arg(long, value_name = "LANG", default_value = "en", value_parser = option_value::<Lang>())]
+        lang: Lang,
+
+        /// Link issue codes to a local `kubeowler docs generate` bundle in DIR (default: docs) instead of GitHub
+        #[arg(long = "offline-docs", value_name = "DIR", num_args = 0..=1, default_missing_value = "docs")]
+        offline_docs: Option<String>,
+
+        /// Handlebars template rendered against the report data instead of the built-in Markdown layout (md/html formats; `*.html.hbs` for a full HTML page)
+        #[arg(long, value_name = "FILE")]
+        template: Option<String>,
+
+        /// Report sections to include in Markdown/HTML, comma-separated (risks, overdue, overview, containers, nodes, events, namespaces, checks, issues, scoring, baseline, diagnostics). Default: `report.sections` from the config file, else all.
+        #[arg(long, value_name = "SECTIONS", value_delimiter = ',', value_parser = option_value::<ReportSection>())]
+        sections: Vec<ReportSection>,
+
+        /// Resources listed per issue code and level in Markdown/HTML issue tables; the rest are summarized as "… and N more". 0 lists all.
+        #[arg(long = "max-resources-per-issue", value_name = "N", default_value_t = kubeowler_core::reporting::resource_appendix::DEFAULT_MAX_RESOURCES_PER_ISSUE)]
+        max_resources_per_issue: usize,
+
+        /// Also write the full affected-resource list of every issue to FILE (JSON)
+        #[arg(long = "resource-appendix", value_name = "FILE")]
+        resource_appendix: Option<String>,
+
+        /// Replace cluster, namespace, node and object names and IPs with hashed pseudonyms in every output, for sharing outside the organisation.
+        #[arg(long = "redact")]
+        redact: bool,
+
+        /// Also write an anonymous benchmark summary (scores and findings per rule, no names) to FILE, for `kubeowler compare`.
+        #[arg(long = "benchmark", value_name = "FILE")]
+        benchmark: Option<String>,
+
+        /// Label of the cluster in the benchmark summary (default: none; `compare` then uses the file name).
+        #[arg(long = "benchmark-label", value_name = "LABEL", requires = "benchmark")]
+        benchmark_label: Option<String>,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+
+        /// Check levels to show in report: "all" or comma-separated (Info, warning, critical). Default: warning,critical.
+        #[arg(
+            short = 'l',
+            long = "level",
+            value_name = "LEVELS",
+            default_value = "warning,critical"
+        )]
+        level: String,
+
+        /// Namespaces treated as production tier (comma-separated globs) for debug-leftover checks.
+        #[arg(
+            long = "prod-namespaces",
+            value_name = "PATTERNS",
+            default_value = kubeowler_core::inspections::options::DEFAULT_PROD_NAMESPACE_PATTERNS
+        )]
+        prod_namespaces: String,
+
+        /// Container env patterns (comma-separated `NAME=value` globs, case-insensitive) that indicate debug logging.
+        #[arg(
+            long = "debug-env-patterns",
+            value_name = "PATTERNS",
+            default_value = kubeowler_core::inspections::options::DEFAULT_DEBUG_ENV_PATTERNS
+        )]
+        debug_env_patterns: String,
+
+        /// Node label keys (comma-separated, first match wins) whose value names the node pool.
+        #[arg(
+            long = "node-pool-label",
+            value_name = "LABELS",
+            default_value = kubeowler_core::inspections::options::DEFAULT_NODE_POOL_LABELS
+        )]
+        node_pool_label: String,
+
+        /// Report finished standalone Jobs older than this many days (ORPH-005).
+        #[arg(long = "stale-job-days", value_name = "DAYS", default_value_t = kubeowler_core::inspections::options::DEFAULT_STALE_DAYS)]
+        stale_job_days: u32,
+
+        /// Report Succeeded/Failed pods older than this many days (ORPH-006).
+        #[arg(long = "stale-pod-days", value_name = "DAYS", default_value_t = kubeowler_core::inspections::options::DEFAULT_STALE_DAYS)]
+        stale_pod_days: u32,
+
+        /// Report nodes whose image is older than this many days (NODE-025).
+        #[arg(long = "max-node-image-age", value_name = "DAYS", default_value_t = kubeowler_core::inspections::node_images::DEFAULT_MAX_NODE_IMAGE_AGE_DAYS)]
+        max_node_image_age: u32,
+
+        /// Kernel version globs (comma-separated) with known regressions (NODE-026).
+        #[arg(long = "kernel-denylist", value_name = "PATTERNS", default_value = "")]
+        kernel_denylist: String,
+
+        /// Report nodes cordoned (SchedulingDisabled) for more than this many hours (NODE-029).
+        #[arg(long = "max-cordon-hours", value_name = "HOURS", default_value_t = kubeowler_core::inspections::node_hygiene::DEFAULT_MAX_CORDON_HOURS)]
+        max_cordon_hours: u32,
+
+        /// Report nodes whose memory limits exceed this percent of allocatable (NODE-032).
+        #[arg(long = "max-memory-overcommit", value_name = "PERCENT", default_value_t = kubeowler_core::inspections::node_overcommit::DEFAULT_MAX_MEMORY_OVERCOMMIT_PCT)]
+        max_memory_overcommit: u32,
+
+        /// Report nodes whose CPU limits exceed this percent of allocatable (NODE-033).
+        #[arg(long = "max-cpu-overcommit", value_name = "PERCENT", default_value_t = kubeowler_core::inspections::node_overcommit::DEFAULT_MAX_CPU_OVERCOMMIT_PCT)]
+        max_cpu_overcommit: u32,
+
+        /// Label keys (comma-separated) every namespace outside default and kube-* must carry (POLICY-007).
+        #[arg(
+            long = "required-namespace-labels",
+            value_name = "KEYS",
+            default_value = ""
+        )]
+        required_namespace_labels: String,
+
+        /// Report namespaces older than this many days without pods, workloads, Services or PVCs (POLICY-006); 0 disables.
+        #[arg(long = "empty-namespace-days", value_name = "DAYS", default_value_t = kubeowler_core::inspections::namespace_lifecycle::DEFAULT_EMPTY_NAMESPACE_DAYS)]
+        empty_namespace_days: u32,
+
+        /// Report pods, PVCs, namespaces and custom resources deleting for more than this many minutes (ORPH-013, POLICY-005).
+        #[arg(long = "stuck-deletion-minutes", value_name = "MINUTES", default_value_t = kubeowler_core::inspections::stuck_deletions::DEFAULT_STUCK_DELETION_MINUTES)]
+        stuck_deletion_minutes: u32,
+
+        /// Query public registries for image architectures on mixed-architecture clusters (HA-011).
+        #[arg(long = "registry-lookup")]
+        registry_lookup: bool,
+
+        /// Window (minutes) over which Warning events are aggregated for event analytics.
+        #[arg(long = "event-window", value_name = "MINUTES", default_value_t = kubeowler_core::inspections::events::DEFAULT_EVENT_WINDOW_MINUTES)]
+        event_window: u32,
+
+        /// Report a Warning event reason as noisy (EVT-001) at or above this many events per hour.
+        #[arg(long = "noisy-event-rate", value_name = "PER_HOUR", default_value_t = kubeowler_core::inspections::events::DEFAULT_NOISY_EVENTS_PER_HOUR)]
+        noisy_event_rate: u32,
+
+        /// Issues listed per issue code by the pod, resource and security checks; the rest are counted in one summary row (0 = list all).
+        #[arg(long = "max-issues-per-rule", value_name = "N", default_value_t = kubeowler_core::inspections::sampling::DEFAULT_MAX_ISSUES_PER_RULE)]
+        max_issues_per_rule: usize,
+
+        /// List pod findings per pod instead of rolling repeated ones up to the owning controller.
+        #[arg(long = "per-pod-issues")]
+        per_pod_issues: bool,
+
+        /// Run only the checks whose issue codes match these globs (comma-separated, e.g. POD-*,NET-*); modules without a matching code are not started, so their API calls are skipped.
+        #[arg(long = "only-checks", value_name = "PATTERNS", default_value = "")]
+        only_checks: String,
+
+        /// Skip the checks whose issue codes match these globs (comma-separated, e.g. SEC-004,RES-*); a module whose codes are all skipped is not started.
+        #[arg(long = "skip-checks", value_name = "PATTERNS", default_value = "")]
+        skip_checks: String,
+
+        /// Node data collection: logs (script output at pod start) or exec (run the script now in each pod).
+        #[arg(long = "node-collect", value_name = "MODE", default_value = "logs", value_parser = option_value::<NodeCollectMode>())]
+        node_collect: NodeCollectMode,
+
+        /// Opt-in active tests, comma-separated: network (TCP probes from the node inspector pods to Service VIPs,
+        /// pods on other nodes, other nodes' kubelet port and the egress target; needs pods/exec).
+        #[arg(long = "active-probes", value_name = "PROBES", value_delimiter = ',', value_parser = option_value::<ActiveProbe>())]
+        active_probes: Vec<ActiveProbe>,
+
+        /// host:port the network probe connects to for external egress; empty skips the egress probe.
+        #[arg(long = "probe-egress-target", value_name = "HOST:PORT", default_value = kubeowler_core::inspections::connectivity_probes::DEFAULT_EGRESS_TARGET)]
+        probe_egress_target: String,
+
+        /// Save sanitized YAML of objects affected by Critical findings into this directory (one subdirectory per issue code).
+        #[arg(long = "export-affected", value_name = "DIR")]
+        export_affected: Option<String>,
+
+        /// Skip the RBAC preflight (access reviews for every permission the checks use) before running.
+        #[arg(long = "skip-preflight")]
+        skip_preflight: bool,
+
+        /// Inspect exported manifests instead of a live cluster (requires --from-dir).
+        #[arg(long, requires = "from_dir")]
+        offline: bool,
+
+        /// Directory of `kubectl get -o yaml|json` or `kubectl cluster-info dump` output, read with --offline.
+        #[arg(long = "from-dir", value_name = "DIR", requires = "offline")]
+        from_dir: Option<String>,
+
+        /// Module weights for the overall score: balanced, security, reliability or custom (weights from the config file).
+        /// Default: `scoring.profile` from the config file, else balanced.
+        #[arg(long = "scoring-profile", value_name = "PROFILE", value_parser = option_value::<ScoringProfileName>())]
+        scoring_profile: Option<ScoringProfileName>,
+
+        /// Config file profile (`profiles.<NAME>`): severity overrides, excluded namespaces and scoring for e.g. dev or prod clusters.
+        #[arg(long = "profile", value_name = "NAME")]
+        profile: Option<String>,
+
+        /// kubeowler config file (default: ~/.config/kubeowler/config.yaml when it exists).
+        #[arg(long = "config", value_name = "FILE")]
+        config: Option<String>,
+
+        /// Owners file assigning namespaces/resources to teams (`teams.<team>.namespaces` / `.resources` globs).
+        #[arg(long, value_name = "FILE")]
+        owners: Option<String>,
+
+        /// Namespace label naming the owning team, used where the owners file has no match.
+        #[arg(long = "team-label", value_name = "KEY", default_value = kubeowler_core::ownership::DEFAULT_TEAM_LABEL)]
+        team_label: String,
+
+        /// Also write one report per team (only that team's findings), named `<report>-team-<team>.<ext>`.
+        #[arg(long = "split-by-team")]
+        split_by_team: bool,
+
+        /// Baseline file (`kubeowler baseline create`): matching findings are listed as accepted in an appendix
+        /// and left out of scores, rankings and tickets.
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<String>,
+
+        /// Directory of earlier `--format json` reports of this cluster: records since when each
+        /// finding has been open and lists overdue Critical findings.
+        #[arg(long = "history-dir", value_name = "DIR")]
+        history_dir: Option<String>,
+
+        /// Days a Critical finding may stay open before it is overdue (with --history-dir).
+        #[arg(long = "critical-sla-days", value_name = "DAYS", default_value_t = kubeowler_core::sla::DEFAULT_CRITICAL_SLA_DAYS)]
+        critical_sla_days: u32,
+
+        #[command(flatten)]
+        brand: BrandArgs,
+
+        /// Create or update a ticket per Critical issue code and namespace: github:<owner>/<repo> or jira:<PROJECT>.
+        /// Repeatable. Default: `tickets.targets` from the config file. Credentials come from the environment.
+        #[arg(long = "create-issues", value_name = "TARGET")]
+        create_issues: Vec<String>,
+    },
+    /// Rank clusters side by side from `check --benchmark` files or JSON reports
+    Compare {
+        /// Benchmark files or `check --format json` reports, one per cluster
+        #[arg(value_name = "FILE", num_args = 2.., required = true)]
+        inputs: Vec<String>,
+
+        /// Write the Markdown comparison to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+    /// Re-generate a report in another format from a saved JSON report, without re-running inspections
+    Render {
+        /// JSON report written by `check --format json`
+        #[arg(short, long, value_name = "FILE")]
+        input: String,
+
+        /// Output format: md (default), json, csv, html, or xlsx
+        #[arg(short, long, default_value = "md")]
+        format: ReportFormat,
+
+        /// CSV layout: summary (overview, namespace ranking and issue rows from the report tables), issues (one row per issue) or checks (one row per check)
+        #[arg(long = "csv-layout", value_name = "LAYOUT", default_value = "summary")]
+        csv_layout: CsvLayout,
+
+        /// Language of headings, labels and issue titles in Markdown/HTML reports: en, zh-CN or ja
+        #[arg(long, value_name = "LANG", default_value = "en", value_parser = option_value::<Lang>())]
+        lang: Lang,
+
+        /// Link issue codes to a local `kubeowler docs generate` bundle in DIR (default: docs) instead of GitHub
+        #[arg(long = "offline-docs", value_name = "DIR", num_args = 0..=1, default_missing_value = "docs")]
+        offline_docs: Option<String>,
+
+        /// Handlebars template rendered against the report data instead of the built-in Markdown layout (md/html formats; `*.html.hbs` for a full HTML page)
+        #[arg(long, value_name = "FILE")]
+        template: Option<String>,
+
+        /// Report sections to include in Markdown/HTML, comma-separated (risks, overdue, overview, containers, nodes, events, namespaces, checks, issues, scoring, baseline, diagnostics). Default: `report.sections` from the config file, else all.
+        #[arg(long, value_name = "SECTIONS", value_delimiter = ',', value_parser = option_value::<ReportSection>())]
+        sections: Vec<ReportSection>,
+
+        /// Resources listed per issue code and level in Markdown/HTML issue tables; the rest are summarized as "… and N more". 0 lists all.
+        #[arg(long = "max-resources-per-issue", value_name = "N", default_value_t = kubeowler_core::reporting::resource_appendix::DEFAULT_MAX_RESOURCES_PER_ISSUE)]
+        max_resources_per_issue: usize,
+
+        /// Also write the full affected-resource list of every issue to FILE (JSON)
+        #[arg(long = "resource-appendix", value_name = "FILE")]
+        resource_appendix: Option<String>,
+
+        /// Replace cluster, namespace, node and object names and IPs with hashed pseudonyms in every output, for sharing outside the organisation.
+        #[arg(long = "redact")]
+        redact: bool,
+
+        /// Output file path; if not set, defaults to the `check` file name for the saved report's cluster and time
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Check levels to show in report: "all" or comma-separated (Info, warning, critical). Default: warning,critical.
+        #[arg(
+            short = 'l',
+            long = "level",
+            value_name = "LEVELS",
+            default_value = "warning,critical"
+        )]
+        level: String,
+
+        /// kubeowler config file, for its `branding` section (default: ~/.config/kubeowler/config.yaml when it exists).
+        #[arg(long = "config", value_name = "FILE")]
+        config: Option<String>,
+
+        #[command(flatten)]
+        brand: BrandArgs,
+    },
+    /// Serve a web dashboard over a directory of saved JSON reports: run list, rendered reports and score trends
+    Serve {
+        /// Directory of `check --format json` reports (e.g. the deploy-cron report PVC)
+        #[arg(long = "history-dir", value_name = "DIR")]
+        history_dir: String,
+
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = crate::dashboard::DEFAULT_LISTEN)]
+        listen: std::net::SocketAddr,
+
+        /// Language of rendered reports: en, zh-CN or ja
+        #[arg(long, value_name = "LANG", default_value = "en", value_parser = option_value::<Lang>())]
+        lang: Lang,
+
+        /// Check levels to show in rendered reports: "all" or comma-separated (Info, warning, critical).
+        #[arg(
+            short = 'l',
+            long = "level",
+            value_name = "LEVELS",
+            default_value = "warning,critical"
+        )]
+        level: String,
+
+        /// kubeowler config file, for its `branding` section (default: ~/.config/kubeowler/config.yaml when it exists).
+        #[arg(long = "config", value_name = "FILE")]
+        config: Option<String>,
+
+        #[command(flatten)]
+        brand: BrandArgs,
+    },
+    /// Re-run the inspection periodically and print only what changed between runs
+    Watch {
+        /// Time between runs, e.g. 30s, 10m, 1h
+        #[arg(long, value_name = "DURATION", default_value = crate::watch::DEFAULT_WATCH_INTERVAL)]
+        interval: String,
+
+        /// Report an overall score change larger than this many points
+        #[arg(long = "score-threshold", value_name = "POINTS", default_value_t = crate::watch::DEFAULT_SCORE_THRESHOLD)]
+        score_threshold: f64,
+
+        /// Append every change (plain text) to this file
+        #[arg(long = "log-file", value_name = "PATH")]
+        log_file: Option<String>,
+
+        /// Issue levels to track: "all" or comma-separated (info, warning, critical). Default: warning,critical.
+        #[arg(
+            short = 'l',
+            long = "level",
+            value_name = "LEVELS",
+            default_value = "warning,critical"
+        )]
+        level: String,
+
+        /// Cluster name (default: from kubeconfig or "default")
+        #[arg(long = "cluster-name", value_name = "NAME")]
+        cluster_name: Option<String>,
+
+        /// Only inspect resources in this namespace. When unset, all namespaces are inspected.
+        #[arg(short, long, value_name = "NAMESPACE")]
+        namespace: Option<String>,
+
+        /// Namespace where kubeowler-node-inspector DaemonSet runs. Default: kubeowler.
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Node data collection: logs (script output at pod start) or exec (run the script now in each pod).
+        #[arg(long = "node-collect", value_name = "MODE", default_value = "logs", value_parser = option_value::<NodeCollectMode>())]
+        node_collect: NodeCollectMode,
+
+        /// Module weights for the overall score: balanced, security, reliability or custom.
+        #[arg(long = "scoring-profile", value_name = "PROFILE", value_parser = option_value::<ScoringProfileName>())]
+        scoring_profile: Option<ScoringProfileName>,
+
+        /// kubeowler config file (default: ~/.config/kubeowler/config.yaml when it exists).
+        #[arg(long = "config", value_name = "FILE")]
+        config: Option<String>,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Run the inspection and browse scores and issues in an interactive terminal dashboard
+    Tui {
+        /// Cluster name (default: from kubeconfig or "default")
+        #[arg(long = "cluster-name", value_name = "NAME")]
+        cluster_name: Option<String>,
+
+        /// Only inspect resources in this namespace. When unset, all namespaces are inspected.
+        #[arg(short, long, value_name = "NAMESPACE")]
+        namespace: Option<String>,
+
+        /// Namespace where kubeowler-node-inspector DaemonSet runs. Default: kubeowler.
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Node data collection: logs (script output at pod start) or exec (run the script now in each pod).
+        #[arg(long = "node-collect", value_name = "MODE", default_value = "logs", value_parser = option_value::<NodeCollectMode>())]
+        node_collect: NodeCollectMode,
+
+        /// Module weights for the overall score: balanced, security, reliability or custom.
+        #[arg(long = "scoring-profile", value_name = "PROFILE", value_parser = option_value::<ScoringProfileName>())]
+        scoring_profile: Option<ScoringProfileName>,
+
+        /// kubeowler config file (default: ~/.config/kubeowler/config.yaml when it exists).
+        #[arg(long = "config", value_name = "FILE")]
+        config: Option<String>,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Deploy a CronJob (with ServiceAccount and read-only RBAC) that runs `check` in-cluster
+    DeployCron {
+        /// Cron schedule of the inspection job
+        #[arg(long, value_name = "CRON", default_value = crate::deploy::cron::DEFAULT_SCHEDULE)]
+        schedule: String,
+
+        /// Namespace for the CronJob, ServiceAccount and report PVC
+        #[arg(short, long, value_name = "NAMESPACE", default_value = "kubeowler")]
+        namespace: String,
+
+        /// Container image of the job
+        #[arg(long, value_name = "IMAGE", default_value = crate::deploy::cron::DEFAULT_IMAGE)]
+        image: String,
+
+        /// PVC that receives the reports (mounted at /reports); without it reports are discarded with the pod
+        #[arg(long = "output-pvc", value_name = "NAME")]
+        output_pvc: Option<String>,
+
+        /// Create the output PVC with this size (e.g. 1Gi); when unset the PVC must already exist
+        #[arg(long = "pvc-size", value_name = "SIZE", requires = "output_pvc")]
+        pvc_size: Option<String>,
+
+        /// StorageClass of the created PVC (default: cluster default)
+        #[arg(long = "storage-class", value_name = "NAME", requires = "pvc_size")]
+        storage_class: Option<String>,
+
+        /// Secret exposed to the job as environment variables (e.g. credentials for uploading reports)
+        #[arg(long = "upload-secret", value_name = "NAME")]
+        upload_secret: Option<String>,
+
+        /// Namespace where kubeowler-node-inspector DaemonSet runs. Default: kubeowler.
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Print the manifests as YAML instead of applying them
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+
+        /// Extra arguments passed to `check` in the job (after `--`), e.g. -- -f html -l all
+        #[arg(last = true, value_name = "CHECK_ARGS")]
+        check_args: Vec<String>,
+    },
+    /// Remove the objects created by deploy-cron
+    Undeploy {
+        /// Namespace given to deploy-cron
+        #[arg(short, long, value_name = "NAMESPACE", default_value = "kubeowler")]
+        namespace: String,
+
+        /// Namespace given to deploy-cron for the node inspector
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Also delete the report PVC created with --pvc-size
+        #[arg(long = "delete-pvc")]
+        delete_pvc: bool,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Install, upgrade, remove or inspect the kubeowler-node-inspector DaemonSet
+    NodeInspector {
+        #[command(subcommand)]
+        action: NodeInspectorAction,
+    },
+    /// Manage baselines of accepted findings
+    Baseline {
+        #[command(subcommand)]
+        action: BaselineAction,
+    },
+    /// Walk fixable findings of a saved JSON report and apply allowlisted low-risk fixes
+    /// (STO-009, BATCH-001, STO-002) after a dry-run diff
+    Fix {
+        /// JSON report written by `check --format json`
+        #[arg(short, long, value_name = "FILE")]
+        input: String,
+
+        /// Ask before each fix and apply the confirmed ones; without it only the dry-run diffs are shown
+        #[arg(long)]
+        interactive: bool,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Print what an issue code means and how to fix it, e.g. `kubeowler explain POD-007`
+    Explain {
+        /// Issue code as shown in reports (case-insensitive)
+        #[arg(value_name = "CODE")]
+        code: String,
+    },
+    /// Print the JSON Schema of the `check --format json` report
+    Schema {
+        /// Write the schema to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+    /// Work with the issue-code documentation embedded in the binary
+    Docs {
+        #[command(subcommand)]
+        action: DocsAction,
+    },
+    /// Check which permissions kubeowler has (SelfSubjectAccessReview) and which checks would be skipped
+    Preflight {
+        /// Namespace that `check --namespace` would inspect (default: all namespaces)
+        #[arg(short, long, value_name = "NAMESPACE")]
+        namespace: Option<String>,
+
+        /// Namespace where the node inspector DaemonSet runs
+        #[arg(
+            long = "node-inspector-namespace",
+            value_name = "NAMESPACE",
+            default_value = "kubeowler"
+        )]
+        node_inspector_namespace: String,
+
+        /// Node data collection mode to check permissions for (logs needs pods/log, exec needs pods/exec)
+        #[arg(long = "node-collect", value_name = "MODE", default_value = "logs", value_parser = option_value::<NodeCollectMode>())]
+        node_collect: NodeCollectMode,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Generate an ordered Markdown upgrade plan for a target Kubernetes version
+    UpgradePlan {
+        /// Target Kubernetes version (e.g. 1.30)
+        #[arg(long, value_name = "VERSION")]
+        target: String,
+
+        /// Cluster name for the plan title (default: from kubeconfig or "default")
+        #[arg(long = "cluster-name", value_name = "NAME")]
+        cluster_name: Option<String>,
+
+        /// Output file path; defaults to {cluster-name}-upgrade-plan-{target}.md
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+
+        /// Node label keys (comma-separated, first match wins) whose value names the node pool.
+        #[arg(
+            long = "node-pool-label",
+            value_name = "LABELS",
+            default_value = kubeowler_core::inspections::options::DEFAULT_NODE_POOL_LABELS
+        )]
+        node_pool_label: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum NodeInspectorAction {
+    /// Create the namespace and DaemonSet, then wait for the rollout
+    Install {
+        #[command(flatten)]
+        deploy: NodeInspectorDeployArgs,
+    },
+    /// Re-apply the DaemonSet (e.g. with a new --image), then wait for the rollout
+    Upgrade {
+        #[command(flatten)]
+        deploy: NodeInspectorDeployArgs,
+    },
+    /// Delete the DaemonSet
+    Uninstall {
+        /// Namespace of the DaemonSet
+        #[arg(short, long, value_name = "NAMESPACE", default_value = kubeowler_core::node_inspection::collector::DEFAULT_NODE_INSPECTOR_NAMESPACE)]
+        namespace: String,
+
+        /// Also delete the namespace
+        #[arg(long = "delete-namespace")]
+        delete_namespace: bool,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+    /// Show rollout status and pods that are not running
+    Status {
+        /// Namespace of the DaemonSet
+        #[arg(short, long, value_name = "NAMESPACE", default_value = kubeowler_core::node_inspection::collector::DEFAULT_NODE_INSPECTOR_NAMESPACE)]
+        namespace: String,
+
+        /// Kubernetes config file path
+        #[arg(short, long)]
+        config_file: Option<String>,
+    },
+}
+
+/// Options shared by `node-inspector install` and `upgrade`.
+#[derive(clap::Args, Debug)]
+pub struct NodeInspectorDeployArgs {
+    /// Namespace of the DaemonSet
+    #[arg(short, long, value_name = "NAMESPACE", default_value = kubeowler_core::node_inspection::collector::DEFAULT_NODE_INSPECTOR_NAMESPACE)]
+    pub namespace: String,
+
+    /// Node inspector image
+    #[arg(long, value_name = "IMAGE", default_value = crate::deploy::node_inspector::DEFAULT_IMAGE)]
+    pub image: String,
+
+    /// Toleration KEY[=VALUE][:EFFECT] (repeatable); default tolerates all taints
+    #[arg(long = "toleration", value_name = "TOLERATION")]
+    pub tolerations: Vec<String>,
+
+    /// CPU request of the inspector container
+    #[arg(long = "cpu-request", value_name = "CPU", default_value = crate::deploy::node_inspector::DEFAULT_CPU_REQUEST)]
+    pub cpu_request: String,
+
+    /// Memory request of the inspector container
+    #[arg(long = "memory-request", value_name = "MEMORY", default_value = crate::deploy::node_inspector::DEFAULT_MEMORY_REQUEST)]
+    pub memory_request: String,
+
+    /// CPU limit of the inspector container
+    #[arg(long = "cpu-limit", value_name = "CPU", default_value = crate::deploy::node_inspector::DEFAULT_CPU_LIMIT)]
+    pub cpu_limit: String,
+
+    /// Memory limit of the inspector container
+    #[arg(long = "memory-limit", value_name = "MEMORY", default_value = crate::deploy::node_inspector::DEFAULT_MEMORY_LIMIT)]
+    pub memory_limit: String,
+
+    /// Seconds to wait for the rollout (0 = do not wait)
+    #[arg(long, value_name = "SECONDS", default_value_t = crate::deploy::node_inspector::DEFAULT_ROLLOUT_TIMEOUT_SECS)]
+    pub timeout: u64,
+
+    /// Print the manifests as YAML instead of applying them
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// Kubernetes config file path
+    #[arg(short, long)]
+    pub config_file: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum BaselineAction {
+    /// Record every finding of a saved JSON report as accepted
+    Create {
+        /// JSON report written by `check --format json`
+        #[arg(long, value_name = "FILE")]
+        from: String,
+
+        /// Baseline file to write
+        #[arg(short, long, value_name = "FILE", default_value = kubeowler_core::baseline::DEFAULT_BASELINE_FILE)]
+        output: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DocsAction {
+    /// Write one Markdown page per issue code, plus an index, for offline use with `check --offline-docs`
+    Generate {
+        /// Directory to write the pages to
+        #[arg(long, value_name = "DIR", default_value = "./docs")]
+        out: String,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum, Debug, Default)]
+#[value(rename_all = "kebab-case")]
+pub enum ReportFormat {
+    #[default]
+    Md,
+    Json,
+    Csv,
+    Html,
+    Xlsx,
+}
+
+/// Row layout of `--format csv`.
+#[derive(Clone, Copy, ValueEnum, Debug, Default, PartialEq, Eq)]
+#[value(rename_all = "kebab-case")]
+pub enum CsvLayout {
+    /// Cluster overview, data completeness, namespace ranking and issue rows as in the report tables
+    #[default]
+    Summary,
+    /// One row per issue with all fields
+    Issues,
+    /// One row per check
+    Checks,
+}
+
+/// How every cluster-connecting subcommand reaches the API server, on top of `--config-file`.
+#[derive(clap::Args, Debug, Clone)]
+pub struct ClusterAccessArgs {
+    /// kubeconfig context to use instead of the current context
+    #[arg(long, global = true, value_name = "NAME")]
+    pub context: Option<String>,
+
+    /// Send requests as this user (Impersonate-User), e.g. system:serviceaccount:<ns>:<name>; needs `impersonate` RBAC
+    #[arg(long, global = true, value_name = "USER")]
+    pub impersonate: Option<String>,
+
+    /// Group to impersonate along with --impersonate; repeatable
+    #[arg(
+        long = "impersonate-group",
+        global = true,
+        value_name = "GROUP",
+        requires = "impersonate"
+    )]
+    pub impersonate_group: Vec<String>,
+
+    /// Maximum sustained API requests per second (0 = unlimited)
+    #[arg(long, global = true, value_name = "QPS", default_value_t = kubeowler_core::k8s::throttle::DEFAULT_QPS)]
+    pub qps: f64,
+
+    /// API requests allowed at once above --qps
+    #[arg(long, global = true, value_name = "N", default_value_t = kubeowler_core::k8s::throttle::DEFAULT_BURST)]
+    pub burst: u32,
+
+    /// Retries of a request answered with 429 (or 5xx / a connection error for reads), with exponential backoff
+    #[arg(long = "max-retries", global = true, value_name = "N", default_value_t = kubeowler_core::k8s::throttle::DEFAULT_MAX_RETRIES)]
+    pub max_retries: u32,
+
+    /// Seconds to wait for each API response (0 = no timeout)
+    #[arg(long = "request-timeout", global = true, value_name = "SECONDS", default_value_t = kubeowler_core::k8s::throttle::DEFAULT_REQUEST_TIMEOUT_SECS)]
+    pub request_timeout: u64,
+
+    /// Objects per LIST request; larger lists are fetched in pages with `continue` tokens (0 = one request)
+    #[arg(long = "page-size", global = true, value_name = "N", default_value_t = kubeowler_core::k8s::client::DEFAULT_PAGE_SIZE)]
+    pub page_size: u32,
+
+    /// Serve LIST requests from the API server watch cache (`resourceVersion=0`): lighter on etcd, possibly slightly stale
+    #[arg(long = "list-from-cache", global = true)]
+    pub list_from_cache: bool,
+
+    /// Per-namespace and per-node requests in flight at once (namespace summary, kubelet stats and configz, ...); still subject to --qps
+    #[arg(long = "api-concurrency", global = true, value_name = "N", default_value_t = kubeowler_core::k8s::client::DEFAULT_API_CONCURRENCY, value_parser = clap::value_parser!(u32).range(1..))]
+    pub api_concurrency: u32,
+}
+
+impl From<ClusterAccessArgs> for ClusterAccess {
+    fn from(args: ClusterAccessArgs) -> Self {
+        Self {
+            context: args.context,
+            impersonate: args.impersonate,
+            impersonate_group: args.impersonate_group,
+            qps: args.qps,
+            burst: args.burst,
+            max_retries: args.max_retries,
+            request_timeout: args.request_timeout,
+            page_size: args.page_size,
+            list_from_cache: args.list_from_cache,
+            api_concurrency: args.api_concurrency,
+        }
+    }
+}
+
+/// HTML report branding; each option overrides `branding` in the config file.
+#[derive(clap::Args, Debug, Default, Clone)]
+pub struct BrandArgs {
+    /// Company name shown above the HTML report and in its title
+    #[arg(long = "brand-name", value_name = "NAME")]
+    pub brand_name: Option<String>,
+
+    /// Logo for the HTML report: image file (png, jpg, gif, svg, webp; embedded) or http(s) URL
+    #[arg(long = "brand-logo", value_name = "FILE|URL")]
+    pub brand_logo: Option<String>,
+
+    /// Accent color of the HTML report as #rgb or #rrggbb
+    #[arg(long = "brand-color", value_name = "HEX")]
+    pub brand_color: Option<String>,
+
+    /// HTML report theme: light (default) or dark
+    #[arg(long, value_name = "THEME", value_parser = option_value::<HtmlTheme>())]
+    pub theme: Option<HtmlTheme>,
+}
+
+impl From<BrandArgs> for BrandOverrides {
+    fn from(args: BrandArgs) -> Self {
+        Self {
+            brand_name: args.brand_name,
+            brand_logo: args.brand_logo,
+            brand_color: args.brand_color,
+            theme: args.theme,
+        }
+    }
+}
+
+/// Parser for an option enum of `kubeowler_core`: its names as possible values, with their
+/// descriptions in `--help`.
+fn option_value<T: OptionValue>() -> impl TypedValueParser<Value = T> {
+    PossibleValuesParser::new(
+        T::VARIANTS
+            .iter()
+            .map(|(_, name, help)| PossibleValue::new(*name).help(*help)),
+    )
+    .map(|name| T::from_name(&name).expect("name is one of the possible values"))
+}
+
+/// Prefix of the environment variable read for every long flag.
+pub const ENV_PREFIX: &str = "KUBEOWLER_";
+
+/// `--max-issues-per-rule` → `KUBEOWLER_MAX_ISSUES_PER_RULE`.
+pub fn env_var_name(long: &str) -> String {
+    format!("{}{}", ENV_PREFIX, long.replace('-', "_").to_uppercase())
+}
+
+/// Long flags that mean different things in different subcommands (`--namespace` scopes `check`
+/// but is where `deploy-cron` and `undeploy` act); their variables are named after the
+/// subcommand, so one exported for `check` cannot retarget `undeploy`.
+pub const SUBCOMMAND_ENV_FLAGS: &[&str] = &["namespace", "dry-run", "image", "output"];
+
+/// Variable of `long` in the subcommand `path` (names joined by `-`, empty for top-level flags):
+/// `--namespace` of `deploy-cron` → `KUBEOWLER_DEPLOY_CRON_NAMESPACE`, other flags as
+/// [`env_var_name`].
+pub fn subcommand_env_var_name(path: &str, long: &str) -> String {
+    if path.is_empty() || !SUBCOMMAND_ENV_FLAGS.contains(&long) {
+        env_var_name(long)
+    } else {
+        env_var_name(&format!("{}-{}", path, long))
+    }
+}
+
+/// `command` with every long flag of every subcommand also read from its
+/// [`subcommand_env_var_name`] variable (clap `env`), for containers configured through the
+/// environment. A flag on the command line wins over its variable.
+pub fn with_env_vars(command: Command) -> Command {
+    with_env_vars_under(command, "")
+}
+
+fn with_env_vars_under(command: Command, path: &str) -> Command {
+    command
+        .mut_args(|arg| match arg.get_long() {
+            Some(long) => {
+                let name = subcommand_env_var_name(path, long);
+                arg.env(name)
+            }
+            None => arg,
+        })
+        .mut_subcommands(|sub| {
+            let path = match path {
+                "" => sub.get_name().to_string(),
+                parent => format!("{}-{}", parent, sub.get_name()),
+            };
+            with_env_vars_under(sub, &path)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, FromArgMatches};
+
+    /// (variable, long name) of every long flag.
+    fn env_vars(command: &Command, path: &str, out: &mut Vec<(String, String)>) {
+        for arg in command.get_arguments() {
+            if let Some(long) = arg.get_long() {
+                let env = arg.get_env().and_then(|e| e.to_str()).unwrap_or_default();
+                assert_eq!(env, subcommand_env_var_name(path, long));
+                out.push((env.to_string(), long.to_string()));
+            }
+        }
+        for sub in command.get_subcommands() {
+            let sub_path = match path {
+                "" => sub.get_name().to_string(),
+                parent => format!("{}-{}", parent, sub.get_name()),
+            };
+            env_vars(sub, &sub_path, out);
+        }
+    }
+
+    #[test]
+    fn every_long_flag_reads_its_env_var() {
+        let mut vars = Vec::new();
+        env_vars(&with_env_vars(Args::command()), "", &mut vars);
+        assert!(vars.contains(&("KUBEOWLER_QPS".to_string(), "qps".to_string())));
+
+        std::env::set_var("KUBEOWLER_MAX_ISSUES_PER_RULE", "9");
+        std::env::set_var("KUBEOWLER_PER_POD_ISSUES", "true");
+        let matches = with_env_vars(Args::command())
+            .try_get_matches_from(["kubeowler", "check", "--max-issues-per-rule", "3"])
+            .unwrap();
+        std::env::remove_var("KUBEOWLER_MAX_ISSUES_PER_RULE");
+        std::env::remove_var("KUBEOWLER_PER_POD_ISSUES");
+        let Commands::Check {
+            max_issues_per_rule,
+            per_pod_issues,
+            ..
+        } = Args::from_arg_matches(&matches).unwrap().command
+        else {
+            panic!("not check");
+        };
+        assert_eq!(max_issues_per_rule, 3);
+        assert!(per_pod_issues);
+    }
+
+    #[test]
+    fn subcommand_flags_do_not_share_env_vars() {
+        let mut vars = Vec::new();
+        env_vars(&with_env_vars(Args::command()), "", &mut vars);
+        for long in SUBCOMMAND_ENV_FLAGS {
+            let mut readers: Vec<&str> = vars
+                .iter()
+                .filter(|(_, l)| l == long)
+                .map(|(env, _)| env.as_str())
+                .collect();
+            let count = readers.len();
+            assert!(count > 1, "--{} is not shared", long);
+            readers.sort_unstable();
+            readers.dedup();
+            assert_eq!(readers.len(), count, "--{} variable read twice", long);
+        }
+        assert!(!vars.iter().any(|(env, _)| env == "KUBEOWLER_NAMESPACE"));
+
+        std::env::set_var("KUBEOWLER_CHECK_NAMESPACE", "shop");
+        let parse = |argv: &[&str]| {
+            let matches = with_env_vars(Args::command())
+                .try_get_matches_from(argv)
+                .unwrap();
+            Args::from_arg_matches(&matches).unwrap().command
+        };
+        let check = parse(&["kubeowler", "check"]);
+        let undeploy = parse(&["kubeowler", "undeploy"]);
+        std::env::remove_var("KUBEOWLER_CHECK_NAMESPACE");
+        let Commands::Check { namespace, .. } = check else {
+            panic!("not check");
+        };
+        assert_eq!(namespace.as_deref(), Some("shop"));
+        let Commands::Undeploy { namespace, .. } = undeploy else {
+            panic!("not undeploy");
+        };
+        assert_eq!(namespace, "kubeowler");
+    }
+}
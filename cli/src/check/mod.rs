@@ -0,0 +1,467 @@
+//! `kubeowler check`: run the inspection, apply ownership, profile, baseline and history, sync
+//! tickets and write the report in the requested format. `render` re-renders a saved report.
+
+pub mod output;
+pub mod render;
+
+use anyhow::Result;
+use colored::Colorize;
+use log::info;
+
+use kubeowler_core::cli::{ClusterAccess, InspectionType, Lang, ReportSection, ScoringProfileName};
+use kubeowler_core::inspections::{self, InspectionOptions, InspectionRunner};
+use kubeowler_core::reporting::template::ReportTemplate;
+use kubeowler_core::reporting::{self, branding::Branding, ReportGenerator};
+use kubeowler_core::{
+    baseline, benchmark, capacity, config, offline, ownership, preflight, scoring, sla,
+};
+
+use crate::args::{CsvLayout, ReportFormat};
+use crate::console::{connect, console_notify, print_affected_checks};
+use crate::tickets;
+use output::{output_path_with_extension, team_output_path, write_report};
+
+/// Resolved options of the `check` subcommand.
+pub struct CheckOptions {
+    pub cluster_name: Option<String>,
+    pub namespace: Option<String>,
+    /// `--selector`: label selector for LISTs of namespaced objects.
+    pub selector: Option<String>,
+    pub node_inspector_namespace: String,
+    pub output: Option<String>,
+    pub format: ReportFormat,
+    pub csv_layout: CsvLayout,
+    pub lang: Lang,
+    /// Issue doc bundle directory for report links (`--offline-docs`).
+    pub offline_docs: Option<String>,
+    /// Compiled `--template`, checked before the cluster is contacted.
+    pub template: Option<ReportTemplate>,
+    /// `--sections`, or `report.sections` from the config file.
+    pub sections: Vec<ReportSection>,
+    pub max_resources_per_issue: usize,
+    pub resource_appendix: Option<String>,
+    /// `--redact`: names and IPs replaced before any output is written.
+    pub redact: bool,
+    /// `--benchmark` file and its `--benchmark-label`.
+    pub benchmark: Option<String>,
+    pub benchmark_label: Option<String>,
+    pub config_file: Option<String>,
+    pub level: String,
+    pub inspection_options: InspectionOptions,
+    pub export_affected: Option<String>,
+    pub skip_preflight: bool,
+    /// Dump directory for `--offline`.
+    pub from_dir: Option<String>,
+    pub owners: Option<String>,
+    pub team_label: String,
+    pub split_by_team: bool,
+    pub baseline: Option<String>,
+    pub history_dir: Option<String>,
+    pub critical_sla_days: u32,
+    pub profile: Option<config::Profile>,
+    pub ticket_targets: Vec<tickets::TicketTarget>,
+    pub ticket_config: config::TicketConfig,
+    pub branding: Branding,
+    pub access: ClusterAccess,
+}
+
+/// `--scoring-profile`, else `scoring.profile` from the config file, else balanced.
+pub fn resolve_scoring_profile(
+    name: Option<ScoringProfileName>,
+    config: &config::Config,
+) -> Result<scoring::ScoringProfile> {
+    let name = name.or(config.scoring.profile).unwrap_or_default();
+    scoring::ScoringProfile::resolve(name, &config.scoring.weights)?
+        .with_thresholds(config.scoring.thresholds.as_ref())
+}
+
+/// Run `check` with resolved options.
+pub async fn run(opts: CheckOptions) -> Result<()> {
+    let CheckOptions {
+        cluster_name,
+        namespace,
+        selector,
+        node_inspector_namespace,
+        output,
+        format,
+        csv_layout,
+        lang,
+        offline_docs,
+        template,
+        sections,
+        max_resources_per_issue,
+        resource_appendix,
+        redact,
+        benchmark,
+        benchmark_label,
+        config_file,
+        level,
+        inspection_options,
+        export_affected,
+        skip_preflight,
+        from_dir,
+        owners,
+        team_label,
+        split_by_team,
+        baseline,
+        history_dir,
+        critical_sla_days,
+        profile,
+        ticket_targets,
+        ticket_config,
+        branding,
+        access,
+    } = opts;
+
+    println!(
+        "{}",
+        "🔍 Kubeowler - Kubernetes Cluster Checker"
+            .bright_cyan()
+            .bold()
+    );
+    println!(
+        "{}",
+        "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".bright_cyan()
+    );
+
+    info!("Starting Kubernetes cluster check");
+
+    println!("📋 {}", "Configuration:".bright_yellow().bold());
+    println!(
+        "   Inspection scope: {}",
+        namespace
+            .as_deref()
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "all namespaces".to_string())
+            .bright_green()
+    );
+    if let Some(selector) = selector.as_deref() {
+        println!("   Label selector: {}", selector.bright_green());
+    }
+    if let Some(dir) = from_dir.as_deref() {
+        println!("   Source: {} (offline)", dir.bright_green());
+    } else {
+        println!(
+            "   Node inspector DaemonSet: {}",
+            node_inspector_namespace.bright_green()
+        );
+    }
+    println!(
+        "   Output File: {}",
+        output.as_deref().unwrap_or("(auto)").bright_green()
+    );
+    println!();
+
+    let (client, dump) = match from_dir.as_deref() {
+        Some(dir) => {
+            print!("📂 Loading dump... ");
+            let store = match offline::load_dump_dir(std::path::Path::new(dir)) {
+                Ok(store) => std::sync::Arc::new(store),
+                Err(e) => {
+                    println!("{}", "❌ Failed".bright_red());
+                    return Err(e);
+                }
+            };
+            println!(
+                "{} ({} objects, {} resource types)",
+                "✅ Loaded".bright_green(),
+                store.object_count(),
+                store.resource_counts().len()
+            );
+            // Without a kubeconfig, the dump directory names the cluster.
+            let name = cluster_name.clone().or_else(|| {
+                std::path::Path::new(dir)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+            });
+            (offline::offline_client(store.clone(), name), Some(store))
+        }
+        None => (connect(config_file.as_deref(), &access).await?, None),
+    };
+    let client = client.with_label_selector(selector);
+
+    if !skip_preflight && dump.is_none() {
+        print!("🛂 Checking permissions... ");
+        match preflight::run_preflight(
+            &client,
+            namespace.as_deref(),
+            &node_inspector_namespace,
+            inspection_options.node_collect,
+            &inspection_options.active_probes,
+        )
+        .await
+        {
+            Ok(report) if report.denied().next().is_none() => {
+                println!("{}", "✅ All granted".bright_green())
+            }
+            Ok(report) => {
+                println!(
+                    "{}",
+                    format!(
+                        "{} denied (run `kubeowler preflight` for the full table)",
+                        report.denied().count()
+                    )
+                    .bright_yellow()
+                );
+                print_affected_checks(&report);
+            }
+            // Not fatal: the checks themselves report what they could not read.
+            Err(e) => println!("{} ({})", "skipped".bright_yellow(), e),
+        }
+    }
+
+    println!("🔍 Running checks...");
+    let runner = InspectionRunner::new(client.clone())
+        .with_notify(console_notify())
+        .with_options(inspection_options);
+
+    let mut raw_results = match runner
+        .run_inspections(
+            InspectionType::All,
+            namespace.as_deref(),
+            &node_inspector_namespace,
+            cluster_name.as_deref(),
+        )
+        .await
+    {
+        Ok(results) => {
+            println!("{}", "✅ Completed".bright_green());
+            results
+        }
+        Err(e) => {
+            println!("{}", "❌ Failed".bright_red());
+            eprintln!("Error: {}", e);
+            return Err(e);
+        }
+    };
+
+    if let Some(store) = dump.as_deref() {
+        let missing = store.missing_resources();
+        if !missing.is_empty() {
+            raw_results.metadata.collection_warnings.push(
+                inspections::types::CollectionWarning::partial(
+                    "Offline dump",
+                    format!(
+                        "Not in the dump, so checks using them saw no objects: {}.",
+                        missing.join(", ")
+                    ),
+                ),
+            );
+        }
+    }
+
+    let owners_file = match owners.as_deref() {
+        Some(path) => ownership::load_owners_file(std::path::Path::new(path))?,
+        None => ownership::OwnersFile::default(),
+    };
+    let label_teams = match ownership::namespace_label_teams(&client, &team_label).await {
+        Ok(teams) => teams,
+        Err(e) => {
+            raw_results.metadata.collection_warnings.push(
+                inspections::types::CollectionWarning::unavailable(
+                    "Namespace team labels",
+                    format!(
+                        "Findings are not assigned to teams through the `{}` label: {}",
+                        team_label, e
+                    ),
+                ),
+            );
+            Default::default()
+        }
+    };
+    let owners = ownership::Ownership::new(owners_file, label_teams);
+    let owned = owners.assign(&mut raw_results);
+
+    // Every output format is generated from the redacted copy.
+    let mut results = reporting::redact::redact_report(&raw_results)?;
+    let profile_info = profile.map(|p| p.apply(&mut results));
+    let baseline_info = match baseline.as_deref() {
+        Some(path) => {
+            Some(baseline::Baseline::load(std::path::Path::new(path))?.apply(&mut results, path))
+        }
+        None => None,
+    };
+    let finding_ages = match history_dir.as_deref() {
+        Some(dir) => Some(sla::apply(
+            &mut results,
+            std::path::Path::new(dir),
+            critical_sla_days,
+        )?),
+        None => None,
+    };
+    if let Some(dir) = history_dir.as_deref() {
+        capacity::apply_forecast(&mut results, std::path::Path::new(dir))?;
+    }
+
+    println!();
+    println!("{}", "📊 Summary:".bright_yellow().bold());
+    let health = &results.executive_summary.health_status;
+    println!(
+        "   Overall Score: {} {:.1}/100, grade {} ({}, {} profile)",
+        health.emoji(),
+        results.overall_score,
+        health.grade(),
+        health.label(),
+        results.metadata.scoring_profile.name
+    );
+
+    let total_issues: usize = results
+        .inspections
+        .iter()
+        .map(|i| i.summary.issues.len())
+        .sum();
+
+    println!(
+        "   Issues Found: {}",
+        if total_issues == 0 {
+            format!("{}", total_issues).bright_green()
+        } else {
+            format!("{}", total_issues).bright_yellow()
+        }
+    );
+    if let Some(counts) = results.metadata.issue_counts.filter(|c| c.merged > 0) {
+        println!(
+            "   Duplicates Merged: {} ({} raw findings)",
+            counts.merged, counts.raw
+        );
+    }
+
+    if let Some(dir) = export_affected.as_deref() {
+        print!("📦 Exporting affected resources... ");
+        match reporting::export_affected::export_affected(&client, &results, dir).await {
+            Ok(n) => println!(
+                "{} ({} file(s) in {})",
+                "✅ Done".bright_green(),
+                n,
+                dir.bright_cyan()
+            ),
+            Err(e) => {
+                println!("{}", "❌ Failed".bright_red());
+                eprintln!("Error: {}", e);
+            }
+        }
+    }
+
+    if let Some(info) = &profile_info {
+        println!(
+            "   Profile {}: {} finding(s) in excluded namespaces, {} severity override(s)",
+            info.name, info.excluded, info.overridden
+        );
+    }
+    if let Some(info) = &baseline_info {
+        println!(
+            "   Accepted (baseline): {} ({} baseline finding(s) resolved)",
+            info.accepted, info.resolved
+        );
+    }
+    if let Some(ages) = &finding_ages {
+        let line = format!(
+            "   Overdue (Critical open > {} day(s)): {} ({} earlier report(s))",
+            ages.critical_sla_days, ages.overdue, ages.earlier_runs
+        );
+        if ages.overdue > 0 {
+            println!("{}", line.bright_red());
+        } else {
+            println!("{}", line);
+        }
+    }
+    if owned > 0 {
+        println!(
+            "   Findings with an owning team: {}/{}",
+            owned, total_issues
+        );
+    }
+
+    for target in &ticket_targets {
+        print!("🎫 Syncing Critical findings to {}... ", target);
+        match tickets::sync_tickets(&results, target, &ticket_config).await {
+            Ok(summary) => {
+                println!(
+                    "{} ({} created, {} updated)",
+                    "✅ Done".bright_green(),
+                    summary.created.len(),
+                    summary.updated.len()
+                );
+                for url in &summary.created {
+                    println!("   New: {}", url.bright_cyan());
+                }
+            }
+            Err(e) => {
+                println!("{}", "❌ Failed".bright_red());
+                eprintln!("Error: {:#}", e);
+            }
+        }
+    }
+
+    if let Some(path) = &benchmark {
+        let summary = benchmark::BenchmarkSummary::from_report(&results, benchmark_label);
+        std::fs::write(path, serde_json::to_string_pretty(&summary)?)?;
+        println!("   Benchmark summary: {}", path.bright_cyan());
+    }
+    // Names are replaced last: export, baseline and tickets above need the real ones.
+    if redact {
+        results = reporting::anonymize::anonymize_report(&results)?;
+    }
+    let output_path = output_path_with_extension(output, &results, format);
+
+    print!("📝 Generating report... ");
+    let generator = ReportGenerator::new()
+        .with_lang(lang)
+        .with_docs_base(offline_docs)
+        .with_template(template)
+        .with_sections(sections)
+        .with_resource_limit(max_resources_per_issue, resource_appendix.clone());
+    write_report(
+        &results,
+        format,
+        csv_layout,
+        &generator,
+        &output_path,
+        &level,
+        &branding,
+    )
+    .await?;
+    if let Some(path) = &resource_appendix {
+        reporting::resource_appendix::write(&results, path)?;
+    }
+    println!("{}", "✅ Done".bright_green());
+
+    let mut team_paths = Vec::new();
+    if split_by_team {
+        for (team, team_report) in owners.split_by_team(&results) {
+            let path = team_output_path(&output_path, &team);
+            write_report(
+                &team_report,
+                format,
+                csv_layout,
+                &generator,
+                &path,
+                &level,
+                &branding,
+            )
+            .await?;
+            team_paths.push(path);
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        "🎉 Check completed successfully!".bright_green().bold()
+    );
+    println!("   Report: {}", output_path.bright_cyan());
+    if let Some(path) = &resource_appendix {
+        println!("   Resource appendix: {}", path.bright_cyan());
+    }
+    for path in &team_paths {
+        println!("   Team report: {}", path.bright_cyan());
+    }
+    if split_by_team && team_paths.is_empty() {
+        println!(
+            "   {}",
+            "No findings have an owning team; no team reports written (see --owners / --team-label)."
+                .yellow()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,151 @@
+//! Report files: default names, per-team paths and writing one report in any `--format`.
+
+use anyhow::Result;
+
+use kubeowler_core::inspections::types::ClusterReport;
+use kubeowler_core::reporting::generator::parse_check_level_filter;
+use kubeowler_core::reporting::template::ReportTemplate;
+use kubeowler_core::reporting::{self, branding::Branding, ReportGenerator};
+
+use crate::args::{CsvLayout, ReportFormat};
+
+/// Sanitize cluster name for use in filename: replace invalid chars with `-`, collapse and trim.
+pub fn sanitize_cluster_name(name: &str) -> String {
+    let s: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+            _ => c,
+        })
+        .collect();
+    let s = s
+        .split('-')
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if s.is_empty() {
+        "cluster".to_string()
+    } else {
+        s
+    }
+}
+
+/// `report.md` -> `report-team-<team>.md`, next to the full report.
+pub fn team_output_path(report_path: &str, team: &str) -> String {
+    let path = std::path::Path::new(report_path);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let file = match path.extension() {
+        Some(ext) => format!(
+            "{}-team-{}.{}",
+            stem,
+            sanitize_cluster_name(team),
+            ext.to_string_lossy()
+        ),
+        None => format!("{}-team-{}", stem, sanitize_cluster_name(team)),
+    };
+    path.with_file_name(file).to_string_lossy().into_owned()
+}
+
+/// `--output`, or `<cluster>-kubernetes-inspection-report-<time>`, with the extension of `format`.
+pub fn output_path_with_extension(
+    path: Option<String>,
+    report: &ClusterReport,
+    format: ReportFormat,
+) -> String {
+    let ext = match format {
+        ReportFormat::Md => "md",
+        ReportFormat::Json => "json",
+        ReportFormat::Csv => "csv",
+        ReportFormat::Html => "html",
+        ReportFormat::Xlsx => "xlsx",
+    };
+    let default_name = {
+        let safe_name = sanitize_cluster_name(&report.cluster_name);
+        let ts = report
+            .display_timestamp_filename
+            .clone()
+            .unwrap_or_else(|| report.timestamp.format("%Y-%m-%d-%H%M%S").to_string());
+        format!("{}-kubernetes-inspection-report-{}.{}", safe_name, ts, ext)
+    };
+    let path = path.unwrap_or(default_name);
+    if path.ends_with('.') || !path.contains('.') {
+        format!("{}.{}", path.trim_end_matches('.'), ext)
+    } else {
+        path
+    }
+}
+
+/// Compile `--template` up front so a broken template fails before the (long) inspection.
+pub fn load_template(path: Option<&str>) -> Result<Option<ReportTemplate>> {
+    path.map(ReportTemplate::from_file).transpose()
+}
+
+/// Write `report` to `path` in `format`; `level` filters check rows (all formats but JSON);
+/// `generator` sets language and doc links of Markdown and HTML; `branding` applies to HTML.
+pub async fn write_report(
+    report: &ClusterReport,
+    format: ReportFormat,
+    csv_layout: CsvLayout,
+    generator: &ReportGenerator,
+    path: &str,
+    level: &str,
+    branding: &Branding,
+) -> Result<()> {
+    let check_level_filter = Some(parse_check_level_filter(level));
+    match format {
+        ReportFormat::Json => {
+            let file = std::fs::File::create(path)?;
+            serde_json::to_writer_pretty(file, report)?;
+        }
+        ReportFormat::Csv => {
+            let level = parse_check_level_filter(level);
+            let csv = match csv_layout {
+                CsvLayout::Summary => {
+                    // Parsed from the English Markdown whatever --lang is.
+                    let md_string = ReportGenerator::new().generate_markdown_string(
+                        report,
+                        None,
+                        None,
+                        None,
+                        check_level_filter,
+                    )?;
+                    reporting::md_export::md_to_csv(&md_string)?
+                }
+                CsvLayout::Issues => reporting::csv::issues_csv(report, &level),
+                CsvLayout::Checks => reporting::csv::checks_csv(report, &level),
+            };
+            std::fs::write(path, csv)?;
+        }
+        ReportFormat::Html => {
+            let md_string =
+                generator.generate_markdown_string(report, None, None, None, check_level_filter)?;
+            let html = if generator.renders_html() {
+                md_string
+            } else {
+                reporting::md_export::md_to_html(&md_string, branding)?
+            };
+            std::fs::write(path, html)?;
+        }
+        ReportFormat::Xlsx => {
+            let level = parse_check_level_filter(level);
+            std::fs::write(path, reporting::xlsx::report_to_xlsx(report, &level)?)?;
+        }
+        ReportFormat::Md => {
+            generator
+                .generate_report_with_filters(
+                    report,
+                    path,
+                    None,
+                    true,
+                    None,
+                    None,
+                    check_level_filter,
+                )
+                .await?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! `kubeowler render`: write a saved JSON report in another format without contacting a cluster.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::inspections::types::ClusterReport;
+use kubeowler_core::reporting::{self, branding::Branding, ReportGenerator};
+
+use super::output::{output_path_with_extension, write_report};
+use crate::args::{CsvLayout, ReportFormat};
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    input: &str,
+    format: ReportFormat,
+    csv_layout: CsvLayout,
+    generator: &ReportGenerator,
+    output: Option<String>,
+    level: &str,
+    branding: &Branding,
+    redact: bool,
+) -> Result<()> {
+    let file =
+        std::fs::File::open(input).map_err(|e| anyhow::anyhow!("cannot open {}: {}", input, e))?;
+    let report: ClusterReport =
+        serde_json::from_reader(std::io::BufReader::new(file)).map_err(|e| {
+            anyhow::anyhow!(
+                "{} is not a kubeowler JSON report (`check --format json`): {}",
+                input,
+                e
+            )
+        })?;
+    // Saved reports are already redacted; redact again in case the JSON was edited by hand.
+    let mut report = reporting::redact::redact_report(&report)?;
+    if redact {
+        report = reporting::anonymize::anonymize_report(&report)?;
+    }
+    let output_path = output_path_with_extension(output, &report, format);
+
+    print!("📝 Rendering report {}... ", report.report_id);
+    write_report(
+        &report,
+        format,
+        csv_layout,
+        generator,
+        &output_path,
+        level,
+        branding,
+    )
+    .await?;
+    if let Some(path) = generator.resource_appendix() {
+        reporting::resource_appendix::write(&report, path)?;
+    }
+    println!("{}", "✅ Done".bright_green());
+    println!("   Report: {}", output_path.bright_cyan());
+    if let Some(path) = generator.resource_appendix() {
+        println!("   Resource appendix: {}", path.bright_cyan());
+    }
+    Ok(())
+}
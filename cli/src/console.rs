@@ -0,0 +1,60 @@
+//! Console output shared by the subcommands: the connect progress line, runner notices, prompts
+//! and the preflight summary.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::cli::ClusterAccess;
+use kubeowler_core::inspections::notice::{NoticeLevel, Notify};
+use kubeowler_core::k8s::K8sClient;
+use kubeowler_core::preflight;
+
+/// Print runner notices (node inspector waits, skipped or failed modules) to the console.
+pub fn console_notify() -> Notify {
+    std::sync::Arc::new(|level, message| match level {
+        NoticeLevel::Progress => println!("   {}", message.bright_yellow()),
+        NoticeLevel::Info => println!("{}  {}", "ℹ️".bright_blue(), message),
+        NoticeLevel::Warning => println!("{}  {}", "⚠️".bright_yellow(), message),
+    })
+}
+
+/// Connect to the cluster, printing the outcome on the progress line.
+pub async fn connect(config_file: Option<&str>, access: &ClusterAccess) -> Result<K8sClient> {
+    print!("🔗 Connecting to cluster... ");
+    match K8sClient::new(config_file, access).await {
+        Ok(client) => {
+            println!("{}", "✅ Success".bright_green());
+            Ok(client)
+        }
+        Err(e) => {
+            println!("{}", "❌ Failed".bright_red());
+            eprintln!("Error: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// Read one answer from stdin after printing `prompt`.
+pub fn prompt_line(prompt: &str) -> Result<String> {
+    use std::io::Write;
+    print!("{}", prompt);
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(answer.trim().to_string())
+}
+
+/// List the checks that will be skipped or incomplete because of denied permissions.
+pub fn print_affected_checks(report: &preflight::PreflightReport) {
+    let affected = report.affected_checks();
+    if affected.is_empty() {
+        return;
+    }
+    println!(
+        "{}  Checks that will be skipped or incomplete:",
+        "⚠️".bright_yellow()
+    );
+    for (check, permissions) in affected {
+        println!("   {}: missing {}", check, permissions.join(", "));
+    }
+}
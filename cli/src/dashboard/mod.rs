@@ -0,0 +1,546 @@
+//! `kubeowler serve`: a small web dashboard over a directory of saved JSON reports (the run
+//! history, e.g. the report PVC of `deploy-cron`). Lists the runs, renders any run as the HTML
+//! report and charts the overall and per-module score trend.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, StatusCode};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use kubeowler_core::inspections::types::{ClusterReport, IssueSeverity};
+use kubeowler_core::reporting::branding::{escape_html, Branding};
+use kubeowler_core::reporting::generator::parse_check_level_filter;
+use kubeowler_core::reporting::read_report;
+use kubeowler_core::reporting::ReportGenerator;
+
+/// Default `--listen` address.
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:8080";
+
+/// Chart size in pixels.
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 120.0;
+
+/// Placeholder paragraph for a trend chart. comrak drops raw HTML, so the index Markdown carries
+/// the points and the SVG is inserted after the conversion.
+const CHART: &str = "KUBEOWLER-CHART:";
+
+/// Chart point of a run without that module (the module was skipped or did not exist yet).
+const GAP: &str = "-";
+
+/// Bytes escaped in a run's URL segment: everything but RFC 3986 unreserved characters, so file
+/// names with spaces or `()` survive both the Markdown link and the request path.
+const SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// One saved report in the history directory.
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// File name inside the history directory; also the run's URL segment.
+    pub file: String,
+    pub cluster_name: String,
+    pub timestamp: DateTime<Utc>,
+    pub overall_score: f64,
+    pub module_scores: BTreeMap<String, f64>,
+    pub critical: usize,
+    pub warning: usize,
+    /// API server p95 latency sampled by the Control Plane inspection.
+    pub api_p95_ms: Option<u64>,
+}
+
+impl Run {
+    fn from_report(file: String, report: &ClusterReport) -> Self {
+        let issues = || {
+            report
+                .inspections
+                .iter()
+                .flat_map(|r| r.summary.issues.iter())
+        };
+        Self {
+            file,
+            cluster_name: report.cluster_name.clone(),
+            timestamp: report.timestamp,
+            overall_score: report.overall_score,
+            module_scores: report
+                .inspections
+                .iter()
+                .map(|r| (r.inspection_type.clone(), r.overall_score))
+                .collect(),
+            critical: issues()
+                .filter(|i| i.severity == IssueSeverity::Critical)
+                .count(),
+            warning: issues()
+                .filter(|i| i.severity == IssueSeverity::Warning)
+                .count(),
+            api_p95_ms: report
+                .inspections
+                .iter()
+                .find_map(|r| r.api_latency.as_ref())
+                .map(|l| l.p95_ms),
+        }
+    }
+}
+
+/// Parsed files of the history directory by name, with the modification time they were read
+/// at; None for JSON that is not a report.
+type RunCache = HashMap<String, (SystemTime, Option<Run>)>;
+
+/// `*.json` files of `dir` that are kubeowler reports, oldest first. Other JSON is skipped.
+pub fn load_runs(dir: &Path) -> Result<Vec<Run>> {
+    refresh_runs(dir, &mut RunCache::new())
+}
+
+/// [`load_runs`] reading only the files `cache` does not hold at their current modification
+/// time; files gone from `dir` are dropped from it.
+fn refresh_runs(dir: &Path, cache: &mut RunCache) -> Result<Vec<Run>> {
+    let mut seen = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().is_none_or(|e| e != "json") {
+            continue;
+        }
+        let Some(file) = path.file_name().map(|f| f.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        let modified = entry.metadata()?.modified()?;
+        if cache.get(&file).is_none_or(|(at, _)| *at != modified) {
+            let run = match read_report(&path) {
+                Ok(report) => Some(Run::from_report(file.clone(), &report)),
+                Err(e) => {
+                    log::debug!("skipping {}: {}", path.display(), e);
+                    None
+                }
+            };
+            cache.insert(file.clone(), (modified, run));
+        }
+        seen.push(file);
+    }
+    cache.retain(|file, _| seen.contains(file));
+    let mut runs: Vec<Run> = cache.values().filter_map(|(_, run)| run.clone()).collect();
+    runs.sort_by_key(|r| r.timestamp);
+    Ok(runs)
+}
+
+/// URL of a run's report page.
+fn run_url(run: &Run) -> String {
+    format!("/runs/{}", utf8_percent_encode(&run.file, SEGMENT))
+}
+
+/// Inline SVG line chart of `points` (0–100) in run order. Runs without a value leave a gap,
+/// so every chart shares the run axis; a value between two gaps is drawn as a dot.
+fn trend_svg(points: &[Option<f64>]) -> String {
+    let step = if points.len() > 1 {
+        CHART_WIDTH / (points.len() - 1) as f64
+    } else {
+        0.0
+    };
+    let mut segments: Vec<Vec<String>> = vec![Vec::new()];
+    for (i, point) in points.iter().enumerate() {
+        match point {
+            Some(score) => {
+                let y = CHART_HEIGHT - score.clamp(0.0, 100.0) / 100.0 * CHART_HEIGHT;
+                if let Some(segment) = segments.last_mut() {
+                    segment.push(format!("{:.1},{:.1}", i as f64 * step, y));
+                }
+            }
+            None => segments.push(Vec::new()),
+        }
+    }
+    let lines: String = segments
+        .iter()
+        .map(|segment| match segment.as_slice() {
+            [] => String::new(),
+            [dot] => {
+                let (x, y) = dot.split_once(',').unwrap_or_default();
+                format!(r#"<circle cx="{}" cy="{}" r="2" fill="var(--color-accent)"/>"#, x, y)
+            }
+            _ => format!(
+                r#"<polyline fill="none" stroke="var(--color-accent)" stroke-width="2" points="{}"/>"#,
+                segment.join(" ")
+            ),
+        })
+        .collect();
+    format!(
+        r##"<svg width="{w}" height="{h}" viewBox="-4 -4 {vw} {vh}"><rect x="0" y="0" width="{w}" height="{h}" fill="none" stroke="#ccc"/>{lines}</svg>"##,
+        w = CHART_WIDTH,
+        h = CHART_HEIGHT,
+        vw = CHART_WIDTH + 8.0,
+        vh = CHART_HEIGHT + 8.0,
+        lines = lines
+    )
+}
+
+fn chart_marker(points: &[Option<f64>]) -> String {
+    let points: Vec<String> = points
+        .iter()
+        .map(|p| match p {
+            Some(p) => format!("{:.1}", p),
+            None => GAP.to_string(),
+        })
+        .collect();
+    format!("{} {}", CHART, points.join(" "))
+}
+
+/// `html` with every chart placeholder paragraph replaced by its inline SVG.
+fn insert_charts(html: &str) -> String {
+    let prefix = format!("<p>{} ", CHART);
+    html.lines()
+        .map(
+            |l| match l.strip_prefix(&prefix).and_then(|t| t.strip_suffix("</p>")) {
+                Some(points) => {
+                    let points: Vec<Option<f64>> =
+                        points.split_whitespace().map(|p| p.parse().ok()).collect();
+                    trend_svg(&points)
+                }
+                None => l.to_string(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Dashboard page: score trends and the run list, newest first.
+pub fn render_index(runs: &[Run]) -> String {
+    let mut md = String::from("# Kubeowler dashboard\n\n");
+    if runs.is_empty() {
+        md.push_str("No saved JSON reports yet. Write them with `kubeowler check --format json -o <history dir>/...`.\n");
+        return md;
+    }
+    let latest = &runs[runs.len() - 1];
+    md.push_str(&format!(
+        "Latest run: [{}]({}) — {} — score **{:.1}**\n\n",
+        latest.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+        run_url(latest),
+        escape_html(&latest.cluster_name),
+        latest.overall_score
+    ));
+
+    md.push_str("## Score trend\n\n### Overall\n\n");
+    let overall: Vec<Option<f64>> = runs.iter().map(|r| Some(r.overall_score)).collect();
+    md.push_str(&chart_marker(&overall));
+    md.push_str("\n\n");
+    let modules: std::collections::BTreeSet<&String> =
+        runs.iter().flat_map(|r| r.module_scores.keys()).collect();
+    for module in modules {
+        let points: Vec<Option<f64>> = runs
+            .iter()
+            .map(|r| r.module_scores.get(module).copied())
+            .collect();
+        md.push_str(&format!(
+            "### {}\n\n{}\n\n",
+            escape_html(module),
+            chart_marker(&points)
+        ));
+    }
+
+    md.push_str("## Runs\n\n| Time | Cluster | Score | Critical | Warning | API p95 |\n|------|---------|-------|----------|---------|---------|\n");
+    for run in runs.iter().rev() {
+        md.push_str(&format!(
+            "| [{}]({}) | {} | {:.1} | {} | {} | {} |\n",
+            run.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+            run_url(run),
+            escape_html(&run.cluster_name),
+            run.overall_score,
+            run.critical,
+            run.warning,
+            run.api_p95_ms
+                .map(|ms| format!("{} ms", ms))
+                .unwrap_or_else(|| "-".to_string())
+        ));
+    }
+    md
+}
+
+struct Dashboard {
+    dir: PathBuf,
+    /// Reports are parsed again only when their file changes, not on every request.
+    runs: Mutex<RunCache>,
+    branding: Branding,
+    generator: ReportGenerator,
+    level: String,
+}
+
+impl Dashboard {
+    fn page(&self, md: &str) -> Result<Response<Body>> {
+        let html = kubeowler_core::reporting::md_export::md_to_html(md, &self.branding)?;
+        Ok(Response::builder()
+            .header(hyper::header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(html))?)
+    }
+
+    fn index_page(&self, runs: &[Run]) -> Result<Response<Body>> {
+        let html =
+            kubeowler_core::reporting::md_export::md_to_html(&render_index(runs), &self.branding)?;
+        Ok(Response::builder()
+            .header(hyper::header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(insert_charts(&html)))?)
+    }
+
+    fn report_page(&self, file: &str) -> Result<Response<Body>> {
+        let report =
+            kubeowler_core::reporting::redact::redact_report(&read_report(&self.dir.join(file))?)?;
+        let md = self.generator.generate_markdown_string(
+            &report,
+            None,
+            None,
+            None,
+            Some(parse_check_level_filter(&self.level)),
+        )?;
+        self.page(&format!("[← All runs](/)\n\n{}", md))
+    }
+
+    fn handle(&self, path: &str) -> Result<Response<Body>> {
+        let runs = {
+            let mut cache = self
+                .runs
+                .lock()
+                .map_err(|_| anyhow::anyhow!("run cache poisoned"))?;
+            refresh_runs(&self.dir, &mut cache)?
+        };
+        let Ok(path) = percent_decode_str(path).decode_utf8() else {
+            return Ok(status(StatusCode::NOT_FOUND, "not found"));
+        };
+        match path.as_ref() {
+            "/" | "/index.html" => self.index_page(&runs),
+            "/latest" => match runs.last() {
+                Some(run) => self.report_page(&run.file),
+                None => Ok(status(StatusCode::NOT_FOUND, "no runs yet")),
+            },
+            _ => match path.strip_prefix("/runs/") {
+                // Only files listed as runs are served, so the path cannot leave the directory.
+                Some(file) if runs.iter().any(|r| r.file == file) => self.report_page(file),
+                _ => Ok(status(StatusCode::NOT_FOUND, "not found")),
+            },
+        }
+    }
+}
+
+fn status(code: StatusCode, message: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(message.to_string()));
+    *response.status_mut() = code;
+    response
+}
+
+/// Serve the dashboard for `dir` on `listen` until Ctrl-C.
+pub async fn serve(
+    dir: PathBuf,
+    listen: SocketAddr,
+    branding: Branding,
+    generator: ReportGenerator,
+    level: String,
+) -> Result<()> {
+    let mut runs = RunCache::new();
+    refresh_runs(&dir, &mut runs)?;
+    let dashboard = Arc::new(Dashboard {
+        dir,
+        runs: Mutex::new(runs),
+        branding,
+        generator,
+        level,
+    });
+    let make_service = make_service_fn(move |_| {
+        let dashboard = dashboard.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                let dashboard = dashboard.clone();
+                async move {
+                    let response = dashboard.handle(req.uri().path()).unwrap_or_else(|e| {
+                        log::warn!("{} failed: {:#}", req.uri().path(), e);
+                        status(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string())
+                    });
+                    Ok::<_, Infallible>(response)
+                }
+            }))
+        }
+    });
+    hyper::Server::try_bind(&listen)
+        .with_context(|| format!("binding {}", listen))?
+        .serve(make_service)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_report(dir: &Path, file: &str, timestamp: &str, score: f64) {
+        let report = serde_json::json!({
+            "cluster_name": "prod",
+            "report_id": file,
+            "timestamp": timestamp,
+            "overall_score": score,
+            "inspections": [{
+                "inspection_type": "Pod Status",
+                "timestamp": timestamp,
+                "overall_score": score,
+                "checks": [],
+                "summary": {
+                    "total_checks": 0, "passed_checks": 0, "warning_checks": 0,
+                    "critical_checks": 0, "error_checks": 0,
+                    "issues": [{
+                        "severity": "Critical", "category": "Pod",
+                        "description": "Pod x failed", "resource": "ns/x",
+                        "recommendation": "Check logs"
+                    }]
+                }
+            }],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        });
+        std::fs::write(dir.join(file), report.to_string()).unwrap();
+    }
+
+    #[test]
+    fn loads_runs_oldest_first_and_skips_other_json() {
+        let dir = tempfile::tempdir().unwrap();
+        write_report(dir.path(), "b.json", "2026-03-02T00:00:00Z", 90.0);
+        write_report(dir.path(), "a.json", "2026-03-01T00:00:00Z", 70.0);
+        std::fs::write(dir.path().join("baseline.json"), r#"{"entries": []}"#).unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "x").unwrap();
+
+        let runs = load_runs(dir.path()).unwrap();
+        let files: Vec<&str> = runs.iter().map(|r| r.file.as_str()).collect();
+        assert_eq!(files, vec!["a.json", "b.json"]);
+        assert_eq!(runs[0].critical, 1);
+        assert_eq!(runs[1].module_scores.get("Pod Status"), Some(&90.0));
+
+        let index = render_index(&runs);
+        assert!(index.contains("Latest run: [2026-03-02 00:00:00 UTC](/runs/b.json)"));
+        assert!(index.contains("### Pod Status"));
+        assert!(
+            index.find("/runs/b.json) | prod").unwrap()
+                < index.find("/runs/a.json) | prod").unwrap()
+        );
+    }
+
+    #[test]
+    fn trend_chart_scales_scores_to_height() {
+        let svg = trend_svg(&[Some(100.0), Some(50.0), Some(0.0)]);
+        assert!(svg.contains(r#"points="0.0,0.0 320.0,60.0 640.0,120.0""#));
+    }
+
+    #[test]
+    fn trend_chart_keeps_runs_without_the_module_as_gaps() {
+        let svg = trend_svg(&[Some(100.0), Some(50.0), None, Some(0.0), None]);
+        assert!(svg.contains(r#"points="0.0,0.0 160.0,60.0""#));
+        assert!(svg.contains(r#"<circle cx="480.0" cy="120.0""#));
+        assert_eq!(
+            chart_marker(&[Some(80.0), None]),
+            format!("{} 80.0 -", CHART)
+        );
+    }
+
+    #[test]
+    fn index_html_contains_trend_charts() {
+        let dir = tempfile::tempdir().unwrap();
+        write_report(dir.path(), "a.json", "2026-03-01T00:00:00Z", 70.0);
+        write_report(dir.path(), "b.json", "2026-03-02T00:00:00Z", 90.0);
+        let runs = load_runs(dir.path()).unwrap();
+        let html = kubeowler_core::reporting::md_export::md_to_html(
+            &render_index(&runs),
+            &Branding::default(),
+        )
+        .unwrap();
+        let html = insert_charts(&html);
+        assert!(html.contains("<svg"));
+        assert!(html.contains(r#"points="0.0,36.0 640.0,12.0""#));
+        assert!(!html.contains(CHART));
+    }
+
+    #[test]
+    fn serves_only_listed_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        write_report(dir.path(), "run.json", "2026-03-01T00:00:00Z", 80.0);
+        let dashboard = Dashboard {
+            dir: dir.path().to_path_buf(),
+            runs: Mutex::new(RunCache::new()),
+            branding: Branding::default(),
+            generator: ReportGenerator::new(),
+            level: "all".to_string(),
+        };
+        assert_eq!(dashboard.handle("/").unwrap().status(), StatusCode::OK);
+        assert_eq!(
+            dashboard.handle("/runs/run.json").unwrap().status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            dashboard.handle("/latest").unwrap().status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            dashboard.handle("/runs/../secret.json").unwrap().status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn encodes_run_links_and_decodes_request_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        write_report(dir.path(), "prod (1).json", "2026-03-01T00:00:00Z", 80.0);
+        let dashboard = Dashboard {
+            dir: dir.path().to_path_buf(),
+            runs: Mutex::new(RunCache::new()),
+            branding: Branding::default(),
+            generator: ReportGenerator::new(),
+            level: "all".to_string(),
+        };
+        let runs = load_runs(dir.path()).unwrap();
+        assert!(render_index(&runs).contains("](/runs/prod%20%281%29.json)"));
+        assert_eq!(
+            dashboard
+                .handle("/runs/prod%20%281%29.json")
+                .unwrap()
+                .status(),
+            StatusCode::OK
+        );
+    }
+
+    #[test]
+    fn rereads_only_changed_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        write_report(dir.path(), "a.json", "2026-03-01T00:00:00Z", 70.0);
+        write_report(dir.path(), "b.json", "2026-03-02T00:00:00Z", 90.0);
+        let mut cache = RunCache::new();
+        assert_eq!(refresh_runs(dir.path(), &mut cache).unwrap().len(), 2);
+
+        // A cached entry is served as long as the file's modification time is unchanged.
+        let modified = cache["a.json"].0;
+        if let Some((_, Some(run))) = cache.get_mut("a.json") {
+            run.overall_score = 10.0;
+        }
+        let runs = refresh_runs(dir.path(), &mut cache).unwrap();
+        assert_eq!(runs[0].overall_score, 10.0);
+
+        write_report(dir.path(), "a.json", "2026-03-01T00:00:00Z", 75.0);
+        std::fs::File::options()
+            .write(true)
+            .open(dir.path().join("a.json"))
+            .unwrap()
+            .set_modified(modified + std::time::Duration::from_secs(1))
+            .unwrap();
+        std::fs::remove_file(dir.path().join("b.json")).unwrap();
+        let runs = refresh_runs(dir.path(), &mut cache).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].overall_score, 75.0);
+        assert!(!cache.contains_key("b.json"));
+    }
+}
@@ -0,0 +1,248 @@
+//! Command-line flag defaults from the `defaults` section of the config file:
+//!
+//! ```yaml
+//! defaults:
+//!   global:          # flags every subcommand accepts (--context, --qps, ...)
+//!     context: prod
+//!   check:
+//!     format: html
+//!     level: all
+//!     max-issues-per-rule: 20
+//!   node-inspector install:   # nested subcommands: names joined by a space
+//!     namespace: ops
+//! ```
+//!
+//! Keys are long flag names without `--`. A flag given on the command line or through its
+//! `KUBEOWLER_*` environment variable wins over the file.
+
+use std::ffi::OsString;
+
+use anyhow::{anyhow, bail, Result};
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, Command};
+use serde_yaml::Value;
+
+/// Section applied to every subcommand.
+const GLOBAL_SECTION: &str = "global";
+
+fn scalar(key: &str, value: &Value) -> Result<String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        _ => bail!("config file: `{}` must be a string, number or boolean", key),
+    }
+}
+
+/// Command-line tokens that set `arg` to `value`.
+fn flag_tokens(arg: &Arg, key: &str, value: &Value) -> Result<Vec<String>> {
+    let flag = format!("--{}", key);
+    match arg.get_action() {
+        ArgAction::SetTrue => match value {
+            Value::Bool(true) => Ok(vec![flag]),
+            Value::Bool(false) => Ok(Vec::new()),
+            _ => bail!("config file: `{}` is a switch; use true or false", key),
+        },
+        ArgAction::SetFalse => match value {
+            Value::Bool(false) => Ok(vec![flag]),
+            Value::Bool(true) => Ok(Vec::new()),
+            _ => bail!("config file: `{}` is a switch; use true or false", key),
+        },
+        ArgAction::Append => match value {
+            Value::Sequence(items) => items
+                .iter()
+                .map(|v| Ok(format!("{}={}", flag, scalar(key, v)?)))
+                .collect(),
+            v => Ok(vec![format!("{}={}", flag, scalar(key, v)?)]),
+        },
+        _ => match value {
+            Value::Sequence(items) => {
+                let items = items
+                    .iter()
+                    .map(|v| scalar(key, v))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(vec![format!("{}={}", flag, items.join(","))])
+            }
+            v => Ok(vec![format!("{}={}", flag, scalar(key, v)?)]),
+        },
+    }
+}
+
+/// `argv` with the config file's flag defaults for the invoked subcommand inserted right after
+/// its name (ahead of any `--` or trailing positionals), skipping flags already given on the
+/// command line or in the environment. The file is `--config` of the
+/// subcommand when it has one, else the default path. Arguments clap cannot make sense of (including `--help`) are returned
+/// unchanged for the real parse to report.
+pub fn args_with_defaults(command: &Command, argv: Vec<OsString>) -> Result<Vec<OsString>> {
+    let mut command = command.clone().ignore_errors(true);
+    command.build();
+    let Ok(matches) = command.clone().try_get_matches_from(&argv) else {
+        return Ok(argv);
+    };
+
+    let mut path = Vec::new();
+    let mut leaf = &command;
+    let mut leaf_matches = &matches;
+    while let Some((name, sub_matches)) = leaf_matches.subcommand() {
+        let Some(sub) = leaf.find_subcommand(name) else {
+            break;
+        };
+        path.push(name.to_string());
+        leaf = sub;
+        leaf_matches = sub_matches;
+    }
+    if path.is_empty() {
+        return Ok(argv);
+    }
+
+    let config_path = leaf
+        .get_arguments()
+        .any(|a| a.get_id() == "config")
+        .then(|| leaf_matches.get_one::<String>("config").cloned())
+        .flatten();
+    let config = kubeowler_core::config::load(config_path.as_deref())?;
+    let section = path.join(" ");
+
+    let mut extra = Vec::new();
+    for name in [GLOBAL_SECTION, section.as_str()] {
+        let Some(values) = config.defaults.get(name) else {
+            continue;
+        };
+        for (key, value) in values {
+            let arg = leaf
+                .get_arguments()
+                .find(|a| a.get_long() == Some(key.as_str()))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "config file: `defaults.{}.{}` is not a flag of `kubeowler {}`",
+                        name,
+                        key,
+                        section
+                    )
+                })?;
+            let id = arg.get_id().as_str();
+            if matches!(
+                leaf_matches.value_source(id),
+                Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+            ) {
+                continue;
+            }
+            extra.extend(flag_tokens(arg, key, value)?);
+        }
+    }
+    let at = subcommand_end(&argv, &path);
+    let mut argv = argv;
+    argv.splice(at..at, extra.into_iter().map(OsString::from));
+    Ok(argv)
+}
+
+/// Index in `argv` just past the last name of the subcommand `path`, found in order before any
+/// `--`; the end of `argv` if a name is missing.
+fn subcommand_end(argv: &[OsString], path: &[String]) -> usize {
+    let mut names = path.iter().peekable();
+    for (i, token) in argv.iter().enumerate().skip(1) {
+        if token == "--" {
+            break;
+        }
+        let Some(name) = names.peek() else {
+            break;
+        };
+        if token.to_str() == Some(name.as_str()) {
+            names.next();
+            if names.peek().is_none() {
+                return i + 1;
+            }
+        }
+    }
+    argv.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::{Args, Commands, ReportFormat};
+    use clap::{CommandFactory, Parser};
+
+    fn parse(config: &str, args: &[&str]) -> Result<Args> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, config).unwrap();
+        let mut argv: Vec<OsString> = args.iter().map(OsString::from).collect();
+        argv.extend(["--config".into(), path.clone().into_os_string()]);
+        let argv = args_with_defaults(&Args::command(), argv)?;
+        Ok(Args::try_parse_from(argv)?)
+    }
+
+    #[test]
+    fn fills_flags_not_given_on_the_command_line() {
+        let config = "defaults:\n  global:\n    context: prod\n  check:\n    format: html\n    level: [warning, critical]\n    per-pod-issues: true\n    max-issues-per-rule: 7\n    create-issues: [github:o/r, jira:OPS]\n";
+        let args = parse(config, &["kubeowler", "check", "-f", "json"]).unwrap();
+        assert_eq!(args.access.context.as_deref(), Some("prod"));
+        let Commands::Check {
+            format,
+            level,
+            per_pod_issues,
+            max_issues_per_rule,
+            create_issues,
+            ..
+        } = args.command
+        else {
+            panic!("not check");
+        };
+        assert!(matches!(format, ReportFormat::Json));
+        assert_eq!(level, "warning,critical");
+        assert!(per_pod_issues);
+        assert_eq!(max_issues_per_rule, 7);
+        assert_eq!(create_issues, ["github:o/r", "jira:OPS"]);
+
+        let Err(err) = parse(
+            "defaults:\n  check:\n    fromat: html\n",
+            &["kubeowler", "check"],
+        ) else {
+            panic!("unknown flag accepted");
+        };
+        let err = err.to_string();
+        assert!(err.contains("defaults.check.fromat"), "{}", err);
+    }
+
+    #[test]
+    fn inserts_defaults_before_trailing_args() {
+        // Shaped like `deploy-cron`, whose job arguments follow `--`; with a `--config` flag.
+        let command = Command::new("kubeowler").subcommand(
+            Command::new("deploy-cron")
+                .arg(Arg::new("config").long("config"))
+                .arg(
+                    Arg::new("schedule")
+                        .long("schedule")
+                        .default_value("0 6 * * *"),
+                )
+                .arg(
+                    Arg::new("dry_run")
+                        .long("dry-run")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(Arg::new("check_args").last(true).num_args(0..)),
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(
+            &path,
+            "defaults:\n  deploy-cron:\n    schedule: \"0 1 * * *\"\n",
+        )
+        .unwrap();
+        let argv: Vec<OsString> = ["kubeowler", "deploy-cron", "--dry-run", "--config"]
+            .iter()
+            .map(OsString::from)
+            .chain([path.into_os_string()])
+            .chain(["--", "-f", "html"].iter().map(OsString::from))
+            .collect();
+        let argv = args_with_defaults(&command, argv).unwrap();
+        assert_eq!(argv[2], "--schedule=0 1 * * *");
+
+        let matches = command.get_matches_from(argv);
+        let (_, sub) = matches.subcommand().unwrap();
+        assert_eq!(sub.get_one::<String>("schedule").unwrap(), "0 1 * * *");
+        let check_args: Vec<&String> = sub.get_many("check_args").unwrap().collect();
+        assert_eq!(check_args, ["-f", "html"]);
+    }
+}
@@ -0,0 +1,219 @@
+//! Console side of `deploy-cron`, `undeploy` and `node-inspector`: connect, apply or remove the
+//! manifests and report progress.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::cli::ClusterAccess;
+use kubeowler_core::k8s::K8sClient;
+
+use crate::args::{NodeInspectorAction, NodeInspectorDeployArgs};
+use crate::console::connect;
+use crate::deploy;
+
+/// `deploy-cron`: print the manifests with `dry_run`, else apply them.
+pub async fn deploy_cron(
+    config: &deploy::cron::CronDeployConfig,
+    dry_run: bool,
+    config_file: Option<String>,
+    access: &ClusterAccess,
+) -> Result<()> {
+    let manifests = deploy::cron::render_cron_manifests(config)?;
+    if dry_run {
+        print!("{}", deploy::render_yaml(&manifests)?);
+        return Ok(());
+    }
+    let client = connect(config_file.as_deref(), access).await?;
+    print!("🚀 Applying kubeowler CronJob to {}... ", config.namespace);
+    match deploy::apply_manifests(&client, &manifests).await {
+        Ok(applied) => {
+            println!("{}", "✅ Done".bright_green());
+            for a in applied {
+                println!("   applied {}", a);
+            }
+            println!(
+                "   Schedule: {}  Reports: {}",
+                config.schedule.bright_cyan(),
+                config
+                    .output_pvc
+                    .as_deref()
+                    .map(|p| format!("PVC {} ({})", p, deploy::cron::REPORTS_DIR))
+                    .unwrap_or_else(|| "pod-local (add --output-pvc to keep them)".to_string())
+            );
+            Ok(())
+        }
+        Err(e) => {
+            println!("{}", "❌ Failed".bright_red());
+            Err(e)
+        }
+    }
+}
+
+/// `undeploy`: remove what `deploy-cron` applied.
+pub async fn undeploy(
+    namespace: &str,
+    node_inspector_namespace: &str,
+    delete_pvc: bool,
+    config_file: Option<&str>,
+    access: &ClusterAccess,
+) -> Result<()> {
+    let client = connect(config_file, access).await?;
+    print!("🧹 Removing kubeowler CronJob from {}... ", namespace);
+    match deploy::cron::undeploy_cron(&client, namespace, node_inspector_namespace, delete_pvc)
+        .await
+    {
+        Ok(deleted) if deleted.is_empty() => println!("{}", "nothing to remove".bright_yellow()),
+        Ok(deleted) => {
+            println!("{}", "✅ Done".bright_green());
+            for d in deleted {
+                println!("   deleted {}", d);
+            }
+        }
+        Err(e) => {
+            println!("{}", "❌ Failed".bright_red());
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// `node-inspector install|upgrade|uninstall|status`.
+pub async fn node_inspector(action: NodeInspectorAction, access: &ClusterAccess) -> Result<()> {
+    use deploy::node_inspector::{self as ni, RolloutStatus};
+
+    match action {
+        NodeInspectorAction::Install { deploy: args }
+        | NodeInspectorAction::Upgrade { deploy: args }
+            if args.dry_run =>
+        {
+            let manifests = ni::render_node_inspector_manifests(&node_inspector_config(&args))?;
+            print!("{}", deploy::render_yaml(&manifests)?);
+        }
+        NodeInspectorAction::Install { deploy: args } => {
+            let client = connect(args.config_file.as_deref(), access).await?;
+            if let Some(ds) = ni::get_daemon_set(&client, &args.namespace).await? {
+                let status = RolloutStatus::from_daemon_set(&ds);
+                anyhow::bail!(
+                    "node inspector already installed in '{}' (image {}); use `kubeowler node-inspector upgrade`",
+                    args.namespace,
+                    status.image.as_deref().unwrap_or("unknown")
+                );
+            }
+            apply_node_inspector(&client, &args).await?;
+        }
+        NodeInspectorAction::Upgrade { deploy: args } => {
+            let client = connect(args.config_file.as_deref(), access).await?;
+            if ni::get_daemon_set(&client, &args.namespace)
+                .await?
+                .is_none()
+            {
+                anyhow::bail!(
+                    "node inspector is not installed in '{}'; use `kubeowler node-inspector install`",
+                    args.namespace
+                );
+            }
+            apply_node_inspector(&client, &args).await?;
+        }
+        NodeInspectorAction::Uninstall {
+            namespace,
+            delete_namespace,
+            config_file,
+        } => {
+            let client = connect(config_file.as_deref(), access).await?;
+            print!("🧹 Removing node inspector from {}... ", namespace);
+            if ni::uninstall(&client, &namespace, delete_namespace).await? {
+                println!("{}", "✅ Done".bright_green());
+            } else {
+                println!("{}", "not installed".bright_yellow());
+            }
+        }
+        NodeInspectorAction::Status {
+            namespace,
+            config_file,
+        } => {
+            let client = connect(config_file.as_deref(), access).await?;
+            let Some(ds) = ni::get_daemon_set(&client, &namespace).await? else {
+                println!(
+                    "{}  Node inspector is not installed in namespace '{}'.",
+                    "ℹ️".bright_blue(),
+                    namespace
+                );
+                return Ok(());
+            };
+            let status = RolloutStatus::from_daemon_set(&ds);
+            println!(
+                "📦 Node inspector in {}: {}",
+                namespace.bright_green(),
+                if status.is_complete() {
+                    "rolled out".bright_green()
+                } else {
+                    "rollout in progress".bright_yellow()
+                }
+            );
+            print_rollout_status(&status);
+            for (pod, node, reason) in ni::unhealthy_pods(&client, &namespace).await? {
+                println!("   {} {} on {}: {}", "⚠️".yellow(), pod, node, reason);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn node_inspector_config(
+    args: &NodeInspectorDeployArgs,
+) -> deploy::node_inspector::NodeInspectorConfig {
+    deploy::node_inspector::NodeInspectorConfig {
+        namespace: args.namespace.clone(),
+        image: args.image.clone(),
+        tolerations: args.tolerations.clone(),
+        cpu_request: args.cpu_request.clone(),
+        memory_request: args.memory_request.clone(),
+        cpu_limit: args.cpu_limit.clone(),
+        memory_limit: args.memory_limit.clone(),
+    }
+}
+
+fn print_rollout_status(status: &deploy::node_inspector::RolloutStatus) {
+    println!(
+        "   Image: {}\n   Desired: {}  Updated: {}  Ready: {}  Available: {}",
+        status.image.as_deref().unwrap_or("-").bright_cyan(),
+        status.desired,
+        status.updated,
+        status.ready,
+        status.available
+    );
+}
+
+async fn apply_node_inspector(client: &K8sClient, args: &NodeInspectorDeployArgs) -> Result<()> {
+    use deploy::node_inspector as ni;
+
+    let manifests = ni::render_node_inspector_manifests(&node_inspector_config(args))?;
+    print!("🚀 Applying node inspector to {}... ", args.namespace);
+    if let Err(e) = deploy::apply_manifests(client, &manifests).await {
+        println!("{}", "❌ Failed".bright_red());
+        return Err(e);
+    }
+    println!("{}", "✅ Done".bright_green());
+    if args.timeout == 0 {
+        return Ok(());
+    }
+    print!("⏳ Waiting for rollout (up to {}s)... ", args.timeout);
+    let status = ni::wait_for_rollout(
+        client,
+        &args.namespace,
+        std::time::Duration::from_secs(args.timeout),
+    )
+    .await?;
+    if status.is_complete() {
+        println!("{}", "✅ Rolled out".bright_green());
+    } else {
+        println!("{}", "⚠️ Timed out".bright_yellow());
+    }
+    print_rollout_status(&status);
+    if !status.is_complete() {
+        for (pod, node, reason) in ni::unhealthy_pods(client, &args.namespace).await? {
+            println!("   {} {} on {}: {}", "⚠️".yellow(), pod, node, reason);
+        }
+    }
+    Ok(())
+}
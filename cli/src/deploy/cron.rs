@@ -0,0 +1,304 @@
+//! Manifests that run `kubeowler check` on a schedule inside the cluster: ServiceAccount,
+//! read-only ClusterRole and binding, a Role for restarting the node inspector, an optional
+//! report PVC and the CronJob.
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+
+use super::{delete_labelled, COMPONENT_LABEL, MANAGED_BY_LABEL};
+use kubeowler_core::k8s::K8sClient;
+
+pub const COMPONENT: &str = "cron";
+pub const SERVICE_ACCOUNT: &str = "kubeowler";
+pub const CLUSTER_ROLE: &str = "kubeowler-reader";
+pub const CRON_JOB: &str = "kubeowler-check";
+pub const NODE_INSPECTOR_ROLE: &str = "kubeowler-node-inspector-restart";
+/// Directory the report is written to inside the job container.
+pub const REPORTS_DIR: &str = "/reports";
+/// Default container image: the release matching this binary.
+pub const DEFAULT_IMAGE: &str = concat!("ghostwritten/kubeowler:v", env!("CARGO_PKG_VERSION"));
+pub const DEFAULT_SCHEDULE: &str = "0 6 * * *";
+
+/// Component label of a report PVC created by `deploy-cron`; kept by `undeploy` by default.
+pub const REPORTS_COMPONENT: &str = "cron-reports";
+
+#[derive(Debug, Clone)]
+pub struct CronDeployConfig {
+    pub namespace: String,
+    pub schedule: String,
+    pub image: String,
+    /// PVC mounted at [`REPORTS_DIR`]; without it reports go to an emptyDir and only the
+    /// job log remains.
+    pub output_pvc: Option<String>,
+    /// Create the PVC with this size; when None the PVC must already exist.
+    pub pvc_size: Option<String>,
+    pub storage_class: Option<String>,
+    /// Secret exposed to the job as environment variables (e.g. upload credentials).
+    pub upload_secret: Option<String>,
+    pub node_inspector_namespace: String,
+    /// Arguments after `check` (format, level, ...).
+    pub check_args: Vec<String>,
+}
+
+fn labels_for(component: &str) -> Value {
+    json!({ MANAGED_BY_LABEL: "kubeowler", COMPONENT_LABEL: component })
+}
+
+fn labels() -> Value {
+    labels_for(COMPONENT)
+}
+
+fn selector(component: &str) -> String {
+    format!(
+        "{}=kubeowler,{}={}",
+        MANAGED_BY_LABEL, COMPONENT_LABEL, component
+    )
+}
+
+/// Read-only rules needed by `kubeowler check` (kept in sync with docs/docker-and-kubernetes.md).
+pub fn reader_rules() -> Value {
+    json!([
+        { "apiGroups": [""], "resources": ["nodes", "pods", "services", "namespaces", "persistentvolumes", "persistentvolumeclaims", "configmaps", "secrets", "serviceaccounts", "events", "resourcequotas", "limitranges", "componentstatuses"], "verbs": ["get", "list"] },
+        { "apiGroups": [""], "resources": ["nodes/proxy", "pods/log", "pods/proxy"], "verbs": ["get"] },
+        { "apiGroups": ["apps"], "resources": ["deployments", "replicasets", "daemonsets", "statefulsets"], "verbs": ["get", "list"] },
+        { "apiGroups": ["rbac.authorization.k8s.io"], "resources": ["roles", "rolebindings", "clusterroles", "clusterrolebindings"], "verbs": ["get", "list"] },
+        { "apiGroups": ["networking.k8s.io"], "resources": ["networkpolicies", "ingresses", "ingressclasses"], "verbs": ["get", "list"] },
+        { "apiGroups": ["batch"], "resources": ["jobs", "cronjobs"], "verbs": ["get", "list"] },
+        { "apiGroups": ["storage.k8s.io"], "resources": ["storageclasses", "csidrivers"], "verbs": ["get", "list"] },
+        { "apiGroups": ["coordination.k8s.io"], "resources": ["leases"], "verbs": ["get", "list"] },
+        { "apiGroups": ["scheduling.k8s.io"], "resources": ["priorityclasses"], "verbs": ["get", "list"] },
+        { "apiGroups": ["autoscaling"], "resources": ["horizontalpodautoscalers"], "verbs": ["get", "list"] },
+        { "apiGroups": ["autoscaling.k8s.io"], "resources": ["verticalpodautoscalers"], "verbs": ["get", "list"] },
+        { "apiGroups": ["policy"], "resources": ["poddisruptionbudgets"], "verbs": ["get", "list"] },
+        { "apiGroups": ["certificates.k8s.io"], "resources": ["certificatesigningrequests"], "verbs": ["get", "list"] },
+        { "apiGroups": ["apiextensions.k8s.io"], "resources": ["customresourcedefinitions"], "verbs": ["get", "list"] },
+        { "apiGroups": ["metrics.k8s.io"], "resources": ["nodes", "pods"], "verbs": ["get", "list"] },
+        { "nonResourceURLs": ["/metrics", "/version", "/healthz", "/livez", "/readyz"], "verbs": ["get"] }
+    ])
+}
+
+/// Manifests in apply order.
+pub fn render_cron_manifests(cfg: &CronDeployConfig) -> Result<Vec<Value>> {
+    if cfg.schedule.split_whitespace().count() != 5 && !cfg.schedule.starts_with('@') {
+        bail!(
+            "invalid schedule '{}': expected 5 cron fields (e.g. \"0 6 * * *\") or @daily/@hourly",
+            cfg.schedule
+        );
+    }
+    let ns = cfg.namespace.as_str();
+    let mut out = vec![
+        json!({ "apiVersion": "v1", "kind": "Namespace", "metadata": { "name": ns } }),
+        json!({
+            "apiVersion": "v1", "kind": "ServiceAccount",
+            "metadata": { "name": SERVICE_ACCOUNT, "namespace": ns, "labels": labels() }
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "ClusterRole",
+            "metadata": { "name": CLUSTER_ROLE, "labels": labels() },
+            "rules": reader_rules()
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "ClusterRoleBinding",
+            "metadata": { "name": CLUSTER_ROLE, "labels": labels() },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": CLUSTER_ROLE },
+            "subjects": [{ "kind": "ServiceAccount", "name": SERVICE_ACCOUNT, "namespace": ns }]
+        }),
+        // Stale node inspector data triggers a DaemonSet restart (patch) in its namespace.
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "Role",
+            "metadata": { "name": NODE_INSPECTOR_ROLE, "namespace": cfg.node_inspector_namespace, "labels": labels() },
+            "rules": [{ "apiGroups": ["apps"], "resources": ["daemonsets"], "verbs": ["get", "patch"] }]
+        }),
+        json!({
+            "apiVersion": "rbac.authorization.k8s.io/v1", "kind": "RoleBinding",
+            "metadata": { "name": NODE_INSPECTOR_ROLE, "namespace": cfg.node_inspector_namespace, "labels": labels() },
+            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": NODE_INSPECTOR_ROLE },
+            "subjects": [{ "kind": "ServiceAccount", "name": SERVICE_ACCOUNT, "namespace": ns }]
+        }),
+    ];
+
+    if let (Some(pvc), Some(size)) = (cfg.output_pvc.as_deref(), cfg.pvc_size.as_deref()) {
+        let mut spec = json!({
+            "accessModes": ["ReadWriteOnce"],
+            "resources": { "requests": { "storage": size } }
+        });
+        if let Some(sc) = cfg.storage_class.as_deref() {
+            spec["storageClassName"] = json!(sc);
+        }
+        out.push(json!({
+            "apiVersion": "v1", "kind": "PersistentVolumeClaim",
+            "metadata": { "name": pvc, "namespace": ns, "labels": labels_for(REPORTS_COMPONENT) },
+            "spec": spec
+        }));
+    }
+
+    let reports_volume = match cfg.output_pvc.as_deref() {
+        Some(pvc) => json!({ "name": "reports", "persistentVolumeClaim": { "claimName": pvc } }),
+        None => json!({ "name": "reports", "emptyDir": {} }),
+    };
+    let mut args = vec![
+        "check".to_string(),
+        "--node-inspector-namespace".to_string(),
+        cfg.node_inspector_namespace.clone(),
+    ];
+    args.extend(cfg.check_args.iter().cloned());
+    let mut container = json!({
+        "name": "kubeowler",
+        "image": cfg.image,
+        "args": args,
+        // Reports use the default file name, written to the working directory.
+        "workingDir": REPORTS_DIR,
+        "volumeMounts": [{ "name": "reports", "mountPath": REPORTS_DIR }],
+        "resources": {
+            "requests": { "cpu": "100m", "memory": "128Mi" },
+            "limits": { "memory": "512Mi" }
+        },
+        "securityContext": {
+            "allowPrivilegeEscalation": false,
+            "readOnlyRootFilesystem": true,
+            "capabilities": { "drop": ["ALL"] }
+        }
+    });
+    if let Some(secret) = cfg.upload_secret.as_deref() {
+        container["envFrom"] = json!([{ "secretRef": { "name": secret } }]);
+    }
+    out.push(json!({
+        "apiVersion": "batch/v1", "kind": "CronJob",
+        "metadata": { "name": CRON_JOB, "namespace": ns, "labels": labels() },
+        "spec": {
+            "schedule": cfg.schedule,
+            "concurrencyPolicy": "Forbid",
+            "successfulJobsHistoryLimit": 3,
+            "failedJobsHistoryLimit": 3,
+            "jobTemplate": {
+                "spec": {
+                    "backoffLimit": 1,
+                    "template": {
+                        "metadata": { "labels": labels() },
+                        "spec": {
+                            "serviceAccountName": SERVICE_ACCOUNT,
+                            "restartPolicy": "Never",
+                            "securityContext": {
+                                "runAsNonRoot": true,
+                                "runAsUser": 65532,
+                                "fsGroup": 65532,
+                                "seccompProfile": { "type": "RuntimeDefault" }
+                            },
+                            "containers": [container],
+                            "volumes": [reports_volume]
+                        }
+                    }
+                }
+            }
+        }
+    }));
+    Ok(out)
+}
+
+/// Remove what `deploy-cron` created; the report PVC only when `delete_pvc` is set.
+/// The namespace itself is kept (it may hold the node inspector).
+pub async fn undeploy_cron(
+    client: &K8sClient,
+    namespace: &str,
+    node_inspector_namespace: &str,
+    delete_pvc: bool,
+) -> Result<Vec<String>> {
+    let rbac = "rbac.authorization.k8s.io/v1";
+    let mut deleted = delete_labelled(
+        client,
+        &[
+            ("batch/v1", "CronJob"),
+            (rbac, "ClusterRoleBinding"),
+            (rbac, "ClusterRole"),
+            ("v1", "ServiceAccount"),
+        ],
+        namespace,
+        &selector(COMPONENT),
+    )
+    .await?;
+    deleted.extend(
+        delete_labelled(
+            client,
+            &[(rbac, "RoleBinding"), (rbac, "Role")],
+            node_inspector_namespace,
+            &selector(COMPONENT),
+        )
+        .await?,
+    );
+    if delete_pvc {
+        deleted.extend(
+            delete_labelled(
+                client,
+                &[("v1", "PersistentVolumeClaim")],
+                namespace,
+                &selector(REPORTS_COMPONENT),
+            )
+            .await?,
+        );
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CronDeployConfig {
+        CronDeployConfig {
+            namespace: "kubeowler".to_string(),
+            schedule: DEFAULT_SCHEDULE.to_string(),
+            image: DEFAULT_IMAGE.to_string(),
+            output_pvc: Some("reports".to_string()),
+            pvc_size: Some("2Gi".to_string()),
+            storage_class: None,
+            upload_secret: Some("report-upload".to_string()),
+            node_inspector_namespace: "kubeowler".to_string(),
+            check_args: vec!["-f".to_string(), "html".to_string()],
+        }
+    }
+
+    #[test]
+    fn renders_cronjob_with_pvc_and_secret() {
+        let manifests = render_cron_manifests(&config()).unwrap();
+        let kinds: Vec<_> = manifests
+            .iter()
+            .map(|m| m["kind"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "Namespace",
+                "ServiceAccount",
+                "ClusterRole",
+                "ClusterRoleBinding",
+                "Role",
+                "RoleBinding",
+                "PersistentVolumeClaim",
+                "CronJob"
+            ]
+        );
+        let cron = manifests.last().unwrap();
+        let pod = &cron["spec"]["jobTemplate"]["spec"]["template"]["spec"];
+        assert_eq!(
+            pod["volumes"][0]["persistentVolumeClaim"]["claimName"],
+            "reports"
+        );
+        assert_eq!(pod["containers"][0]["args"][4], "html");
+        assert_eq!(
+            pod["containers"][0]["envFrom"][0]["secretRef"]["name"],
+            "report-upload"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schedule_and_skips_pvc_without_size() {
+        let mut cfg = config();
+        cfg.pvc_size = None;
+        let manifests = render_cron_manifests(&cfg).unwrap();
+        assert!(!manifests
+            .iter()
+            .any(|m| m["kind"] == "PersistentVolumeClaim"));
+        cfg.schedule = "every day".to_string();
+        assert!(render_cron_manifests(&cfg).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+//! In-cluster deployment of kubeowler: manifests are rendered as JSON values and applied with
+//! server-side apply under the `kubeowler` field manager, so re-running a deploy command
+//! updates the objects in place.
+
+pub mod command;
+pub mod cron;
+pub mod node_inspector;
+
+use anyhow::{anyhow, bail, Context, Result};
+use kube::api::{
+    Api, ApiResource, DeleteParams, DynamicObject, GroupVersionKind, ListParams, Patch, PatchParams,
+};
+
+use kubeowler_core::k8s::K8sClient;
+
+/// Field manager used for server-side apply.
+pub const FIELD_MANAGER: &str = "kubeowler";
+/// Label set on every object kubeowler deploys (except namespaces), used by the undeploy commands.
+pub const MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by";
+/// Label naming the deployed component (`cron`, `node-inspector`).
+pub const COMPONENT_LABEL: &str = "app.kubernetes.io/component";
+
+/// Plural resource name and whether the kind is namespaced, for the kinds kubeowler deploys.
+fn resource_of(kind: &str) -> Result<(&'static str, bool)> {
+    Ok(match kind {
+        "Namespace" => ("namespaces", false),
+        "ServiceAccount" => ("serviceaccounts", true),
+        "PersistentVolumeClaim" => ("persistentvolumeclaims", true),
+        "ConfigMap" => ("configmaps", true),
+        "Secret" => ("secrets", true),
+        "ClusterRole" => ("clusterroles", false),
+        "ClusterRoleBinding" => ("clusterrolebindings", false),
+        "Role" => ("roles", true),
+        "RoleBinding" => ("rolebindings", true),
+        "CronJob" => ("cronjobs", true),
+        "DaemonSet" => ("daemonsets", true),
+        _ => bail!("unsupported kind for deployment: {}", kind),
+    })
+}
+
+fn dynamic_api(
+    client: &K8sClient,
+    api_version: &str,
+    kind: &str,
+    namespace: Option<&str>,
+) -> Result<Api<DynamicObject>> {
+    let (group, version) = match api_version.split_once('/') {
+        Some((g, v)) => (g, v),
+        None => ("", api_version),
+    };
+    let gvk = GroupVersionKind::gvk(group, version, kind);
+    let (plural, namespaced) = resource_of(kind)?;
+    let ar = ApiResource::from_gvk_with_plural(&gvk, plural);
+    Ok(match (namespaced, namespace) {
+        (true, Some(ns)) => Api::namespaced_with(client.client().clone(), ns, &ar),
+        (true, None) => bail!("{} requires a namespace", kind),
+        (false, _) => Api::all_with(client.client().clone(), &ar),
+    })
+}
+
+/// `Kind/name` (or `Kind namespace/name`) label of a manifest.
+pub fn manifest_label(manifest: &serde_json::Value) -> String {
+    let kind = manifest["kind"].as_str().unwrap_or("?");
+    let name = manifest["metadata"]["name"].as_str().unwrap_or("?");
+    match manifest["metadata"]["namespace"].as_str() {
+        Some(ns) => format!("{} {}/{}", kind, ns, name),
+        None => format!("{} {}", kind, name),
+    }
+}
+
+/// Apply manifests in order with server-side apply; returns the labels of the applied objects.
+pub async fn apply_manifests(
+    client: &K8sClient,
+    manifests: &[serde_json::Value],
+) -> Result<Vec<String>> {
+    let params = PatchParams::apply(FIELD_MANAGER).force();
+    let mut applied = Vec::new();
+    for m in manifests {
+        let api_version = m["apiVersion"]
+            .as_str()
+            .ok_or_else(|| anyhow!("manifest without apiVersion"))?;
+        let kind = m["kind"]
+            .as_str()
+            .ok_or_else(|| anyhow!("manifest without kind"))?;
+        let name = m["metadata"]["name"]
+            .as_str()
+            .ok_or_else(|| anyhow!("{} manifest without name", kind))?;
+        let api = dynamic_api(
+            client,
+            api_version,
+            kind,
+            m["metadata"]["namespace"].as_str(),
+        )?;
+        let label = manifest_label(m);
+        api.patch(name, &params, &Patch::Apply(m))
+            .await
+            .with_context(|| format!("applying {}", label))?;
+        applied.push(label);
+    }
+    Ok(applied)
+}
+
+/// Delete every object of the given `(apiVersion, kind)` types that carries `selector`;
+/// namespaced kinds are looked up in `namespace`. Returns the labels of the deleted objects.
+pub async fn delete_labelled(
+    client: &K8sClient,
+    kinds: &[(&str, &str)],
+    namespace: &str,
+    selector: &str,
+) -> Result<Vec<String>> {
+    let mut deleted = Vec::new();
+    for (api_version, kind) in kinds {
+        let api = dynamic_api(client, api_version, kind, Some(namespace))?;
+        let list = api
+            .list(&ListParams::default().labels(selector))
+            .await
+            .with_context(|| format!("listing {} objects", kind))?;
+        for obj in list.items {
+            let Some(name) = obj.metadata.name.as_deref() else {
+                continue;
+            };
+            api.delete(name, &DeleteParams::background())
+                .await
+                .with_context(|| format!("deleting {} {}", kind, name))?;
+            deleted.push(match obj.metadata.namespace.as_deref() {
+                Some(ns) => format!("{} {}/{}", kind, ns, name),
+                None => format!("{} {}", kind, name),
+            });
+        }
+    }
+    Ok(deleted)
+}
+
+/// Multi-document YAML of the manifests (for `--dry-run`).
+pub fn render_yaml(manifests: &[serde_json::Value]) -> Result<String> {
+    let mut out = String::new();
+    for m in manifests {
+        out.push_str("---\n");
+        out.push_str(&serde_yaml::to_string(m)?);
+    }
+    Ok(out)
+}
@@ -0,0 +1,317 @@
+//! Lifecycle of the kubeowler-node-inspector DaemonSet: manifests (same shape as
+//! `deploy/node-inspector/daemonset.yaml`), rollout status and uninstall.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{DeleteParams, ListParams};
+use serde_json::{json, Value};
+use tokio::time::sleep;
+
+use super::{COMPONENT_LABEL, MANAGED_BY_LABEL};
+use kubeowler_core::k8s::K8sClient;
+use kubeowler_core::node_inspection::collector::{
+    CONTAINER_NAME, DAEMONSET_NAME, NODE_INSPECTOR_LABEL,
+};
+
+pub const COMPONENT: &str = "node-inspector";
+/// Default image: the node inspector release matching this binary.
+pub const DEFAULT_IMAGE: &str = concat!(
+    "docker.io/ghostwritten/kubeowler-node-inspector:v",
+    env!("CARGO_PKG_VERSION")
+);
+pub const DEFAULT_CPU_REQUEST: &str = "10m";
+pub const DEFAULT_MEMORY_REQUEST: &str = "32Mi";
+pub const DEFAULT_CPU_LIMIT: &str = "100m";
+pub const DEFAULT_MEMORY_LIMIT: &str = "64Mi";
+/// Default rollout wait after install/upgrade, in seconds.
+pub const DEFAULT_ROLLOUT_TIMEOUT_SECS: u64 = 180;
+
+#[derive(Debug, Clone)]
+pub struct NodeInspectorConfig {
+    pub namespace: String,
+    pub image: String,
+    /// `KEY[=VALUE][:EFFECT]` specs; empty means tolerate every taint (run on all nodes).
+    pub tolerations: Vec<String>,
+    pub cpu_request: String,
+    pub memory_request: String,
+    pub cpu_limit: String,
+    pub memory_limit: String,
+}
+
+/// Parse `KEY[=VALUE][:EFFECT]` (kubectl taint syntax) into a toleration; `*` tolerates everything.
+pub fn parse_toleration(spec: &str) -> Result<Value> {
+    let spec = spec.trim();
+    if spec == "*" {
+        return Ok(json!({ "operator": "Exists" }));
+    }
+    let (kv, effect) = match spec.rsplit_once(':') {
+        Some((kv, effect)) => (kv, Some(effect)),
+        None => (spec, None),
+    };
+    if let Some(e) = effect {
+        if !matches!(e, "NoSchedule" | "PreferNoSchedule" | "NoExecute") {
+            bail!(
+                "invalid toleration '{}': effect must be NoSchedule, PreferNoSchedule or NoExecute",
+                spec
+            );
+        }
+    }
+    let mut t = match kv.split_once('=') {
+        Some((k, v)) => json!({ "key": k, "operator": "Equal", "value": v }),
+        None => json!({ "key": kv, "operator": "Exists" }),
+    };
+    if t["key"].as_str().is_none_or(str::is_empty) {
+        bail!("invalid toleration '{}': missing key", spec);
+    }
+    if let Some(e) = effect {
+        t["effect"] = json!(e);
+    }
+    Ok(t)
+}
+
+/// Namespace and DaemonSet, in apply order.
+pub fn render_node_inspector_manifests(cfg: &NodeInspectorConfig) -> Result<Vec<Value>> {
+    let tolerations = if cfg.tolerations.is_empty() {
+        vec![json!({ "operator": "Exists" })]
+    } else {
+        cfg.tolerations
+            .iter()
+            .map(|t| parse_toleration(t))
+            .collect::<Result<Vec<_>>>()?
+    };
+    let (label_key, label_value) = NODE_INSPECTOR_LABEL
+        .split_once('=')
+        .unwrap_or(("app", DAEMONSET_NAME));
+    Ok(vec![
+        json!({
+            "apiVersion": "v1", "kind": "Namespace",
+            "metadata": { "name": cfg.namespace, "labels": { "app.kubernetes.io/name": "kubeowler" } }
+        }),
+        json!({
+            "apiVersion": "apps/v1", "kind": "DaemonSet",
+            "metadata": {
+                "name": DAEMONSET_NAME,
+                "namespace": cfg.namespace,
+                "labels": { label_key: label_value, MANAGED_BY_LABEL: "kubeowler", COMPONENT_LABEL: COMPONENT }
+            },
+            "spec": {
+                "selector": { "matchLabels": { label_key: label_value } },
+                "updateStrategy": { "type": "RollingUpdate" },
+                "template": {
+                    "metadata": { "labels": { label_key: label_value } },
+                    "spec": {
+                        "hostNetwork": false,
+                        "hostPID": true,
+                        "nodeSelector": { "kubernetes.io/os": "linux" },
+                        "tolerations": tolerations,
+                        "containers": [{
+                            "name": CONTAINER_NAME,
+                            "image": cfg.image,
+                            "imagePullPolicy": "IfNotPresent",
+                            "env": [{ "name": "NODE_NAME", "valueFrom": { "fieldRef": { "fieldPath": "spec.nodeName" } } }],
+                            "resources": {
+                                "requests": { "cpu": cfg.cpu_request, "memory": cfg.memory_request },
+                                "limits": { "cpu": cfg.cpu_limit, "memory": cfg.memory_limit }
+                            },
+                            "securityContext": {
+                                "runAsNonRoot": false,
+                                "runAsUser": 0,
+                                "readOnlyRootFilesystem": false,
+                                "allowPrivilegeEscalation": false,
+                                "seLinuxOptions": { "type": "spc_t" },
+                                "capabilities": { "drop": ["ALL"] }
+                            },
+                            "volumeMounts": [{ "name": "host-root", "mountPath": "/host", "readOnly": true }]
+                        }],
+                        "volumes": [{ "name": "host-root", "hostPath": { "path": "/", "type": "Directory" } }]
+                    }
+                }
+            }
+        }),
+    ])
+}
+
+/// Rollout state of the DaemonSet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutStatus {
+    pub image: Option<String>,
+    pub desired: i32,
+    pub updated: i32,
+    pub ready: i32,
+    pub available: i32,
+    /// The controller has seen the latest spec.
+    pub observed: bool,
+}
+
+impl RolloutStatus {
+    pub fn from_daemon_set(ds: &DaemonSet) -> Self {
+        let image = ds
+            .spec
+            .as_ref()
+            .and_then(|s| s.template.spec.as_ref())
+            .and_then(|s| s.containers.iter().find(|c| c.name == CONTAINER_NAME))
+            .and_then(|c| c.image.clone());
+        let Some(status) = ds.status.as_ref() else {
+            return Self {
+                image,
+                ..Default::default()
+            };
+        };
+        Self {
+            image,
+            desired: status.desired_number_scheduled,
+            updated: status.updated_number_scheduled.unwrap_or(0),
+            ready: status.number_ready,
+            available: status.number_available.unwrap_or(0),
+            observed: status.observed_generation.unwrap_or(0)
+                >= ds.metadata.generation.unwrap_or(0),
+        }
+    }
+
+    /// Every scheduled pod runs the current template and is available.
+    pub fn is_complete(&self) -> bool {
+        self.observed
+            && self.desired > 0
+            && self.updated == self.desired
+            && self.available == self.desired
+    }
+}
+
+/// The node inspector DaemonSet, or None when it is not installed.
+pub async fn get_daemon_set(client: &K8sClient, namespace: &str) -> Result<Option<DaemonSet>> {
+    client
+        .daemon_sets(Some(namespace))
+        .get_opt(DAEMONSET_NAME)
+        .await
+        .with_context(|| format!("reading DaemonSet {}/{}", namespace, DAEMONSET_NAME))
+}
+
+/// Poll until the rollout completes or `timeout` elapses; returns the last status seen.
+pub async fn wait_for_rollout(
+    client: &K8sClient,
+    namespace: &str,
+    timeout: Duration,
+) -> Result<RolloutStatus> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let status = get_daemon_set(client, namespace)
+            .await?
+            .map(|ds| RolloutStatus::from_daemon_set(&ds))
+            .unwrap_or_default();
+        if status.is_complete() || Instant::now() >= deadline {
+            return Ok(status);
+        }
+        sleep(Duration::from_secs(2)).await;
+    }
+}
+
+/// Node inspector pods that are not Running, as `(pod, node, reason)`.
+pub async fn unhealthy_pods(
+    client: &K8sClient,
+    namespace: &str,
+) -> Result<Vec<(String, String, String)>> {
+    let pods = client
+        .pods(Some(namespace))
+        .list(&ListParams::default().labels(NODE_INSPECTOR_LABEL))
+        .await?;
+    Ok(pods.items.iter().filter_map(unhealthy_pod).collect())
+}
+
+fn unhealthy_pod(pod: &Pod) -> Option<(String, String, String)> {
+    let status = pod.status.as_ref();
+    let phase = status.and_then(|s| s.phase.as_deref()).unwrap_or("Unknown");
+    // A waiting container reason (ImagePullBackOff, CrashLoopBackOff) says more than the phase.
+    let waiting = status
+        .and_then(|s| s.container_statuses.as_ref())
+        .and_then(|cs| {
+            cs.iter()
+                .find_map(|c| c.state.as_ref()?.waiting.as_ref()?.reason.clone())
+        });
+    if phase == "Running" && waiting.is_none() {
+        return None;
+    }
+    Some((
+        pod.metadata.name.clone().unwrap_or_default(),
+        pod.spec
+            .as_ref()
+            .and_then(|s| s.node_name.clone())
+            .unwrap_or_else(|| "-".to_string()),
+        waiting.unwrap_or_else(|| phase.to_string()),
+    ))
+}
+
+/// Delete the DaemonSet (and the namespace when asked). Returns false if it was not installed.
+pub async fn uninstall(
+    client: &K8sClient,
+    namespace: &str,
+    delete_namespace: bool,
+) -> Result<bool> {
+    let existed = get_daemon_set(client, namespace).await?.is_some();
+    if existed {
+        client
+            .daemon_sets(Some(namespace))
+            .delete(DAEMONSET_NAME, &DeleteParams::background())
+            .await
+            .with_context(|| format!("deleting DaemonSet {}/{}", namespace, DAEMONSET_NAME))?;
+    }
+    if delete_namespace {
+        let namespaces = client.namespaces();
+        if namespaces.get_opt(namespace).await?.is_some() {
+            namespaces
+                .delete(namespace, &DeleteParams::background())
+                .await
+                .with_context(|| format!("deleting namespace {}", namespace))?;
+        }
+    }
+    Ok(existed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tolerations() {
+        assert_eq!(
+            parse_toleration("dedicated=gpu:NoSchedule").unwrap(),
+            json!({ "key": "dedicated", "operator": "Equal", "value": "gpu", "effect": "NoSchedule" })
+        );
+        assert_eq!(
+            parse_toleration("node-role.kubernetes.io/control-plane").unwrap(),
+            json!({ "key": "node-role.kubernetes.io/control-plane", "operator": "Exists" })
+        );
+        assert!(parse_toleration("=x").is_err());
+        assert!(parse_toleration("a:Never").is_err());
+    }
+
+    #[test]
+    fn renders_daemonset_with_overrides() {
+        let cfg = NodeInspectorConfig {
+            namespace: "ops".to_string(),
+            image: "registry.local/node-inspector:v1".to_string(),
+            tolerations: vec!["dedicated=gpu:NoSchedule".to_string()],
+            cpu_request: DEFAULT_CPU_REQUEST.to_string(),
+            memory_request: DEFAULT_MEMORY_REQUEST.to_string(),
+            cpu_limit: "200m".to_string(),
+            memory_limit: DEFAULT_MEMORY_LIMIT.to_string(),
+        };
+        let manifests = render_node_inspector_manifests(&cfg).unwrap();
+        let ds = &manifests[1];
+        assert_eq!(ds["metadata"]["namespace"], "ops");
+        let pod = &ds["spec"]["template"]["spec"];
+        assert_eq!(
+            pod["containers"][0]["image"],
+            "registry.local/node-inspector:v1"
+        );
+        assert_eq!(pod["containers"][0]["resources"]["limits"]["cpu"], "200m");
+        assert_eq!(pod["tolerations"].as_array().unwrap().len(), 1);
+        assert_eq!(pod["nodeSelector"]["kubernetes.io/os"], "linux");
+        assert_eq!(
+            ds["spec"]["selector"]["matchLabels"]["app"],
+            "kubeowler-node-inspector"
+        );
+    }
+}
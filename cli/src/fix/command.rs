@@ -0,0 +1,98 @@
+//! `kubeowler fix`: dry-run every fixable finding of a saved report and, with `--interactive`,
+//! apply them one by one after confirmation.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::cli::ClusterAccess;
+use kubeowler_core::inspections::types::ClusterReport;
+
+use crate::console::{connect, prompt_line};
+use crate::fix;
+
+/// Run `fix` on the saved JSON report `input`.
+pub async fn run(
+    input: &str,
+    interactive: bool,
+    config_file: Option<&str>,
+    access: &ClusterAccess,
+) -> Result<()> {
+    let text = std::fs::read_to_string(input)
+        .map_err(|e| anyhow::anyhow!("cannot open {}: {}", input, e))?;
+    let report: ClusterReport = serde_json::from_str(&text)
+        .map_err(|e| anyhow::anyhow!("{} is not a kubeowler JSON report: {}", input, e))?;
+    let candidates = fix::candidates(&report);
+    if candidates.is_empty() {
+        println!(
+            "No fixable Critical/Warning findings in {} (fixable codes: {})",
+            input,
+            fix::FIXABLE_RULES.join(", ")
+        );
+        return Ok(());
+    }
+    let client = connect(config_file, access).await?;
+    let (mut applied, mut skipped) = (0usize, 0usize);
+    for issue in candidates {
+        let code = issue.rule_id.as_deref().unwrap_or("-");
+        println!();
+        println!(
+            "{} {} {}",
+            format!("[{:?}]", issue.severity).yellow(),
+            code.bright_cyan().bold(),
+            issue.description
+        );
+        let fix = match fix::plan(&client, issue).await {
+            Ok(fix::Plan::Ready(fix)) => fix,
+            Ok(fix::Plan::Skipped(reason)) => {
+                println!("   {} {}", "skipped:".yellow(), reason);
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("   {} dry run failed: {:#}", "skipped:".yellow(), e);
+                skipped += 1;
+                continue;
+            }
+        };
+        println!("   {} (dry run)", fix.target.bold());
+        for line in &fix.diff {
+            println!("     {}", line.bright_green());
+        }
+        if let Some(undo) = &fix.undo {
+            println!("     undo: {}", undo);
+        }
+        if !interactive {
+            continue;
+        }
+        let confirmed = if fix.is_destructive() {
+            prompt_line(&format!(
+                "   This cannot be undone. Type {} to confirm (empty to skip): ",
+                fix.confirmation_name().bold()
+            ))? == fix.confirmation_name()
+        } else {
+            prompt_line("   Apply? [y/N]: ")?.eq_ignore_ascii_case("y")
+        };
+        if !confirmed {
+            println!("   {}", "skipped".yellow());
+            skipped += 1;
+            continue;
+        }
+        match fix::apply(&client, &fix).await {
+            Ok(()) => {
+                println!("   {}", "✅ applied".bright_green());
+                applied += 1;
+            }
+            Err(e) => {
+                println!("   {} {:#}", "❌ failed:".bright_red(), e);
+                skipped += 1;
+            }
+        }
+    }
+    println!();
+    if interactive {
+        println!("Applied {} fix(es), skipped {}", applied, skipped);
+    } else {
+        println!("Dry run only; re-run with --interactive to apply fixes one by one");
+    }
+    Ok(())
+}
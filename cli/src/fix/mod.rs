@@ -0,0 +1,419 @@
+//! `kubeowler fix`: guarded remediation of an allowlisted set of low-risk findings from a saved
+//! JSON report. Every fix re-reads the live object (the report may be stale), is validated with a
+//! server-side dry run and shown as a diff before anything is changed.
+
+pub mod command;
+
+use anyhow::Result;
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::storage::v1::StorageClass;
+use kube::api::{Api, DeleteParams, ListParams, Patch, PatchParams, Preconditions};
+use serde_json::{json, Value};
+
+use crate::deploy::FIELD_MANAGER;
+use kubeowler_core::inspections::types::{ClusterReport, Issue, IssueSeverity};
+use kubeowler_core::k8s::K8sClient;
+
+/// Issue codes `fix` can remediate. Anything else is left to the operator.
+pub const FIXABLE_RULES: &[&str] = &["STO-009", "BATCH-001", "STO-002"];
+
+const DEFAULT_CLASS_ANNOTATION: &str = "storageclass.kubernetes.io/is-default-class";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FixAction {
+    /// Mark the cluster's only StorageClass as default (STO-009).
+    SetDefaultStorageClass { name: String },
+    /// Set `spec.suspend: false` on a suspended CronJob (BATCH-001).
+    ResumeCronJob { namespace: String, name: String },
+    /// Delete a PersistentVolume that is still Released (STO-002); not reversible. `uid` and
+    /// `resource_version` are those of the inspected object: if the PV was recreated or changed
+    /// (e.g. bound again) since, the API server refuses the delete.
+    DeleteReleasedPv {
+        name: String,
+        uid: Option<String>,
+        resource_version: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedFix {
+    pub action: FixAction,
+    /// `Kind name` or `Kind namespace/name` of the object changed.
+    pub target: String,
+    /// Dry-run result: `field: before -> after` lines, or the object to be deleted.
+    pub diff: Vec<String>,
+    /// Command that reverts the fix; None for deletions.
+    pub undo: Option<String>,
+}
+
+impl PlannedFix {
+    /// Deletions need the object name typed back instead of a y/N answer.
+    pub fn is_destructive(&self) -> bool {
+        matches!(self.action, FixAction::DeleteReleasedPv { .. })
+    }
+
+    /// Name the operator must type to confirm a destructive fix.
+    pub fn confirmation_name(&self) -> &str {
+        match &self.action {
+            FixAction::SetDefaultStorageClass { name }
+            | FixAction::ResumeCronJob { name, .. }
+            | FixAction::DeleteReleasedPv { name, .. } => name,
+        }
+    }
+}
+
+/// Outcome of planning one finding.
+#[derive(Debug)]
+pub enum Plan {
+    Ready(PlannedFix),
+    /// Nothing to do or not safe to automate, with the reason.
+    Skipped(String),
+}
+
+/// Critical and Warning findings of `report` with an allowlisted code, one per code and resource.
+pub fn candidates(report: &ClusterReport) -> Vec<&Issue> {
+    let mut seen = Vec::new();
+    report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+        .filter(|i| i.severity != IssueSeverity::Info)
+        .filter(|i| {
+            i.rule_id
+                .as_deref()
+                .is_some_and(|c| FIXABLE_RULES.contains(&c))
+        })
+        .filter(|i| {
+            let key = (i.rule_id.clone(), i.resource.clone());
+            let new = !seen.contains(&key);
+            seen.push(key);
+            new
+        })
+        .collect()
+}
+
+/// `label: before -> after` for the value at JSON `pointer`; `(unset)` for missing values.
+pub fn field_diff(label: &str, before: &Value, after: &Value, pointer: &str) -> String {
+    let show = |v: &Value| {
+        v.pointer(pointer)
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| "(unset)".to_string())
+    };
+    format!("{}: {} -> {}", label, show(before), show(after))
+}
+
+fn patch_params(dry_run: bool) -> PatchParams {
+    let params = PatchParams {
+        field_manager: Some(FIELD_MANAGER.to_string()),
+        ..Default::default()
+    };
+    if dry_run {
+        params.dry_run()
+    } else {
+        params
+    }
+}
+
+fn default_class_patch() -> Value {
+    json!({ "metadata": { "annotations": { DEFAULT_CLASS_ANNOTATION: "true" } } })
+}
+
+fn resume_patch() -> Value {
+    json!({ "spec": { "suspend": false } })
+}
+
+/// Re-check `issue` against the cluster and dry-run its fix.
+pub async fn plan(client: &K8sClient, issue: &Issue) -> Result<Plan> {
+    match issue.rule_id.as_deref() {
+        Some("STO-009") => plan_default_storage_class(client).await,
+        Some("BATCH-001") => plan_resume_cronjob(client, issue.resource.as_deref()).await,
+        Some("STO-002") => plan_delete_released_pv(client, issue.resource.as_deref()).await,
+        other => Ok(Plan::Skipped(format!(
+            "{} is not in the fix allowlist",
+            other.unwrap_or("finding without a code")
+        ))),
+    }
+}
+
+async fn plan_default_storage_class(client: &K8sClient) -> Result<Plan> {
+    let api = client.storage_classes();
+    let classes = api.list(&ListParams::default()).await?.items;
+    let is_default = |sc: &StorageClass| {
+        sc.metadata
+            .annotations
+            .as_ref()
+            .and_then(|a| a.get(DEFAULT_CLASS_ANNOTATION))
+            .is_some_and(|v| v == "true")
+    };
+    if let Some(sc) = classes.iter().find(|sc| is_default(sc)) {
+        return Ok(Plan::Skipped(format!(
+            "StorageClass {} is already the default",
+            sc.metadata.name.as_deref().unwrap_or("?")
+        )));
+    }
+    let name = match classes.as_slice() {
+        [only] => only.metadata.name.clone().unwrap_or_default(),
+        [] => return Ok(Plan::Skipped("the cluster has no StorageClass".to_string())),
+        _ => {
+            return Ok(Plan::Skipped(format!(
+                "{} StorageClasses exist; pick the default yourself (kubectl annotate storageclass <name> {}=true)",
+                classes.len(),
+                DEFAULT_CLASS_ANNOTATION
+            )))
+        }
+    };
+    let before = serde_json::to_value(&classes[0])?;
+    let after = serde_json::to_value(
+        api.patch(
+            &name,
+            &patch_params(true),
+            &Patch::Merge(default_class_patch()),
+        )
+        .await?,
+    )?;
+    let pointer = format!(
+        "/metadata/annotations/{}",
+        DEFAULT_CLASS_ANNOTATION.replace('/', "~1")
+    );
+    Ok(Plan::Ready(PlannedFix {
+        target: format!("StorageClass {}", name),
+        diff: vec![field_diff(
+            &format!("metadata.annotations[{}]", DEFAULT_CLASS_ANNOTATION),
+            &before,
+            &after,
+            &pointer,
+        )],
+        undo: Some(format!(
+            "kubectl annotate storageclass {} {}-",
+            name, DEFAULT_CLASS_ANNOTATION
+        )),
+        action: FixAction::SetDefaultStorageClass { name },
+    }))
+}
+
+async fn plan_resume_cronjob(client: &K8sClient, resource: Option<&str>) -> Result<Plan> {
+    let Some(resource) = resource else {
+        return Ok(Plan::Skipped("finding names no CronJob".to_string()));
+    };
+    // BATCH-001 findings name the CronJob without its namespace; accept `namespace/name` too.
+    let (namespace, name) = match resource.split_once('/') {
+        Some((ns, n)) => (Some(ns), n),
+        None => (None, resource),
+    };
+    let suspended: Vec<CronJob> = client
+        .cron_jobs(namespace)
+        .list(&ListParams::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|c| c.metadata.name.as_deref() == Some(name))
+        .filter(|c| c.spec.as_ref().and_then(|s| s.suspend) == Some(true))
+        .collect();
+    let cron = match suspended.as_slice() {
+        [only] => only,
+        [] => {
+            return Ok(Plan::Skipped(format!(
+                "no suspended CronJob named {} anymore",
+                resource
+            )))
+        }
+        _ => {
+            return Ok(Plan::Skipped(format!(
+                "{} suspended CronJobs are named {}; resume the right one yourself",
+                suspended.len(),
+                name
+            )))
+        }
+    };
+    let namespace = cron.metadata.namespace.clone().unwrap_or_default();
+    let api: Api<CronJob> = client.cron_jobs(Some(&namespace));
+    let before = serde_json::to_value(cron)?;
+    let after = serde_json::to_value(
+        api.patch(name, &patch_params(true), &Patch::Merge(resume_patch()))
+            .await?,
+    )?;
+    Ok(Plan::Ready(PlannedFix {
+        target: format!("CronJob {}/{}", namespace, name),
+        diff: vec![field_diff("spec.suspend", &before, &after, "/spec/suspend")],
+        undo: Some(format!(
+            "kubectl -n {} patch cronjob {} -p '{{\"spec\":{{\"suspend\":true}}}}'",
+            namespace, name
+        )),
+        action: FixAction::ResumeCronJob {
+            namespace,
+            name: name.to_string(),
+        },
+    }))
+}
+
+async fn plan_delete_released_pv(client: &K8sClient, resource: Option<&str>) -> Result<Plan> {
+    let Some(name) = resource else {
+        return Ok(Plan::Skipped(
+            "finding names no PersistentVolume".to_string(),
+        ));
+    };
+    let api = client.persistent_volumes();
+    let Some(pv) = api.get_opt(name).await? else {
+        return Ok(Plan::Skipped(format!(
+            "PersistentVolume {} no longer exists",
+            name
+        )));
+    };
+    let phase = pv.status.as_ref().and_then(|s| s.phase.as_deref());
+    if phase != Some("Released") {
+        return Ok(Plan::Skipped(format!(
+            "PersistentVolume {} is {} now, not Released",
+            name,
+            phase.unwrap_or("in an unknown phase")
+        )));
+    }
+    let uid = pv.metadata.uid.clone();
+    let resource_version = pv.metadata.resource_version.clone();
+    api.delete(
+        name,
+        &delete_params(uid.clone(), resource_version.clone()).dry_run(),
+    )
+    .await?;
+    let spec = pv.spec.as_ref();
+    let claim = spec
+        .and_then(|s| s.claim_ref.as_ref())
+        .map(|c| {
+            format!(
+                "{}/{}",
+                c.namespace.as_deref().unwrap_or("-"),
+                c.name.as_deref().unwrap_or("-")
+            )
+        })
+        .unwrap_or_else(|| "-".to_string());
+    let capacity = spec
+        .and_then(|s| s.capacity.as_ref())
+        .and_then(|c| c.get("storage"))
+        .map(|q| q.0.clone())
+        .unwrap_or_else(|| "-".to_string());
+    let policy = spec
+        .and_then(|s| s.persistent_volume_reclaim_policy.clone())
+        .unwrap_or_else(|| "-".to_string());
+    Ok(Plan::Ready(PlannedFix {
+        target: format!("PersistentVolume {}", name),
+        diff: vec![format!(
+            "delete PersistentVolume {} (Released, claim {}, capacity {}, reclaimPolicy {})",
+            name, claim, capacity, policy
+        )],
+        undo: None,
+        action: FixAction::DeleteReleasedPv {
+            name: name.to_string(),
+            uid,
+            resource_version,
+        },
+    }))
+}
+
+/// Delete only the object version that was inspected.
+fn delete_params(uid: Option<String>, resource_version: Option<String>) -> DeleteParams {
+    DeleteParams {
+        preconditions: Some(Preconditions {
+            uid,
+            resource_version,
+        }),
+        ..DeleteParams::default()
+    }
+}
+
+/// Apply a planned fix for real.
+pub async fn apply(client: &K8sClient, fix: &PlannedFix) -> Result<()> {
+    match &fix.action {
+        FixAction::SetDefaultStorageClass { name } => {
+            client
+                .storage_classes()
+                .patch(
+                    name,
+                    &patch_params(false),
+                    &Patch::Merge(default_class_patch()),
+                )
+                .await?;
+        }
+        FixAction::ResumeCronJob { namespace, name } => {
+            client
+                .cron_jobs(Some(namespace))
+                .patch(name, &patch_params(false), &Patch::Merge(resume_patch()))
+                .await?;
+        }
+        FixAction::DeleteReleasedPv {
+            name,
+            uid,
+            resource_version,
+        } => {
+            client
+                .persistent_volumes()
+                .delete(name, &delete_params(uid.clone(), resource_version.clone()))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(rule_id: &str, severity: IssueSeverity, resource: &str) -> Issue {
+        Issue::new(severity, "Test", rule_id, "", resource.to_string(), "")
+    }
+
+    #[test]
+    fn candidates_are_allowlisted_non_info_and_unique() {
+        let mut report: ClusterReport = serde_json::from_value(json!({
+            "cluster_name": "c", "report_id": "r", "timestamp": "2026-01-01T00:00:00Z",
+            "overall_score": 80.0, "inspections": [{
+                "inspection_type": "Storage", "timestamp": "2026-01-01T00:00:00Z",
+                "overall_score": 80.0, "checks": [],
+                "summary": { "total_checks": 0, "passed_checks": 0, "warning_checks": 0,
+                             "critical_checks": 0, "error_checks": 0, "issues": [] }
+            }],
+            "executive_summary": { "health_status": "Good", "key_findings": [],
+                                   "priority_recommendations": [], "score_breakdown": {} }
+        }))
+        .unwrap();
+        report.inspections[0].summary.issues = vec![
+            issue("STO-002", IssueSeverity::Warning, "pv-1"),
+            issue("STO-002", IssueSeverity::Warning, "pv-1"),
+            issue("STO-003", IssueSeverity::Warning, "pv-2"),
+            issue("BATCH-001", IssueSeverity::Info, "nightly"),
+            issue("BATCH-001", IssueSeverity::Warning, "backup"),
+        ];
+        let found: Vec<_> = candidates(&report)
+            .iter()
+            .map(|i| (i.rule_id.clone().unwrap(), i.resource.clone().unwrap()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("STO-002".to_string(), "pv-1".to_string()),
+                ("BATCH-001".to_string(), "backup".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn field_diff_shows_unset_values() {
+        let before = json!({ "spec": { "suspend": true }, "metadata": {} });
+        let after = json!({ "spec": { "suspend": false }, "metadata": { "annotations": { "a/b": "true" } } });
+        assert_eq!(
+            field_diff("spec.suspend", &before, &after, "/spec/suspend"),
+            "spec.suspend: true -> false"
+        );
+        assert_eq!(
+            field_diff("annotation", &before, &after, "/metadata/annotations/a~1b"),
+            "annotation: (unset) -> \"true\""
+        );
+    }
+
+    #[test]
+    fn pv_delete_is_conditional_on_the_inspected_object() {
+        let params = delete_params(Some("uid-1".to_string()), Some("42".to_string()));
+        let preconditions = params.preconditions.unwrap();
+        assert_eq!(preconditions.uid.as_deref(), Some("uid-1"));
+        assert_eq!(preconditions.resource_version.as_deref(), Some("42"));
+        assert!(!params.dry_run);
+    }
+}
@@ -0,0 +1,16 @@
+//! The `kubeowler` command line on top of `kubeowler_core`: flag parsing, config file flag
+//! defaults, the terminal UI and dashboard server, and the subcommand handlers: `check` and
+//! `render`, the ones that change a cluster (`node-inspector`, `deploy-cron`, `fix`), tickets,
+//! `watch` and `upgrade-plan`. `main.rs` only parses arguments and dispatches.
+
+pub mod args;
+pub mod check;
+pub mod console;
+pub mod dashboard;
+pub mod defaults;
+pub mod deploy;
+pub mod fix;
+pub mod tickets;
+pub mod tui;
+pub mod upgrade_plan;
+pub mod watch;
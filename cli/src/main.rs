@@ -0,0 +1,477 @@
+use anyhow::Result;
+use clap::{CommandFactory, FromArgMatches};
+use colored::Colorize;
+
+use kubeowler::{
+    args, check, console, dashboard, defaults, deploy, fix, tickets, tui, upgrade_plan, watch,
+};
+use kubeowler_core::{
+    baseline, benchmark, config, inspections, preflight, reporting, telemetry, utils,
+};
+
+use args::{Args, BaselineAction, Commands, DocsAction};
+use inspections::check_filter::CheckFilter;
+use inspections::types::ClusterReport;
+use inspections::{InspectionOptions, InspectionRunner};
+use kubeowler_core::cli::{ClusterAccess, InspectionType};
+use reporting::ReportGenerator;
+use utils::pattern::parse_pattern_list;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::init();
+
+    let command = args::with_env_vars(Args::command());
+    let argv = defaults::args_with_defaults(&command, std::env::args_os().collect())?;
+    let args = Args::from_arg_matches(&command.get_matches_from(argv)).unwrap_or_else(|e| e.exit());
+    let telemetry = args
+        .otel_endpoint
+        .as_deref()
+        .map(telemetry::init)
+        .transpose()?;
+    let result = run(args).await;
+    if let Some(telemetry) = telemetry {
+        telemetry.shutdown().await;
+    }
+    result
+}
+
+async fn run(args: Args) -> Result<()> {
+    let access = &ClusterAccess::from(args.access);
+
+    match args.command {
+        Commands::Check {
+            cluster_name,
+            namespace,
+            selector,
+            node_inspector_namespace,
+            output,
+            format,
+            csv_layout,
+            lang,
+            offline_docs,
+            template,
+            sections,
+            max_resources_per_issue,
+            resource_appendix,
+            redact,
+            benchmark,
+            benchmark_label,
+            config_file,
+            level,
+            prod_namespaces,
+            debug_env_patterns,
+            node_pool_label,
+            stale_job_days,
+            stale_pod_days,
+            max_node_image_age,
+            kernel_denylist,
+            max_cordon_hours,
+            max_memory_overcommit,
+            max_cpu_overcommit,
+            required_namespace_labels,
+            empty_namespace_days,
+            stuck_deletion_minutes,
+            registry_lookup,
+            event_window,
+            noisy_event_rate,
+            max_issues_per_rule,
+            per_pod_issues,
+            only_checks,
+            skip_checks,
+            node_collect,
+            active_probes,
+            probe_egress_target,
+            export_affected,
+            skip_preflight,
+            offline,
+            from_dir,
+            scoring_profile,
+            profile,
+            config,
+            owners,
+            team_label,
+            split_by_team,
+            baseline,
+            history_dir,
+            critical_sla_days,
+            create_issues,
+            brand,
+        } => {
+            let mut config = config::load(config.as_deref())?;
+            let profile = config.take_profile(profile.as_deref())?;
+            let branding = config.branding.clone().merged(&brand.into())?;
+            let ticket_targets = if create_issues.is_empty() {
+                &config.tickets.targets
+            } else {
+                &create_issues
+            }
+            .iter()
+            .map(|t| tickets::TicketTarget::parse(t))
+            .collect::<Result<Vec<_>>>()?;
+            let inspection_options = InspectionOptions {
+                prod_namespace_patterns: parse_pattern_list(&prod_namespaces),
+                debug_env_patterns: parse_pattern_list(&debug_env_patterns),
+                node_pool_labels: parse_pattern_list(&node_pool_label),
+                stale_job_days,
+                stale_pod_days,
+                max_node_image_age_days: max_node_image_age,
+                kernel_denylist: parse_pattern_list(&kernel_denylist),
+                max_cordon_hours,
+                max_memory_overcommit_pct: max_memory_overcommit,
+                max_cpu_overcommit_pct: max_cpu_overcommit,
+                required_namespace_labels: parse_pattern_list(&required_namespace_labels),
+                empty_namespace_days,
+                stuck_deletion_minutes,
+                event_window_minutes: event_window,
+                noisy_events_per_hour: noisy_event_rate,
+                node_collect,
+                offline,
+                scoring_profile: check::resolve_scoring_profile(scoring_profile, &config)?,
+                max_issues_per_rule,
+                aggregate_by_controller: !per_pod_issues,
+                active_probes,
+                probe_egress_target: Some(probe_egress_target).filter(|t| !t.is_empty()),
+                registry_lookup,
+                checks: CheckFilter::parse(&only_checks, &skip_checks)?,
+            };
+            check::run(check::CheckOptions {
+                cluster_name,
+                namespace,
+                selector,
+                node_inspector_namespace,
+                output,
+                format,
+                csv_layout,
+                lang,
+                offline_docs,
+                template: check::output::load_template(template.as_deref())?,
+                sections: config.report.sections(sections),
+                max_resources_per_issue,
+                resource_appendix,
+                redact,
+                benchmark,
+                benchmark_label,
+                config_file,
+                level,
+                inspection_options,
+                export_affected,
+                skip_preflight,
+                from_dir,
+                owners,
+                team_label,
+                split_by_team,
+                baseline,
+                history_dir,
+                critical_sla_days,
+                profile,
+                ticket_targets,
+                ticket_config: config.tickets,
+                branding,
+                access: access.clone(),
+            })
+            .await?;
+        }
+        Commands::Render {
+            input,
+            format,
+            csv_layout,
+            lang,
+            offline_docs,
+            template,
+            sections,
+            max_resources_per_issue,
+            resource_appendix,
+            redact,
+            output,
+            level,
+            config,
+            brand,
+        } => {
+            let config = config::load(config.as_deref())?;
+            let generator = ReportGenerator::new()
+                .with_lang(lang)
+                .with_docs_base(offline_docs)
+                .with_template(check::output::load_template(template.as_deref())?)
+                .with_sections(config.report.sections(sections))
+                .with_resource_limit(max_resources_per_issue, resource_appendix.clone());
+            let branding = config.branding.merged(&brand.into())?;
+            check::render::run(
+                &input, format, csv_layout, &generator, output, &level, &branding, redact,
+            )
+            .await?
+        }
+        Commands::Serve {
+            history_dir,
+            listen,
+            lang,
+            level,
+            config,
+            brand,
+        } => {
+            let branding = config::load(config.as_deref())?
+                .branding
+                .merged(&brand.into())?;
+            println!(
+                "📈 Serving {} on {} (Ctrl-C to stop)",
+                history_dir.bright_cyan(),
+                format!("http://{}", listen).bright_cyan()
+            );
+            dashboard::serve(
+                history_dir.into(),
+                listen,
+                branding,
+                ReportGenerator::new().with_lang(lang),
+                level,
+            )
+            .await?;
+        }
+        Commands::Watch {
+            interval,
+            score_threshold,
+            log_file,
+            level,
+            cluster_name,
+            namespace,
+            node_inspector_namespace,
+            node_collect,
+            scoring_profile,
+            config,
+            config_file,
+        } => {
+            watch::command::run(watch::command::WatchOptions {
+                interval: watch::parse_interval(&interval)?,
+                score_threshold,
+                log_file,
+                severities: watch::tracked_severities(&level),
+                cluster_name,
+                namespace,
+                node_inspector_namespace,
+                node_collect,
+                scoring_profile: check::resolve_scoring_profile(
+                    scoring_profile,
+                    &config::load(config.as_deref())?,
+                )?,
+                config_file,
+                access: access.clone(),
+            })
+            .await?;
+        }
+        Commands::Tui {
+            cluster_name,
+            namespace,
+            node_inspector_namespace,
+            node_collect,
+            scoring_profile,
+            config,
+            config_file,
+        } => {
+            let scoring_profile =
+                check::resolve_scoring_profile(scoring_profile, &config::load(config.as_deref())?)?;
+            let client = console::connect(config_file.as_deref(), access).await?;
+            let runner = InspectionRunner::new(client)
+                .with_notify(console::console_notify())
+                .with_options(InspectionOptions {
+                    node_collect,
+                    scoring_profile,
+                    ..InspectionOptions::default()
+                });
+            let run = || {
+                runner.run_inspections(
+                    InspectionType::All,
+                    namespace.as_deref(),
+                    &node_inspector_namespace,
+                    cluster_name.as_deref(),
+                )
+            };
+            let report = run().await?;
+            tui::run(report, run).await?;
+        }
+        Commands::DeployCron {
+            schedule,
+            namespace,
+            image,
+            output_pvc,
+            pvc_size,
+            storage_class,
+            upload_secret,
+            node_inspector_namespace,
+            dry_run,
+            config_file,
+            check_args,
+        } => {
+            let config = deploy::cron::CronDeployConfig {
+                namespace,
+                schedule,
+                image,
+                output_pvc,
+                pvc_size,
+                storage_class,
+                upload_secret,
+                node_inspector_namespace,
+                check_args,
+            };
+            deploy::command::deploy_cron(&config, dry_run, config_file, access).await?;
+        }
+        Commands::Undeploy {
+            namespace,
+            node_inspector_namespace,
+            delete_pvc,
+            config_file,
+        } => {
+            deploy::command::undeploy(
+                &namespace,
+                &node_inspector_namespace,
+                delete_pvc,
+                config_file.as_deref(),
+                access,
+            )
+            .await?
+        }
+        Commands::NodeInspector { action } => {
+            deploy::command::node_inspector(action, access).await?
+        }
+        Commands::Baseline {
+            action: BaselineAction::Create { from, output },
+        } => {
+            let text = std::fs::read_to_string(&from)?;
+            let report: ClusterReport = serde_json::from_str(&text)
+                .map_err(|e| anyhow::anyhow!("{} is not a kubeowler JSON report: {}", from, e))?;
+            let baseline = baseline::Baseline::from_report(&report);
+            std::fs::write(&output, serde_json::to_string_pretty(&baseline)?)?;
+            println!(
+                "📌 Baseline with {} finding(s) from {} written to {}",
+                baseline.entries.len(),
+                from,
+                output.bright_cyan()
+            );
+        }
+        Commands::Fix {
+            input,
+            interactive,
+            config_file,
+        } => fix::command::run(&input, interactive, config_file.as_deref(), access).await?,
+        Commands::Explain { code } => run_explain_command(&code)?,
+        Commands::Compare { inputs, output } => {
+            let summaries = inputs
+                .iter()
+                .map(|p| benchmark::BenchmarkSummary::load(std::path::Path::new(p)))
+                .collect::<Result<Vec<_>>>()?;
+            let matrix = benchmark::render_comparison(&summaries);
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, matrix)?;
+                    println!(
+                        "📊 Comparison of {} clusters written to {}",
+                        summaries.len(),
+                        path.bright_cyan()
+                    );
+                }
+                None => print!("{}", matrix),
+            }
+        }
+        Commands::Schema { output } => {
+            let schema = reporting::schema::report_schema()?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, schema)?;
+                    println!("📐 Report schema written to {}", path.bright_cyan());
+                }
+                None => print!("{}", schema),
+            }
+        }
+        Commands::Docs {
+            action: DocsAction::Generate { out },
+        } => {
+            let count = inspections::issue_docs::write_bundle(std::path::Path::new(&out))?;
+            println!(
+                "📚 Wrote {} issue page(s) and README.md to {}",
+                count,
+                out.bright_cyan()
+            );
+        }
+        Commands::Preflight {
+            namespace,
+            node_inspector_namespace,
+            node_collect,
+            config_file,
+        } => {
+            let client = console::connect(config_file.as_deref(), access).await?;
+            let report = preflight::run_preflight(
+                &client,
+                namespace.as_deref(),
+                &node_inspector_namespace,
+                node_collect,
+                &[],
+            )
+            .await?;
+            print!("{}", report.render_table());
+            console::print_affected_checks(&report);
+            let denied = report.denied_required();
+            if denied > 0 {
+                anyhow::bail!("{} required permission(s) denied", denied);
+            }
+            println!("{}", "✅ All required permissions granted".bright_green());
+        }
+        Commands::UpgradePlan {
+            target,
+            cluster_name,
+            output,
+            config_file,
+            node_pool_label,
+        } => {
+            upgrade_plan::command::run(
+                &target,
+                cluster_name,
+                output,
+                config_file,
+                access,
+                &parse_pattern_list(&node_pool_label),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run_explain_command(code: &str) -> Result<()> {
+    let Some(e) = inspections::issue_docs::explain(code) else {
+        anyhow::bail!(
+            "unknown issue code '{}'; codes are listed in {}/README.md",
+            code,
+            inspections::issue_codes::DOCS_BASE_URL
+        );
+    };
+    let indent = |text: &str| {
+        text.lines()
+            .map(|l| format!("  {}", l))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    println!("{} {}", e.code.bright_cyan().bold(), e.title.bold());
+    println!("{} {}", "Severity:".bold(), e.severity);
+    println!();
+    println!("{}", "What triggers it".bold());
+    println!("{}", indent(e.summary));
+    if let Some(symptoms) = e.symptoms {
+        println!();
+        println!("{}", "Symptoms".bold());
+        println!("{}", indent(symptoms));
+    }
+    if let Some(resolution) = e.resolution {
+        println!();
+        println!("{}", "Remediation".bold());
+        println!("{}", indent(resolution));
+    }
+    if !e.commands.is_empty() {
+        println!();
+        println!("{}", "Example commands".bold());
+        for cmd in &e.commands {
+            println!("  {}", cmd.bright_green());
+        }
+    }
+    println!();
+    println!("Docs: {}", inspections::issue_codes::doc_path(e.code));
+    Ok(())
+}
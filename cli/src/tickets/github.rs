@@ -0,0 +1,110 @@
+//! GitHub Issues backend (REST v3). Token from `GITHUB_TOKEN`.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::{required_env, ticket_labels, ExistingTicket, TicketConfig, TICKET_LABEL};
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+const PAGE_SIZE: usize = 100;
+
+pub struct GitHubClient {
+    http: reqwest::Client,
+    repo_url: String,
+    token: String,
+    labels: Vec<String>,
+}
+
+impl GitHubClient {
+    pub fn from_env(owner: &str, repo: &str, config: &TicketConfig) -> Result<Self> {
+        let api = config
+            .github_api_url
+            .as_deref()
+            .unwrap_or(DEFAULT_API_URL)
+            .trim_end_matches('/');
+        Ok(Self {
+            http: reqwest::Client::new(),
+            repo_url: format!("{}/repos/{}/{}", api, owner, repo),
+            token: required_env("GITHUB_TOKEN", "needed to create GitHub issues")?,
+            labels: ticket_labels(config),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        self.http
+            .request(method, url)
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "kubeowler")
+    }
+
+    async fn send(&self, req: reqwest::RequestBuilder, what: &str) -> Result<Value> {
+        let resp = req.send().await.with_context(|| what.to_string())?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("{}: HTTP {}: {}", what, status, text);
+        }
+        resp.json().await.with_context(|| what.to_string())
+    }
+
+    /// Open issues labelled `kubeowler` (pull requests excluded).
+    pub async fn open_tickets(&self) -> Result<Vec<ExistingTicket>> {
+        let mut tickets = Vec::new();
+        for page in 1.. {
+            let url = format!(
+                "{}/issues?state=open&labels={}&per_page={}&page={}",
+                self.repo_url, TICKET_LABEL, PAGE_SIZE, page
+            );
+            let items = self
+                .send(
+                    self.request(reqwest::Method::GET, &url),
+                    "listing GitHub issues",
+                )
+                .await?;
+            let items = items.as_array().cloned().unwrap_or_default();
+            tickets.extend(
+                items
+                    .iter()
+                    .filter(|i| i.get("pull_request").is_none())
+                    .filter_map(|i| {
+                        Some(ExistingTicket {
+                            id: i["number"].as_u64()?.to_string(),
+                            body: i["body"].as_str().unwrap_or_default().to_string(),
+                        })
+                    }),
+            );
+            if items.len() < PAGE_SIZE {
+                break;
+            }
+        }
+        Ok(tickets)
+    }
+
+    /// Returns the issue URL.
+    pub async fn create(&self, title: &str, body: &str) -> Result<String> {
+        let created = self
+            .send(
+                self.request(reqwest::Method::POST, &format!("{}/issues", self.repo_url))
+                    .json(&json!({ "title": title, "body": body, "labels": self.labels })),
+                "creating GitHub issue",
+            )
+            .await?;
+        Ok(created["html_url"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Replace the body of issue `number`; returns the issue URL.
+    pub async fn update(&self, number: &str, body: &str) -> Result<String> {
+        let updated = self
+            .send(
+                self.request(
+                    reqwest::Method::PATCH,
+                    &format!("{}/issues/{}", self.repo_url, number),
+                )
+                .json(&json!({ "body": body })),
+                "updating GitHub issue",
+            )
+            .await?;
+        Ok(updated["html_url"].as_str().unwrap_or_default().to_string())
+    }
+}
@@ -0,0 +1,140 @@
+//! Jira backend (REST v2). Base URL from `tickets.jira_url` or `JIRA_URL`; `JIRA_API_TOKEN` with
+//! `JIRA_USER` for basic auth (Jira Cloud), or alone as a bearer token (Data Center PAT).
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::{required_env, ticket_labels, ExistingTicket, TicketConfig, TICKET_LABEL};
+
+const DEFAULT_ISSUE_TYPE: &str = "Bug";
+const PAGE_SIZE: u64 = 100;
+
+pub struct JiraClient {
+    http: reqwest::Client,
+    base_url: String,
+    user: Option<String>,
+    token: String,
+    project: String,
+    issue_type: String,
+    labels: Vec<String>,
+}
+
+impl JiraClient {
+    pub fn from_env(project: &str, config: &TicketConfig) -> Result<Self> {
+        let base_url = match &config.jira_url {
+            Some(url) => url.clone(),
+            None => required_env("JIRA_URL", "or set tickets.jira_url in the config file")?,
+        };
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            user: std::env::var("JIRA_USER").ok().filter(|u| !u.is_empty()),
+            token: required_env("JIRA_API_TOKEN", "needed to create Jira issues")?,
+            project: project.to_string(),
+            issue_type: config
+                .jira_issue_type
+                .clone()
+                .unwrap_or_else(|| DEFAULT_ISSUE_TYPE.to_string()),
+            labels: ticket_labels(config),
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let req = self
+            .http
+            .request(method, format!("{}{}", self.base_url, path));
+        match &self.user {
+            Some(user) => req.basic_auth(user, Some(&self.token)),
+            None => req.bearer_auth(&self.token),
+        }
+    }
+
+    async fn send(&self, req: reqwest::RequestBuilder, what: &str) -> Result<Option<Value>> {
+        let resp = req.send().await.with_context(|| what.to_string())?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!("{}: HTTP {}: {}", what, status, text);
+        }
+        if status == reqwest::StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        Ok(Some(resp.json().await.with_context(|| what.to_string())?))
+    }
+
+    fn browse_url(&self, key: &str) -> String {
+        format!("{}/browse/{}", self.base_url, key)
+    }
+
+    /// Unresolved issues in the project labelled `kubeowler`.
+    pub async fn open_tickets(&self) -> Result<Vec<ExistingTicket>> {
+        let jql = format!(
+            "project = \"{}\" AND labels = {} AND statusCategory != Done",
+            self.project, TICKET_LABEL
+        );
+        let mut tickets = Vec::new();
+        let mut start_at = 0u64;
+        loop {
+            let page = self
+                .send(
+                    self.request(reqwest::Method::GET, "/rest/api/2/search")
+                        .query(&[
+                            ("jql", jql.as_str()),
+                            ("fields", "description"),
+                            ("startAt", &start_at.to_string()),
+                            ("maxResults", &PAGE_SIZE.to_string()),
+                        ]),
+                    "searching Jira issues",
+                )
+                .await?
+                .unwrap_or_default();
+            let issues = page["issues"].as_array().cloned().unwrap_or_default();
+            tickets.extend(issues.iter().filter_map(|i| {
+                Some(ExistingTicket {
+                    id: i["key"].as_str()?.to_string(),
+                    body: i["fields"]["description"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+            }));
+            start_at += issues.len() as u64;
+            if issues.is_empty() || start_at >= page["total"].as_u64().unwrap_or(0) {
+                break;
+            }
+        }
+        Ok(tickets)
+    }
+
+    /// Returns the issue URL.
+    pub async fn create(&self, title: &str, body: &str) -> Result<String> {
+        let created = self
+            .send(
+                self.request(reqwest::Method::POST, "/rest/api/2/issue")
+                    .json(&json!({
+                        "fields": {
+                            "project": { "key": self.project },
+                            "summary": title,
+                            "description": body,
+                            "issuetype": { "name": self.issue_type },
+                            "labels": self.labels,
+                        }
+                    })),
+                "creating Jira issue",
+            )
+            .await?
+            .unwrap_or_default();
+        Ok(self.browse_url(created["key"].as_str().unwrap_or_default()))
+    }
+
+    /// Replace the description of issue `key`; returns the issue URL.
+    pub async fn update(&self, key: &str, body: &str) -> Result<String> {
+        self.send(
+            self.request(reqwest::Method::PUT, &format!("/rest/api/2/issue/{}", key))
+                .json(&json!({ "fields": { "description": body } })),
+            "updating Jira issue",
+        )
+        .await?;
+        Ok(self.browse_url(key))
+    }
+}
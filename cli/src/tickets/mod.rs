@@ -0,0 +1,292 @@
+//! Tickets for Critical findings in GitHub Issues or Jira. Findings are grouped by issue code and
+//! namespace; each ticket body carries a fingerprint of its group so re-runs update the open
+//! ticket instead of filing a duplicate.
+
+pub mod github;
+pub mod jira;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+
+use kubeowler_core::config::TicketConfig;
+use kubeowler_core::inspections::issue_codes;
+use kubeowler_core::inspections::types::{ClusterReport, IssueSeverity};
+use kubeowler_core::scoring::namespace::{issue_namespace, known_namespaces};
+
+/// Label put on every ticket; open tickets with it are searched for fingerprints.
+pub const TICKET_LABEL: &str = "kubeowler";
+/// Marker line prefix in ticket bodies.
+pub const FINGERPRINT_MARKER: &str = "kubeowler-fingerprint: ";
+/// Namespace shown for findings on cluster-scoped resources.
+const CLUSTER_SCOPE: &str = "(cluster)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TicketTarget {
+    GitHub { owner: String, repo: String },
+    Jira { project: String },
+}
+
+impl TicketTarget {
+    /// `github:<owner>/<repo>` or `jira:<PROJECT>`.
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim().split_once(':') {
+            Some(("github", path)) => match path.split_once('/') {
+                Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() => Ok(Self::GitHub {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                }),
+                _ => bail!("invalid target '{}': expected github:<owner>/<repo>", s),
+            },
+            Some(("jira", project)) if !project.is_empty() => Ok(Self::Jira {
+                project: project.to_string(),
+            }),
+            _ => bail!(
+                "invalid target '{}': expected github:<owner>/<repo> or jira:<PROJECT>",
+                s
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for TicketTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHub { owner, repo } => write!(f, "github:{}/{}", owner, repo),
+            Self::Jira { project } => write!(f, "jira:{}", project),
+        }
+    }
+}
+
+/// Critical findings of one issue code in one namespace.
+#[derive(Debug, Clone)]
+pub struct TicketGroup {
+    pub rule_id: String,
+    pub namespace: String,
+    pub title: String,
+    pub recommendation: String,
+    pub resources: BTreeSet<String>,
+    pub fingerprint: String,
+}
+
+/// Stable ID of a group: cluster, issue code and namespace (affected resources may change).
+pub fn group_fingerprint(cluster: &str, rule_id: &str, namespace: &str) -> String {
+    let digest = Sha256::digest(format!("{}\n{}\n{}", cluster, rule_id, namespace).as_bytes());
+    digest
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Group Critical issues with an issue code by (code, namespace).
+pub fn critical_groups(report: &ClusterReport) -> Vec<TicketGroup> {
+    let known = known_namespaces(&report.inspections);
+    let mut groups: BTreeMap<(String, String), TicketGroup> = BTreeMap::new();
+    for issue in report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+        .filter(|i| i.severity == IssueSeverity::Critical)
+    {
+        let Some(rule_id) = issue.rule_id.clone() else {
+            continue;
+        };
+        let namespace = issue_namespace(issue, &known).unwrap_or_else(|| CLUSTER_SCOPE.to_string());
+        let group = groups
+            .entry((rule_id.clone(), namespace.clone()))
+            .or_insert_with(|| TicketGroup {
+                title: issue_codes::short_title(&rule_id)
+                    .map(String::from)
+                    .unwrap_or_else(|| issue.description.clone()),
+                recommendation: issue.recommendation.clone(),
+                resources: BTreeSet::new(),
+                fingerprint: group_fingerprint(&report.cluster_name, &rule_id, &namespace),
+                rule_id,
+                namespace,
+            });
+        if let Some(r) = &issue.resource {
+            group.resources.insert(r.clone());
+        }
+    }
+    groups.into_values().collect()
+}
+
+pub fn ticket_title(report: &ClusterReport, group: &TicketGroup) -> String {
+    format!(
+        "[kubeowler] {} {} in {} ({})",
+        group.rule_id, group.title, group.namespace, report.cluster_name
+    )
+}
+
+/// Markdown body; the fingerprint line is an HTML comment (hidden on GitHub, plain text in Jira).
+pub fn ticket_body(report: &ClusterReport, group: &TicketGroup) -> String {
+    let mut body = format!(
+        "Critical finding **{}** ({}) reported by kubeowler.\n\n\
+         - Cluster: {}\n- Namespace: {}\n- Last seen: {} (report `{}`)\n\n\
+         Affected resources ({}):\n\n",
+        group.rule_id,
+        group.title,
+        report.cluster_name,
+        group.namespace,
+        report.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+        report.report_id,
+        group.resources.len()
+    );
+    for r in &group.resources {
+        body.push_str(&format!("- `{}`\n", r));
+    }
+    body.push_str(&format!(
+        "\nRecommendation: {}\n\nDocumentation: {}\n\n<!-- {}{} -->\n",
+        group.recommendation,
+        issue_codes::doc_path(&group.rule_id),
+        FINGERPRINT_MARKER,
+        group.fingerprint
+    ));
+    body
+}
+
+/// Fingerprint stored in a ticket body, if any.
+pub fn body_fingerprint(body: &str) -> Option<&str> {
+    let start = body.find(FINGERPRINT_MARKER)? + FINGERPRINT_MARKER.len();
+    let fp = &body[start..];
+    let end = fp
+        .find(|c: char| !c.is_ascii_hexdigit())
+        .unwrap_or(fp.len());
+    (end > 0).then(|| &fp[..end])
+}
+
+/// An open ticket created by kubeowler.
+#[derive(Debug, Clone)]
+pub struct ExistingTicket {
+    /// GitHub issue number or Jira issue key.
+    pub id: String,
+    pub body: String,
+}
+
+/// Outcome of one sync against one target.
+#[derive(Debug, Default)]
+pub struct SyncSummary {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+}
+
+enum Tracker {
+    GitHub(github::GitHubClient),
+    Jira(jira::JiraClient),
+}
+
+impl Tracker {
+    fn new(target: &TicketTarget, config: &TicketConfig) -> Result<Self> {
+        Ok(match target {
+            TicketTarget::GitHub { owner, repo } => {
+                Self::GitHub(github::GitHubClient::from_env(owner, repo, config)?)
+            }
+            TicketTarget::Jira { project } => {
+                Self::Jira(jira::JiraClient::from_env(project, config)?)
+            }
+        })
+    }
+
+    async fn open_tickets(&self) -> Result<Vec<ExistingTicket>> {
+        match self {
+            Self::GitHub(c) => c.open_tickets().await,
+            Self::Jira(c) => c.open_tickets().await,
+        }
+    }
+
+    async fn create(&self, title: &str, body: &str) -> Result<String> {
+        match self {
+            Self::GitHub(c) => c.create(title, body).await,
+            Self::Jira(c) => c.create(title, body).await,
+        }
+    }
+
+    async fn update(&self, id: &str, body: &str) -> Result<String> {
+        match self {
+            Self::GitHub(c) => c.update(id, body).await,
+            Self::Jira(c) => c.update(id, body).await,
+        }
+    }
+}
+
+/// Create a ticket per Critical group, or update the open ticket carrying its fingerprint.
+pub async fn sync_tickets(
+    report: &ClusterReport,
+    target: &TicketTarget,
+    config: &TicketConfig,
+) -> Result<SyncSummary> {
+    let groups = critical_groups(report);
+    let mut summary = SyncSummary::default();
+    if groups.is_empty() {
+        return Ok(summary);
+    }
+    let tracker = Tracker::new(target, config)?;
+    let existing: BTreeMap<String, String> = tracker
+        .open_tickets()
+        .await?
+        .into_iter()
+        .filter_map(|t| Some((body_fingerprint(&t.body)?.to_string(), t.id)))
+        .collect();
+    for group in &groups {
+        let body = ticket_body(report, group);
+        match existing.get(&group.fingerprint) {
+            Some(id) => summary.updated.push(tracker.update(id, &body).await?),
+            None => summary
+                .created
+                .push(tracker.create(&ticket_title(report, group), &body).await?),
+        }
+    }
+    Ok(summary)
+}
+
+/// Labels of new tickets: the kubeowler label plus configured extras.
+fn ticket_labels(config: &TicketConfig) -> Vec<String> {
+    std::iter::once(TICKET_LABEL.to_string())
+        .chain(config.labels.iter().cloned())
+        .collect()
+}
+
+/// Required environment variable (credentials are read only from the environment).
+fn required_env(name: &str, purpose: &str) -> Result<String> {
+    match std::env::var(name) {
+        Ok(v) if !v.is_empty() => Ok(v),
+        _ => bail!("{} is not set ({})", name, purpose),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_targets() {
+        assert_eq!(
+            TicketTarget::parse("github:acme/platform").unwrap(),
+            TicketTarget::GitHub {
+                owner: "acme".to_string(),
+                repo: "platform".to_string()
+            }
+        );
+        assert_eq!(
+            TicketTarget::parse("jira:OPS").unwrap(),
+            TicketTarget::Jira {
+                project: "OPS".to_string()
+            }
+        );
+        assert!(TicketTarget::parse("github:acme").is_err());
+        assert!(TicketTarget::parse("gitlab:acme/x").is_err());
+    }
+
+    #[test]
+    fn fingerprint_round_trips_through_body() {
+        let fp = group_fingerprint("prod", "POD-003", "payments");
+        assert_eq!(fp.len(), 16);
+        assert_eq!(fp, group_fingerprint("prod", "POD-003", "payments"));
+        assert_ne!(fp, group_fingerprint("prod", "POD-003", "web"));
+        let body = format!("text\n\n<!-- {}{} -->\n", FINGERPRINT_MARKER, fp);
+        assert_eq!(body_fingerprint(&body), Some(fp.as_str()));
+        assert_eq!(body_fingerprint("no marker"), None);
+    }
+}
@@ -0,0 +1,453 @@
+//! `kubeowler tui`: browse an inspection run in the terminal. Module scores in a sidebar, the
+//! issue list filtered by module and severity, and the selected issue's details; `r` re-runs.
+
+use std::future::Future;
+
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use kubeowler_core::inspections::types::{ClusterReport, HealthStatus, Issue, IssueSeverity};
+
+/// Pane that receives the up/down keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Modules,
+    Issues,
+}
+
+/// What the event loop should do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Rerun,
+    Quit,
+}
+
+/// Dashboard state; kept free of terminal I/O so navigation can be tested.
+pub struct App {
+    pub report: ClusterReport,
+    /// Index into the sidebar: 0 is "All modules", then one entry per inspection.
+    pub module: usize,
+    /// Show only this severity; None shows all.
+    pub severity: Option<IssueSeverity>,
+    /// Index into [`App::visible_issues`].
+    pub selected: usize,
+    pub focus: Focus,
+}
+
+impl App {
+    pub fn new(report: ClusterReport) -> Self {
+        Self {
+            report,
+            module: 0,
+            severity: None,
+            selected: 0,
+            focus: Focus::Issues,
+        }
+    }
+
+    /// Issues of the selected module(s) matching the severity filter, most severe first.
+    pub fn visible_issues(&self) -> Vec<&Issue> {
+        let mut issues: Vec<&Issue> = self
+            .report
+            .inspections
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.module == 0 || self.module == i + 1)
+            .flat_map(|(_, r)| r.summary.issues.iter())
+            .filter(|issue| self.severity.as_ref().is_none_or(|s| &issue.severity == s))
+            .collect();
+        issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+        issues
+    }
+
+    /// Swap in the report of a re-run, keeping the selected module by name.
+    pub fn replace_report(&mut self, report: ClusterReport) {
+        let module_name = self.module.checked_sub(1).and_then(|i| {
+            self.report
+                .inspections
+                .get(i)
+                .map(|r| r.inspection_type.clone())
+        });
+        self.module = module_name
+            .and_then(|name| {
+                report
+                    .inspections
+                    .iter()
+                    .position(|r| r.inspection_type == name)
+            })
+            .map_or(0, |i| i + 1);
+        self.report = report;
+        self.selected = 0;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> Action {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('r') => return Action::Rerun,
+            KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                self.focus = match self.focus {
+                    Focus::Modules => Focus::Issues,
+                    Focus::Issues => Focus::Modules,
+                }
+            }
+            KeyCode::Char('a') => self.set_severity(None),
+            KeyCode::Char('c') => self.set_severity(Some(IssueSeverity::Critical)),
+            KeyCode::Char('w') => self.set_severity(Some(IssueSeverity::Warning)),
+            KeyCode::Char('i') => self.set_severity(Some(IssueSeverity::Info)),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn set_severity(&mut self, severity: Option<IssueSeverity>) {
+        self.severity = severity;
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        match self.focus {
+            Focus::Modules => {
+                let len = self.report.inspections.len() + 1;
+                self.module = step(self.module, delta, len);
+                self.selected = 0;
+            }
+            Focus::Issues => {
+                let len = self.visible_issues().len();
+                self.selected = step(self.selected, delta, len);
+            }
+        }
+    }
+}
+
+/// `index + delta` clamped to `0..len`.
+fn step(index: usize, delta: isize, len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    index.saturating_add_signed(delta).min(len - 1)
+}
+
+fn severity_color(severity: &IssueSeverity) -> Color {
+    match severity {
+        IssueSeverity::Critical => Color::Red,
+        IssueSeverity::Warning => Color::Yellow,
+        IssueSeverity::Info => Color::Blue,
+    }
+}
+
+/// Colour of `score` by its health status under the report's thresholds.
+fn score_color(report: &ClusterReport, score: f64) -> Color {
+    match report.metadata.scoring_profile.thresholds.status(score) {
+        HealthStatus::Excellent => Color::Green,
+        HealthStatus::Good | HealthStatus::Fair => Color::Yellow,
+        HealthStatus::Poor | HealthStatus::Critical => Color::Red,
+    }
+}
+
+fn pane(title: String, focused: bool) -> Block<'static> {
+    let style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(style)
+        .title(title)
+}
+
+fn highlight() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [sidebar, main] =
+        Layout::horizontal([Constraint::Length(34), Constraint::Min(0)]).areas(body);
+    let [issue_area, detail_area] =
+        Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(main);
+
+    let report = &app.report;
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                format!(" {} ", report.cluster_name),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "score {:.1} ({})",
+                    report.overall_score,
+                    report.executive_summary.health_status.grade()
+                ),
+                Style::default().fg(score_color(report, report.overall_score)),
+            ),
+            Span::raw(format!(
+                "  run {}",
+                report.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+            )),
+        ])),
+        header,
+    );
+
+    draw_modules(frame, app, sidebar);
+    draw_issues(frame, app, issue_area);
+    draw_detail(frame, app, detail_area);
+
+    frame.render_widget(
+        Paragraph::new(
+            " ↑/↓ move  Tab switch pane  a/c/w/i all/critical/warning/info  r re-run  q quit",
+        )
+        .style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+}
+
+fn draw_modules(frame: &mut Frame, app: &App, area: Rect) {
+    let mut items = vec![ListItem::new("All modules")];
+    items.extend(app.report.inspections.iter().map(|r| {
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("{:>5.1} ", r.overall_score),
+                Style::default().fg(score_color(&app.report, r.overall_score)),
+            ),
+            Span::raw(r.inspection_type.clone()),
+        ]))
+    }));
+    let mut state = ListState::default().with_selected(Some(app.module));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(pane(" Modules ".to_string(), app.focus == Focus::Modules))
+            .highlight_style(highlight()),
+        area,
+        &mut state,
+    );
+}
+
+fn draw_issues(frame: &mut Frame, app: &App, area: Rect) {
+    let issues = app.visible_issues();
+    let filter = match &app.severity {
+        Some(s) => format!("{:?}", s),
+        None => "all".to_string(),
+    };
+    let items: Vec<ListItem> = issues
+        .iter()
+        .map(|issue| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<9}", format!("{:?}", issue.severity)),
+                    Style::default().fg(severity_color(&issue.severity)),
+                ),
+                Span::raw(format!("{:<10} ", issue.rule_id.as_deref().unwrap_or("-"))),
+                Span::raw(issue.description.clone()),
+            ]))
+        })
+        .collect();
+    let mut state =
+        ListState::default().with_selected((!issues.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(pane(
+                format!(" Issues ({}, {}) ", issues.len(), filter),
+                app.focus == Focus::Issues,
+            ))
+            .highlight_style(highlight()),
+        area,
+        &mut state,
+    );
+}
+
+fn draw_detail(frame: &mut Frame, app: &App, area: Rect) {
+    let issues = app.visible_issues();
+    let lines = match issues.get(app.selected) {
+        Some(issue) => {
+            let mut lines = vec![
+                Line::from(Span::styled(
+                    issue.description.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Line::from(format!(
+                    "Code: {}   Category: {}   Resource: {}",
+                    issue.rule_id.as_deref().unwrap_or("-"),
+                    issue.category,
+                    issue.resource.as_deref().unwrap_or("-")
+                )),
+                Line::from(""),
+                Line::from(format!("Recommendation: {}", issue.recommendation)),
+            ];
+            if !issue.remediation_commands.is_empty() {
+                lines.push(Line::from(""));
+                lines.extend(
+                    issue
+                        .remediation_commands
+                        .iter()
+                        .map(|c| Line::from(format!("  $ {}", c))),
+                );
+            }
+            lines
+        }
+        None => vec![Line::from("No issues match the current filter.")],
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(pane(" Details ".to_string(), false))
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+/// Show `report` until the user quits; `r` leaves the dashboard, awaits `rerun` (which may
+/// print progress) and comes back with its report.
+pub async fn run<F, Fut>(report: ClusterReport, mut rerun: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<ClusterReport>>,
+{
+    let mut app = App::new(report);
+    loop {
+        let mut terminal = ratatui::init();
+        let action = loop {
+            if let Err(e) = terminal.draw(|f| draw(f, &app)) {
+                ratatui::restore();
+                return Err(e.into());
+            }
+            match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                    match app.handle_key(key.code) {
+                        Action::None => {}
+                        action => break action,
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    ratatui::restore();
+                    return Err(e.into());
+                }
+            }
+        };
+        ratatui::restore();
+        if action == Action::Quit {
+            return Ok(());
+        }
+        match rerun().await {
+            Ok(report) => app.replace_report(report),
+            Err(e) => {
+                eprintln!("Re-run failed: {} (showing the previous run)", e);
+                std::thread::sleep(std::time::Duration::from_secs(2));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kubeowler_core::inspections::types::{InspectionResult, InspectionSummary};
+
+    fn issue(severity: IssueSeverity, code: &str) -> Issue {
+        Issue::new(severity, "Pod", code, code, None, "")
+    }
+
+    fn module(name: &str, issues: Vec<Issue>) -> InspectionResult {
+        InspectionResult {
+            inspection_type: name.to_string(),
+            timestamp: chrono::Utc::now(),
+            overall_score: 80.0,
+            checks: Vec::new(),
+            summary: InspectionSummary {
+                total_checks: 0,
+                passed_checks: 0,
+                warning_checks: 0,
+                critical_checks: 0,
+                error_checks: 0,
+                issues,
+            },
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        }
+    }
+
+    fn report(modules: Vec<InspectionResult>) -> ClusterReport {
+        let json = serde_json::json!({
+            "cluster_name": "test",
+            "report_id": "r",
+            "timestamp": "2026-01-01T00:00:00Z",
+            "overall_score": 80.0,
+            "inspections": [],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        });
+        let mut report: ClusterReport = serde_json::from_value(json).unwrap();
+        report.inspections = modules;
+        report
+    }
+
+    #[test]
+    fn filters_by_module_and_severity() {
+        let mut app = App::new(report(vec![
+            module(
+                "Pod Status",
+                vec![
+                    issue(IssueSeverity::Warning, "POD-002"),
+                    issue(IssueSeverity::Critical, "POD-001"),
+                ],
+            ),
+            module("Security", vec![issue(IssueSeverity::Info, "SEC-009")]),
+        ]));
+        let codes = |app: &App| -> Vec<String> {
+            app.visible_issues()
+                .iter()
+                .map(|i| i.description.clone())
+                .collect()
+        };
+        assert_eq!(codes(&app), vec!["POD-001", "POD-002", "SEC-009"]);
+
+        app.handle_key(KeyCode::Char('w'));
+        assert_eq!(codes(&app), vec!["POD-002"]);
+        app.handle_key(KeyCode::Char('a'));
+
+        app.handle_key(KeyCode::Tab);
+        assert_eq!(app.focus, Focus::Modules);
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.module, 2);
+        assert_eq!(codes(&app), vec!["SEC-009"]);
+
+        assert_eq!(app.handle_key(KeyCode::Char('r')), Action::Rerun);
+        assert_eq!(app.handle_key(KeyCode::Char('q')), Action::Quit);
+    }
+
+    #[test]
+    fn rerun_keeps_selected_module_by_name() {
+        let mut app = App::new(report(vec![
+            module("Pod Status", Vec::new()),
+            module("Security", Vec::new()),
+        ]));
+        app.module = 2;
+        app.replace_report(report(vec![module("Security", Vec::new())]));
+        assert_eq!(app.module, 1);
+        app.replace_report(report(vec![module("Pod Status", Vec::new())]));
+        assert_eq!(app.module, 0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Cluster addon detection from container images and the version each addon needs on the
+//! target Kubernetes release.
+
+use super::deprecations::KubeVersion;
+use kubeowler_core::inspections::registry::image_name_tag;
+
+/// CoreDNS version shipped by kubeadm for a Kubernetes minor (1.x); used as the minimum.
+const COREDNS_BY_MINOR: &[(u32, &str)] = &[
+    (25, "1.9.3"),
+    (26, "1.9.3"),
+    (27, "1.10.1"),
+    (28, "1.10.1"),
+    (29, "1.11.1"),
+    (30, "1.11.1"),
+    (31, "1.11.3"),
+    (32, "1.11.3"),
+    (33, "1.12.0"),
+];
+
+/// Addons whose compatibility depends on the Kubernetes version but has no fixed rule here;
+/// they are listed for a manual check against the vendor's compatibility matrix.
+const VERIFY_ADDONS: &[&str] = &[
+    "metrics-server",
+    "ingress-nginx",
+    "cilium",
+    "calico-node",
+    "amazon-k8s-cni",
+    "aws-load-balancer-controller",
+    "csi-provisioner",
+    "csi-attacher",
+    "csi-resizer",
+    "csi-snapshotter",
+    "cert-manager-controller",
+    "kube-state-metrics",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddonAction {
+    /// Must be bumped to at least this version.
+    Bump(String),
+    /// Check the vendor compatibility matrix.
+    Verify,
+    /// Already compatible.
+    Ok,
+}
+
+/// An addon found in the cluster and what the upgrade needs from it.
+#[derive(Debug, Clone)]
+pub struct AddonCheck {
+    pub addon: String,
+    /// `Kind namespace/name` of the workload running it.
+    pub workload: String,
+    pub current: String,
+    pub action: AddonAction,
+}
+
+/// Numeric `[major, minor, patch]` from a tag like `v1.11.1`, `1.28.4-eksbuild.1`.
+fn tag_version(tag: &str) -> Option<[u32; 3]> {
+    let core = tag.trim_start_matches('v');
+    let mut out = [0u32; 3];
+    let mut n = 0;
+    for part in core.split('.').take(3) {
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+        out[n] = digits.parse().ok()?;
+        n += 1;
+    }
+    (n >= 2).then_some(out)
+}
+
+/// Required action for an addon image on the target release, or None if the image is not a
+/// known addon.
+pub fn evaluate_addon(image: &str, target: KubeVersion) -> Option<(String, String, AddonAction)> {
+    let (name, tag) = image_name_tag(image);
+    let current = tag_version(tag);
+    let action = match name {
+        // Must run the same minor as the control plane (kube-proxy) / the cluster
+        // (cluster-autoscaler releases track Kubernetes minors).
+        "kube-proxy" | "cluster-autoscaler" => match current {
+            Some([maj, min, _]) if maj == target.major && min >= target.minor => AddonAction::Ok,
+            _ => AddonAction::Bump(format!("{}.x", target.to_string().trim_start_matches('v'))),
+        },
+        "coredns" => {
+            let required = COREDNS_BY_MINOR
+                .iter()
+                .rev()
+                .find(|(m, _)| *m <= target.minor)
+                .map(|(_, v)| *v);
+            match (required, current) {
+                (Some(req), Some(cur)) if cur < tag_version(req).unwrap_or_default() => {
+                    AddonAction::Bump(req.to_string())
+                }
+                (Some(_), Some(_)) => AddonAction::Ok,
+                _ => AddonAction::Verify,
+            }
+        }
+        n if VERIFY_ADDONS.contains(&n) => AddonAction::Verify,
+        _ => return None,
+    };
+    Some((name.to_string(), tag.to_string(), action))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_known_addons() {
+        let target = KubeVersion::parse("1.30").unwrap();
+        let (name, tag, action) =
+            evaluate_addon("registry.k8s.io/coredns/coredns:v1.10.1", target).unwrap();
+        assert_eq!((name.as_str(), tag.as_str()), ("coredns", "v1.10.1"));
+        assert_eq!(action, AddonAction::Bump("1.11.1".to_string()));
+        let (_, _, action) = evaluate_addon(
+            "602401143452.dkr.ecr.us-west-2.amazonaws.com/eks/kube-proxy:v1.29.0-eksbuild.1",
+            target,
+        )
+        .unwrap();
+        assert_eq!(action, AddonAction::Bump("1.30.x".to_string()));
+        let (_, _, action) = evaluate_addon(
+            "registry.k8s.io/metrics-server/metrics-server:v0.7.0",
+            target,
+        )
+        .unwrap();
+        assert_eq!(action, AddonAction::Verify);
+        assert!(evaluate_addon("nginx:1.25", target).is_none());
+    }
+}
@@ -0,0 +1,56 @@
+//! `kubeowler upgrade-plan`: build the plan against the cluster and write it as Markdown.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::cli::ClusterAccess;
+
+use crate::check::output::sanitize_cluster_name;
+use crate::console::connect;
+use crate::upgrade_plan;
+
+/// Run `upgrade-plan` to `target` and write the plan to `output` (or a name from the cluster).
+pub async fn run(
+    target: &str,
+    cluster_name: Option<String>,
+    output: Option<String>,
+    config_file: Option<String>,
+    access: &ClusterAccess,
+    pool_labels: &[String],
+) -> Result<()> {
+    let client = connect(config_file.as_deref(), access).await?;
+    let cluster_name =
+        cluster_name.unwrap_or_else(|| client.cluster_name().unwrap_or("default").to_string());
+
+    print!("🧭 Building upgrade plan to {}... ", target);
+    let plan =
+        match upgrade_plan::build_upgrade_plan(&client, target, &cluster_name, pool_labels).await {
+            Ok(plan) => {
+                println!("{}", "✅ Done".bright_green());
+                plan
+            }
+            Err(e) => {
+                println!("{}", "❌ Failed".bright_red());
+                eprintln!("Error: {}", e);
+                return Err(e);
+            }
+        };
+
+    let path = output.unwrap_or_else(|| {
+        format!(
+            "{}-upgrade-plan-{}.md",
+            sanitize_cluster_name(&cluster_name),
+            plan.target.to_string().trim_start_matches('v')
+        )
+    });
+    std::fs::write(&path, upgrade_plan::render_markdown(&plan))?;
+    println!(
+        "   {} removed-API usage(s), {} drain blocker(s), {} addon(s), {} node pool(s)",
+        plan.deprecated_usages.len(),
+        plan.drain_blockers.len(),
+        plan.addons.len(),
+        plan.node_pools.len()
+    );
+    println!("   Plan: {}", path.bright_cyan());
+    Ok(())
+}
@@ -0,0 +1,332 @@
+//! Kubernetes versions, upgrade hops and the removed-API table from the upstream deprecation
+//! guide (https://kubernetes.io/docs/reference/using-api/deprecation-guide/).
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+/// A Kubernetes `major.minor` version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KubeVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl KubeVersion {
+    /// Parses `1.30`, `v1.30` or a full git version such as `v1.28.3-eks-4f4795d`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().trim_start_matches('v');
+        let mut parts = s.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor_str: String = parts
+            .next()?
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        let minor = minor_str.parse().ok()?;
+        Some(Self { major, minor })
+    }
+
+    /// Maximum number of minor versions a kubelet may lag behind the API server
+    /// (3 since v1.28, 2 before).
+    pub fn kubelet_skew(&self) -> u32 {
+        if self.minor >= 28 {
+            3
+        } else {
+            2
+        }
+    }
+}
+
+impl fmt::Display for KubeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{}", self.major, self.minor)
+    }
+}
+
+/// Control plane hops from `current` to `target`: minor versions cannot be skipped.
+pub fn upgrade_path(current: KubeVersion, target: KubeVersion) -> Result<Vec<KubeVersion>> {
+    if current.major != target.major {
+        bail!(
+            "Cannot plan an upgrade across major versions ({} -> {})",
+            current,
+            target
+        );
+    }
+    if target <= current {
+        bail!(
+            "Target {} is not newer than the cluster version {}",
+            target,
+            current
+        );
+    }
+    Ok((current.minor + 1..=target.minor)
+        .map(|minor| KubeVersion {
+            major: target.major,
+            minor,
+        })
+        .collect())
+}
+
+/// An API version removed in a given release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemovedApi {
+    pub group_version: &'static str,
+    pub kind: &'static str,
+    /// Plural resource name as reported by `apiserver_requested_deprecated_apis`.
+    pub resource: &'static str,
+    /// Minor version (of 1.x) in which the API is no longer served.
+    pub removed_in: u32,
+    pub replacement: &'static str,
+}
+
+const fn api(
+    group_version: &'static str,
+    kind: &'static str,
+    resource: &'static str,
+    removed_in: u32,
+    replacement: &'static str,
+) -> RemovedApi {
+    RemovedApi {
+        group_version,
+        kind,
+        resource,
+        removed_in,
+        replacement,
+    }
+}
+
+#[rustfmt::skip]
+pub const REMOVED_APIS: &[RemovedApi] = &[
+    api("extensions/v1beta1", "Deployment", "deployments", 16, "apps/v1"),
+    api("extensions/v1beta1", "DaemonSet", "daemonsets", 16, "apps/v1"),
+    api("extensions/v1beta1", "ReplicaSet", "replicasets", 16, "apps/v1"),
+    api("extensions/v1beta1", "NetworkPolicy", "networkpolicies", 16, "networking.k8s.io/v1"),
+    api("apps/v1beta1", "Deployment", "deployments", 16, "apps/v1"),
+    api("apps/v1beta1", "StatefulSet", "statefulsets", 16, "apps/v1"),
+    api("apps/v1beta2", "Deployment", "deployments", 16, "apps/v1"),
+    api("apps/v1beta2", "DaemonSet", "daemonsets", 16, "apps/v1"),
+    api("apps/v1beta2", "StatefulSet", "statefulsets", 16, "apps/v1"),
+    api("apps/v1beta2", "ReplicaSet", "replicasets", 16, "apps/v1"),
+    api("extensions/v1beta1", "Ingress", "ingresses", 22, "networking.k8s.io/v1"),
+    api("networking.k8s.io/v1beta1", "Ingress", "ingresses", 22, "networking.k8s.io/v1"),
+    api("networking.k8s.io/v1beta1", "IngressClass", "ingressclasses", 22, "networking.k8s.io/v1"),
+    api("apiextensions.k8s.io/v1beta1", "CustomResourceDefinition", "customresourcedefinitions", 22, "apiextensions.k8s.io/v1"),
+    api("admissionregistration.k8s.io/v1beta1", "MutatingWebhookConfiguration", "mutatingwebhookconfigurations", 22, "admissionregistration.k8s.io/v1"),
+    api("admissionregistration.k8s.io/v1beta1", "ValidatingWebhookConfiguration", "validatingwebhookconfigurations", 22, "admissionregistration.k8s.io/v1"),
+    api("apiregistration.k8s.io/v1beta1", "APIService", "apiservices", 22, "apiregistration.k8s.io/v1"),
+    api("rbac.authorization.k8s.io/v1beta1", "ClusterRole", "clusterroles", 22, "rbac.authorization.k8s.io/v1"),
+    api("rbac.authorization.k8s.io/v1beta1", "ClusterRoleBinding", "clusterrolebindings", 22, "rbac.authorization.k8s.io/v1"),
+    api("rbac.authorization.k8s.io/v1beta1", "Role", "roles", 22, "rbac.authorization.k8s.io/v1"),
+    api("rbac.authorization.k8s.io/v1beta1", "RoleBinding", "rolebindings", 22, "rbac.authorization.k8s.io/v1"),
+    api("scheduling.k8s.io/v1beta1", "PriorityClass", "priorityclasses", 22, "scheduling.k8s.io/v1"),
+    api("storage.k8s.io/v1beta1", "CSIDriver", "csidrivers", 22, "storage.k8s.io/v1"),
+    api("storage.k8s.io/v1beta1", "CSINode", "csinodes", 22, "storage.k8s.io/v1"),
+    api("storage.k8s.io/v1beta1", "StorageClass", "storageclasses", 22, "storage.k8s.io/v1"),
+    api("storage.k8s.io/v1beta1", "VolumeAttachment", "volumeattachments", 22, "storage.k8s.io/v1"),
+    api("certificates.k8s.io/v1beta1", "CertificateSigningRequest", "certificatesigningrequests", 22, "certificates.k8s.io/v1"),
+    api("coordination.k8s.io/v1beta1", "Lease", "leases", 22, "coordination.k8s.io/v1"),
+    api("batch/v1beta1", "CronJob", "cronjobs", 25, "batch/v1"),
+    api("discovery.k8s.io/v1beta1", "EndpointSlice", "endpointslices", 25, "discovery.k8s.io/v1"),
+    api("events.k8s.io/v1beta1", "Event", "events", 25, "events.k8s.io/v1"),
+    api("autoscaling/v2beta1", "HorizontalPodAutoscaler", "horizontalpodautoscalers", 25, "autoscaling/v2"),
+    api("policy/v1beta1", "PodDisruptionBudget", "poddisruptionbudgets", 25, "policy/v1"),
+    api("policy/v1beta1", "PodSecurityPolicy", "podsecuritypolicies", 25, "Pod Security Admission"),
+    api("node.k8s.io/v1beta1", "RuntimeClass", "runtimeclasses", 25, "node.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta1", "FlowSchema", "flowschemas", 26, "flowcontrol.apiserver.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta1", "PriorityLevelConfiguration", "prioritylevelconfigurations", 26, "flowcontrol.apiserver.k8s.io/v1"),
+    api("autoscaling/v2beta2", "HorizontalPodAutoscaler", "horizontalpodautoscalers", 26, "autoscaling/v2"),
+    api("storage.k8s.io/v1beta1", "CSIStorageCapacity", "csistoragecapacities", 27, "storage.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta2", "FlowSchema", "flowschemas", 29, "flowcontrol.apiserver.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta2", "PriorityLevelConfiguration", "prioritylevelconfigurations", 29, "flowcontrol.apiserver.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta3", "FlowSchema", "flowschemas", 32, "flowcontrol.apiserver.k8s.io/v1"),
+    api("flowcontrol.apiserver.k8s.io/v1beta3", "PriorityLevelConfiguration", "prioritylevelconfigurations", 32, "flowcontrol.apiserver.k8s.io/v1"),
+];
+
+/// APIs removed by one of the hops in (current, target].
+pub fn removed_between(current: KubeVersion, target: KubeVersion) -> Vec<&'static RemovedApi> {
+    REMOVED_APIS
+        .iter()
+        .filter(|a| a.removed_in > current.minor && a.removed_in <= target.minor)
+        .collect()
+}
+
+/// One object whose manifests or writers still use a removed API version.
+#[derive(Debug, Clone)]
+pub struct DeprecatedApiUsage {
+    pub api: &'static RemovedApi,
+    /// `namespace/name` (or `name` for cluster-scoped objects).
+    pub object: String,
+    /// Who writes the old version: field managers, Helm release, or `kubectl apply`.
+    pub owners: Vec<String>,
+}
+
+/// Removed-API usages recorded on an object: managedFields entries written with a removed
+/// apiVersion and a `kubectl apply` last-applied manifest with a removed apiVersion.
+pub fn usage_in_meta(
+    meta: &ObjectMeta,
+    kind: &str,
+    removed: &[&'static RemovedApi],
+) -> Option<DeprecatedApiUsage> {
+    let matching = |gv: &str| {
+        removed
+            .iter()
+            .copied()
+            .find(|a| a.kind == kind && a.group_version == gv)
+    };
+    let mut found: Option<&'static RemovedApi> = None;
+    let mut owners: Vec<String> = Vec::new();
+    for mf in meta.managed_fields.iter().flatten() {
+        if let Some(a) = mf.api_version.as_deref().and_then(matching) {
+            found = Some(a);
+            let manager = mf.manager.clone().unwrap_or_else(|| "unknown".to_string());
+            if !owners.contains(&manager) {
+                owners.push(manager);
+            }
+        }
+    }
+    let last_applied = meta
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get("kubectl.kubernetes.io/last-applied-configuration"))
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok())
+        .and_then(|v| {
+            v.get("apiVersion")
+                .and_then(|a| a.as_str())
+                .and_then(matching)
+        });
+    if let Some(a) = last_applied {
+        found = Some(a);
+        owners.push("kubectl apply".to_string());
+    }
+    let api = found?;
+    if let Some(release) = meta
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get("meta.helm.sh/release-name"))
+    {
+        owners.insert(0, format!("Helm release {}", release));
+    }
+    let name = meta.name.as_deref().unwrap_or("unknown");
+    let object = match meta.namespace.as_deref() {
+        Some(ns) => format!("{}/{}", ns, name),
+        None => name.to_string(),
+    };
+    Some(DeprecatedApiUsage {
+        api,
+        object,
+        owners,
+    })
+}
+
+/// A removed API still being requested, from the API server's
+/// `apiserver_requested_deprecated_apis` metric.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecatedApiRequest {
+    pub group_version: String,
+    pub resource: String,
+    pub removed_release: String,
+}
+
+fn label<'a>(labels: &'a str, key: &str) -> Option<&'a str> {
+    labels.split(',').find_map(|kv| {
+        let (k, v) = kv.split_once('=')?;
+        (k.trim() == key).then(|| v.trim().trim_matches('"'))
+    })
+}
+
+/// Parses `apiserver_requested_deprecated_apis` samples from API server `/metrics` text,
+/// keeping APIs removed in a release after `current` up to and including `target`.
+pub fn parse_deprecated_requests(
+    metrics: &str,
+    current: KubeVersion,
+    target: KubeVersion,
+) -> Vec<DeprecatedApiRequest> {
+    let mut out: Vec<DeprecatedApiRequest> = Vec::new();
+    for line in metrics.lines() {
+        let Some(rest) = line.strip_prefix("apiserver_requested_deprecated_apis{") else {
+            continue;
+        };
+        let Some((labels, _)) = rest.split_once('}') else {
+            continue;
+        };
+        let removed_release = label(labels, "removed_release").unwrap_or("");
+        let Some(removed) = KubeVersion::parse(removed_release) else {
+            continue;
+        };
+        if removed <= current || removed > target {
+            continue;
+        }
+        let group = label(labels, "group").unwrap_or("");
+        let version = label(labels, "version").unwrap_or("");
+        let req = DeprecatedApiRequest {
+            group_version: if group.is_empty() {
+                version.to_string()
+            } else {
+                format!("{}/{}", group, version)
+            },
+            resource: label(labels, "resource").unwrap_or("").to_string(),
+            removed_release: removed_release.to_string(),
+        };
+        if !out.contains(&req) {
+            out.push(req);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry;
+
+    #[test]
+    fn parses_versions_and_builds_hops() {
+        let cur = KubeVersion::parse("v1.27.9-eks-5e0fdde").unwrap();
+        let target = KubeVersion::parse("1.30").unwrap();
+        let path: Vec<String> = upgrade_path(cur, target)
+            .unwrap()
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        assert_eq!(path, vec!["v1.28", "v1.29", "v1.30"]);
+        assert!(upgrade_path(target, cur).is_err());
+        let removed = removed_between(KubeVersion::parse("1.24").unwrap(), cur);
+        assert!(removed.iter().any(|a| a.group_version == "batch/v1beta1"));
+        assert!(removed
+            .iter()
+            .all(|a| a.removed_in > 24 && a.removed_in <= 27));
+    }
+
+    #[test]
+    fn finds_usage_in_managed_fields_and_metrics() {
+        let removed = removed_between(
+            KubeVersion::parse("1.24").unwrap(),
+            KubeVersion::parse("1.26").unwrap(),
+        );
+        let meta = ObjectMeta {
+            name: Some("web".to_string()),
+            namespace: Some("app".to_string()),
+            managed_fields: Some(vec![ManagedFieldsEntry {
+                manager: Some("argocd-controller".to_string()),
+                api_version: Some("autoscaling/v2beta2".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let usage = usage_in_meta(&meta, "HorizontalPodAutoscaler", &removed).unwrap();
+        assert_eq!(usage.object, "app/web");
+        assert_eq!(usage.owners, vec!["argocd-controller"]);
+        assert!(usage_in_meta(&meta, "CronJob", &removed).is_none());
+
+        let metrics = "# HELP x\napiserver_requested_deprecated_apis{group=\"policy\",removed_release=\"1.25\",resource=\"poddisruptionbudgets\",subresource=\"\",version=\"v1beta1\"} 1\napiserver_requested_deprecated_apis{group=\"flowcontrol.apiserver.k8s.io\",removed_release=\"1.29\",resource=\"flowschemas\",subresource=\"\",version=\"v1beta2\"} 1\n";
+        let reqs = parse_deprecated_requests(
+            metrics,
+            KubeVersion::parse("1.24").unwrap(),
+            KubeVersion::parse("1.26").unwrap(),
+        );
+        assert_eq!(reqs.len(), 1);
+        assert_eq!(reqs[0].group_version, "policy/v1beta1");
+    }
+}
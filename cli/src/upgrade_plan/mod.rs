@@ -0,0 +1,396 @@
+//! `upgrade-plan`: turns cluster state into an ordered Markdown checklist for upgrading to a
+//! target Kubernetes version — removed APIs to migrate (with the objects and writers still using
+//! them), drain blockers, addons to bump, then control plane hops and node pools in sequence.
+
+pub mod addons;
+pub mod command;
+pub mod deprecations;
+mod render;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::ListParams;
+use kube::Resource;
+
+use addons::{evaluate_addon, AddonCheck};
+use deprecations::{
+    parse_deprecated_requests, removed_between, upgrade_path, usage_in_meta, DeprecatedApiRequest,
+    DeprecatedApiUsage, KubeVersion, RemovedApi,
+};
+use kubeowler_core::inspections::node_pools::{pool_for_labels, UNASSIGNED_POOL};
+use kubeowler_core::k8s::K8sClient;
+
+pub use render::render_markdown;
+
+/// Pool name used for control plane nodes of self-managed clusters.
+pub const CONTROL_PLANE_POOL: &str = "control-plane";
+
+/// Something that makes `kubectl drain` fail or lose data.
+#[derive(Debug, Clone)]
+pub struct DrainBlocker {
+    /// `PodDisruptionBudget`, `Unmanaged pod` or `emptyDir pod`.
+    pub kind: &'static str,
+    pub object: String,
+    pub detail: String,
+}
+
+/// One node pool to upgrade, in plan order.
+#[derive(Debug, Clone)]
+pub struct PoolStep {
+    pub pool: String,
+    pub nodes: Vec<String>,
+    pub kubelet_versions: Vec<String>,
+    pub control_plane: bool,
+    /// First control plane hop the pool's oldest kubelet would fall out of the supported skew for;
+    /// the pool must be upgraded before the control plane moves to that version.
+    pub before_hop: Option<KubeVersion>,
+}
+
+/// Everything the Markdown plan is rendered from.
+#[derive(Debug, Clone)]
+pub struct UpgradePlan {
+    pub cluster_name: String,
+    pub current_git_version: String,
+    pub current: KubeVersion,
+    pub target: KubeVersion,
+    pub path: Vec<KubeVersion>,
+    pub removed_apis: Vec<&'static RemovedApi>,
+    pub deprecated_usages: Vec<DeprecatedApiUsage>,
+    /// None when the API server `/metrics` endpoint is not readable.
+    pub deprecated_requests: Option<Vec<DeprecatedApiRequest>>,
+    pub drain_blockers: Vec<DrainBlocker>,
+    pub addons: Vec<AddonCheck>,
+    pub node_pools: Vec<PoolStep>,
+    pub generated_at: DateTime<Utc>,
+}
+
+fn is_control_plane(node: &Node) -> bool {
+    node.metadata.labels.as_ref().is_some_and(|l| {
+        l.contains_key("node-role.kubernetes.io/control-plane")
+            || l.contains_key("node-role.kubernetes.io/master")
+    })
+}
+
+/// Node pools in upgrade order: control plane nodes first, then pools that must move before a
+/// control plane hop, then the rest smallest first (the first pool acts as a canary).
+pub fn order_node_pools(
+    nodes: &[Node],
+    pool_labels: &[String],
+    path: &[KubeVersion],
+) -> Vec<PoolStep> {
+    let mut pools: BTreeMap<String, (bool, Vec<String>, BTreeSet<String>)> = BTreeMap::new();
+    for node in nodes {
+        let control_plane = is_control_plane(node);
+        let pool = if control_plane {
+            CONTROL_PLANE_POOL.to_string()
+        } else {
+            pool_for_labels(node.metadata.labels.as_ref(), pool_labels)
+                .unwrap_or_else(|| UNASSIGNED_POOL.to_string())
+        };
+        let entry = pools.entry(pool).or_default();
+        entry.0 |= control_plane;
+        entry.1.push(node.metadata.name.clone().unwrap_or_default());
+        if let Some(info) = node.status.as_ref().and_then(|s| s.node_info.as_ref()) {
+            entry.2.insert(info.kubelet_version.clone());
+        }
+    }
+    let mut steps: Vec<PoolStep> = pools
+        .into_iter()
+        .map(|(pool, (control_plane, nodes, kubelets))| {
+            let oldest = kubelets.iter().filter_map(|v| KubeVersion::parse(v)).min();
+            let before_hop = oldest.and_then(|old| {
+                path.iter()
+                    .copied()
+                    .find(|hop| old.minor + hop.kubelet_skew() < hop.minor)
+            });
+            PoolStep {
+                pool,
+                nodes,
+                kubelet_versions: kubelets.into_iter().collect(),
+                control_plane,
+                before_hop,
+            }
+        })
+        .collect();
+    steps.sort_by(|a, b| {
+        (
+            !a.control_plane,
+            a.before_hop.is_none(),
+            a.before_hop,
+            a.nodes.len(),
+            &a.pool,
+        )
+            .cmp(&(
+                !b.control_plane,
+                b.before_hop.is_none(),
+                b.before_hop,
+                b.nodes.len(),
+                &b.pool,
+            ))
+    });
+    steps
+}
+
+/// PDBs that currently allow no disruption, pods without a controller, and pods with emptyDir
+/// volumes (DaemonSet pods are skipped: drain ignores them with `--ignore-daemonsets`).
+pub fn drain_blockers(pdbs: &[PodDisruptionBudget], pods: &[Pod]) -> Vec<DrainBlocker> {
+    let mut out = Vec::new();
+    for pdb in pdbs {
+        let Some(status) = pdb.status.as_ref() else {
+            continue;
+        };
+        if status.disruptions_allowed == 0 && status.expected_pods > 0 {
+            out.push(DrainBlocker {
+                kind: "PodDisruptionBudget",
+                object: format!(
+                    "{}/{}",
+                    pdb.metadata.namespace.as_deref().unwrap_or(""),
+                    pdb.metadata.name.as_deref().unwrap_or("unknown")
+                ),
+                detail: format!(
+                    "allows 0 disruptions (currentHealthy {}, desiredHealthy {})",
+                    status.current_healthy, status.desired_healthy
+                ),
+            });
+        }
+    }
+    for pod in pods {
+        let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+        if !matches!(phase, Some("Running") | Some("Pending")) {
+            continue;
+        }
+        let meta = &pod.metadata;
+        let object = format!(
+            "{}/{}",
+            meta.namespace.as_deref().unwrap_or(""),
+            meta.name.as_deref().unwrap_or("unknown")
+        );
+        let mirror = meta
+            .annotations
+            .as_ref()
+            .is_some_and(|a| a.contains_key("kubernetes.io/config.mirror"));
+        let owners = meta.owner_references.as_deref().unwrap_or(&[]);
+        if owners.is_empty() {
+            if !mirror {
+                out.push(DrainBlocker {
+                    kind: "Unmanaged pod",
+                    object,
+                    detail: "not owned by a controller; drain needs --force and the pod is not recreated".to_string(),
+                });
+            }
+            continue;
+        }
+        if owners.iter().any(|o| o.kind == "DaemonSet") {
+            continue;
+        }
+        let empty_dir = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.volumes.as_ref())
+            .is_some_and(|v| v.iter().any(|vol| vol.empty_dir.is_some()));
+        if empty_dir {
+            out.push(DrainBlocker {
+                kind: "emptyDir pod",
+                object,
+                detail: "uses emptyDir; drain needs --delete-emptydir-data and the data is lost"
+                    .to_string(),
+            });
+        }
+    }
+    out
+}
+
+async fn list_usages<K>(
+    client: &K8sClient,
+    kind: &str,
+    removed: &[&'static RemovedApi],
+    out: &mut Vec<DeprecatedApiUsage>,
+) where
+    K: Resource<DynamicType = ()> + Clone + serde::de::DeserializeOwned + std::fmt::Debug,
+    K::Scope: 'static,
+{
+    if !removed.iter().any(|a| a.kind == kind) {
+        return;
+    }
+    let api: kube::Api<K> = kube::Api::all(client.client().clone());
+    if let Ok(list) = client.list_all(&api, &ListParams::default()).await {
+        out.extend(
+            list.items
+                .iter()
+                .filter_map(|o| usage_in_meta(o.meta(), kind, removed)),
+        );
+    }
+}
+
+/// Collect cluster state and build the plan for upgrading to `target` (e.g. `1.30`).
+pub async fn build_upgrade_plan(
+    client: &K8sClient,
+    target: &str,
+    cluster_name: &str,
+    pool_labels: &[String],
+) -> Result<UpgradePlan> {
+    use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+    use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+    use k8s_openapi::api::batch::v1::CronJob;
+    use k8s_openapi::api::networking::v1::Ingress;
+
+    let target = KubeVersion::parse(target)
+        .ok_or_else(|| anyhow!("Invalid target version {:?}; expected e.g. 1.30", target))?;
+    let current_git_version = client
+        .server_version()
+        .await?
+        .ok_or_else(|| anyhow!("API server did not report a version"))?;
+    let current = KubeVersion::parse(&current_git_version)
+        .ok_or_else(|| anyhow!("Cannot parse API server version {}", current_git_version))?;
+    let path = upgrade_path(current, target)?;
+    let removed = removed_between(current, target);
+
+    let mut deprecated_usages = Vec::new();
+    list_usages::<Deployment>(client, "Deployment", &removed, &mut deprecated_usages).await;
+    list_usages::<DaemonSet>(client, "DaemonSet", &removed, &mut deprecated_usages).await;
+    list_usages::<StatefulSet>(client, "StatefulSet", &removed, &mut deprecated_usages).await;
+    list_usages::<Ingress>(client, "Ingress", &removed, &mut deprecated_usages).await;
+    list_usages::<CronJob>(client, "CronJob", &removed, &mut deprecated_usages).await;
+    list_usages::<HorizontalPodAutoscaler>(
+        client,
+        "HorizontalPodAutoscaler",
+        &removed,
+        &mut deprecated_usages,
+    )
+    .await;
+    list_usages::<PodDisruptionBudget>(
+        client,
+        "PodDisruptionBudget",
+        &removed,
+        &mut deprecated_usages,
+    )
+    .await;
+    let deprecated_requests = client
+        .apiserver_metrics()
+        .await?
+        .map(|m| parse_deprecated_requests(&m, current, target));
+
+    let lp = ListParams::default();
+    let pods = client.list_all(&client.pods(None), &lp).await?.items;
+    let pdbs = client
+        .list_unselected(&client.pod_disruption_budgets(None), &lp)
+        .await?
+        .items;
+    let nodes = client.list_all(&client.nodes(), &lp).await?.items;
+
+    let mut addons = Vec::new();
+    let deployments = client.list_all(&client.deployments(None), &lp).await?.items;
+    let daemon_sets = client.list_all(&client.daemon_sets(None), &lp).await?.items;
+    let templates = deployments
+        .iter()
+        .map(|d| {
+            (
+                "Deployment",
+                &d.metadata,
+                d.spec.as_ref().map(|s| &s.template),
+            )
+        })
+        .chain(daemon_sets.iter().map(|d| {
+            (
+                "DaemonSet",
+                &d.metadata,
+                d.spec.as_ref().map(|s| &s.template),
+            )
+        }));
+    for (kind, meta, template) in templates {
+        let containers = template
+            .and_then(|t| t.spec.as_ref())
+            .map(|s| s.containers.as_slice())
+            .unwrap_or(&[]);
+        for c in containers {
+            let Some(image) = c.image.as_deref() else {
+                continue;
+            };
+            if let Some((addon, current, action)) = evaluate_addon(image, target) {
+                addons.push(AddonCheck {
+                    addon,
+                    workload: format!(
+                        "{} {}/{}",
+                        kind,
+                        meta.namespace.as_deref().unwrap_or(""),
+                        meta.name.as_deref().unwrap_or("unknown")
+                    ),
+                    current,
+                    action,
+                });
+            }
+        }
+    }
+
+    Ok(UpgradePlan {
+        cluster_name: cluster_name.to_string(),
+        current_git_version,
+        current,
+        target,
+        node_pools: order_node_pools(&nodes, pool_labels, &path),
+        path,
+        removed_apis: removed,
+        deprecated_usages,
+        deprecated_requests,
+        drain_blockers: drain_blockers(&pdbs, &pods),
+        addons,
+        generated_at: Utc::now(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{NodeStatus, NodeSystemInfo};
+
+    fn node(name: &str, labels: &[(&str, &str)], kubelet: &str) -> Node {
+        let mut n = Node {
+            status: Some(NodeStatus {
+                node_info: Some(NodeSystemInfo {
+                    kubelet_version: kubelet.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        n.metadata.name = Some(name.to_string());
+        n.metadata.labels = Some(
+            labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        n
+    }
+
+    #[test]
+    fn orders_control_plane_then_lagging_then_smallest_pools() {
+        let nodes = vec![
+            node(
+                "cp1",
+                &[("node-role.kubernetes.io/control-plane", "")],
+                "v1.28.4",
+            ),
+            node("a1", &[("agentpool", "apps")], "v1.28.4"),
+            node("a2", &[("agentpool", "apps")], "v1.28.4"),
+            node("b1", &[("agentpool", "batch")], "v1.28.4"),
+            node("o1", &[("agentpool", "old")], "v1.26.1"),
+            node("o2", &[("agentpool", "old")], "v1.26.1"),
+            node("o3", &[("agentpool", "old")], "v1.26.1"),
+        ];
+        let path = upgrade_path(
+            KubeVersion::parse("1.28").unwrap(),
+            KubeVersion::parse("1.30").unwrap(),
+        )
+        .unwrap();
+        let steps = order_node_pools(&nodes, &["agentpool".to_string()], &path);
+        let order: Vec<&str> = steps.iter().map(|s| s.pool.as_str()).collect();
+        assert_eq!(order, vec![CONTROL_PLANE_POOL, "old", "batch", "apps"]);
+        assert_eq!(steps[1].before_hop, KubeVersion::parse("1.30"));
+    }
+}
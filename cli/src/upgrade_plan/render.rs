@@ -0,0 +1,236 @@
+//! Markdown rendering of an [`UpgradePlan`] as an ordered checklist.
+
+use super::addons::AddonAction;
+use super::UpgradePlan;
+
+/// Items listed per drain-blocker kind before the rest is summarized.
+const MAX_BLOCKERS_PER_KIND: usize = 20;
+
+/// Render the plan: deprecated APIs, drain blockers and addons first (they gate the upgrade),
+/// then control plane hops, node pools and verification.
+pub fn render_markdown(plan: &UpgradePlan) -> String {
+    let mut out = String::new();
+    let path = std::iter::once(plan.current)
+        .chain(plan.path.iter().copied())
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(" → ");
+
+    out.push_str(&format!(
+        "# Upgrade plan: {} {} → {}\n\n",
+        plan.cluster_name, plan.current_git_version, plan.target
+    ));
+    out.push_str(&format!(
+        "**Generated**: {}  \n**Upgrade path**: {} (the control plane cannot skip minor versions)\n\n",
+        plan.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        path
+    ));
+    out.push_str("Work through the sections in order; each item is a checkbox.\n\n");
+
+    // 1. Deprecated APIs
+    out.push_str("## 1. Migrate removed APIs\n\n");
+    if plan.removed_apis.is_empty() {
+        out.push_str(&format!(
+            "No API versions are removed between {} and {}.\n\n",
+            plan.current, plan.target
+        ));
+    } else {
+        if plan.deprecated_usages.is_empty() {
+            out.push_str(
+                "No object found whose manifests or writers use an API removed on this path.\n\n",
+            );
+        }
+        for u in &plan.deprecated_usages {
+            out.push_str(&format!(
+                "- [ ] {} `{}`: migrate `{}` → `{}` (removed in v1.{}); written by {}\n",
+                u.api.kind,
+                u.object,
+                u.api.group_version,
+                u.api.replacement,
+                u.api.removed_in,
+                if u.owners.is_empty() {
+                    "unknown".to_string()
+                } else {
+                    u.owners.join(", ")
+                }
+            ));
+        }
+        match &plan.deprecated_requests {
+            Some(reqs) if !reqs.is_empty() => {
+                out.push_str("\nClients still requesting removed APIs (`apiserver_requested_deprecated_apis`; find them in the audit log by `userAgent`):\n\n");
+                for r in reqs {
+                    out.push_str(&format!(
+                        "- [ ] `{}` {} (removed in {})\n",
+                        r.group_version, r.resource, r.removed_release
+                    ));
+                }
+            }
+            Some(_) => out.push_str(
+                "\nThe API server reports no requests to APIs removed on this path since its last restart.\n",
+            ),
+            None => out.push_str(
+                "\nAPI server `/metrics` not readable: requests to removed APIs by clients (CI pipelines, operators) were not checked.\n",
+            ),
+        }
+        out.push_str("\nAPIs removed on this path:\n\n| API | Kind | Removed in | Replacement |\n|-----|------|------------|-------------|\n");
+        for a in &plan.removed_apis {
+            out.push_str(&format!(
+                "| `{}` | {} | v1.{} | `{}` |\n",
+                a.group_version, a.kind, a.removed_in, a.replacement
+            ));
+        }
+        out.push('\n');
+    }
+
+    // 2. Drain blockers
+    out.push_str("## 2. Resolve drain blockers\n\n");
+    if plan.drain_blockers.is_empty() {
+        out.push_str(
+            "No PodDisruptionBudget, unmanaged pod or emptyDir volume blocks node drains.\n\n",
+        );
+    } else {
+        for kind in ["PodDisruptionBudget", "Unmanaged pod", "emptyDir pod"] {
+            let items: Vec<_> = plan
+                .drain_blockers
+                .iter()
+                .filter(|b| b.kind == kind)
+                .collect();
+            for b in items.iter().take(MAX_BLOCKERS_PER_KIND) {
+                out.push_str(&format!("- [ ] {} `{}` {}\n", b.kind, b.object, b.detail));
+            }
+            if items.len() > MAX_BLOCKERS_PER_KIND {
+                out.push_str(&format!(
+                    "- … and {} more {} item(s)\n",
+                    items.len() - MAX_BLOCKERS_PER_KIND,
+                    kind
+                ));
+            }
+        }
+        out.push('\n');
+    }
+
+    // 3. Addons
+    out.push_str("## 3. Bump addons\n\n");
+    if plan.addons.is_empty() {
+        out.push_str("No known addon detected.\n\n");
+    } else {
+        for a in &plan.addons {
+            let action = match &a.action {
+                AddonAction::Bump(v) => format!("bump `{}` → `{}`", a.current, v),
+                AddonAction::Verify => format!(
+                    "`{}`: verify compatibility with {} in the vendor matrix",
+                    a.current, plan.target
+                ),
+                AddonAction::Ok => format!("`{}` is compatible", a.current),
+            };
+            let check = if a.action == AddonAction::Ok {
+                "x"
+            } else {
+                " "
+            };
+            out.push_str(&format!(
+                "- [{}] **{}** ({}): {}\n",
+                check, a.addon, a.workload, action
+            ));
+        }
+        out.push_str("\nkube-proxy and cluster-autoscaler are bumped together with the control plane hop that reaches their version.\n\n");
+    }
+
+    // 4. Control plane
+    out.push_str("## 4. Upgrade the control plane\n\n");
+    let mut from = plan.current;
+    for hop in &plan.path {
+        out.push_str(&format!("- [ ] Control plane {} → {}\n", from, hop));
+        for p in plan
+            .node_pools
+            .iter()
+            .filter(|p| p.before_hop == Some(*hop))
+        {
+            out.push_str(&format!(
+                "  - [ ] First upgrade node pool **{}** (kubelet {}): it would exceed the supported kubelet skew\n",
+                p.pool,
+                p.kubelet_versions.join(", ")
+            ));
+        }
+        from = *hop;
+    }
+    out.push('\n');
+
+    // 5. Node pools
+    out.push_str("## 5. Upgrade node pools (in order)\n\n");
+    if plan.node_pools.is_empty() {
+        out.push_str("No nodes found.\n\n");
+    } else {
+        for (i, p) in plan.node_pools.iter().enumerate() {
+            let note = if p.control_plane {
+                " — control plane nodes, upgraded with step 4"
+            } else if p.before_hop.is_some() {
+                " — must move before a control plane hop (see step 4)"
+            } else if i == plan.node_pools.iter().filter(|p| p.control_plane).count() {
+                " — smallest pool, use as canary"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "{}. [ ] **{}** ({} node(s); kubelet {}) → {}{}\n",
+                i + 1,
+                p.pool,
+                p.nodes.len(),
+                p.kubelet_versions.join(", "),
+                plan.target,
+                note
+            ));
+        }
+        out.push_str("\nDrain one node at a time (`kubectl drain <node> --ignore-daemonsets`) or use surge upgrades, and wait for workloads to become Ready before the next node.\n\n");
+    }
+
+    // 6. Verify
+    out.push_str("## 6. Verify\n\n");
+    out.push_str("- [ ] All nodes Ready and on the target kubelet version (`kubectl get nodes`)\n");
+    out.push_str("- [ ] Re-run `kubeowler check` and compare with the pre-upgrade report\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::upgrade_plan::deprecations::{removed_between, upgrade_path, KubeVersion};
+    use crate::upgrade_plan::{DrainBlocker, PoolStep};
+    use chrono::Utc;
+
+    #[test]
+    fn renders_sections_in_order() {
+        let current = KubeVersion::parse("1.24").unwrap();
+        let target = KubeVersion::parse("1.26").unwrap();
+        let plan = UpgradePlan {
+            cluster_name: "prod".to_string(),
+            current_git_version: "v1.24.17".to_string(),
+            current,
+            target,
+            path: upgrade_path(current, target).unwrap(),
+            removed_apis: removed_between(current, target),
+            deprecated_usages: vec![],
+            deprecated_requests: None,
+            drain_blockers: vec![DrainBlocker {
+                kind: "PodDisruptionBudget",
+                object: "app/web".to_string(),
+                detail: "allows 0 disruptions".to_string(),
+            }],
+            addons: vec![],
+            node_pools: vec![PoolStep {
+                pool: "apps".to_string(),
+                nodes: vec!["n1".to_string()],
+                kubelet_versions: vec!["v1.24.17".to_string()],
+                control_plane: false,
+                before_hop: None,
+            }],
+            generated_at: Utc::now(),
+        };
+        let md = render_markdown(&plan);
+        let pos = |s: &str| md.find(s).unwrap();
+        assert!(pos("## 1. Migrate") < pos("## 2. Resolve") && pos("## 2.") < pos("## 5."));
+        assert!(md.contains("v1.24 → v1.25 → v1.26"));
+        assert!(md.contains("`batch/v1beta1`"));
+        assert!(md.contains("- [ ] PodDisruptionBudget `app/web`"));
+    }
+}
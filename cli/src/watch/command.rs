@@ -0,0 +1,119 @@
+//! `kubeowler watch`: re-run the inspection on an interval and print what changed.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use kubeowler_core::cli::{ClusterAccess, InspectionType, NodeCollectMode};
+use kubeowler_core::inspections::types::IssueSeverity;
+use kubeowler_core::inspections::{InspectionOptions, InspectionRunner};
+use kubeowler_core::{reporting, scoring};
+
+use crate::console::{connect, console_notify};
+use crate::watch;
+
+/// Resolved options of the `watch` subcommand.
+pub struct WatchOptions {
+    pub interval: std::time::Duration,
+    pub score_threshold: f64,
+    pub log_file: Option<String>,
+    pub severities: Vec<IssueSeverity>,
+    pub cluster_name: Option<String>,
+    pub namespace: Option<String>,
+    pub node_inspector_namespace: String,
+    pub node_collect: NodeCollectMode,
+    pub scoring_profile: scoring::ScoringProfile,
+    pub config_file: Option<String>,
+    pub access: ClusterAccess,
+}
+
+/// Run `watch` until Ctrl-C.
+pub async fn run(opts: WatchOptions) -> Result<()> {
+    let client = connect(opts.config_file.as_deref(), &opts.access).await?;
+    let runner = InspectionRunner::new(client)
+        .with_notify(console_notify())
+        .with_options(InspectionOptions {
+            node_collect: opts.node_collect,
+            scoring_profile: opts.scoring_profile,
+            ..InspectionOptions::default()
+        });
+    println!(
+        "👀 Watching every {}s (Ctrl-C to stop); changes only are printed{}",
+        opts.interval.as_secs(),
+        opts.log_file
+            .as_deref()
+            .map(|p| format!(" and appended to {}", p))
+            .unwrap_or_default()
+    );
+
+    let mut previous: Option<watch::Snapshot> = None;
+    loop {
+        let run = runner
+            .run_inspections(
+                InspectionType::All,
+                opts.namespace.as_deref(),
+                &opts.node_inspector_namespace,
+                opts.cluster_name.as_deref(),
+            )
+            .await
+            .and_then(|r| reporting::redact::redact_report(&r));
+        match run {
+            Ok(report) => {
+                let current = watch::Snapshot::from_report(&report, &opts.severities);
+                // (text, new critical finding) for this run, None when nothing changed.
+                let entry = match &previous {
+                    None => Some((
+                        format!(
+                            "[{}] baseline: score {:.1}, {} critical, {} warning, {} info finding(s)\n",
+                            report.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+                            current.score,
+                            current.count(IssueSeverity::Critical),
+                            current.count(IssueSeverity::Warning),
+                            current.count(IssueSeverity::Info)
+                        ),
+                        false,
+                    )),
+                    Some(prev) => {
+                        let diff = watch::diff_snapshots(prev, &current, opts.score_threshold);
+                        (!diff.is_empty()).then(|| {
+                            (
+                                watch::format_diff(&diff, report.timestamp),
+                                diff.has_new_critical(),
+                            )
+                        })
+                    }
+                };
+                if let Some((text, critical)) = entry {
+                    if critical {
+                        print!("{}", text.bright_red());
+                    } else {
+                        print!("{}", text);
+                    }
+                    if let Some(path) = opts.log_file.as_deref() {
+                        append_to_file(path, &text)?;
+                    }
+                }
+                previous = Some(current);
+            }
+            // Keep watching through transient API errors; the next run compares with the last good one.
+            Err(e) => eprintln!("{} inspection run failed: {}", "⚠️".yellow(), e),
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(opts.interval) => {}
+            _ = tokio::signal::ctrl_c() => {
+                println!("Stopped.");
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn append_to_file(path: &str, text: &str) -> Result<()> {
+    use std::io::Write;
+    let mut f = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    f.write_all(text.as_bytes())?;
+    Ok(())
+}
@@ -0,0 +1,322 @@
+//! Watch mode: compare consecutive inspection runs and report only what changed
+//! (new findings, resolved findings, severity changes, score shifts).
+
+pub mod command;
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+
+use kubeowler_core::inspections::types::{ClusterReport, IssueSeverity};
+
+/// Default watch interval.
+pub const DEFAULT_WATCH_INTERVAL: &str = "10m";
+/// Default overall score change (points) that is reported on its own.
+pub const DEFAULT_SCORE_THRESHOLD: f64 = 1.0;
+
+/// One tracked finding. Identity is rule (or category) plus resource, so a finding whose
+/// description carries a changing value (usage %, counts) is not reported as new on every run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: IssueSeverity,
+    pub rule: String,
+    pub resource: String,
+    pub description: String,
+}
+
+impl Finding {
+    fn key(&self) -> String {
+        format!("{}|{}", self.rule, self.resource)
+    }
+}
+
+/// Findings and overall score of one run.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub score: f64,
+    pub findings: BTreeMap<String, Finding>,
+}
+
+impl Snapshot {
+    /// Collect issues of the given severities from a report.
+    pub fn from_report(report: &ClusterReport, severities: &[IssueSeverity]) -> Self {
+        let mut findings: BTreeMap<String, Finding> = BTreeMap::new();
+        for issue in report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+        {
+            if !severities.contains(&issue.severity) {
+                continue;
+            }
+            let finding = Finding {
+                severity: issue.severity.clone(),
+                rule: issue
+                    .rule_id
+                    .clone()
+                    .unwrap_or_else(|| issue.category.clone()),
+                resource: issue
+                    .resource
+                    .clone()
+                    .unwrap_or_else(|| issue.description.clone()),
+                description: issue.description.clone(),
+            };
+            // Keep the most severe entry when the same rule hits a resource twice.
+            match findings.get(&finding.key()) {
+                Some(existing) if existing.severity >= finding.severity => {}
+                _ => {
+                    findings.insert(finding.key(), finding);
+                }
+            }
+        }
+        Self {
+            score: report.overall_score,
+            findings,
+        }
+    }
+
+    pub fn count(&self, severity: IssueSeverity) -> usize {
+        self.findings
+            .values()
+            .filter(|f| f.severity == severity)
+            .count()
+    }
+}
+
+/// Changes between two snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct WatchDiff {
+    pub new: Vec<Finding>,
+    pub resolved: Vec<Finding>,
+    /// (previous severity, finding with its current severity)
+    pub severity_changed: Vec<(IssueSeverity, Finding)>,
+    pub score_before: f64,
+    pub score_after: f64,
+    /// True when the score moved by more than the threshold.
+    pub score_shifted: bool,
+}
+
+impl WatchDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty()
+            && self.resolved.is_empty()
+            && self.severity_changed.is_empty()
+            && !self.score_shifted
+    }
+
+    pub fn has_new_critical(&self) -> bool {
+        self.new
+            .iter()
+            .chain(self.severity_changed.iter().map(|(_, f)| f))
+            .any(|f| f.severity == IssueSeverity::Critical)
+    }
+}
+
+pub fn diff_snapshots(prev: &Snapshot, cur: &Snapshot, score_threshold: f64) -> WatchDiff {
+    let mut out = WatchDiff {
+        score_before: prev.score,
+        score_after: cur.score,
+        score_shifted: (cur.score - prev.score).abs() > score_threshold,
+        ..Default::default()
+    };
+    for (key, finding) in &cur.findings {
+        match prev.findings.get(key) {
+            None => out.new.push(finding.clone()),
+            Some(old) if old.severity != finding.severity => out
+                .severity_changed
+                .push((old.severity.clone(), finding.clone())),
+            Some(_) => {}
+        }
+    }
+    for (key, finding) in &prev.findings {
+        if !cur.findings.contains_key(key) {
+            out.resolved.push(finding.clone());
+        }
+    }
+    // Critical first, then by rule.
+    out.new
+        .sort_by(|a, b| b.severity.cmp(&a.severity).then(a.rule.cmp(&b.rule)));
+    out
+}
+
+fn severity_label(s: &IssueSeverity) -> &'static str {
+    match s {
+        IssueSeverity::Critical => "CRITICAL",
+        IssueSeverity::Warning => "WARNING",
+        IssueSeverity::Info => "INFO",
+    }
+}
+
+/// Plain-text change log entry (also appended to the watch log file).
+pub fn format_diff(diff: &WatchDiff, at: DateTime<Utc>) -> String {
+    let mut out = format!(
+        "[{}] score {:.1} → {:.1} ({:+.1}); {} new, {} resolved, {} changed severity\n",
+        at.format("%Y-%m-%d %H:%M:%S UTC"),
+        diff.score_before,
+        diff.score_after,
+        diff.score_after - diff.score_before,
+        diff.new.len(),
+        diff.resolved.len(),
+        diff.severity_changed.len()
+    );
+    for f in &diff.new {
+        out.push_str(&format!(
+            "  + {} {} {}: {}\n",
+            severity_label(&f.severity),
+            f.rule,
+            f.resource,
+            f.description
+        ));
+    }
+    for (old, f) in &diff.severity_changed {
+        out.push_str(&format!(
+            "  ~ {}→{} {} {}: {}\n",
+            severity_label(old),
+            severity_label(&f.severity),
+            f.rule,
+            f.resource,
+            f.description
+        ));
+    }
+    for f in &diff.resolved {
+        out.push_str(&format!(
+            "  - resolved {} {} {}\n",
+            severity_label(&f.severity),
+            f.rule,
+            f.resource
+        ));
+    }
+    out
+}
+
+/// Severities tracked for a `--level` value ("all" or comma-separated info,warning,critical).
+pub fn tracked_severities(level: &str) -> Vec<IssueSeverity> {
+    let level = level.trim().to_lowercase();
+    if level == "all" {
+        return vec![
+            IssueSeverity::Info,
+            IssueSeverity::Warning,
+            IssueSeverity::Critical,
+        ];
+    }
+    let mut out = Vec::new();
+    for part in level.split(',') {
+        let s = match part.trim() {
+            "info" => IssueSeverity::Info,
+            "warning" => IssueSeverity::Warning,
+            "critical" => IssueSeverity::Critical,
+            _ => continue,
+        };
+        if !out.contains(&s) {
+            out.push(s);
+        }
+    }
+    if out.is_empty() {
+        vec![IssueSeverity::Warning, IssueSeverity::Critical]
+    } else {
+        out
+    }
+}
+
+/// Parse an interval such as `30s`, `10m`, `1h` or `1h30m` (a bare number is seconds).
+pub fn parse_interval(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    if let Ok(secs) = s.parse::<u64>() {
+        if secs == 0 {
+            bail!("invalid interval '{}': must be greater than zero", s);
+        }
+        return Ok(Duration::from_secs(secs));
+    }
+    let mut total = 0u64;
+    let mut num = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => bail!("invalid interval '{}': unknown unit '{}'", s, c),
+        };
+        let n: u64 = num
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid interval '{}'", s))?;
+        total += n * unit;
+        num.clear();
+    }
+    if !num.is_empty() || total == 0 {
+        bail!("invalid interval '{}': use e.g. 30s, 10m, 1h", s);
+    }
+    Ok(Duration::from_secs(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(score: f64, findings: &[(IssueSeverity, &str, &str)]) -> Snapshot {
+        let findings = findings
+            .iter()
+            .map(|(severity, rule, resource)| {
+                let f = Finding {
+                    severity: severity.clone(),
+                    rule: rule.to_string(),
+                    resource: resource.to_string(),
+                    description: format!("{} on {}", rule, resource),
+                };
+                (f.key(), f)
+            })
+            .collect();
+        Snapshot { score, findings }
+    }
+
+    #[test]
+    fn diff_reports_new_resolved_and_score_shift() {
+        let prev = snapshot(
+            80.0,
+            &[
+                (IssueSeverity::Warning, "POD-001", "app/web"),
+                (IssueSeverity::Warning, "NODE-008", "node-1"),
+            ],
+        );
+        let cur = snapshot(
+            78.5,
+            &[
+                (IssueSeverity::Critical, "NODE-008", "node-1"),
+                (IssueSeverity::Critical, "POD-007", "app/api"),
+            ],
+        );
+        let d = diff_snapshots(&prev, &cur, 1.0);
+        assert_eq!(d.new.len(), 1);
+        assert_eq!(d.resolved[0].rule, "POD-001");
+        assert_eq!(d.severity_changed[0].0, IssueSeverity::Warning);
+        assert!(d.score_shifted && d.has_new_critical());
+
+        let same = diff_snapshots(
+            &cur,
+            &snapshot(
+                78.0,
+                &[
+                    (IssueSeverity::Critical, "NODE-008", "node-1"),
+                    (IssueSeverity::Critical, "POD-007", "app/api"),
+                ],
+            ),
+            1.0,
+        );
+        assert!(same.is_empty());
+    }
+
+    #[test]
+    fn parses_intervals() {
+        assert_eq!(parse_interval("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_interval("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_interval("45").unwrap(), Duration::from_secs(45));
+        assert!(parse_interval("10x").is_err());
+        assert!(parse_interval("m").is_err());
+    }
+}
@@ -0,0 +1,735 @@
+use clap::Parser;
+use kubeowler::args::{
+    Args, BaselineAction, Commands, CsvLayout, DocsAction, NodeInspectorAction, ReportFormat,
+};
+use kubeowler_core::cli::{
+    HtmlTheme, InspectionType, Lang, NodeCollectMode, OptionValue, ReportSection,
+    ScoringProfileName,
+};
+
+#[test]
+fn test_cli_parsing() {
+    // Default check
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(matches!(args.command, Commands::Check { .. }));
+
+    // With namespace
+    let args = Args::try_parse_from(["kubeowler", "check", "-n", "kube-system"]).unwrap();
+    let Commands::Check { namespace, .. } = &args.command else {
+        panic!("expected check command");
+    };
+    assert_eq!(namespace.as_deref(), Some("kube-system"));
+
+    // With custom output
+    let args = Args::try_parse_from(["kubeowler", "check", "-o", "custom-report.md"]).unwrap();
+    let Commands::Check { output, .. } = &args.command else {
+        panic!("expected check command");
+    };
+    assert_eq!(output.as_deref(), Some("custom-report.md"));
+
+    // With format
+    let args = Args::try_parse_from(["kubeowler", "check", "-f", "json"]).unwrap();
+    assert!(matches!(args.command, Commands::Check { .. }));
+}
+
+#[test]
+fn test_upgrade_plan_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "upgrade-plan", "--target", "1.30"]).unwrap();
+    let Commands::UpgradePlan { target, output, .. } = &args.command else {
+        panic!("expected upgrade-plan command");
+    };
+    assert_eq!(target, "1.30");
+    assert!(output.is_none());
+    assert!(Args::try_parse_from(["kubeowler", "upgrade-plan"]).is_err());
+}
+
+#[test]
+fn test_watch_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "watch",
+        "--interval",
+        "5m",
+        "--log-file",
+        "watch.log",
+    ])
+    .unwrap();
+    let Commands::Watch {
+        interval,
+        score_threshold,
+        log_file,
+        level,
+        ..
+    } = args.command
+    else {
+        panic!("expected watch command");
+    };
+    assert_eq!(interval, "5m");
+    assert_eq!(score_threshold, 1.0);
+    assert_eq!(log_file.as_deref(), Some("watch.log"));
+    assert_eq!(level, "warning,critical");
+}
+
+#[test]
+fn test_tui_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "tui", "-n", "payments"]).unwrap();
+    let Commands::Tui {
+        namespace,
+        node_inspector_namespace,
+        node_collect,
+        ..
+    } = args.command
+    else {
+        panic!("expected tui command");
+    };
+    assert_eq!(namespace.as_deref(), Some("payments"));
+    assert_eq!(node_inspector_namespace, "kubeowler");
+    assert_eq!(node_collect, NodeCollectMode::Logs);
+}
+
+#[test]
+fn test_schema_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "schema", "-o", "schema.json"]).unwrap();
+    let Commands::Schema { output } = args.command else {
+        panic!("expected schema command");
+    };
+    assert_eq!(output.as_deref(), Some("schema.json"));
+}
+
+#[test]
+fn test_serve_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "serve",
+        "--history-dir",
+        "/reports",
+        "--listen",
+        "0.0.0.0:9000",
+    ])
+    .unwrap();
+    let Commands::Serve {
+        history_dir,
+        listen,
+        level,
+        ..
+    } = args.command
+    else {
+        panic!("expected serve command");
+    };
+    assert_eq!(history_dir, "/reports");
+    assert_eq!(listen.port(), 9000);
+    assert_eq!(level, "warning,critical");
+    assert!(Args::try_parse_from(["kubeowler", "serve"]).is_err());
+}
+
+#[test]
+fn test_deploy_cron_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "deploy-cron",
+        "--schedule",
+        "0 6 * * *",
+        "--output-pvc",
+        "reports",
+        "--",
+        "-f",
+        "html",
+    ])
+    .unwrap();
+    let Commands::DeployCron {
+        schedule,
+        output_pvc,
+        pvc_size,
+        check_args,
+        ..
+    } = args.command
+    else {
+        panic!("expected deploy-cron command");
+    };
+    assert_eq!(schedule, "0 6 * * *");
+    assert_eq!(output_pvc.as_deref(), Some("reports"));
+    assert!(pvc_size.is_none());
+    assert_eq!(check_args, vec!["-f", "html"]);
+    // --pvc-size needs a PVC name
+    assert!(Args::try_parse_from(["kubeowler", "deploy-cron", "--pvc-size", "1Gi"]).is_err());
+    assert!(matches!(
+        Args::try_parse_from(["kubeowler", "undeploy", "--delete-pvc"])
+            .unwrap()
+            .command,
+        Commands::Undeploy {
+            delete_pvc: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_node_inspector_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "node-inspector",
+        "install",
+        "--toleration",
+        "dedicated=gpu:NoSchedule",
+        "--toleration",
+        "node-role.kubernetes.io/control-plane",
+        "--memory-limit",
+        "128Mi",
+    ])
+    .unwrap();
+    let Commands::NodeInspector {
+        action: NodeInspectorAction::Install { deploy },
+    } = args.command
+    else {
+        panic!("expected node-inspector install");
+    };
+    assert_eq!(deploy.namespace, "kubeowler");
+    assert_eq!(deploy.tolerations.len(), 2);
+    assert_eq!(deploy.memory_limit, "128Mi");
+    assert_eq!(deploy.cpu_limit, "100m");
+    assert!(matches!(
+        Args::try_parse_from(["kubeowler", "node-inspector", "status", "-n", "ops"])
+            .unwrap()
+            .command,
+        Commands::NodeInspector {
+            action: NodeInspectorAction::Status { .. }
+        }
+    ));
+}
+
+#[test]
+fn test_node_collect_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check", "--node-collect", "exec"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            node_collect: NodeCollectMode::Exec,
+            ..
+        }
+    ));
+    let args = Args::try_parse_from(["kubeowler", "watch"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Watch {
+            node_collect: NodeCollectMode::Logs,
+            ..
+        }
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--node-collect", "ssh"]).is_err());
+}
+
+#[test]
+fn test_preflight_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "preflight",
+        "-n",
+        "apps",
+        "--node-collect",
+        "exec",
+    ])
+    .unwrap();
+    let Commands::Preflight {
+        namespace,
+        node_inspector_namespace,
+        node_collect,
+        ..
+    } = args.command
+    else {
+        panic!("expected preflight command");
+    };
+    assert_eq!(namespace.as_deref(), Some("apps"));
+    assert_eq!(node_inspector_namespace, "kubeowler");
+    assert_eq!(node_collect, NodeCollectMode::Exec);
+
+    let args = Args::try_parse_from(["kubeowler", "check", "--skip-preflight"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            skip_preflight: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_offline_parsing() {
+    let args =
+        Args::try_parse_from(["kubeowler", "check", "--offline", "--from-dir", "./dump"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check { offline: true, from_dir: Some(ref d), .. } if d == "./dump"
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--offline"]).is_err());
+    assert!(Args::try_parse_from(["kubeowler", "check", "--from-dir", "./dump"]).is_err());
+}
+
+#[test]
+fn test_render_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "render", "-i", "r.json", "-f", "html"]).unwrap();
+    let Commands::Render {
+        input,
+        format,
+        output,
+        level,
+        ..
+    } = args.command
+    else {
+        panic!("expected render command");
+    };
+    assert_eq!(input, "r.json");
+    assert!(matches!(format, ReportFormat::Html));
+    assert!(output.is_none());
+    assert_eq!(level, "warning,critical");
+    assert!(Args::try_parse_from(["kubeowler", "render"]).is_err());
+}
+
+#[test]
+fn test_template_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "render",
+        "-i",
+        "r.json",
+        "--template",
+        "summary.md.hbs",
+    ])
+    .unwrap();
+    let Commands::Render { template, .. } = args.command else {
+        panic!("expected render command");
+    };
+    assert_eq!(template.as_deref(), Some("summary.md.hbs"));
+
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check { template: None, .. }
+    ));
+}
+
+#[test]
+fn test_sections_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check", "--sections", "overview,issues,nodes"])
+        .unwrap();
+    let Commands::Check { sections, .. } = args.command else {
+        panic!("expected check command");
+    };
+    assert_eq!(
+        sections,
+        vec![
+            ReportSection::Overview,
+            ReportSection::Issues,
+            ReportSection::Nodes
+        ]
+    );
+    let args = Args::try_parse_from(["kubeowler", "render", "-i", "r.json"]).unwrap();
+    assert!(matches!(args.command, Commands::Render { sections, .. } if sections.is_empty()));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--sections", "summary"]).is_err());
+}
+
+#[test]
+fn test_max_resources_per_issue_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "check",
+        "--max-resources-per-issue",
+        "20",
+        "--resource-appendix",
+        "affected.json",
+    ])
+    .unwrap();
+    let Commands::Check {
+        max_resources_per_issue,
+        resource_appendix,
+        ..
+    } = args.command
+    else {
+        panic!("expected check command");
+    };
+    assert_eq!(max_resources_per_issue, 20);
+    assert_eq!(resource_appendix.as_deref(), Some("affected.json"));
+
+    let args = Args::try_parse_from(["kubeowler", "render", "-i", "r.json"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Render {
+            max_resources_per_issue: 0,
+            resource_appendix: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_scoring_profile_parsing() {
+    let args =
+        Args::try_parse_from(["kubeowler", "check", "--scoring-profile", "security"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            scoring_profile: Some(ScoringProfileName::Security),
+            config: None,
+            ..
+        }
+    ));
+    let args = Args::try_parse_from(["kubeowler", "watch", "--config", "kubeowler.yaml"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Watch { scoring_profile: None, config: Some(ref c), .. } if c == "kubeowler.yaml"
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--scoring-profile", "fast"]).is_err());
+}
+
+#[test]
+fn test_split_by_team_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "check",
+        "--owners",
+        "owners.yaml",
+        "--split-by-team",
+    ])
+    .unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check { owners: Some(ref o), split_by_team: true, ref team_label, .. }
+            if o == "owners.yaml" && team_label == "team"
+    ));
+}
+
+#[test]
+fn test_create_issues_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "check",
+        "--create-issues",
+        "github:acme/platform",
+        "--create-issues",
+        "jira:OPS",
+    ])
+    .unwrap();
+    let Commands::Check { create_issues, .. } = args.command else {
+        panic!("expected check command");
+    };
+    assert_eq!(create_issues, vec!["github:acme/platform", "jira:OPS"]);
+}
+
+#[test]
+fn test_baseline_parsing() {
+    let args =
+        Args::try_parse_from(["kubeowler", "baseline", "create", "--from", "r.json"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Baseline { action: BaselineAction::Create { ref from, ref output } }
+            if from == "r.json" && output == "kubeowler-baseline.json"
+    ));
+
+    let args = Args::try_parse_from(["kubeowler", "check", "--baseline", "b.json"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check { baseline: Some(ref b), .. } if b == "b.json"
+    ));
+}
+
+#[test]
+fn test_docs_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "docs", "generate"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Docs { action: DocsAction::Generate { ref out } } if out == "./docs"
+    ));
+
+    let args = Args::try_parse_from(["kubeowler", "check", "--offline-docs"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check { offline_docs: Some(ref d), .. } if d == "docs"
+    ));
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "render",
+        "-i",
+        "r.json",
+        "--offline-docs",
+        "kb",
+    ])
+    .unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Render { offline_docs: Some(ref d), .. } if d == "kb"
+    ));
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            offline_docs: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_cluster_access_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "check",
+        "--context",
+        "prod",
+        "--impersonate",
+        "system:serviceaccount:audit:kubeowler",
+        "--impersonate-group",
+        "auditors",
+        "--impersonate-group",
+        "system:authenticated",
+    ])
+    .unwrap();
+    assert_eq!(args.access.context.as_deref(), Some("prod"));
+    assert_eq!(
+        args.access.impersonate.as_deref(),
+        Some("system:serviceaccount:audit:kubeowler")
+    );
+    assert_eq!(
+        args.access.impersonate_group,
+        vec!["auditors", "system:authenticated"]
+    );
+
+    // Global: accepted before the subcommand too.
+    let args = Args::try_parse_from(["kubeowler", "--context", "dev", "preflight"]).unwrap();
+    assert_eq!(args.access.context.as_deref(), Some("dev"));
+    assert!(args.access.impersonate.is_none());
+
+    assert!(Args::try_parse_from(["kubeowler", "check", "--impersonate-group", "admins"]).is_err());
+}
+
+#[test]
+fn test_api_limits_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert_eq!(args.access.qps, 50.0);
+    assert_eq!(args.access.burst, 100);
+    assert_eq!(args.access.max_retries, 3);
+    assert_eq!(args.access.request_timeout, 60);
+    assert_eq!(args.access.page_size, 500);
+    assert!(!args.access.list_from_cache);
+    // Library callers get the same limits from Default.
+    let default = kubeowler_core::cli::ClusterAccess::default();
+    assert_eq!(
+        (default.qps, default.burst, default.max_retries),
+        (args.access.qps, args.access.burst, args.access.max_retries)
+    );
+    assert_eq!(
+        (default.request_timeout, default.page_size),
+        (args.access.request_timeout, args.access.page_size)
+    );
+
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "--qps",
+        "5",
+        "preflight",
+        "--burst",
+        "10",
+        "--max-retries",
+        "0",
+        "--request-timeout",
+        "0",
+        "--page-size",
+        "1000",
+        "--list-from-cache",
+    ])
+    .unwrap();
+    assert_eq!(args.access.qps, 5.0);
+    assert_eq!(args.access.burst, 10);
+    assert_eq!(args.access.max_retries, 0);
+    assert_eq!(args.access.request_timeout, 0);
+    assert_eq!(args.access.page_size, 1000);
+    assert!(args.access.list_from_cache);
+}
+
+#[test]
+fn test_otel_endpoint_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(args.otel_endpoint.is_none());
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "check",
+        "--otel-endpoint",
+        "http://otel-collector:4318",
+    ])
+    .unwrap();
+    assert_eq!(
+        args.otel_endpoint.as_deref(),
+        Some("http://otel-collector:4318")
+    );
+}
+
+#[test]
+fn test_max_issues_per_rule_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            max_issues_per_rule: 200,
+            ..
+        }
+    ));
+    let args = Args::try_parse_from(["kubeowler", "check", "--max-issues-per-rule", "0"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            max_issues_per_rule: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_per_pod_issues_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            per_pod_issues: false,
+            ..
+        }
+    ));
+    let args = Args::try_parse_from(["kubeowler", "check", "--per-pod-issues"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            per_pod_issues: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_fix_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "fix", "-i", "r.json", "--interactive"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Fix { ref input, interactive: true, config_file: None } if input == "r.json"
+    ));
+    let args = Args::try_parse_from(["kubeowler", "fix", "--input", "r.json"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Fix {
+            interactive: false,
+            ..
+        }
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "fix", "--interactive"]).is_err());
+}
+
+#[test]
+fn test_explain_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "explain", "POD-007"]).unwrap();
+    assert!(matches!(args.command, Commands::Explain { ref code } if code == "POD-007"));
+    assert!(Args::try_parse_from(["kubeowler", "explain"]).is_err());
+}
+
+#[test]
+fn test_csv_layout_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check", "-f", "csv"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            csv_layout: CsvLayout::Summary,
+            ..
+        }
+    ));
+
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "render",
+        "-i",
+        "r.json",
+        "-f",
+        "csv",
+        "--csv-layout",
+        "issues",
+    ])
+    .unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Render {
+            csv_layout: CsvLayout::Issues,
+            ..
+        }
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--csv-layout", "rows"]).is_err());
+    let args = Args::try_parse_from(["kubeowler", "check", "-f", "xlsx"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            format: ReportFormat::Xlsx,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_brand_parsing() {
+    let args = Args::try_parse_from([
+        "kubeowler",
+        "render",
+        "-i",
+        "r.json",
+        "-f",
+        "html",
+        "--brand-name",
+        "Acme",
+        "--brand-color",
+        "#ff6600",
+        "--theme",
+        "dark",
+    ])
+    .unwrap();
+    let Commands::Render { brand, .. } = args.command else {
+        panic!("expected render command");
+    };
+    assert_eq!(brand.brand_name.as_deref(), Some("Acme"));
+    assert_eq!(brand.brand_color.as_deref(), Some("#ff6600"));
+    assert_eq!(brand.theme, Some(HtmlTheme::Dark));
+    assert!(brand.brand_logo.is_none());
+}
+
+#[test]
+fn test_lang_parsing() {
+    let args = Args::try_parse_from(["kubeowler", "check", "--lang", "zh-CN"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Check {
+            lang: Lang::ZhCn,
+            ..
+        }
+    ));
+    let args = Args::try_parse_from(["kubeowler", "render", "-i", "r.json"]).unwrap();
+    assert!(matches!(
+        args.command,
+        Commands::Render { lang: Lang::En, .. }
+    ));
+    assert!(Args::try_parse_from(["kubeowler", "check", "--lang", "fr"]).is_err());
+}
+
+#[test]
+fn test_inspection_type_variants() {
+    let types = InspectionType::VARIANTS;
+    assert!(types.len() >= 6); // We have at least 6 inspection types
+
+    // Test that all types can be parsed
+    assert!(matches!(
+        "all".parse::<InspectionType>(),
+        Ok(InspectionType::All)
+    ));
+    assert!(matches!(
+        "nodes".parse::<InspectionType>(),
+        Ok(InspectionType::Nodes)
+    ));
+    assert!(matches!(
+        "pods".parse::<InspectionType>(),
+        Ok(InspectionType::Pods)
+    ));
+    assert!(matches!(
+        "security".parse::<InspectionType>(),
+        Ok(InspectionType::Security)
+    ));
+}
@@ -196,6 +196,37 @@ gather_disk_mounts() {
   rm -f "$tmpf" 2>/dev/null || true
 }
 
+# ------------------------------------------------------------------------------
+# Partition usage for a host path (df -P on the deepest existing directory).
+# Usage: partition_usage_json "/var/lib/kubelet" -> {"path":..,"device":..,"total_g":..,"used_g":..,"used_pct":..} or empty
+# ------------------------------------------------------------------------------
+partition_usage_json() {
+  local host_path="$1" p="/host$1"
+  [ -d /host ] || p="$1"
+  [ -d "$p" ] || return 0
+  df -P "$p" 2>/dev/null | awk -v path="$host_path" 'NR==2 {
+    total_kb=$2; used_kb=$3;
+    if (total_kb+0>0) pct=(used_kb/total_kb)*100; else pct=0;
+    printf "{\"path\":\"%s\",\"device\":\"%s\",\"total_g\":%.2f,\"used_g\":%.2f,\"used_pct\":%.1f}", path, $1, total_kb/1024/1024, used_kb/1024/1024, pct
+  }' 2>/dev/null || true
+}
+
+# ------------------------------------------------------------------------------
+# nodefs (kubelet root) and imagefs (container runtime root) usage.
+# Sets: nodefs_json, imagefs_json (JSON object or null)
+# ------------------------------------------------------------------------------
+gather_storage_partitions() {
+  nodefs_json=$(partition_usage_json "/var/lib/kubelet")
+  imagefs_json=""
+  for d in /var/lib/containerd /var/lib/docker /var/lib/containers; do
+    imagefs_json=$(partition_usage_json "$d")
+    [ -n "$imagefs_json" ] && break
+  done
+  [ -z "$nodefs_json" ] && nodefs_json="null"
+  [ -z "$imagefs_json" ] && imagefs_json="null"
+  return 0
+}
+
 # ------------------------------------------------------------------------------
 # Check if a process name exists in host /proc (host-aware, no systemctl).
 # Usage: host_proc_running "firewalld" && var="true"
@@ -341,6 +372,70 @@ gather_kernel_sysctl() {
   ker_detail=""
 }
 
+# ------------------------------------------------------------------------------
+# Gather OS hardening inputs (CIS node items). Sets: hardening_json
+#   file_permissions: mode/owner of kubelet and kubeadm files that exist
+#   loaded_kernel_modules: loaded modules from the CIS "disable" list
+#   sysctls: hardening sysctls (dotted names)
+#   listening_ports: TCP LISTEN sockets of the host network namespace (PID 1)
+#   ssh: first-occurrence sshd_config values (null when no sshd_config)
+# ------------------------------------------------------------------------------
+HARDENING_FILES="/etc/kubernetes/kubelet.conf /etc/kubernetes/bootstrap-kubelet.conf /var/lib/kubelet/config.yaml /etc/systemd/system/kubelet.service.d/10-kubeadm.conf /usr/lib/systemd/system/kubelet.service.d/10-kubeadm.conf /etc/kubernetes/pki/ca.crt /etc/kubernetes/admin.conf /etc/kubernetes/manifests/kube-apiserver.yaml /etc/kubernetes/manifests/etcd.yaml"
+HARDENING_MODULES="cramfs freevxfs jffs2 hfs hfsplus udf dccp sctp rds tipc usb_storage"
+HARDENING_SYSCTLS="net.ipv4.conf.all.send_redirects net.ipv4.conf.default.send_redirects net.ipv4.conf.all.accept_redirects net.ipv4.conf.all.accept_source_route net.ipv4.conf.all.log_martians net.ipv4.icmp_echo_ignore_broadcasts net.ipv4.tcp_syncookies kernel.randomize_va_space fs.suid_dumpable"
+
+gather_hardening() {
+  local root="" f mode owner m key val path perms="" mods="" sysctls="" ports="" ssh="null" sshd_cfg
+  [ -d /host ] && root="/host"
+  for f in $HARDENING_FILES; do
+    [ -e "$root$f" ] || continue
+    mode=$(stat -c '%a' "$root$f" 2>/dev/null || true)
+    owner=$(stat -c '%u:%g' "$root$f" 2>/dev/null || true)
+    [ -n "$mode" ] || continue
+    perms="${perms:+$perms,}{\"path\":\"$f\",\"mode\":\"$mode\",\"owner\":\"$owner\"}"
+  done
+  for m in $HARDENING_MODULES; do
+    grep -q "^$m " "$HOST_PROC/modules" 2>/dev/null && mods="${mods:+$mods,}\"$m\"" || true
+  done
+  for key in $HARDENING_SYSCTLS; do
+    path="$HOST_PROC/sys/$(echo "$key" | tr '.' '/')"
+    [ -r "$path" ] || continue
+    val=$(tr -d '\n' < "$path" 2>/dev/null || true)
+    sysctls="${sysctls:+$sysctls,}\"$key\":\"$(escape_json "$val")\""
+  done
+  # LISTEN (0A) sockets; addresses are hex (IPv4 little-endian), decoded for 0.0.0.0/127.x/::
+  ports=$(cat "$HOST_PROC/1/net/tcp" "$HOST_PROC/1/net/tcp6" 2>/dev/null | awk '
+    $4 == "0A" {
+      split($2, a, ":"); hex = a[1]; port = 0
+      for (i = 1; i <= length(a[2]); i++) port = port * 16 + index("0123456789ABCDEF", substr(a[2], i, 1)) - 1
+      if (hex == "00000000") addr = "0.0.0.0"
+      else if (hex == "00000000000000000000000000000000") addr = "::"
+      else if (hex == "00000000000000000000000001000000") addr = "::1"
+      else if (length(hex) == 8 && substr(hex, 7, 2) == "7F") addr = "127.0.0.1"
+      else addr = hex
+      k = addr ":" port
+      if (!(k in seen)) { seen[k] = 1; printf "%s{\"address\":\"%s\",\"port\":%d}", sep, addr, port; sep = "," }
+    }' || true)
+  sshd_cfg="$HOST_ETC/ssh/sshd_config"
+  if [ -r "$sshd_cfg" ]; then
+    ssh=$(awk '
+      BEGIN { n = split("permitrootlogin passwordauthentication permitemptypasswords x11forwarding maxauthtries", keys, " ") }
+      /^[ \t]*#/ || NF < 2 { next }
+      tolower($1) == "match" { exit }
+      { k = tolower($1); if (!(k in v)) v[k] = $2 }
+      END {
+        split("permit_root_login password_authentication permit_empty_passwords x11_forwarding max_auth_tries", names, " ")
+        printf "{"
+        for (i = 1; i <= n; i++) {
+          printf "%s\"%s\":", (i > 1 ? "," : ""), names[i]
+          if (keys[i] in v) printf "\"%s\"", v[keys[i]]; else printf "null"
+        }
+        printf "}"
+      }' "$sshd_cfg" 2>/dev/null || echo "null")
+  fi
+  hardening_json="{\"file_permissions\":[$perms],\"loaded_kernel_modules\":[$mods],\"sysctls\":{$sysctls},\"listening_ports\":[$ports],\"ssh\":${ssh:-null}}"
+}
+
 # ------------------------------------------------------------------------------
 # Count zombie processes (state Z) in /proc
 # ------------------------------------------------------------------------------
@@ -559,7 +654,12 @@ emit_node_inspection_json() {
   "zombie_count": $zombie_count,
   "issue_count": $issue_count,
   "node_certificates": [${node_certificates_json}],
-  "node_disks": [${node_disks_json:-}]
+  "node_disks": [${node_disks_json:-}],
+  "storage_partitions": {
+    "nodefs": ${nodefs_json:-null},
+    "imagefs": ${imagefs_json:-null}
+  },
+  "hardening": ${hardening_json:-null}
 }
 EOF
 }
@@ -572,10 +672,12 @@ kernel_version=$(get_kernel_version)
 uptime_str=$(get_uptime_string)
 gather_resources
 gather_disk_mounts
+gather_storage_partitions
 gather_services
 gather_security
 gather_stability
 gather_kernel_sysctl
+gather_hardening
 zombie_count=$(count_zombie_processes)
 compute_issue_count
 
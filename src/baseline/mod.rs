@@ -0,0 +1,127 @@
+//! Baseline of accepted findings. `baseline create` records the fingerprints of a saved report's
+//! issues; `check --baseline` moves matching issues out of the inspections into
+//! `accepted_issues`, so only new findings count towards scores, rankings and tickets.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::inspections::types::{BaselineInfo, ClusterReport, IssueSeverity};
+use crate::scoring::rescore_report;
+
+pub const BASELINE_VERSION: u32 = 1;
+pub const DEFAULT_BASELINE_FILE: &str = "kubeowler-baseline.json";
+
+/// Baseline file. Entries keep code, resource and severity for review; only `fingerprint` is matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub cluster_name: String,
+    pub source_report_id: String,
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub resource: Option<String>,
+    pub severity: IssueSeverity,
+}
+
+impl Baseline {
+    /// One entry per distinct issue fingerprint in `report`.
+    pub fn from_report(report: &ClusterReport) -> Self {
+        let mut entries: BTreeMap<String, BaselineEntry> = BTreeMap::new();
+        for issue in report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+        {
+            let fingerprint = issue.stable_fingerprint();
+            entries
+                .entry(fingerprint.clone())
+                .or_insert_with(|| BaselineEntry {
+                    fingerprint,
+                    rule_id: issue.rule_id.clone(),
+                    resource: issue.resource.clone(),
+                    severity: issue.severity.clone(),
+                });
+        }
+        Self {
+            version: BASELINE_VERSION,
+            created_at: Utc::now(),
+            cluster_name: report.cluster_name.clone(),
+            source_report_id: report.report_id.clone(),
+            entries: entries.into_values().collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading baseline {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&text)
+            .with_context(|| format!("parsing baseline {}", path.display()))?;
+        if baseline.version > BASELINE_VERSION {
+            bail!(
+                "baseline {} has version {}; this kubeowler reads up to version {}",
+                path.display(),
+                baseline.version,
+                BASELINE_VERSION
+            );
+        }
+        Ok(baseline)
+    }
+
+    /// Move matching issues to `report.accepted_issues` and re-score without them.
+    ///
+    /// Module scores come from checks, not issues, so each module gets back the share of its lost
+    /// points held by accepted issues: `score + (100 - score) * accepted / issues`.
+    pub fn apply(&self, report: &mut ClusterReport, file: &str) -> BaselineInfo {
+        let known: HashSet<&str> = self
+            .entries
+            .iter()
+            .map(|e| e.fingerprint.as_str())
+            .collect();
+        let mut matched: HashSet<String> = HashSet::new();
+        let mut accepted = Vec::new();
+        for inspection in report.inspections.iter_mut() {
+            let total = inspection.summary.issues.len();
+            let (acc, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut inspection.summary.issues)
+                .into_iter()
+                .partition(|i| {
+                    let fp = i.stable_fingerprint();
+                    let hit = known.contains(fp.as_str());
+                    if hit {
+                        matched.insert(fp);
+                    }
+                    hit
+                });
+            inspection.summary.issues = kept;
+            if !acc.is_empty() && !inspection.is_failed() {
+                let score = inspection.overall_score;
+                inspection.overall_score =
+                    score + (100.0 - score) * acc.len() as f64 / total as f64;
+            }
+            accepted.extend(acc);
+        }
+
+        rescore_report(report);
+
+        let info = BaselineInfo {
+            file: file.to_string(),
+            entries: self.entries.len(),
+            accepted: accepted.len(),
+            resolved: known.len() - matched.len(),
+        };
+        report.accepted_issues = Some(accepted).filter(|a| !a.is_empty());
+        report.metadata.baseline = Some(info.clone());
+        info
+    }
+}
@@ -0,0 +1,282 @@
+//! Anonymous benchmark summaries for comparing clusters side by side. `check --benchmark FILE`
+//! reduces a run to scores and issue counts per rule, without cluster, namespace or object
+//! names; `kubeowler compare` ranks several summaries (or full JSON reports) in one matrix.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::inspections::types::{ClusterReport, IssueSeverity};
+
+pub const BENCHMARK_VERSION: u32 = 1;
+
+/// Rules listed in the comparison matrix, most frequent across all clusters first.
+const MAX_RULES: usize = 25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSummary {
+    pub version: u32,
+    /// `--benchmark-label`; `compare` falls back to the file name.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub label: Option<String>,
+    pub generated_at: DateTime<Utc>,
+    /// Kubernetes minor version (`v1.29`), if known.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub kubernetes_version: Option<String>,
+    pub node_count: u32,
+    pub scoring_profile: String,
+    pub overall_score: f64,
+    /// Inspection module → score.
+    pub module_scores: BTreeMap<String, f64>,
+    pub critical: usize,
+    pub warning: usize,
+    pub info: usize,
+    /// Issue code → number of findings.
+    pub rules: BTreeMap<String, usize>,
+}
+
+/// `v1.29.4-eks-1` → `v1.29`: the patch and vendor suffix say little and narrow down the cluster.
+fn minor_version(version: &str) -> Option<String> {
+    let mut parts = version.trim_start_matches('v').split('.');
+    let major = parts.next()?;
+    let minor: String = parts
+        .next()?
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    (!major.is_empty() && !minor.is_empty()).then(|| format!("v{}.{}", major, minor))
+}
+
+impl BenchmarkSummary {
+    pub fn from_report(report: &ClusterReport, label: Option<String>) -> Self {
+        let mut summary = Self {
+            version: BENCHMARK_VERSION,
+            label,
+            generated_at: report.timestamp,
+            kubernetes_version: report
+                .cluster_overview
+                .as_ref()
+                .and_then(|o| o.cluster_version.as_deref())
+                .and_then(minor_version),
+            node_count: report
+                .cluster_overview
+                .as_ref()
+                .map(|o| o.node_count)
+                .unwrap_or(0),
+            scoring_profile: report.metadata.scoring_profile.name.clone(),
+            overall_score: report.overall_score,
+            module_scores: report
+                .inspections
+                .iter()
+                .map(|i| (i.inspection_type.clone(), i.overall_score))
+                .collect(),
+            critical: 0,
+            warning: 0,
+            info: 0,
+            rules: BTreeMap::new(),
+        };
+        for issue in report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+        {
+            match issue.severity {
+                IssueSeverity::Critical => summary.critical += 1,
+                IssueSeverity::Warning => summary.warning += 1,
+                IssueSeverity::Info => summary.info += 1,
+            }
+            if let Some(code) = &issue.rule_id {
+                *summary.rules.entry(code.clone()).or_default() += 1;
+            }
+        }
+        summary
+    }
+
+    /// A benchmark file, or a `check --format json` report reduced on the fly. Unlabelled
+    /// summaries are labelled with the file stem.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let value: serde_json::Value =
+            serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+        let mut summary = if value.get("inspections").is_some() {
+            let report: ClusterReport = serde_json::from_value(value)
+                .with_context(|| format!("{} is not a kubeowler JSON report", path.display()))?;
+            Self::from_report(&report, None)
+        } else {
+            let summary: Self = serde_json::from_value(value).with_context(|| {
+                format!(
+                    "{} is neither a benchmark file nor a JSON report",
+                    path.display()
+                )
+            })?;
+            if summary.version > BENCHMARK_VERSION {
+                bail!(
+                    "benchmark {} has version {}; this kubeowler reads up to version {}",
+                    path.display(),
+                    summary.version,
+                    BENCHMARK_VERSION
+                );
+            }
+            summary
+        };
+        if summary.label.is_none() {
+            summary.label = path.file_stem().map(|s| s.to_string_lossy().into_owned());
+        }
+        Ok(summary)
+    }
+
+    fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or("-")
+    }
+}
+
+fn rank_order(summaries: &[BenchmarkSummary]) -> Vec<&BenchmarkSummary> {
+    let mut ranked: Vec<&BenchmarkSummary> = summaries.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.overall_score
+            .total_cmp(&a.overall_score)
+            .then(a.critical.cmp(&b.critical))
+            .then(a.label().cmp(b.label()))
+    });
+    ranked
+}
+
+fn table_row(cells: &[String]) -> String {
+    format!("| {} |\n", cells.join(" | "))
+}
+
+/// Markdown ranking of `summaries` (best score first), then module scores and the most frequent
+/// rules as cluster-by-cluster matrices.
+pub fn render_comparison(summaries: &[BenchmarkSummary]) -> String {
+    let ranked = rank_order(summaries);
+    let mut out = String::from("# Cluster comparison\n\n");
+
+    out.push_str("## Ranking\n\n");
+    out.push_str(
+        "| Rank | Cluster | Score | Critical | Warning | Info | Nodes | Version | Profile |\n",
+    );
+    out.push_str(
+        "|------|---------|-------|----------|---------|------|-------|---------|---------|\n",
+    );
+    for (i, s) in ranked.iter().enumerate() {
+        out.push_str(&table_row(&[
+            (i + 1).to_string(),
+            s.label().to_string(),
+            format!("{:.1}", s.overall_score),
+            s.critical.to_string(),
+            s.warning.to_string(),
+            s.info.to_string(),
+            s.node_count.to_string(),
+            s.kubernetes_version
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+            s.scoring_profile.clone(),
+        ]));
+    }
+    if ranked
+        .windows(2)
+        .any(|w| w[0].scoring_profile != w[1].scoring_profile)
+    {
+        out.push_str("\nScores were computed with different scoring profiles and are not directly comparable.\n");
+    }
+
+    let header = |first: &str| {
+        let mut h = table_row(
+            &std::iter::once(first.to_string())
+                .chain(ranked.iter().map(|s| s.label().to_string()))
+                .collect::<Vec<_>>(),
+        );
+        h.push_str(&format!("|{}\n", "---|".repeat(ranked.len() + 1)));
+        h
+    };
+
+    out.push_str("\n## Module scores\n\n");
+    let mut modules: Vec<&String> = ranked.iter().flat_map(|s| s.module_scores.keys()).collect();
+    modules.sort();
+    modules.dedup();
+    out.push_str(&header("Module"));
+    for module in modules {
+        let cells: Vec<String> = std::iter::once(module.clone())
+            .chain(ranked.iter().map(|s| {
+                s.module_scores
+                    .get(module)
+                    .map(|v| format!("{:.1}", v))
+                    .unwrap_or_else(|| "-".to_string())
+            }))
+            .collect();
+        out.push_str(&table_row(&cells));
+    }
+
+    let mut totals: BTreeMap<&String, usize> = BTreeMap::new();
+    for s in &ranked {
+        for (code, n) in &s.rules {
+            *totals.entry(code).or_default() += n;
+        }
+    }
+    let mut rules: Vec<(&String, usize)> = totals.into_iter().collect();
+    rules.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    out.push_str("\n## Findings per rule\n\n");
+    if rules.is_empty() {
+        out.push_str("No findings.\n");
+        return out;
+    }
+    if rules.len() > MAX_RULES {
+        out.push_str(&format!(
+            "The {} most frequent of {} rules.\n\n",
+            MAX_RULES,
+            rules.len()
+        ));
+    }
+    out.push_str(&header("Rule"));
+    for (code, _) in rules.into_iter().take(MAX_RULES) {
+        let cells: Vec<String> = std::iter::once(code.clone())
+            .chain(
+                ranked
+                    .iter()
+                    .map(|s| s.rules.get(code).copied().unwrap_or(0).to_string()),
+            )
+            .collect();
+        out.push_str(&table_row(&cells));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(label: &str, score: f64, rules: &[(&str, usize)]) -> BenchmarkSummary {
+        BenchmarkSummary {
+            version: BENCHMARK_VERSION,
+            label: Some(label.to_string()),
+            generated_at: Utc::now(),
+            kubernetes_version: minor_version("v1.29.4-eks-1"),
+            node_count: 3,
+            scoring_profile: "balanced".to_string(),
+            overall_score: score,
+            module_scores: BTreeMap::from([("Pod Status".to_string(), score)]),
+            critical: 0,
+            warning: rules.iter().map(|(_, n)| n).sum(),
+            info: 0,
+            rules: rules.iter().map(|(c, n)| (c.to_string(), *n)).collect(),
+        }
+    }
+
+    #[test]
+    fn ranks_best_score_first() {
+        let out = render_comparison(&[
+            summary("staging", 71.0, &[("POD-002", 4)]),
+            summary("prod", 92.5, &[("POD-002", 1), ("SEC-005", 2)]),
+        ]);
+        assert!(out.contains("| 1 | prod | 92.5 | 0 | 3 | 0 | 3 | v1.29 | balanced |"));
+        assert!(out.contains("| 2 | staging | 71.0 |"));
+        assert!(out.contains("| Rule | prod | staging |"));
+        assert!(out.contains("| POD-002 | 1 | 4 |"));
+        assert!(out.contains("| SEC-005 | 2 | 0 |"));
+        assert!(!out.contains("not directly comparable"));
+    }
+}
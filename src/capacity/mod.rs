@@ -0,0 +1,354 @@
+//! Cluster capacity forecast. The overview records how much CPU and memory the pods on each node
+//! pool request and limit against allocatable; `check --history-dir` then fits a linear trend to
+//! the cluster requests of the earlier reports of the same cluster and estimates the days until
+//! requests exceed allocatable, at which point new pods stay Pending without a scale-up.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::core::v1::{Node, Pod, PodSpec};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+use crate::inspections::types::{
+    CapacityCommitment, CapacityForecast, CapacityTrend, ClusterReport, NodeRow,
+};
+use crate::reporting::read_report;
+use crate::utils::resource_quantity::{parse_cpu_str, parse_memory_str};
+
+/// Only reports from the last this many days feed the trend, so it follows recent growth.
+pub const FORECAST_WINDOW_DAYS: i64 = 30;
+
+/// Scope name of the whole-cluster commitment.
+pub const CLUSTER_SCOPE: &str = "cluster";
+
+const GI: f64 = 1024.0 * 1024.0 * 1024.0;
+
+fn cores(q: Option<&Quantity>) -> f64 {
+    q.and_then(|q| parse_cpu_str(&q.0)).unwrap_or(0) as f64 / 1000.0
+}
+
+fn gib(q: Option<&Quantity>) -> f64 {
+    q.and_then(|q| parse_memory_str(&q.0)).unwrap_or(0) as f64 / GI
+}
+
+/// Effective (cpu request, cpu limit, memory request, memory limit) of a pod: the sum over its
+/// containers, or its largest init container when that is higher, plus the pod overhead.
+fn pod_resources(spec: &PodSpec) -> [f64; 4] {
+    let of = |c: &k8s_openapi::api::core::v1::Container| {
+        let r = c.resources.as_ref();
+        let requests = r.and_then(|r| r.requests.as_ref());
+        let limits = r.and_then(|r| r.limits.as_ref());
+        [
+            cores(requests.and_then(|m| m.get("cpu"))),
+            cores(limits.and_then(|m| m.get("cpu"))),
+            gib(requests.and_then(|m| m.get("memory"))),
+            gib(limits.and_then(|m| m.get("memory"))),
+        ]
+    };
+    let mut total = [0.0; 4];
+    for c in &spec.containers {
+        for (t, v) in total.iter_mut().zip(of(c)) {
+            *t += v;
+        }
+    }
+    for c in spec.init_containers.iter().flatten() {
+        for (t, v) in total.iter_mut().zip(of(c)) {
+            *t = t.max(v);
+        }
+    }
+    if let Some(overhead) = &spec.overhead {
+        total[0] += cores(overhead.get("cpu"));
+        total[2] += gib(overhead.get("memory"));
+    }
+    total
+}
+
+fn add_node(c: &mut CapacityCommitment, node: &Node) {
+    let alloc = node.status.as_ref().and_then(|s| s.allocatable.as_ref());
+    c.node_count += 1;
+    c.allocatable_cpu_cores += cores(alloc.and_then(|a| a.get("cpu")));
+    c.allocatable_memory_gi += gib(alloc.and_then(|a| a.get("memory")));
+}
+
+fn add_pod(c: &mut CapacityCommitment, [cpu_req, cpu_lim, mem_req, mem_lim]: [f64; 4]) {
+    c.cpu_requests_cores += cpu_req;
+    c.cpu_limits_cores += cpu_lim;
+    c.memory_requests_gi += mem_req;
+    c.memory_limits_gi += mem_lim;
+}
+
+/// Requests and limits against allocatable for the cluster, for each pool in `node_list`
+/// (`NodeRow::node_pool`) and for each node. The trend is left empty; see [`apply_forecast`].
+pub fn commitments(nodes: &[Node], pods: &[Pod], node_list: &[NodeRow]) -> CapacityForecast {
+    let pool_of: HashMap<&str, &str> = node_list
+        .iter()
+        .filter_map(|n| Some((n.name.as_str(), n.node_pool.as_deref()?)))
+        .collect();
+    let mut cluster = CapacityCommitment {
+        scope: CLUSTER_SCOPE.to_string(),
+        ..Default::default()
+    };
+    let mut pools: BTreeMap<&str, CapacityCommitment> = BTreeMap::new();
+    let mut per_node: BTreeMap<&str, CapacityCommitment> = BTreeMap::new();
+    for node in nodes {
+        let name = node.metadata.name.as_deref().unwrap_or_default();
+        add_node(&mut cluster, node);
+        let entry = per_node.entry(name).or_insert_with(|| CapacityCommitment {
+            scope: name.to_string(),
+            ..Default::default()
+        });
+        add_node(entry, node);
+        if let Some(pool) = pool_of.get(name) {
+            let entry = pools.entry(pool).or_insert_with(|| CapacityCommitment {
+                scope: pool.to_string(),
+                ..Default::default()
+            });
+            add_node(entry, node);
+        }
+    }
+    for pod in pods {
+        if matches!(
+            pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+            Some("Succeeded" | "Failed")
+        ) {
+            continue;
+        }
+        let Some(spec) = &pod.spec else {
+            continue;
+        };
+        let Some(node) = spec.node_name.as_deref() else {
+            continue;
+        };
+        let resources = pod_resources(spec);
+        add_pod(&mut cluster, resources);
+        if let Some(entry) = per_node.get_mut(node) {
+            add_pod(entry, resources);
+        }
+        if let Some(pool) = pool_of.get(node).and_then(|p| pools.get_mut(p)) {
+            add_pod(pool, resources);
+        }
+    }
+    CapacityForecast {
+        cluster,
+        node_pools: pools.into_values().collect(),
+        nodes: per_node.into_values().collect(),
+        trend: None,
+    }
+}
+
+/// Least-squares slope of `points` (x, y); None without two distinct x values.
+fn slope(points: &[(f64, f64)]) -> Option<f64> {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let var_x: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    if var_x <= f64::EPSILON {
+        return None;
+    }
+    let cov: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    Some(cov / var_x)
+}
+
+/// Days until `current` reaches `allocatable` at `per_day`; 0 when already there, None when
+/// not growing.
+fn days_until_full(current: f64, allocatable: f64, per_day: f64) -> Option<f64> {
+    if current >= allocatable {
+        Some(0.0)
+    } else if per_day > 0.0 {
+        Some((allocatable - current) / per_day)
+    } else {
+        None
+    }
+}
+
+/// Trend of cluster requests over `history` (timestamp, commitment) and the current `now`
+/// commitment. Points older than [`FORECAST_WINDOW_DAYS`] are ignored.
+pub fn trend(
+    history: &[(DateTime<Utc>, CapacityCommitment)],
+    now: DateTime<Utc>,
+    current: &CapacityCommitment,
+) -> Option<CapacityTrend> {
+    let since = now - Duration::days(FORECAST_WINDOW_DAYS);
+    let mut points: Vec<(f64, &CapacityCommitment)> = history
+        .iter()
+        .filter(|(ts, _)| *ts >= since && *ts < now)
+        .map(|(ts, c)| ((*ts - now).num_seconds() as f64 / 86_400.0, c))
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+    points.push((0.0, current));
+    let series = |f: fn(&CapacityCommitment) -> f64| -> Vec<(f64, f64)> {
+        points.iter().map(|(x, c)| (*x, f(c))).collect()
+    };
+    let cpu = slope(&series(|c| c.cpu_requests_cores))?;
+    let memory = slope(&series(|c| c.memory_requests_gi))?;
+    Some(CapacityTrend {
+        data_points: points.len(),
+        span_days: -points.iter().map(|p| p.0).fold(0.0, f64::min),
+        cpu_requests_cores_per_day: cpu,
+        memory_requests_gi_per_day: memory,
+        cpu_days_until_full: days_until_full(
+            current.cpu_requests_cores,
+            current.allocatable_cpu_cores,
+            cpu,
+        ),
+        memory_days_until_full: days_until_full(
+            current.memory_requests_gi,
+            current.allocatable_memory_gi,
+            memory,
+        ),
+    })
+}
+
+/// Set `cluster_overview.capacity.trend` from the earlier reports of this cluster in
+/// `history_dir` that carry a capacity section. Returns the number of reports used.
+pub fn apply_forecast(report: &mut ClusterReport, history_dir: &Path) -> Result<usize> {
+    let Some(capacity) = report
+        .cluster_overview
+        .as_mut()
+        .and_then(|o| o.capacity.as_mut())
+    else {
+        return Ok(0);
+    };
+    let mut history = Vec::new();
+    for entry in std::fs::read_dir(history_dir)
+        .with_context(|| format!("reading {}", history_dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "json") {
+            continue;
+        }
+        let Ok(earlier) = read_report(&path) else {
+            continue;
+        };
+        if earlier.cluster_name != report.cluster_name {
+            continue;
+        }
+        if let Some(c) = earlier.cluster_overview.and_then(|o| o.capacity) {
+            history.push((earlier.timestamp, c.cluster));
+        }
+    }
+    capacity.trend = trend(&history, report.timestamp, &capacity.cluster);
+    Ok(capacity.trend.as_ref().map_or(0, |t| t.data_points - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{Container, NodeStatus, PodStatus, ResourceRequirements};
+    use kube::api::ObjectMeta;
+
+    fn quantities(cpu: &str, memory: &str) -> BTreeMap<String, Quantity> {
+        [
+            ("cpu".to_string(), Quantity(cpu.to_string())),
+            ("memory".to_string(), Quantity(memory.to_string())),
+        ]
+        .into()
+    }
+
+    fn node(name: &str) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(NodeStatus {
+                allocatable: Some(quantities("4", "16Gi")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(node: &str, phase: &str, cpu: &str, memory: &str) -> Pod {
+        Pod {
+            spec: Some(PodSpec {
+                node_name: Some(node.to_string()),
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    resources: Some(ResourceRequirements {
+                        requests: Some(quantities(cpu, memory)),
+                        limits: Some(quantities("2", memory)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some(phase.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn row(name: &str, pool: &str) -> NodeRow {
+        NodeRow {
+            name: name.to_string(),
+            operating_system: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            kubelet_version: "v1.29.0".to_string(),
+            ready: true,
+            pod_count: 0,
+            allocatable_pods: None,
+            node_address: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime_version: None,
+            node_pool: Some(pool.to_string()),
+            zone: None,
+            spot: false,
+        }
+    }
+
+    #[test]
+    fn sums_requests_per_pool_and_forecasts_from_history() {
+        let nodes = vec![node("a"), node("b")];
+        let pods = vec![
+            pod("a", "Running", "500m", "2Gi"),
+            pod("b", "Running", "1", "4Gi"),
+            pod("b", "Succeeded", "3", "8Gi"),
+        ];
+        let rows = vec![row("a", "web"), row("b", "batch")];
+        let capacity = commitments(&nodes, &pods, &rows);
+        assert_eq!(capacity.cluster.node_count, 2);
+        assert_eq!(capacity.cluster.allocatable_cpu_cores, 8.0);
+        assert_eq!(capacity.cluster.cpu_requests_cores, 1.5);
+        assert_eq!(capacity.cluster.cpu_limits_cores, 4.0);
+        assert_eq!(capacity.cluster.memory_requests_gi, 6.0);
+        let scopes: Vec<(&str, f64)> = capacity
+            .node_pools
+            .iter()
+            .map(|p| (p.scope.as_str(), p.cpu_requests_cores))
+            .collect();
+        assert_eq!(scopes, vec![("batch", 1.0), ("web", 0.5)]);
+        assert_eq!(capacity.nodes[1].scope, "b");
+        assert_eq!(capacity.nodes[1].memory_limits_gi, 4.0);
+
+        // CPU requests grew by 0.5 cores/day over the last 4 days; memory is flat.
+        let now: DateTime<Utc> = "2024-06-10T00:00:00Z".parse().unwrap();
+        let earlier = |days: i64, cpu: f64| {
+            (
+                now - Duration::days(days),
+                CapacityCommitment {
+                    cpu_requests_cores: cpu,
+                    memory_requests_gi: 6.0,
+                    ..capacity.cluster.clone()
+                },
+            )
+        };
+        let history = vec![earlier(4, -0.5), earlier(2, 0.5), earlier(90, 0.0)];
+        let t = trend(&history, now, &capacity.cluster).unwrap();
+        assert_eq!(t.data_points, 3);
+        assert_eq!(t.span_days, 4.0);
+        assert!((t.cpu_requests_cores_per_day - 0.5).abs() < 1e-9);
+        assert!((t.cpu_days_until_full.unwrap() - 13.0).abs() < 1e-9);
+        assert_eq!(t.memory_days_until_full, None);
+
+        assert_eq!(trend(&[], now, &capacity.cluster), None);
+    }
+}
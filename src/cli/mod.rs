@@ -1,97 +1,338 @@
-use clap::{Parser, Subcommand, ValueEnum};
+//! Option types shared by the `kubeowler` command line and library callers: inspection types,
+//! report languages and sections, scoring profiles and how to reach the cluster. The flags
+//! themselves are defined by the CLI crate; enums it accepts by name implement [`OptionValue`].
+
 use std::str::FromStr;
 
-#[derive(Parser)]
-#[command(author, version, about = "Kubernetes cluster inspection tool", long_about = None)]
-pub struct Args {
-    #[command(subcommand)]
-    pub command: Commands,
-}
-
-#[derive(Subcommand)]
-pub enum Commands {
-    /// Run cluster inspection
-    Check {
-        /// Cluster name for the report title (default: from kubeconfig or "default")
-        #[arg(long = "cluster-name", value_name = "NAME")]
-        cluster_name: Option<String>,
-
-        /// Namespace(s) scope for inspection: only resources in this namespace are inspected. When unset, all namespaces are inspected.
-        #[arg(short, long, value_name = "NAMESPACE")]
-        namespace: Option<String>,
-
-        /// Namespace where kubeowler-node-inspector DaemonSet runs; used only for node-level data collection. Default: kubeowler.
-        #[arg(
-            long = "node-inspector-namespace",
-            value_name = "NAMESPACE",
-            default_value = "kubeowler"
-        )]
-        node_inspector_namespace: String,
-
-        /// Output file path for the report; if not set, defaults to {cluster-name}-kubernetes-inspection-report-{YYYY-MM-DD-HHMMSS}.{ext}
-        #[arg(short, long)]
-        output: Option<String>,
-
-        /// Output format: md (default), json, csv, or html
-        #[arg(short, long, default_value = "md")]
-        format: ReportFormat,
-
-        /// Kubernetes config file path
-        #[arg(short, long)]
-        config_file: Option<String>,
-
-        /// Check levels to show in report: "all" or comma-separated (Info, warning, critical). Default: warning,critical.
-        #[arg(
-            short = 'l',
-            long = "level",
-            value_name = "LEVELS",
-            default_value = "warning,critical"
-        )]
-        level: String,
-    },
-}
-
-#[derive(Clone, Copy, ValueEnum, Debug, Default)]
-#[value(rename_all = "kebab-case")]
-pub enum ReportFormat {
+/// Enums selected by name on the command line (and in the config file).
+pub trait OptionValue: Sized + Clone + Send + Sync + 'static {
+    /// Every variant with its name and a one-line description, in the order `--help` lists them.
+    const VARIANTS: &'static [(Self, &'static str, &'static str)];
+
+    /// Variant called `name`.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .find(|(_, n, _)| *n == name)
+            .map(|(v, _, _)| v.clone())
+    }
+}
+
+/// Optional parts of the Markdown/HTML report (`--sections`); the title block and footer are
+/// always written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportSection {
+    Risks,
+    Overdue,
+    Overview,
+    Containers,
+    Nodes,
+    Events,
+    Namespaces,
+    Checks,
+    Issues,
+    Scoring,
+    Baseline,
+    Diagnostics,
+}
+
+impl OptionValue for ReportSection {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (
+            Self::Risks,
+            "risks",
+            "Top riskiest resources: findings per resource weighted by severity",
+        ),
+        (
+            Self::Overdue,
+            "overdue",
+            "Critical findings open longer than --critical-sla-days (needs --history-dir)",
+        ),
+        (
+            Self::Overview,
+            "overview",
+            "Data completeness and cluster overview (metrics, node pools, node conditions, workloads, storage)",
+        ),
+        (Self::Containers, "containers", "Container resource usage table"),
+        (
+            Self::Nodes,
+            "nodes",
+            "Node inspection tables from the node inspector DaemonSet",
+        ),
+        (Self::Events, "events", "Warning event analytics and recent events"),
+        (
+            Self::Namespaces,
+            "namespaces",
+            "Namespace ranking and namespace summary",
+        ),
+        (Self::Checks, "checks", "Check results table"),
+        (
+            Self::Issues,
+            "issues",
+            "Per-resource issue tables and remediation commands",
+        ),
+        (
+            Self::Scoring,
+            "scoring",
+            "Score methodology appendix (module weights, check scores, points lost)",
+        ),
+        (Self::Baseline, "baseline", "Findings accepted by --baseline"),
+        (
+            Self::Diagnostics,
+            "diagnostics",
+            "Run diagnostics (step timing, API traffic, data sources)",
+        ),
+    ];
+}
+
+/// How node-level data is read from the node inspector DaemonSet pods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NodeCollectMode {
+    #[default]
+    Logs,
+    Exec,
+}
+
+impl OptionValue for NodeCollectMode {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (
+            Self::Logs,
+            "logs",
+            "Parse the JSON the script printed at pod start (restarts pods when older than 24h)",
+        ),
+        (
+            Self::Exec,
+            "exec",
+            "Exec the script in each pod now for fresh data (needs pods/exec)",
+        ),
+    ];
+}
+
+/// Active tests that change nothing but do more than read the API (`--active-probes`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActiveProbe {
+    Network,
+}
+
+impl OptionValue for ActiveProbe {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[(
+        Self::Network,
+        "network",
+        "TCP connectivity matrix run from the node inspector pods",
+    )];
+}
+
+/// Language of the Markdown/HTML report.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Lang {
     #[default]
-    Md,
-    Json,
-    Csv,
-    Html,
+    En,
+    ZhCn,
+    Ja,
+}
+
+impl OptionValue for Lang {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (Self::En, "en", "English"),
+        (Self::ZhCn, "zh-CN", "Simplified Chinese"),
+        (Self::Ja, "ja", "Japanese"),
+    ];
 }
 
-#[derive(Clone, ValueEnum, Debug)]
-#[value(rename_all = "kebab-case")]
+/// How every cluster-connecting subcommand reaches the API server, on top of `--config-file`.
+/// `Default` matches the CLI defaults, for library callers.
+#[derive(Debug, Clone)]
+pub struct ClusterAccess {
+    /// kubeconfig context to use instead of the current context.
+    pub context: Option<String>,
+    /// Send requests as this user (Impersonate-User); needs `impersonate` RBAC.
+    pub impersonate: Option<String>,
+    /// Groups to impersonate along with `impersonate`.
+    pub impersonate_group: Vec<String>,
+    /// Maximum sustained API requests per second (0 = unlimited).
+    pub qps: f64,
+    /// API requests allowed at once above `qps`.
+    pub burst: u32,
+    /// Retries of a request answered with 429 (or 5xx / a connection error for reads).
+    pub max_retries: u32,
+    /// Seconds to wait for each API response (0 = no timeout).
+    pub request_timeout: u64,
+    /// Objects per LIST request (0 = one request).
+    pub page_size: u32,
+    /// Serve LIST requests from the API server watch cache (`resourceVersion=0`).
+    pub list_from_cache: bool,
+    /// Per-namespace and per-node requests in flight at once.
+    pub api_concurrency: u32,
+}
+
+impl Default for ClusterAccess {
+    fn default() -> Self {
+        Self {
+            context: None,
+            impersonate: None,
+            impersonate_group: Vec::new(),
+            qps: crate::k8s::throttle::DEFAULT_QPS,
+            burst: crate::k8s::throttle::DEFAULT_BURST,
+            max_retries: crate::k8s::throttle::DEFAULT_MAX_RETRIES,
+            request_timeout: crate::k8s::throttle::DEFAULT_REQUEST_TIMEOUT_SECS,
+            page_size: crate::k8s::client::DEFAULT_PAGE_SIZE,
+            list_from_cache: false,
+            api_concurrency: crate::k8s::client::DEFAULT_API_CONCURRENCY,
+        }
+    }
+}
+
+/// HTML report branding given on the command line; each value overrides `branding` in the
+/// config file (see [`Branding::merged`](crate::reporting::branding::Branding::merged)).
+#[derive(Debug, Default, Clone)]
+pub struct BrandOverrides {
+    pub brand_name: Option<String>,
+    pub brand_logo: Option<String>,
+    pub brand_color: Option<String>,
+    pub theme: Option<HtmlTheme>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HtmlTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl OptionValue for HtmlTheme {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (Self::Light, "light", "Light background"),
+        (Self::Dark, "dark", "Dark background"),
+    ];
+}
+
+/// Named weight sets for the overall score (see `scoring::profiles`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScoringProfileName {
+    #[default]
+    Balanced,
+    Security,
+    Reliability,
+    Custom,
+}
+
+impl OptionValue for ScoringProfileName {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (
+            Self::Balanced,
+            "balanced",
+            "Default weights across all modules",
+        ),
+        (
+            Self::Security,
+            "security",
+            "Weight security, policy, certificates and debug settings highest",
+        ),
+        (
+            Self::Reliability,
+            "reliability",
+            "Weight nodes, pods, control plane, autoscaling and storage highest",
+        ),
+        (
+            Self::Custom,
+            "custom",
+            "Balanced weights overridden by `scoring.weights` in the config file",
+        ),
+    ];
+}
+
+#[derive(Clone, Debug)]
 pub enum InspectionType {
-    /// Full cluster inspection (default)
     All,
-    /// Node health inspection
     Nodes,
-    /// Pod status inspection
     Pods,
-    /// Resource usage inspection
     Resources,
-    /// Network connectivity inspection
     Network,
-    /// Storage inspection
     Storage,
-    /// Security configuration inspection
     Security,
-    /// Control plane health inspection
     ControlPlane,
-    /// Autoscaling health inspection
     Autoscaling,
-    /// Batch and CronJob inspection
     Batch,
-    /// Namespace policies inspection (quota/limit/pdb)
     Policies,
-    /// Observability components inspection
     Observability,
-    /// Upgrade readiness inspection
+    Events,
+    DebugSettings,
+    Orphans,
+    Resilience,
     Upgrade,
-    /// Certificate (CSR) inspection
     Certificates,
+    Gpu,
+}
+
+impl OptionValue for InspectionType {
+    const VARIANTS: &'static [(Self, &'static str, &'static str)] = &[
+        (Self::All, "all", "Full cluster inspection (default)"),
+        (Self::Nodes, "nodes", "Node health inspection"),
+        (Self::Pods, "pods", "Pod status inspection"),
+        (Self::Resources, "resources", "Resource usage inspection"),
+        (Self::Network, "network", "Network connectivity inspection"),
+        (Self::Storage, "storage", "Storage inspection"),
+        (
+            Self::Security,
+            "security",
+            "Security configuration inspection",
+        ),
+        (
+            Self::ControlPlane,
+            "control-plane",
+            "Control plane health inspection",
+        ),
+        (
+            Self::Autoscaling,
+            "autoscaling",
+            "Autoscaling health inspection",
+        ),
+        (Self::Batch, "batch", "Batch and CronJob inspection"),
+        (
+            Self::Policies,
+            "policies",
+            "Namespace policies inspection (quota/limit/pdb)",
+        ),
+        (
+            Self::Observability,
+            "observability",
+            "Observability components inspection",
+        ),
+        (
+            Self::Events,
+            "events",
+            "Warning event analytics (noisy reasons, sustained patterns)",
+        ),
+        (
+            Self::DebugSettings,
+            "debug-settings",
+            "Debug settings left in production namespaces",
+        ),
+        (
+            Self::Orphans,
+            "orphans",
+            "Orphaned resources (unused ConfigMaps/Secrets/PVCs, stale Jobs/pods, ...)",
+        ),
+        (
+            Self::Resilience,
+            "resilience",
+            "Zone/topology resilience (single zone, replicas in one zone, topology spread)",
+        ),
+        (Self::Upgrade, "upgrade", "Upgrade readiness inspection"),
+        (
+            Self::Certificates,
+            "certificates",
+            "Certificate (CSR) inspection",
+        ),
+        (
+            Self::Gpu,
+            "gpu",
+            "GPUs and other extended resources (optional in a full run)",
+        ),
+    ];
 }
 
 impl FromStr for InspectionType {
@@ -111,8 +352,13 @@ impl FromStr for InspectionType {
             "batch" | "cron" => Ok(InspectionType::Batch),
             "policies" | "policy" => Ok(InspectionType::Policies),
             "observability" | "monitoring" => Ok(InspectionType::Observability),
+            "events" | "event" => Ok(InspectionType::Events),
+            "debug" | "debug-settings" => Ok(InspectionType::DebugSettings),
+            "orphans" | "orphaned" => Ok(InspectionType::Orphans),
+            "resilience" | "ha" => Ok(InspectionType::Resilience),
             "upgrade" | "upgrade-readiness" => Ok(InspectionType::Upgrade),
             "certificates" | "certificate" | "csr" => Ok(InspectionType::Certificates),
+            "gpu" | "extended-resources" => Ok(InspectionType::Gpu),
             _ => Err(format!("Unknown inspection type: {}", s)),
         }
     }
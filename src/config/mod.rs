@@ -0,0 +1,154 @@
+//! kubeowler configuration file: `--config <FILE>`, or `~/.config/kubeowler/config.yaml` when present.
+//! Command-line flags take precedence over values from the file.
+
+mod profile;
+
+pub use profile::Profile;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::cli::{ReportSection, ScoringProfileName};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub scoring: ScoringConfig,
+    pub tickets: TicketConfig,
+    pub branding: crate::reporting::branding::Branding,
+    pub report: ReportConfig,
+    /// Command-line flag defaults per subcommand (`check`, `render`, ...; `global` for all),
+    /// keyed by long flag name; applied by the CLI before parsing.
+    pub defaults: BTreeMap<String, BTreeMap<String, serde_yaml::Value>>,
+    /// Named presets selected with `check --profile` (see [`Profile`]).
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl Config {
+    /// Profile `name`, with its `scoring` section replacing the top-level one. An unknown name or
+    /// issue code is an error.
+    pub fn take_profile(&mut self, name: Option<&str>) -> Result<Option<Profile>> {
+        let Some(name) = name else {
+            return Ok(None);
+        };
+        let Some(mut profile) = self.profiles.remove(name) else {
+            bail!(
+                "profile '{}' is not defined in the config file (defined: {})",
+                name,
+                if self.profiles.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                }
+            );
+        };
+        profile.name = name.to_string();
+        profile.validate()?;
+        if let Some(scoring) = profile.scoring.take() {
+            self.scoring = scoring;
+        }
+        Ok(Some(profile))
+    }
+}
+
+/// `tickets` section of the config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TicketConfig {
+    /// Targets used when `--create-issues` is not given, e.g. `github:org/repo`, `jira:OPS`.
+    pub targets: Vec<String>,
+    /// GitHub API base URL (GitHub Enterprise: `https://ghe.example.com/api/v3`).
+    pub github_api_url: Option<String>,
+    /// Jira base URL, e.g. `https://example.atlassian.net`.
+    pub jira_url: Option<String>,
+    /// Jira issue type of new tickets. Default: Bug.
+    pub jira_issue_type: Option<String>,
+    /// Extra labels on new tickets.
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScoringConfig {
+    /// Profile used when `--scoring-profile` is not given.
+    pub profile: Option<ScoringProfileName>,
+    /// Module weights for the `custom` profile (inspection module name → weight).
+    pub weights: BTreeMap<String, f64>,
+    /// Lowest score of each health status / grade; default 90/80/70/60.
+    pub thresholds: Option<crate::scoring::HealthThresholds>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReportConfig {
+    /// Sections of Markdown/HTML reports when `--sections` is not given; empty means all.
+    pub sections: Vec<ReportSection>,
+}
+
+impl ReportConfig {
+    /// `--sections` when given, else the configured sections.
+    pub fn sections(&self, args: Vec<ReportSection>) -> Vec<ReportSection> {
+        if args.is_empty() {
+            self.sections.clone()
+        } else {
+            args
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/kubeowler/config.yaml`, falling back to `~/.config/kubeowler/config.yaml`.
+pub fn default_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(base.join("kubeowler").join("config.yaml"))
+}
+
+/// Load `path` (must exist), or the default file if it exists, or an empty config.
+pub fn load(path: Option<&str>) -> Result<Config> {
+    let path = match path {
+        Some(p) => PathBuf::from(p),
+        None => match default_path().filter(|p| p.is_file()) {
+            Some(p) => p,
+            None => return Ok(Config::default()),
+        },
+    };
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("reading config file {}", path.display()))?;
+    serde_yaml::from_str(&text).with_context(|| format!("parsing config file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scoring_section() {
+        let cfg: Config = serde_yaml::from_str(
+            "scoring:\n  profile: custom\n  weights:\n    Security Configuration: 3\n",
+        )
+        .unwrap();
+        assert_eq!(cfg.scoring.profile, Some(ScoringProfileName::Custom));
+        assert_eq!(cfg.scoring.weights["Security Configuration"], 3.0);
+        assert!(serde_yaml::from_str::<Config>("scoring:\n  profil: custom\n").is_err());
+    }
+
+    #[test]
+    fn report_sections_from_file_unless_given() {
+        let cfg: Config =
+            serde_yaml::from_str("report:\n  sections: [overview, issues]\n").unwrap();
+        assert_eq!(
+            cfg.report.sections(Vec::new()),
+            vec![ReportSection::Overview, ReportSection::Issues]
+        );
+        assert_eq!(
+            cfg.report.sections(vec![ReportSection::Nodes]),
+            vec![ReportSection::Nodes]
+        );
+        assert!(serde_yaml::from_str::<Config>("report:\n  sections: [summary]\n").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! Named profiles of the config file, selected with `check --profile <NAME>`:
+//!
+//! ```yaml
+//! profiles:
+//!   dev:
+//!     exclude_namespaces: [debug-*, sandbox]
+//!     severity:
+//!       SEC-005: Info        # privileged debug pods are expected here
+//!   prod:
+//!     severity:
+//!       POD-004: Critical
+//!     scoring:
+//!       profile: security
+//! ```
+//!
+//! A profile's `scoring` section replaces the top-level one; severity overrides and namespace
+//! exclusions are applied to the report after the checks ran.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use super::ScoringConfig;
+use crate::inspections::issue_codes;
+use crate::inspections::types::{ClusterReport, IssueSeverity, ProfileInfo};
+use crate::scoring::namespace::{issue_namespace, known_namespaces};
+use crate::scoring::{issue_penalty, rescale_module_score, rescore_report};
+use crate::utils::pattern::matches_any;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// Key under `profiles`.
+    #[serde(skip)]
+    pub name: String,
+    /// Issue code → severity replacing the rule's own.
+    pub severity: BTreeMap<String, IssueSeverity>,
+    /// Namespace globs whose findings are dropped from the report.
+    pub exclude_namespaces: Vec<String>,
+    /// Replaces the top-level `scoring` section.
+    pub scoring: Option<ScoringConfig>,
+}
+
+impl Profile {
+    /// Issue codes in `severity` must exist.
+    pub(super) fn validate(&self) -> Result<()> {
+        for code in self.severity.keys() {
+            if issue_codes::short_title(code).is_none() {
+                bail!(
+                    "config file: unknown issue code `{}` in profiles.{}.severity",
+                    code,
+                    self.name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop issues in excluded namespaces, override severities and re-score (see
+    /// [`rescale_module_score`]).
+    pub fn apply(&self, report: &mut ClusterReport) -> ProfileInfo {
+        let known = known_namespaces(&report.inspections);
+        let mut info = ProfileInfo {
+            name: self.name.clone(),
+            excluded: 0,
+            overridden: 0,
+        };
+        for inspection in report.inspections.iter_mut() {
+            let before: f64 = inspection.summary.issues.iter().map(issue_penalty).sum();
+            inspection.summary.issues.retain(|issue| {
+                let excluded = issue_namespace(issue, &known)
+                    .is_some_and(|ns| matches_any(&self.exclude_namespaces, &ns));
+                info.excluded += excluded as usize;
+                !excluded
+            });
+            for issue in inspection.summary.issues.iter_mut() {
+                let Some(severity) = issue.rule_id.as_ref().and_then(|c| self.severity.get(c))
+                else {
+                    continue;
+                };
+                if *severity != issue.severity {
+                    issue.severity = severity.clone();
+                    info.overridden += 1;
+                }
+            }
+            rescale_module_score(inspection, before);
+        }
+        rescore_report(report);
+        report.metadata.profile = Some(info.clone());
+        info
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::{InspectionResult, InspectionSummary, Issue};
+
+    fn issue(code: &str, resource: &str, severity: IssueSeverity) -> Issue {
+        Issue::new(severity, "Security", code, "", resource.to_string(), "")
+    }
+
+    fn report(issues: Vec<Issue>) -> ClusterReport {
+        let json = serde_json::json!({
+            "cluster_name": "test",
+            "report_id": "r",
+            "timestamp": "2026-01-01T00:00:00Z",
+            "overall_score": 60.0,
+            "inspections": [],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        });
+        let mut report: ClusterReport = serde_json::from_value(json).unwrap();
+        report.inspections = vec![InspectionResult {
+            inspection_type: "Security Configuration".to_string(),
+            timestamp: chrono::Utc::now(),
+            overall_score: 60.0,
+            checks: Vec::new(),
+            summary: InspectionSummary {
+                total_checks: 0,
+                passed_checks: 0,
+                warning_checks: 0,
+                critical_checks: 0,
+                error_checks: 0,
+                issues,
+            },
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        }];
+        report
+    }
+
+    #[test]
+    fn excludes_namespaces_and_overrides_severity() {
+        let mut profile: Profile =
+            serde_yaml::from_str("exclude_namespaces: [debug-*]\nseverity:\n  SEC-005: Info\n")
+                .unwrap();
+        profile.name = "dev".to_string();
+        profile.validate().unwrap();
+        let mut report = report(vec![
+            issue("SEC-005", "debug-tools/shell", IssueSeverity::Critical),
+            issue("SEC-005", "payments/api", IssueSeverity::Critical),
+        ]);
+        let info = profile.apply(&mut report);
+        assert_eq!((info.excluded, info.overridden), (1, 1));
+        let inspection = &report.inspections[0];
+        assert_eq!(inspection.summary.issues.len(), 1);
+        assert_eq!(inspection.summary.issues[0].severity, IssueSeverity::Info);
+        // 40 lost points * 0.5 / 20
+        assert!((inspection.overall_score - 99.0).abs() < 1e-9);
+        assert_eq!(report.metadata.profile.unwrap().name, "dev");
+
+        let bad: Profile = serde_yaml::from_str("severity:\n  SEC-999: Info\n").unwrap();
+        assert!(bad.validate().is_err());
+    }
+}
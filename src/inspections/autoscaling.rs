@@ -1,27 +1,66 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use chrono::Utc;
-use k8s_openapi::api::autoscaling::v2::{HPAScalingRules, MetricSpec, MetricTarget};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::{
+    HPAScalingRules, HorizontalPodAutoscaler, MetricSpec, MetricTarget,
+};
 use kube::api::ListParams;
 
+use crate::inspections::hpa_targets::{self, ScaleTarget};
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::replica_conflicts::{evaluate_replica_conflicts, ScaledWorkload};
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
 pub struct AutoscalingInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
 }
 
 impl<'a> AutoscalingInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
         let mut checks = Vec::new();
         let mut issues = Vec::new();
 
-        let hpa_check = self.inspect_hpas(namespace, &mut issues).await?;
+        let lp = ListParams::default();
+        let hpas = self
+            .client
+            .list_all(&self.client.horizontal_pod_autoscalers(namespace), &lp)
+            .await?
+            .items;
+        // Scale targets are looked up by name, whatever their labels.
+        let deployments = self
+            .client
+            .list_unselected(&self.client.deployments(namespace), &lp)
+            .await?
+            .items;
+        let stateful_sets = self
+            .client
+            .list_unselected(&self.client.stateful_sets(namespace), &lp)
+            .await?
+            .items;
+
+        let hpa_check = self.inspect_hpas(&hpas, &mut issues);
         checks.push(hpa_check);
 
+        let alignment_check = self
+            .inspect_replica_alignment(namespace, &hpas, &deployments, &stateful_sets, &mut issues)
+            .await?;
+        checks.push(alignment_check);
+
+        if !hpas.is_empty() {
+            let targets_check = self
+                .inspect_hpa_targets(namespace, &hpas, &deployments, &stateful_sets, &mut issues)
+                .await?;
+            checks.push(targets_check);
+        }
+
         let overall_score = if checks.is_empty() {
             0.0
         } else {
@@ -39,19 +78,18 @@ impl<'a> AutoscalingInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
-    async fn inspect_hpas(
+    fn inspect_hpas(
         &self,
-        namespace: Option<&str>,
+        hpas: &[HorizontalPodAutoscaler],
         issues: &mut Vec<Issue>,
-    ) -> Result<CheckResult> {
-        let hpa_api = self.client.horizontal_pod_autoscalers(namespace);
-        let hpas = hpa_api.list(&ListParams::default()).await?;
-
-        if hpas.items.is_empty() {
-            return Ok(CheckResult {
+    ) -> CheckResult {
+        if hpas.is_empty() {
+            return CheckResult {
                 name: "Horizontal Pod Autoscalers".to_string(),
                 description: "Evaluates health and configuration of HPAs".to_string(),
                 status: CheckStatus::Warning,
@@ -61,11 +99,11 @@ impl<'a> AutoscalingInspector<'a> {
                 recommendations: vec![
                     "Consider deploying HPAs to improve workload elasticity.".to_string()
                 ],
-            });
+            };
         }
 
         let mut healthy = 0usize;
-        for hpa in &hpas.items {
+        for hpa in hpas {
             let name = hpa
                 .metadata
                 .name
@@ -82,6 +120,10 @@ impl<'a> AutoscalingInspector<'a> {
                         recommendation: "Set a wider min/max replica range so the HPA can scale."
                             .to_string(),
                         rule_id: Some("AUTO-001".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
 
@@ -98,6 +140,10 @@ impl<'a> AutoscalingInspector<'a> {
                         recommendation: "Define CPU/Memory or custom metrics for this HPA."
                             .to_string(),
                         rule_id: Some("AUTO-002".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
 
@@ -127,13 +173,17 @@ impl<'a> AutoscalingInspector<'a> {
                                 "Check target workload readiness and metrics availability."
                                     .to_string(),
                             rule_id: Some("AUTO-003".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
             }
         }
 
-        let score = (healthy as f64 / hpas.items.len() as f64) * 100.0;
+        let score = (healthy as f64 / hpas.len() as f64) * 100.0;
         let status = if score >= 90.0 {
             CheckStatus::Pass
         } else if score >= 70.0 {
@@ -142,19 +192,132 @@ impl<'a> AutoscalingInspector<'a> {
             CheckStatus::Critical
         };
 
-        Ok(CheckResult {
+        CheckResult {
             name: "Horizontal Pod Autoscalers".to_string(),
             description: "Checks configuration and health of HPAs".to_string(),
             status,
             score,
             max_score: 100.0,
-            details: Some(format!("{}/{} HPAs healthy", healthy, hpas.items.len())),
+            details: Some(format!("{}/{} HPAs healthy", healthy, hpas.len())),
             recommendations: if score < 100.0 {
                 vec!["Ensure metrics.k8s.io and custom metric APIs are available, and verify workload readiness.".to_string()]
             } else {
                 vec![]
             },
-        })
+        }
+    }
+
+    async fn inspect_replica_alignment(
+        &self,
+        namespace: Option<&str>,
+        hpas: &[HorizontalPodAutoscaler],
+        deployments: &[Deployment],
+        stateful_sets: &[StatefulSet],
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let vpas = self
+            .client
+            .vertical_pod_autoscalers(namespace)
+            .await?
+            .unwrap_or_default();
+
+        let workloads: Vec<ScaledWorkload> = deployments
+            .iter()
+            .map(|d| ScaledWorkload {
+                kind: "Deployment",
+                meta: &d.metadata,
+                replicas: d.spec.as_ref().and_then(|s| s.replicas),
+            })
+            .chain(stateful_sets.iter().map(|s| ScaledWorkload {
+                kind: "StatefulSet",
+                meta: &s.metadata,
+                replicas: s.spec.as_ref().and_then(|s| s.replicas),
+            }))
+            .collect();
+        let (check, found) = evaluate_replica_conflicts(hpas, &workloads, &vpas);
+        issues.extend(found);
+        Ok(check)
+    }
+
+    /// Scale targets and their pod templates (ReplicaSets only listed when an HPA targets one),
+    /// and the metrics APIs the HPAs use, probed on the API server except for `--offline` dumps.
+    async fn inspect_hpa_targets(
+        &self,
+        namespace: Option<&str>,
+        hpas: &[HorizontalPodAutoscaler],
+        deployments: &[Deployment],
+        stateful_sets: &[StatefulSet],
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let replica_sets = if hpas.iter().any(|h| {
+            h.spec
+                .as_ref()
+                .is_some_and(|s| s.scale_target_ref.kind == "ReplicaSet")
+        }) {
+            self.client
+                .list_unselected(&self.client.replica_sets(namespace), &ListParams::default())
+                .await?
+                .items
+        } else {
+            Vec::new()
+        };
+        let targets: Vec<ScaleTarget> = deployments
+            .iter()
+            .map(|d| ScaleTarget {
+                kind: "Deployment",
+                meta: &d.metadata,
+                template: d.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+            })
+            .chain(stateful_sets.iter().map(|s| ScaleTarget {
+                kind: "StatefulSet",
+                meta: &s.metadata,
+                template: s.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+            }))
+            .chain(replica_sets.iter().map(|r| {
+                ScaleTarget {
+                    kind: "ReplicaSet",
+                    meta: &r.metadata,
+                    template: r
+                        .spec
+                        .as_ref()
+                        .and_then(|s| s.template.as_ref())
+                        .and_then(|t| t.spec.as_ref()),
+                }
+            }))
+            .collect();
+
+        let mut served = BTreeMap::new();
+        if !self.options.offline {
+            for api in hpa_targets::metric_apis(hpas) {
+                served.insert(api, self.metrics_api_served(api).await);
+            }
+        }
+        let (check, found) = hpa_targets::evaluate_hpa_targets(hpas, &targets, &served);
+        issues.extend(found);
+        Ok(check)
+    }
+
+    /// Whether a version of the metrics API `group` answers discovery: Some(false) on 404 or 503
+    /// (not registered, or its APIService backend is down), None when it could not be told.
+    async fn metrics_api_served(&self, group: &str) -> Option<bool> {
+        let versions: &[&str] = if group == hpa_targets::CUSTOM_METRICS_API {
+            &["v1beta2", "v1beta1"]
+        } else {
+            &["v1beta1"]
+        };
+        let mut served = None;
+        for version in versions {
+            match self
+                .client
+                .api_request_status(&format!("/apis/{}/{}", group, version))
+                .await
+            {
+                Ok(200) => return Some(true),
+                Ok(404 | 503) => served = Some(false),
+                _ => return None,
+            }
+        }
+        served
     }
 
     fn validate_metric(&self, metric: &MetricSpec, name: &str, issues: &mut Vec<Issue>) {
@@ -207,6 +370,10 @@ impl<'a> AutoscalingInspector<'a> {
                             "Review HPA behavior policy to ensure scaling is permitted when needed."
                                 .to_string(),
                         rule_id: Some("AUTO-004".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
             }
@@ -254,6 +421,10 @@ fn validate_target(target: &MetricTarget, metric_name: &str, hpa: &str, issues:
                 "Configure averageUtilization, averageValue, or value for the metric target."
                     .to_string(),
             rule_id: Some("AUTO-005".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
         });
     }
 }
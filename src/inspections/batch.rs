@@ -42,6 +42,8 @@ impl<'a> BatchInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
@@ -51,7 +53,10 @@ impl<'a> BatchInspector<'a> {
         issues: &mut Vec<Issue>,
     ) -> Result<CheckResult> {
         let cron_api = self.client.cron_jobs(namespace);
-        let cron_jobs = cron_api.list(&ListParams::default()).await?;
+        let cron_jobs = self
+            .client
+            .list_all(&cron_api, &ListParams::default())
+            .await?;
 
         if cron_jobs.items.is_empty() {
             return Ok(CheckResult {
@@ -76,6 +81,7 @@ impl<'a> BatchInspector<'a> {
                 .unwrap_or_else(|| "unknown".to_string());
             if let Some(spec) = &cron.spec {
                 if spec.suspend == Some(true) {
+                    let namespace = cron.metadata.namespace.as_deref().unwrap_or("default");
                     issues.push(Issue {
                         severity: IssueSeverity::Warning,
                         category: "Batch".to_string(),
@@ -83,6 +89,13 @@ impl<'a> BatchInspector<'a> {
                         resource: Some(name.clone()),
                         recommendation: "Enable CronJob or remove if no longer needed.".to_string(),
                         rule_id: Some("BATCH-001".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: vec![format!(
+                            "kubectl -n {} patch cronjob {} -p '{}'",
+                            namespace, name, r#"{"spec":{"suspend":false}}"#
+                        )],
                     });
                     continue;
                 }
@@ -103,6 +116,10 @@ impl<'a> BatchInspector<'a> {
                                 "Check CronJob job logs and fix failures before next schedule."
                                     .to_string(),
                             rule_id: Some("BATCH-002".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                         continue;
                     }
@@ -118,6 +135,10 @@ impl<'a> BatchInspector<'a> {
                             "Ensure CronJob schedule is correct and controller is running."
                                 .to_string(),
                         rule_id: Some("BATCH-003".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                     continue;
                 }
@@ -163,7 +184,10 @@ impl<'a> BatchInspector<'a> {
         } else {
             kube::Api::all(self.client.client().clone())
         };
-        let jobs = job_api.list(&ListParams::default()).await?;
+        let jobs = self
+            .client
+            .list_all(&job_api, &ListParams::default())
+            .await?;
 
         if jobs.items.is_empty() {
             return Ok(CheckResult {
@@ -197,6 +221,14 @@ impl<'a> BatchInspector<'a> {
                             "Inspect job pod logs and adjust backoffLimit or resource requests."
                                 .to_string(),
                         rule_id: Some("BATCH-004".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: vec![format!(
+                            "kubectl -n {} logs job/{}",
+                            job.metadata.namespace.as_deref().unwrap_or("default"),
+                            name
+                        )],
                     });
                     continue;
                 }
@@ -214,6 +246,10 @@ impl<'a> BatchInspector<'a> {
                                     "Check for stuck pods or adjust activeDeadlineSeconds."
                                         .to_string(),
                                 rule_id: Some("BATCH-005".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: Vec::new(),
                             });
                             continue;
                         }
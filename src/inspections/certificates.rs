@@ -5,11 +5,33 @@
 use anyhow::Result;
 use chrono::Utc;
 use kube::api::ListParams;
+use x509_parser::certificate::Validity;
 use x509_parser::pem::Pem;
 
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
+/// Whole days until notAfter (negative once expired).
+fn days_until_expiry(validity: &Validity) -> i64 {
+    match validity.time_to_expiration() {
+        Some(d) => d.whole_days(),
+        None => {
+            let now = time::OffsetDateTime::now_utc();
+            let not_after = validity.not_after.to_datetime();
+            (not_after - now).whole_days()
+        }
+    }
+}
+
+/// Days until expiry of the first (leaf) certificate in a PEM `tls.crt`; None if none parses.
+pub fn leaf_certificate_days(pem_bytes: &[u8]) -> Option<i64> {
+    Pem::iter_from_buffer(pem_bytes).flatten().find_map(|pem| {
+        pem.parse_x509()
+            .ok()
+            .map(|c| days_until_expiry(c.validity()))
+    })
+}
+
 pub struct CertificateInspector<'a> {
     client: &'a K8sClient,
 }
@@ -50,13 +72,18 @@ impl<'a> CertificateInspector<'a> {
             },
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
     /// List TLS secrets, parse tls.crt, and return (CheckResult, CertificateExpiryRow list).
     async fn inspect_tls_certificates(&self) -> Result<(CheckResult, Vec<CertificateExpiryRow>)> {
         let secrets_api = self.client.secrets(None);
-        let list = secrets_api.list(&ListParams::default()).await?;
+        let list = self
+            .client
+            .list_all(&secrets_api, &ListParams::default())
+            .await?;
         let mut rows = Vec::new();
         let mut total_certs = 0usize;
         let mut expiring_90 = 0usize;
@@ -106,14 +133,7 @@ impl<'a> CertificateInspector<'a> {
                 };
                 let validity = x509.validity();
                 let expiry_utc = format!("{}", validity.not_after);
-                let days = match validity.time_to_expiration() {
-                    Some(d) => d.whole_days(),
-                    None => {
-                        let now = time::OffsetDateTime::now_utc();
-                        let not_after = validity.not_after.to_datetime();
-                        (not_after - now).whole_days()
-                    }
-                };
+                let days = days_until_expiry(validity);
                 if days < 0 {
                     expired += 1;
                 } else if days <= 30 {
@@ -173,7 +193,7 @@ impl<'a> CertificateInspector<'a> {
 
     async fn inspect_csrs(&self, issues: &mut Vec<Issue>) -> Result<CheckResult> {
         let api = self.client.certificate_signing_requests();
-        let list = api.list(&ListParams::default()).await?;
+        let list = self.client.list_all(&api, &ListParams::default()).await?;
         let total = list.items.len();
         let mut pending = 0usize;
         let mut denied_or_failed = 0usize;
@@ -214,6 +234,10 @@ impl<'a> CertificateInspector<'a> {
                     recommendation: "Review and clean up denied/failed CSRs; re-issue if needed."
                         .to_string(),
                     rule_id: Some("CERT-001".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
                 });
             } else if !has_approved {
                 pending += 1;
@@ -224,6 +248,10 @@ impl<'a> CertificateInspector<'a> {
                     resource: Some(name),
                     recommendation: "Approve or deny pending CSRs (e.g. kubectl certificate approve/deny). Cluster component cert expiry (apiserver/etcd/kubelet) must be checked on nodes (e.g. kubeadm cert check-expiry).".to_string(),
                     rule_id: Some("CERT-001".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
                 });
             }
         }
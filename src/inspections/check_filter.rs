@@ -0,0 +1,152 @@
+//! `--only-checks` / `--skip-checks`: issue code globs (`POD-*,NET-*`, `SEC-004,RES-*`) that
+//! decide which rules run. A module none of whose codes is selected is not started, so its API
+//! calls are not made; findings of the modules that do run are filtered by code.
+
+use anyhow::{bail, Result};
+
+use crate::inspections::issue_docs;
+use crate::inspections::types::InspectionResult;
+use crate::scoring::{issue_penalty, rescale_module_score};
+use crate::utils::pattern::{glob_match, matches_any, parse_pattern_list};
+
+/// Module (`inspection_type`) → prefixes of the issue codes it reports. Modules without codes
+/// (Namespace, Upgrade Readiness) only run when `--only-checks` is unset.
+const MODULE_PREFIXES: &[(&str, &[&str])] = &[
+    ("Node Health", &["NODE"]),
+    ("Control Plane", &["CTRL"]),
+    ("Network Connectivity", &["NET"]),
+    ("Storage", &["STO"]),
+    ("Orphaned Resources", &["ORPH"]),
+    ("Resilience", &["HA", "STS", "DS"]),
+    ("Resource Usage", &["RES"]),
+    ("GPU & Extended Resources", &["GPU"]),
+    ("Pod Status", &["POD"]),
+    ("Autoscaling", &["AUTO"]),
+    ("Batch Workloads", &["BATCH"]),
+    ("Security Configuration", &["SEC"]),
+    ("Policy & Governance", &["POLICY"]),
+    ("Observability", &["OBS"]),
+    ("Event Analytics", &["EVT"]),
+    ("Debug Settings", &["DBG"]),
+    ("Certificates", &["CERT"]),
+];
+
+/// Module failures are always reported.
+const ALWAYS_KEPT: &str = "INSP-001";
+
+#[derive(Debug, Clone, Default)]
+pub struct CheckFilter {
+    only: Vec<String>,
+    skip: Vec<String>,
+}
+
+impl CheckFilter {
+    /// Parse the comma-separated `--only-checks` and `--skip-checks` values. A pattern matching
+    /// no issue code is an error, so a typo does not silently select nothing.
+    pub fn parse(only: &str, skip: &str) -> Result<Self> {
+        let filter = Self {
+            only: parse_pattern_list(only),
+            skip: parse_pattern_list(skip),
+        };
+        for (flag, patterns) in [
+            ("--only-checks", &filter.only),
+            ("--skip-checks", &filter.skip),
+        ] {
+            if let Some(p) = patterns
+                .iter()
+                .find(|p| !issue_docs::codes().any(|code| glob_match(p, code)))
+            {
+                bail!("{}: `{}` matches no issue code", flag, p);
+            }
+        }
+        Ok(filter)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty()
+    }
+
+    /// `only POD-*, NET-*; skip SEC-004` for the report header, or None without a filter.
+    pub fn describe(&self) -> Option<String> {
+        let parts: Vec<String> = [("only", &self.only), ("skip", &self.skip)]
+            .into_iter()
+            .filter(|(_, patterns)| !patterns.is_empty())
+            .map(|(label, patterns)| format!("{} {}", label, patterns.join(", ")))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("; "))
+    }
+
+    /// Whether findings with issue `code` are reported.
+    pub fn selects(&self, code: &str) -> bool {
+        code == ALWAYS_KEPT
+            || ((self.only.is_empty() || matches_any(&self.only, code))
+                && !matches_any(&self.skip, code))
+    }
+
+    /// Whether `module` has at least one selected code and should run.
+    pub fn runs_module(&self, module: &str) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let Some((_, prefixes)) = MODULE_PREFIXES.iter().find(|(m, _)| *m == module) else {
+            return self.only.is_empty();
+        };
+        issue_docs::codes()
+            .filter(|code| {
+                code.split_once('-')
+                    .is_some_and(|(prefix, _)| prefixes.contains(&prefix))
+            })
+            .any(|code| self.selects(code))
+    }
+
+    /// Drop findings whose code is not selected and re-scale the module scores; returns the
+    /// number of findings dropped.
+    pub fn apply(&self, inspections: &mut [InspectionResult]) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let mut dropped = 0;
+        for inspection in inspections.iter_mut() {
+            let before: f64 = inspection.summary.issues.iter().map(issue_penalty).sum();
+            let count = inspection.summary.issues.len();
+            inspection
+                .summary
+                .issues
+                .retain(|issue| issue.rule_id.as_deref().is_none_or(|c| self.selects(c)));
+            dropped += count - inspection.summary.issues.len();
+            rescale_module_score(inspection, before);
+        }
+        dropped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_and_skip_select_codes_and_modules() {
+        let filter = CheckFilter::parse("POD-*,SEC-*", "SEC-004").unwrap();
+        assert!(filter.selects("POD-002"));
+        assert!(filter.selects("SEC-005"));
+        assert!(!filter.selects("SEC-004"));
+        assert!(!filter.selects("NET-001"));
+        assert!(filter.selects("INSP-001"));
+        assert_eq!(
+            filter.describe().as_deref(),
+            Some("only POD-*, SEC-*; skip SEC-004")
+        );
+        assert!(filter.runs_module("Pod Status"));
+        assert!(filter.runs_module("Security Configuration"));
+        assert!(!filter.runs_module("Network Connectivity"));
+        assert!(!filter.runs_module("Namespace"));
+
+        let skip = CheckFilter::parse("", "NET-*").unwrap();
+        assert!(!skip.runs_module("Network Connectivity"));
+        assert!(skip.runs_module("Namespace"));
+        assert!(skip.runs_module("Storage"));
+
+        assert!(CheckFilter::parse("SECX-*", "").is_err());
+        assert!(CheckFilter::parse("", "").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,425 @@
+//! Referential integrity of ConfigMap and Secret references in pod specs. A volume, `envFrom`,
+//! `configMapKeyRef` or `secretKeyRef` naming an object or key that does not exist keeps running
+//! pods alive, but the next pod started from the spec (rollout, eviction, node drain) is stuck in
+//! CreateContainerConfigError or ContainerCreating. References marked `optional: true` are skipped.
+
+use std::collections::{BTreeSet, HashMap};
+
+use k8s_openapi::api::core::v1::{ConfigMap, KeyToPath, PodSpec, Secret};
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+type NsName = (String, String);
+
+/// Keys of the ConfigMaps and Secrets that exist, by (namespace, name).
+#[derive(Debug, Default)]
+pub struct ConfigObjects {
+    config_maps: HashMap<NsName, BTreeSet<String>>,
+    secrets: HashMap<NsName, BTreeSet<String>>,
+}
+
+impl ConfigObjects {
+    pub fn new(config_maps: &[ConfigMap], secrets: &[Secret]) -> Self {
+        let key = |meta: &kube::api::ObjectMeta| {
+            (
+                meta.namespace.clone().unwrap_or_default(),
+                meta.name.clone().unwrap_or_default(),
+            )
+        };
+        Self {
+            config_maps: config_maps
+                .iter()
+                .map(|c| {
+                    let keys = c
+                        .data
+                        .iter()
+                        .flat_map(|d| d.keys())
+                        .chain(c.binary_data.iter().flat_map(|d| d.keys()))
+                        .cloned()
+                        .collect();
+                    (key(&c.metadata), keys)
+                })
+                .collect(),
+            secrets: secrets
+                .iter()
+                .map(|s| {
+                    let keys = s
+                        .data
+                        .iter()
+                        .flat_map(|d| d.keys())
+                        .chain(s.string_data.iter().flat_map(|d| d.keys()))
+                        .cloned()
+                        .collect();
+                    (key(&s.metadata), keys)
+                })
+                .collect(),
+        }
+    }
+
+    fn keys(&self, kind: &str, namespace: &str, name: &str) -> Option<&BTreeSet<String>> {
+        let objects = if kind == "ConfigMap" {
+            &self.config_maps
+        } else {
+            &self.secrets
+        };
+        objects.get(&(namespace.to_string(), name.to_string()))
+    }
+}
+
+/// A reference to a missing ConfigMap or Secret (`key` None) or to a missing key of one.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DanglingRef {
+    pub kind: &'static str,
+    pub name: String,
+    pub key: Option<String>,
+    /// Where the spec uses it, e.g. `volume cfg` or `env DB_URL of container api`.
+    pub usage: String,
+}
+
+struct Reference<'a> {
+    kind: &'static str,
+    name: &'a str,
+    keys: Vec<&'a str>,
+    usage: String,
+}
+
+fn item_keys(items: &Option<Vec<KeyToPath>>) -> Vec<&str> {
+    items.iter().flatten().map(|i| i.key.as_str()).collect()
+}
+
+fn add<'a>(
+    refs: &mut Vec<Reference<'a>>,
+    kind: &'static str,
+    name: Option<&'a String>,
+    optional: Option<bool>,
+    keys: Vec<&'a str>,
+    usage: String,
+) {
+    if let Some(name) = name.filter(|_| optional != Some(true)) {
+        refs.push(Reference {
+            kind,
+            name,
+            keys,
+            usage,
+        });
+    }
+}
+
+/// Required ConfigMap and Secret references of `spec`, with the keys each one needs.
+fn references(spec: &PodSpec) -> Vec<Reference<'_>> {
+    let mut refs = Vec::new();
+    for v in spec.volumes.iter().flatten() {
+        let usage = || format!("volume {}", v.name);
+        if let Some(c) = &v.config_map {
+            add(
+                &mut refs,
+                "ConfigMap",
+                c.name.as_ref(),
+                c.optional,
+                item_keys(&c.items),
+                usage(),
+            );
+        }
+        if let Some(s) = &v.secret {
+            add(
+                &mut refs,
+                "Secret",
+                s.secret_name.as_ref(),
+                s.optional,
+                item_keys(&s.items),
+                usage(),
+            );
+        }
+        for source in v.projected.iter().flat_map(|p| p.sources.iter().flatten()) {
+            if let Some(c) = &source.config_map {
+                add(
+                    &mut refs,
+                    "ConfigMap",
+                    c.name.as_ref(),
+                    c.optional,
+                    item_keys(&c.items),
+                    usage(),
+                );
+            }
+            if let Some(s) = &source.secret {
+                add(
+                    &mut refs,
+                    "Secret",
+                    s.name.as_ref(),
+                    s.optional,
+                    item_keys(&s.items),
+                    usage(),
+                );
+            }
+        }
+    }
+    for c in spec
+        .init_containers
+        .iter()
+        .flatten()
+        .chain(&spec.containers)
+    {
+        for e in c.env.iter().flatten() {
+            let usage = || format!("env {} of container {}", e.name, c.name);
+            let Some(from) = &e.value_from else { continue };
+            if let Some(r) = &from.config_map_key_ref {
+                add(
+                    &mut refs,
+                    "ConfigMap",
+                    r.name.as_ref(),
+                    r.optional,
+                    vec![r.key.as_str()],
+                    usage(),
+                );
+            }
+            if let Some(r) = &from.secret_key_ref {
+                add(
+                    &mut refs,
+                    "Secret",
+                    r.name.as_ref(),
+                    r.optional,
+                    vec![r.key.as_str()],
+                    usage(),
+                );
+            }
+        }
+        for ef in c.env_from.iter().flatten() {
+            let usage = || format!("envFrom of container {}", c.name);
+            if let Some(r) = &ef.config_map_ref {
+                add(
+                    &mut refs,
+                    "ConfigMap",
+                    r.name.as_ref(),
+                    r.optional,
+                    Vec::new(),
+                    usage(),
+                );
+            }
+            if let Some(r) = &ef.secret_ref {
+                add(
+                    &mut refs,
+                    "Secret",
+                    r.name.as_ref(),
+                    r.optional,
+                    Vec::new(),
+                    usage(),
+                );
+            }
+        }
+    }
+    refs
+}
+
+/// References of `spec` (in `namespace`) to ConfigMaps, Secrets or keys missing from `objects`.
+pub fn dangling_refs(namespace: &str, spec: &PodSpec, objects: &ConfigObjects) -> Vec<DanglingRef> {
+    let mut found = BTreeSet::new();
+    for r in references(spec) {
+        let dangling = |key: Option<&str>| DanglingRef {
+            kind: r.kind,
+            name: r.name.to_string(),
+            key: key.map(str::to_string),
+            usage: r.usage.clone(),
+        };
+        match objects.keys(r.kind, namespace, r.name) {
+            None => {
+                found.insert(dangling(None));
+            }
+            Some(keys) => {
+                for key in r.keys.iter().filter(|k| !keys.contains(**k)) {
+                    found.insert(dangling(Some(key)));
+                }
+            }
+        }
+    }
+    found.into_iter().collect()
+}
+
+/// "Config References" check over `sources`, each a (`Kind namespace/name` label, namespace, pod
+/// spec): ORPH-011 per reference to a missing ConfigMap or Secret, ORPH-012 per missing key.
+pub fn reference_check(
+    sources: &[(String, String, &PodSpec)],
+    objects: &ConfigObjects,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut flagged = 0usize;
+    for (source, namespace, spec) in sources {
+        let dangling = dangling_refs(namespace, spec, objects);
+        if dangling.is_empty() {
+            continue;
+        }
+        flagged += 1;
+        let (kind, resource) = source.split_once(' ').unwrap_or(("", source.as_str()));
+        for d in dangling {
+            let (code, description) = match &d.key {
+                None => (
+                    "ORPH-011",
+                    format!(
+                        "{} references {} {}/{}, which does not exist ({})",
+                        source, d.kind, namespace, d.name, d.usage
+                    ),
+                ),
+                Some(key) => (
+                    "ORPH-012",
+                    format!(
+                        "{} references key {} of {} {}/{}, which has no such key ({})",
+                        source, key, d.kind, namespace, d.name, d.usage
+                    ),
+                ),
+            };
+            let mut key_attributes = vec![
+                format!("kind={}", kind),
+                format!("{}={}", d.kind.to_lowercase(), d.name),
+            ];
+            key_attributes.extend(d.key.iter().map(|k| format!("key={}", k)));
+            key_attributes.push(format!("usage={}", d.usage));
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: d.kind.to_string(),
+                description,
+                resource: Some(resource.to_string()),
+                recommendation: format!(
+                    "Create the {} (or key), fix the reference or mark it optional; new pods fail with CreateContainerConfigError until then.",
+                    d.kind
+                ),
+                rule_id: Some(code.to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes,
+                remediation_commands: Vec::new(),
+            });
+        }
+    }
+    let evaluated = sources.len();
+    CheckResult {
+        name: "Config References".to_string(),
+        description:
+            "ConfigMap and Secret references in pod specs point to existing objects and keys"
+                .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} pods and workloads with resolvable ConfigMap/Secret references",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![
+                "See ORPH-011/ORPH-012; fix dangling references before the next rollout."
+                    .to_string(),
+            ]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        ConfigMapEnvSource, ConfigMapVolumeSource, Container, EnvFromSource, EnvVar, EnvVarSource,
+        SecretKeySelector, Volume,
+    };
+    use kube::api::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some("shop".to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finds_missing_objects_and_keys_but_skips_optional_references() {
+        let config_maps = vec![ConfigMap {
+            metadata: meta("app-config"),
+            data: Some(BTreeMap::from([("app.yaml".to_string(), String::new())])),
+            ..Default::default()
+        }];
+        let secrets = vec![Secret {
+            metadata: meta("db"),
+            data: Some(BTreeMap::new()),
+            ..Default::default()
+        }];
+        let objects = ConfigObjects::new(&config_maps, &secrets);
+        let spec = PodSpec {
+            volumes: Some(vec![Volume {
+                name: "cfg".to_string(),
+                config_map: Some(ConfigMapVolumeSource {
+                    name: Some("app-config".to_string()),
+                    items: Some(vec![KeyToPath {
+                        key: "app.yaml".to_string(),
+                        path: "app.yaml".to_string(),
+                        mode: None,
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            containers: vec![Container {
+                name: "api".to_string(),
+                env: Some(vec![EnvVar {
+                    name: "DB_PASSWORD".to_string(),
+                    value_from: Some(EnvVarSource {
+                        secret_key_ref: Some(SecretKeySelector {
+                            name: Some("db".to_string()),
+                            key: "password".to_string(),
+                            optional: None,
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                env_from: Some(vec![
+                    EnvFromSource {
+                        config_map_ref: Some(ConfigMapEnvSource {
+                            name: Some("feature-flags".to_string()),
+                            optional: None,
+                        }),
+                        ..Default::default()
+                    },
+                    EnvFromSource {
+                        config_map_ref: Some(ConfigMapEnvSource {
+                            name: Some("overrides".to_string()),
+                            optional: Some(true),
+                        }),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let sources = vec![("Deployment shop/api".to_string(), "shop".to_string(), &spec)];
+        let mut issues = Vec::new();
+        let check = reference_check(&sources, &objects, &mut issues);
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| (i.rule_id.as_deref().unwrap(), i.category.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("ORPH-011", "ConfigMap"), ("ORPH-012", "Secret")]
+        );
+        assert_eq!(issues[0].resource.as_deref(), Some("shop/api"));
+        assert!(issues[1]
+            .description
+            .contains("key password of Secret shop/db"));
+        assert_eq!(
+            issues[1].key_attributes[..3],
+            ["kind=Deployment", "secret=db", "key=password"]
+        );
+        assert_eq!(check.score, 0.0);
+    }
+}
@@ -0,0 +1,578 @@
+//! Active network probes (`--active-probes network`), run from the node inspector pods with
+//! `pods/exec`: every pod opens TCP connections to the `kubernetes` and DNS Service VIPs, to the
+//! inspector pods on other nodes, to other nodes' kubelet port and to an external egress target.
+//! A refused connection still proves the path works; only timeouts and other errors count as loss.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use k8s_openapi::api::core::v1::{Node, Pod, Service};
+use kube::api::ListParams;
+use log::debug;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::k8s::K8sClient;
+use crate::node_inspection::collector::{exec_in_inspector, is_pod_running, NODE_INSPECTOR_LABEL};
+
+/// Default external egress target (an anycast IP, so DNS is not part of the path).
+pub const DEFAULT_EGRESS_TARGET: &str = "1.1.1.1:443";
+/// Connection attempts per path; loss is the share of attempts that timed out or errored.
+const ATTEMPTS: usize = 3;
+/// Per-attempt connect timeout.
+const CONNECT_TIMEOUT_SECS: u64 = 2;
+/// Peers (inspector pods, nodes) each pod probes, the next ones in node-name order; keeps the
+/// matrix linear in the node count on large clusters.
+const MAX_PEERS: usize = 5;
+/// Average in-cluster connect time above which a path is reported as slow.
+const SLOW_PATH_MS: u64 = 200;
+/// Upper bound for one pod's whole probe run.
+const EXEC_TIMEOUT_SECS: u64 = 120;
+/// Port probed on peer inspector pods; nothing listens there, so a reachable pod refuses.
+const POD_PROBE_PORT: u16 = 1;
+/// Kubelet port probed on peer nodes.
+const KUBELET_PORT: u16 = 10250;
+
+/// Opens one TCP connection per attempt to each `host:port` argument with bash `/dev/tcp` and
+/// prints `<index> <open|refused|timeout|error> <ms>`.
+const PROBE_SCRIPT: &str = r#"i=0
+for t in "$@"; do
+  host=${t%:*}; port=${t##*:}; host=${host#[}; host=${host%]}
+  for a in $(seq "$ATTEMPTS"); do
+    s=$(date +%s%N)
+    err=$(timeout "$CONNECT_TIMEOUT" bash -c "exec 3<>/dev/tcp/$host/$port" 2>&1); rc=$?
+    ms=$(( ($(date +%s%N) - s) / 1000000 ))
+    if [ $rc -eq 0 ]; then r=open
+    elif [ $rc -eq 124 ]; then r=timeout
+    elif echo "$err" | grep -qi refused; then r=refused
+    else r=error; fi
+    echo "$i $r $ms"
+  done
+  i=$((i + 1))
+done"#;
+
+/// Kind of path a probe exercises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProbeKind {
+    /// Pod to a Service ClusterIP (kube-proxy / eBPF service dataplane).
+    ServiceVip,
+    /// Pod to a pod on another node (CNI overlay or routing).
+    CrossNodePod,
+    /// Pod to another node's InternalIP (kubelet port).
+    NodeToNode,
+    /// Pod to a target outside the cluster (NAT / egress).
+    Egress,
+}
+
+impl ProbeKind {
+    fn label(self) -> &'static str {
+        match self {
+            ProbeKind::ServiceVip => "Pod → Service VIP",
+            ProbeKind::CrossNodePod => "Pod → Pod (cross-node)",
+            ProbeKind::NodeToNode => "Pod → Node (kubelet)",
+            ProbeKind::Egress => "Pod → External egress",
+        }
+    }
+
+    /// Issue code and severity when every attempt on a path fails.
+    fn failure(self) -> (&'static str, IssueSeverity) {
+        match self {
+            ProbeKind::ServiceVip => ("NET-015", IssueSeverity::Critical),
+            ProbeKind::CrossNodePod => ("NET-016", IssueSeverity::Critical),
+            ProbeKind::NodeToNode => ("NET-017", IssueSeverity::Warning),
+            ProbeKind::Egress => ("NET-018", IssueSeverity::Warning),
+        }
+    }
+
+    fn recommendation(self) -> &'static str {
+        match self {
+            ProbeKind::ServiceVip => "Check kube-proxy (or the CNI's service replacement) on the source node and the Service endpoints.",
+            ProbeKind::CrossNodePod => "Check the CNI between the two nodes: overlay/BGP state, MTU, and firewall rules for the encapsulation port; NetworkPolicies in the node inspector namespace also block this probe.",
+            ProbeKind::NodeToNode => "Check security groups / host firewalls between the nodes for the kubelet port (10250).",
+            ProbeKind::Egress => "Check NAT gateway, egress firewall or proxy settings; pass --probe-egress-target \"\" to skip this probe in air-gapped clusters.",
+        }
+    }
+}
+
+/// One path probed from one node.
+#[derive(Debug, Clone)]
+pub struct ProbeTarget {
+    pub kind: ProbeKind,
+    /// What is probed, e.g. `default/kubernetes` or a node name.
+    pub name: String,
+    /// `host:port` connected to.
+    pub address: String,
+}
+
+/// Outcome of one path after all attempts.
+#[derive(Debug, Clone)]
+pub struct ProbeResult {
+    pub source_node: String,
+    pub target: ProbeTarget,
+    pub attempts: usize,
+    /// Attempts that connected or were refused.
+    pub reachable: usize,
+    /// Average time of reachable attempts, ms.
+    pub avg_ms: Option<u64>,
+}
+
+impl ProbeResult {
+    fn loss_percent(&self) -> f64 {
+        if self.attempts == 0 {
+            return 0.0;
+        }
+        (self.attempts - self.reachable) as f64 * 100.0 / self.attempts as f64
+    }
+}
+
+fn node_of(pod: &Pod) -> Option<&str> {
+    pod.spec.as_ref()?.node_name.as_deref()
+}
+
+fn host_port(host: &str, port: u16) -> String {
+    if host.contains(':') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+fn internal_ip(node: &Node) -> Option<&str> {
+    node.status
+        .as_ref()?
+        .addresses
+        .as_ref()?
+        .iter()
+        .find(|a| a.type_ == "InternalIP")
+        .map(|a| a.address.as_str())
+}
+
+/// `kubernetes` (443) and the cluster DNS Service (TCP 53) ClusterIPs.
+fn service_targets(services: &[Service]) -> Vec<ProbeTarget> {
+    let mut targets = Vec::new();
+    for (ns, name, port) in [
+        ("default", "kubernetes", 443),
+        ("kube-system", "kube-dns", 53),
+    ] {
+        let ip = services
+            .iter()
+            .find(|s| {
+                s.metadata.namespace.as_deref() == Some(ns)
+                    && s.metadata.name.as_deref() == Some(name)
+            })
+            .and_then(|s| s.spec.as_ref()?.cluster_ip.as_deref())
+            .filter(|ip| !ip.is_empty() && *ip != "None");
+        if let Some(ip) = ip {
+            targets.push(ProbeTarget {
+                kind: ProbeKind::ServiceVip,
+                name: format!("{}/{}", ns, name),
+                address: host_port(ip, port),
+            });
+        }
+    }
+    targets
+}
+
+/// Probe plan: (source pod, source node, targets) for every Running inspector pod with an IP.
+pub fn plan_probes(
+    inspector_pods: &[Pod],
+    nodes: &[Node],
+    services: &[Service],
+    egress_target: Option<&str>,
+) -> Vec<(String, String, Vec<ProbeTarget>)> {
+    let mut sources: Vec<(&str, &str, &str)> = inspector_pods
+        .iter()
+        .filter(|p| is_pod_running(p))
+        .filter_map(|p| {
+            Some((
+                p.metadata.name.as_deref()?,
+                node_of(p)?,
+                p.status.as_ref()?.pod_ip.as_deref()?,
+            ))
+        })
+        .collect();
+    sources.sort_by_key(|(_, node, _)| *node);
+    let mut node_ips: Vec<(&str, &str)> = nodes
+        .iter()
+        .filter_map(|n| Some((n.metadata.name.as_deref()?, internal_ip(n)?)))
+        .collect();
+    node_ips.sort();
+    let services = service_targets(services);
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, (pod, node, _))| {
+            let mut targets = services.clone();
+            let peers = sources.len().saturating_sub(1).min(MAX_PEERS);
+            for k in 1..=peers {
+                let (_, peer_node, peer_ip) = sources[(i + k) % sources.len()];
+                targets.push(ProbeTarget {
+                    kind: ProbeKind::CrossNodePod,
+                    name: peer_node.to_string(),
+                    address: host_port(peer_ip, POD_PROBE_PORT),
+                });
+            }
+            if let Some(at) = node_ips.iter().position(|(n, _)| n == node) {
+                let peers = node_ips.len().saturating_sub(1).min(MAX_PEERS);
+                for k in 1..=peers {
+                    let (peer, ip) = node_ips[(at + k) % node_ips.len()];
+                    targets.push(ProbeTarget {
+                        kind: ProbeKind::NodeToNode,
+                        name: peer.to_string(),
+                        address: host_port(ip, KUBELET_PORT),
+                    });
+                }
+            }
+            if let Some(egress) = egress_target.filter(|t| !t.is_empty()) {
+                targets.push(ProbeTarget {
+                    kind: ProbeKind::Egress,
+                    name: egress.to_string(),
+                    address: egress.to_string(),
+                });
+            }
+            (pod.to_string(), node.to_string(), targets)
+        })
+        .collect()
+}
+
+/// Aggregate the script's `<index> <result> <ms>` lines into one result per target.
+pub fn parse_probe_output(
+    source_node: &str,
+    targets: &[ProbeTarget],
+    output: &str,
+) -> Vec<ProbeResult> {
+    // index -> (attempts, reachable, total ms of reachable attempts)
+    let mut tally: BTreeMap<usize, (usize, usize, u64)> = BTreeMap::new();
+    for line in output.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(idx), Some(result), Some(ms)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let (Ok(idx), Ok(ms)) = (idx.parse::<usize>(), ms.parse::<u64>()) else {
+            continue;
+        };
+        if idx >= targets.len() {
+            continue;
+        }
+        let entry = tally.entry(idx).or_default();
+        entry.0 += 1;
+        if matches!(result, "open" | "refused") {
+            entry.1 += 1;
+            entry.2 += ms;
+        }
+    }
+    targets
+        .iter()
+        .enumerate()
+        .map(|(i, target)| {
+            let (attempts, reachable, total_ms) = tally.get(&i).copied().unwrap_or_default();
+            ProbeResult {
+                source_node: source_node.to_string(),
+                target: target.clone(),
+                attempts,
+                reachable,
+                avg_ms: (reachable > 0).then(|| total_ms / reachable as u64),
+            }
+        })
+        .collect()
+}
+
+/// Run the probe matrix from every Running inspector pod in `namespace`.
+pub async fn run_network_probes(
+    client: &K8sClient,
+    namespace: &str,
+    egress_target: Option<&str>,
+) -> Result<Vec<ProbeResult>> {
+    let lp = ListParams::default();
+    let pods_api = client.pods(Some(namespace));
+    let inspector_pods = client
+        .list_all(
+            &pods_api,
+            &ListParams::default().labels(NODE_INSPECTOR_LABEL),
+        )
+        .await?
+        .items;
+    let nodes = client.list_all(&client.nodes(), &lp).await?.items;
+    let mut services = client
+        .list_unselected(&client.services(Some("default")), &lp)
+        .await?
+        .items;
+    services.extend(
+        client
+            .list_unselected(&client.services(Some("kube-system")), &lp)
+            .await?
+            .items,
+    );
+
+    let plan = plan_probes(&inspector_pods, &nodes, &services, egress_target);
+    if plan.is_empty() {
+        bail!("no Running node inspector pod in namespace '{}'", namespace);
+    }
+    let runs = plan.iter().map(|(pod, node, targets)| {
+        let pods_api = pods_api.clone();
+        async move {
+            let mut command = vec![
+                "bash".to_string(),
+                "-c".to_string(),
+                format!(
+                    "ATTEMPTS={} CONNECT_TIMEOUT={}\n{}",
+                    ATTEMPTS, CONNECT_TIMEOUT_SECS, PROBE_SCRIPT
+                ),
+                "probe".to_string(),
+            ];
+            command.extend(targets.iter().map(|t| t.address.clone()));
+            match tokio::time::timeout(
+                std::time::Duration::from_secs(EXEC_TIMEOUT_SECS),
+                exec_in_inspector(&pods_api, pod, command),
+            )
+            .await
+            {
+                Ok(Ok(output)) => Some(parse_probe_output(node, targets, &output)),
+                Ok(Err(e)) => {
+                    debug!("Network probe exec failed for pod {}: {:#}", pod, e);
+                    None
+                }
+                Err(_) => {
+                    debug!("Network probe exec timed out for pod {}", pod);
+                    None
+                }
+            }
+        }
+    });
+    let results: Vec<ProbeResult> = futures::future::join_all(runs)
+        .await
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect();
+    if results.is_empty() {
+        bail!("no node inspector pod returned probe output (needs pods/exec)");
+    }
+    Ok(results)
+}
+
+fn probe_issue(
+    code: &str,
+    severity: IssueSeverity,
+    result: &ProbeResult,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue::new(
+        severity,
+        "Network",
+        code,
+        description,
+        format!("{} → {}", result.source_node, result.target.name),
+        recommendation,
+    )
+}
+
+/// One check per probe kind with loss and latency, NET-015..NET-018 for paths where every attempt
+/// failed and NET-019 for paths with partial loss or slow in-cluster connects.
+pub fn evaluate_probes(results: &[ProbeResult]) -> Vec<(CheckResult, Vec<Issue>)> {
+    let mut by_kind: BTreeMap<ProbeKind, Vec<&ProbeResult>> = BTreeMap::new();
+    for r in results {
+        by_kind.entry(r.target.kind).or_default().push(r);
+    }
+    by_kind
+        .into_iter()
+        .map(|(kind, paths)| {
+            let mut issues = Vec::new();
+            let (code, severity) = kind.failure();
+            for r in &paths {
+                if r.attempts > 0 && r.reachable == 0 {
+                    issues.push(probe_issue(
+                        code,
+                        severity.clone(),
+                        r,
+                        format!(
+                            "{} probe from node {} to {} ({}) failed on all {} attempts",
+                            kind.label(),
+                            r.source_node,
+                            r.target.name,
+                            r.target.address,
+                            r.attempts
+                        ),
+                        kind.recommendation(),
+                    ));
+                } else if r.reachable < r.attempts {
+                    issues.push(probe_issue(
+                        "NET-019",
+                        IssueSeverity::Warning,
+                        r,
+                        format!(
+                            "{} probe from node {} to {} ({}) lost {:.0}% of attempts",
+                            kind.label(),
+                            r.source_node,
+                            r.target.name,
+                            r.target.address,
+                            r.loss_percent()
+                        ),
+                        kind.recommendation(),
+                    ));
+                } else if let Some(ms) = r
+                    .avg_ms
+                    .filter(|ms| kind != ProbeKind::Egress && *ms > SLOW_PATH_MS)
+                {
+                    issues.push(probe_issue(
+                        "NET-019",
+                        IssueSeverity::Warning,
+                        r,
+                        format!(
+                            "{} probe from node {} to {} ({}) took {} ms on average (> {} ms)",
+                            kind.label(),
+                            r.source_node,
+                            r.target.name,
+                            r.target.address,
+                            ms,
+                            SLOW_PATH_MS
+                        ),
+                        kind.recommendation(),
+                    ));
+                }
+            }
+
+            let attempts: usize = paths.iter().map(|r| r.attempts).sum();
+            let reachable: usize = paths.iter().map(|r| r.reachable).sum();
+            let latencies: Vec<u64> = paths.iter().filter_map(|r| r.avg_ms).collect();
+            let failed_paths = paths
+                .iter()
+                .filter(|r| r.attempts > 0 && r.reachable == 0)
+                .count();
+            let loss = if attempts == 0 {
+                0.0
+            } else {
+                (attempts - reachable) as f64 * 100.0 / attempts as f64
+            };
+            let mut details = format!(
+                "{} path(s), {} failing, loss {:.1}%",
+                paths.len(),
+                failed_paths,
+                loss
+            );
+            if !latencies.is_empty() {
+                details.push_str(&format!(
+                    ", connect latency avg {} ms / max {} ms",
+                    latencies.iter().sum::<u64>() / latencies.len() as u64,
+                    latencies.iter().max().copied().unwrap_or_default()
+                ));
+            }
+            let status = if issues.is_empty() {
+                CheckStatus::Pass
+            } else if failed_paths > 0 && severity == IssueSeverity::Critical {
+                CheckStatus::Critical
+            } else {
+                CheckStatus::Warning
+            };
+            let check = CheckResult {
+                name: format!("Probe: {}", kind.label()),
+                description: format!("Active TCP probes {}", kind.label()),
+                status,
+                score: 100.0 - loss,
+                max_score: 100.0,
+                details: Some(details),
+                recommendations: if issues.is_empty() {
+                    vec![]
+                } else {
+                    vec![kind.recommendation().to_string()]
+                },
+            };
+            (check, issues)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{NodeAddress, NodeStatus, PodSpec, PodStatus, ServiceSpec};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn meta(ns: Option<&str>, name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: ns.map(str::to_string),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn plans_ring_of_peers_and_parses_results() {
+        let pods: Vec<Pod> = (1..=3)
+            .map(|i| Pod {
+                metadata: meta(Some("kubeowler"), &format!("inspector-{}", i)),
+                spec: Some(PodSpec {
+                    node_name: Some(format!("n{}", i)),
+                    ..Default::default()
+                }),
+                status: Some(PodStatus {
+                    phase: Some("Running".to_string()),
+                    pod_ip: Some(format!("10.244.{}.5", i)),
+                    ..Default::default()
+                }),
+            })
+            .collect();
+        let nodes: Vec<Node> = (1..=3)
+            .map(|i| Node {
+                metadata: meta(None, &format!("n{}", i)),
+                status: Some(NodeStatus {
+                    addresses: Some(vec![NodeAddress {
+                        type_: "InternalIP".to_string(),
+                        address: format!("192.168.0.{}", i),
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+        let services = vec![Service {
+            metadata: meta(Some("default"), "kubernetes"),
+            spec: Some(ServiceSpec {
+                cluster_ip: Some("10.96.0.1".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+
+        let plan = plan_probes(&pods, &nodes, &services, Some(DEFAULT_EGRESS_TARGET));
+        assert_eq!(plan.len(), 3);
+        let (pod, node, targets) = &plan[2];
+        assert_eq!((pod.as_str(), node.as_str()), ("inspector-3", "n3"));
+        let addresses: Vec<&str> = targets.iter().map(|t| t.address.as_str()).collect();
+        assert_eq!(
+            addresses,
+            [
+                "10.96.0.1:443",
+                "10.244.1.5:1",
+                "10.244.2.5:1",
+                "192.168.0.1:10250",
+                "192.168.0.2:10250",
+                "1.1.1.1:443"
+            ]
+        );
+
+        let output = "0 open 2\n0 open 4\n0 open 3\n\
+                      1 refused 1\n1 refused 1\n1 refused 1\n\
+                      2 timeout 2000\n2 timeout 2000\n2 timeout 2000\n\
+                      3 refused 1\n3 timeout 2000\n3 refused 1\n\
+                      4 refused 1\n4 refused 1\n4 refused 1\n\
+                      5 open 30\n5 open 30\n5 open 30\n";
+        let results = parse_probe_output("n3", targets, output);
+        assert_eq!(results[0].avg_ms, Some(3));
+        assert_eq!(results[2].reachable, 0);
+
+        let evaluated = evaluate_probes(&results);
+        let codes: Vec<&str> = evaluated
+            .iter()
+            .flat_map(|(_, issues)| issues.iter().filter_map(|i| i.rule_id.as_deref()))
+            .collect();
+        assert_eq!(codes, ["NET-016", "NET-019"]);
+        let (pod_check, issues) = &evaluated[1];
+        assert_eq!(pod_check.status, CheckStatus::Critical);
+        assert_eq!(issues[0].resource.as_deref(), Some("n3 → n2"));
+        assert!(evaluated
+            .iter()
+            .all(|(c, _)| c.name != "Probe: Pod → Service VIP" || c.status == CheckStatus::Pass));
+    }
+}
@@ -1,7 +1,10 @@
 use anyhow::Result;
-use chrono::Utc;
-use k8s_openapi::api::core::v1::{ComponentStatus, Pod};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::coordination::v1::Lease;
+use k8s_openapi::api::core::v1::{ComponentStatus, Container, Pod};
 use kube::{api::ListParams, Api};
+use std::collections::HashMap;
+use std::time::Instant;
 
 /// ComponentStatus API was removed in Kubernetes 1.24; list can return 404 or "not found".
 fn is_component_status_unavailable(err: &kube::Error) -> bool {
@@ -16,6 +19,8 @@ fn is_component_status_unavailable(err: &kube::Error) -> bool {
     }
 }
 
+use crate::inspections::etcd_objects;
+use crate::inspections::provider;
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
@@ -43,9 +48,76 @@ impl<'a> ControlPlaneInspector<'a> {
         let component_check = self.inspect_component_statuses(&mut issues).await?;
         checks.push(component_check);
 
-        // Control-plane pod check
-        let pod_check = self.inspect_control_plane_pods(&mut issues).await?;
-        checks.push(pod_check);
+        let pods = self
+            .client
+            .list_unselected(
+                &self.client.pods(Some("kube-system")),
+                &ListParams::default(),
+            )
+            .await?
+            .items;
+        let nodes = self
+            .client
+            .list_all(&self.client.nodes(), &ListParams::default())
+            .await?
+            .items;
+        let server_version = self.client.server_version().await.ok().flatten();
+        let managed = provider::detect(&nodes, server_version.as_deref());
+
+        if let Some(managed) = managed {
+            // The provider runs the control plane and etcd: static-pod checks do not apply.
+            let daemon_sets = self
+                .client
+                .list_unselected(
+                    &self.client.daemon_sets(Some("kube-system")),
+                    &ListParams::default(),
+                )
+                .await?
+                .items;
+            checks.push(provider::provider_check(
+                managed,
+                server_version.as_deref(),
+                &nodes,
+                &daemon_sets,
+                Utc::now(),
+                &mut issues,
+            ));
+        } else {
+            // Control-plane pod check
+            let pod_check = self.inspect_control_plane_pods(&pods, &mut issues);
+            checks.push(pod_check);
+
+            // Flags of self-managed (static pod) components
+            checks.push(inspect_component_flags(&pods, &mut issues));
+            checks.push(inspect_audit_logging(&pods, &mut issues));
+        }
+
+        // Leader election of scheduler and controller manager (also on managed control planes)
+        let leases = self
+            .client
+            .list_unselected(
+                &self.client.leases(Some("kube-system")),
+                &ListParams::default(),
+            )
+            .await?
+            .items;
+        checks.push(leader_election_check(&leases, Utc::now(), &mut issues));
+        if managed.is_none() {
+            let healthz = probe_component_healthz(self.client, &pods).await;
+            checks.push(component_healthz_check(&healthz, &mut issues));
+        }
+
+        // API server latency and errors over a short burst of cheap requests
+        let samples = sample_api_latency(self.client).await;
+        let api_latency = summarize_api_latency(&samples);
+        checks.push(api_latency_check(api_latency.as_ref(), &mut issues));
+
+        // Objects per kind in etcd (cluster-wide)
+        let etcd_objects = etcd_objects::collect(self.client).await;
+        checks.push(etcd_objects::etcd_objects_check(
+            etcd_objects.as_ref(),
+            &mut issues,
+        ));
 
         let overall_score = if checks.is_empty() {
             0.0
@@ -64,12 +136,14 @@ impl<'a> ControlPlaneInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency,
+            etcd_objects,
         })
     }
 
     async fn inspect_component_statuses(&self, issues: &mut Vec<Issue>) -> Result<CheckResult> {
         let api: Api<ComponentStatus> = Api::all(self.client.client().clone());
-        let statuses = match api.list(&ListParams::default()).await {
+        let statuses = match self.client.list_all(&api, &ListParams::default()).await {
             Ok(s) => s,
             Err(e) if is_component_status_unavailable(&e) => {
                 return Ok(CheckResult {
@@ -107,6 +181,10 @@ impl<'a> ControlPlaneInspector<'a> {
                             resource: Some(name.clone()),
                             recommendation: "Inspect control-plane logs and ensure all components are running and healthy.".to_string(),
                             rule_id: Some("CTRL-001".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
@@ -148,18 +226,15 @@ impl<'a> ControlPlaneInspector<'a> {
         })
     }
 
-    async fn inspect_control_plane_pods(&self, issues: &mut Vec<Issue>) -> Result<CheckResult> {
-        let pods_api = self.client.pods(Some("kube-system"));
-        let pods = pods_api.list(&ListParams::default()).await?;
-
+    fn inspect_control_plane_pods(&self, pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
         let mut evaluated = 0usize;
         let mut healthy = 0usize;
 
-        for pod in pods.items {
+        for pod in pods {
             if let Some(name) = pod.metadata.name.clone() {
                 if CONTROL_PLANE_POD_KEYWORDS.iter().any(|k| name.contains(k)) {
                     evaluated += 1;
-                    if !is_pod_running(&pod) {
+                    if !is_pod_running(pod) {
                         issues.push(Issue {
                             severity: IssueSeverity::Critical,
                             category: "ControlPlane".to_string(),
@@ -169,6 +244,10 @@ impl<'a> ControlPlaneInspector<'a> {
                                 "Check the static pod manifest and node health for this component."
                                     .to_string(),
                             rule_id: Some("CTRL-002".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     } else {
                         healthy += 1;
@@ -191,7 +270,7 @@ impl<'a> ControlPlaneInspector<'a> {
             CheckStatus::Critical
         };
 
-        Ok(CheckResult {
+        CheckResult {
             name: "Control Plane Pods".to_string(),
             description: "Validates that key control-plane pods in kube-system are running"
                 .to_string(),
@@ -208,7 +287,7 @@ impl<'a> ControlPlaneInspector<'a> {
             } else {
                 vec![]
             },
-        })
+        }
     }
 
     fn build_summary(&self, checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
@@ -249,3 +328,1061 @@ fn is_pod_running(pod: &Pod) -> bool {
     }
     false
 }
+
+/// Components whose flags are audited, as named by the kubeadm `component` label.
+const FLAG_AUDIT_COMPONENTS: [&str; 3] = [
+    "kube-apiserver",
+    "kube-controller-manager",
+    "kube-scheduler",
+];
+
+/// Feature gates that are locked (GA or deprecated) or removed, with a note for the report.
+const DEPRECATED_FEATURE_GATES: &[(&str, &str)] = &[
+    ("PodSecurity", "GA in 1.25, removed in 1.28"),
+    ("EphemeralContainers", "GA in 1.25, removed in 1.27"),
+    ("CSIMigration", "GA in 1.25, removed in 1.27"),
+    ("CSIStorageCapacity", "GA in 1.24, removed in 1.28"),
+    (
+        "DynamicKubeletConfig",
+        "deprecated in 1.22, removed in 1.26",
+    ),
+    ("IndexedJob", "GA in 1.24, removed in 1.26"),
+    ("StatefulSetMinReadySeconds", "GA in 1.25, removed in 1.27"),
+    ("DaemonSetUpdateSurge", "GA in 1.25, removed in 1.27"),
+    ("TTLAfterFinished", "GA in 1.23, removed in 1.25"),
+    ("ServiceLBNodePortControl", "GA in 1.24, removed in 1.26"),
+    ("NetworkPolicyEndPort", "GA in 1.25, removed in 1.27"),
+    (
+        "LegacyServiceAccountTokenNoAutoGeneration",
+        "GA in 1.26, removed in 1.29",
+    ),
+    ("DownwardAPIHugePages", "GA in 1.27, removed in 1.29"),
+    ("ProbeTerminationGracePeriod", "GA in 1.28, removed in 1.29"),
+    ("KubeletCredentialProviders", "GA in 1.26, removed in 1.28"),
+    ("JobTrackingWithFinalizers", "GA in 1.26, removed in 1.28"),
+];
+
+/// Component name of a control-plane pod: the kubeadm `component` label, else the pod name prefix.
+fn flag_audit_component(pod: &Pod) -> Option<&'static str> {
+    let label = pod
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("component"))
+        .map(String::as_str);
+    let name = pod.metadata.name.as_deref().unwrap_or_default();
+    FLAG_AUDIT_COMPONENTS
+        .iter()
+        .copied()
+        .find(|c| label == Some(*c) || (label.is_none() && name.starts_with(&format!("{}-", c))))
+}
+
+/// The component's container (named after it in kubeadm manifests), else the first container.
+fn component_container<'p>(pod: &'p Pod, component: &str) -> Option<&'p Container> {
+    pod.spec.as_ref().and_then(|s| {
+        s.containers
+            .iter()
+            .find(|c| c.name == component)
+            .or_else(|| s.containers.first())
+    })
+}
+
+/// `--name=value` / `--name value` / bare `--name` flags of the component's container.
+fn component_flags(pod: &Pod, component: &str) -> HashMap<String, String> {
+    let Some(container) = component_container(pod, component) else {
+        return HashMap::new();
+    };
+    let argv: Vec<&str> = container
+        .command
+        .iter()
+        .flatten()
+        .chain(container.args.iter().flatten())
+        .map(String::as_str)
+        .collect();
+    let mut flags = HashMap::new();
+    let mut i = 0;
+    while i < argv.len() {
+        if let Some(flag) = argv[i].strip_prefix("--") {
+            match flag.split_once('=') {
+                Some((k, v)) => {
+                    flags.insert(k.to_string(), v.to_string());
+                }
+                None => {
+                    let value = argv
+                        .get(i + 1)
+                        .filter(|v| !v.starts_with("--"))
+                        .map(|v| v.to_string());
+                    if value.is_some() {
+                        i += 1;
+                    }
+                    flags.insert(
+                        flag.to_string(),
+                        value.unwrap_or_else(|| "true".to_string()),
+                    );
+                }
+            }
+        }
+        i += 1;
+    }
+    flags
+}
+
+/// Node the static pod runs on, for resource names and descriptions.
+fn pod_node(pod: &Pod) -> &str {
+    pod.spec
+        .as_ref()
+        .and_then(|s| s.node_name.as_deref())
+        .unwrap_or("unknown")
+}
+
+/// Finding on the `kube-system/<component>-<node>` static pod.
+fn static_pod_issue(
+    component: &str,
+    node: &str,
+    severity: IssueSeverity,
+    code: &str,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue::new(
+        severity,
+        "ControlPlane",
+        code,
+        description,
+        format!("kube-system/{}-{}", component, node),
+        recommendation,
+    )
+}
+
+/// Check result over static pod findings: 25 points off per critical, 10 per warning, 2 per info.
+pub(crate) fn static_pod_check(
+    name: &str,
+    description: &str,
+    details: String,
+    findings: &[Issue],
+    recommendation: &str,
+) -> CheckResult {
+    let count =
+        |severity: IssueSeverity| findings.iter().filter(|i| i.severity == severity).count();
+    let (critical, warning, info) = (
+        count(IssueSeverity::Critical),
+        count(IssueSeverity::Warning),
+        count(IssueSeverity::Info),
+    );
+    let score =
+        (100.0 - 25.0 * critical as f64 - 10.0 * warning as f64 - 2.0 * info as f64).max(0.0);
+    let status = if critical > 0 {
+        CheckStatus::Critical
+    } else if warning > 0 {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if findings.is_empty() {
+            vec![]
+        } else {
+            vec![recommendation.to_string()]
+        },
+    }
+}
+
+/// Risky flags of one component (CTRL-003..CTRL-007).
+fn flag_findings(component: &str, node: &str, flags: &HashMap<String, String>) -> Vec<Issue> {
+    let issue = |severity: IssueSeverity, code: &str, description: String, recommendation: &str| {
+        static_pod_issue(component, node, severity, code, description, recommendation)
+    };
+    let mut out = Vec::new();
+    if component == "kube-apiserver" {
+        if flags.get("anonymous-auth").map(String::as_str) != Some("false") {
+            out.push(issue(
+                IssueSeverity::Warning,
+                "CTRL-003",
+                format!("{} on {} allows anonymous requests", component, node),
+                "Set --anonymous-auth=false in the kube-apiserver manifest.",
+            ));
+        }
+        if let Some(port) = flags.get("insecure-port").filter(|p| p.as_str() != "0") {
+            out.push(issue(
+                IssueSeverity::Critical,
+                "CTRL-004",
+                format!(
+                    "{} on {} serves the insecure port {}",
+                    component, node, port
+                ),
+                "Set --insecure-port=0 or remove it; move clients to the secure port.",
+            ));
+        }
+        if !flags.contains_key("audit-log-path") && !flags.contains_key("audit-webhook-config-file")
+        {
+            out.push(issue(
+                IssueSeverity::Warning,
+                "CTRL-005",
+                format!(
+                    "{} on {} has no audit log or audit webhook configured",
+                    component, node
+                ),
+                "Configure --audit-policy-file with --audit-log-path or --audit-webhook-config-file.",
+            ));
+        }
+        if flags
+            .get("authorization-mode")
+            .is_some_and(|m| m.split(',').any(|mode| mode.trim() == "AlwaysAllow"))
+        {
+            out.push(issue(
+                IssueSeverity::Critical,
+                "CTRL-006",
+                format!(
+                    "{} on {} uses authorization mode AlwaysAllow",
+                    component, node
+                ),
+                "Set --authorization-mode=Node,RBAC.",
+            ));
+        }
+    }
+    if let Some(gates) = flags.get("feature-gates") {
+        for gate in gates.split(',') {
+            let name = gate.split('=').next().unwrap_or_default().trim();
+            if let Some((_, note)) = DEPRECATED_FEATURE_GATES.iter().find(|(g, _)| *g == name) {
+                out.push(issue(
+                    IssueSeverity::Warning,
+                    "CTRL-007",
+                    format!(
+                        "{} on {} sets deprecated feature gate {} ({})",
+                        component, node, name, note
+                    ),
+                    "Remove the gate from --feature-gates (and from kubeadm extraArgs).",
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Audit the flags of kube-apiserver, kube-controller-manager and kube-scheduler static pods.
+/// Managed control planes (no such pods) pass with a note.
+fn inspect_component_flags(pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut evaluated = 0usize;
+    let mut findings = Vec::new();
+    for pod in pods {
+        let Some(component) = flag_audit_component(pod) else {
+            continue;
+        };
+        evaluated += 1;
+        findings.extend(flag_findings(
+            component,
+            pod_node(pod),
+            &component_flags(pod, component),
+        ));
+    }
+
+    let details = if evaluated == 0 {
+        "No static control-plane pods detected (managed control plane?); flags not audited"
+            .to_string()
+    } else {
+        format!(
+            "{} component pod(s) audited, {} risky flag(s)",
+            evaluated,
+            findings.len()
+        )
+    };
+    let check = static_pod_check(
+        "Control Plane Flags",
+        "Audits risky kube-apiserver, controller-manager and scheduler flags and feature gates",
+        details,
+        &findings,
+        "Fix the flags in /etc/kubernetes/manifests on each control plane node (see CTRL-003..CTRL-007).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// CIS minimums for the audit log backend rotation flags (1.2.19-1.2.21).
+const AUDIT_LOG_RETENTION_MINIMUMS: [(&str, u64); 3] = [
+    ("audit-log-maxage", 30),
+    ("audit-log-maxbackup", 10),
+    ("audit-log-maxsize", 100),
+];
+
+/// True when `path` lies inside one of the container's volume mounts.
+fn is_mounted(container: &Container, path: &str) -> bool {
+    container.volume_mounts.iter().flatten().any(|m| {
+        let dir = m.mount_path.trim_end_matches('/');
+        path == dir || path.starts_with(&format!("{}/", dir))
+    })
+}
+
+/// Audit sinks of one API server pod ("webhook", "log file", "stdout") and CTRL-008..CTRL-010
+/// findings. A pod without any sink is already reported by the flag audit (CTRL-005).
+fn audit_findings(pod: &Pod) -> (Vec<&'static str>, Vec<Issue>) {
+    let component = "kube-apiserver";
+    let node = pod_node(pod);
+    let flags = component_flags(pod, component);
+    let Some(container) = component_container(pod, component) else {
+        return (Vec::new(), Vec::new());
+    };
+    let issue = |severity: IssueSeverity, code: &str, description: String, recommendation: &str| {
+        static_pod_issue(component, node, severity, code, description, recommendation)
+    };
+
+    let mut sinks = Vec::new();
+    if flags.contains_key("audit-webhook-config-file") {
+        sinks.push("webhook");
+    }
+    let log_path = flags.get("audit-log-path").map(String::as_str);
+    match log_path {
+        Some("-") => sinks.push("stdout"),
+        Some(_) => sinks.push("log file"),
+        None => {}
+    }
+    let mut out = Vec::new();
+    if sinks.is_empty() {
+        return (sinks, out);
+    }
+
+    match flags.get("audit-policy-file") {
+        None => out.push(issue(
+            IssueSeverity::Warning,
+            "CTRL-008",
+            format!("{} on {} has no audit policy file", component, node),
+            "Set --audit-policy-file and mount the policy into the kube-apiserver pod.",
+        )),
+        Some(policy) if !is_mounted(container, policy) => out.push(issue(
+            IssueSeverity::Warning,
+            "CTRL-008",
+            format!(
+                "{} on {} audit policy file {} is not on a mounted volume",
+                component, node, policy
+            ),
+            "Mount the audit policy file as a hostPath volume in the kube-apiserver manifest.",
+        )),
+        Some(_) => {}
+    }
+
+    if let Some(path) = log_path.filter(|p| *p != "-") {
+        if !is_mounted(container, path) {
+            out.push(issue(
+                IssueSeverity::Warning,
+                "CTRL-009",
+                format!(
+                    "{} on {} writes its audit log to {}, which is not on a mounted volume",
+                    component, node, path
+                ),
+                "Mount the audit log directory as a hostPath volume and ship it off the node.",
+            ));
+        }
+        let short: Vec<String> = AUDIT_LOG_RETENTION_MINIMUMS
+            .iter()
+            .filter_map(|(flag, min)| {
+                let name = flag.trim_start_matches("audit-log-");
+                match flags.get(*flag).map(|v| v.parse::<u64>()) {
+                    Some(Ok(v)) if v >= *min => None,
+                    Some(Ok(v)) => Some(format!("{}={} (< {})", name, v, min)),
+                    _ => Some(format!("{} unset (< {})", name, min)),
+                }
+            })
+            .collect();
+        if !short.is_empty() {
+            out.push(issue(
+                IssueSeverity::Info,
+                "CTRL-010",
+                format!(
+                    "{} on {} audit log retention below CIS: {}",
+                    component,
+                    node,
+                    short.join(", ")
+                ),
+                "Set --audit-log-maxage=30, --audit-log-maxbackup=10 and --audit-log-maxsize=100 or higher.",
+            ));
+        }
+    }
+    (sinks, out)
+}
+
+/// Verify that every API server static pod has an audit policy and a sink that outlives the pod
+/// (CIS 1.2.18-1.2.21). Managed control planes (no such pods) pass with a note.
+fn inspect_audit_logging(pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut lines = Vec::new();
+    let mut findings = Vec::new();
+    for pod in pods {
+        if flag_audit_component(pod) != Some("kube-apiserver") {
+            continue;
+        }
+        let (sinks, pod_findings) = audit_findings(pod);
+        let sinks = if sinks.is_empty() {
+            "none".to_string()
+        } else {
+            sinks.join(", ")
+        };
+        lines.push(format!("{}: {}", pod_node(pod), sinks));
+        findings.extend(pod_findings);
+    }
+
+    let details = if lines.is_empty() {
+        "No kube-apiserver static pods detected (managed control plane?); audit logging not verified"
+            .to_string()
+    } else {
+        format!("Audit sinks per API server: {}", lines.join("; "))
+    };
+    let check = static_pod_check(
+        "Audit Logging",
+        "Checks the API server audit policy, audit log sinks and log retention",
+        details,
+        &findings,
+        "Configure an audit policy and a persisted or shipped audit sink on every API server (see CTRL-008..CTRL-010).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// Leader-election Leases in kube-system of the components that run one active instance.
+const LEADER_LEASES: [&str; 2] = ["kube-scheduler", "kube-controller-manager"];
+/// A leader Lease not renewed for this long (or its lease duration, if longer) has no live leader.
+const LEASE_STALE_SECS: i64 = 120;
+/// Label of the per-instance API server identity Leases, which the API server garbage-collects.
+const APISERVER_IDENTITY_LABEL: &str = "apiserver.kubernetes.io/identity";
+/// Components whose `/healthz` is read through the pod proxy, with their default secure port.
+const HEALTHZ_COMPONENTS: [(&str, u16); 2] = [
+    ("kube-scheduler", 10259),
+    ("kube-controller-manager", 10257),
+];
+
+/// Stale leader Leases (CTRL-013). Missing Leases pass with a note: the component may run with
+/// `--leader-elect=false` or the provider may hide it. Other held Leases in kube-system (add-on
+/// controllers, cloud controller manager) not renewed within the same threshold are CTRL-020.
+fn leader_election_check(
+    leases: &[Lease],
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut lines = Vec::new();
+    let mut findings = Vec::new();
+    for component in LEADER_LEASES {
+        let Some(lease) = leases
+            .iter()
+            .find(|l| l.metadata.name.as_deref() == Some(component))
+        else {
+            lines.push(format!("{}: no Lease", component));
+            continue;
+        };
+        let spec = lease.spec.as_ref();
+        let holder = spec
+            .and_then(|s| s.holder_identity.as_deref())
+            .filter(|h| !h.is_empty())
+            .unwrap_or("none");
+        let threshold = spec
+            .and_then(|s| s.lease_duration_seconds)
+            .map_or(LEASE_STALE_SECS, |d| LEASE_STALE_SECS.max(i64::from(d)));
+        match spec.and_then(|s| s.renew_time.as_ref()) {
+            Some(renewed) => {
+                let age = (now - renewed.0).num_seconds();
+                lines.push(format!(
+                    "{}: held by {}, renewed {}s ago",
+                    component, holder, age
+                ));
+                if age > threshold {
+                    findings.push(Issue {
+                        severity: IssueSeverity::Critical,
+                        category: "ControlPlane".to_string(),
+                        description: format!(
+                            "Leader Lease kube-system/{} was last renewed {}s ago by {} (threshold {}s); no {} instance is active",
+                            component, age, holder, threshold, component
+                        ),
+                        resource: Some(format!("kube-system/{}", component)),
+                        recommendation: format!(
+                            "Check that {} is running and can reach the API server; on managed clusters contact the provider.",
+                            component
+                        ),
+                        rule_id: Some("CTRL-013".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
+                    });
+                }
+            }
+            None => lines.push(format!("{}: held by {}, never renewed", component, holder)),
+        }
+    }
+    let mut components = 0usize;
+    for lease in leases {
+        let Some(name) = lease.metadata.name.as_deref() else {
+            continue;
+        };
+        let identity = lease
+            .metadata
+            .labels
+            .as_ref()
+            .is_some_and(|l| l.contains_key(APISERVER_IDENTITY_LABEL));
+        if LEADER_LEASES.contains(&name) || identity {
+            continue;
+        }
+        let Some(spec) = lease.spec.as_ref() else {
+            continue;
+        };
+        let Some(holder) = spec.holder_identity.as_deref().filter(|h| !h.is_empty()) else {
+            continue;
+        };
+        components += 1;
+        let threshold = spec
+            .lease_duration_seconds
+            .map_or(LEASE_STALE_SECS, |d| LEASE_STALE_SECS.max(i64::from(d)));
+        let Some(age) = spec.renew_time.as_ref().map(|t| (now - t.0).num_seconds()) else {
+            continue;
+        };
+        if age > threshold {
+            findings.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "ControlPlane".to_string(),
+                description: format!(
+                    "Leader Lease kube-system/{} was last renewed {}s ago by {} (threshold {}s); the component holding it is not running or cannot reach the API server",
+                    name, age, holder, threshold
+                ),
+                resource: Some(format!("kube-system/{}", name)),
+                recommendation: "Check the controller that owns the Lease; delete the Lease if the component was removed.".to_string(),
+                rule_id: Some("CTRL-020".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: vec![format!(
+                    "kubectl -n kube-system get lease {} -o yaml",
+                    name
+                )],
+            });
+        }
+    }
+    if components > 0 {
+        lines.push(format!("{} other held Lease(s) in kube-system", components));
+    }
+    let check = static_pod_check(
+        "Leader Election",
+        "Checks that the scheduler, controller manager and other kube-system leader Leases are renewed",
+        lines.join("; "),
+        &findings,
+        "Restore the component whose leader Lease is stale (see CTRL-013 and CTRL-020).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// `/healthz` of each scheduler and controller-manager static pod through the API server pod
+/// proxy: (pod, HTTP status; None when no response).
+async fn probe_component_healthz(client: &K8sClient, pods: &[Pod]) -> Vec<(String, Option<u16>)> {
+    let mut results = Vec::new();
+    for pod in pods {
+        let Some((component, default_port)) = HEALTHZ_COMPONENTS
+            .iter()
+            .find(|(c, _)| flag_audit_component(pod) == Some(*c))
+        else {
+            continue;
+        };
+        let Some(name) = pod.metadata.name.as_deref() else {
+            continue;
+        };
+        let port = component_flags(pod, component)
+            .get("secure-port")
+            .and_then(|p| p.parse::<u16>().ok())
+            .unwrap_or(*default_port);
+        let path = format!(
+            "/api/v1/namespaces/kube-system/pods/https:{}:{}/proxy/healthz",
+            name, port
+        );
+        results.push((
+            name.to_string(),
+            client.api_request_status(&path).await.ok(),
+        ));
+    }
+    results
+}
+
+/// Static pods whose `/healthz` answers with an error (CTRL-014). Pods that cannot be reached
+/// (no response, 401/403 without `pods/proxy`) are only noted; managed control planes pass.
+fn component_healthz_check(
+    results: &[(String, Option<u16>)],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut lines = Vec::new();
+    let mut findings = Vec::new();
+    for (pod, status) in results {
+        match status {
+            Some(200..=299) => lines.push(format!("{}: ok", pod)),
+            None | Some(401 | 403) => lines.push(format!("{}: not reachable", pod)),
+            Some(code) => {
+                lines.push(format!("{}: HTTP {}", pod, code));
+                findings.push(Issue {
+                    severity: IssueSeverity::Critical,
+                    category: "ControlPlane".to_string(),
+                    description: format!("Control plane pod {} answers /healthz with HTTP {}", pod, code),
+                    resource: Some(format!("kube-system/{}", pod)),
+                    recommendation: "Read the component's logs and `/healthz?verbose` output; restart the static pod once the cause is fixed.".to_string(),
+                    rule_id: Some("CTRL-014".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+    }
+    let details = if lines.is_empty() {
+        "No scheduler or controller-manager static pods detected (managed control plane?); see Leader Election".to_string()
+    } else {
+        lines.join("; ")
+    };
+    let check = static_pod_check(
+        "Component Health Endpoints",
+        "Reads /healthz of the scheduler and controller-manager static pods",
+        details,
+        &findings,
+        "Fix the unhealthy control-plane component (see CTRL-014).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// Cheap read-only endpoints sampled for API server latency, requested in turn.
+const LATENCY_ENDPOINTS: [&str; 5] = [
+    "/version",
+    "/healthz",
+    "/livez",
+    "/readyz",
+    "/api/v1/namespaces?limit=1",
+];
+/// Passes over [`LATENCY_ENDPOINTS`]; requests are sequential so each one measures the server.
+const LATENCY_ROUNDS: usize = 5;
+/// p95 at or above which the API server is reported as slow (Warning / Critical).
+const SLOW_API_P95_MS: u64 = 1000;
+const VERY_SLOW_API_P95_MS: u64 = 3000;
+/// Share of failed requests at or above which CTRL-012 is Critical.
+const API_ERROR_RATE_CRITICAL: f64 = 0.2;
+
+/// One sampled request: HTTP status (None: no response) and elapsed time.
+struct ApiSample {
+    path: &'static str,
+    status: Option<u16>,
+    ms: u64,
+}
+
+async fn sample_api_latency(client: &K8sClient) -> Vec<ApiSample> {
+    let mut samples = Vec::with_capacity(LATENCY_ROUNDS * LATENCY_ENDPOINTS.len());
+    for _ in 0..LATENCY_ROUNDS {
+        for path in LATENCY_ENDPOINTS {
+            let started = Instant::now();
+            let status = client.api_request_status(path).await.ok();
+            samples.push(ApiSample {
+                path,
+                status,
+                ms: started.elapsed().as_millis() as u64,
+            });
+        }
+    }
+    samples
+}
+
+/// Nearest-rank percentile of ascending `sorted` (0 when empty).
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn is_failed(sample: &ApiSample) -> bool {
+    !matches!(sample.status, Some(200..=299))
+}
+
+/// Percentiles over answered requests; endpoints answering 401/403 (not readable by the caller)
+/// are left out. None when nothing could be sampled.
+fn summarize_api_latency(samples: &[ApiSample]) -> Option<ApiLatencySummary> {
+    let counted: Vec<&ApiSample> = samples
+        .iter()
+        .filter(|s| !matches!(s.status, Some(401 | 403)))
+        .collect();
+    if counted.is_empty() {
+        return None;
+    }
+    let sorted_ms = |samples: &[&ApiSample]| {
+        let mut ms: Vec<u64> = samples
+            .iter()
+            .filter(|s| s.status.is_some())
+            .map(|s| s.ms)
+            .collect();
+        ms.sort_unstable();
+        ms
+    };
+    let all = sorted_ms(&counted);
+    let mut endpoints = Vec::new();
+    for path in LATENCY_ENDPOINTS {
+        let of_path: Vec<&ApiSample> = counted.iter().copied().filter(|s| s.path == path).collect();
+        if of_path.is_empty() {
+            continue;
+        }
+        let ms = sorted_ms(&of_path);
+        endpoints.push(ApiEndpointLatency {
+            path: path.to_string(),
+            samples: of_path.len() as u32,
+            errors: of_path.iter().filter(|s| is_failed(s)).count() as u32,
+            p50_ms: percentile(&ms, 50.0),
+            p95_ms: percentile(&ms, 95.0),
+        });
+    }
+    Some(ApiLatencySummary {
+        samples: counted.len() as u32,
+        errors: counted.iter().filter(|s| is_failed(s)).count() as u32,
+        p50_ms: percentile(&all, 50.0),
+        p95_ms: percentile(&all, 95.0),
+        p99_ms: percentile(&all, 99.0),
+        max_ms: all.last().copied().unwrap_or_default(),
+        endpoints,
+    })
+}
+
+fn api_server_issue(
+    severity: IssueSeverity,
+    code: &str,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue::new(
+        severity,
+        "ControlPlane",
+        code,
+        description,
+        "kube-apiserver".to_string(),
+        recommendation,
+    )
+}
+
+/// Slow p95 (CTRL-011) and failed requests (CTRL-012) over the latency burst.
+fn api_latency_check(summary: Option<&ApiLatencySummary>, issues: &mut Vec<Issue>) -> CheckResult {
+    let name = "API Server Latency";
+    let description = "Samples latency and error rate of cheap API server requests";
+    let Some(summary) = summary else {
+        return CheckResult {
+            name: name.to_string(),
+            description: description.to_string(),
+            status: CheckStatus::Pass,
+            score: 100.0,
+            max_score: 100.0,
+            details: Some("No sampled endpoint was readable; latency not measured".to_string()),
+            recommendations: vec![],
+        };
+    };
+
+    let mut findings = Vec::new();
+    if summary.p95_ms >= SLOW_API_P95_MS {
+        let severity = if summary.p95_ms >= VERY_SLOW_API_P95_MS {
+            IssueSeverity::Critical
+        } else {
+            IssueSeverity::Warning
+        };
+        findings.push(api_server_issue(
+            severity,
+            "CTRL-011",
+            format!(
+                "API server p95 latency is {} ms over {} cheap requests (p50 {} ms, max {} ms)",
+                summary.p95_ms, summary.samples, summary.p50_ms, summary.max_ms
+            ),
+            "Check API server CPU/memory, etcd latency, expensive LIST clients and admission webhooks; compare with apiserver_request_duration_seconds.",
+        ));
+    }
+    if summary.errors > 0 {
+        let rate = summary.errors as f64 / summary.samples as f64;
+        let failing: Vec<String> = summary
+            .endpoints
+            .iter()
+            .filter(|e| e.errors > 0)
+            .map(|e| format!("{} ({}/{})", e.path, e.errors, e.samples))
+            .collect();
+        findings.push(api_server_issue(
+            if rate >= API_ERROR_RATE_CRITICAL {
+                IssueSeverity::Critical
+            } else {
+                IssueSeverity::Warning
+            },
+            "CTRL-012",
+            format!(
+                "{} of {} API server requests failed or returned non-2xx: {}",
+                summary.errors,
+                summary.samples,
+                failing.join(", ")
+            ),
+            "Check `kubectl get --raw='/readyz?verbose'` for failing health checks and the API server and etcd logs.",
+        ));
+    }
+
+    let details = format!(
+        "{} requests: p50 {} ms, p95 {} ms, p99 {} ms, max {} ms; {} failed",
+        summary.samples,
+        summary.p50_ms,
+        summary.p95_ms,
+        summary.p99_ms,
+        summary.max_ms,
+        summary.errors
+    );
+    let check = static_pod_check(
+        name,
+        description,
+        details,
+        &findings,
+        "Investigate API server load and health (see CTRL-011, CTRL-012).",
+    );
+    issues.extend(findings);
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{PodSpec, VolumeMount};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn static_pod(component: &str, command: &[&str]) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(format!("{}-cp1", component)),
+                namespace: Some("kube-system".to_string()),
+                labels: Some([("component".to_string(), component.to_string())].into()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                node_name: Some("cp1".to_string()),
+                containers: vec![Container {
+                    name: component.to_string(),
+                    command: Some(command.iter().map(|s| s.to_string()).collect()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn codes(issues: &[Issue]) -> Vec<&str> {
+        issues.iter().filter_map(|i| i.rule_id.as_deref()).collect()
+    }
+
+    #[test]
+    fn stale_leader_lease_and_failing_healthz() {
+        use k8s_openapi::api::coordination::v1::LeaseSpec;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+
+        let now = Utc::now();
+        let lease = |name: &str, age_secs: i64| Lease {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("kube-system".to_string()),
+                ..Default::default()
+            },
+            spec: Some(LeaseSpec {
+                holder_identity: Some(format!("cp1_{}", name)),
+                lease_duration_seconds: Some(15),
+                renew_time: Some(MicroTime(now - chrono::Duration::seconds(age_secs))),
+                ..Default::default()
+            }),
+        };
+        let mut identity = lease("kube-apiserver-abc", 7200);
+        identity.metadata.labels = Some(
+            [(
+                APISERVER_IDENTITY_LABEL.to_string(),
+                "kube-apiserver".to_string(),
+            )]
+            .into(),
+        );
+        let leases = vec![
+            lease("kube-scheduler", 3),
+            lease("kube-controller-manager", 900),
+            lease("cloud-controller-manager", 5),
+            lease("snapshot-controller-leader", 3600),
+            identity,
+        ];
+        let mut issues = Vec::new();
+        let check = leader_election_check(&leases, now, &mut issues);
+        assert_eq!(codes(&issues), ["CTRL-013", "CTRL-020"]);
+        assert_eq!(
+            issues[1].resource.as_deref(),
+            Some("kube-system/snapshot-controller-leader")
+        );
+        assert_eq!(
+            issues[0].resource.as_deref(),
+            Some("kube-system/kube-controller-manager")
+        );
+        assert_eq!(check.status, CheckStatus::Critical);
+
+        let mut issues = Vec::new();
+        let check = leader_election_check(&[], now, &mut issues);
+        assert!(issues.is_empty());
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        let results = vec![
+            ("kube-scheduler-cp1".to_string(), Some(200)),
+            ("kube-controller-manager-cp1".to_string(), Some(500)),
+            ("kube-scheduler-cp2".to_string(), Some(403)),
+        ];
+        let check = component_healthz_check(&results, &mut issues);
+        assert_eq!(codes(&issues), ["CTRL-014"]);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert!(check
+            .details
+            .unwrap()
+            .contains("kube-scheduler-cp2: not reachable"));
+    }
+
+    #[test]
+    fn api_latency_percentiles_and_errors() {
+        let mut samples: Vec<ApiSample> = (1..=20)
+            .map(|ms| ApiSample {
+                path: "/version",
+                status: Some(200),
+                ms: ms * 100,
+            })
+            .collect();
+        samples.push(ApiSample {
+            path: "/readyz",
+            status: Some(500),
+            ms: 50,
+        });
+        samples.push(ApiSample {
+            path: "/readyz",
+            status: None,
+            ms: 30_000,
+        });
+        samples.push(ApiSample {
+            path: "/api/v1/namespaces?limit=1",
+            status: Some(403),
+            ms: 5,
+        });
+
+        let summary = summarize_api_latency(&samples).unwrap();
+        assert_eq!(summary.samples, 22);
+        assert_eq!(summary.errors, 2);
+        assert_eq!((summary.p50_ms, summary.p95_ms), (1000, 1900));
+        assert_eq!(summary.max_ms, 2000);
+        assert_eq!(summary.endpoints.len(), 2);
+        assert_eq!(summary.endpoints[1].path, "/readyz");
+        assert_eq!(summary.endpoints[1].errors, 2);
+
+        let mut issues = Vec::new();
+        let check = api_latency_check(Some(&summary), &mut issues);
+        assert_eq!(codes(&issues), ["CTRL-011", "CTRL-012"]);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert!(summarize_api_latency(&samples[22..]).is_none());
+    }
+
+    #[test]
+    fn flags_risky_apiserver_settings() {
+        let pod = static_pod(
+            "kube-apiserver",
+            &[
+                "kube-apiserver",
+                "--insecure-port=8080",
+                "--authorization-mode",
+                "Node,AlwaysAllow",
+                "--feature-gates=PodSecurity=true,SomethingNew=true",
+            ],
+        );
+        let mut issues = Vec::new();
+        let check = inspect_component_flags(&[pod], &mut issues);
+        assert_eq!(
+            codes(&issues),
+            vec!["CTRL-003", "CTRL-004", "CTRL-005", "CTRL-006", "CTRL-007"]
+        );
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(
+            issues[0].resource.as_deref(),
+            Some("kube-system/kube-apiserver-cp1")
+        );
+        assert!(issues[4].description.contains("PodSecurity (GA in 1.25"));
+    }
+
+    #[test]
+    fn hardened_apiserver_and_other_components_pass() {
+        let apiserver = static_pod(
+            "kube-apiserver",
+            &[
+                "kube-apiserver",
+                "--anonymous-auth=false",
+                "--authorization-mode=Node,RBAC",
+                "--audit-log-path=/var/log/kubernetes/audit.log",
+            ],
+        );
+        let scheduler = static_pod("kube-scheduler", &["kube-scheduler", "--leader-elect=true"]);
+        let mut issues = Vec::new();
+        let check = inspect_component_flags(&[apiserver, scheduler], &mut issues);
+        assert!(issues.is_empty());
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("2 component pod(s) audited, 0 risky flag(s)")
+        );
+
+        let check = inspect_component_flags(&[], &mut issues);
+        assert!(check.details.unwrap().contains("managed control plane"));
+    }
+
+    #[test]
+    fn audit_logging_requires_policy_persisted_sink_and_retention() {
+        let mut pod = static_pod(
+            "kube-apiserver",
+            &[
+                "kube-apiserver",
+                "--audit-policy-file=/etc/kubernetes/audit/policy.yaml",
+                "--audit-log-path=/var/log/kubernetes/audit/audit.log",
+                "--audit-log-maxage=7",
+                "--audit-log-maxbackup=10",
+            ],
+        );
+        let mut issues = Vec::new();
+        let check = inspect_audit_logging(std::slice::from_ref(&pod), &mut issues);
+        assert_eq!(codes(&issues), vec!["CTRL-008", "CTRL-009", "CTRL-010"]);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert!(issues[2]
+            .description
+            .ends_with("maxage=7 (< 30), maxsize unset (< 100)"));
+
+        let container = &mut pod.spec.as_mut().unwrap().containers[0];
+        container.volume_mounts = Some(
+            ["/etc/kubernetes/audit", "/var/log/kubernetes/audit/"]
+                .iter()
+                .map(|p| VolumeMount {
+                    mount_path: p.to_string(),
+                    name: "audit".to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+        );
+        let mut issues = Vec::new();
+        let check = inspect_audit_logging(&[pod], &mut issues);
+        assert_eq!(codes(&issues), vec!["CTRL-010"]);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("Audit sinks per API server: cp1: log file")
+        );
+    }
+
+    #[test]
+    fn audit_webhook_without_policy_is_flagged() {
+        let pod = static_pod(
+            "kube-apiserver",
+            &[
+                "kube-apiserver",
+                "--audit-webhook-config-file=/etc/kubernetes/audit-webhook.yaml",
+            ],
+        );
+        let (sinks, findings) = audit_findings(&pod);
+        assert_eq!(sinks, vec!["webhook"]);
+        assert_eq!(codes(&findings), vec!["CTRL-008"]);
+
+        let bare = static_pod("kube-apiserver", &["kube-apiserver"]);
+        let (sinks, findings) = audit_findings(&bare);
+        assert!(sinks.is_empty() && findings.is_empty());
+    }
+}
@@ -0,0 +1,323 @@
+//! Pod-level findings rolled up to the controller that owns the pods: the same issue code on
+//! several pods of one Deployment, StatefulSet, DaemonSet, Job or CronJob becomes one finding
+//! on the controller, noting how many of its pods are affected. A 50-replica Deployment without
+//! limits is then one RES-002 row pointing at the object to fix instead of 50 pod rows.
+
+use std::collections::{BTreeSet, HashMap};
+
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::inspections::sampling::SAMPLED_MODULES;
+use crate::inspections::types::{InspectionResult, Issue};
+
+/// Controller kinds pod findings are rolled up to (bare ReplicaSets are left alone).
+const CONTROLLER_KINDS: &[&str] = &["Deployment", "StatefulSet", "DaemonSet", "Job", "CronJob"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Controller {
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+}
+
+/// Owning controller of every controller-managed pod, keyed by the `namespace/pod` resource
+/// string the inspectors use.
+#[derive(Debug, Default)]
+pub struct PodOwners {
+    pods: HashMap<String, Controller>,
+    pod_counts: HashMap<Controller, usize>,
+    /// ReplicaSet → Deployment and Job → CronJob, by namespace and name.
+    replica_set_owners: Parents,
+    job_owners: Parents,
+}
+
+type Parents = HashMap<(String, String), (String, String)>;
+
+fn controller_ref(meta: &ObjectMeta) -> Option<(&str, &str)> {
+    meta.owner_references
+        .as_deref()?
+        .iter()
+        .find(|o| o.controller == Some(true))
+        .or_else(|| meta.owner_references.as_deref()?.first())
+        .map(|o| (o.kind.as_str(), o.name.as_str()))
+}
+
+fn parents<'a>(items: impl Iterator<Item = &'a ObjectMeta>) -> Parents {
+    items
+        .filter_map(|m| {
+            let (kind, name) = controller_ref(m)?;
+            Some((
+                (
+                    m.namespace.clone().unwrap_or_default(),
+                    m.name.clone().unwrap_or_default(),
+                ),
+                (kind.to_string(), name.to_string()),
+            ))
+        })
+        .collect()
+}
+
+impl PodOwners {
+    /// Resolve pods through ReplicaSet → Deployment and Job → CronJob ownership.
+    pub fn from_objects(pods: &[Pod], replica_sets: &[ReplicaSet], jobs: &[Job]) -> Self {
+        let mut owners = Self::with_parents(replica_sets, jobs);
+        owners.add_pods(pods);
+        owners
+    }
+
+    /// No pods yet; [`PodOwners::add_pods`] resolves them page by page through these
+    /// ReplicaSets and Jobs.
+    pub fn with_parents(replica_sets: &[ReplicaSet], jobs: &[Job]) -> Self {
+        Self {
+            replica_set_owners: parents(replica_sets.iter().map(|r| &r.metadata)),
+            job_owners: parents(jobs.iter().map(|j| &j.metadata)),
+            ..Self::default()
+        }
+    }
+
+    /// Record the owning controller of each controller-managed pod; the pods are not kept.
+    pub fn add_pods(&mut self, pods: &[Pod]) {
+        for pod in pods {
+            let Some((kind, name)) = controller_ref(&pod.metadata) else {
+                continue;
+            };
+            let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+            let key = (namespace.clone(), name.to_string());
+            let (kind, name) = match kind {
+                "ReplicaSet" => self
+                    .replica_set_owners
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_else(|| (kind.to_string(), name.to_string())),
+                "Job" => self
+                    .job_owners
+                    .get(&key)
+                    .filter(|(k, _)| k == "CronJob")
+                    .cloned()
+                    .unwrap_or_else(|| (kind.to_string(), name.to_string())),
+                _ => (kind.to_string(), name.to_string()),
+            };
+            if !CONTROLLER_KINDS.contains(&kind.as_str()) {
+                continue;
+            }
+            let controller = Controller {
+                kind,
+                namespace: namespace.clone(),
+                name,
+            };
+            *self.pod_counts.entry(controller.clone()).or_default() += 1;
+            self.pods.insert(
+                format!(
+                    "{}/{}",
+                    namespace,
+                    pod.metadata.name.as_deref().unwrap_or_default()
+                ),
+                controller,
+            );
+        }
+    }
+
+    /// Owning controller of a pod, by its `namespace/pod` key.
+    pub fn controller_of(&self, pod: &str) -> Option<&Controller> {
+        self.pods.get(pod)
+    }
+
+    /// Pods of `controller` in scope.
+    pub fn pod_count(&self, controller: &Controller) -> Option<usize> {
+        self.pod_counts.get(controller).copied()
+    }
+}
+
+/// Replace findings with the same issue code, key attributes and severity on two or more pods of
+/// one controller
+/// by a single finding on the controller. Single-pod findings and findings without a code stay.
+/// The sampled modules are skipped: their inspectors roll up while streaming pods.
+pub fn roll_up_pod_issues(inspections: &mut [InspectionResult], owners: &PodOwners) {
+    for inspection in inspections
+        .iter_mut()
+        .filter(|i| !SAMPLED_MODULES.contains(&i.inspection_type.as_str()))
+    {
+        let issues = std::mem::take(&mut inspection.summary.issues);
+        inspection.summary.issues = roll_up(issues, owners);
+    }
+}
+
+/// Issue code, key attributes, severity and owning controller of a pod finding.
+type GroupKey<'a> = (&'a str, &'a [String], String, &'a Controller);
+
+fn group_key<'a>(issue: &'a Issue, owners: &'a PodOwners) -> Option<GroupKey<'a>> {
+    let controller = owners.pods.get(issue.resource.as_deref()?)?;
+    Some((
+        issue.rule_id.as_deref()?,
+        &issue.key_attributes,
+        format!("{:?}", issue.severity),
+        controller,
+    ))
+}
+
+fn roll_up(issues: Vec<Issue>, owners: &PodOwners) -> Vec<Issue> {
+    let key = |issue| group_key(issue, owners);
+    let mut pods_by_key: HashMap<GroupKey<'_>, BTreeSet<&str>> = HashMap::new();
+    for issue in &issues {
+        if let (Some(k), Some(pod)) = (key(issue), issue.resource.as_deref()) {
+            pods_by_key.entry(k).or_default().insert(pod);
+        }
+    }
+
+    let mut rolled: HashMap<GroupKey<'_>, usize> = HashMap::new();
+    let mut out: Vec<Issue> = Vec::with_capacity(issues.len());
+    for issue in &issues {
+        let Some(k) = key(issue).filter(|k| pods_by_key[k].len() > 1) else {
+            out.push(issue.clone());
+            continue;
+        };
+        if let Some(&at) = rolled.get(&k) {
+            let merged = &mut out[at];
+            for cmd in &issue.remediation_commands {
+                if !merged.remediation_commands.contains(cmd) {
+                    merged.remediation_commands.push(cmd.clone());
+                }
+            }
+            continue;
+        }
+        let controller = k.3;
+        let affected = pods_by_key[&k].len();
+        let total = owners.pod_count(controller).unwrap_or(affected);
+        rolled.insert(k, out.len());
+        out.push(controller_issue(issue, controller, affected, total));
+    }
+    out
+}
+
+/// The finding `issue` of `affected` of the `total` pods of `controller`, on the controller.
+pub fn controller_issue(
+    issue: &Issue,
+    controller: &Controller,
+    affected: usize,
+    total: usize,
+) -> Issue {
+    Issue {
+        severity: issue.severity.clone(),
+        category: controller.kind.clone(),
+        description: format!(
+            "{} of {} pod(s) of {} {}/{}, e.g. {}",
+            affected,
+            total,
+            controller.kind,
+            controller.namespace,
+            controller.name,
+            issue.description
+        ),
+        resource: Some(format!("{}/{}", controller.namespace, controller.name)),
+        recommendation: format!(
+            "{} (fix in the {} pod template)",
+            issue.recommendation, controller.kind
+        ),
+        rule_id: issue.rule_id.clone(),
+        team: issue.team.clone(),
+        fingerprint: None,
+        key_attributes: issue.key_attributes.clone(),
+        remediation_commands: issue.remediation_commands.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::IssueSeverity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    fn meta(ns: &str, name: &str, owner: Option<(&str, &str)>) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            owner_references: owner.map(|(kind, name)| {
+                vec![OwnerReference {
+                    kind: kind.to_string(),
+                    name: name.to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(name: &str, owner: Option<(&str, &str)>) -> Pod {
+        Pod {
+            metadata: meta("shop", name, owner),
+            ..Default::default()
+        }
+    }
+
+    fn issue(rule: &str, resource: &str) -> Issue {
+        Issue::new(
+            IssueSeverity::Warning,
+            "Container",
+            rule,
+            format!("Container app in pod {} has no resource limits", resource),
+            resource.to_string(),
+            "Set limits",
+        )
+    }
+
+    fn owners() -> PodOwners {
+        let rs = ReplicaSet {
+            metadata: meta("shop", "web-7d9f", Some(("Deployment", "web"))),
+            ..Default::default()
+        };
+        let job = Job {
+            metadata: meta("shop", "backup-2900", Some(("CronJob", "backup"))),
+            ..Default::default()
+        };
+        PodOwners::from_objects(
+            &[
+                pod("web-7d9f-a", Some(("ReplicaSet", "web-7d9f"))),
+                pod("web-7d9f-b", Some(("ReplicaSet", "web-7d9f"))),
+                pod("web-7d9f-c", Some(("ReplicaSet", "web-7d9f"))),
+                pod("db-0", Some(("StatefulSet", "db"))),
+                pod("backup-2900-x", Some(("Job", "backup-2900"))),
+                pod("debug", None),
+            ],
+            &[rs],
+            &[job],
+        )
+    }
+
+    #[test]
+    fn resolves_pods_to_top_level_controllers() {
+        let owners = owners();
+        assert_eq!(owners.pods["shop/web-7d9f-a"].kind, "Deployment");
+        assert_eq!(owners.pods["shop/web-7d9f-a"].name, "web");
+        assert_eq!(owners.pods["shop/db-0"].kind, "StatefulSet");
+        assert_eq!(owners.pods["shop/backup-2900-x"].kind, "CronJob");
+        assert!(!owners.pods.contains_key("shop/debug"));
+    }
+
+    #[test]
+    fn rolls_up_repeated_findings_per_controller() {
+        let issues = vec![
+            issue("RES-002", "shop/web-7d9f-a"),
+            issue("RES-002", "shop/web-7d9f-b"),
+            issue("RES-002", "shop/web-7d9f-b"),
+            issue("RES-002", "shop/db-0"),
+            issue("SEC-009", "shop/web-7d9f-a"),
+            issue("RES-002", "shop/debug"),
+        ];
+        let out = roll_up(issues, &owners());
+        assert_eq!(out.len(), 4);
+        assert_eq!(out[0].category, "Deployment");
+        assert_eq!(out[0].resource.as_deref(), Some("shop/web"));
+        assert!(out[0]
+            .description
+            .starts_with("2 of 3 pod(s) of Deployment shop/web, e.g. "));
+        // Single-pod findings keep pointing at the pod.
+        assert_eq!(out[1].resource.as_deref(), Some("shop/db-0"));
+        assert_eq!(out[2].rule_id.as_deref(), Some("SEC-009"));
+        assert_eq!(out[2].resource.as_deref(), Some("shop/web-7d9f-a"));
+        assert_eq!(out[3].resource.as_deref(), Some("shop/debug"));
+    }
+}
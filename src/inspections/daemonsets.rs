@@ -0,0 +1,540 @@
+//! DaemonSet scheduling completeness. A DaemonSet missing from some nodes does not fail loudly:
+//! its status just reports fewer ready pods than desired, while the nodes without a CNI, CSI,
+//! kube-proxy or log agent pod keep accepting workloads that then cannot reach the network,
+//! mount volumes or ship logs. For every DaemonSet the Ready nodes without a ready pod are named
+//! with the reason: an untolerated taint, a nodeSelector or node affinity mismatch, or the
+//! scheduler message of the Pending pod (e.g. insufficient CPU). NODE-031 reports the same
+//! unschedulable pods per node; this is the per-DaemonSet view, including pods that are
+//! scheduled but not ready.
+
+use std::collections::{BTreeMap, HashMap};
+
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::{Node, NodeSelectorRequirement, Pod, PodSpec, Taint, Toleration};
+
+use crate::inspections::node_hygiene::daemonset_pod_node;
+use crate::inspections::node_networking::rollout_checked;
+use crate::inspections::priority::critical_addon;
+use crate::inspections::resilience::is_ready;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Log and metrics agents that must run on every node, by workload name fragment.
+const NODE_AGENTS: &[&str] = &[
+    "kube-proxy",
+    "fluent-bit",
+    "fluentd",
+    "promtail",
+    "vector",
+    "filebeat",
+    "datadog-agent",
+    "node-exporter",
+];
+
+/// Node labels DaemonSets select on to target an OS or CPU architecture; nodes left out by them
+/// are left out by design (e.g. Linux-only CNI agents on Windows nodes) and not reported.
+const PLATFORM_LABELS: &[&str] = &[
+    "kubernetes.io/os",
+    "kubernetes.io/arch",
+    "beta.kubernetes.io/os",
+    "beta.kubernetes.io/arch",
+];
+
+/// Nodes listed per finding before the rest is summarised as "and N more".
+const MAX_LISTED_NODES: usize = 5;
+
+/// CNI, CSI or node agent the DaemonSet is, from its name; these are expected on every node.
+fn infrastructure_kind(name: &str) -> Option<&'static str> {
+    critical_addon(name).or_else(|| {
+        NODE_AGENTS
+            .iter()
+            .any(|fragment| name.contains(fragment))
+            .then_some("node agent")
+    })
+}
+
+fn tolerates(tolerations: &[Toleration], taint: &Taint) -> bool {
+    tolerations.iter().any(|t| {
+        let key = match t.key.as_deref() {
+            None | Some("") => t.operator.as_deref() == Some("Exists"),
+            Some(key) => {
+                key == taint.key
+                    && (t.operator.as_deref() == Some("Exists")
+                        || t.value.as_deref().unwrap_or("") == taint.value.as_deref().unwrap_or(""))
+            }
+        };
+        key && t
+            .effect
+            .as_deref()
+            .is_none_or(|e| e.is_empty() || e == taint.effect)
+    })
+}
+
+/// First NoSchedule or NoExecute taint of `node` that `spec` does not tolerate. Taints under
+/// `node.kubernetes.io/` (not-ready, unschedulable, pressure) are tolerated by every DaemonSet
+/// pod, the controller adds those tolerations itself.
+fn untolerated_taint<'a>(node: &'a Node, spec: &PodSpec) -> Option<&'a Taint> {
+    let tolerations = spec.tolerations.as_deref().unwrap_or(&[]);
+    node.spec
+        .as_ref()
+        .and_then(|s| s.taints.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|t| t.effect == "NoSchedule" || t.effect == "NoExecute")
+        .filter(|t| !t.key.starts_with("node.kubernetes.io/"))
+        .find(|t| !tolerates(tolerations, t))
+}
+
+fn expression_matches(labels: &BTreeMap<String, String>, e: &NodeSelectorRequirement) -> bool {
+    let value = labels.get(&e.key);
+    let values = e.values.as_deref().unwrap_or(&[]);
+    let number = |v: &str| v.parse::<i64>().ok();
+    match e.operator.as_str() {
+        "In" => value.is_some_and(|v| values.contains(v)),
+        "NotIn" => value.is_none_or(|v| !values.contains(v)),
+        "Exists" => value.is_some(),
+        "DoesNotExist" => value.is_none(),
+        "Gt" | "Lt" => match (
+            value.and_then(|v| number(v)),
+            values.first().and_then(|v| number(v)),
+        ) {
+            (Some(v), Some(bound)) if e.operator == "Gt" => v > bound,
+            (Some(v), Some(bound)) => v < bound,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Why `spec`'s nodeSelector or required node affinity rules out a node with `labels`, if it
+/// does. With `platform` false, requirements on [`PLATFORM_LABELS`] are ignored.
+fn selector_mismatch(
+    labels: &BTreeMap<String, String>,
+    spec: &PodSpec,
+    platform: bool,
+) -> Option<String> {
+    let considered = |key: &str| platform || !PLATFORM_LABELS.contains(&key);
+    if let Some((key, value)) = spec
+        .node_selector
+        .iter()
+        .flatten()
+        .filter(|(k, _)| considered(k))
+        .find(|(k, v)| labels.get(*k) != Some(*v))
+    {
+        return Some(format!("nodeSelector {}={} not matched", key, value));
+    }
+    let terms = spec
+        .affinity
+        .as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|a| {
+            a.required_during_scheduling_ignored_during_execution
+                .as_ref()
+        })
+        .map(|r| r.node_selector_terms.as_slice())
+        .unwrap_or(&[]);
+    // Terms are ORed, expressions within a term ANDed; matchFields (metadata.name) only appear
+    // on the pods the controller creates, not in templates, and are not evaluated.
+    let term_matches = |term: &k8s_openapi::api::core::v1::NodeSelectorTerm| {
+        term.match_expressions
+            .iter()
+            .flatten()
+            .filter(|e| considered(&e.key))
+            .all(|e| expression_matches(labels, e))
+    };
+    if !terms.is_empty() && !terms.iter().any(term_matches) {
+        return Some("required node affinity not matched".to_string());
+    }
+    None
+}
+
+fn pod_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+}
+
+/// Why the DaemonSet pod on its node is not ready: the scheduler message while unschedulable,
+/// else the phase.
+fn pod_problem(pod: &Pod) -> String {
+    let status = pod.status.as_ref();
+    let unschedulable = status
+        .and_then(|s| s.conditions.as_ref())
+        .into_iter()
+        .flatten()
+        .find(|c| c.type_ == "PodScheduled" && c.status == "False");
+    match unschedulable {
+        Some(c) => format!(
+            "unschedulable: {}",
+            c.message
+                .as_deref()
+                .or(c.reason.as_deref())
+                .unwrap_or("no message")
+        ),
+        None => format!(
+            "pod {} not ready ({})",
+            pod.metadata.name.as_deref().unwrap_or("unknown"),
+            status.and_then(|s| s.phase.as_deref()).unwrap_or("Unknown")
+        ),
+    }
+}
+
+fn node_list(nodes: &[(String, String)]) -> String {
+    let mut listed: Vec<String> = nodes
+        .iter()
+        .take(MAX_LISTED_NODES)
+        .map(|(node, reason)| format!("{} ({})", node, reason))
+        .collect();
+    if nodes.len() > MAX_LISTED_NODES {
+        listed.push(format!("and {} more", nodes.len() - MAX_LISTED_NODES));
+    }
+    listed.join(", ")
+}
+
+/// "DaemonSet Coverage" check. DS-001 when a DaemonSet has fewer ready pods than desired or a
+/// Ready node it targets has no ready pod, listing those nodes with the reason (Critical for CNI,
+/// CSI and node agents, else Warning); kube-proxy and the Calico, Cilium and Flannel agents are
+/// left to NET-011/NET-013. DS-002 when a CNI, CSI or node agent DaemonSet is kept off Ready
+/// nodes by an untolerated taint or a selector; OS and architecture selectors are by design and
+/// ignored, and so are such exclusions for other DaemonSets.
+pub fn daemonset_coverage(
+    daemon_sets: &[DaemonSet],
+    nodes: &[Node],
+    pods: &[Pod],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let ready_nodes: Vec<&Node> = nodes.iter().filter(|n| is_ready(n)).collect();
+    let mut pods_by_owner: HashMap<&str, Vec<&Pod>> = HashMap::new();
+    for pod in pods {
+        let owner = pod
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .find(|o| o.kind == "DaemonSet" && o.controller == Some(true));
+        if let Some(owner) = owner {
+            pods_by_owner
+                .entry(owner.uid.as_str())
+                .or_default()
+                .push(pod);
+        }
+    }
+    let no_labels = BTreeMap::new();
+    let mut flagged = 0usize;
+    let mut critical = false;
+    for ds in daemon_sets {
+        let Some(template) = ds.spec.as_ref().and_then(|s| s.template.spec.as_ref()) else {
+            continue;
+        };
+        let ns = ds.metadata.namespace.as_deref().unwrap_or("default");
+        let name = ds.metadata.name.as_deref().unwrap_or("unknown");
+        let resource = format!("{}/{}", ns, name);
+        let infrastructure = infrastructure_kind(name);
+        let owned = ds
+            .metadata
+            .uid
+            .as_deref()
+            .and_then(|uid| pods_by_owner.get(uid))
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+
+        let mut missing = Vec::new();
+        let mut excluded = Vec::new();
+        for node in &ready_nodes {
+            let node_name = node.metadata.name.as_deref().unwrap_or("unknown");
+            let labels = node.metadata.labels.as_ref().unwrap_or(&no_labels);
+            if selector_mismatch(labels, template, false).is_none()
+                && selector_mismatch(labels, template, true).is_some()
+            {
+                continue;
+            }
+            let reason = untolerated_taint(node, template)
+                .map(|t| {
+                    format!(
+                        "taint {}{}:{} not tolerated",
+                        t.key,
+                        t.value
+                            .as_deref()
+                            .map(|v| format!("={}", v))
+                            .unwrap_or_default(),
+                        t.effect
+                    )
+                })
+                .or_else(|| selector_mismatch(labels, template, true));
+            if let Some(reason) = reason {
+                excluded.push((node_name.to_string(), reason));
+                continue;
+            }
+            let on_node: Vec<&Pod> = owned
+                .iter()
+                .copied()
+                .filter(|p| daemonset_pod_node(p) == Some(node_name))
+                .collect();
+            if on_node.iter().any(|p| pod_ready(p)) {
+                continue;
+            }
+            let reason = on_node
+                .first()
+                .map_or_else(|| "no pod".to_string(), |p| pod_problem(p));
+            missing.push((node_name.to_string(), reason));
+        }
+
+        let status = ds.status.as_ref();
+        let desired = status.map(|s| s.desired_number_scheduled).unwrap_or(0);
+        let scheduled = status.map(|s| s.current_number_scheduled).unwrap_or(0);
+        let ready = status.map(|s| s.number_ready).unwrap_or(0);
+        let before = issues.len();
+        if (ready < desired || !missing.is_empty()) && !rollout_checked(ds) {
+            let severity = if infrastructure.is_some() {
+                IssueSeverity::Critical
+            } else {
+                IssueSeverity::Warning
+            };
+            critical |= severity == IssueSeverity::Critical;
+            let nodes = if missing.is_empty() {
+                String::new()
+            } else {
+                format!("; missing on {}", node_list(&missing))
+            };
+            issues.push(Issue::new(
+                severity,
+                "DaemonSet",
+                "DS-001",
+                format!(
+                    "DaemonSet {} has {}/{} pods ready ({} scheduled){}",
+                    resource, ready, desired, scheduled, nodes
+                ),
+                resource.to_string(),
+                "Fix the reason per node: free or add capacity for unschedulable pods, check events of pods that are not ready (image pull, crash loop).",
+            ));
+        }
+        if let (Some(kind), false) = (infrastructure, excluded.is_empty()) {
+            issues.push(Issue::new(
+                IssueSeverity::Warning,
+                "DaemonSet",
+                "DS-002",
+                format!(
+                    "{} DaemonSet {} does not run on {} Ready node(s): {}",
+                    kind,
+                    resource,
+                    excluded.len(),
+                    node_list(&excluded)
+                ),
+                resource.to_string(),
+                "Add tolerations (or widen the nodeSelector/affinity) so the DaemonSet runs on every node that runs workloads, or keep workloads off those nodes.",
+            ));
+        }
+        if issues.len() > before {
+            flagged += 1;
+        }
+    }
+    let evaluated = daemon_sets.len();
+    CheckResult {
+        name: "DaemonSet Coverage".to_string(),
+        description: "DaemonSets run a ready pod on every node they should cover".to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if critical {
+            CheckStatus::Critical
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} DaemonSets fully scheduled and ready",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![
+                "See DS-001/DS-002; nodes without CNI, CSI or agent pods fail silently."
+                    .to_string(),
+            ]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DaemonSetSpec, DaemonSetStatus};
+    use k8s_openapi::api::core::v1::{
+        NodeCondition, NodeSpec, NodeStatus, PodCondition, PodStatus, PodTemplateSpec,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, os: &str, taint: Option<&str>) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(BTreeMap::from([(
+                    "kubernetes.io/os".to_string(),
+                    os.to_string(),
+                )])),
+                ..Default::default()
+            },
+            spec: Some(NodeSpec {
+                taints: taint.map(|key| {
+                    vec![Taint {
+                        key: key.to_string(),
+                        value: Some("true".to_string()),
+                        effect: "NoSchedule".to_string(),
+                        ..Default::default()
+                    }]
+                }),
+                ..Default::default()
+            }),
+            status: Some(NodeStatus {
+                conditions: Some(vec![NodeCondition {
+                    type_: "Ready".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn daemon_set(name: &str, desired: i32, ready: i32) -> DaemonSet {
+        DaemonSet {
+            metadata: ObjectMeta {
+                namespace: Some("kube-system".to_string()),
+                name: Some(name.to_string()),
+                uid: Some(format!("uid-{}", name)),
+                ..Default::default()
+            },
+            spec: Some(DaemonSetSpec {
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec {
+                        node_selector: Some(BTreeMap::from([(
+                            "kubernetes.io/os".to_string(),
+                            "linux".to_string(),
+                        )])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            status: Some(DaemonSetStatus {
+                desired_number_scheduled: desired,
+                current_number_scheduled: desired,
+                number_ready: ready,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn pod(ds: &str, node: &str, ready: bool, unschedulable: Option<&str>) -> Pod {
+        let mut conditions = vec![PodCondition {
+            type_: "Ready".to_string(),
+            status: if ready { "True" } else { "False" }.to_string(),
+            ..Default::default()
+        }];
+        if let Some(message) = unschedulable {
+            conditions.push(PodCondition {
+                type_: "PodScheduled".to_string(),
+                status: "False".to_string(),
+                message: Some(message.to_string()),
+                ..Default::default()
+            });
+        }
+        Pod {
+            metadata: ObjectMeta {
+                namespace: Some("kube-system".to_string()),
+                name: Some(format!("{}-{}", ds, node)),
+                owner_references: Some(vec![OwnerReference {
+                    kind: "DaemonSet".to_string(),
+                    name: ds.to_string(),
+                    uid: format!("uid-{}", ds),
+                    controller: Some(true),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                node_name: unschedulable.is_none().then(|| node.to_string()),
+                affinity: unschedulable.map(|_| k8s_openapi::api::core::v1::Affinity {
+                    node_affinity: Some(k8s_openapi::api::core::v1::NodeAffinity {
+                        required_during_scheduling_ignored_during_execution: Some(
+                            k8s_openapi::api::core::v1::NodeSelector {
+                                node_selector_terms: vec![
+                                    k8s_openapi::api::core::v1::NodeSelectorTerm {
+                                        match_fields: Some(vec![NodeSelectorRequirement {
+                                            key: "metadata.name".to_string(),
+                                            operator: "In".to_string(),
+                                            values: Some(vec![node.to_string()]),
+                                        }]),
+                                        ..Default::default()
+                                    },
+                                ],
+                            },
+                        ),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some(if ready { "Running" } else { "Pending" }.to_string()),
+                conditions: Some(conditions),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn names_missing_nodes_with_reasons() {
+        let nodes = vec![
+            node("a", "linux", None),
+            node("b", "linux", None),
+            node("gpu", "linux", Some("nvidia.com/gpu")),
+            node("win", "windows", None),
+        ];
+        let daemon_sets = vec![
+            daemon_set("ebs-csi-node", 2, 1),
+            daemon_set("app-agent", 2, 2),
+        ];
+        let pods = vec![
+            pod("ebs-csi-node", "a", true, None),
+            pod(
+                "ebs-csi-node",
+                "b",
+                false,
+                Some("0/4 nodes are available: 1 Insufficient cpu."),
+            ),
+            pod("app-agent", "a", true, None),
+            pod("app-agent", "b", true, None),
+        ];
+        let mut issues = Vec::new();
+        let check = daemonset_coverage(&daemon_sets, &nodes, &pods, &mut issues);
+        let found: Vec<(&str, IssueSeverity)> = issues
+            .iter()
+            .map(|i| (i.rule_id.as_deref().unwrap(), i.severity.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("DS-001", IssueSeverity::Critical),
+                ("DS-002", IssueSeverity::Warning)
+            ]
+        );
+        assert!(issues[0]
+            .description
+            .contains("1/2 pods ready (2 scheduled); missing on b (unschedulable: 0/4 nodes are available: 1 Insufficient cpu.)"));
+        assert!(issues[1].description.ends_with(
+            "1 Ready node(s): gpu (taint nvidia.com/gpu=true:NoSchedule not tolerated)"
+        ));
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(check.score, 50.0);
+    }
+}
@@ -0,0 +1,415 @@
+use anyhow::Result;
+use chrono::Utc;
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use kube::api::ListParams;
+use std::collections::HashMap;
+
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::types::*;
+use crate::k8s::K8sClient;
+use crate::utils::pattern::matches_any;
+
+/// Service port names (substring) that expose profiling or debugger endpoints.
+const DEBUG_PORT_NAME_KEYWORDS: [&str; 4] = ["pprof", "debug", "jdwp", "delve"];
+/// Well-known debugger/profiler ports: Go pprof, Delve, Java JDWP, Node.js inspector.
+const DEBUG_PORT_NUMBERS: [i32; 4] = [6060, 40000, 5005, 9229];
+/// ReplicaSet annotation carrying the Deployment's desired replicas at that revision.
+const DESIRED_REPLICAS_ANNOTATION: &str = "deployment.kubernetes.io/desired-replicas";
+
+/// Debug settings left behind in production-tier namespaces after incident debugging.
+pub struct DebugSettingsInspector<'a> {
+    client: &'a K8sClient,
+    options: &'a InspectionOptions,
+}
+
+impl<'a> DebugSettingsInspector<'a> {
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
+    }
+
+    pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        let mut checks = Vec::new();
+        let mut issues = Vec::new();
+
+        checks.push(self.inspect_debug_env(namespace, &mut issues).await?);
+        checks.push(self.inspect_debug_ports(namespace, &mut issues).await?);
+        checks.push(self.inspect_scaled_down(namespace, &mut issues).await?);
+
+        let overall_score = if checks.is_empty() {
+            0.0
+        } else {
+            checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64
+        };
+
+        let summary = self.build_summary(&checks, issues);
+
+        Ok(InspectionResult {
+            inspection_type: "Debug Settings".to_string(),
+            timestamp: Utc::now(),
+            overall_score,
+            checks,
+            summary,
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        })
+    }
+
+    fn is_prod_namespace(&self, ns: &str) -> bool {
+        matches_any(&self.options.prod_namespace_patterns, ns)
+    }
+
+    /// Returns the first `NAME=value` env entry of the template matching a debug pattern.
+    fn debug_env_in_template(&self, template: &PodTemplateSpec) -> Option<(String, String)> {
+        let spec = template.spec.as_ref()?;
+        for container in spec
+            .init_containers
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .chain(spec.containers.iter())
+        {
+            for env in container.env.as_deref().unwrap_or(&[]) {
+                let Some(value) = env.value.as_deref() else {
+                    continue;
+                };
+                let entry = format!("{}={}", env.name, value);
+                if matches_any(&self.options.debug_env_patterns, &entry) {
+                    return Some((container.name.clone(), entry));
+                }
+            }
+        }
+        None
+    }
+
+    async fn inspect_debug_env(
+        &self,
+        namespace: Option<&str>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let mut templates: Vec<(&str, String, String, PodTemplateSpec)> = Vec::new();
+        let deployments = self
+            .client
+            .list_all(&self.client.deployments(namespace), &ListParams::default())
+            .await?;
+        for d in deployments.items {
+            if let Some(spec) = d.spec {
+                templates.push((
+                    "Deployment",
+                    d.metadata.namespace.unwrap_or_default(),
+                    d.metadata.name.unwrap_or_default(),
+                    spec.template,
+                ));
+            }
+        }
+        let statefulsets = self
+            .client
+            .list_all(
+                &self.client.stateful_sets(namespace),
+                &ListParams::default(),
+            )
+            .await?;
+        for s in statefulsets.items {
+            if let Some(spec) = s.spec {
+                templates.push((
+                    "StatefulSet",
+                    s.metadata.namespace.unwrap_or_default(),
+                    s.metadata.name.unwrap_or_default(),
+                    spec.template,
+                ));
+            }
+        }
+        let daemonsets = self
+            .client
+            .list_all(&self.client.daemon_sets(namespace), &ListParams::default())
+            .await?;
+        for d in daemonsets.items {
+            if let Some(spec) = d.spec {
+                templates.push((
+                    "DaemonSet",
+                    d.metadata.namespace.unwrap_or_default(),
+                    d.metadata.name.unwrap_or_default(),
+                    spec.template,
+                ));
+            }
+        }
+
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for (kind, ns, name, template) in &templates {
+            if !self.is_prod_namespace(ns) {
+                continue;
+            }
+            evaluated += 1;
+            if let Some((container, entry)) = self.debug_env_in_template(template) {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: kind.to_string(),
+                    description: format!(
+                        "{} {}/{} container {} runs with debug setting {}",
+                        kind, ns, name, container, entry
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation:
+                        "Restore the production log level; debug logging costs CPU, log storage and may leak sensitive data."
+                            .to_string(),
+                    rule_id: Some("DBG-001".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: vec![format!("kind={}", kind)],
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+
+        Ok(ratio_check(
+            "Debug Logging",
+            "Checks production workloads for debug log-level environment variables",
+            evaluated,
+            flagged,
+            "workloads in production namespaces without debug env settings",
+            "Remove debug log-level env vars from production workloads",
+        ))
+    }
+
+    async fn inspect_debug_ports(
+        &self,
+        namespace: Option<&str>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let services = self
+            .client
+            .list_all(&self.client.services(namespace), &ListParams::default())
+            .await?;
+
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for svc in &services.items {
+            let ns = svc.metadata.namespace.as_deref().unwrap_or("default");
+            if !self.is_prod_namespace(ns) {
+                continue;
+            }
+            let name = svc.metadata.name.as_deref().unwrap_or("unknown");
+            evaluated += 1;
+            let debug_port = svc
+                .spec
+                .as_ref()
+                .and_then(|s| s.ports.as_ref())
+                .and_then(|ports| {
+                    ports.iter().find(|p| {
+                        let port_name = p.name.as_deref().unwrap_or("").to_lowercase();
+                        DEBUG_PORT_NAME_KEYWORDS
+                            .iter()
+                            .any(|k| port_name.contains(k))
+                            || DEBUG_PORT_NUMBERS.contains(&p.port)
+                    })
+                });
+            if let Some(port) = debug_port {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: "Service".to_string(),
+                    description: format!(
+                        "Service {}/{} exposes debug/profiling port {} ({})",
+                        ns,
+                        name,
+                        port.port,
+                        port.name.as_deref().unwrap_or("unnamed")
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation:
+                        "Remove pprof/debugger ports from production Services; use kubectl port-forward for ad-hoc profiling."
+                            .to_string(),
+                    rule_id: Some("DBG-002".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+
+        Ok(ratio_check(
+            "Debug Ports",
+            "Checks production Services for exposed pprof/debugger ports",
+            evaluated,
+            flagged,
+            "services in production namespaces without debug ports",
+            "Remove debug and profiling ports from production Services",
+        ))
+    }
+
+    async fn inspect_scaled_down(
+        &self,
+        namespace: Option<&str>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let deployments = self
+            .client
+            .list_all(&self.client.deployments(namespace), &ListParams::default())
+            .await?;
+        let replica_sets = self
+            .client
+            .list_unselected(&self.client.replica_sets(namespace), &ListParams::default())
+            .await?;
+        // Deployments scaled by an HPA legitimately change replicas.
+        let hpas = self
+            .client
+            .list_unselected(
+                &self.client.horizontal_pod_autoscalers(namespace),
+                &ListParams::default(),
+            )
+            .await
+            .map(|l| l.items)
+            .unwrap_or_default();
+
+        // (namespace, deployment) -> highest desired replicas seen in any revision
+        let mut historic_max: HashMap<(String, String), i32> = HashMap::new();
+        for rs in &replica_sets.items {
+            let ns = rs.metadata.namespace.clone().unwrap_or_default();
+            let Some(owner) = rs
+                .metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or(&[])
+                .iter()
+                .find(|o| o.kind == "Deployment")
+            else {
+                continue;
+            };
+            let desired = rs
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|a| a.get(DESIRED_REPLICAS_ANNOTATION))
+                .and_then(|v| v.parse::<i32>().ok())
+                .unwrap_or(0);
+            let entry = historic_max.entry((ns, owner.name.clone())).or_insert(0);
+            *entry = (*entry).max(desired);
+        }
+
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for d in &deployments.items {
+            let ns = d.metadata.namespace.clone().unwrap_or_default();
+            if !self.is_prod_namespace(&ns) {
+                continue;
+            }
+            let name = d.metadata.name.clone().unwrap_or_default();
+            evaluated += 1;
+            let replicas = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+            if replicas != 1 {
+                continue;
+            }
+            let hpa_managed = hpas.iter().any(|h| {
+                h.metadata.namespace.as_deref() == Some(ns.as_str())
+                    && h.spec.as_ref().map(|s| {
+                        s.scale_target_ref.kind == "Deployment" && s.scale_target_ref.name == name
+                    }) == Some(true)
+            });
+            if hpa_managed {
+                continue;
+            }
+            let previous = historic_max
+                .get(&(ns.clone(), name.clone()))
+                .copied()
+                .unwrap_or(0);
+            if previous > 1 {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: "Deployment".to_string(),
+                    description: format!(
+                        "Deployment {}/{} runs 1 replica but earlier revisions ran {}",
+                        ns, name, previous
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation:
+                        "Confirm the scale-down was intentional; restore the previous replica count to regain redundancy."
+                            .to_string(),
+                    rule_id: Some("DBG-003".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+
+        Ok(ratio_check(
+            "Replica Scale-down",
+            "Checks production Deployments patched down to 1 replica from a higher revision history",
+            evaluated,
+            flagged,
+            "production deployments at their historic replica count",
+            "Restore replica counts reduced during debugging",
+        ))
+    }
+
+    fn build_summary(&self, checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
+        let total_checks = checks.len() as u32;
+        let mut passed_checks = 0;
+        let mut warning_checks = 0;
+        let mut critical_checks = 0;
+        let mut error_checks = 0;
+
+        for check in checks {
+            match check.status {
+                CheckStatus::Pass => passed_checks += 1,
+                CheckStatus::Warning => warning_checks += 1,
+                CheckStatus::Critical => critical_checks += 1,
+                CheckStatus::Error => error_checks += 1,
+            }
+        }
+
+        InspectionSummary {
+            total_checks,
+            passed_checks,
+            warning_checks,
+            critical_checks,
+            error_checks,
+            issues,
+        }
+    }
+}
+
+fn ratio_check(
+    name: &str,
+    description: &str,
+    evaluated: usize,
+    flagged: usize,
+    detail_suffix: &str,
+    recommendation: &str,
+) -> CheckResult {
+    let score = if evaluated == 0 {
+        100.0
+    } else {
+        ((evaluated - flagged) as f64 / evaluated as f64) * 100.0
+    };
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if score >= 80.0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Critical
+        },
+        score,
+        max_score: 100.0,
+        details: Some(if evaluated == 0 {
+            "No production-tier namespaces matched".to_string()
+        } else {
+            format!("{}/{} {}", evaluated - flagged, evaluated, detail_suffix)
+        }),
+        recommendations: if flagged > 0 {
+            vec![recommendation.to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
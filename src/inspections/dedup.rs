@@ -0,0 +1,274 @@
+//! Merge findings that share a fingerprint (same issue code and key attributes on the same
+//! resource), e.g. the same object reported by two modules. The first finding is kept with the
+//! highest severity of the group and a note of how many were merged; the count before merging is
+//! kept in `metadata.issue_counts`. Findings on the same resource under different codes from
+//! different modules (a pod flagged by Security, Resource Usage and Pod Status) are kept apart
+//! and cross-linked: each names the codes the other modules reported for the resource.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::inspections::types::{InspectionResult, IssueCounts};
+
+/// Merge duplicates across all `inspections` and cross-link the rest; fingerprints must already
+/// be set.
+pub fn merge_duplicate_issues(inspections: &mut [InspectionResult]) -> IssueCounts {
+    let raw = inspections.iter().map(|i| i.summary.issues.len()).sum();
+    // fingerprint → (inspection, issue) of the kept finding, and how many were merged into it
+    let mut kept: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut merged_into: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut drop: HashSet<(usize, usize)> = HashSet::new();
+    for (i, inspection) in inspections.iter().enumerate() {
+        for (j, issue) in inspection.summary.issues.iter().enumerate() {
+            let fingerprint = issue.stable_fingerprint();
+            match kept.get(&fingerprint) {
+                Some(&first) => {
+                    *merged_into.entry(first).or_default() += 1;
+                    drop.insert((i, j));
+                }
+                None => {
+                    kept.insert(fingerprint, (i, j));
+                }
+            }
+        }
+    }
+    if drop.is_empty() {
+        cross_link(inspections);
+        return IssueCounts { raw, merged: 0 };
+    }
+    // Highest severity of each group, looked up before the duplicates are removed.
+    let mut severities = HashMap::new();
+    for &(i, j) in &drop {
+        let issue = &inspections[i].summary.issues[j];
+        let first = kept[&issue.stable_fingerprint()];
+        let max = severities.entry(first).or_insert_with(|| {
+            inspections[first.0].summary.issues[first.1]
+                .severity
+                .clone()
+        });
+        if issue.severity > *max {
+            *max = issue.severity.clone();
+        }
+    }
+    for ((i, j), count) in &merged_into {
+        let issue = &mut inspections[*i].summary.issues[*j];
+        if let Some(severity) = severities.remove(&(*i, *j)) {
+            issue.severity = severity;
+        }
+        issue.description = format!(
+            "{} (+{} identical finding(s) merged)",
+            issue.description, count
+        );
+    }
+    for (i, inspection) in inspections.iter_mut().enumerate() {
+        let mut j = 0;
+        inspection.summary.issues.retain(|_| {
+            let keep = !drop.contains(&(i, j));
+            j += 1;
+            keep
+        });
+    }
+    cross_link(inspections);
+    IssueCounts {
+        raw,
+        merged: drop.len(),
+    }
+}
+
+/// Note on every coded finding which codes other modules reported for the same resource.
+fn cross_link(inspections: &mut [InspectionResult]) {
+    // resource → (module, code) of every coded finding on it
+    let mut by_resource: HashMap<String, BTreeSet<(String, String)>> = HashMap::new();
+    for inspection in inspections.iter() {
+        for issue in &inspection.summary.issues {
+            if let (Some(resource), Some(code)) = (issue.resource.as_deref(), &issue.rule_id) {
+                by_resource
+                    .entry(resource.trim().to_string())
+                    .or_default()
+                    .insert((inspection.inspection_type.clone(), code.clone()));
+            }
+        }
+    }
+    for inspection in inspections.iter_mut() {
+        let module = &inspection.inspection_type;
+        for issue in &mut inspection.summary.issues {
+            let (Some(resource), Some(_)) = (issue.resource.as_deref(), &issue.rule_id) else {
+                continue;
+            };
+            let others: Vec<String> = by_resource[resource.trim()]
+                .iter()
+                .filter(|(m, _)| m != module)
+                .map(|(m, code)| format!("{} ({})", code, m))
+                .collect();
+            if !others.is_empty() {
+                issue.description = format!(
+                    "{} (also flagged on this resource: {})",
+                    issue.description,
+                    others.join(", ")
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::{InspectionSummary, Issue, IssueSeverity};
+
+    fn issue(code: &str, resource: &str, severity: IssueSeverity) -> Issue {
+        let mut issue = Issue::new(
+            severity,
+            "Container",
+            code,
+            format!("{} on {}", code, resource),
+            resource.to_string(),
+            "",
+        );
+        issue.fingerprint = Some(issue.compute_fingerprint());
+        issue
+    }
+
+    fn inspection(name: &str, issues: Vec<Issue>) -> InspectionResult {
+        InspectionResult {
+            inspection_type: name.to_string(),
+            timestamp: chrono::Utc::now(),
+            overall_score: 80.0,
+            checks: Vec::new(),
+            summary: InspectionSummary {
+                total_checks: 0,
+                passed_checks: 0,
+                warning_checks: 0,
+                critical_checks: 0,
+                error_checks: 0,
+                issues,
+            },
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        }
+    }
+
+    fn with_key(mut issue: Issue, attribute: &str) -> Issue {
+        issue.key_attributes = vec![attribute.to_string()];
+        issue.fingerprint = Some(issue.compute_fingerprint());
+        issue
+    }
+
+    #[test]
+    fn merges_same_code_on_same_resource() {
+        let mut inspections = vec![
+            inspection(
+                "Resource Usage",
+                vec![
+                    issue("RES-002", "shop/web", IssueSeverity::Warning),
+                    issue("RES-002", "shop/web", IssueSeverity::Warning),
+                    issue("RES-002", "shop/db", IssueSeverity::Warning),
+                ],
+            ),
+            inspection(
+                "Security Configuration",
+                vec![
+                    issue("SEC-004", "shop/web", IssueSeverity::Warning),
+                    issue("RES-002", "shop/web", IssueSeverity::Critical),
+                ],
+            ),
+        ];
+        let counts = merge_duplicate_issues(&mut inspections);
+        assert_eq!((counts.raw, counts.merged), (5, 2));
+        let kept = &inspections[0].summary.issues;
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].severity, IssueSeverity::Critical);
+        assert_eq!(
+            kept[0].description,
+            "RES-002 on shop/web (+2 identical finding(s) merged) (also flagged on this resource: SEC-004 (Security Configuration))"
+        );
+        assert_eq!(kept[1].description, "RES-002 on shop/db");
+        let codes: Vec<_> = inspections[1]
+            .summary
+            .issues
+            .iter()
+            .filter_map(|i| i.rule_id.as_deref())
+            .collect();
+        assert_eq!(codes, vec!["SEC-004"]);
+    }
+
+    #[test]
+    fn keeps_findings_with_different_key_attributes() {
+        let missing = |name: &str| {
+            with_key(
+                issue("ORPH-011", "shop/api", IssueSeverity::Warning),
+                &format!("configmap={}", name),
+            )
+        };
+        let mut inspections = vec![inspection(
+            "Orphan Resources",
+            vec![missing("flags"), missing("overrides"), missing("flags")],
+        )];
+        let counts = merge_duplicate_issues(&mut inspections);
+        assert_eq!((counts.raw, counts.merged), (3, 1));
+        let kept = &inspections[0].summary.issues;
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[1].key_attributes, ["configmap=overrides"]);
+        assert_eq!(kept[1].description, "ORPH-011 on shop/api");
+    }
+
+    #[test]
+    fn cross_links_findings_of_other_modules_on_the_same_resource() {
+        let mut inspections = vec![
+            inspection(
+                "Security Configuration",
+                vec![issue("SEC-006", "shop/web-1", IssueSeverity::Warning)],
+            ),
+            inspection(
+                "Resource Usage",
+                vec![
+                    with_key(
+                        issue("RES-002", "shop/web-1", IssueSeverity::Warning),
+                        "container=app",
+                    ),
+                    with_key(
+                        issue("RES-002", "shop/web-1", IssueSeverity::Warning),
+                        "container=sidecar",
+                    ),
+                ],
+            ),
+            inspection(
+                "Pod Status",
+                vec![issue("POD-003", "shop/web-2", IssueSeverity::Warning)],
+            ),
+        ];
+        let counts = merge_duplicate_issues(&mut inspections);
+        assert_eq!((counts.raw, counts.merged), (4, 0));
+        assert_eq!(
+            inspections[0].summary.issues[0].description,
+            "SEC-006 on shop/web-1 (also flagged on this resource: RES-002 (Resource Usage))"
+        );
+        assert!(inspections[1].summary.issues.iter().all(|i| i
+            .description
+            .ends_with("(also flagged on this resource: SEC-006 (Security Configuration))")));
+        assert_eq!(
+            inspections[2].summary.issues[0].description,
+            "POD-003 on shop/web-2"
+        );
+    }
+
+    #[test]
+    fn keeps_distinct_findings_without_a_resource() {
+        let noisy = |reason: &str| {
+            let mut issue = issue("EVT-001", "", IssueSeverity::Warning);
+            issue.resource = None;
+            issue.description = format!("120 {} events on Pod in 60m", reason);
+            issue.fingerprint = Some(issue.compute_fingerprint());
+            issue
+        };
+        let mut inspections = vec![inspection(
+            "Event Analytics",
+            vec![noisy("BackOff"), noisy("FailedMount"), noisy("BackOff")],
+        )];
+        let counts = merge_duplicate_issues(&mut inspections);
+        assert_eq!((counts.raw, counts.merged), (3, 1));
+        assert_eq!(inspections[0].summary.issues.len(), 2);
+    }
+}
@@ -0,0 +1,337 @@
+//! etcd object counts by kind, for the Control Plane inspection. Read from the API server's
+//! `apiserver_storage_objects` metric (every kind, custom resources included) and, when
+//! `/metrics` is not readable, counted for the common kinds with one `limit=1` metadata LIST
+//! each (`remainingItemCount`). Kinds with abnormally many objects (CTRL-015) and a large etcd
+//! database (CTRL-016) are flagged with a rough estimate of the storage they take.
+
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{ConfigMap, Event, Pod, Secret, Service};
+use kube::api::ListParams;
+use kube::Api;
+use serde::de::DeserializeOwned;
+
+use crate::inspections::types::*;
+use crate::k8s::K8sClient;
+
+/// Objects of one kind from which CTRL-015 is a Warning, and from which it is Critical.
+const OBJECTS_WARNING: u64 = 50_000;
+const OBJECTS_CRITICAL: u64 = 250_000;
+/// etcd database size from which CTRL-016 is a Warning (75% of the default 2 GiB quota), and
+/// from which it is Critical (75% of the 8 GiB recommended maximum quota).
+const DB_SIZE_WARNING: u64 = 3 * 512 * 1024 * 1024;
+const DB_SIZE_CRITICAL: u64 = 6 * 1024 * 1024 * 1024;
+/// Kinds listed in the report table, largest count first.
+const MAX_ROWS: usize = 15;
+/// Typical stored size per object of common kinds (bytes), for the storage estimate; other kinds
+/// count as [`DEFAULT_OBJECT_BYTES`]. Secrets include Helm release records.
+const TYPICAL_OBJECT_BYTES: &[(&str, u64)] = &[
+    ("events", 1024),
+    ("pods", 6 * 1024),
+    ("secrets", 8 * 1024),
+    ("configmaps", 4 * 1024),
+    ("replicasets", 5 * 1024),
+    ("deployments", 5 * 1024),
+    ("jobs", 3 * 1024),
+    ("services", 2 * 1024),
+];
+const DEFAULT_OBJECT_BYTES: u64 = 2 * 1024;
+
+fn typical_bytes(resource: &str) -> u64 {
+    let plural = resource.split('.').next().unwrap_or(resource);
+    TYPICAL_OBJECT_BYTES
+        .iter()
+        .find(|(r, _)| *r == plural)
+        .map_or(DEFAULT_OBJECT_BYTES, |(_, b)| *b)
+}
+
+fn label<'a>(labels: &'a str, key: &str) -> Option<&'a str> {
+    labels.split(',').find_map(|kv| {
+        let (k, v) = kv.split_once('=')?;
+        (k.trim() == key).then(|| v.trim().trim_matches('"'))
+    })
+}
+
+/// `apiserver_storage_objects` (`etcd_object_counts` before 1.23) per resource and the largest
+/// `apiserver_storage_size_bytes` / `apiserver_storage_db_total_size_in_bytes` sample.
+pub fn parse_storage_metrics(metrics: &str) -> EtcdObjectCounts {
+    let mut counts = EtcdObjectCounts {
+        source: "apiserver_storage_objects".to_string(),
+        db_size_bytes: None,
+        rows: Vec::new(),
+    };
+    for line in metrics.lines() {
+        let Some((name, rest)) = line.split_once('{') else {
+            continue;
+        };
+        let Some((labels, value)) = rest.rsplit_once('}') else {
+            continue;
+        };
+        let Some(value) = value
+            .split_whitespace()
+            .next()
+            .and_then(|v| v.parse::<f64>().ok())
+        else {
+            continue;
+        };
+        if value < 0.0 {
+            continue;
+        }
+        match name {
+            "apiserver_storage_objects" | "etcd_object_counts" => {
+                let Some(resource) = label(labels, "resource") else {
+                    continue;
+                };
+                if counts.rows.iter().any(|r| r.resource == resource) {
+                    continue;
+                }
+                counts.rows.push(object_row(resource, value as u64));
+            }
+            "apiserver_storage_size_bytes" | "apiserver_storage_db_total_size_in_bytes" => {
+                counts.db_size_bytes = counts.db_size_bytes.max(Some(value as u64));
+            }
+            _ => {}
+        }
+    }
+    sort_rows(&mut counts.rows);
+    counts
+}
+
+fn object_row(resource: &str, count: u64) -> ObjectCountRow {
+    ObjectCountRow {
+        resource: resource.to_string(),
+        count,
+        estimated_bytes: count * typical_bytes(resource),
+    }
+}
+
+fn sort_rows(rows: &mut [ObjectCountRow]) {
+    rows.sort_by(|a, b| b.count.cmp(&a.count).then(a.resource.cmp(&b.resource)));
+}
+
+/// Objects of `api`'s kind from a one-item metadata LIST; None when not listable.
+async fn count<K>(api: Api<K>) -> Option<u64>
+where
+    K: kube::Resource + Clone + DeserializeOwned + std::fmt::Debug,
+{
+    let list = api
+        .list_metadata(&ListParams::default().limit(1))
+        .await
+        .ok()?;
+    let remaining = list.metadata.remaining_item_count.unwrap_or(0).max(0) as u64;
+    Some(list.items.len() as u64 + remaining)
+}
+
+/// Object counts for the common kinds, for API servers whose `/metrics` is not readable.
+async fn count_by_listing(client: &K8sClient) -> EtcdObjectCounts {
+    let c = client.client().clone();
+    let mut rows = Vec::new();
+    let counted = [
+        ("events", count(Api::<Event>::all(c.clone())).await),
+        ("pods", count(Api::<Pod>::all(c.clone())).await),
+        ("secrets", count(Api::<Secret>::all(c.clone())).await),
+        ("configmaps", count(Api::<ConfigMap>::all(c.clone())).await),
+        (
+            "replicasets.apps",
+            count(Api::<ReplicaSet>::all(c.clone())).await,
+        ),
+        (
+            "deployments.apps",
+            count(Api::<Deployment>::all(c.clone())).await,
+        ),
+        ("jobs.batch", count(Api::<Job>::all(c.clone())).await),
+        ("services", count(Api::<Service>::all(c)).await),
+    ];
+    for (resource, n) in counted {
+        if let Some(n) = n {
+            rows.push(object_row(resource, n));
+        }
+    }
+    sort_rows(&mut rows);
+    EtcdObjectCounts {
+        source: "list".to_string(),
+        db_size_bytes: None,
+        rows,
+    }
+}
+
+/// Object counts from the API server metrics, else by listing; None when neither is readable.
+pub async fn collect(client: &K8sClient) -> Option<EtcdObjectCounts> {
+    let from_metrics = client
+        .apiserver_metrics()
+        .await
+        .ok()
+        .flatten()
+        .map(|m| parse_storage_metrics(&m))
+        .filter(|c| !c.rows.is_empty());
+    let counts = match from_metrics {
+        Some(counts) => counts,
+        None => count_by_listing(client).await,
+    };
+    (!counts.rows.is_empty()).then_some(counts)
+}
+
+fn mib(bytes: u64) -> String {
+    format!("{:.0} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn etcd_issue(severity: IssueSeverity, code: &str, resource: &str, description: String) -> Issue {
+    let recommendation = match (code, resource.split('.').next().unwrap_or(resource)) {
+        ("CTRL-016", _) => "Find the kinds holding the most objects (etcd objects by kind table), clean them up, then defragment etcd; raise --quota-backend-bytes (at most 8 GiB) only as a stopgap.",
+        (_, "events") => "Lower the API server --event-ttl (default 1h) and fix the controllers or pods emitting events in a loop.",
+        (_, "pods") => "Delete finished pods and set ttlSecondsAfterFinished on Jobs (see ORPH-008, ORPH-009).",
+        (_, "jobs") => "Set ttlSecondsAfterFinished on Jobs and history limits on CronJobs (see ORPH-005, ORPH-009).",
+        (_, "replicasets") => "Lower Deployment revisionHistoryLimit (see ORPH-010).",
+        (_, "secrets") => "Limit Helm release history (--history-max) and delete unused Secrets (see ORPH-002).",
+        _ => "Find the controller creating these objects and clean up or expire objects that are no longer needed.",
+    };
+    Issue::new(
+        severity,
+        "ControlPlane",
+        code,
+        description,
+        resource.to_string(),
+        recommendation,
+    )
+}
+
+/// CTRL-015 per kind over the thresholds and CTRL-016 for a large database.
+pub fn etcd_objects_check(
+    counts: Option<&EtcdObjectCounts>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let name = "etcd Object Counts";
+    let description = "Objects stored per kind and etcd database size";
+    let Some(counts) = counts else {
+        return CheckResult {
+            name: name.to_string(),
+            description: description.to_string(),
+            status: CheckStatus::Pass,
+            score: 100.0,
+            max_score: 100.0,
+            details: Some(
+                "API server metrics and object lists not readable; object counts not measured"
+                    .to_string(),
+            ),
+            recommendations: vec![],
+        };
+    };
+
+    let mut findings = Vec::new();
+    for row in counts.rows.iter().filter(|r| r.count >= OBJECTS_WARNING) {
+        findings.push(etcd_issue(
+            if row.count >= OBJECTS_CRITICAL {
+                IssueSeverity::Critical
+            } else {
+                IssueSeverity::Warning
+            },
+            "CTRL-015",
+            &row.resource,
+            format!(
+                "etcd holds {} {} objects (~{} estimated)",
+                row.count,
+                row.resource,
+                mib(row.estimated_bytes)
+            ),
+        ));
+    }
+    if let Some(size) = counts.db_size_bytes.filter(|s| *s >= DB_SIZE_WARNING) {
+        findings.push(etcd_issue(
+            if size >= DB_SIZE_CRITICAL {
+                IssueSeverity::Critical
+            } else {
+                IssueSeverity::Warning
+            },
+            "CTRL-016",
+            "etcd",
+            format!("etcd database size is {}", mib(size)),
+        ));
+    }
+
+    let total: u64 = counts.rows.iter().map(|r| r.count).sum();
+    let estimated: u64 = counts.rows.iter().map(|r| r.estimated_bytes).sum();
+    let mut details = format!(
+        "{} objects in {} kinds (~{} estimated)",
+        total,
+        counts.rows.len(),
+        mib(estimated)
+    );
+    if let Some(size) = counts.db_size_bytes {
+        details.push_str(&format!("; database {}", mib(size)));
+    }
+    let count =
+        |severity: IssueSeverity| findings.iter().filter(|i| i.severity == severity).count();
+    let (critical, warning) = (
+        count(IssueSeverity::Critical),
+        count(IssueSeverity::Warning),
+    );
+    let check = CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if critical > 0 {
+            CheckStatus::Critical
+        } else if warning > 0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Pass
+        },
+        score: (100.0 - 25.0 * critical as f64 - 10.0 * warning as f64).max(0.0),
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if findings.is_empty() {
+            vec![]
+        } else {
+            vec!["Clean up the kinds with the most objects (see CTRL-015, CTRL-016).".to_string()]
+        },
+    };
+    issues.extend(findings);
+    check
+}
+
+/// The largest kinds, for the report table.
+pub fn top_rows(counts: &EtcdObjectCounts) -> &[ObjectCountRow] {
+    &counts.rows[..counts.rows.len().min(MAX_ROWS)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_storage_metrics_and_flags_large_kinds() {
+        let metrics = "# HELP apiserver_storage_objects Number of stored objects\n\
+            apiserver_storage_objects{resource=\"events\"} 312000\n\
+            apiserver_storage_objects{resource=\"jobs.batch\"} 5.1e+04\n\
+            apiserver_storage_objects{resource=\"pods\"} 1200\n\
+            apiserver_storage_objects{resource=\"certificates.cert-manager.io\"} -1\n\
+            apiserver_storage_size_bytes{storage_cluster_id=\"etcd-0\"} 2.147483648e+09\n";
+        let counts = parse_storage_metrics(metrics);
+        let resources: Vec<&str> = counts.rows.iter().map(|r| r.resource.as_str()).collect();
+        assert_eq!(resources, vec!["events", "jobs.batch", "pods"]);
+        assert_eq!(counts.rows[0].estimated_bytes, 312000 * 1024);
+        assert_eq!(counts.db_size_bytes, Some(2 * 1024 * 1024 * 1024));
+
+        let mut issues = Vec::new();
+        let check = etcd_objects_check(Some(&counts), &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        let found: Vec<(&str, &str, IssueSeverity)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                    i.severity.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("CTRL-015", "events", IssueSeverity::Critical),
+                ("CTRL-015", "jobs.batch", IssueSeverity::Warning),
+                ("CTRL-016", "etcd", IssueSeverity::Warning),
+            ]
+        );
+        assert!(issues[1].recommendation.contains("ttlSecondsAfterFinished"));
+    }
+}
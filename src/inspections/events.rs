@@ -0,0 +1,407 @@
+//! Event analytics: Warning events seen in the collection window are aggregated by
+//! (reason, involved kind) with occurrence rates. Reasons above the noise threshold and
+//! sustained per-object patterns (e.g. a pod in FailedScheduling for half an hour) become issues.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::core::v1::Event;
+
+use crate::inspections::types::{
+    CheckResult, CheckStatus, EventReasonRow, InspectionResult, InspectionSummary, Issue,
+    IssueSeverity,
+};
+
+/// Default collection window (minutes); matches the API server's default event TTL of 1h.
+pub const DEFAULT_EVENT_WINDOW_MINUTES: u32 = 60;
+/// Default rate (events per hour for one reason and kind) above which a reason is noisy.
+pub const DEFAULT_NOISY_EVENTS_PER_HOUR: u32 = 100;
+/// A single object repeating the same reason at least this often ...
+const SUSTAINED_MIN_COUNT: u32 = 10;
+/// ... over at least this many minutes is a sustained pattern.
+const SUSTAINED_MIN_MINUTES: i64 = 15;
+
+/// A Warning event reduced to the fields analytics needs. `count` is the number of
+/// occurrences the API server folded into this object between `first_seen` and `last_seen`.
+#[derive(Debug, Clone)]
+pub struct WarningEvent {
+    pub namespace: String,
+    pub kind: String,
+    pub name: String,
+    pub reason: String,
+    pub message: String,
+    pub count: u32,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+/// When an event was last observed: the series' last observation, `lastTimestamp`, `eventTime`
+/// (events.k8s.io writers leave `lastTimestamp` empty), else its creation time.
+pub fn event_last_seen(ev: &Event) -> Option<DateTime<Utc>> {
+    ev.series
+        .as_ref()
+        .and_then(|s| s.last_observed_time.as_ref().map(|t| t.0))
+        .or_else(|| ev.last_timestamp.as_ref().map(|t| t.0))
+        .or_else(|| ev.event_time.as_ref().map(|t| t.0))
+        .or_else(|| ev.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+impl WarningEvent {
+    /// None for non-Warning events and events without any timestamp.
+    pub fn from_event(ev: &Event) -> Option<Self> {
+        if ev.type_.as_deref() != Some("Warning") {
+            return None;
+        }
+        let series = ev.series.as_ref();
+        let last_seen = event_last_seen(ev)?;
+        let first_seen = ev
+            .first_timestamp
+            .as_ref()
+            .map(|t| t.0)
+            .or_else(|| ev.event_time.as_ref().map(|t| t.0))
+            .unwrap_or(last_seen)
+            .min(last_seen);
+        let count = series
+            .and_then(|s| s.count)
+            .or(ev.count)
+            .unwrap_or(1)
+            .max(1) as u32;
+        let obj = &ev.involved_object;
+        Some(Self {
+            namespace: ev.metadata.namespace.clone().unwrap_or_default(),
+            kind: obj.kind.clone().unwrap_or_else(|| "Unknown".to_string()),
+            name: obj.name.clone().unwrap_or_default(),
+            reason: ev.reason.clone().unwrap_or_else(|| "Unknown".to_string()),
+            message: ev.message.clone().unwrap_or_default(),
+            count,
+            first_seen,
+            last_seen,
+        })
+    }
+
+    fn object_ref(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.namespace, self.name)
+        }
+    }
+
+    /// Occurrences inside the window, assuming they were spread evenly between first and last seen.
+    fn occurrences_since(&self, window_start: DateTime<Utc>) -> u64 {
+        if self.last_seen < window_start {
+            return 0;
+        }
+        if self.first_seen >= window_start || self.count <= 1 {
+            return self.count as u64;
+        }
+        let span = (self.last_seen - self.first_seen).num_seconds().max(1) as f64;
+        let inside = (self.last_seen - window_start).num_seconds() as f64;
+        ((self.count as f64 * inside / span).round() as u64).max(1)
+    }
+}
+
+#[derive(Default)]
+struct ReasonAcc {
+    occurrences: u64,
+    objects: BTreeSet<String>,
+    namespaces: BTreeSet<String>,
+}
+
+/// Aggregate Warning events by (reason, involved kind) over the last `window_minutes`,
+/// most frequent first.
+pub fn aggregate_warning_events(
+    events: &[WarningEvent],
+    now: DateTime<Utc>,
+    window_minutes: u32,
+    noisy_per_hour: u32,
+) -> Vec<EventReasonRow> {
+    let window_start = now - Duration::minutes(window_minutes as i64);
+    let mut groups: BTreeMap<(&str, &str), ReasonAcc> = BTreeMap::new();
+    for ev in events {
+        let occurrences = ev.occurrences_since(window_start);
+        if occurrences == 0 {
+            continue;
+        }
+        let acc = groups
+            .entry((ev.reason.as_str(), ev.kind.as_str()))
+            .or_default();
+        acc.occurrences += occurrences;
+        acc.objects.insert(ev.object_ref());
+        if !ev.namespace.is_empty() {
+            acc.namespaces.insert(ev.namespace.clone());
+        }
+    }
+    let hours = window_minutes.max(1) as f64 / 60.0;
+    let mut rows: Vec<EventReasonRow> = groups
+        .into_iter()
+        .map(|((reason, kind), acc)| {
+            let rate_per_hour = acc.occurrences as f64 / hours;
+            EventReasonRow {
+                reason: reason.to_string(),
+                kind: kind.to_string(),
+                occurrences: acc.occurrences,
+                objects: acc.objects.len() as u32,
+                namespaces: acc.namespaces.len() as u32,
+                rate_per_hour,
+                noisy: rate_per_hour >= noisy_per_hour as f64,
+            }
+        })
+        .collect();
+    rows.sort_by_key(|r| std::cmp::Reverse(r.occurrences));
+    rows
+}
+
+/// Report category for issues about an involved object of `kind`; kinds without their own
+/// report section are grouped under Event.
+fn issue_category(kind: &str) -> String {
+    match kind {
+        "Pod"
+        | "Node"
+        | "Service"
+        | "Deployment"
+        | "StatefulSet"
+        | "DaemonSet"
+        | "Namespace"
+        | "PersistentVolume"
+        | "PersistentVolumeClaim"
+        | "StorageClass"
+        | "ConfigMap"
+        | "Secret"
+        | "CronJob"
+        | "Job" => kind.to_string(),
+        "ReplicaSet" => "Deployment".to_string(),
+        "HorizontalPodAutoscaler" => "Autoscaling".to_string(),
+        _ => "Event".to_string(),
+    }
+}
+
+/// Issue code and severity for a sustained pattern of `reason`.
+fn sustained_rule(reason: &str) -> (&'static str, IssueSeverity, &'static str) {
+    match reason {
+        "FailedScheduling" => (
+            "EVT-002",
+            IssueSeverity::Critical,
+            "Check resource requests, node selectors/affinity, taints and quota; kubectl describe pod shows the scheduler's reasons.",
+        ),
+        "FailedMount" | "FailedAttachVolume" | "FailedMapVolume" => (
+            "EVT-003",
+            IssueSeverity::Critical,
+            "Check the PVC/Secret/ConfigMap being mounted, the CSI driver, and volume attachment limits on the node.",
+        ),
+        "BackOff" | "Unhealthy" | "ProbeWarning" => (
+            "EVT-004",
+            IssueSeverity::Warning,
+            "Check container logs and probe configuration; the container keeps restarting or failing probes.",
+        ),
+        _ => (
+            "EVT-005",
+            IssueSeverity::Warning,
+            "Investigate the repeated event with kubectl describe on the object.",
+        ),
+    }
+}
+
+/// "Event Patterns" check: EVT-001 for noisy (reason, kind) groups and EVT-002..005 for
+/// objects repeating the same Warning reason for a sustained period within the window.
+pub fn evaluate_event_patterns(
+    events: &[WarningEvent],
+    rows: &[EventReasonRow],
+    now: DateTime<Utc>,
+    window_minutes: u32,
+) -> (CheckResult, Vec<Issue>) {
+    let mut issues = Vec::new();
+    for row in rows.iter().filter(|r| r.noisy) {
+        issues.push(Issue {
+            severity: IssueSeverity::Warning,
+            category: issue_category(&row.kind),
+            description: format!(
+                "{} {} events on {} in {}m ({:.0}/h) across {} object(s)",
+                row.occurrences,
+                row.reason,
+                row.kind,
+                window_minutes,
+                row.rate_per_hour,
+                row.objects
+            ),
+            resource: Some(format!("{}/{}", row.kind, row.reason)),
+            recommendation: format!(
+                "Find the source of the repeated {} events (kubectl get events -A --field-selector reason={}) and fix the underlying cause.",
+                row.reason, row.reason
+            ),
+            rule_id: Some("EVT-001".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+
+    let window_start = now - Duration::minutes(window_minutes as i64);
+    let mut seen: BTreeSet<(String, String)> = BTreeSet::new();
+    for ev in events {
+        let sustained = ev.last_seen >= window_start
+            && ev.count >= SUSTAINED_MIN_COUNT
+            && ev.last_seen - ev.first_seen >= Duration::minutes(SUSTAINED_MIN_MINUTES);
+        if !sustained || !seen.insert((ev.object_ref(), ev.reason.clone())) {
+            continue;
+        }
+        let (rule_id, severity, recommendation) = sustained_rule(&ev.reason);
+        issues.push(Issue {
+            severity,
+            category: issue_category(&ev.kind),
+            description: format!(
+                "{} {} repeated {} {} times over {}m: {}",
+                ev.kind,
+                ev.object_ref(),
+                ev.reason,
+                ev.count,
+                (ev.last_seen - ev.first_seen).num_minutes(),
+                ev.message
+            ),
+            resource: Some(ev.object_ref()),
+            recommendation: recommendation.to_string(),
+            rule_id: Some(rule_id.to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if !issues.is_empty() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let total: u64 = rows.iter().map(|r| r.occurrences).sum();
+    let check = CheckResult {
+        name: "Event Patterns".to_string(),
+        description: "Aggregates Warning events by reason and flags noisy or sustained patterns"
+            .to_string(),
+        status,
+        score: (100.0 - issues.len() as f64 * 10.0).max(0.0),
+        max_score: 100.0,
+        details: Some(format!(
+            "{} Warning events in {} reason(s) over the last {}m; {} noisy reason(s), {} sustained pattern(s)",
+            total,
+            rows.len(),
+            window_minutes,
+            rows.iter().filter(|r| r.noisy).count(),
+            issues
+                .iter()
+                .filter(|i| i.rule_id.as_deref() != Some("EVT-001"))
+                .count()
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["Resolve the most frequent Warning event reasons first".to_string()]
+        },
+    };
+    (check, issues)
+}
+
+/// "Event Analytics" inspection result holding the Event Patterns check.
+pub fn event_inspection(check: CheckResult, issues: Vec<Issue>) -> InspectionResult {
+    let (passed, warning, critical, error) = match check.status {
+        CheckStatus::Pass => (1, 0, 0, 0),
+        CheckStatus::Warning => (0, 1, 0, 0),
+        CheckStatus::Critical => (0, 0, 1, 0),
+        CheckStatus::Error => (0, 0, 0, 1),
+    };
+    InspectionResult {
+        inspection_type: "Event Analytics".to_string(),
+        timestamp: Utc::now(),
+        overall_score: check.score,
+        checks: vec![check],
+        summary: InspectionSummary {
+            total_checks: 1,
+            passed_checks: passed,
+            warning_checks: warning,
+            critical_checks: critical,
+            error_checks: error,
+            issues,
+        },
+        certificate_expiries: None,
+        pod_container_states: None,
+        namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ev(
+        name: &str,
+        reason: &str,
+        count: u32,
+        first_min_ago: i64,
+        now: DateTime<Utc>,
+    ) -> WarningEvent {
+        WarningEvent {
+            namespace: "default".to_string(),
+            kind: "Pod".to_string(),
+            name: name.to_string(),
+            reason: reason.to_string(),
+            message: String::new(),
+            count,
+            first_seen: now - Duration::minutes(first_min_ago),
+            last_seen: now - Duration::minutes(1),
+        }
+    }
+
+    #[test]
+    fn aggregates_by_reason_and_prorates_old_series() {
+        let now = Utc::now();
+        let events = vec![
+            ev("a", "FailedMount", 120, 30, now),
+            ev("b", "FailedMount", 120, 30, now),
+            // 200 occurrences over 119m, ~half inside the 60m window.
+            ev("c", "BackOff", 200, 120, now),
+        ];
+        let rows = aggregate_warning_events(&events, now, 60, 200);
+        assert_eq!(rows[0].reason, "FailedMount");
+        assert_eq!(rows[0].occurrences, 240);
+        assert_eq!(rows[0].objects, 2);
+        assert!(rows[0].noisy);
+        let backoff = rows.iter().find(|r| r.reason == "BackOff").unwrap();
+        assert!((95..=105).contains(&backoff.occurrences));
+        assert!(!backoff.noisy);
+    }
+
+    #[test]
+    fn promotes_sustained_patterns_to_issues() {
+        let now = Utc::now();
+        let events = vec![
+            ev("pending", "FailedScheduling", 30, 40, now),
+            ev("blip", "FailedScheduling", 30, 5, now),
+            ev("crash", "BackOff", 12, 20, now),
+        ];
+        let rows = aggregate_warning_events(&events, now, 60, 1000);
+        let (check, issues) = evaluate_event_patterns(&events, &rows, now, 60);
+        let ids: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(ids, vec!["EVT-002", "EVT-004"]);
+        assert_eq!(check.status, CheckStatus::Critical);
+    }
+
+    #[test]
+    fn last_seen_falls_back_to_event_time() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "e", "creationTimestamp": "2026-01-01T00:00:00Z"},
+            "involvedObject": {},
+            "type": "Warning",
+            "eventTime": "2026-01-01T09:30:00.000000Z"
+        }))
+        .unwrap();
+        assert_eq!(
+            event_last_seen(&event).unwrap().to_rfc3339(),
+            "2026-01-01T09:30:00+00:00"
+        );
+    }
+}
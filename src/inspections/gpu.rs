@@ -0,0 +1,524 @@
+//! GPUs and other extended resources (`nvidia.com/gpu`, `amd.com/gpu`, `aws.amazon.com/neuron`,
+//! ...): nodes advertising accelerators that no pod uses (GPU-001), pods requesting extended
+//! resources stuck Pending (GPU-002) and device-plugin DaemonSets or nodes whose advertised
+//! devices are not allocatable (GPU-003). Allocatable vs requested per node is listed in the
+//! Accelerator Allocation check. The module is optional: in a full run it is only reported when
+//! a node advertises or a pod requests an extended resource.
+
+use anyhow::Result;
+use chrono::Utc;
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::api::ListParams;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::inspections::types::*;
+use crate::k8s::K8sClient;
+
+/// Inspection type of this module.
+pub const MODULE: &str = "GPU & Extended Resources";
+/// Name of the only check of a run where no extended resource was found.
+const NO_EXTENDED_RESOURCES_CHECK: &str = "Extended Resources";
+/// Substrings of extended resource names that are accelerators (and cost money when idle).
+const ACCELERATOR_KEYWORDS: &[&str] = &["gpu", "neuron", "tpu", "habana.ai/", "xilinx.com/"];
+
+/// Domain-prefixed resource names outside `kubernetes.io` (CPU, memory, hugepages and
+/// ephemeral-storage have no domain).
+fn is_extended(name: &str) -> bool {
+    name.contains('/') && !name.starts_with("kubernetes.io/") && !name.contains(".kubernetes.io/")
+}
+
+fn is_accelerator(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ACCELERATOR_KEYWORDS.iter().any(|k| name.contains(k))
+}
+
+fn count(quantity: &k8s_openapi::apimachinery::pkg::api::resource::Quantity) -> i64 {
+    quantity.0.trim().parse().unwrap_or(0)
+}
+
+/// Extended resources a node advertises: name → (capacity, allocatable).
+fn node_extended(node: &Node) -> BTreeMap<&str, (i64, i64)> {
+    let mut out: BTreeMap<&str, (i64, i64)> = BTreeMap::new();
+    let Some(status) = node.status.as_ref() else {
+        return out;
+    };
+    for (name, q) in status.capacity.iter().flatten() {
+        if is_extended(name) {
+            out.entry(name).or_default().0 = count(q);
+        }
+    }
+    for (name, q) in status.allocatable.iter().flatten() {
+        if is_extended(name) {
+            out.entry(name).or_default().1 = count(q);
+        }
+    }
+    out.retain(|_, (capacity, allocatable)| *capacity > 0 || *allocatable > 0);
+    out
+}
+
+/// Extended resources a pod requests (limits count as requests; they must be equal).
+fn pod_extended(pod: &Pod) -> BTreeMap<&str, i64> {
+    let mut out = BTreeMap::new();
+    for container in pod.spec.iter().flat_map(|s| s.containers.iter()) {
+        let Some(resources) = container.resources.as_ref() else {
+            continue;
+        };
+        let mut per_container: BTreeMap<&str, i64> = BTreeMap::new();
+        for (name, q) in resources
+            .limits
+            .iter()
+            .flatten()
+            .chain(resources.requests.iter().flatten())
+        {
+            if is_extended(name) {
+                let v = per_container.entry(name).or_default();
+                *v = (*v).max(count(q));
+            }
+        }
+        for (name, v) in per_container {
+            *out.entry(name).or_default() += v;
+        }
+    }
+    out.retain(|_, v| *v > 0);
+    out
+}
+
+fn is_finished(pod: &Pod) -> bool {
+    matches!(
+        pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+        Some("Succeeded" | "Failed")
+    )
+}
+
+fn pod_key(pod: &Pod) -> String {
+    format!(
+        "{}/{}",
+        pod.metadata.namespace.as_deref().unwrap_or_default(),
+        pod.metadata.name.as_deref().unwrap_or_default()
+    )
+}
+
+/// Score from findings: 25 points off per critical, 10 per warning.
+fn findings_check(
+    name: &str,
+    description: &str,
+    details: String,
+    findings: &[Issue],
+    recommendation: &str,
+) -> CheckResult {
+    let count =
+        |severity: IssueSeverity| findings.iter().filter(|i| i.severity == severity).count();
+    let (critical, warning) = (
+        count(IssueSeverity::Critical),
+        count(IssueSeverity::Warning),
+    );
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if critical > 0 {
+            CheckStatus::Critical
+        } else if warning > 0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Pass
+        },
+        score: (100.0 - 25.0 * critical as f64 - 10.0 * warning as f64).max(0.0),
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if findings.is_empty() {
+            vec![]
+        } else {
+            vec![recommendation.to_string()]
+        },
+    }
+}
+
+/// Allocatable vs requested extended resources per node, and GPU-001 for accelerator nodes
+/// with nothing scheduled that uses them.
+fn allocation_check(nodes: &[Node], pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+    // (node, resource) → requested by non-finished pods bound to the node
+    let mut requested: BTreeMap<(&str, &str), i64> = BTreeMap::new();
+    for pod in pods.iter().filter(|p| !is_finished(p)) {
+        let Some(node) = pod.spec.as_ref().and_then(|s| s.node_name.as_deref()) else {
+            continue;
+        };
+        for (name, v) in pod_extended(pod) {
+            *requested.entry((node, name)).or_default() += v;
+        }
+    }
+    let mut lines = Vec::new();
+    let mut findings = Vec::new();
+    for node in nodes {
+        let node_name = node.metadata.name.as_deref().unwrap_or("unknown");
+        for (name, (_, allocatable)) in node_extended(node) {
+            let used = requested.get(&(node_name, name)).copied().unwrap_or(0);
+            lines.push(format!("{}: {} {}/{}", node_name, name, used, allocatable));
+            if used == 0 && allocatable > 0 && is_accelerator(name) {
+                findings.push(Issue::new(
+                    IssueSeverity::Warning,
+                    "GPU",
+                    "GPU-001",
+                    format!(
+                        "Node {} advertises {} {} but no pod requests it",
+                        node_name, allocatable, name
+                    ),
+                    node_name.to_string(),
+                    "Scale the accelerator node pool down (to zero when idle) or schedule the GPU workloads onto it.",
+                ));
+            }
+        }
+    }
+    let check = findings_check(
+        "Accelerator Allocation",
+        "Extended resources requested vs allocatable per node; idle accelerator nodes",
+        if lines.is_empty() {
+            "No node advertises extended resources".to_string()
+        } else {
+            format!("requested/allocatable: {}", lines.join("; "))
+        },
+        &findings,
+        "Remove or scale down idle accelerator nodes (see GPU-001).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// GPU-002: Pending pods requesting extended resources, with the scheduler's reason.
+fn pending_check(pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut findings = Vec::new();
+    let mut requesting = 0;
+    for pod in pods.iter().filter(|p| !is_finished(p)) {
+        let wanted = pod_extended(pod);
+        if wanted.is_empty() {
+            continue;
+        }
+        requesting += 1;
+        let status = pod.status.as_ref();
+        if status.and_then(|s| s.phase.as_deref()) != Some("Pending") {
+            continue;
+        }
+        let reason = status
+            .and_then(|s| s.conditions.as_ref())
+            .and_then(|c| {
+                c.iter()
+                    .find(|c| c.type_ == "PodScheduled" && c.status == "False")
+            })
+            .and_then(|c| c.message.clone().or_else(|| c.reason.clone()));
+        let wanted: Vec<String> = wanted
+            .iter()
+            .map(|(name, v)| format!("{} {}", v, name))
+            .collect();
+        findings.push(Issue::new(
+            IssueSeverity::Warning,
+            "GPU",
+            "GPU-002",
+            format!(
+                "Pod {} requesting {} is Pending{}",
+                pod_key(pod),
+                wanted.join(", "),
+                reason.map(|r| format!(": {}", r)).unwrap_or_default()
+            ),
+            pod_key(pod),
+            "Check free accelerator capacity, node selectors/tolerations for the GPU nodes and the device plugin on those nodes.",
+        ));
+    }
+    let check = findings_check(
+        "Pending Accelerator Pods",
+        "Pods requesting extended resources are scheduled",
+        format!(
+            "{}/{} pods requesting extended resources are Pending",
+            findings.len(),
+            requesting
+        ),
+        &findings,
+        "Add accelerator capacity or fix scheduling constraints (see GPU-002).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// GPU-003: device-plugin DaemonSets with unready pods, and nodes whose advertised devices have
+/// no allocatable units (the plugin reports them unhealthy).
+fn device_plugin_check(
+    nodes: &[Node],
+    daemon_sets: &[DaemonSet],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut findings = Vec::new();
+    let plugins: Vec<&DaemonSet> = daemon_sets
+        .iter()
+        .filter(|ds| {
+            ds.metadata
+                .name
+                .as_deref()
+                .is_some_and(|n| n.contains("device-plugin"))
+        })
+        .collect();
+    for ds in &plugins {
+        let Some(status) = ds.status.as_ref() else {
+            continue;
+        };
+        if status.number_ready < status.desired_number_scheduled {
+            let key = format!(
+                "{}/{}",
+                ds.metadata.namespace.as_deref().unwrap_or_default(),
+                ds.metadata.name.as_deref().unwrap_or_default()
+            );
+            findings.push(Issue::new(
+                IssueSeverity::Critical,
+                "GPU",
+                "GPU-003",
+                format!(
+                    "Device plugin DaemonSet {} has {}/{} pods ready",
+                    key, status.number_ready, status.desired_number_scheduled
+                ),
+                key.clone(),
+                "Check the device plugin pod logs on the affected nodes (driver version, container toolkit).",
+            ));
+        }
+    }
+    for node in nodes {
+        let node_name = node.metadata.name.as_deref().unwrap_or("unknown");
+        for (name, (capacity, allocatable)) in node_extended(node) {
+            if capacity > 0 && allocatable == 0 {
+                findings.push(Issue {
+                    key_attributes: vec![format!("resource={}", name)],
+                    ..Issue::new(
+                        IssueSeverity::Critical,
+                        "GPU",
+                        "GPU-003",
+                        format!(
+                            "Node {} has {} {} but none allocatable (device plugin reports them unhealthy)",
+                            node_name, capacity, name
+                        ),
+                        node_name.to_string(),
+                        "Check the device plugin and driver on the node; restart the plugin pod after fixing the driver.",
+                    )
+                });
+            }
+        }
+    }
+    let names: BTreeSet<&str> = plugins
+        .iter()
+        .filter_map(|ds| ds.metadata.name.as_deref())
+        .collect();
+    let check = findings_check(
+        "Device Plugin Health",
+        "Device-plugin DaemonSets are ready and advertised devices are allocatable",
+        if names.is_empty() {
+            "No device-plugin DaemonSet found".to_string()
+        } else {
+            format!(
+                "{} device-plugin DaemonSet(s): {}",
+                names.len(),
+                names.into_iter().collect::<Vec<_>>().join(", ")
+            )
+        },
+        &findings,
+        "Restore the device plugins (see GPU-003).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// Whether a result of this module found any extended resource; a full run leaves the module
+/// out otherwise.
+pub fn found_extended_resources(result: &InspectionResult) -> bool {
+    result
+        .checks
+        .iter()
+        .any(|c| c.name != NO_EXTENDED_RESOURCES_CHECK)
+}
+
+pub struct GpuInspector<'a> {
+    client: &'a K8sClient,
+}
+
+impl<'a> GpuInspector<'a> {
+    pub fn new(client: &'a K8sClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        let lp = ListParams::default();
+        let nodes = self.client.list_all(&self.client.nodes(), &lp).await?.items;
+        let pods = self
+            .client
+            .list_all(&self.client.pods(namespace), &lp)
+            .await?
+            .items;
+
+        let mut issues = Vec::new();
+        let applicable = nodes.iter().any(|n| !node_extended(n).is_empty())
+            || pods.iter().any(|p| !pod_extended(p).is_empty());
+        let checks = if applicable {
+            // Device plugins usually run in their own namespace (gpu-operator, kube-system).
+            let daemon_sets = self
+                .client
+                .list_unselected(&self.client.daemon_sets(None), &lp)
+                .await?
+                .items;
+            vec![
+                allocation_check(&nodes, &pods, &mut issues),
+                pending_check(&pods, &mut issues),
+                device_plugin_check(&nodes, &daemon_sets, &mut issues),
+            ]
+        } else {
+            vec![CheckResult {
+                name: NO_EXTENDED_RESOURCES_CHECK.to_string(),
+                description: "Nodes advertising or pods requesting extended resources".to_string(),
+                status: CheckStatus::Pass,
+                score: 100.0,
+                max_score: 100.0,
+                details: Some(
+                    "No node advertises and no pod requests an extended resource".to_string(),
+                ),
+                recommendations: vec![],
+            }]
+        };
+
+        let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
+        let count =
+            |status: CheckStatus| checks.iter().filter(|c| c.status == status).count() as u32;
+        let summary = InspectionSummary {
+            total_checks: checks.len() as u32,
+            passed_checks: count(CheckStatus::Pass),
+            warning_checks: count(CheckStatus::Warning),
+            critical_checks: count(CheckStatus::Critical),
+            error_checks: count(CheckStatus::Error),
+            issues,
+        };
+
+        Ok(InspectionResult {
+            inspection_type: MODULE.to_string(),
+            timestamp: Utc::now(),
+            overall_score,
+            checks,
+            summary,
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::DaemonSetStatus;
+    use k8s_openapi::api::core::v1::{
+        Container, NodeStatus, PodCondition, PodSpec, PodStatus, ResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn gpu_node(name: &str, capacity: &str, allocatable: &str) -> Node {
+        let mut node = Node::default();
+        node.metadata.name = Some(name.to_string());
+        node.status = Some(NodeStatus {
+            capacity: Some(
+                [
+                    ("cpu".to_string(), Quantity("8".to_string())),
+                    ("nvidia.com/gpu".to_string(), Quantity(capacity.to_string())),
+                ]
+                .into(),
+            ),
+            allocatable: Some(
+                [(
+                    "nvidia.com/gpu".to_string(),
+                    Quantity(allocatable.to_string()),
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        });
+        node
+    }
+
+    fn gpu_pod(name: &str, node: Option<&str>, phase: &str, gpus: &str) -> Pod {
+        let mut pod = Pod::default();
+        pod.metadata.namespace = Some("ml".to_string());
+        pod.metadata.name = Some(name.to_string());
+        pod.spec = Some(PodSpec {
+            node_name: node.map(str::to_string),
+            containers: vec![Container {
+                name: "train".to_string(),
+                resources: Some(ResourceRequirements {
+                    limits: Some(
+                        [("nvidia.com/gpu".to_string(), Quantity(gpus.to_string()))].into(),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        pod.status = Some(PodStatus {
+            phase: Some(phase.to_string()),
+            conditions: (phase == "Pending").then(|| {
+                vec![PodCondition {
+                    type_: "PodScheduled".to_string(),
+                    status: "False".to_string(),
+                    message: Some(
+                        "0/3 nodes are available: 3 Insufficient nvidia.com/gpu.".to_string(),
+                    ),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        });
+        pod
+    }
+
+    #[test]
+    fn idle_nodes_pending_pods_and_unhealthy_plugins() {
+        let nodes = [
+            gpu_node("gpu-a", "4", "4"),
+            gpu_node("gpu-b", "8", "8"),
+            gpu_node("gpu-c", "4", "0"),
+        ];
+        let pods = [
+            gpu_pod("train-1", Some("gpu-a"), "Running", "3"),
+            gpu_pod("train-2", None, "Pending", "8"),
+        ];
+        let mut issues = Vec::new();
+        let check = allocation_check(&nodes, &pods, &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("gpu-b"));
+        assert!(check.details.unwrap().contains("gpu-a: nvidia.com/gpu 3/4"));
+
+        let mut issues = Vec::new();
+        let check = pending_check(&pods, &mut issues);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("1/2 pods requesting extended resources are Pending")
+        );
+        assert!(issues[0]
+            .description
+            .ends_with("3 Insufficient nvidia.com/gpu."));
+
+        let mut plugin = DaemonSet::default();
+        plugin.metadata.namespace = Some("gpu-operator".to_string());
+        plugin.metadata.name = Some("nvidia-device-plugin-daemonset".to_string());
+        plugin.status = Some(DaemonSetStatus {
+            desired_number_scheduled: 3,
+            number_ready: 2,
+            ..Default::default()
+        });
+        let mut issues = Vec::new();
+        let check = device_plugin_check(&nodes, &[plugin], &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        let resources: Vec<_> = issues
+            .iter()
+            .filter_map(|i| i.resource.as_deref())
+            .collect();
+        assert_eq!(
+            resources,
+            vec!["gpu-operator/nvidia-device-plugin-daemonset", "gpu-c"]
+        );
+        assert!(!is_extended("hugepages-2Mi"));
+        assert!(!is_extended("attachable-volumes-aws-ebs"));
+    }
+}
@@ -0,0 +1,343 @@
+//! HPA target and metric validation. An HPA whose scaleTargetRef does not exist, whose
+//! utilization metrics refer to containers without requests, or whose metrics come from an API
+//! nobody serves never scales; its conditions say so, but only when someone reads them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use k8s_openapi::api::autoscaling::v2::{HorizontalPodAutoscaler, MetricSpec};
+use k8s_openapi::api::core::v1::PodSpec;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Resource metrics (Resource, ContainerResource), from metrics-server.
+pub const RESOURCE_METRICS_API: &str = "metrics.k8s.io";
+/// Pods and Object metrics, from a custom metrics adapter (prometheus-adapter, KEDA, ...).
+pub const CUSTOM_METRICS_API: &str = "custom.metrics.k8s.io";
+/// External metrics, from an external metrics adapter (KEDA, cloud adapters).
+pub const EXTERNAL_METRICS_API: &str = "external.metrics.k8s.io";
+
+/// Kinds whose existence is checked (AUTO-009); other scale targets (e.g. Argo Rollouts) are not
+/// listed and are skipped.
+pub const CHECKED_KINDS: [&str; 3] = ["Deployment", "StatefulSet", "ReplicaSet"];
+
+/// A workload an HPA can target, with its pod template.
+pub struct ScaleTarget<'a> {
+    pub kind: &'a str,
+    pub meta: &'a ObjectMeta,
+    pub template: Option<&'a PodSpec>,
+}
+
+/// Metrics API group serving `metric`, if it is of a known type.
+fn metric_api(metric: &MetricSpec) -> Option<&'static str> {
+    match metric.type_.as_str() {
+        "Resource" | "ContainerResource" => Some(RESOURCE_METRICS_API),
+        "Pods" | "Object" => Some(CUSTOM_METRICS_API),
+        "External" => Some(EXTERNAL_METRICS_API),
+        _ => None,
+    }
+}
+
+fn metrics(hpa: &HorizontalPodAutoscaler) -> &[MetricSpec] {
+    hpa.spec
+        .as_ref()
+        .and_then(|s| s.metrics.as_deref())
+        .unwrap_or(&[])
+}
+
+/// Metrics API groups the HPAs use, to be probed before [`evaluate_hpa_targets`].
+pub fn metric_apis(hpas: &[HorizontalPodAutoscaler]) -> BTreeSet<&'static str> {
+    hpas.iter()
+        .flat_map(|h| metrics(h).iter().filter_map(metric_api))
+        .collect()
+}
+
+/// Containers of `spec` without a request for `resource` that a utilization `metric` needs:
+/// all containers for Resource metrics, the named one for ContainerResource metrics.
+fn containers_without_request(metric: &MetricSpec, spec: &PodSpec) -> (String, Vec<String>) {
+    let (resource, container, target) = match (&metric.resource, &metric.container_resource) {
+        (Some(r), _) => (r.name.as_str(), None, &r.target),
+        (None, Some(c)) => (c.name.as_str(), Some(c.container.as_str()), &c.target),
+        (None, None) => return (String::new(), Vec::new()),
+    };
+    if target.type_ != "Utilization" && target.average_utilization.is_none() {
+        return (resource.to_string(), Vec::new());
+    }
+    let missing = spec
+        .containers
+        .iter()
+        .filter(|c| container.is_none_or(|name| c.name == name))
+        .filter(|c| {
+            c.resources
+                .as_ref()
+                .and_then(|r| r.requests.as_ref())
+                .is_none_or(|r| !r.contains_key(resource))
+        })
+        .map(|c| c.name.clone())
+        .collect();
+    (resource.to_string(), missing)
+}
+
+/// "HPA Targets" check: AUTO-009 when the scaleTargetRef (Deployment, StatefulSet, ReplicaSet)
+/// does not exist, AUTO-010 per utilization metric on containers without requests for the
+/// resource, AUTO-011 per metrics API the HPA needs that `served` marks as not served (APIs
+/// missing from `served` or mapped to None could not be probed and are skipped).
+pub fn evaluate_hpa_targets(
+    hpas: &[HorizontalPodAutoscaler],
+    targets: &[ScaleTarget],
+    served: &BTreeMap<&str, Option<bool>>,
+) -> (CheckResult, Vec<Issue>) {
+    let mut issues = Vec::new();
+    let mut flagged = 0usize;
+    for hpa in hpas {
+        let Some(spec) = &hpa.spec else { continue };
+        let ns = hpa.metadata.namespace.as_deref().unwrap_or("default");
+        let resource = format!(
+            "{}/{}",
+            ns,
+            hpa.metadata.name.as_deref().unwrap_or("unknown")
+        );
+        let before = issues.len();
+        let target_ref = &spec.scale_target_ref;
+        let target = targets.iter().find(|t| {
+            t.kind == target_ref.kind
+                && t.meta.namespace.as_deref() == Some(ns)
+                && t.meta.name.as_deref() == Some(target_ref.name.as_str())
+        });
+        if target.is_none() && CHECKED_KINDS.contains(&target_ref.kind.as_str()) {
+            issues.push(Issue::new(
+                IssueSeverity::Critical,
+                "Autoscaling",
+                "AUTO-009",
+                format!(
+                    "HPA {} targets {} {}/{}, which does not exist",
+                    resource, target_ref.kind, ns, target_ref.name
+                ),
+                resource.to_string(),
+                "Point scaleTargetRef at the workload to scale, or delete the HPA left behind by a removed workload.",
+            ));
+        }
+
+        if let Some(template) = target.and_then(|t| t.template) {
+            for metric in metrics(hpa) {
+                let (name, missing) = containers_without_request(metric, template);
+                if !missing.is_empty() {
+                    let mut key_attributes = vec![format!("metric={}", name)];
+                    key_attributes.extend(missing.iter().map(|c| format!("container={}", c)));
+                    issues.push(Issue {
+                        key_attributes,
+                        ..Issue::new(
+                            IssueSeverity::Warning,
+                            "Autoscaling",
+                            "AUTO-010",
+                            format!(
+                                "HPA {} scales on {} utilization, but container(s) {} of {} {}/{} have no {} request",
+                                resource,
+                                name,
+                                missing.join(", "),
+                                target_ref.kind,
+                                ns,
+                                target_ref.name,
+                                name
+                            ),
+                            resource.to_string(),
+                            "Set requests for the resource on every container the metric covers, or use an AverageValue target.",
+                        )
+                    });
+                }
+            }
+        }
+
+        let apis: BTreeSet<&str> = metrics(hpa).iter().filter_map(metric_api).collect();
+        for api in apis {
+            if served.get(api) == Some(&Some(false)) {
+                issues.push(Issue {
+                    key_attributes: vec![format!("api={}", api)],
+                    ..Issue::new(
+                        IssueSeverity::Critical,
+                        "Autoscaling",
+                        "AUTO-011",
+                        format!(
+                            "HPA {} uses metrics from {}, which the API server does not serve",
+                            resource, api
+                        ),
+                        resource.to_string(),
+                        "Install or repair the metrics adapter serving the API (metrics-server, prometheus-adapter, KEDA) and check its APIService.",
+                    )
+                });
+            }
+        }
+        if issues.len() > before {
+            flagged += 1;
+        }
+    }
+    let evaluated = hpas.iter().filter(|h| h.spec.is_some()).count();
+    let check = CheckResult {
+        name: "HPA Targets".to_string(),
+        description: "HPA scale targets exist, have requests for utilization metrics and metric APIs are served".to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+            CheckStatus::Critical
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} HPAs with a valid target and metrics",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See AUTO-009..AUTO-011; an HPA with a broken target or metrics never scales.".to_string()]
+        },
+    };
+    (check, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::autoscaling::v2::{
+        CrossVersionObjectReference, ExternalMetricSource, HorizontalPodAutoscalerSpec,
+        MetricIdentifier, MetricTarget, ResourceMetricSource,
+    };
+    use k8s_openapi::api::core::v1::{Container, ResourceRequirements};
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some("shop".to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn hpa(name: &str, target: &str, metrics: Vec<MetricSpec>) -> HorizontalPodAutoscaler {
+        HorizontalPodAutoscaler {
+            metadata: meta(name),
+            spec: Some(HorizontalPodAutoscalerSpec {
+                scale_target_ref: CrossVersionObjectReference {
+                    api_version: Some("apps/v1".to_string()),
+                    kind: "Deployment".to_string(),
+                    name: target.to_string(),
+                },
+                min_replicas: Some(2),
+                max_replicas: 10,
+                metrics: Some(metrics),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn cpu_utilization() -> MetricSpec {
+        MetricSpec {
+            type_: "Resource".to_string(),
+            resource: Some(ResourceMetricSource {
+                name: "cpu".to_string(),
+                target: MetricTarget {
+                    type_: "Utilization".to_string(),
+                    average_utilization: Some(70),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_missing_targets_requests_and_metric_apis() {
+        let external = MetricSpec {
+            type_: "External".to_string(),
+            external: Some(ExternalMetricSource {
+                metric: MetricIdentifier {
+                    name: "queue_depth".to_string(),
+                    selector: None,
+                },
+                target: MetricTarget {
+                    type_: "AverageValue".to_string(),
+                    average_value: Some(Quantity("30".to_string())),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        };
+        let hpas = vec![
+            hpa("api", "api", vec![cpu_utilization()]),
+            hpa("gone", "old-api", vec![cpu_utilization()]),
+            hpa("worker", "worker", vec![external]),
+        ];
+        let container = |name: &str, cpu: Option<&str>| Container {
+            name: name.to_string(),
+            resources: cpu.map(|q| ResourceRequirements {
+                requests: Some(BTreeMap::from([(
+                    "cpu".to_string(),
+                    Quantity(q.to_string()),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let api_spec = PodSpec {
+            containers: vec![container("api", Some("500m")), container("envoy", None)],
+            ..Default::default()
+        };
+        let worker_spec = PodSpec {
+            containers: vec![container("worker", None)],
+            ..Default::default()
+        };
+        let (api_meta, worker_meta) = (meta("api"), meta("worker"));
+        let targets = vec![
+            ScaleTarget {
+                kind: "Deployment",
+                meta: &api_meta,
+                template: Some(&api_spec),
+            },
+            ScaleTarget {
+                kind: "Deployment",
+                meta: &worker_meta,
+                template: Some(&worker_spec),
+            },
+        ];
+        assert_eq!(
+            metric_apis(&hpas).into_iter().collect::<Vec<_>>(),
+            vec![EXTERNAL_METRICS_API, RESOURCE_METRICS_API]
+        );
+        let served = BTreeMap::from([
+            (RESOURCE_METRICS_API, Some(true)),
+            (EXTERNAL_METRICS_API, Some(false)),
+        ]);
+        let (check, issues) = evaluate_hpa_targets(&hpas, &targets, &served);
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("AUTO-010", "shop/api"),
+                ("AUTO-009", "shop/gone"),
+                ("AUTO-011", "shop/worker"),
+            ]
+        );
+        assert!(issues[0].description.contains("container(s) envoy"));
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("0/3 HPAs with a valid target and metrics")
+        );
+    }
+}
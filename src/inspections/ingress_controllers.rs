@@ -0,0 +1,627 @@
+//! Ingress controller checks for the Network Connectivity module: ingress-nginx, Traefik and
+//! HAProxy controllers are detected from their container images, then their readiness, the
+//! settings that commonly turn into 4xx/5xx for every host (nginx proxy body size, default
+//! backend), the default TLS certificate and the IngressClasses referenced by Ingresses.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment};
+use k8s_openapi::api::core::v1::{ConfigMap, Container, PodSpec, Secret, Service};
+use k8s_openapi::api::networking::v1::{Ingress, IngressClass};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::inspections::certificates::leaf_certificate_days;
+use crate::inspections::registry::image_name_tag;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Days before expiry at which the default TLS certificate is reported.
+const DEFAULT_CERT_WARNING_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControllerFlavor {
+    Nginx,
+    Traefik,
+    HaProxy,
+}
+
+impl ControllerFlavor {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Nginx => "ingress-nginx",
+            Self::Traefik => "Traefik",
+            Self::HaProxy => "HAProxy",
+        }
+    }
+}
+
+/// An ingress controller workload and the flags of its controller container.
+#[derive(Debug, Clone)]
+pub struct ControllerWorkload {
+    pub flavor: ControllerFlavor,
+    pub kind: &'static str,
+    pub namespace: String,
+    pub name: String,
+    pub desired: i32,
+    pub ready: i32,
+    args: Vec<String>,
+}
+
+impl ControllerWorkload {
+    fn resource(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
+
+    /// Value of `--name=value` or `--name value`.
+    fn flag(&self, name: &str) -> Option<&str> {
+        let prefix = format!("--{}", name);
+        self.args.iter().enumerate().find_map(|(i, arg)| {
+            let rest = arg.strip_prefix(&prefix)?;
+            match rest.strip_prefix('=') {
+                Some(value) => Some(value),
+                None if rest.is_empty() => self.args.get(i + 1).map(String::as_str),
+                None => None,
+            }
+        })
+    }
+
+    /// `namespace/name` flag value split; a bare name or `$(POD_NAMESPACE)` (as the Helm
+    /// charts pass it) resolves to the controller namespace.
+    fn object_flag(&self, name: &str) -> Option<(String, String)> {
+        let value = self.flag(name).filter(|v| !v.is_empty())?;
+        Some(match value.split_once('/') {
+            Some(("$(POD_NAMESPACE)", n)) => (self.namespace.clone(), n.to_string()),
+            Some((ns, n)) => (ns.to_string(), n.to_string()),
+            None => (self.namespace.clone(), value.to_string()),
+        })
+    }
+}
+
+fn flavor_of(container: &Container) -> Option<ControllerFlavor> {
+    let image = container.image.as_deref()?;
+    let (name, _) = image_name_tag(image);
+    if image.contains("ingress-nginx/controller") || name == "nginx-ingress-controller" {
+        Some(ControllerFlavor::Nginx)
+    } else if name == "traefik" {
+        Some(ControllerFlavor::Traefik)
+    } else if name == "haproxy-ingress" || name == "kubernetes-ingress" {
+        Some(ControllerFlavor::HaProxy)
+    } else {
+        None
+    }
+}
+
+fn workload(
+    kind: &'static str,
+    meta: &ObjectMeta,
+    spec: Option<&PodSpec>,
+    desired: i32,
+    ready: i32,
+) -> Option<ControllerWorkload> {
+    let (flavor, container) = spec?
+        .containers
+        .iter()
+        .find_map(|c| flavor_of(c).map(|f| (f, c)))?;
+    let args = container
+        .command
+        .iter()
+        .chain(container.args.iter())
+        .flatten()
+        .cloned()
+        .collect();
+    Some(ControllerWorkload {
+        flavor,
+        kind,
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        name: meta.name.clone().unwrap_or_default(),
+        desired,
+        ready,
+        args,
+    })
+}
+
+/// Ingress controllers among the Deployments and DaemonSets.
+pub fn detect_controllers(
+    deployments: &[Deployment],
+    daemon_sets: &[DaemonSet],
+) -> Vec<ControllerWorkload> {
+    let from_deployments = deployments.iter().filter_map(|d| {
+        let spec = d.spec.as_ref();
+        workload(
+            "Deployment",
+            &d.metadata,
+            spec.and_then(|s| s.template.spec.as_ref()),
+            spec.and_then(|s| s.replicas).unwrap_or(1),
+            d.status
+                .as_ref()
+                .and_then(|s| s.ready_replicas)
+                .unwrap_or(0),
+        )
+    });
+    let from_daemon_sets = daemon_sets.iter().filter_map(|d| {
+        let status = d.status.as_ref();
+        workload(
+            "DaemonSet",
+            &d.metadata,
+            d.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+            status.map(|s| s.desired_number_scheduled).unwrap_or(0),
+            status.map(|s| s.number_ready).unwrap_or(0),
+        )
+    });
+    from_deployments.chain(from_daemon_sets).collect()
+}
+
+/// Namespaces whose ConfigMaps, Services and Secrets the controller flags point into.
+pub fn referenced_namespaces(controllers: &[ControllerWorkload]) -> BTreeSet<String> {
+    controllers
+        .iter()
+        .flat_map(|c| {
+            [
+                "configmap",
+                "default-backend-service",
+                "default-ssl-certificate",
+            ]
+            .iter()
+            .filter_map(|f| c.object_flag(f).map(|(ns, _)| ns))
+            .chain([c.namespace.clone()])
+            .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Score share of items without findings; Pass only when none is flagged.
+fn ratio_check(
+    name: &str,
+    description: &str,
+    total: usize,
+    flagged: usize,
+    details: String,
+) -> CheckResult {
+    let score = if total == 0 {
+        100.0
+    } else {
+        ((total - flagged) as f64 / total as f64) * 100.0
+    };
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if score >= 70.0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Critical
+        },
+        score,
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: vec![],
+    }
+}
+
+/// NET-006: controllers with fewer ready replicas than desired. Single replicas are HA-005.
+pub fn controller_readiness(
+    controllers: &[ControllerWorkload],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut flagged = 0;
+    let mut rows = Vec::new();
+    for c in controllers {
+        rows.push(format!(
+            "{} {} {} {}/{} ready",
+            c.flavor.label(),
+            c.kind,
+            c.resource(),
+            c.ready,
+            c.desired
+        ));
+        if c.ready < c.desired || c.desired == 0 {
+            flagged += 1;
+            issues.push(Issue::new(
+                IssueSeverity::Critical,
+                c.kind,
+                "NET-006",
+                format!(
+                    "{} controller {} {} has {}/{} replicas ready",
+                    c.flavor.label(),
+                    c.kind,
+                    c.resource(),
+                    c.ready,
+                    c.desired
+                ),
+                c.resource(),
+                "Check the controller pods' events and logs; unready controllers drop traffic for every Ingress they serve.",
+            ));
+        }
+    }
+    let mut check = ratio_check(
+        "Ingress Controllers",
+        "ingress-nginx, Traefik and HAProxy controllers found and ready",
+        controllers.len(),
+        flagged,
+        if rows.is_empty() {
+            "No ingress-nginx, Traefik or HAProxy controller detected".to_string()
+        } else {
+            rows.join("; ")
+        },
+    );
+    if flagged > 0 {
+        check.status = CheckStatus::Critical;
+        check.recommendations = vec!["Restore ingress controller readiness (NET-006).".to_string()];
+    }
+    check
+}
+
+/// Parse an nginx size (`1m`, `512k`, `0` = unlimited) into bytes; None for unparsable values.
+fn nginx_size_bytes(value: &str) -> Option<u64> {
+    let value = value.trim().to_ascii_lowercase();
+    let (digits, unit) = match value.chars().last()? {
+        'k' => (&value[..value.len() - 1], 1024),
+        'm' => (&value[..value.len() - 1], 1024 * 1024),
+        'g' => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value.as_str(), 1),
+    };
+    digits.parse::<u64>().ok().map(|n| n * unit)
+}
+
+/// Objects the controller flags reference, keyed by `namespace/name`.
+#[derive(Debug, Default)]
+pub struct ReferencedObjects<'a> {
+    pub config_maps: HashMap<String, &'a ConfigMap>,
+    pub services: HashSet<String>,
+    pub secrets: HashMap<String, &'a Secret>,
+}
+
+impl<'a> ReferencedObjects<'a> {
+    pub fn new(
+        config_maps: &'a [ConfigMap],
+        services: &'a [Service],
+        secrets: &'a [Secret],
+    ) -> Self {
+        let key = |m: &ObjectMeta| {
+            format!(
+                "{}/{}",
+                m.namespace.as_deref().unwrap_or_default(),
+                m.name.as_deref().unwrap_or_default()
+            )
+        };
+        Self {
+            config_maps: config_maps.iter().map(|c| (key(&c.metadata), c)).collect(),
+            services: services.iter().map(|s| key(&s.metadata)).collect(),
+            secrets: secrets.iter().map(|s| (key(&s.metadata), s)).collect(),
+        }
+    }
+}
+
+/// NET-007..NET-009: nginx proxy body size, default backend Service and default TLS certificate.
+pub fn controller_configuration(
+    controllers: &[ControllerWorkload],
+    objects: &ReferencedObjects<'_>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut flagged = BTreeSet::new();
+    for c in controllers {
+        let before = issues.len();
+        if c.flavor == ControllerFlavor::Nginx {
+            let body_size = c
+                .object_flag("configmap")
+                .and_then(|(ns, n)| objects.config_maps.get(&format!("{}/{}", ns, n)))
+                .and_then(|cm| cm.data.as_ref()?.get("proxy-body-size").cloned());
+            let limited = match body_size.as_deref() {
+                None => Some("1m (default)".to_string()),
+                Some(v) => nginx_size_bytes(v)
+                    .filter(|b| *b != 0 && *b <= 1024 * 1024)
+                    .map(|_| v.to_string()),
+            };
+            if let Some(size) = limited {
+                issues.push(Issue::new(
+                    IssueSeverity::Info,
+                    c.kind,
+                    "NET-007",
+                    format!(
+                        "ingress-nginx {} limits request bodies to {}; larger uploads get 413",
+                        c.resource(),
+                        size
+                    ),
+                    c.resource(),
+                    "Set proxy-body-size in the controller ConfigMap (or the nginx.ingress.kubernetes.io/proxy-body-size annotation on Ingresses that accept uploads).",
+                ));
+            }
+        }
+        if c.flavor != ControllerFlavor::Traefik {
+            if let Some((ns, name)) = c.object_flag("default-backend-service") {
+                if !objects.services.contains(&format!("{}/{}", ns, name)) {
+                    issues.push(Issue::new(
+                        IssueSeverity::Warning,
+                        c.kind,
+                        "NET-008",
+                        format!(
+                            "{} controller {} uses default backend Service {}/{}, which does not exist",
+                            c.flavor.label(),
+                            c.resource(),
+                            ns,
+                            name
+                        ),
+                        c.resource(),
+                        "Create the default backend Service or drop --default-backend-service; unmatched requests otherwise get 503.",
+                    ));
+                }
+            }
+            if let Some((ns, name)) = c.object_flag("default-ssl-certificate") {
+                let days = objects.secrets.get(&format!("{}/{}", ns, name)).map(|s| {
+                    s.data
+                        .as_ref()
+                        .and_then(|d| d.get("tls.crt"))
+                        .and_then(|b| leaf_certificate_days(&b.0))
+                });
+                let finding = match days {
+                    None => Some((IssueSeverity::Warning, "does not exist".to_string())),
+                    Some(None) => Some((
+                        IssueSeverity::Warning,
+                        "has no parsable tls.crt".to_string(),
+                    )),
+                    Some(Some(d)) if d < 0 => Some((
+                        IssueSeverity::Critical,
+                        format!("expired {} day(s) ago", -d),
+                    )),
+                    Some(Some(d)) if d <= DEFAULT_CERT_WARNING_DAYS => {
+                        Some((IssueSeverity::Warning, format!("expires in {} day(s)", d)))
+                    }
+                    Some(Some(_)) => None,
+                };
+                if let Some((severity, state)) = finding {
+                    issues.push(Issue::new(
+                        severity,
+                        c.kind,
+                        "NET-009",
+                        format!(
+                            "Default TLS certificate {}/{} of {} controller {} {}",
+                            ns,
+                            name,
+                            c.flavor.label(),
+                            c.resource(),
+                            state
+                        ),
+                        c.resource(),
+                        "Renew or recreate the default certificate Secret; hosts without their own TLS secret are served with it.",
+                    ));
+                }
+            }
+        }
+        if issues.len() > before {
+            flagged.insert(c.resource());
+        }
+    }
+    let mut check = ratio_check(
+        "Ingress Controller Configuration",
+        "Proxy body size, default backend and default TLS certificate of ingress controllers",
+        controllers.len(),
+        flagged.len(),
+        format!(
+            "{}/{} controller(s) without configuration findings",
+            controllers.len() - flagged.len(),
+            controllers.len()
+        ),
+    );
+    if !flagged.is_empty() {
+        check.recommendations =
+            vec!["Review ingress controller settings (NET-007..NET-009).".to_string()];
+    }
+    check
+}
+
+/// NET-010: Ingresses whose `spec.ingressClassName` names no existing IngressClass.
+pub fn ingress_class_references(
+    ingresses: &[Ingress],
+    classes: &[IngressClass],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let known: HashSet<&str> = classes
+        .iter()
+        .filter_map(|c| c.metadata.name.as_deref())
+        .collect();
+    let mut with_class = 0;
+    let mut flagged = 0;
+    for ingress in ingresses {
+        let Some(class) = ingress
+            .spec
+            .as_ref()
+            .and_then(|s| s.ingress_class_name.as_deref())
+        else {
+            continue;
+        };
+        with_class += 1;
+        if known.contains(class) {
+            continue;
+        }
+        flagged += 1;
+        let ns = ingress.metadata.namespace.as_deref().unwrap_or("default");
+        let name = ingress.metadata.name.as_deref().unwrap_or("unknown");
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Service",
+            "NET-010",
+            format!(
+                "Ingress {}/{} references IngressClass {}, which does not exist",
+                ns, name, class
+            ),
+            format!("{}/{}", ns, name),
+            "Fix ingressClassName or create the IngressClass; no controller serves this Ingress.",
+        ));
+    }
+    let mut check = ratio_check(
+        "IngressClass References",
+        "Ingresses reference an existing IngressClass",
+        with_class,
+        flagged,
+        format!(
+            "{}/{} Ingress(es) with ingressClassName reference an existing class ({} class(es))",
+            with_class - flagged,
+            with_class,
+            known.len()
+        ),
+    );
+    if flagged > 0 {
+        check.recommendations =
+            vec!["Fix Ingresses referencing missing IngressClasses (NET-010).".to_string()];
+    }
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DeploymentSpec, DeploymentStatus};
+    use k8s_openapi::api::core::v1::PodTemplateSpec;
+    use k8s_openapi::api::networking::v1::IngressSpec;
+
+    fn meta(ns: &str, name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn controller(name: &str, image: &str, args: &[&str], replicas: i32, ready: i32) -> Deployment {
+        Deployment {
+            metadata: meta("ingress", name),
+            spec: Some(DeploymentSpec {
+                replicas: Some(replicas),
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec {
+                        containers: vec![Container {
+                            name: "controller".to_string(),
+                            image: Some(image.to_string()),
+                            args: Some(args.iter().map(|a| a.to_string()).collect()),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            status: Some(DeploymentStatus {
+                ready_replicas: Some(ready),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn detects_controllers_and_flags_configuration() {
+        let deployments = [
+            controller(
+                "nginx",
+                "registry.k8s.io/ingress-nginx/controller:v1.10.0@sha256:abc",
+                &[
+                    "/nginx-ingress-controller",
+                    "--configmap=$(POD_NAMESPACE)/nginx",
+                    "--default-backend-service",
+                    "ingress/fallback",
+                    "--default-ssl-certificate=certs/wildcard",
+                ],
+                2,
+                1,
+            ),
+            controller(
+                "traefik",
+                "traefik:v3.0",
+                &["--providers.kubernetesingress"],
+                2,
+                2,
+            ),
+            controller("web", "nginx:1.25", &[], 3, 3),
+        ];
+        let controllers = detect_controllers(&deployments, &[]);
+        let flavors: Vec<ControllerFlavor> = controllers.iter().map(|c| c.flavor).collect();
+        assert_eq!(
+            flavors,
+            vec![ControllerFlavor::Nginx, ControllerFlavor::Traefik]
+        );
+
+        let mut issues = Vec::new();
+        let check = controller_readiness(&controllers, &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NET-006"));
+        assert_eq!(
+            issues[0].description,
+            "ingress-nginx controller Deployment ingress/nginx has 1/2 replicas ready"
+        );
+
+        let mut issues = Vec::new();
+        let objects = ReferencedObjects::default();
+        let check = controller_configuration(&controllers, &objects, &mut issues);
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["NET-007", "NET-008", "NET-009"]);
+        assert!(issues[1].description.contains("Service ingress/fallback"));
+        assert!(issues[2]
+            .description
+            .starts_with("Default TLS certificate certs/wildcard of ingress-nginx"));
+        assert_eq!(check.score, 50.0);
+        assert_eq!(
+            referenced_namespaces(&controllers)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["certs", "ingress"]
+        );
+    }
+
+    #[test]
+    fn nginx_body_size_and_ingress_classes() {
+        assert_eq!(nginx_size_bytes("1m"), Some(1024 * 1024));
+        assert_eq!(nginx_size_bytes("512K"), Some(512 * 1024));
+        assert_eq!(nginx_size_bytes("0"), Some(0));
+        assert_eq!(nginx_size_bytes("big"), None);
+
+        let cm = ConfigMap {
+            metadata: meta("ingress", "nginx"),
+            data: Some([("proxy-body-size".to_string(), "50m".to_string())].into()),
+            ..Default::default()
+        };
+        let configured = detect_controllers(
+            &[controller(
+                "nginx",
+                "registry.k8s.io/ingress-nginx/controller:v1.10.0",
+                &["--configmap=ingress/nginx"],
+                2,
+                2,
+            )],
+            &[],
+        );
+        let cms = [cm];
+        let objects = ReferencedObjects::new(&cms, &[], &[]);
+        let mut issues = Vec::new();
+        controller_configuration(&configured, &objects, &mut issues);
+        assert!(issues.is_empty());
+
+        let ingress = |name: &str, class: Option<&str>| Ingress {
+            metadata: meta("app", name),
+            spec: Some(IngressSpec {
+                ingress_class_name: class.map(|c| c.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let classes = [IngressClass {
+            metadata: ObjectMeta {
+                name: Some("nginx".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }];
+        let mut issues = Vec::new();
+        let check = ingress_class_references(
+            &[
+                ingress("a", Some("nginx")),
+                ingress("b", Some("nginx-internal")),
+                ingress("c", None),
+            ],
+            &classes,
+            &mut issues,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("app/b"));
+        assert_eq!(check.score, 50.0);
+    }
+}
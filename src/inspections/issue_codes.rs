@@ -1,5 +1,5 @@
 //! Issue code registry: stable codes and short titles for report grouping and docs linking.
-//! Format: prefix (NODE/POD/RES/NET/STO/SEC/CTRL/AUTO/BATCH/POLICY/OBS) + three-digit number.
+//! Format: prefix (NODE/POD/RES/NET/STO/SEC/CTRL/AUTO/BATCH/POLICY/OBS/DBG/ORPH/EVT/CERT/INSP/STS/DS) + three-digit number.
 
 /// Returns the short title for an issue code, or None if unknown.
 pub fn short_title(code: &str) -> Option<&'static str> {
@@ -10,6 +10,36 @@ pub fn short_title(code: &str) -> Option<&'static str> {
         "NODE-003" => Some("Zombie processes on node"),
         "NODE-004" => Some("Node disk usage high (Warning)"),
         "NODE-005" => Some("Node disk usage critical"),
+        "NODE-006" => Some("Node CPU capacity mismatch"),
+        "NODE-007" => Some("Node memory capacity mismatch"),
+        "NODE-008" => Some("Node resource usage high"),
+        "NODE-009" => Some("Node resource usage critical"),
+        "NODE-010" => Some("Unbalanced node utilization"),
+        "NODE-011" => Some("Node ephemeral-storage allocatable low"),
+        "NODE-012" => Some("Node imagefs near image GC threshold"),
+        "NODE-013" => Some("Node nodefs near eviction threshold"),
+        "NODE-014" => Some("Kubelet files too permissive"),
+        "NODE-015" => Some("Unneeded kernel modules loaded"),
+        "NODE-016" => Some("Kernel parameters not hardened"),
+        "NODE-017" => Some("Insecure service port open on node"),
+        "NODE-018" => Some("Weak SSH daemon configuration"),
+        "NODE-019" => Some("Kubelet accepts anonymous requests"),
+        "NODE-020" => Some("Kubelet hard eviction thresholds missing"),
+        "NODE-021" => Some("Kubelet image GC thresholds misconfigured"),
+        "NODE-022" => Some("Kubelet maxPods above tested limit"),
+        "NODE-023" => Some("Node near pod capacity"),
+        "NODE-024" => Some("Cluster pod headroom below one node"),
+        "NODE-025" => Some("Node image older than maximum age"),
+        "NODE-026" => Some("Node kernel on denylist"),
+        "NODE-027" => Some("Windows node container runtime unsupported"),
+        "NODE-028" => Some("Windows node pause image outdated"),
+        "NODE-029" => Some("Node cordoned too long"),
+        "NODE-030" => Some("Node with many evicted pods"),
+        "NODE-031" => Some("DaemonSet pods unschedulable on node"),
+        "NODE-032" => Some("Node memory limits overcommitted"),
+        "NODE-033" => Some("Node CPU limits overcommitted"),
+        "NODE-034" => Some("Node Lease not renewed"),
+        "NODE-035" => Some("Node Ready status flapping"),
         // Pod
         "POD-001" => Some("Pod in Failed state"),
         "POD-002" => Some("Pod cannot be scheduled"),
@@ -23,18 +53,46 @@ pub fn short_title(code: &str) -> Option<&'static str> {
         "POD-010" => Some("OOMKilled"),
         "POD-011" => Some("Container terminated (non-zero exit)"),
         "POD-012" => Some("Pod Running but not Ready"),
+        "POD-013" => Some("Liveness and readiness probes identical"),
+        "POD-014" => Some("Liveness probe checks external dependencies"),
+        "POD-015" => Some("Aggressive liveness probe timing"),
+        "POD-016" => Some("Exec probe runs a heavy command"),
         // Resource
         "RES-001" => Some("Container has no resource requests"),
         "RES-002" => Some("Container has no resource limits"),
         "RES-003" => Some("Namespace has no resource quota"),
         "RES-004" => Some("CPU limit below request"),
         "RES-005" => Some("Memory limit below request"),
+        "RES-006" => Some("BestEffort pods in a system namespace"),
+        "RES-007" => Some("Most pods are BestEffort"),
+        // GPU and extended resources
+        "GPU-001" => Some("Accelerator node idle"),
+        "GPU-002" => Some("Pod requesting extended resources Pending"),
+        "GPU-003" => Some("Device plugin unhealthy"),
         // Network
         "NET-001" => Some("LoadBalancer has no external IP"),
         "NET-002" => Some("NodePort outside recommended range"),
         "NET-003" => Some("Service has no selector or endpoints"),
         "NET-004" => Some("DNS deployment not ready"),
         "NET-005" => Some("DNS service not found"),
+        "NET-006" => Some("Ingress controller not ready"),
+        "NET-007" => Some("ingress-nginx request body size limit"),
+        "NET-008" => Some("Ingress default backend Service missing"),
+        "NET-009" => Some("Ingress default TLS certificate invalid"),
+        "NET-010" => Some("Ingress references missing IngressClass"),
+        "NET-011" => Some("kube-proxy DaemonSet not fully rolled out"),
+        "NET-012" => Some("kube-proxy ipvs mode without ip_vs module"),
+        "NET-013" => Some("CNI DaemonSet not fully rolled out"),
+        "NET-014" => Some("Pod stuck in ContainerCreating on CNI error"),
+        "NET-015" => Some("Service VIP unreachable from node"),
+        "NET-016" => Some("Cross-node pod connectivity broken"),
+        "NET-017" => Some("Node unreachable from pods on another node"),
+        "NET-018" => Some("External egress failed"),
+        "NET-019" => Some("Network path with packet loss or high latency"),
+        "NET-020" => Some("Ingress backend Service missing"),
+        "NET-021" => Some("Ingress backend port not exposed by Service"),
+        "NET-022" => Some("Service targetPort not exposed by selected pods"),
+        "NET-023" => Some("ExternalName Service does not resolve"),
         // Storage
         "STO-001" => Some("PV config or backing storage issue"),
         "STO-002" => Some("PV Released, needs cleanup"),
@@ -46,6 +104,12 @@ pub fn short_title(code: &str) -> Option<&'static str> {
         "STO-008" => Some("StorageClass has no provisioner"),
         "STO-009" => Some("No default StorageClass"),
         "STO-010" => Some("Multiple StorageClasses marked default"),
+        "STO-011" => Some("PVC usage high"),
+        "STO-012" => Some("PVC nearly full"),
+        "STO-013" => Some("StorageClass provisioner has no CSIDriver"),
+        "STO-014" => Some("CSI controller not running"),
+        "STO-015" => Some("Immediate binding in multi-zone cluster"),
+        "STO-016" => Some("PV references missing StorageClass"),
         // Security
         "SEC-001" => Some("ClusterRole has excessive permissions"),
         "SEC-002" => Some("User has cluster-admin"),
@@ -59,12 +123,36 @@ pub fn short_title(code: &str) -> Option<&'static str> {
         // Control plane
         "CTRL-001" => Some("Control plane component not ready"),
         "CTRL-002" => Some("Static Pod not ready"),
+        "CTRL-003" => Some("API server allows anonymous requests"),
+        "CTRL-004" => Some("API server insecure port enabled"),
+        "CTRL-005" => Some("API server audit logging disabled"),
+        "CTRL-006" => Some("API server authorizes every request"),
+        "CTRL-007" => Some("Deprecated feature gate set"),
+        "CTRL-008" => Some("API server audit policy missing"),
+        "CTRL-009" => Some("API server audit log not persisted"),
+        "CTRL-010" => Some("API server audit log retention too short"),
+        "CTRL-011" => Some("API server slow"),
+        "CTRL-012" => Some("API server requests failing"),
+        "CTRL-013" => Some("Stale scheduler or controller-manager leader Lease"),
+        "CTRL-014" => Some("Control-plane component /healthz failing"),
+        "CTRL-015" => Some("Many objects of one kind in etcd"),
+        "CTRL-016" => Some("etcd database large"),
+        "CTRL-017" => Some("EKS kube-proxy add-on version mismatch"),
+        "CTRL-018" => Some("GKE node pool behind control plane"),
+        "CTRL-019" => Some("AKS node image outdated"),
+        "CTRL-020" => Some("Stale component leader Lease"),
         // Autoscaling
         "AUTO-001" => Some("HPA replica range too narrow"),
         "AUTO-002" => Some("HPA has no metrics configured"),
         "AUTO-003" => Some("HPA target workload or metrics issue"),
         "AUTO-004" => Some("HPA behavior limits scaling"),
         "AUTO-005" => Some("HPA metric target not configured"),
+        "AUTO-006" => Some("Workload replicas outside HPA range"),
+        "AUTO-007" => Some("Workload replicas set by another writer"),
+        "AUTO-008" => Some("Workload driven by multiple autoscalers"),
+        "AUTO-009" => Some("HPA scale target not found"),
+        "AUTO-010" => Some("HPA utilization metric without container requests"),
+        "AUTO-011" => Some("HPA metrics API not served"),
         // Batch
         "BATCH-001" => Some("CronJob suspended"),
         "BATCH-002" => Some("CronJob job failed"),
@@ -76,23 +164,79 @@ pub fn short_title(code: &str) -> Option<&'static str> {
         "POLICY-002" => Some("No LimitRange configured"),
         "POLICY-003" => Some("Critical workload has no PDB"),
         "POLICY-004" => Some("Replica count does not satisfy PDB"),
+        "POLICY-005" => Some("Namespace stuck in Terminating"),
+        "POLICY-006" => Some("Namespace without workloads"),
+        "POLICY-007" => Some("Namespace missing required labels"),
         // Observability
         "OBS-001" => Some("metrics-server not deployed"),
         "OBS-002" => Some("kube-state-metrics not deployed"),
         "OBS-003" => Some("Log aggregation not deployed"),
         "OBS-004" => Some("Prometheus/monitoring not deployed"),
+        // Debug leftovers
+        "DBG-001" => Some("Debug log level in production"),
+        "DBG-002" => Some("Debug/profiling port exposed"),
+        "DBG-003" => Some("Replicas reduced to 1"),
+        // Orphaned resources
+        "ORPH-001" => Some("Unused ConfigMap"),
+        "ORPH-002" => Some("Unused Secret"),
+        "ORPH-003" => Some("Service selects no pods"),
+        "ORPH-004" => Some("PVC not mounted"),
+        "ORPH-005" => Some("Stale finished Job"),
+        "ORPH-006" => Some("Stale finished pod"),
+        "ORPH-007" => Some("Old ReplicaSets beyond history limit"),
+        "ORPH-008" => Some("Many finished pods retained in namespace"),
+        "ORPH-009" => Some("Finished Jobs without ttlSecondsAfterFinished"),
+        "ORPH-010" => Some("Long Deployment revision history"),
+        "ORPH-011" => Some("Reference to missing ConfigMap or Secret"),
+        "ORPH-012" => Some("Reference to missing ConfigMap or Secret key"),
+        "ORPH-013" => Some("Object stuck deleting"),
+        // Zone resilience
+        "HA-001" => Some("Cluster runs in a single zone"),
+        "HA-002" => Some("Workload replicas all in one zone"),
+        "HA-003" => Some("Multi-replica Deployment without topology spread"),
+        "HA-004" => Some("CoreDNS runs a single replica"),
+        "HA-005" => Some("Ingress controller runs a single replica"),
+        "HA-006" => Some("metrics-server single replica without PDB"),
+        "HA-007" => Some("Control-plane component runs a single instance"),
+        "HA-008" => Some("Workload runs only on spot nodes"),
+        "HA-009" => Some("Single-replica workload on a spot node"),
+        "HA-010" => Some("Workload without architecture selector on mixed-arch cluster"),
+        "HA-011" => Some("Single-arch image on mixed-arch cluster"),
+        "HA-012" => Some("No custom PriorityClass defined"),
+        "HA-013" => Some("Critical add-on without system priority"),
+        "HA-014" => Some("Workload uses a system priority class"),
+        "HA-015" => Some("StatefulSet without termination grace period"),
+        "HA-016" => Some("Long-lived connections without preStop hook"),
+        "HA-017" => Some("Rolling update without surge"),
+        // StatefulSet
+        "STS-001" => Some("StatefulSet claim without usable StorageClass"),
+        "STS-002" => Some("StatefulSet on node-local storage"),
+        "STS-003" => Some("StatefulSet OnDelete update strategy"),
+        "STS-004" => Some("StatefulSet governing Service missing or not headless"),
+        "STS-005" => Some("StatefulSet replicas not spread across zones"),
+        // DaemonSet
+        "DS-001" => Some("DaemonSet pods missing or not ready on nodes"),
+        "DS-002" => Some("Infrastructure DaemonSet excluded from nodes"),
+        // Events
+        "EVT-001" => Some("Noisy Warning event reason"),
+        "EVT-002" => Some("Sustained FailedScheduling events"),
+        "EVT-003" => Some("Sustained volume mount/attach failures"),
+        "EVT-004" => Some("Sustained back-off or probe failures"),
+        "EVT-005" => Some("Sustained Warning event pattern"),
         // Certificates
         "CERT-001" => Some("CSR long Pending or abnormal"),
         "CERT-002" => Some("Certificate expiring soon"),
         "CERT-003" => Some("Certificate expired"),
+        // Inspection modules
+        "INSP-001" => Some("Inspection module failed"),
         _ => None,
     }
 }
 
+/// Directory URL of the issue docs on GitHub; `--offline-docs` replaces it in report links.
+pub const DOCS_BASE_URL: &str = "https://github.com/Ghostwritten/kubeowler/blob/main/docs/issues";
+
 /// GitHub URL to the issue doc (for report links; works for binary-only users).
 pub fn doc_path(code: &str) -> String {
-    format!(
-        "https://github.com/Ghostwritten/kubeowler/blob/main/docs/issues/{}.md",
-        code
-    )
+    format!("{}/{}.md", DOCS_BASE_URL, code)
 }
@@ -0,0 +1,403 @@
+//! Issue documentation embedded in the binary (`docs/issues/*.md`), so `kubeowler docs generate`
+//! can write the pages for offline use, reports can link to them with `--offline-docs`, and
+//! `kubeowler explain` can print them.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::inspections::issue_codes;
+
+/// Index page of the bundle, with one row per code by category.
+const INDEX: &str = include_str!("../../docs/issues/README.md");
+
+/// One page per issue code: title, summary, severity, example, symptoms, resolution, references.
+#[rustfmt::skip]
+const PAGES: &[(&str, &str)] = &[
+    ("NODE-001", include_str!("../../docs/issues/NODE-001.md")),
+    ("NODE-002", include_str!("../../docs/issues/NODE-002.md")),
+    ("NODE-003", include_str!("../../docs/issues/NODE-003.md")),
+    ("NODE-004", include_str!("../../docs/issues/NODE-004.md")),
+    ("NODE-005", include_str!("../../docs/issues/NODE-005.md")),
+    ("NODE-006", include_str!("../../docs/issues/NODE-006.md")),
+    ("NODE-007", include_str!("../../docs/issues/NODE-007.md")),
+    ("NODE-008", include_str!("../../docs/issues/NODE-008.md")),
+    ("NODE-009", include_str!("../../docs/issues/NODE-009.md")),
+    ("NODE-010", include_str!("../../docs/issues/NODE-010.md")),
+    ("NODE-011", include_str!("../../docs/issues/NODE-011.md")),
+    ("NODE-012", include_str!("../../docs/issues/NODE-012.md")),
+    ("NODE-013", include_str!("../../docs/issues/NODE-013.md")),
+    ("NODE-014", include_str!("../../docs/issues/NODE-014.md")),
+    ("NODE-015", include_str!("../../docs/issues/NODE-015.md")),
+    ("NODE-016", include_str!("../../docs/issues/NODE-016.md")),
+    ("NODE-017", include_str!("../../docs/issues/NODE-017.md")),
+    ("NODE-018", include_str!("../../docs/issues/NODE-018.md")),
+    ("NODE-019", include_str!("../../docs/issues/NODE-019.md")),
+    ("NODE-020", include_str!("../../docs/issues/NODE-020.md")),
+    ("NODE-021", include_str!("../../docs/issues/NODE-021.md")),
+    ("NODE-022", include_str!("../../docs/issues/NODE-022.md")),
+    ("NODE-023", include_str!("../../docs/issues/NODE-023.md")),
+    ("NODE-024", include_str!("../../docs/issues/NODE-024.md")),
+    ("NODE-025", include_str!("../../docs/issues/NODE-025.md")),
+    ("NODE-026", include_str!("../../docs/issues/NODE-026.md")),
+    ("NODE-027", include_str!("../../docs/issues/NODE-027.md")),
+    ("NODE-028", include_str!("../../docs/issues/NODE-028.md")),
+    ("NODE-029", include_str!("../../docs/issues/NODE-029.md")),
+    ("NODE-030", include_str!("../../docs/issues/NODE-030.md")),
+    ("NODE-031", include_str!("../../docs/issues/NODE-031.md")),
+    ("NODE-032", include_str!("../../docs/issues/NODE-032.md")),
+    ("NODE-033", include_str!("../../docs/issues/NODE-033.md")),
+    ("NODE-034", include_str!("../../docs/issues/NODE-034.md")),
+    ("NODE-035", include_str!("../../docs/issues/NODE-035.md")),
+    ("POD-001", include_str!("../../docs/issues/POD-001.md")),
+    ("POD-002", include_str!("../../docs/issues/POD-002.md")),
+    ("POD-003", include_str!("../../docs/issues/POD-003.md")),
+    ("POD-004", include_str!("../../docs/issues/POD-004.md")),
+    ("POD-005", include_str!("../../docs/issues/POD-005.md")),
+    ("POD-006", include_str!("../../docs/issues/POD-006.md")),
+    ("POD-007", include_str!("../../docs/issues/POD-007.md")),
+    ("POD-008", include_str!("../../docs/issues/POD-008.md")),
+    ("POD-009", include_str!("../../docs/issues/POD-009.md")),
+    ("POD-010", include_str!("../../docs/issues/POD-010.md")),
+    ("POD-011", include_str!("../../docs/issues/POD-011.md")),
+    ("POD-012", include_str!("../../docs/issues/POD-012.md")),
+    ("POD-013", include_str!("../../docs/issues/POD-013.md")),
+    ("POD-014", include_str!("../../docs/issues/POD-014.md")),
+    ("POD-015", include_str!("../../docs/issues/POD-015.md")),
+    ("POD-016", include_str!("../../docs/issues/POD-016.md")),
+    ("RES-001", include_str!("../../docs/issues/RES-001.md")),
+    ("RES-002", include_str!("../../docs/issues/RES-002.md")),
+    ("RES-003", include_str!("../../docs/issues/RES-003.md")),
+    ("RES-004", include_str!("../../docs/issues/RES-004.md")),
+    ("RES-005", include_str!("../../docs/issues/RES-005.md")),
+    ("RES-006", include_str!("../../docs/issues/RES-006.md")),
+    ("RES-007", include_str!("../../docs/issues/RES-007.md")),
+    ("GPU-001", include_str!("../../docs/issues/GPU-001.md")),
+    ("GPU-002", include_str!("../../docs/issues/GPU-002.md")),
+    ("GPU-003", include_str!("../../docs/issues/GPU-003.md")),
+    ("NET-001", include_str!("../../docs/issues/NET-001.md")),
+    ("NET-002", include_str!("../../docs/issues/NET-002.md")),
+    ("NET-003", include_str!("../../docs/issues/NET-003.md")),
+    ("NET-004", include_str!("../../docs/issues/NET-004.md")),
+    ("NET-005", include_str!("../../docs/issues/NET-005.md")),
+    ("NET-006", include_str!("../../docs/issues/NET-006.md")),
+    ("NET-007", include_str!("../../docs/issues/NET-007.md")),
+    ("NET-008", include_str!("../../docs/issues/NET-008.md")),
+    ("NET-009", include_str!("../../docs/issues/NET-009.md")),
+    ("NET-010", include_str!("../../docs/issues/NET-010.md")),
+    ("NET-011", include_str!("../../docs/issues/NET-011.md")),
+    ("NET-012", include_str!("../../docs/issues/NET-012.md")),
+    ("NET-013", include_str!("../../docs/issues/NET-013.md")),
+    ("NET-014", include_str!("../../docs/issues/NET-014.md")),
+    ("NET-015", include_str!("../../docs/issues/NET-015.md")),
+    ("NET-016", include_str!("../../docs/issues/NET-016.md")),
+    ("NET-017", include_str!("../../docs/issues/NET-017.md")),
+    ("NET-018", include_str!("../../docs/issues/NET-018.md")),
+    ("NET-019", include_str!("../../docs/issues/NET-019.md")),
+    ("NET-020", include_str!("../../docs/issues/NET-020.md")),
+    ("NET-021", include_str!("../../docs/issues/NET-021.md")),
+    ("NET-022", include_str!("../../docs/issues/NET-022.md")),
+    ("NET-023", include_str!("../../docs/issues/NET-023.md")),
+    ("STO-001", include_str!("../../docs/issues/STO-001.md")),
+    ("STO-002", include_str!("../../docs/issues/STO-002.md")),
+    ("STO-003", include_str!("../../docs/issues/STO-003.md")),
+    ("STO-004", include_str!("../../docs/issues/STO-004.md")),
+    ("STO-005", include_str!("../../docs/issues/STO-005.md")),
+    ("STO-006", include_str!("../../docs/issues/STO-006.md")),
+    ("STO-007", include_str!("../../docs/issues/STO-007.md")),
+    ("STO-008", include_str!("../../docs/issues/STO-008.md")),
+    ("STO-009", include_str!("../../docs/issues/STO-009.md")),
+    ("STO-010", include_str!("../../docs/issues/STO-010.md")),
+    ("STO-011", include_str!("../../docs/issues/STO-011.md")),
+    ("STO-012", include_str!("../../docs/issues/STO-012.md")),
+    ("STO-013", include_str!("../../docs/issues/STO-013.md")),
+    ("STO-014", include_str!("../../docs/issues/STO-014.md")),
+    ("STO-015", include_str!("../../docs/issues/STO-015.md")),
+    ("STO-016", include_str!("../../docs/issues/STO-016.md")),
+    ("SEC-001", include_str!("../../docs/issues/SEC-001.md")),
+    ("SEC-002", include_str!("../../docs/issues/SEC-002.md")),
+    ("SEC-003", include_str!("../../docs/issues/SEC-003.md")),
+    ("SEC-004", include_str!("../../docs/issues/SEC-004.md")),
+    ("SEC-005", include_str!("../../docs/issues/SEC-005.md")),
+    ("SEC-006", include_str!("../../docs/issues/SEC-006.md")),
+    ("SEC-007", include_str!("../../docs/issues/SEC-007.md")),
+    ("SEC-008", include_str!("../../docs/issues/SEC-008.md")),
+    ("SEC-009", include_str!("../../docs/issues/SEC-009.md")),
+    ("CTRL-001", include_str!("../../docs/issues/CTRL-001.md")),
+    ("CTRL-002", include_str!("../../docs/issues/CTRL-002.md")),
+    ("CTRL-003", include_str!("../../docs/issues/CTRL-003.md")),
+    ("CTRL-004", include_str!("../../docs/issues/CTRL-004.md")),
+    ("CTRL-005", include_str!("../../docs/issues/CTRL-005.md")),
+    ("CTRL-006", include_str!("../../docs/issues/CTRL-006.md")),
+    ("CTRL-007", include_str!("../../docs/issues/CTRL-007.md")),
+    ("CTRL-008", include_str!("../../docs/issues/CTRL-008.md")),
+    ("CTRL-009", include_str!("../../docs/issues/CTRL-009.md")),
+    ("CTRL-010", include_str!("../../docs/issues/CTRL-010.md")),
+    ("CTRL-011", include_str!("../../docs/issues/CTRL-011.md")),
+    ("CTRL-012", include_str!("../../docs/issues/CTRL-012.md")),
+    ("CTRL-013", include_str!("../../docs/issues/CTRL-013.md")),
+    ("CTRL-014", include_str!("../../docs/issues/CTRL-014.md")),
+    ("CTRL-015", include_str!("../../docs/issues/CTRL-015.md")),
+    ("CTRL-016", include_str!("../../docs/issues/CTRL-016.md")),
+    ("CTRL-017", include_str!("../../docs/issues/CTRL-017.md")),
+    ("CTRL-018", include_str!("../../docs/issues/CTRL-018.md")),
+    ("CTRL-019", include_str!("../../docs/issues/CTRL-019.md")),
+    ("CTRL-020", include_str!("../../docs/issues/CTRL-020.md")),
+    ("AUTO-001", include_str!("../../docs/issues/AUTO-001.md")),
+    ("AUTO-002", include_str!("../../docs/issues/AUTO-002.md")),
+    ("AUTO-003", include_str!("../../docs/issues/AUTO-003.md")),
+    ("AUTO-004", include_str!("../../docs/issues/AUTO-004.md")),
+    ("AUTO-005", include_str!("../../docs/issues/AUTO-005.md")),
+    ("AUTO-006", include_str!("../../docs/issues/AUTO-006.md")),
+    ("AUTO-007", include_str!("../../docs/issues/AUTO-007.md")),
+    ("AUTO-008", include_str!("../../docs/issues/AUTO-008.md")),
+    ("AUTO-009", include_str!("../../docs/issues/AUTO-009.md")),
+    ("AUTO-010", include_str!("../../docs/issues/AUTO-010.md")),
+    ("AUTO-011", include_str!("../../docs/issues/AUTO-011.md")),
+    ("BATCH-001", include_str!("../../docs/issues/BATCH-001.md")),
+    ("BATCH-002", include_str!("../../docs/issues/BATCH-002.md")),
+    ("BATCH-003", include_str!("../../docs/issues/BATCH-003.md")),
+    ("BATCH-004", include_str!("../../docs/issues/BATCH-004.md")),
+    ("BATCH-005", include_str!("../../docs/issues/BATCH-005.md")),
+    ("POLICY-001", include_str!("../../docs/issues/POLICY-001.md")),
+    ("POLICY-002", include_str!("../../docs/issues/POLICY-002.md")),
+    ("POLICY-003", include_str!("../../docs/issues/POLICY-003.md")),
+    ("POLICY-004", include_str!("../../docs/issues/POLICY-004.md")),
+    ("POLICY-005", include_str!("../../docs/issues/POLICY-005.md")),
+    ("POLICY-006", include_str!("../../docs/issues/POLICY-006.md")),
+    ("POLICY-007", include_str!("../../docs/issues/POLICY-007.md")),
+    ("OBS-001", include_str!("../../docs/issues/OBS-001.md")),
+    ("OBS-002", include_str!("../../docs/issues/OBS-002.md")),
+    ("OBS-003", include_str!("../../docs/issues/OBS-003.md")),
+    ("OBS-004", include_str!("../../docs/issues/OBS-004.md")),
+    ("DBG-001", include_str!("../../docs/issues/DBG-001.md")),
+    ("DBG-002", include_str!("../../docs/issues/DBG-002.md")),
+    ("DBG-003", include_str!("../../docs/issues/DBG-003.md")),
+    ("ORPH-001", include_str!("../../docs/issues/ORPH-001.md")),
+    ("ORPH-002", include_str!("../../docs/issues/ORPH-002.md")),
+    ("ORPH-003", include_str!("../../docs/issues/ORPH-003.md")),
+    ("ORPH-004", include_str!("../../docs/issues/ORPH-004.md")),
+    ("ORPH-005", include_str!("../../docs/issues/ORPH-005.md")),
+    ("ORPH-006", include_str!("../../docs/issues/ORPH-006.md")),
+    ("ORPH-007", include_str!("../../docs/issues/ORPH-007.md")),
+    ("ORPH-008", include_str!("../../docs/issues/ORPH-008.md")),
+    ("ORPH-009", include_str!("../../docs/issues/ORPH-009.md")),
+    ("ORPH-010", include_str!("../../docs/issues/ORPH-010.md")),
+    ("ORPH-011", include_str!("../../docs/issues/ORPH-011.md")),
+    ("ORPH-012", include_str!("../../docs/issues/ORPH-012.md")),
+    ("ORPH-013", include_str!("../../docs/issues/ORPH-013.md")),
+    ("HA-001", include_str!("../../docs/issues/HA-001.md")),
+    ("HA-002", include_str!("../../docs/issues/HA-002.md")),
+    ("HA-003", include_str!("../../docs/issues/HA-003.md")),
+    ("HA-004", include_str!("../../docs/issues/HA-004.md")),
+    ("HA-005", include_str!("../../docs/issues/HA-005.md")),
+    ("HA-006", include_str!("../../docs/issues/HA-006.md")),
+    ("HA-007", include_str!("../../docs/issues/HA-007.md")),
+    ("HA-008", include_str!("../../docs/issues/HA-008.md")),
+    ("HA-009", include_str!("../../docs/issues/HA-009.md")),
+    ("HA-010", include_str!("../../docs/issues/HA-010.md")),
+    ("HA-011", include_str!("../../docs/issues/HA-011.md")),
+    ("HA-012", include_str!("../../docs/issues/HA-012.md")),
+    ("HA-013", include_str!("../../docs/issues/HA-013.md")),
+    ("HA-014", include_str!("../../docs/issues/HA-014.md")),
+    ("HA-015", include_str!("../../docs/issues/HA-015.md")),
+    ("HA-016", include_str!("../../docs/issues/HA-016.md")),
+    ("HA-017", include_str!("../../docs/issues/HA-017.md")),
+    ("STS-001", include_str!("../../docs/issues/STS-001.md")),
+    ("STS-002", include_str!("../../docs/issues/STS-002.md")),
+    ("STS-003", include_str!("../../docs/issues/STS-003.md")),
+    ("STS-004", include_str!("../../docs/issues/STS-004.md")),
+    ("STS-005", include_str!("../../docs/issues/STS-005.md")),
+    ("DS-001", include_str!("../../docs/issues/DS-001.md")),
+    ("DS-002", include_str!("../../docs/issues/DS-002.md")),
+    ("EVT-001", include_str!("../../docs/issues/EVT-001.md")),
+    ("EVT-002", include_str!("../../docs/issues/EVT-002.md")),
+    ("EVT-003", include_str!("../../docs/issues/EVT-003.md")),
+    ("EVT-004", include_str!("../../docs/issues/EVT-004.md")),
+    ("EVT-005", include_str!("../../docs/issues/EVT-005.md")),
+    ("CERT-001", include_str!("../../docs/issues/CERT-001.md")),
+    ("CERT-002", include_str!("../../docs/issues/CERT-002.md")),
+    ("CERT-003", include_str!("../../docs/issues/CERT-003.md")),
+    ("INSP-001", include_str!("../../docs/issues/INSP-001.md")),
+];
+
+/// Every documented issue code, in page order.
+pub fn codes() -> impl Iterator<Item = &'static str> {
+    PAGES.iter().map(|(code, _)| *code)
+}
+
+/// A rule's page broken into the parts `kubeowler explain` prints.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub severity: &'static str,
+    /// What triggers the finding (`## Summary`).
+    pub summary: &'static str,
+    pub symptoms: Option<&'static str>,
+    /// Remediation steps (`## Resolution`).
+    pub resolution: Option<&'static str>,
+    /// `kubectl` commands from the Example code blocks and the Resolution steps.
+    pub commands: Vec<String>,
+}
+
+/// Explanation of `code` (case-insensitive), or None if unknown.
+pub fn explain(code: &str) -> Option<Explanation> {
+    let code = code.trim().to_ascii_uppercase();
+    let (code, text) = PAGES.iter().find(|(c, _)| *c == code)?;
+    let title = issue_codes::short_title(code)?;
+    let mut commands = Vec::new();
+    for cmd in section(text, "Example")
+        .map(fenced_commands)
+        .unwrap_or_default()
+        .into_iter()
+        .chain(
+            section(text, "Resolution")
+                .map(inline_commands)
+                .unwrap_or_default(),
+        )
+    {
+        if !commands.contains(&cmd) {
+            commands.push(cmd);
+        }
+    }
+    Some(Explanation {
+        code,
+        title,
+        severity: section(text, "Severity").unwrap_or("Unknown"),
+        summary: section(text, "Summary").unwrap_or_default(),
+        symptoms: section(text, "Symptoms"),
+        resolution: section(text, "Resolution"),
+        commands,
+    })
+}
+
+/// Trimmed body of the `## <heading>` section; None when missing, empty or "N/A".
+fn section<'a>(page: &'a str, heading: &str) -> Option<&'a str> {
+    let marker = format!("\n## {}\n", heading);
+    let start = page.find(&marker)? + marker.len();
+    let end = page[start..]
+        .find("\n## ")
+        .map_or(page.len(), |i| start + i);
+    let body = page[start..end].trim();
+    (!body.is_empty() && body != "N/A").then_some(body)
+}
+
+/// `kubectl` lines of fenced code blocks, with `\`-continued lines joined.
+fn fenced_commands(text: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut in_block = false;
+    let mut current: Option<String> = None;
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if !in_block {
+            continue;
+        }
+        if let Some(cmd) = current.as_mut() {
+            cmd.push(' ');
+            cmd.push_str(line.trim_end_matches('\\').trim());
+        } else if line.starts_with("kubectl ") {
+            current = Some(line.trim_end_matches('\\').trim().to_string());
+        }
+        if !line.ends_with('\\') {
+            commands.extend(current.take());
+        }
+    }
+    commands
+}
+
+/// Inline code spans that are `kubectl` commands.
+fn inline_commands(text: &str) -> Vec<String> {
+    text.split('`')
+        .skip(1)
+        .step_by(2)
+        .filter(|span| span.starts_with("kubectl "))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Write `README.md` and `<CODE>.md` for every code into `dir` (created if missing); returns the
+/// number of issue pages written.
+pub fn write_bundle(dir: &Path) -> Result<usize> {
+    std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    let write = |name: &str, text: &str| {
+        let path = dir.join(name);
+        std::fs::write(&path, text).with_context(|| format!("writing {}", path.display()))
+    };
+    write("README.md", INDEX)?;
+    for (code, text) in PAGES {
+        write(&format!("{}.md", code), text)?;
+    }
+    Ok(PAGES.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_code_has_a_page_and_titles_match() {
+        for (code, text) in PAGES {
+            let title = issue_codes::short_title(code).expect("page for an unknown code");
+            let heading = text.lines().next().unwrap_or_default();
+            assert!(
+                heading.starts_with(&format!("# {} ", code)),
+                "{} page heading is {:?}",
+                code,
+                heading
+            );
+            assert!(INDEX.contains(&format!("[{}]({}.md) | {} |", code, code, title)));
+        }
+    }
+
+    #[test]
+    fn bundle_has_index_and_pages() {
+        let dir = std::env::temp_dir().join(format!("kubeowler-docs-{}", std::process::id()));
+        let count = write_bundle(&dir).unwrap();
+        assert_eq!(count, PAGES.len());
+        assert!(dir.join("README.md").exists());
+        let page = std::fs::read_to_string(dir.join("POD-007.md")).unwrap();
+        assert!(page.starts_with("# POD-007 CrashLoopBackOff"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn explain_extracts_sections_and_commands() {
+        let e = explain("evt-003").unwrap();
+        assert_eq!(e.code, "EVT-003");
+        assert_eq!(e.title, "Sustained volume mount/attach failures");
+        assert_eq!(e.severity, "Critical");
+        assert!(e
+            .summary
+            .starts_with("A pod has repeatedly failed to mount"));
+        assert!(e
+            .resolution
+            .unwrap()
+            .starts_with("1. Check that the referenced Secret"));
+        assert_eq!(
+            e.commands,
+            vec![
+                "kubectl get events -n app --field-selector involvedObject.name=web-7c9d8,reason=FailedMount",
+                "kubectl get volumeattachments | grep <pv-name>",
+            ]
+        );
+        assert!(explain("POD-999").is_none());
+
+        let block =
+            "```bash\nkubectl get deploy web \\\n  -o json\necho done\n```\nkubectl outside";
+        assert_eq!(
+            fenced_commands(block),
+            vec!["kubectl get deploy web -o json"]
+        );
+        assert_eq!(
+            inline_commands(
+                "Run `kubectl get csr` then `approve`, or `kubectl certificate approve <name>`"
+            ),
+            vec!["kubectl get csr", "kubectl certificate approve <name>"]
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Kubelet configuration checks over the effective config each kubelet reports on `/configz`
+//! (config file, flags and defaults merged): anonymous access, hard eviction thresholds, image
+//! garbage collection and `maxPods` against the pods actually running on the node.
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity, NodeRow};
+use crate::k8s::KubeletConfig;
+
+/// Hard eviction signals every kubelet should keep; without them the node OOMs or fills up
+/// before pods are evicted.
+const REQUIRED_EVICTION_SIGNALS: [&str; 2] = ["memory.available", "nodefs.available"];
+/// imageGCHighThresholdPercent at or above which image GC starts after nodefs eviction (90% used).
+const IMAGE_GC_HIGH_MAX_PCT: i32 = 90;
+/// Pods per node Kubernetes is tested with; higher maxPods needs matching CNI IPs and kubelet sizing.
+pub const TESTED_MAX_PODS: i32 = 110;
+
+/// A threshold of "0", "0%" or "0Mi" disables the signal.
+fn is_zero_threshold(value: &str) -> bool {
+    value
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '%')
+        .parse::<f64>()
+        .map(|v| v == 0.0)
+        .unwrap_or(false)
+}
+
+/// NODE-019..NODE-022 findings of one kubelet.
+fn kubelet_findings(node: &str, config: &KubeletConfig, pod_count: Option<u32>) -> Vec<Issue> {
+    let mut out = Vec::new();
+
+    let always_allow = config.authorization.mode.as_deref() == Some("AlwaysAllow");
+    if config.authentication.anonymous.enabled == Some(true) {
+        let (severity, authz) = if always_allow {
+            (
+                IssueSeverity::Critical,
+                " and authorizes every request (AlwaysAllow)",
+            )
+        } else {
+            (IssueSeverity::Warning, "")
+        };
+        out.push(Issue::new(
+            severity,
+            "Node",
+            "NODE-019",
+            format!("Kubelet on {} accepts anonymous requests{}", node, authz),
+            node.to_string(),
+            "Set authentication.anonymous.enabled: false and authorization.mode: Webhook in the kubelet config.",
+        ));
+    }
+
+    let eviction = config.eviction_hard.as_ref();
+    let missing: Vec<&str> = REQUIRED_EVICTION_SIGNALS
+        .iter()
+        .copied()
+        .filter(|signal| {
+            eviction
+                .and_then(|e| e.get(*signal))
+                .is_none_or(|v| is_zero_threshold(v))
+        })
+        .collect();
+    if !missing.is_empty() {
+        out.push(Issue {
+            key_attributes: missing.iter().map(|s| format!("signal={}", s)).collect(),
+            ..Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NODE-020",
+                format!(
+                    "Kubelet on {} has no hard eviction threshold for {}",
+                    node,
+                    missing.join(", ")
+                ),
+                node.to_string(),
+                "Set evictionHard (e.g. memory.available: 100Mi, nodefs.available: 10%) in the kubelet config.",
+            )
+        });
+    }
+
+    let high = config.image_gc_high_threshold_percent;
+    let low = config.image_gc_low_threshold_percent;
+    let gc_problem = match (high, low) {
+        (Some(h), _) if h >= IMAGE_GC_HIGH_MAX_PCT => Some(format!(
+            "imageGCHighThresholdPercent {} (>= {}: image GC starts after nodefs eviction)",
+            h, IMAGE_GC_HIGH_MAX_PCT
+        )),
+        (Some(h), Some(l)) if l >= h => Some(format!(
+            "imageGCLowThresholdPercent {} is not below imageGCHighThresholdPercent {}",
+            l, h
+        )),
+        _ => None,
+    };
+    if let Some(problem) = gc_problem {
+        out.push(Issue::new(
+            IssueSeverity::Warning,
+            "Node",
+            "NODE-021",
+            format!("Kubelet on {} image GC misconfigured: {}", node, problem),
+            node.to_string(),
+            "Keep imageGCHighThresholdPercent below 90 (default 85) and the low threshold below it (default 80).",
+        ));
+    }
+
+    if let Some(max_pods) = config.max_pods.filter(|m| *m > TESTED_MAX_PODS) {
+        let density = pod_count
+            .map(|c| format!(" ({} pods running)", c))
+            .unwrap_or_default();
+        out.push(Issue::new(
+            IssueSeverity::Info,
+            "Node",
+            "NODE-022",
+            format!(
+                "Kubelet on {} allows maxPods {}, above the tested {}{}",
+                node, max_pods, TESTED_MAX_PODS, density
+            ),
+            node.to_string(),
+            "Verify CNI IP capacity, kubelet/runtime sizing and kube-reserved for this density, or lower maxPods.",
+        ));
+    }
+    out
+}
+
+/// Compute the "Kubelet Configuration" check and NODE-019..NODE-022 issues from the `/configz`
+/// of each node. Returns None when no kubelet config could be read.
+pub fn evaluate_kubelet_configs(
+    configs: &[(String, KubeletConfig)],
+    nodes: &[NodeRow],
+) -> Option<(CheckResult, Vec<Issue>)> {
+    if configs.is_empty() {
+        return None;
+    }
+    let mut issues = Vec::new();
+    let mut affected = 0usize;
+    let mut density = Vec::new();
+    for (node, config) in configs {
+        let pod_count = nodes.iter().find(|n| &n.name == node).map(|n| n.pod_count);
+        if let (Some(count), Some(max)) = (pod_count, config.max_pods) {
+            density.push(format!("{} {}/{}", node, count, max));
+        }
+        let findings = kubelet_findings(node, config, pod_count);
+        if !findings.is_empty() {
+            affected += 1;
+        }
+        issues.extend(findings);
+    }
+
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if issues.iter().any(|i| i.severity == IssueSeverity::Warning) {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let mut details = format!(
+        "{} of {} kubelet(s) with configuration findings",
+        affected,
+        configs.len()
+    );
+    if !density.is_empty() {
+        details.push_str(&format!("; pods/maxPods: {}", density.join(", ")));
+    }
+    let check = CheckResult {
+        name: "Kubelet Configuration".to_string(),
+        description:
+            "Kubelet anonymous auth, eviction thresholds, image GC and maxPods from /configz"
+                .to_string(),
+        status,
+        score: ((configs.len() - affected) as f64 / configs.len() as f64) * 100.0,
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec![
+                "See NODE-019..NODE-022 and fix the kubelet config of the listed nodes."
+                    .to_string(),
+            ]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(json: serde_json::Value) -> KubeletConfig {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn node_row(name: &str, pods: u32) -> NodeRow {
+        NodeRow {
+            name: name.to_string(),
+            operating_system: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            kubelet_version: "v1.29.0".to_string(),
+            ready: true,
+            pod_count: pods,
+            allocatable_pods: None,
+            node_address: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime_version: None,
+            node_pool: None,
+            zone: None,
+            spot: false,
+        }
+    }
+
+    #[test]
+    fn flags_risky_kubelet_settings() {
+        let risky = config(serde_json::json!({
+            "authentication": {"anonymous": {"enabled": true}},
+            "authorization": {"mode": "AlwaysAllow"},
+            "evictionHard": {"memory.available": "0", "imagefs.available": "15%"},
+            "imageGCHighThresholdPercent": 95,
+            "imageGCLowThresholdPercent": 80,
+            "maxPods": 250
+        }));
+        let (check, issues) =
+            evaluate_kubelet_configs(&[("n1".to_string(), risky)], &[node_row("n1", 40)]).unwrap();
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["NODE-019", "NODE-020", "NODE-021", "NODE-022"]);
+        assert_eq!(issues[0].severity, IssueSeverity::Critical);
+        assert!(issues[1]
+            .description
+            .ends_with("for memory.available, nodefs.available"));
+        assert!(issues[3].description.ends_with("(40 pods running)"));
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert!(check.details.unwrap().ends_with("pods/maxPods: n1 40/250"));
+    }
+
+    #[test]
+    fn default_kubelet_config_passes() {
+        let defaults = config(serde_json::json!({
+            "authentication": {"anonymous": {"enabled": false}},
+            "authorization": {"mode": "Webhook"},
+            "evictionHard": {"memory.available": "100Mi", "nodefs.available": "10%"},
+            "imageGCHighThresholdPercent": 85,
+            "imageGCLowThresholdPercent": 80,
+            "maxPods": 110
+        }));
+        let (check, issues) =
+            evaluate_kubelet_configs(&[("n1".to_string(), defaults)], &[]).unwrap();
+        assert!(issues.is_empty());
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(evaluate_kubelet_configs(&[], &[]).is_none());
+    }
+}
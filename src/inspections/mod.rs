@@ -1,21 +1,67 @@
 pub mod autoscaling;
 pub mod batch;
 pub mod certificates;
+pub mod check_filter;
+pub mod config_refs;
+pub mod connectivity_probes;
 pub mod control_plane;
+pub mod controllers;
+pub mod daemonsets;
+pub mod debug_settings;
+pub mod dedup;
+pub mod etcd_objects;
+pub mod events;
+pub mod gpu;
+pub mod hpa_targets;
+pub mod ingress_controllers;
 pub mod issue_codes;
+pub mod issue_docs;
+pub mod kubelet_config;
+pub mod multi_arch;
+pub mod namespace_lifecycle;
 pub mod namespace_summary;
 pub mod network;
+pub mod node_capacity;
+pub mod node_density;
+pub mod node_hardening;
+pub mod node_heartbeat;
+pub mod node_hygiene;
+pub mod node_images;
+pub mod node_networking;
+pub mod node_overcommit;
+pub mod node_pools;
+pub mod node_pressure;
+pub mod node_storage;
 pub mod nodes;
+pub mod notice;
 pub mod observability;
+pub mod options;
+pub mod orphans;
 pub mod pods;
 pub mod policies;
+pub mod priority;
+pub mod probes;
+pub mod provider;
+pub mod pvc_usage;
+pub mod qos;
+pub mod registry;
+pub mod replica_conflicts;
+pub mod resilience;
 pub mod resources;
 pub mod runner;
+pub mod sampling;
 pub mod security;
+pub mod service_refs;
+pub mod shutdown;
+pub mod statefulsets;
 pub mod storage;
+pub mod storage_classes;
+pub mod stuck_deletions;
 pub mod types;
 pub mod upgrade;
+pub mod windows;
 
+pub use options::InspectionOptions;
 pub use runner::InspectionRunner;
 #[allow(unused_imports)]
 pub use types::*;
@@ -0,0 +1,403 @@
+//! Image architecture compatibility on clusters mixing CPU architectures (amd64 + arm64). A
+//! workload can be scheduled on any architecture unless it selects one with a nodeSelector or
+//! required node affinity on `kubernetes.io/arch`; its images must then be published for every
+//! architecture in the cluster. With `--registry-lookup` the manifest lists of public images are
+//! read, so single-arch images are reported (HA-011); otherwise unpinned workloads are (HA-010).
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use k8s_openapi::api::core::v1::{Node, Pod, PodSpec};
+
+use crate::inspections::controllers::{Controller, PodOwners};
+use crate::inspections::types::*;
+
+const ARCH_LABEL: &str = "kubernetes.io/arch";
+const LEGACY_ARCH_LABEL: &str = "beta.kubernetes.io/arch";
+
+/// Controller kinds whose pods must run on whichever architecture they are scheduled to.
+const WORKLOAD_KINDS: &[&str] = &["Deployment", "StatefulSet", "DaemonSet"];
+
+/// Architectures of the nodes in the cluster (`kubernetes.io/arch`, else `nodeInfo`).
+pub fn node_architectures(nodes: &[Node]) -> BTreeSet<String> {
+    nodes
+        .iter()
+        .filter_map(|n| {
+            n.metadata
+                .labels
+                .as_ref()
+                .and_then(|l| l.get(ARCH_LABEL))
+                .cloned()
+                .or_else(|| {
+                    n.status
+                        .as_ref()?
+                        .node_info
+                        .as_ref()
+                        .map(|i| i.architecture.clone())
+                })
+                .filter(|a| !a.is_empty())
+        })
+        .collect()
+}
+
+/// Architectures a pod is restricted to by nodeSelector or required node affinity (`In`), or
+/// None when it may run on any.
+fn pinned_architectures(spec: &PodSpec) -> Option<BTreeSet<String>> {
+    let is_arch = |key: &str| key == ARCH_LABEL || key == LEGACY_ARCH_LABEL;
+    if let Some(arch) = spec
+        .node_selector
+        .as_ref()
+        .and_then(|s| s.iter().find(|(k, _)| is_arch(k)).map(|(_, v)| v))
+    {
+        return Some(BTreeSet::from([arch.clone()]));
+    }
+    let archs: BTreeSet<String> = spec
+        .affinity
+        .as_ref()?
+        .node_affinity
+        .as_ref()?
+        .required_during_scheduling_ignored_during_execution
+        .as_ref()?
+        .node_selector_terms
+        .iter()
+        .flat_map(|t| t.match_expressions.iter().flatten())
+        .filter(|e| is_arch(&e.key) && e.operator == "In")
+        .flat_map(|e| e.values.iter().flatten().cloned())
+        .collect();
+    (!archs.is_empty()).then_some(archs)
+}
+
+fn pod_images(spec: &PodSpec) -> BTreeSet<String> {
+    spec.containers
+        .iter()
+        .chain(spec.init_containers.iter().flatten())
+        .filter_map(|c| c.image.clone())
+        .collect()
+}
+
+/// One pod spec per Deployment, StatefulSet and DaemonSet.
+fn workload_specs<'a>(
+    pods: &'a [Pod],
+    owners: &'a PodOwners,
+) -> BTreeMap<&'a Controller, &'a PodSpec> {
+    let mut specs = BTreeMap::new();
+    for pod in pods {
+        let key = format!(
+            "{}/{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            pod.metadata.name.as_deref().unwrap_or_default()
+        );
+        let (Some(controller), Some(spec)) = (owners.controller_of(&key), pod.spec.as_ref()) else {
+            continue;
+        };
+        if WORKLOAD_KINDS.contains(&controller.kind.as_str()) {
+            specs.entry(controller).or_insert(spec);
+        }
+    }
+    specs
+}
+
+/// Images of every Deployment, StatefulSet and DaemonSet, to resolve with `--registry-lookup`.
+/// Empty on single-architecture clusters.
+pub fn images_to_resolve(nodes: &[Node], pods: &[Pod], owners: &PodOwners) -> BTreeSet<String> {
+    if node_architectures(nodes).len() < 2 {
+        return BTreeSet::new();
+    }
+    workload_specs(pods, owners)
+        .values()
+        .flat_map(|spec| pod_images(spec))
+        .collect()
+}
+
+/// HA-011 for workloads with an image (per `platforms`) missing an architecture they can be
+/// scheduled on; HA-010 for Deployments/StatefulSets without an architecture selector whose
+/// images are not all known to be multi-arch.
+pub fn arch_compatibility(
+    nodes: &[Node],
+    pods: &[Pod],
+    owners: &PodOwners,
+    platforms: &HashMap<String, BTreeSet<String>>,
+    registry_lookup: bool,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let name = "Multi-arch Image Compatibility";
+    let description =
+        "Workloads on mixed-architecture clusters use multi-arch images or select an architecture";
+    let cluster_archs = node_architectures(nodes);
+    if cluster_archs.len() < 2 {
+        return CheckResult {
+            name: name.to_string(),
+            description: description.to_string(),
+            status: CheckStatus::Pass,
+            score: 100.0,
+            max_score: 100.0,
+            details: Some(format!(
+                "Single node architecture ({})",
+                cluster_archs
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| "-".to_string())
+            )),
+            recommendations: vec![],
+        };
+    }
+    let arch_list = cluster_archs.iter().cloned().collect::<Vec<_>>().join("/");
+
+    let mut evaluated = 0;
+    let mut incompatible = 0;
+    let mut unpinned = 0;
+    for (controller, spec) in workload_specs(pods, owners) {
+        evaluated += 1;
+        let pinned = pinned_architectures(spec);
+        let needed = pinned.as_ref().unwrap_or(&cluster_archs);
+        let images = pod_images(spec);
+        let workload = format!(
+            "{} {}/{}",
+            controller.kind, controller.namespace, controller.name
+        );
+        let resource = Some(format!("{}/{}", controller.namespace, controller.name));
+
+        let missing: Vec<String> = images
+            .iter()
+            .filter_map(|image| {
+                let published = platforms.get(image)?;
+                let absent: Vec<&str> = needed.difference(published).map(String::as_str).collect();
+                (!absent.is_empty()).then(|| {
+                    format!(
+                        "{} (published for {}, missing {})",
+                        image,
+                        published.iter().cloned().collect::<Vec<_>>().join("/"),
+                        absent.join("/")
+                    )
+                })
+            })
+            .collect();
+        if !missing.is_empty() {
+            incompatible += 1;
+            issues.push(Issue {
+                severity: IssueSeverity::Critical,
+                category: controller.kind.clone(),
+                description: format!(
+                    "{} can be scheduled on {} nodes but uses single-arch image(s): {}",
+                    workload,
+                    needed.iter().cloned().collect::<Vec<_>>().join("/"),
+                    missing.join(", ")
+                ),
+                resource,
+                recommendation: format!(
+                    "Publish a multi-arch image or add nodeSelector {}: <arch> for the published architecture.",
+                    ARCH_LABEL
+                ),
+                rule_id: Some("HA-011".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: vec![format!("kind={}", controller.kind)],
+                remediation_commands: Vec::new(),
+            });
+            continue;
+        }
+
+        let all_multi_arch = images.iter().all(|image| {
+            platforms
+                .get(image)
+                .is_some_and(|published| cluster_archs.is_subset(published))
+        });
+        if pinned.is_none() && controller.kind != "DaemonSet" && !all_multi_arch {
+            unpinned += 1;
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: controller.kind.clone(),
+                description: format!(
+                    "{} has no {} nodeSelector or affinity on a {} cluster",
+                    workload, ARCH_LABEL, arch_list
+                ),
+                resource,
+                recommendation: format!(
+                    "Confirm the images are multi-arch (--registry-lookup) or pin the workload with nodeSelector {}: <arch>.",
+                    ARCH_LABEL
+                ),
+                rule_id: Some("HA-010".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: vec![format!("kind={}", controller.kind)],
+                remediation_commands: Vec::new(),
+            });
+        }
+    }
+
+    let flagged = incompatible + unpinned;
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if incompatible > 0 {
+            CheckStatus::Critical
+        } else if unpinned > 0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Pass
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "Node architectures {}; {} single-arch, {} unpinned of {} workload(s); {}",
+            arch_list,
+            incompatible,
+            unpinned,
+            evaluated,
+            if registry_lookup {
+                format!("{} image(s) resolved from registries", platforms.len())
+            } else {
+                "registry lookup disabled".to_string()
+            }
+        )),
+        recommendations: if flagged > 0 {
+            vec![
+                "Use multi-arch images or select an architecture (see HA-010, HA-011).".to_string(),
+            ]
+        } else {
+            vec![]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Affinity, Container, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, arch: &str) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some([(ARCH_LABEL.to_string(), arch.to_string())].into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn pod(name: &str, kind: &str, owner: &str, image: &str, spec: PodSpec) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("shop".to_string()),
+                owner_references: Some(vec![OwnerReference {
+                    kind: kind.to_string(),
+                    name: owner.to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..spec
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_unpinned_and_single_arch_workloads_on_mixed_clusters() {
+        let nodes = vec![node("a", "amd64"), node("b", "arm64")];
+        let arm_affinity = PodSpec {
+            affinity: Some(Affinity {
+                node_affinity: Some(NodeAffinity {
+                    required_during_scheduling_ignored_during_execution: Some(NodeSelector {
+                        node_selector_terms: vec![NodeSelectorTerm {
+                            match_expressions: Some(vec![NodeSelectorRequirement {
+                                key: ARCH_LABEL.to_string(),
+                                operator: "In".to_string(),
+                                values: Some(vec!["arm64".to_string()]),
+                            }]),
+                            ..Default::default()
+                        }],
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pods = vec![
+            pod("api-0", "StatefulSet", "api", "api:1", PodSpec::default()),
+            pod(
+                "web-0",
+                "StatefulSet",
+                "web",
+                "nginx:1.25",
+                PodSpec::default(),
+            ),
+            pod(
+                "agent-x",
+                "DaemonSet",
+                "agent",
+                "agent:2",
+                PodSpec::default(),
+            ),
+            pod("arm-0", "StatefulSet", "arm", "legacy:1", arm_affinity),
+        ];
+        let owners = PodOwners::from_objects(&pods, &[], &[]);
+        assert_eq!(images_to_resolve(&nodes, &pods, &owners).len(), 4);
+
+        // Without registry data only the unpinned StatefulSets are reported.
+        let mut issues = Vec::new();
+        let check = arch_compatibility(&nodes, &pods, &owners, &HashMap::new(), false, &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        let codes: Vec<_> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(codes, vec![("HA-010", "shop/api"), ("HA-010", "shop/web")]);
+
+        let both = BTreeSet::from(["amd64".to_string(), "arm64".to_string()]);
+        let amd = BTreeSet::from(["amd64".to_string()]);
+        let platforms: HashMap<String, BTreeSet<String>> = [
+            ("nginx:1.25".to_string(), both),
+            ("agent:2".to_string(), amd.clone()),
+            ("legacy:1".to_string(), amd),
+        ]
+        .into();
+        let mut issues = Vec::new();
+        let check = arch_compatibility(&nodes, &pods, &owners, &platforms, true, &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        let codes: Vec<_> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("HA-011", "shop/agent"),
+                ("HA-010", "shop/api"),
+                ("HA-011", "shop/arm")
+            ]
+        );
+
+        let mut issues = Vec::new();
+        let check = arch_compatibility(&nodes[..1], &pods, &owners, &platforms, true, &mut issues);
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(issues.is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+//! Namespace lifecycle: namespaces stuck in Terminating, named with what blocks the deletion (a
+//! failing API group, remaining content or finalizers), namespaces that have held no workloads
+//! for longer than `--empty-namespace-days`, and namespaces missing the labels the organisation
+//! requires (`--required-namespace-labels`, e.g. owner or cost-center).
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Namespace;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Default age (days) after which a namespace without workloads is reported (POLICY-006).
+pub const DEFAULT_EMPTY_NAMESPACE_DAYS: u32 = 30;
+
+/// Namespace conditions set by the namespace controller while a deletion cannot finish.
+const BLOCKING_CONDITIONS: [&str; 5] = [
+    "NamespaceDeletionDiscoveryFailure",
+    "NamespaceDeletionGroupVersionParsingFailure",
+    "NamespaceDeletionContentFailure",
+    "NamespaceContentRemaining",
+    "NamespaceFinalizersRemaining",
+];
+
+/// Namespaces created by Kubernetes itself; they are never reported as empty or unlabeled.
+fn is_builtin(name: &str) -> bool {
+    name == "default" || name.starts_with("kube-")
+}
+
+/// What keeps a Terminating namespace from being removed, from its True deletion conditions, or
+/// its remaining spec finalizers when the controller reported none. The second value is true
+/// when API discovery fails, which blocks every namespace deletion in the cluster.
+fn blocking(ns: &Namespace) -> (String, bool) {
+    let status = ns.status.as_ref();
+    let conditions: Vec<_> = status
+        .and_then(|s| s.conditions.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|c| c.status == "True" && BLOCKING_CONDITIONS.contains(&c.type_.as_str()))
+        .collect();
+    let discovery = conditions
+        .iter()
+        .any(|c| c.type_ == "NamespaceDeletionDiscoveryFailure");
+    if !conditions.is_empty() {
+        let reasons: Vec<String> = conditions
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}: {}",
+                    c.type_,
+                    c.message.as_deref().unwrap_or("no message")
+                )
+            })
+            .collect();
+        return (reasons.join("; "), discovery);
+    }
+    let finalizers = ns
+        .spec
+        .as_ref()
+        .and_then(|s| s.finalizers.as_deref())
+        .unwrap_or(&[]);
+    if finalizers.is_empty() {
+        ("no blocking condition reported".to_string(), false)
+    } else {
+        (format!("finalizers {}", finalizers.join(", ")), false)
+    }
+}
+
+/// "Namespace Lifecycle" check over `namespaces`. POLICY-005 per namespace Terminating for
+/// `stuck_minutes` or more, with the blocking API or finalizer (Critical when API discovery fails).
+/// POLICY-006 (Info) per namespace older than `empty_days` (0 disables it) that is not in
+/// `occupied`, the namespaces holding pods, workloads, Services or PVCs; None when those were
+/// not listed. POLICY-007 per namespace missing one of `required_labels`. Built-in namespaces
+/// (`default`, `kube-*`) are only checked for POLICY-005.
+pub fn namespace_lifecycle(
+    namespaces: &[Namespace],
+    occupied: Option<&BTreeSet<String>>,
+    required_labels: &[String],
+    empty_days: u32,
+    stuck_minutes: u32,
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut flagged = 0usize;
+    let mut critical = false;
+    for ns in namespaces {
+        let Some(name) = ns.metadata.name.as_deref() else {
+            continue;
+        };
+        let before = issues.len();
+        let deleted = ns.metadata.deletion_timestamp.as_ref().map(|t| t.0);
+        let terminating = deleted.is_some()
+            || ns.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Terminating");
+        if terminating {
+            let minutes = deleted.map(|t| (now - t).num_minutes());
+            if minutes.is_none_or(|m| m >= i64::from(stuck_minutes)) {
+                let (reason, discovery) = blocking(ns);
+                critical |= discovery;
+                issues.push(Issue::new(
+                    if discovery {
+                        IssueSeverity::Critical
+                    } else {
+                        IssueSeverity::Warning
+                    },
+                    "Namespace",
+                    "POLICY-005",
+                    format!(
+                        "Namespace {} has been Terminating for {}; blocked by {}",
+                        name,
+                        minutes.map_or("an unknown time".to_string(), |m| {
+                            if m >= 120 {
+                                format!("{} hours", m / 60)
+                            } else {
+                                format!("{} minutes", m)
+                            }
+                        }),
+                        reason
+                    ),
+                    name.to_string(),
+                    "Fix the blocking cause: repair or delete an unavailable APIService, or remove the finalizers of the remaining objects once their controller is gone.",
+                ));
+            }
+            if issues.len() > before {
+                flagged += 1;
+            }
+            continue;
+        }
+        if is_builtin(name) {
+            continue;
+        }
+
+        let age_days = ns
+            .metadata
+            .creation_timestamp
+            .as_ref()
+            .map(|t| (now - t.0).num_days());
+        if let (Some(occupied), Some(age)) = (occupied, age_days) {
+            if empty_days > 0 && age >= i64::from(empty_days) && !occupied.contains(name) {
+                issues.push(Issue::new(
+                    IssueSeverity::Info,
+                    "Namespace",
+                    "POLICY-006",
+                    format!(
+                        "Namespace {} is {} days old and has no pods, workloads, Services or PVCs",
+                        name, age
+                    ),
+                    name.to_string(),
+                    "Delete the namespace if it is no longer used, or record its purpose in a label or annotation.",
+                ));
+            }
+        }
+
+        let labels = ns.metadata.labels.as_ref();
+        let missing: Vec<&str> = required_labels
+            .iter()
+            .filter(|key| labels.is_none_or(|l| !l.contains_key(key.as_str())))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            issues.push(Issue::new(
+                IssueSeverity::Warning,
+                "Namespace",
+                "POLICY-007",
+                format!(
+                    "Namespace {} is missing required label(s) {}",
+                    name,
+                    missing.join(", ")
+                ),
+                name.to_string(),
+                "Add the labels (kubectl label namespace <name> key=value) and enforce them at admission.",
+            ));
+        }
+        if issues[before..]
+            .iter()
+            .any(|i| i.severity != IssueSeverity::Info)
+        {
+            flagged += 1;
+        }
+    }
+    let evaluated = namespaces.len();
+    CheckResult {
+        name: "Namespace Lifecycle".to_string(),
+        description: "Namespaces are not stuck Terminating, left empty or missing required labels"
+            .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if critical {
+            CheckStatus::Critical
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} namespaces without lifecycle findings",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See POLICY-005..POLICY-007.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use k8s_openapi::api::core::v1::{NamespaceCondition, NamespaceSpec, NamespaceStatus};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use kube::api::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn namespace(name: &str, created: DateTime<Utc>, labels: &[&str]) -> Namespace {
+        Namespace {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                creation_timestamp: Some(Time(created)),
+                labels: Some(
+                    labels
+                        .iter()
+                        .map(|l| (l.to_string(), "x".to_string()))
+                        .collect::<BTreeMap<_, _>>(),
+                ),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_stuck_empty_and_unlabeled_namespaces() {
+        let now = Utc::now();
+        let mut stuck = namespace("old-team", now - Duration::days(400), &["owner"]);
+        stuck.metadata.deletion_timestamp = Some(Time(now - Duration::hours(5)));
+        stuck.spec = Some(NamespaceSpec {
+            finalizers: Some(vec!["kubernetes".to_string()]),
+        });
+        stuck.status = Some(NamespaceStatus {
+            phase: Some("Terminating".to_string()),
+            conditions: Some(vec![NamespaceCondition {
+                type_: "NamespaceDeletionDiscoveryFailure".to_string(),
+                status: "True".to_string(),
+                message: Some(
+                    "Discovery failed for some groups, 1 failing: metrics.k8s.io/v1beta1: the server is currently unable to handle the request"
+                        .to_string(),
+                ),
+                ..Default::default()
+            }]),
+        });
+        let mut leaving = namespace("leaving", now - Duration::days(400), &[]);
+        leaving.metadata.deletion_timestamp = Some(Time(now - Duration::minutes(2)));
+        let namespaces = vec![
+            stuck,
+            leaving,
+            namespace("shop", now - Duration::days(90), &["owner"]),
+            namespace("sandbox", now - Duration::days(90), &[]),
+            namespace("kube-system", now - Duration::days(400), &[]),
+        ];
+        let occupied = BTreeSet::from(["shop".to_string()]);
+        let mut issues = Vec::new();
+        let check = namespace_lifecycle(
+            &namespaces,
+            Some(&occupied),
+            &["owner".to_string()],
+            DEFAULT_EMPTY_NAMESPACE_DAYS,
+            crate::inspections::stuck_deletions::DEFAULT_STUCK_DELETION_MINUTES,
+            now,
+            &mut issues,
+        );
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("POLICY-005", "old-team"),
+                ("POLICY-006", "sandbox"),
+                ("POLICY-007", "sandbox"),
+            ]
+        );
+        assert_eq!(issues[0].severity, IssueSeverity::Critical);
+        assert!(issues[0]
+            .description
+            .contains("Terminating for 5 hours; blocked by NamespaceDeletionDiscoveryFailure: Discovery failed"));
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("3/5 namespaces without lifecycle findings")
+        );
+    }
+}
@@ -44,47 +44,64 @@ impl<'a> NamespaceSummaryInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: Some(rows),
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
     async fn collect_namespace_summary(&self) -> Result<Vec<NamespaceSummaryRow>> {
         let ns_api = self.client.namespaces();
-        let ns_list = ns_api.list(&ListParams::default()).await?;
-        let mut rows = Vec::new();
-        for ns in &ns_list.items {
-            let name = ns.metadata.name.as_deref().unwrap_or("").to_string();
-            if name.is_empty() {
-                continue;
-            }
-            let pods_api = self.client.pods(Some(&name));
-            let pods = pods_api.list(&ListParams::default()).await?;
-            let pod_count = pods.items.len() as u32;
+        let ns_list = self
+            .client
+            .list_all(&ns_api, &ListParams::default())
+            .await?;
+        let names = ns_list
+            .items
+            .into_iter()
+            .filter_map(|ns| ns.metadata.name)
+            .filter(|name| !name.is_empty());
+        self.client
+            .fan_out(names, |name| self.namespace_row(name))
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Counts and policy coverage of one namespace (five LISTs).
+    async fn namespace_row(&self, name: String) -> Result<NamespaceSummaryRow> {
+        let lp = ListParams::default();
+        let pods_api = self.client.pods(Some(&name));
+        let pod_count = self.client.list_all(&pods_api, &lp).await?.items.len() as u32;
 
-            let deployments_api = self.client.deployments(Some(&name));
-            let deployments = deployments_api.list(&ListParams::default()).await?;
-            let deployment_count = deployments.items.len() as u32;
+        let deployments_api = self.client.deployments(Some(&name));
+        let deployment_count = self
+            .client
+            .list_all(&deployments_api, &lp)
+            .await?
+            .items
+            .len() as u32;
 
-            let np_api = self.client.network_policies(Some(&name));
-            let nps = np_api.list(&ListParams::default()).await?;
-            let has_network_policy = !nps.items.is_empty();
+        let np_api = self.client.network_policies(Some(&name));
+        let has_network_policy = !self
+            .client
+            .list_unselected(&np_api, &lp)
+            .await?
+            .items
+            .is_empty();
 
-            let rq_api: Api<ResourceQuota> = Api::namespaced(self.client.client().clone(), &name);
-            let rqs = rq_api.list(&ListParams::default()).await?;
-            let has_resource_quota = !rqs.items.is_empty();
+        let rq_api: Api<ResourceQuota> = Api::namespaced(self.client.client().clone(), &name);
+        let has_resource_quota = !self.client.list_all(&rq_api, &lp).await?.items.is_empty();
 
-            let lr_api: Api<LimitRange> = Api::namespaced(self.client.client().clone(), &name);
-            let lrs = lr_api.list(&ListParams::default()).await?;
-            let has_limit_range = !lrs.items.is_empty();
+        let lr_api: Api<LimitRange> = Api::namespaced(self.client.client().clone(), &name);
+        let has_limit_range = !self.client.list_all(&lr_api, &lp).await?.items.is_empty();
 
-            rows.push(NamespaceSummaryRow {
-                name,
-                pod_count,
-                deployment_count,
-                has_network_policy,
-                has_resource_quota,
-                has_limit_range,
-            });
-        }
-        Ok(rows)
+        Ok(NamespaceSummaryRow {
+            name,
+            pod_count,
+            deployment_count,
+            has_network_policy,
+            has_resource_quota,
+            has_limit_range,
+        })
     }
 }
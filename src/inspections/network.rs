@@ -1,18 +1,30 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use chrono::Utc;
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::Service;
 use kube::api::ListParams;
 use log::info;
 
+use crate::inspections::ingress_controllers::{self, ReferencedObjects};
+use crate::inspections::node_networking;
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::service_refs;
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
+/// Time allowed for resolving one ExternalName target (NET-023).
+const EXTERNAL_NAME_TIMEOUT: Duration = Duration::from_secs(2);
+
 pub struct NetworkInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
 }
 
 impl<'a> NetworkInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
@@ -23,7 +35,10 @@ impl<'a> NetworkInspector<'a> {
 
         // Check services
         let services_api = self.client.services(namespace);
-        let services = services_api.list(&ListParams::default()).await?;
+        let services = self
+            .client
+            .list_all(&services_api, &ListParams::default())
+            .await?;
 
         let mut total_services = 0;
         let mut services_with_endpoints = 0;
@@ -59,6 +74,10 @@ impl<'a> NetworkInspector<'a> {
                                         resource: Some(format!("{}/{}", service_namespace, service_name)),
                                         recommendation: "Check LoadBalancer configuration and cloud provider settings".to_string(),
                                         rule_id: Some("NET-001".to_string()),
+                                        team: None,
+                                        fingerprint: None,
+                                        key_attributes: Vec::new(),
+                                        remediation_commands: Vec::new(),
                                     });
                                 }
                             }
@@ -79,6 +98,10 @@ impl<'a> NetworkInspector<'a> {
                                             resource: Some(format!("{}/{}", service_namespace, service_name)),
                                             recommendation: "Use NodePort in range 30000-32767".to_string(),
                                             rule_id: Some("NET-002".to_string()),
+                                            team: None,
+                                            fingerprint: None,
+                                            key_attributes: vec![format!("node-port={}", node_port)],
+                                            remediation_commands: Vec::new(),
                                         });
                                     }
                                 }
@@ -106,6 +129,10 @@ impl<'a> NetworkInspector<'a> {
                                 "Ensure service has proper selectors or manual endpoints"
                                     .to_string(),
                             rule_id: Some("NET-003".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
@@ -114,10 +141,16 @@ impl<'a> NetworkInspector<'a> {
 
         // Check network policies
         let network_policies_api = self.client.network_policies(namespace);
-        let network_policies = network_policies_api.list(&ListParams::default()).await?;
+        let network_policies = self
+            .client
+            .list_unselected(&network_policies_api, &ListParams::default())
+            .await?;
 
         let namespaces_api = self.client.namespaces();
-        let namespaces_list = namespaces_api.list(&ListParams::default()).await?;
+        let namespaces_list = self
+            .client
+            .list_all(&namespaces_api, &ListParams::default())
+            .await?;
         let total_namespaces = namespaces_list.items.len();
 
         let mut namespaces_with_policies = std::collections::HashSet::new();
@@ -212,6 +245,24 @@ impl<'a> NetworkInspector<'a> {
             },
         });
 
+        let daemon_sets = self
+            .client
+            .list_unselected(&self.client.daemon_sets(None), &ListParams::default())
+            .await?
+            .items;
+        self.inspect_ingress(
+            namespace,
+            &services.items,
+            &daemon_sets,
+            &mut checks,
+            &mut issues,
+        )
+        .await?;
+        self.inspect_service_references(namespace, &services.items, &mut checks, &mut issues)
+            .await?;
+        self.inspect_node_networking(namespace, &daemon_sets, &mut checks, &mut issues)
+            .await?;
+
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
         let summary = self.create_summary(&checks, issues);
@@ -225,13 +276,176 @@ impl<'a> NetworkInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
+    /// Ingress controllers (cluster-wide, like DNS), the IngressClasses Ingresses reference and the
+    /// Services and ports their backends reference.
+    async fn inspect_ingress(
+        &self,
+        namespace: Option<&str>,
+        scoped_services: &[Service],
+        daemon_sets: &[DaemonSet],
+        checks: &mut Vec<CheckResult>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<()> {
+        let lp = ListParams::default();
+        let deployments = self
+            .client
+            .list_unselected(&self.client.deployments(None), &lp)
+            .await?
+            .items;
+        let controllers = ingress_controllers::detect_controllers(&deployments, daemon_sets);
+
+        let (mut config_maps, mut services, mut secrets) = (Vec::new(), Vec::new(), Vec::new());
+        for ns in ingress_controllers::referenced_namespaces(&controllers) {
+            let ns = Some(ns.as_str());
+            config_maps.extend(
+                self.client
+                    .list_unselected(&self.client.config_maps(ns), &lp)
+                    .await?
+                    .items,
+            );
+            services.extend(
+                self.client
+                    .list_unselected(&self.client.services(ns), &lp)
+                    .await?
+                    .items,
+            );
+            secrets.extend(
+                self.client
+                    .list_unselected(&self.client.secrets(ns), &lp)
+                    .await?
+                    .items,
+            );
+        }
+        let objects = ReferencedObjects::new(&config_maps, &services, &secrets);
+
+        let ingresses = self
+            .client
+            .list_all(&self.client.ingresses(namespace), &lp)
+            .await?
+            .items;
+        let classes = self
+            .client
+            .list_all(&self.client.ingress_classes(), &lp)
+            .await?
+            .items;
+
+        checks.push(ingress_controllers::controller_readiness(
+            &controllers,
+            issues,
+        ));
+        checks.push(ingress_controllers::controller_configuration(
+            &controllers,
+            &objects,
+            issues,
+        ));
+        checks.push(ingress_controllers::ingress_class_references(
+            &ingresses, &classes, issues,
+        ));
+        checks.push(service_refs::ingress_backends(
+            &ingresses,
+            scoped_services,
+            issues,
+        ));
+        Ok(())
+    }
+
+    /// Service target ports against the selected pods, and ExternalName targets: cluster-local
+    /// names against the Service list, others through DNS from where kubeowler runs (not when
+    /// inspecting a dump).
+    async fn inspect_service_references(
+        &self,
+        namespace: Option<&str>,
+        services: &[Service],
+        checks: &mut Vec<CheckResult>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<()> {
+        // Services select pods by their own labels, so their backends are listed without the
+        // --selector.
+        let pods = self
+            .client
+            .list_unselected(&self.client.pods(namespace), &ListParams::default())
+            .await?
+            .items;
+        checks.push(service_refs::service_target_ports(services, &pods, issues));
+
+        let mut resolved = Vec::new();
+        for (resource, target) in service_refs::external_names(services) {
+            let ok = match service_refs::cluster_service_target(&target) {
+                Some((ns, name)) if namespace.is_none_or(|n| n == ns) => {
+                    Some(services.iter().any(|s| {
+                        s.metadata.namespace.as_deref() == Some(ns.as_str())
+                            && s.metadata.name.as_deref() == Some(name.as_str())
+                    }))
+                }
+                Some(_) => None,
+                None if self.options.offline => None,
+                None => Some(
+                    tokio::time::timeout(
+                        EXTERNAL_NAME_TIMEOUT,
+                        tokio::net::lookup_host((target.trim_end_matches('.'), 0)),
+                    )
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+                    .is_some_and(|mut addrs| addrs.next().is_some()),
+                ),
+            };
+            resolved.push((resource, target, ok));
+        }
+        if !resolved.is_empty() {
+            checks.push(service_refs::external_name_check(&resolved, issues));
+        }
+        Ok(())
+    }
+
+    /// kube-proxy and CNI DaemonSets (cluster-wide) and pods whose sandbox network setup failed.
+    async fn inspect_node_networking(
+        &self,
+        namespace: Option<&str>,
+        daemon_sets: &[DaemonSet],
+        checks: &mut Vec<CheckResult>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<()> {
+        let lp = ListParams::default();
+        let config_maps = self
+            .client
+            .list_unselected(&self.client.config_maps(Some("kube-system")), &lp)
+            .await?
+            .items;
+        let mode = node_networking::kube_proxy_mode(daemon_sets, &config_maps);
+        checks.push(node_networking::kube_proxy_check(
+            daemon_sets,
+            mode.as_deref(),
+            issues,
+        ));
+        checks.push(node_networking::cni_check(daemon_sets, issues));
+
+        let pods = self
+            .client
+            .list_all(&self.client.pods(namespace), &lp)
+            .await?
+            .items;
+        let events = self
+            .client
+            .list_all(&self.client.events(namespace), &lp)
+            .await?
+            .items;
+        checks.push(node_networking::sandbox_failures(&pods, &events, issues));
+        Ok(())
+    }
+
     async fn check_dns_configuration(&self, issues: &mut Vec<Issue>) -> Result<bool> {
         // Check for CoreDNS or kube-dns deployment
         let deployments_api = self.client.deployments(Some("kube-system"));
-        let deployments = deployments_api.list(&ListParams::default()).await?;
+        let deployments = self
+            .client
+            .list_unselected(&deployments_api, &ListParams::default())
+            .await?;
 
         let mut has_dns_deployment = false;
         for deployment in &deployments.items {
@@ -257,6 +471,10 @@ impl<'a> NetworkInspector<'a> {
                                     "Check DNS deployment logs and resource availability"
                                         .to_string(),
                                 rule_id: Some("NET-004".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: Vec::new(),
                             });
                             return Ok(false);
                         }
@@ -274,6 +492,10 @@ impl<'a> NetworkInspector<'a> {
                 resource: Some("kube-system".to_string()),
                 recommendation: "Deploy CoreDNS or kube-dns for cluster DNS resolution".to_string(),
                 rule_id: Some("NET-005".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             return Ok(false);
         }
@@ -307,3 +529,47 @@ impl<'a> NetworkInspector<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn selector_limits_reported_services() {
+        use crate::offline::{offline_client, DumpStore};
+        use serde_json::json;
+        use std::sync::Arc;
+
+        let service = |name: &str, labels: serde_json::Value| {
+            json!({ "kind": "Service", "apiVersion": "v1",
+                    "metadata": { "name": name, "namespace": "shop", "labels": labels },
+                    "spec": { "ports": [{ "port": 80 }] } })
+        };
+        let mut store = DumpStore::default();
+        store.add(json!({
+            "kind": "List", "apiVersion": "v1",
+            "items": [
+                service("checkout", json!({ "app": "checkout" })),
+                service("legacy", json!({}))
+            ]
+        }));
+        let client = offline_client(Arc::new(store), None)
+            .with_label_selector(Some("app=checkout".to_string()));
+        let options = InspectionOptions {
+            offline: true,
+            ..Default::default()
+        };
+        let result = NetworkInspector::new(&client, &options)
+            .inspect(Some("shop"))
+            .await
+            .unwrap();
+        let resources: Vec<&str> = result
+            .summary
+            .issues
+            .iter()
+            .filter(|i| i.rule_id.as_deref() == Some("NET-003"))
+            .filter_map(|i| i.resource.as_deref())
+            .collect();
+        assert_eq!(resources, vec!["shop/checkout"]);
+    }
+}
@@ -0,0 +1,183 @@
+//! Cross-check of kubelet-reported node capacity against hardware measured by the node inspector.
+//! A kubelet that misreports CPUs or memory (hot-added memory, offlined CPUs, ballooned VMs)
+//! silently skews scheduling, allocatable and every utilisation percentage in the report.
+
+use k8s_openapi::api::core::v1::Node;
+
+use crate::inspections::types::{Issue, IssueSeverity};
+use crate::node_inspection::NodeInspectionResult;
+use crate::utils::resource_quantity::{parse_cpu_str, parse_memory_str};
+
+/// Relative difference (percent) between declared and measured capacity tolerated before flagging.
+/// Fixed rather than a flag: the noise it absorbs is not cluster-specific. CPU counts are whole
+/// cores, so one missing core already exceeds it on nodes up to 20 cores, while `MemTotal` moves
+/// by well under 5% between boots (kernel and crashkernel reservations, a slightly different
+/// kernel). A real misreport (offlined CPUs, hot-added or ballooned memory) is far larger.
+pub const CAPACITY_MISMATCH_TOLERANCE_PCT: f64 = 5.0;
+
+/// Declared (kubelet) vs measured (node inspector) value for one resource on one node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacityMismatch {
+    pub node_name: String,
+    /// "cpu" or "memory"
+    pub resource: &'static str,
+    /// CPU in cores, memory in MiB.
+    pub declared: f64,
+    pub measured: f64,
+    pub diff_pct: f64,
+}
+
+fn diff_pct(declared: f64, measured: f64) -> f64 {
+    if measured <= 0.0 {
+        return 0.0;
+    }
+    ((declared - measured).abs() / measured) * 100.0
+}
+
+/// Compare node `status.capacity` with node inspector `resources.cpu_cores` / `memory_total_mib`.
+/// Nodes missing on either side, or with unknown values, are skipped.
+pub fn find_capacity_mismatches(
+    nodes: &[Node],
+    results: &[NodeInspectionResult],
+    tolerance_pct: f64,
+) -> Vec<CapacityMismatch> {
+    let mut out = Vec::new();
+    for result in results {
+        let Some(node) = nodes
+            .iter()
+            .find(|n| n.metadata.name.as_deref() == Some(result.node_name.as_str()))
+        else {
+            continue;
+        };
+        let Some(capacity) = node.status.as_ref().and_then(|s| s.capacity.as_ref()) else {
+            continue;
+        };
+
+        let declared_cpu = capacity
+            .get("cpu")
+            .and_then(|q| parse_cpu_str(&q.0))
+            .map(|m| m as f64 / 1000.0);
+        if let (Some(declared), Some(measured)) = (declared_cpu, result.resources.cpu_cores) {
+            let measured = measured as f64;
+            let pct = diff_pct(declared, measured);
+            if measured > 0.0 && pct > tolerance_pct {
+                out.push(CapacityMismatch {
+                    node_name: result.node_name.clone(),
+                    resource: "cpu",
+                    declared,
+                    measured,
+                    diff_pct: pct,
+                });
+            }
+        }
+
+        let declared_mem = capacity
+            .get("memory")
+            .and_then(|q| parse_memory_str(&q.0))
+            .map(|b| b as f64 / (1024.0 * 1024.0));
+        if let (Some(declared), Some(measured)) = (declared_mem, result.resources.memory_total_mib)
+        {
+            let measured = measured as f64;
+            let pct = diff_pct(declared, measured);
+            if measured > 0.0 && pct > tolerance_pct {
+                out.push(CapacityMismatch {
+                    node_name: result.node_name.clone(),
+                    resource: "memory",
+                    declared,
+                    measured,
+                    diff_pct: pct,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Turn mismatches into NODE-006 (CPU) / NODE-007 (memory) issues.
+pub fn capacity_mismatch_issues(mismatches: &[CapacityMismatch]) -> Vec<Issue> {
+    mismatches
+        .iter()
+        .map(|m| {
+            let (rule_id, description) = if m.resource == "cpu" {
+                (
+                    "NODE-006",
+                    format!(
+                        "Node {} declares {:.2} CPU cores but {} were measured on the host ({:.0}% difference)",
+                        m.node_name, m.declared, m.measured, m.diff_pct
+                    ),
+                )
+            } else {
+                (
+                    "NODE-007",
+                    format!(
+                        "Node {} declares {:.0} MiB memory but {:.0} MiB were measured on the host ({:.0}% difference)",
+                        m.node_name, m.declared, m.measured, m.diff_pct
+                    ),
+                )
+            };
+            Issue {
+                severity: IssueSeverity::Warning,
+                category: "Node".to_string(),
+                description,
+                resource: Some(m.node_name.clone()),
+                recommendation:
+                    "Restart kubelet (or re-register the node) so capacity reflects the current hardware; check for hot-plugged or ballooned resources."
+                        .to_string(),
+                rule_id: Some(rule_id.to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::NodeStatus;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use std::collections::BTreeMap;
+
+    fn node(name: &str, cpu: &str, memory: &str) -> Node {
+        let mut capacity = BTreeMap::new();
+        capacity.insert("cpu".to_string(), Quantity(cpu.to_string()));
+        capacity.insert("memory".to_string(), Quantity(memory.to_string()));
+        let mut n = Node::default();
+        n.metadata.name = Some(name.to_string());
+        n.status = Some(NodeStatus {
+            capacity: Some(capacity),
+            ..Default::default()
+        });
+        n
+    }
+
+    fn measured(name: &str, cores: u32, mem_mib: u64) -> NodeInspectionResult {
+        let mut r = NodeInspectionResult {
+            node_name: name.to_string(),
+            ..Default::default()
+        };
+        r.resources.cpu_cores = Some(cores);
+        r.resources.memory_total_mib = Some(mem_mib);
+        r
+    }
+
+    #[test]
+    fn matching_capacity_is_not_flagged() {
+        let nodes = vec![node("n1", "4", "8000Mi")];
+        let results = vec![measured("n1", 4, 8100)];
+        assert!(find_capacity_mismatches(&nodes, &results, 5.0).is_empty());
+    }
+
+    #[test]
+    fn cpu_and_memory_mismatch_are_flagged() {
+        let nodes = vec![node("n1", "2", "4Gi")];
+        let results = vec![measured("n1", 4, 16384)];
+        let mismatches = find_capacity_mismatches(&nodes, &results, 5.0);
+        assert_eq!(mismatches.len(), 2);
+        let issues = capacity_mismatch_issues(&mismatches);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NODE-006"));
+        assert_eq!(issues[1].rule_id.as_deref(), Some("NODE-007"));
+    }
+}
@@ -0,0 +1,189 @@
+//! Pod density per node against the kubelet's pod capacity (allocatable `pods`, i.e. maxPods).
+//! A node at maxPods rejects new pods whatever its CPU and memory headroom, and a cluster whose
+//! spare pod slots cannot absorb the busiest node cannot reschedule its pods when it is lost.
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity, NodeRow};
+
+/// Share of pod capacity (percent) at which a node is reported as filling up.
+pub const POD_DENSITY_WARNING_PCT: f64 = 80.0;
+/// Share of pod capacity (percent) at which new pods are about to be rejected.
+pub const POD_DENSITY_CRITICAL_PCT: f64 = 95.0;
+
+/// Compute the "Pod Density" check and NODE-023/NODE-024 issues from the node list.
+/// Returns None when no node reports allocatable pods.
+pub fn evaluate_pod_density(rows: &[NodeRow]) -> Option<(CheckResult, Vec<Issue>)> {
+    let measured: Vec<(&NodeRow, u32)> = rows
+        .iter()
+        .filter_map(|r| r.allocatable_pods.filter(|a| *a > 0).map(|a| (r, a)))
+        .collect();
+    if measured.is_empty() {
+        return None;
+    }
+
+    let mut issues = Vec::new();
+    for (row, capacity) in &measured {
+        let pct = row.pod_count as f64 / *capacity as f64 * 100.0;
+        let severity = if pct >= POD_DENSITY_CRITICAL_PCT {
+            IssueSeverity::Critical
+        } else if pct >= POD_DENSITY_WARNING_PCT {
+            IssueSeverity::Warning
+        } else {
+            continue;
+        };
+        issues.push(Issue {
+            severity,
+            category: "Node".to_string(),
+            description: format!(
+                "Node {} runs {} of {} pods ({:.0}% of its pod capacity)",
+                row.name, row.pod_count, capacity, pct
+            ),
+            resource: Some(row.name.clone()),
+            recommendation:
+                "Spread pods to other nodes, add nodes, or raise maxPods if the CNI has IPs for it."
+                    .to_string(),
+            rule_id: Some("NODE-023".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+
+    // Node loss: the busiest Ready node's pods must fit into the free slots of the other Ready nodes.
+    let ready: Vec<&(&NodeRow, u32)> = measured.iter().filter(|(r, _)| r.ready).collect();
+    let free = |(r, cap): &&(&NodeRow, u32)| cap.saturating_sub(r.pod_count);
+    let total_free: u32 = ready.iter().map(free).sum();
+    let busiest = ready.iter().max_by_key(|(r, _)| r.pod_count);
+    let mut headroom = format!(
+        "{} free pod slot(s) on {} Ready node(s)",
+        total_free,
+        ready.len()
+    );
+    if let Some(busiest) = busiest.filter(|_| ready.len() > 1) {
+        let spare = total_free - free(busiest);
+        let (node, pods) = (&busiest.0.name, busiest.0.pod_count);
+        headroom.push_str(&format!("; {} without {} ({} pods)", spare, node, pods));
+        if spare < pods {
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "Node".to_string(),
+                description: format!(
+                    "Cluster has {} free pod slot(s) outside node {}, fewer than the {} pods it runs; losing it would leave pods unschedulable",
+                    spare, node, pods
+                ),
+                resource: Some(node.clone()),
+                recommendation: "Add nodes or raise maxPods so the cluster can absorb the loss of its busiest node.".to_string(),
+                rule_id: Some("NODE-024".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+    }
+
+    let full = issues
+        .iter()
+        .filter(|i| i.rule_id.as_deref() == Some("NODE-023"))
+        .count();
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if !issues.is_empty() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let check = CheckResult {
+        name: "Pod Density".to_string(),
+        description:
+            "Pods per node against the kubelet pod capacity (maxPods) and cluster pod headroom"
+                .to_string(),
+        status,
+        score: ((measured.len() - full) as f64 / measured.len() as f64) * 100.0,
+        max_score: 100.0,
+        details: Some(format!(
+            "{} of {} node(s) at or above {}% of pod capacity; {}",
+            full,
+            measured.len(),
+            POD_DENSITY_WARNING_PCT,
+            headroom
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["See NODE-023/NODE-024; spread pods or add pod capacity.".to_string()]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, pods: u32, capacity: Option<u32>) -> NodeRow {
+        NodeRow {
+            name: name.to_string(),
+            operating_system: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            kubelet_version: "v1.29.0".to_string(),
+            ready: true,
+            pod_count: pods,
+            allocatable_pods: capacity,
+            node_address: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime_version: None,
+            node_pool: None,
+            zone: None,
+            spot: false,
+        }
+    }
+
+    #[test]
+    fn flags_full_nodes_and_missing_failover_headroom() {
+        let rows = vec![
+            node("a", 108, Some(110)),
+            node("b", 90, Some(110)),
+            node("c", 100, Some(110)),
+        ];
+        let (check, issues) = evaluate_pod_density(&rows).unwrap();
+        let found: Vec<(&str, &IssueSeverity, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    &i.severity,
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("NODE-023", &IssueSeverity::Critical, "a"),
+                ("NODE-023", &IssueSeverity::Warning, "b"),
+                ("NODE-023", &IssueSeverity::Warning, "c"),
+                ("NODE-024", &IssueSeverity::Warning, "a"),
+            ]
+        );
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("3 of 3 node(s) at or above 80% of pod capacity; 32 free pod slot(s) on 3 Ready node(s); 30 without a (108 pods)")
+        );
+    }
+
+    #[test]
+    fn sparse_cluster_passes() {
+        let rows = vec![
+            node("a", 30, Some(110)),
+            node("b", 20, Some(110)),
+            node("x", 5, None),
+        ];
+        let (check, issues) = evaluate_pod_density(&rows).unwrap();
+        assert!(issues.is_empty());
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(evaluate_pod_density(&[node("x", 5, None)]).is_none());
+    }
+}
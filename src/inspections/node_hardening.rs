@@ -0,0 +1,334 @@
+//! OS-level CIS checks over the hardening data collected by the node inspector: kubelet and
+//! kubeadm file permissions, kernel modules, sysctls, listening ports and sshd settings.
+//! Nodes inspected by older inspector images (no `hardening` object) are skipped.
+
+use crate::inspections::types::{Issue, IssueSeverity};
+use crate::node_inspection::{NodeHardening, NodeInspectionResult, NodeSshConfig};
+
+/// Most permissive mode allowed for kubelet/kubeadm files (CIS 1.1.x, 4.1.x).
+const MAX_FILE_MODE: u32 = 0o600;
+
+/// Hardening sysctls and the value CIS expects.
+const EXPECTED_SYSCTLS: &[(&str, &str)] = &[
+    ("net.ipv4.conf.all.send_redirects", "0"),
+    ("net.ipv4.conf.default.send_redirects", "0"),
+    ("net.ipv4.conf.all.accept_redirects", "0"),
+    ("net.ipv4.conf.all.accept_source_route", "0"),
+    ("net.ipv4.conf.all.log_martians", "1"),
+    ("net.ipv4.icmp_echo_ignore_broadcasts", "1"),
+    ("net.ipv4.tcp_syncookies", "1"),
+    ("kernel.randomize_va_space", "2"),
+    ("fs.suid_dumpable", "0"),
+];
+
+/// Ports that should never listen beyond loopback on a node.
+const INSECURE_PORTS: &[(u16, &str)] = &[
+    (21, "FTP"),
+    (23, "telnet"),
+    (111, "rpcbind"),
+    (2375, "Docker API without TLS"),
+    (4194, "cAdvisor"),
+    (10255, "kubelet read-only API"),
+];
+
+/// Highest sshd MaxAuthTries accepted (CIS 5.2.x).
+const MAX_SSH_AUTH_TRIES: u32 = 4;
+
+fn is_loopback(address: &str) -> bool {
+    address == "::1" || address.starts_with("127.")
+}
+
+/// Settings from sshd_config that weaken remote access, as "Keyword value" strings.
+fn weak_ssh_settings(ssh: &NodeSshConfig) -> Vec<String> {
+    let is_yes = |v: &Option<String>| v.as_deref().is_some_and(|v| v.eq_ignore_ascii_case("yes"));
+    let mut out = Vec::new();
+    if is_yes(&ssh.permit_root_login) {
+        out.push("PermitRootLogin yes".to_string());
+    }
+    if is_yes(&ssh.permit_empty_passwords) {
+        out.push("PermitEmptyPasswords yes".to_string());
+    }
+    if is_yes(&ssh.password_authentication) {
+        out.push("PasswordAuthentication yes".to_string());
+    }
+    if is_yes(&ssh.x11_forwarding) {
+        out.push("X11Forwarding yes".to_string());
+    }
+    if let Some(tries) = ssh
+        .max_auth_tries
+        .as_deref()
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|t| *t > MAX_SSH_AUTH_TRIES)
+    {
+        out.push(format!("MaxAuthTries {}", tries));
+    }
+    out
+}
+
+/// NODE-014..NODE-018 findings of one node (at most one per code, listing every offender).
+fn node_findings(node: &str, hardening: &NodeHardening) -> Vec<Issue> {
+    let mut out = Vec::new();
+
+    let loose_files: Vec<_> = hardening
+        .file_permissions
+        .iter()
+        .filter(|f| {
+            let too_open = u32::from_str_radix(&f.mode, 8)
+                .map(|m| m & !MAX_FILE_MODE != 0)
+                .unwrap_or(false);
+            too_open || (!f.owner.is_empty() && f.owner != "0:0")
+        })
+        .collect();
+    if !loose_files.is_empty() {
+        out.push(Issue {
+            key_attributes: loose_files
+                .iter()
+                .map(|f| format!("file={}", f.path))
+                .collect(),
+            ..Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NODE-014",
+                format!(
+                    "Node {} has kubelet/kubeadm files more open than 600 root:root: {}",
+                    node,
+                    loose_files
+                        .iter()
+                        .map(|f| format!("{} ({} {})", f.path, f.mode, f.owner))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                node.to_string(),
+                "chmod 600 and chown root:root the listed files.",
+            )
+        });
+    }
+
+    if !hardening.loaded_kernel_modules.is_empty() {
+        out.push(Issue {
+            key_attributes: hardening
+                .loaded_kernel_modules
+                .iter()
+                .map(|m| format!("module={}", m))
+                .collect(),
+            ..Issue::new(
+                IssueSeverity::Info,
+                "Node",
+                "NODE-015",
+                format!(
+                    "Node {} has unneeded kernel modules loaded: {}",
+                    node,
+                    hardening.loaded_kernel_modules.join(", ")
+                ),
+                node.to_string(),
+                "Blacklist the modules with `install <module> /bin/false` in /etc/modprobe.d and unload them.",
+            )
+        });
+    }
+
+    let (sysctl_keys, sysctls): (Vec<String>, Vec<String>) = EXPECTED_SYSCTLS
+        .iter()
+        .filter_map(|(key, expected)| {
+            let value = hardening.sysctls.get(*key)?;
+            (value != expected).then(|| {
+                (
+                    format!("sysctl={}", key),
+                    format!("{}={} (want {})", key, value, expected),
+                )
+            })
+        })
+        .unzip();
+    if !sysctls.is_empty() {
+        out.push(Issue {
+            key_attributes: sysctl_keys,
+            ..Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NODE-016",
+                format!(
+                    "Node {} has kernel parameters not hardened: {}",
+                    node,
+                    sysctls.join(", ")
+                ),
+                node.to_string(),
+                "Set the expected values in /etc/sysctl.d and run `sysctl --system`.",
+            )
+        });
+    }
+
+    let mut ports: Vec<String> = hardening
+        .listening_ports
+        .iter()
+        .filter(|p| !is_loopback(&p.address))
+        .filter_map(|p| {
+            let (_, service) = INSECURE_PORTS.iter().find(|(port, _)| *port == p.port)?;
+            Some(format!("{} ({})", p.port, service))
+        })
+        .collect();
+    ports.sort();
+    ports.dedup();
+    if !ports.is_empty() {
+        out.push(Issue {
+            key_attributes: ports
+                .iter()
+                .map(|p| format!("port={}", p.split(' ').next().unwrap_or(p)))
+                .collect(),
+            ..Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NODE-017",
+                format!(
+                    "Node {} listens on insecure service ports: {}",
+                    node,
+                    ports.join(", ")
+                ),
+                node.to_string(),
+                "Disable the services or bind them to localhost (kubelet: readOnlyPort: 0).",
+            )
+        });
+    }
+
+    let ssh = hardening
+        .ssh
+        .as_ref()
+        .map(weak_ssh_settings)
+        .unwrap_or_default();
+    if !ssh.is_empty() {
+        out.push(Issue {
+            key_attributes: ssh
+                .iter()
+                .map(|setting| {
+                    let keyword = setting.split(' ').next().unwrap_or(setting);
+                    format!("setting={}", keyword)
+                })
+                .collect(),
+            ..Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NODE-018",
+                format!(
+                    "Node {} has weak SSH daemon settings: {}",
+                    node,
+                    ssh.join(", ")
+                ),
+                node.to_string(),
+                "Fix the settings in /etc/ssh/sshd_config and reload sshd.",
+            )
+        });
+    }
+    out
+}
+
+/// Evaluate every node that reported hardening data; returns (nodes evaluated, findings).
+pub fn evaluate_os_hardening(nodes: &[NodeInspectionResult]) -> (usize, Vec<Issue>) {
+    let mut evaluated = 0;
+    let mut issues = Vec::new();
+    for node in nodes {
+        if let Some(hardening) = &node.hardening {
+            evaluated += 1;
+            issues.extend(node_findings(&node.node_name, hardening));
+        }
+    }
+    (evaluated, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_inspection::{NodeFilePermission, NodeListeningPort};
+
+    fn node(hardening: Option<NodeHardening>) -> NodeInspectionResult {
+        NodeInspectionResult {
+            node_name: "n1".to_string(),
+            hardening,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_each_hardening_area() {
+        let hardening = NodeHardening {
+            file_permissions: vec![
+                NodeFilePermission {
+                    path: "/var/lib/kubelet/config.yaml".to_string(),
+                    mode: "644".to_string(),
+                    owner: "0:0".to_string(),
+                },
+                NodeFilePermission {
+                    path: "/etc/kubernetes/kubelet.conf".to_string(),
+                    mode: "600".to_string(),
+                    owner: "0:0".to_string(),
+                },
+            ],
+            loaded_kernel_modules: vec!["sctp".to_string()],
+            sysctls: [
+                ("net.ipv4.tcp_syncookies", "1"),
+                ("net.ipv4.conf.all.send_redirects", "1"),
+            ]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+            listening_ports: vec![
+                NodeListeningPort {
+                    address: "0.0.0.0".to_string(),
+                    port: 10255,
+                },
+                NodeListeningPort {
+                    address: "127.0.0.1".to_string(),
+                    port: 2375,
+                },
+                NodeListeningPort {
+                    address: "0.0.0.0".to_string(),
+                    port: 10250,
+                },
+            ],
+            ssh: Some(NodeSshConfig {
+                permit_root_login: Some("yes".to_string()),
+                max_auth_tries: Some("6".to_string()),
+                password_authentication: Some("no".to_string()),
+                ..Default::default()
+            }),
+        };
+        let (evaluated, issues) = evaluate_os_hardening(&[node(Some(hardening)), node(None)]);
+        assert_eq!(evaluated, 1);
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(
+            codes,
+            vec!["NODE-014", "NODE-015", "NODE-016", "NODE-017", "NODE-018"]
+        );
+        assert!(issues[0]
+            .description
+            .ends_with(": /var/lib/kubelet/config.yaml (644 0:0)"));
+        assert!(issues[2]
+            .description
+            .ends_with(": net.ipv4.conf.all.send_redirects=1 (want 0)"));
+        assert!(issues[3]
+            .description
+            .ends_with(": 10255 (kubelet read-only API)"));
+        assert!(issues[4]
+            .description
+            .ends_with(": PermitRootLogin yes, MaxAuthTries 6"));
+        assert_eq!(issues[3].key_attributes, ["port=10255"]);
+        assert_eq!(
+            issues[4].key_attributes,
+            ["setting=PermitRootLogin", "setting=MaxAuthTries"]
+        );
+    }
+
+    #[test]
+    fn hardened_node_has_no_findings() {
+        let hardening = NodeHardening {
+            file_permissions: vec![NodeFilePermission {
+                path: "/etc/kubernetes/pki/ca.crt".to_string(),
+                mode: "600".to_string(),
+                owner: "0:0".to_string(),
+            }],
+            sysctls: [("kernel.randomize_va_space".to_string(), "2".to_string())].into(),
+            ssh: Some(NodeSshConfig::default()),
+            ..Default::default()
+        };
+        let (evaluated, issues) = evaluate_os_hardening(&[node(Some(hardening))]);
+        assert_eq!(evaluated, 1);
+        assert!(issues.is_empty());
+    }
+}
@@ -0,0 +1,295 @@
+//! Node heartbeats: the kubelet renews its Lease in `kube-node-lease` every few seconds (a quarter
+//! of the lease duration), while the Ready condition only changes after the node controller's
+//! grace period and node status is written every few minutes. A Ready node whose Lease is stale
+//! or missing has lost contact with the API server, or the node controller is not acting on it
+//! (NODE-034); a node that repeatedly went NotReady has a flapping kubelet even when it is Ready
+//! at inspection time (NODE-035).
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::coordination::v1::Lease;
+use k8s_openapi::api::core::v1::{Event, Node};
+
+use crate::inspections::types::*;
+
+/// Namespace holding one Lease per node, named after the node.
+pub const NODE_LEASE_NAMESPACE: &str = "kube-node-lease";
+
+/// Event reason recorded when a node's Ready condition stops being True.
+pub const NODE_NOT_READY_REASON: &str = "NodeNotReady";
+
+/// Kubelet default `nodeLeaseDurationSeconds`, used when the Lease does not set one.
+const DEFAULT_NODE_LEASE_SECS: i64 = 40;
+
+/// NotReady transitions of one node within the retained events at or above which NODE-035 is
+/// reported.
+pub const FLAPPING_NOT_READY_EVENTS: i32 = 3;
+
+fn is_ready(node: &Node) -> bool {
+    node.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .into_iter()
+        .flatten()
+        .any(|c| c.type_ == "Ready" && c.status == "True")
+}
+
+fn heartbeat_issue(code: &str, node: &str, description: String, recommendation: &str) -> Issue {
+    Issue {
+        remediation_commands: vec![format!(
+            "kubectl -n {} get lease {} -o yaml",
+            NODE_LEASE_NAMESPACE, node
+        )],
+        ..Issue::new(
+            IssueSeverity::Warning,
+            "Node",
+            code,
+            description,
+            node.to_string(),
+            recommendation,
+        )
+    }
+}
+
+/// "Node Heartbeats" check. NODE-034 per Ready node whose Lease in `leases` was not renewed within
+/// its lease duration, or that has none; None when the Leases could not be listed (NODE-034 is
+/// then skipped). Nodes that are not Ready are NODE-001. NODE-035 per node with at least
+/// `FLAPPING_NOT_READY_EVENTS` `NodeNotReady` events (counting repeats) in `events`.
+pub fn node_heartbeat_check(
+    nodes: &[Node],
+    leases: Option<&[Lease]>,
+    events: &[Event],
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut not_ready_events: BTreeMap<&str, i32> = BTreeMap::new();
+    for event in events {
+        if event.reason.as_deref() != Some(NODE_NOT_READY_REASON)
+            || event.involved_object.kind.as_deref() != Some("Node")
+        {
+            continue;
+        }
+        if let Some(node) = event.involved_object.name.as_deref() {
+            let count = event
+                .series
+                .as_ref()
+                .and_then(|s| s.count)
+                .or(event.count)
+                .unwrap_or(1);
+            *not_ready_events.entry(node).or_default() += count.max(1);
+        }
+    }
+
+    let mut flagged = 0usize;
+    for node in nodes {
+        let Some(name) = node.metadata.name.as_deref() else {
+            continue;
+        };
+        let ready = is_ready(node);
+        let before = issues.len();
+        if let Some(leases) = leases.filter(|_| ready) {
+            let spec = leases
+                .iter()
+                .find(|l| l.metadata.name.as_deref() == Some(name))
+                .and_then(|l| l.spec.as_ref());
+            match spec {
+                Some(spec) => {
+                    let duration = spec
+                        .lease_duration_seconds
+                        .map_or(DEFAULT_NODE_LEASE_SECS, i64::from);
+                    let age = spec
+                        .renew_time
+                        .as_ref()
+                        .map(|t| (now - t.0).num_seconds());
+                    if age.is_none_or(|a| a > duration) {
+                        issues.push(heartbeat_issue(
+                            "NODE-034",
+                            name,
+                            format!(
+                                "Node {} is Ready but its Lease was {} (lease duration {}s)",
+                                name,
+                                age.map_or("never renewed".to_string(), |a| format!(
+                                    "last renewed {}s ago",
+                                    a
+                                )),
+                                duration
+                            ),
+                            "Check the kubelet and its connection to the API server; if the Lease stays stale, check the node lifecycle controller in kube-controller-manager.",
+                        ));
+                    }
+                }
+                None => issues.push(heartbeat_issue(
+                    "NODE-034",
+                    name,
+                    format!(
+                        "Node {} is Ready but has no Lease in {}",
+                        name, NODE_LEASE_NAMESPACE
+                    ),
+                    "Check that the kubelet can create its Lease (RBAC for system:nodes, API server reachability).",
+                )),
+            }
+        }
+        let transitions = not_ready_events.get(name).copied().unwrap_or(0);
+        if transitions >= FLAPPING_NOT_READY_EVENTS {
+            issues.push(heartbeat_issue(
+                "NODE-035",
+                name,
+                format!(
+                    "Node {} went NotReady {} times in the retained events ({})",
+                    name,
+                    transitions,
+                    if ready {
+                        "Ready now"
+                    } else {
+                        "not Ready now"
+                    }
+                ),
+                "Check the kubelet and container runtime logs, node resource pressure and the network path to the API server.",
+            ));
+        }
+        if issues.len() > before {
+            flagged += 1;
+        }
+    }
+
+    let evaluated = nodes.len();
+    let mut details = format!(
+        "{}/{} nodes with a fresh heartbeat",
+        evaluated - flagged,
+        evaluated
+    );
+    if leases.is_none() {
+        details.push_str(&format!(
+            "; Leases in {} not available",
+            NODE_LEASE_NAMESPACE
+        ));
+    }
+    CheckResult {
+        name: "Node Heartbeats".to_string(),
+        description:
+            "Node Leases are renewed and nodes are not flapping between Ready and NotReady"
+                .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See NODE-034 and NODE-035.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use k8s_openapi::api::coordination::v1::LeaseSpec;
+    use k8s_openapi::api::core::v1::{NodeCondition, NodeStatus, ObjectReference};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, ready: bool) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(NodeStatus {
+                conditions: Some(vec![NodeCondition {
+                    type_: "Ready".to_string(),
+                    status: if ready { "True" } else { "Unknown" }.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn lease(name: &str, renewed: DateTime<Utc>) -> Lease {
+        Lease {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(NODE_LEASE_NAMESPACE.to_string()),
+                ..Default::default()
+            },
+            spec: Some(LeaseSpec {
+                holder_identity: Some(name.to_string()),
+                lease_duration_seconds: Some(40),
+                renew_time: Some(MicroTime(renewed)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn not_ready(node: &str, count: i32) -> Event {
+        Event {
+            reason: Some(NODE_NOT_READY_REASON.to_string()),
+            count: Some(count),
+            involved_object: ObjectReference {
+                kind: Some("Node".to_string()),
+                name: Some(node.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_stale_or_missing_leases_of_ready_nodes_and_flapping_nodes() {
+        let now = Utc::now();
+        let nodes = vec![
+            node("fresh", true),
+            node("stale", true),
+            node("no-lease", true),
+            node("down", false),
+            node("flapping", true),
+        ];
+        let leases = vec![
+            lease("fresh", now - Duration::seconds(5)),
+            lease("stale", now - Duration::seconds(300)),
+            lease("down", now - Duration::seconds(600)),
+            lease("flapping", now - Duration::seconds(3)),
+        ];
+        let events = vec![
+            not_ready("flapping", 2),
+            not_ready("flapping", 1),
+            not_ready("fresh", 1),
+        ];
+        let mut issues = Vec::new();
+        let check = node_heartbeat_check(&nodes, Some(&leases), &events, now, &mut issues);
+        let descriptions: Vec<&str> = issues.iter().map(|i| i.description.as_str()).collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Node stale is Ready but its Lease was last renewed 300s ago (lease duration 40s)",
+                "Node no-lease is Ready but has no Lease in kube-node-lease",
+                "Node flapping went NotReady 3 times in the retained events (Ready now)",
+            ]
+        );
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("2/5 nodes with a fresh heartbeat")
+        );
+
+        let mut issues = Vec::new();
+        let check = node_heartbeat_check(&nodes, None, &[], now, &mut issues);
+        assert!(issues.is_empty());
+        assert_eq!(
+            check.details.as_deref(),
+            Some("5/5 nodes with a fresh heartbeat; Leases in kube-node-lease not available")
+        );
+    }
+}
@@ -0,0 +1,482 @@
+//! Cordon and drain hygiene for the Node Health inspection: nodes left `SchedulingDisabled` for
+//! longer than `--max-cordon-hours` (NODE-029), nodes that evicted many pods (NODE-030) and
+//! nodes whose DaemonSet pods cannot be scheduled (NODE-031). A forgotten cordon silently
+//! removes the node's capacity from the cluster.
+//!
+//! The API records no cordon time. It is taken from the `node.kubernetes.io/unschedulable`
+//! taint's `timeAdded` when set, else from the newest `NodeNotSchedulable` event; events expire
+//! (one hour by default), so a cordon with neither is reported as Info with an unknown age.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{Event, Node, Pod};
+
+use crate::inspections::types::*;
+
+/// Default hours a node may stay cordoned before NODE-029.
+pub const DEFAULT_MAX_CORDON_HOURS: u32 = 24;
+
+/// Evicted pods still present on one node at or above which NODE-030 is reported.
+pub const EVICTED_PODS_PER_NODE: usize = 5;
+
+const UNSCHEDULABLE_TAINT: &str = "node.kubernetes.io/unschedulable";
+
+/// Event reason the kubelet records when a node is cordoned.
+pub const NODE_NOT_SCHEDULABLE_REASON: &str = "NodeNotSchedulable";
+
+fn node_issue(
+    severity: IssueSeverity,
+    code: &str,
+    node: &str,
+    description: String,
+    recommendation: &str,
+    remediation_commands: Vec<String>,
+) -> Issue {
+    Issue {
+        remediation_commands,
+        ..Issue::new(
+            severity,
+            "Node",
+            code,
+            description,
+            node.to_string(),
+            recommendation,
+        )
+    }
+}
+
+fn check(
+    name: &str,
+    description: String,
+    evaluated: usize,
+    flagged: usize,
+    status: CheckStatus,
+    details: String,
+    recommendation: &str,
+) -> CheckResult {
+    CheckResult {
+        name: name.to_string(),
+        description,
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            status
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![recommendation.to_string()]
+        },
+    }
+}
+
+fn event_time(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|t| t.0)
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .or_else(|| event.first_timestamp.as_ref().map(|t| t.0))
+}
+
+/// When `node` was cordoned, from the unschedulable taint or the newest `NodeNotSchedulable`
+/// event in `events`.
+fn cordoned_since(node: &Node, events: &[Event]) -> Option<DateTime<Utc>> {
+    let name = node.metadata.name.as_deref()?;
+    let taint = node
+        .spec
+        .as_ref()?
+        .taints
+        .iter()
+        .flatten()
+        .find(|t| t.key == UNSCHEDULABLE_TAINT)
+        .and_then(|t| t.time_added.as_ref())
+        .map(|t| t.0);
+    taint.or_else(|| {
+        events
+            .iter()
+            .filter(|e| e.reason.as_deref() == Some(NODE_NOT_SCHEDULABLE_REASON))
+            .filter(|e| e.involved_object.name.as_deref() == Some(name))
+            .filter_map(event_time)
+            .max()
+    })
+}
+
+/// NODE-029 per node cordoned for more than `max_hours` (Warning), or for an unknown time (Info).
+pub fn cordon_check(
+    nodes: &[Node],
+    events: &[Event],
+    now: DateTime<Utc>,
+    max_hours: u32,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut cordoned = 0;
+    let mut stale = 0;
+    for node in nodes {
+        if !node
+            .spec
+            .as_ref()
+            .and_then(|s| s.unschedulable)
+            .unwrap_or(false)
+        {
+            continue;
+        }
+        cordoned += 1;
+        let name = node.metadata.name.as_deref().unwrap_or("unknown");
+        let uncordon = vec![format!("kubectl uncordon {}", name)];
+        match cordoned_since(node, events) {
+            Some(since) => {
+                let hours = (now - since).num_hours();
+                if hours <= i64::from(max_hours) {
+                    continue;
+                }
+                stale += 1;
+                issues.push(node_issue(
+                    IssueSeverity::Warning,
+                    "NODE-029",
+                    name,
+                    format!(
+                        "Node {} has been cordoned (SchedulingDisabled) for {} hours, above {}",
+                        name, hours, max_hours
+                    ),
+                    "Finish the maintenance and uncordon the node, or remove it from the cluster.",
+                    uncordon,
+                ));
+            }
+            None => issues.push(node_issue(
+                IssueSeverity::Info,
+                "NODE-029",
+                name,
+                format!(
+                    "Node {} is cordoned (SchedulingDisabled); cordon time unknown",
+                    name
+                ),
+                "Confirm the cordon is still needed; uncordon the node otherwise.",
+                uncordon,
+            )),
+        }
+    }
+    check(
+        "Cordoned Nodes",
+        format!(
+            "Checks that no node stays cordoned for more than {} hours",
+            max_hours
+        ),
+        nodes.len(),
+        stale,
+        CheckStatus::Warning,
+        format!(
+            "{} of {} node(s) cordoned, {} for more than {} hours",
+            cordoned,
+            nodes.len(),
+            stale,
+            max_hours
+        ),
+        "Uncordon or remove nodes left in SchedulingDisabled (see NODE-029).",
+    )
+}
+
+fn is_evicted(pod: &Pod) -> bool {
+    pod.status.as_ref().is_some_and(|s| {
+        s.phase.as_deref() == Some("Failed") && s.reason.as_deref() == Some("Evicted")
+    })
+}
+
+/// NODE-030 per node with at least [`EVICTED_PODS_PER_NODE`] evicted pods left behind.
+pub fn evicted_pods_check(nodes: &[Node], pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut per_node: BTreeMap<&str, usize> = BTreeMap::new();
+    for pod in pods.iter().filter(|p| is_evicted(p)) {
+        if let Some(node) = pod.spec.as_ref().and_then(|s| s.node_name.as_deref()) {
+            *per_node.entry(node).or_default() += 1;
+        }
+    }
+    let total: usize = per_node.values().sum();
+    let mut flagged = 0;
+    for (node, count) in &per_node {
+        if *count < EVICTED_PODS_PER_NODE {
+            continue;
+        }
+        flagged += 1;
+        issues.push(node_issue(
+            IssueSeverity::Warning,
+            "NODE-030",
+            node,
+            format!("Node {} has {} evicted pod(s)", node, count),
+            "Find the resource pressure that caused the evictions, then delete the evicted pods.",
+            vec![format!(
+                "kubectl get pods -A --field-selector spec.nodeName={},status.phase=Failed",
+                node
+            )],
+        ));
+    }
+    check(
+        "Evicted Pods",
+        format!(
+            "Checks for nodes with {} or more evicted pods",
+            EVICTED_PODS_PER_NODE
+        ),
+        nodes.len(),
+        flagged,
+        CheckStatus::Warning,
+        format!(
+            "{} evicted pod(s) on {} node(s); {} node(s) at or above {}",
+            total,
+            per_node.len(),
+            flagged,
+            EVICTED_PODS_PER_NODE
+        ),
+        "Investigate node pressure behind the evictions (see NODE-030).",
+    )
+}
+
+/// Node a DaemonSet pod is bound to, from `nodeName` or its `metadata.name` node affinity.
+pub(crate) fn daemonset_pod_node(pod: &Pod) -> Option<&str> {
+    let spec = pod.spec.as_ref()?;
+    spec.node_name.as_deref().or_else(|| {
+        spec.affinity
+            .as_ref()?
+            .node_affinity
+            .as_ref()?
+            .required_during_scheduling_ignored_during_execution
+            .as_ref()?
+            .node_selector_terms
+            .iter()
+            .flat_map(|t| t.match_fields.iter().flatten())
+            .find(|f| f.key == "metadata.name" && f.operator == "In")?
+            .values
+            .as_ref()?
+            .first()
+            .map(String::as_str)
+    })
+}
+
+/// NODE-031 per node with a Pending DaemonSet pod the scheduler marked Unschedulable.
+pub fn daemonset_scheduling_check(
+    nodes: &[Node],
+    pods: &[Pod],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut per_node: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for pod in pods {
+        let Some(daemonset) = pod
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .find(|o| o.kind == "DaemonSet")
+        else {
+            continue;
+        };
+        let Some(status) = pod.status.as_ref() else {
+            continue;
+        };
+        if status.phase.as_deref() != Some("Pending") {
+            continue;
+        }
+        let Some(condition) = status.conditions.iter().flatten().find(|c| {
+            c.type_ == "PodScheduled"
+                && c.status == "False"
+                && c.reason.as_deref() == Some("Unschedulable")
+        }) else {
+            continue;
+        };
+        let Some(node) = daemonset_pod_node(pod) else {
+            continue;
+        };
+        per_node.entry(node).or_default().push(format!(
+            "{}/{}{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            daemonset.name,
+            condition
+                .message
+                .as_deref()
+                .map(|m| format!(" ({})", m))
+                .unwrap_or_default()
+        ));
+    }
+    for (node, daemonsets) in &per_node {
+        issues.push(node_issue(
+            IssueSeverity::Warning,
+            "NODE-031",
+            node,
+            format!(
+                "Node {} cannot schedule DaemonSet pod(s): {}",
+                node,
+                daemonsets.join("; ")
+            ),
+            "Free allocatable resources on the node or fix the taints/tolerations that keep the DaemonSet off it.",
+            vec![format!("kubectl describe node {}", node)],
+        ));
+    }
+    check(
+        "DaemonSet Scheduling",
+        "Checks that every node can run its DaemonSet pods".to_string(),
+        nodes.len(),
+        per_node.len(),
+        CheckStatus::Warning,
+        format!(
+            "{} node(s) with unschedulable DaemonSet pods",
+            per_node.len()
+        ),
+        "Make room for DaemonSet pods on the listed nodes (see NODE-031).",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Affinity, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm, NodeSpec,
+        ObjectReference, PodCondition, PodSpec, PodStatus, Taint,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{OwnerReference, Time};
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, cordoned: bool, taint_added: Option<&str>) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            spec: Some(NodeSpec {
+                unschedulable: Some(cordoned),
+                taints: taint_added.map(|t| {
+                    vec![Taint {
+                        key: UNSCHEDULABLE_TAINT.to_string(),
+                        effect: "NoSchedule".to_string(),
+                        time_added: Some(Time(t.parse().unwrap())),
+                        value: None,
+                    }]
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(node: &str, phase: &str, reason: Option<&str>) -> Pod {
+        Pod {
+            spec: Some(PodSpec {
+                node_name: Some(node.to_string()),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some(phase.to_string()),
+                reason: reason.map(str::to_string),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_stale_cordons_by_taint_time_or_event() {
+        let now: DateTime<Utc> = "2024-06-03T00:00:00Z".parse().unwrap();
+        let nodes = vec![
+            node("old", true, Some("2024-06-01T00:00:00Z")),
+            node("recent", true, None),
+            node("unknown", true, None),
+            node("active", false, None),
+        ];
+        let events = vec![Event {
+            reason: Some(NODE_NOT_SCHEDULABLE_REASON.to_string()),
+            involved_object: ObjectReference {
+                kind: Some("Node".to_string()),
+                name: Some("recent".to_string()),
+                ..Default::default()
+            },
+            last_timestamp: Some(Time("2024-06-02T22:00:00Z".parse().unwrap())),
+            ..Default::default()
+        }];
+        let mut issues = Vec::new();
+        let check = cordon_check(&nodes, &events, now, 24, &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("3 of 4 node(s) cordoned, 1 for more than 24 hours")
+        );
+        let found: Vec<_> = issues
+            .iter()
+            .map(|i| (i.resource.as_deref().unwrap(), i.severity.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("old", IssueSeverity::Warning),
+                ("unknown", IssueSeverity::Info)
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_evictions_and_unschedulable_daemonset_pods_per_node() {
+        let nodes = vec![node("a", false, None), node("b", false, None)];
+        let mut pods: Vec<Pod> = (0..5)
+            .map(|_| pod("a", "Failed", Some("Evicted")))
+            .collect();
+        pods.push(pod("b", "Failed", Some("Evicted")));
+        pods.push(pod("b", "Failed", None));
+        let mut issues = Vec::new();
+        let check = evicted_pods_check(&nodes, &pods, &mut issues);
+        assert_eq!(check.score, 50.0);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].description, "Node a has 5 evicted pod(s)");
+
+        let ds_pod = Pod {
+            metadata: ObjectMeta {
+                namespace: Some("monitoring".to_string()),
+                owner_references: Some(vec![OwnerReference {
+                    kind: "DaemonSet".to_string(),
+                    name: "node-exporter".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                affinity: Some(Affinity {
+                    node_affinity: Some(NodeAffinity {
+                        required_during_scheduling_ignored_during_execution: Some(NodeSelector {
+                            node_selector_terms: vec![NodeSelectorTerm {
+                                match_fields: Some(vec![NodeSelectorRequirement {
+                                    key: "metadata.name".to_string(),
+                                    operator: "In".to_string(),
+                                    values: Some(vec!["b".to_string()]),
+                                }]),
+                                ..Default::default()
+                            }],
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Pending".to_string()),
+                conditions: Some(vec![PodCondition {
+                    type_: "PodScheduled".to_string(),
+                    status: "False".to_string(),
+                    reason: Some("Unschedulable".to_string()),
+                    message: Some("0/2 nodes are available: 1 Insufficient cpu.".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        };
+        let mut issues = Vec::new();
+        let check = daemonset_scheduling_check(&nodes, &[ds_pod], &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(
+            issues[0].description,
+            "Node b cannot schedule DaemonSet pod(s): monitoring/node-exporter (0/2 nodes are available: 1 Insufficient cpu.)"
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Node image age and kernel patch level, for the Node Health inspection. The image date comes
+//! from the AKS `kubernetes.azure.com/node-image-version` label or a `YYYYMMDD` stamp in the OS
+//! image (`Amazon Linux 2023.4.20240416`); otherwise the node creation time is used, which is
+//! when the node last picked up an image. Nodes older than `--max-node-image-age` (NODE-025) and
+//! kernels matching `--kernel-denylist` (NODE-026) are flagged.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use k8s_openapi::api::core::v1::Node;
+
+use crate::inspections::provider;
+use crate::inspections::types::*;
+use crate::utils::pattern::matches_any;
+
+/// Default age (days) above which a node image is reported (NODE-025); twice a monthly rotation
+/// plus slack.
+pub const DEFAULT_MAX_NODE_IMAGE_AGE_DAYS: u32 = 90;
+
+const AKS_NODE_IMAGE_LABEL: &str = "kubernetes.azure.com/node-image-version";
+
+/// First `20YYMMDD` run of digits in `text` as a date.
+fn date_stamp(text: &str) -> Option<NaiveDate> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|run| run.len() == 8 && run.starts_with("20"))
+        .find_map(|run| NaiveDate::parse_from_str(run, "%Y%m%d").ok())
+}
+
+/// Image date of `node` and where it came from.
+fn image_date(node: &Node) -> Option<(DateTime<Utc>, ImageAgeSource)> {
+    let aks = node
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get(AKS_NODE_IMAGE_LABEL))
+        .and_then(|v| provider::aks_image_date(v));
+    let os_image = node
+        .status
+        .as_ref()
+        .and_then(|s| s.node_info.as_ref())
+        .and_then(|i| date_stamp(&i.os_image));
+    if let Some(date) = aks.or(os_image) {
+        return Some((
+            date.and_hms_opt(0, 0, 0)?.and_utc(),
+            ImageAgeSource::ImageBuildDate,
+        ));
+    }
+    node.metadata
+        .creation_timestamp
+        .as_ref()
+        .map(|t| (t.0, ImageAgeSource::NodeCreation))
+}
+
+/// Image age of every node that has one, oldest first.
+pub fn image_ages(nodes: &[Node], now: DateTime<Utc>) -> Vec<NodeImageAge> {
+    let mut ages: Vec<NodeImageAge> = nodes
+        .iter()
+        .filter_map(|node| {
+            let (date, source) = image_date(node)?;
+            Some(NodeImageAge {
+                node: node.metadata.name.clone().unwrap_or_default(),
+                os_image: node
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.node_info.as_ref())
+                    .map(|i| i.os_image.clone())
+                    .filter(|i| !i.is_empty()),
+                age_days: (now - date).num_days().max(0) as u64,
+                source,
+            })
+        })
+        .collect();
+    ages.sort_by(|a, b| b.age_days.cmp(&a.age_days).then(a.node.cmp(&b.node)));
+    ages
+}
+
+/// NODE-025 per node whose image is older than `max_age_days`.
+pub fn image_age_check(
+    ages: &[NodeImageAge],
+    max_age_days: u32,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let stale: Vec<&NodeImageAge> = ages
+        .iter()
+        .filter(|a| a.age_days > u64::from(max_age_days))
+        .collect();
+    for age in &stale {
+        issues.push(Issue {
+            severity: IssueSeverity::Warning,
+            category: "Node".to_string(),
+            description: format!(
+                "Node {} runs an image {} days old ({}{}), above {} days",
+                age.node,
+                age.age_days,
+                age.source,
+                age.os_image
+                    .as_deref()
+                    .map(|i| format!(", {}", i))
+                    .unwrap_or_default(),
+                max_age_days
+            ),
+            resource: Some(age.node.clone()),
+            recommendation: "Rotate the node group or upgrade the node image.".to_string(),
+            rule_id: Some("NODE-025".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: vec![format!(
+                "kubectl drain {} --ignore-daemonsets --delete-emptydir-data",
+                age.node
+            )],
+        });
+    }
+    let score = if ages.is_empty() {
+        100.0
+    } else {
+        (ages.len() - stale.len()) as f64 / ages.len() as f64 * 100.0
+    };
+    CheckResult {
+        name: "Node Image Age".to_string(),
+        description: format!(
+            "Checks that node images are at most {} days old",
+            max_age_days
+        ),
+        status: if stale.is_empty() {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score,
+        max_score: 100.0,
+        details: Some(match ages.first() {
+            Some(oldest) => format!(
+                "{}/{} nodes above {} days; oldest {} ({} days, {})",
+                stale.len(),
+                ages.len(),
+                max_age_days,
+                oldest.node,
+                oldest.age_days,
+                oldest.source
+            ),
+            None => "No node image dates available".to_string(),
+        }),
+        recommendations: if stale.is_empty() {
+            vec![]
+        } else {
+            vec!["Rotate nodes onto current images (see NODE-025).".to_string()]
+        },
+    }
+}
+
+/// NODE-026 per node whose kernel matches a `denylist` glob.
+pub fn kernel_denylist_check(
+    nodes: &[Node],
+    denylist: &[String],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut flagged = 0;
+    for node in nodes {
+        let Some(kernel) = node
+            .status
+            .as_ref()
+            .and_then(|s| s.node_info.as_ref())
+            .map(|i| i.kernel_version.as_str())
+            .filter(|k| matches_any(denylist, k))
+        else {
+            continue;
+        };
+        let name = node.metadata.name.as_deref().unwrap_or("unknown");
+        flagged += 1;
+        issues.push(Issue {
+            severity: IssueSeverity::Critical,
+            category: "Node".to_string(),
+            description: format!("Node {} runs denylisted kernel {}", name, kernel),
+            resource: Some(name.to_string()),
+            recommendation: "Move the node to an image with a fixed kernel.".to_string(),
+            rule_id: Some("NODE-026".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+    CheckResult {
+        name: "Kernel Denylist".to_string(),
+        description: "Checks node kernels against the --kernel-denylist patterns".to_string(),
+        status: if flagged > 0 {
+            CheckStatus::Critical
+        } else {
+            CheckStatus::Pass
+        },
+        score: if nodes.is_empty() {
+            100.0
+        } else {
+            (nodes.len() - flagged) as f64 / nodes.len() as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(if denylist.is_empty() {
+            "No kernel denylist configured".to_string()
+        } else {
+            format!(
+                "{}/{} nodes on a denylisted kernel ({})",
+                flagged,
+                nodes.len(),
+                denylist.join(", ")
+            )
+        }),
+        recommendations: if flagged > 0 {
+            vec!["Replace nodes running denylisted kernels (see NODE-026).".to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{NodeStatus, NodeSystemInfo};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, os_image: &str, kernel: &str, created: &str) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                creation_timestamp: Some(Time(created.parse().unwrap())),
+                ..Default::default()
+            },
+            status: Some(NodeStatus {
+                node_info: Some(NodeSystemInfo {
+                    os_image: os_image.to_string(),
+                    kernel_version: kernel.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn image_age_from_os_image_stamp_or_creation_and_kernel_denylist() {
+        let now: DateTime<Utc> = "2024-06-01T00:00:00Z".parse().unwrap();
+        let nodes = vec![
+            node(
+                "al2023",
+                "Amazon Linux 2023.4.20240116",
+                "6.1.79-99.167.amzn2023.x86_64",
+                "2024-05-20T00:00:00Z",
+            ),
+            node(
+                "ubuntu",
+                "Ubuntu 22.04.4 LTS",
+                "5.15.0-1045-aws",
+                "2024-05-11T00:00:00Z",
+            ),
+        ];
+        let ages = image_ages(&nodes, now);
+        assert_eq!(ages[0].node, "al2023");
+        assert_eq!(ages[0].age_days, 137);
+        assert_eq!(ages[0].source, ImageAgeSource::ImageBuildDate);
+        assert_eq!(ages[1].age_days, 21);
+        assert_eq!(ages[1].source, ImageAgeSource::NodeCreation);
+
+        let mut issues = Vec::new();
+        let check = image_age_check(&ages, 90, &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("al2023"));
+
+        let mut issues = Vec::new();
+        let check = kernel_denylist_check(&nodes, &["5.15.0-1045-*".to_string()], &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NODE-026"));
+    }
+}
@@ -0,0 +1,551 @@
+//! Node networking for the Network Connectivity module: kube-proxy and CNI DaemonSet rollout,
+//! the kube-proxy mode against the IPVS module the node inspector found on each host, and pods
+//! stuck in ContainerCreating because the CNI could not set up their sandbox.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::{ConfigMap, Event, Pod};
+use kube::api::ListParams;
+
+use crate::inspections::registry::image_name_tag;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::k8s::K8sClient;
+use crate::node_inspection::NodeInspectionResult;
+
+/// Event reason the kubelet records when the CNI fails to set up a pod sandbox.
+const SANDBOX_FAILURE_REASON: &str = "FailedCreatePodSandBox";
+
+fn ds_resource(ds: &DaemonSet) -> String {
+    format!(
+        "{}/{}",
+        ds.metadata.namespace.as_deref().unwrap_or_default(),
+        ds.metadata.name.as_deref().unwrap_or_default()
+    )
+}
+
+/// "scheduled/desired scheduled, ready/desired ready" and whether the rollout is incomplete.
+fn rollout(ds: &DaemonSet) -> (String, bool) {
+    let Some(s) = ds.status.as_ref() else {
+        return ("no status".to_string(), true);
+    };
+    let desired = s.desired_number_scheduled;
+    let incomplete = s.current_number_scheduled < desired
+        || s.number_ready < desired
+        || s.number_misscheduled > 0;
+    let mut text = format!(
+        "{}/{} scheduled, {}/{} ready",
+        s.current_number_scheduled, desired, s.number_ready, desired
+    );
+    if s.number_misscheduled > 0 {
+        text.push_str(&format!(", {} misscheduled", s.number_misscheduled));
+    }
+    (text, incomplete)
+}
+
+fn container_images(ds: &DaemonSet) -> impl Iterator<Item = &str> {
+    ds.spec
+        .as_ref()
+        .and_then(|s| s.template.spec.as_ref())
+        .into_iter()
+        .flat_map(|s| s.containers.iter())
+        .filter_map(|c| c.image.as_deref())
+}
+
+fn is_kube_proxy(ds: &DaemonSet) -> bool {
+    ds.metadata.namespace.as_deref() == Some("kube-system")
+        && (ds.metadata.name.as_deref() == Some("kube-proxy")
+            || container_images(ds).any(|i| image_name_tag(i).0 == "kube-proxy"))
+}
+
+/// Whether NET-011 or NET-013 already reports the DaemonSet's rollout (kube-proxy, Calico,
+/// Cilium, Flannel).
+pub(crate) fn rollout_checked(ds: &DaemonSet) -> bool {
+    is_kube_proxy(ds) || cni_plugin(ds).is_some()
+}
+
+/// CNI plugin a DaemonSet runs, from its container images.
+fn cni_plugin(ds: &DaemonSet) -> Option<&'static str> {
+    container_images(ds).find_map(|image| {
+        let (name, _) = image_name_tag(image);
+        if image.contains("calico/node") {
+            Some("Calico")
+        } else if name == "cilium" {
+            Some("Cilium")
+        } else if name.contains("flannel") && !name.contains("cni-plugin") {
+            Some("Flannel")
+        } else {
+            None
+        }
+    })
+}
+
+/// kube-proxy mode: `--proxy-mode` on the DaemonSet wins over `mode` in the kube-proxy
+/// ConfigMap (`config.conf`); an empty mode is the Linux default, iptables. None without kube-proxy.
+pub fn kube_proxy_mode(daemon_sets: &[DaemonSet], config_maps: &[ConfigMap]) -> Option<String> {
+    let ds = daemon_sets.iter().find(|d| is_kube_proxy(d))?;
+    let flag = ds
+        .spec
+        .as_ref()
+        .and_then(|s| s.template.spec.as_ref())
+        .into_iter()
+        .flat_map(|s| s.containers.iter())
+        .flat_map(|c| c.command.iter().chain(c.args.iter()).flatten())
+        .find_map(|a| a.strip_prefix("--proxy-mode="));
+    let configured = || {
+        config_maps
+            .iter()
+            .find(|c| {
+                c.metadata.namespace.as_deref() == Some("kube-system")
+                    && c.metadata.name.as_deref() == Some("kube-proxy")
+            })
+            .and_then(|c| c.data.as_ref()?.get("config.conf"))
+            .and_then(|conf| serde_yaml::from_str::<serde_yaml::Value>(conf).ok())
+            .and_then(|v| v.get("mode")?.as_str().map(str::to_string))
+    };
+    let mode = flag
+        .map(str::to_string)
+        .or_else(configured)
+        .unwrap_or_default();
+    Some(if mode.is_empty() {
+        "iptables".to_string()
+    } else {
+        mode
+    })
+}
+
+/// Read the kube-proxy mode from kube-system (DaemonSet flags, then ConfigMap).
+pub async fn fetch_kube_proxy_mode(client: &K8sClient) -> Result<Option<String>> {
+    let lp = ListParams::default();
+    let daemon_sets = client
+        .list_unselected(&client.daemon_sets(Some("kube-system")), &lp)
+        .await?
+        .items;
+    let config_maps = client
+        .list_unselected(&client.config_maps(Some("kube-system")), &lp)
+        .await?
+        .items;
+    Ok(kube_proxy_mode(&daemon_sets, &config_maps))
+}
+
+/// NET-011: kube-proxy DaemonSet not fully scheduled or ready.
+pub fn kube_proxy_check(
+    daemon_sets: &[DaemonSet],
+    mode: Option<&str>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut check = CheckResult {
+        name: "kube-proxy".to_string(),
+        description: "kube-proxy DaemonSet scheduled and ready on every node".to_string(),
+        status: CheckStatus::Pass,
+        score: 100.0,
+        max_score: 100.0,
+        details: None,
+        recommendations: vec![],
+    };
+    let Some(ds) = daemon_sets.iter().find(|d| is_kube_proxy(d)) else {
+        check.details = Some(
+            "kube-proxy DaemonSet not found (kube-proxy replacement or managed add-on?)"
+                .to_string(),
+        );
+        return check;
+    };
+    let (state, incomplete) = rollout(ds);
+    check.details = Some(format!("{}; mode {}", state, mode.unwrap_or("unknown")));
+    if incomplete {
+        issues.push(Issue::new(
+            IssueSeverity::Critical,
+            "DaemonSet",
+            "NET-011",
+            format!("kube-proxy DaemonSet {} is not fully rolled out: {}", ds_resource(ds), state),
+            ds_resource(ds),
+            "Check kube-proxy pods on the missing nodes (taints, image pulls, crash loops); Services do not work on nodes without it.",
+        ));
+        check.status = CheckStatus::Critical;
+        check.score = 0.0;
+        check.recommendations = vec!["Restore kube-proxy on every node (NET-011).".to_string()];
+    }
+    check
+}
+
+/// NET-013: Calico, Cilium or Flannel DaemonSets not fully scheduled or ready.
+pub fn cni_check(daemon_sets: &[DaemonSet], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut rows = Vec::new();
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    for ds in daemon_sets {
+        let Some(plugin) = cni_plugin(ds) else {
+            continue;
+        };
+        evaluated += 1;
+        let (state, incomplete) = rollout(ds);
+        rows.push(format!("{} {}: {}", plugin, ds_resource(ds), state));
+        if incomplete {
+            flagged += 1;
+            issues.push(Issue::new(
+                IssueSeverity::Critical,
+                "DaemonSet",
+                "NET-013",
+                format!(
+                    "{} CNI DaemonSet {} is not fully rolled out: {}",
+                    plugin,
+                    ds_resource(ds),
+                    state
+                ),
+                ds_resource(ds),
+                "Check the CNI agent pods on the affected nodes; pods there cannot get a network.",
+            ));
+        }
+    }
+    let score = if evaluated == 0 {
+        100.0
+    } else {
+        ((evaluated - flagged) as f64 / evaluated as f64) * 100.0
+    };
+    CheckResult {
+        name: "CNI".to_string(),
+        description: "Calico, Cilium and Flannel agent DaemonSets scheduled and ready".to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Critical
+        },
+        score,
+        max_score: 100.0,
+        details: Some(if rows.is_empty() {
+            "No Calico, Cilium or Flannel DaemonSet detected".to_string()
+        } else {
+            rows.join("; ")
+        }),
+        recommendations: if flagged > 0 {
+            vec!["Restore the CNI agent on every node (NET-013).".to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
+
+fn is_container_creating(pod: &Pod) -> bool {
+    let Some(status) = pod.status.as_ref() else {
+        return false;
+    };
+    if status.phase.as_deref() != Some("Pending")
+        || pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.as_ref())
+            .is_none()
+    {
+        return false;
+    }
+    match status.container_statuses.as_deref() {
+        None | Some([]) => true,
+        Some(cs) => cs.iter().any(|c| {
+            c.state
+                .as_ref()
+                .and_then(|s| s.waiting.as_ref())
+                .and_then(|w| w.reason.as_deref())
+                == Some("ContainerCreating")
+        }),
+    }
+}
+
+/// NET-014: pods stuck in ContainerCreating with a sandbox network/CNI failure event.
+pub fn sandbox_failures(pods: &[Pod], events: &[Event], issues: &mut Vec<Issue>) -> CheckResult {
+    // Latest CNI-related sandbox failure message per namespace/pod.
+    let mut failures: BTreeMap<String, &str> = BTreeMap::new();
+    for e in events {
+        if e.reason.as_deref() != Some(SANDBOX_FAILURE_REASON)
+            || e.involved_object.kind.as_deref() != Some("Pod")
+        {
+            continue;
+        }
+        let message = e.message.as_deref().unwrap_or_default();
+        let lower = message.to_lowercase();
+        if !(lower.contains("network") || lower.contains("cni")) {
+            continue;
+        }
+        failures.insert(
+            format!(
+                "{}/{}",
+                e.involved_object.namespace.as_deref().unwrap_or_default(),
+                e.involved_object.name.as_deref().unwrap_or_default()
+            ),
+            message,
+        );
+    }
+
+    let mut nodes = BTreeSet::new();
+    let mut stuck = 0;
+    for pod in pods.iter().filter(|p| is_container_creating(p)) {
+        let key = format!(
+            "{}/{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            pod.metadata.name.as_deref().unwrap_or_default()
+        );
+        let Some(message) = failures.get(&key) else {
+            continue;
+        };
+        stuck += 1;
+        let node = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.as_deref())
+            .unwrap_or_default();
+        nodes.insert(node);
+        let message: String = message.chars().take(200).collect();
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Pod",
+            "NET-014",
+            format!(
+                "Pod {} on node {} is stuck in ContainerCreating: {}",
+                key, node, message
+            ),
+            key.clone(),
+            "Check the CNI agent and its logs on that node (IP pool exhaustion, missing CNI config, agent not ready).",
+        ));
+    }
+    CheckResult {
+        name: "Pod Network Setup".to_string(),
+        description:
+            "Pods stuck in ContainerCreating because the CNI failed to set up their sandbox"
+                .to_string(),
+        status: if stuck == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if stuck == 0 { 100.0 } else { 50.0 },
+        max_score: 100.0,
+        details: Some(format!(
+            "{} pod(s) stuck on {} node(s) with {} events",
+            stuck,
+            nodes.len(),
+            SANDBOX_FAILURE_REASON
+        )),
+        recommendations: if stuck > 0 {
+            vec!["Fix the CNI on the listed nodes (NET-014).".to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
+
+/// NET-012: kube-proxy in ipvs mode on nodes where the node inspector found no ip_vs module.
+/// Returns None without a kube-proxy mode or without IPVS data from the node inspector.
+pub fn evaluate_proxy_mode(
+    mode: Option<&str>,
+    nodes: &[NodeInspectionResult],
+) -> Option<(CheckResult, Vec<Issue>)> {
+    let mode = mode?;
+    let measured: Vec<(&str, bool)> = nodes
+        .iter()
+        .filter_map(|n| n.security.ipvs_loaded.map(|l| (n.node_name.as_str(), l)))
+        .collect();
+    if measured.is_empty() {
+        return None;
+    }
+    let mut issues = Vec::new();
+    if mode == "ipvs" {
+        for (node, _) in measured.iter().filter(|(_, loaded)| !loaded) {
+            issues.push(Issue::new(
+                IssueSeverity::Warning,
+                "Node",
+                "NET-012",
+                format!(
+                    "kube-proxy runs in ipvs mode but the ip_vs module is not loaded on node {}",
+                    node
+                ),
+                node.to_string(),
+                "Load ip_vs, ip_vs_rr, ip_vs_wrr, ip_vs_sh (modules-load.d) and restart kube-proxy, or switch the cluster to iptables/nftables mode.",
+            ));
+        }
+    }
+    let loaded = measured.iter().filter(|(_, l)| *l).count();
+    let check = CheckResult {
+        name: "kube-proxy Mode".to_string(),
+        description: "kube-proxy mode matches the IPVS kernel modules on the nodes".to_string(),
+        status: if issues.is_empty() {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: ((measured.len() - issues.len()) as f64 / measured.len() as f64) * 100.0,
+        max_score: 100.0,
+        details: Some(format!(
+            "mode {}; ip_vs loaded on {} of {} inspected node(s)",
+            mode,
+            loaded,
+            measured.len()
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["Load the IPVS modules on every node (NET-012).".to_string()]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DaemonSetSpec, DaemonSetStatus};
+    use k8s_openapi::api::core::v1::Container;
+    use k8s_openapi::api::core::v1::{
+        ContainerState, ContainerStateWaiting, ContainerStatus, ObjectReference, PodSpec,
+        PodStatus, PodTemplateSpec,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn meta(ns: &str, name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn daemon_set(name: &str, image: &str, args: &[&str], desired: i32, ready: i32) -> DaemonSet {
+        DaemonSet {
+            metadata: meta("kube-system", name),
+            spec: Some(DaemonSetSpec {
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec {
+                        containers: vec![Container {
+                            name: name.to_string(),
+                            image: Some(image.to_string()),
+                            args: Some(args.iter().map(|a| a.to_string()).collect()),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            status: Some(DaemonSetStatus {
+                desired_number_scheduled: desired,
+                current_number_scheduled: desired,
+                number_ready: ready,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn daemon_set_rollout_and_proxy_mode() {
+        let daemon_sets = vec![
+            daemon_set(
+                "kube-proxy",
+                "registry.k8s.io/kube-proxy:v1.29.2",
+                &["--config=/var/lib/kube-proxy/config.conf"],
+                3,
+                2,
+            ),
+            daemon_set("cilium", "quay.io/cilium/cilium:v1.15.1", &[], 3, 3),
+            daemon_set("calico-node", "docker.io/calico/node:v3.27.0", &[], 3, 1),
+        ];
+        let config_maps = vec![ConfigMap {
+            metadata: meta("kube-system", "kube-proxy"),
+            data: Some(
+                [(
+                    "config.conf".to_string(),
+                    "apiVersion: kubeproxy.config.k8s.io/v1alpha1\nmode: ipvs\n".to_string(),
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        }];
+        let mode = kube_proxy_mode(&daemon_sets, &config_maps);
+        assert_eq!(mode.as_deref(), Some("ipvs"));
+        assert_eq!(
+            kube_proxy_mode(&daemon_sets, &[]).as_deref(),
+            Some("iptables")
+        );
+
+        let mut issues = Vec::new();
+        let check = kube_proxy_check(&daemon_sets, mode.as_deref(), &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        let check = cni_check(&daemon_sets, &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        let codes: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, ["NET-011", "NET-013"]);
+        assert_eq!(
+            issues[1].resource.as_deref(),
+            Some("kube-system/calico-node")
+        );
+
+        let nodes: Vec<NodeInspectionResult> = [("n1", true), ("n2", false)]
+            .into_iter()
+            .map(|(name, loaded)| {
+                let mut n = NodeInspectionResult {
+                    node_name: name.to_string(),
+                    ..Default::default()
+                };
+                n.security.ipvs_loaded = Some(loaded);
+                n
+            })
+            .collect();
+        let (check, issues) = evaluate_proxy_mode(mode.as_deref(), &nodes).unwrap();
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("n2"));
+        let (_, issues) = evaluate_proxy_mode(Some("iptables"), &nodes).unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn sandbox_failures_need_cni_event() {
+        let pod = |name: &str| Pod {
+            metadata: meta("app", name),
+            spec: Some(PodSpec {
+                node_name: Some("n1".to_string()),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Pending".to_string()),
+                container_statuses: Some(vec![ContainerStatus {
+                    name: "web".to_string(),
+                    state: Some(ContainerState {
+                        waiting: Some(ContainerStateWaiting {
+                            reason: Some("ContainerCreating".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        };
+        let event = |name: &str, message: &str| Event {
+            metadata: meta("app", &format!("{}.1", name)),
+            reason: Some(SANDBOX_FAILURE_REASON.to_string()),
+            message: Some(message.to_string()),
+            involved_object: ObjectReference {
+                kind: Some("Pod".to_string()),
+                namespace: Some("app".to_string()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let pods = vec![pod("web-1"), pod("web-2"), pod("web-3")];
+        let events = vec![
+            event(
+                "web-1",
+                "Failed to create pod sandbox: plugin type=\"calico\" failed (add): failed to setup network for sandbox",
+            ),
+            event("web-2", "Failed to create pod sandbox: image pull of pause failed"),
+        ];
+        let mut issues = Vec::new();
+        let check = sandbox_failures(&pods, &events, &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("app/web-1"));
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NET-014"));
+    }
+}
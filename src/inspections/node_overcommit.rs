@@ -0,0 +1,186 @@
+//! Limit overcommit per node: the sum of the limits of the pods on a node against its
+//! allocatable. Requests are capped by the scheduler, limits are not; a node whose memory limits
+//! add up to several times its memory runs into OOM kills and evictions as soon as pods use what
+//! they were allowed to, typically all at once (an "OOM storm").
+
+use crate::inspections::types::{
+    CapacityCommitment, CapacityForecast, CheckResult, CheckStatus, Issue, IssueSeverity,
+};
+
+/// Memory limits (percent of allocatable) above which a node is reported (NODE-032).
+pub const DEFAULT_MAX_MEMORY_OVERCOMMIT_PCT: u32 = 200;
+/// CPU limits (percent of allocatable) above which a node is reported (NODE-033).
+pub const DEFAULT_MAX_CPU_OVERCOMMIT_PCT: u32 = 400;
+
+fn pct(used: f64, allocatable: f64) -> Option<f64> {
+    (allocatable > 0.0).then(|| used / allocatable * 100.0)
+}
+
+fn node_issue(
+    severity: IssueSeverity,
+    code: &str,
+    node: &str,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue {
+        severity,
+        category: "Node".to_string(),
+        description,
+        resource: Some(node.to_string()),
+        recommendation: recommendation.to_string(),
+        rule_id: Some(code.to_string()),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
+    }
+}
+
+fn summary(c: &CapacityCommitment) -> String {
+    let show = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:.0}%", v));
+    format!(
+        "CPU requests {} / limits {}, memory requests {} / limits {}",
+        show(pct(c.cpu_requests_cores, c.allocatable_cpu_cores)),
+        show(pct(c.cpu_limits_cores, c.allocatable_cpu_cores)),
+        show(pct(c.memory_requests_gi, c.allocatable_memory_gi)),
+        show(pct(c.memory_limits_gi, c.allocatable_memory_gi)),
+    )
+}
+
+/// "Resource Overcommit" check with NODE-032 (memory limits above `max_memory_pct` of
+/// allocatable, Warning) and NODE-033 (CPU limits above `max_cpu_pct`, Info) per node. Returns
+/// None when no node reports allocatable memory.
+pub fn evaluate_overcommit(
+    capacity: &CapacityForecast,
+    max_cpu_pct: u32,
+    max_memory_pct: u32,
+) -> Option<(CheckResult, Vec<Issue>)> {
+    let measured: Vec<&CapacityCommitment> = capacity
+        .nodes
+        .iter()
+        .filter(|n| n.allocatable_memory_gi > 0.0)
+        .collect();
+    if measured.is_empty() {
+        return None;
+    }
+
+    let mut issues = Vec::new();
+    let mut memory_flagged = 0;
+    for n in &measured {
+        if let Some(p) =
+            pct(n.memory_limits_gi, n.allocatable_memory_gi).filter(|p| *p > max_memory_pct as f64)
+        {
+            memory_flagged += 1;
+            issues.push(node_issue(
+                IssueSeverity::Warning,
+                "NODE-032",
+                &n.scope,
+                format!(
+                    "Node {} has memory limits of {:.1} Gi, {:.0}% of its {:.1} Gi allocatable (threshold {}%)",
+                    n.scope, n.memory_limits_gi, p, n.allocatable_memory_gi, max_memory_pct
+                ),
+                "Lower memory limits towards requests, spread the pods, or add memory; pods that use their limits together will be OOM-killed or evicted.",
+            ));
+        }
+        if let Some(p) =
+            pct(n.cpu_limits_cores, n.allocatable_cpu_cores).filter(|p| *p > max_cpu_pct as f64)
+        {
+            issues.push(node_issue(
+                IssueSeverity::Info,
+                "NODE-033",
+                &n.scope,
+                format!(
+                    "Node {} has CPU limits of {:.1} cores, {:.0}% of its {:.1} allocatable cores (threshold {}%)",
+                    n.scope, n.cpu_limits_cores, p, n.allocatable_cpu_cores, max_cpu_pct
+                ),
+                "Expect CPU throttling when these pods are busy at the same time; lower CPU limits or spread the pods.",
+            ));
+        }
+    }
+
+    let check = CheckResult {
+        name: "Resource Overcommit".to_string(),
+        description: "Requests and limits of the pods on each node against its allocatable"
+            .to_string(),
+        status: if memory_flagged > 0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Pass
+        },
+        score: (measured.len() - memory_flagged) as f64 / measured.len() as f64 * 100.0,
+        max_score: 100.0,
+        details: Some(format!(
+            "{} of {} node(s) with memory limits above {}% of allocatable; cluster: {}",
+            memory_flagged,
+            measured.len(),
+            max_memory_pct,
+            summary(&capacity.cluster)
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec![
+                "See NODE-032/NODE-033; bring limits closer to requests on overcommitted nodes."
+                    .to_string(),
+            ]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, cpu_limits: f64, memory_limits: f64) -> CapacityCommitment {
+        CapacityCommitment {
+            scope: name.to_string(),
+            node_count: 1,
+            allocatable_cpu_cores: 4.0,
+            cpu_requests_cores: 2.0,
+            cpu_limits_cores: cpu_limits,
+            allocatable_memory_gi: 16.0,
+            memory_requests_gi: 8.0,
+            memory_limits_gi: memory_limits,
+        }
+    }
+
+    #[test]
+    fn flags_nodes_whose_limits_exceed_the_thresholds() {
+        let capacity = CapacityForecast {
+            cluster: node("cluster", 24.0, 52.0),
+            node_pools: Vec::new(),
+            nodes: vec![node("a", 4.0, 16.0), node("b", 20.0, 36.0)],
+            trend: None,
+        };
+        let (check, issues) = evaluate_overcommit(
+            &capacity,
+            DEFAULT_MAX_CPU_OVERCOMMIT_PCT,
+            DEFAULT_MAX_MEMORY_OVERCOMMIT_PCT,
+        )
+        .unwrap();
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.resource.as_deref().unwrap(),
+                    i.rule_id.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(found, vec![("b", "NODE-032"), ("b", "NODE-033")]);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(check.score, 50.0);
+        assert!(check
+            .details
+            .unwrap()
+            .ends_with("CPU requests 50% / limits 600%, memory requests 50% / limits 325%"));
+
+        let empty = CapacityForecast {
+            nodes: Vec::new(),
+            ..capacity
+        };
+        assert!(evaluate_overcommit(&empty, 400, 200).is_none());
+    }
+}
@@ -0,0 +1,220 @@
+//! Node-pool aggregation. A node's pool is the value of the first configured label it carries
+//! (e.g. `cloud.google.com/gke-nodepool`, `eks.amazonaws.com/nodegroup`); node rows, usage,
+//! node inspection results and findings are then rolled up per pool for the report.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::inspections::types::{Issue, IssueSeverity, NodePoolRow, NodeRow, NodeUsageRow};
+use crate::node_inspection::NodeInspectionResult;
+
+/// Pool name for nodes that carry none of the configured labels.
+pub const UNASSIGNED_POOL: &str = "(unassigned)";
+
+/// Value of the first label in `pool_labels` present on the node.
+pub fn pool_for_labels(
+    labels: Option<&BTreeMap<String, String>>,
+    pool_labels: &[String],
+) -> Option<String> {
+    let labels = labels?;
+    pool_labels
+        .iter()
+        .find_map(|key| labels.get(key))
+        .filter(|v| !v.is_empty())
+        .cloned()
+}
+
+#[derive(Default)]
+struct PoolAcc {
+    node_count: u32,
+    ready_count: u32,
+    pod_count: u32,
+    kubelet_versions: BTreeSet<String>,
+    kernel_versions: BTreeSet<String>,
+    cpu_pcts: Vec<f64>,
+    memory_pcts: Vec<f64>,
+    max_disk_pct: Option<f64>,
+    inspected_nodes: u32,
+    node_inspection_issues: u32,
+    critical_findings: u32,
+    warning_findings: u32,
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+/// Roll up nodes per pool. Returns None when no node carries a pool label, so clusters
+/// without pools do not get a one-row table.
+pub fn aggregate_node_pools(
+    node_list: &[NodeRow],
+    node_usage: Option<&[NodeUsageRow]>,
+    node_inspection: Option<&[NodeInspectionResult]>,
+    issues: &[&Issue],
+) -> Option<Vec<NodePoolRow>> {
+    if node_list.iter().all(|n| n.node_pool.is_none()) {
+        return None;
+    }
+    let pool_of: HashMap<&str, &str> = node_list
+        .iter()
+        .map(|n| {
+            (
+                n.name.as_str(),
+                n.node_pool.as_deref().unwrap_or(UNASSIGNED_POOL),
+            )
+        })
+        .collect();
+    let mut pools: BTreeMap<&str, PoolAcc> = BTreeMap::new();
+
+    for n in node_list {
+        let acc = pools.entry(pool_of[n.name.as_str()]).or_default();
+        acc.node_count += 1;
+        if n.ready {
+            acc.ready_count += 1;
+        }
+        acc.pod_count += n.pod_count;
+        if !n.kubelet_version.is_empty() {
+            acc.kubelet_versions.insert(n.kubelet_version.clone());
+        }
+        if let Some(k) = &n.kernel_version {
+            acc.kernel_versions.insert(k.clone());
+        }
+    }
+    for u in node_usage.unwrap_or(&[]) {
+        let Some(acc) = pool_of
+            .get(u.node_name.as_str())
+            .and_then(|p| pools.get_mut(p))
+        else {
+            continue;
+        };
+        acc.cpu_pcts.extend(u.cpu_pct);
+        acc.memory_pcts.extend(u.memory_pct);
+        if let Some(d) = u.disk_pct {
+            acc.max_disk_pct = Some(acc.max_disk_pct.map_or(d, |m: f64| m.max(d)));
+        }
+    }
+    for r in node_inspection.unwrap_or(&[]) {
+        let Some(acc) = pool_of
+            .get(r.node_name.as_str())
+            .and_then(|p| pools.get_mut(p))
+        else {
+            continue;
+        };
+        acc.inspected_nodes += 1;
+        acc.node_inspection_issues += r.issue_count;
+    }
+    for issue in issues {
+        let Some(acc) = issue
+            .resource
+            .as_deref()
+            .and_then(|r| pool_of.get(r))
+            .and_then(|p| pools.get_mut(p))
+        else {
+            continue;
+        };
+        match issue.severity {
+            IssueSeverity::Critical => acc.critical_findings += 1,
+            IssueSeverity::Warning => acc.warning_findings += 1,
+            IssueSeverity::Info => {}
+        }
+    }
+
+    Some(
+        pools
+            .into_iter()
+            .map(|(pool, acc)| NodePoolRow {
+                pool: pool.to_string(),
+                node_count: acc.node_count,
+                ready_count: acc.ready_count,
+                pod_count: acc.pod_count,
+                kubelet_versions: acc.kubelet_versions.into_iter().collect(),
+                kernel_versions: acc.kernel_versions.into_iter().collect(),
+                avg_cpu_pct: mean(&acc.cpu_pcts),
+                avg_memory_pct: mean(&acc.memory_pcts),
+                max_disk_pct: acc.max_disk_pct,
+                inspected_nodes: acc.inspected_nodes,
+                node_inspection_issues: acc.node_inspection_issues,
+                critical_findings: acc.critical_findings,
+                warning_findings: acc.warning_findings,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, pool: Option<&str>, kubelet: &str) -> NodeRow {
+        NodeRow {
+            name: name.to_string(),
+            operating_system: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            kubelet_version: kubelet.to_string(),
+            ready: true,
+            pod_count: 10,
+            allocatable_pods: None,
+            node_address: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime_version: None,
+            node_pool: pool.map(|p| p.to_string()),
+            zone: None,
+            spot: false,
+        }
+    }
+
+    #[test]
+    fn first_configured_label_wins() {
+        let labels: BTreeMap<String, String> = [
+            ("agentpool".to_string(), "sys".to_string()),
+            (
+                "eks.amazonaws.com/nodegroup".to_string(),
+                "ng-1".to_string(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let keys = vec![
+            "eks.amazonaws.com/nodegroup".to_string(),
+            "agentpool".to_string(),
+        ];
+        assert_eq!(
+            pool_for_labels(Some(&labels), &keys).as_deref(),
+            Some("ng-1")
+        );
+        assert_eq!(pool_for_labels(Some(&labels), &["x".to_string()]), None);
+    }
+
+    #[test]
+    fn aggregates_versions_and_findings_per_pool() {
+        let nodes = vec![
+            node("a1", Some("apps"), "v1.28.3"),
+            node("a2", Some("apps"), "v1.27.9"),
+            node("s1", None, "v1.28.3"),
+        ];
+        let issue = Issue {
+            severity: IssueSeverity::Critical,
+            category: "Node".to_string(),
+            description: String::new(),
+            resource: Some("a2".to_string()),
+            recommendation: String::new(),
+            rule_id: None,
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        };
+        let rows = aggregate_node_pools(&nodes, None, None, &[&issue]).unwrap();
+        assert_eq!(rows.len(), 2);
+        let apps = rows.iter().find(|r| r.pool == "apps").unwrap();
+        assert_eq!(apps.node_count, 2);
+        assert_eq!(apps.kubelet_versions.len(), 2);
+        assert_eq!(apps.critical_findings, 1);
+        assert!(rows.iter().any(|r| r.pool == UNASSIGNED_POOL));
+        assert!(aggregate_node_pools(&nodes[2..], None, None, &[]).is_none());
+    }
+}
@@ -0,0 +1,199 @@
+//! Node headroom from live metrics (metrics-server usage vs allocatable).
+//! Flags nodes close to eviction thresholds and clusters where load lands on a few nodes.
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity, NodeUsageRow};
+
+/// Usage (percent of allocatable) at which a node is reported as running hot.
+pub const NODE_USAGE_WARNING_PCT: f64 = 85.0;
+/// Usage (percent of allocatable) at which a node is at risk of eviction / throttling.
+pub const NODE_USAGE_CRITICAL_PCT: f64 = 95.0;
+/// Median usage of the remaining nodes below which a hot node counts as unbalanced scheduling.
+pub const NODE_IDLE_MEDIAN_PCT: f64 = 30.0;
+
+fn usage_pct(row: &NodeUsageRow, resource: &str) -> Option<f64> {
+    if resource == "CPU" {
+        row.cpu_pct
+    } else {
+        row.memory_pct
+    }
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Compute the "Node Headroom" check and NODE-008/009/010 issues from node usage rows.
+/// Returns None when no row carries a usage percentage (metrics unavailable).
+pub fn evaluate_node_headroom(rows: &[NodeUsageRow]) -> Option<(CheckResult, Vec<Issue>)> {
+    let measured: Vec<&NodeUsageRow> = rows
+        .iter()
+        .filter(|r| r.cpu_pct.is_some() || r.memory_pct.is_some())
+        .collect();
+    if measured.is_empty() {
+        return None;
+    }
+
+    let mut issues = Vec::new();
+    let mut hot_nodes = 0usize;
+
+    for row in &measured {
+        let mut node_hot = false;
+        for (resource, pct) in [("CPU", row.cpu_pct), ("memory", row.memory_pct)] {
+            let Some(pct) = pct else { continue };
+            let (severity, rule_id) = if pct >= NODE_USAGE_CRITICAL_PCT {
+                (IssueSeverity::Critical, "NODE-009")
+            } else if pct >= NODE_USAGE_WARNING_PCT {
+                (IssueSeverity::Warning, "NODE-008")
+            } else {
+                continue;
+            };
+            node_hot = true;
+            issues.push(Issue {
+                severity,
+                category: "Node".to_string(),
+                description: format!(
+                    "Node {} {} usage is {:.1}% of allocatable ({:.1}% headroom)",
+                    row.node_name,
+                    resource,
+                    pct,
+                    (100.0 - pct).max(0.0)
+                ),
+                resource: Some(row.node_name.clone()),
+                recommendation: format!(
+                    "Reduce {} load on this node (rebalance, right-size requests) or add capacity before evictions or throttling start.",
+                    resource
+                ),
+                rule_id: Some(rule_id.to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+        if node_hot {
+            hot_nodes += 1;
+        }
+    }
+
+    // Unbalanced scheduling: a node is hot while the median of the other nodes is idle.
+    if measured.len() >= 2 {
+        for resource in ["CPU", "memory"] {
+            let Some((hot, hot_pct)) = measured
+                .iter()
+                .filter_map(|r| usage_pct(r, resource).map(|p| (*r, p)))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            else {
+                continue;
+            };
+            if hot_pct < NODE_USAGE_WARNING_PCT {
+                continue;
+            }
+            let mut others: Vec<f64> = measured
+                .iter()
+                .filter(|r| r.node_name != hot.node_name)
+                .filter_map(|r| usage_pct(r, resource))
+                .collect();
+            if let Some(med) = median(&mut others) {
+                if med <= NODE_IDLE_MEDIAN_PCT {
+                    issues.push(Issue {
+                        severity: IssueSeverity::Warning,
+                        category: "Node".to_string(),
+                        description: format!(
+                            "Unbalanced {} scheduling: node {} at {:.1}% while the median of other nodes is {:.1}%",
+                            resource, hot.node_name, hot_pct, med
+                        ),
+                        resource: Some(hot.node_name.clone()),
+                        recommendation: "Review nodeSelector/affinity, taints and topology spread constraints; consider the descheduler to rebalance pods.".to_string(),
+                        rule_id: Some("NODE-010".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
+                    });
+                }
+            }
+        }
+    }
+
+    let total = measured.len();
+    let score = ((total - hot_nodes) as f64 / total as f64) * 100.0;
+    let has_critical = issues.iter().any(|i| i.severity == IssueSeverity::Critical);
+    let status = if has_critical {
+        CheckStatus::Critical
+    } else if !issues.is_empty() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+
+    let check = CheckResult {
+        name: "Node Headroom".to_string(),
+        description: "Checks live CPU/memory usage against node allocatable and scheduling balance"
+            .to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} nodes below {}% CPU and memory usage",
+            total - hot_nodes,
+            total,
+            NODE_USAGE_WARNING_PCT
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["Rebalance workloads or add node capacity for hot nodes".to_string()]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(name: &str, cpu: f64, mem: f64) -> NodeUsageRow {
+        NodeUsageRow {
+            node_name: name.to_string(),
+            allocatable_cpu_cores: Some(4.0),
+            cpu_usage: String::new(),
+            cpu_pct: Some(cpu),
+            allocatable_memory_gi: Some(16.0),
+            memory_usage: String::new(),
+            memory_pct: Some(mem),
+            disk_allocatable_gi: None,
+            disk_usage_gi: None,
+            disk_pct: None,
+        }
+    }
+
+    #[test]
+    fn healthy_nodes_pass() {
+        let (check, issues) =
+            evaluate_node_headroom(&[row("a", 40.0, 50.0), row("b", 45.0, 55.0)]).unwrap();
+        assert!(matches!(check.status, CheckStatus::Pass));
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn hot_node_among_idle_nodes_is_unbalanced() {
+        let rows = [
+            row("hot", 96.0, 40.0),
+            row("b", 5.0, 20.0),
+            row("c", 10.0, 20.0),
+        ];
+        let (check, issues) = evaluate_node_headroom(&rows).unwrap();
+        assert!(matches!(check.status, CheckStatus::Critical));
+        let codes: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["NODE-009", "NODE-010"]);
+    }
+}
@@ -0,0 +1,269 @@
+//! Ephemeral storage checks per node: allocatable ephemeral-storage, kubelet DiskPressure, and
+//! nodefs/imagefs partition usage measured by the node inspector. A full imagefs makes the kubelet
+//! garbage-collect images in a loop (and re-pull them), a full nodefs triggers pod evictions.
+
+use k8s_openapi::api::core::v1::Node;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::node_inspection::{NodeInspectionResult, NodePartitionUsage};
+use crate::utils::resource_quantity::parse_memory_str;
+
+/// Allocatable ephemeral-storage below this (GiB) leaves little room for logs, emptyDir and image layers.
+pub const MIN_ALLOCATABLE_EPHEMERAL_GI: f64 = 10.0;
+/// Kubelet default imageGCHighThresholdPercent; image GC starts above it.
+pub const IMAGEFS_GC_HIGH_PCT: f64 = 85.0;
+/// Warn this many points before the GC / eviction threshold is reached.
+pub const PARTITION_WARNING_MARGIN_PCT: f64 = 5.0;
+/// Kubelet default eviction-hard nodefs.available<10%.
+pub const NODEFS_EVICTION_PCT: f64 = 90.0;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+fn has_disk_pressure(node: &Node) -> bool {
+    node.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conds| {
+            conds
+                .iter()
+                .any(|c| c.type_ == "DiskPressure" && c.status == "True")
+        })
+        .unwrap_or(false)
+}
+
+fn partition_issue(
+    node_name: &str,
+    label: &str,
+    part: &NodePartitionUsage,
+    threshold_pct: f64,
+    disk_pressure: bool,
+    rule_id: &str,
+) -> Option<Issue> {
+    let used = part.used_pct?;
+    let severity = if used >= threshold_pct {
+        IssueSeverity::Critical
+    } else if used >= threshold_pct - PARTITION_WARNING_MARGIN_PCT {
+        IssueSeverity::Warning
+    } else {
+        return None;
+    };
+    let pressure = if disk_pressure {
+        "; kubelet reports DiskPressure"
+    } else {
+        ""
+    };
+    Some(Issue {
+        severity,
+        category: "Node".to_string(),
+        description: format!(
+            "Node {} {} ({}) is {:.1}% used (threshold {}%){}",
+            node_name, label, part.path, used, threshold_pct, pressure
+        ),
+        resource: Some(node_name.to_string()),
+        recommendation: if rule_id == "NODE-012" {
+            "Prune unused images (crictl rmi --prune), reduce image sizes, or move the container runtime root to a larger partition.".to_string()
+        } else {
+            "Clean up pod logs and emptyDir usage, set ephemeral-storage limits, or grow the kubelet root partition.".to_string()
+        },
+        rule_id: Some(rule_id.to_string()),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
+    })
+}
+
+/// Build the "Ephemeral Storage" check and NODE-011/012/013 issues.
+/// Partition usage is only evaluated when node inspector results are available.
+pub fn evaluate_ephemeral_storage(
+    nodes: &[Node],
+    results: Option<&[NodeInspectionResult]>,
+) -> Option<(CheckResult, Vec<Issue>)> {
+    if nodes.is_empty() {
+        return None;
+    }
+    let mut issues = Vec::new();
+    let mut at_risk = 0usize;
+
+    for node in nodes {
+        let node_name = node.metadata.name.as_deref().unwrap_or("unknown");
+        let disk_pressure = has_disk_pressure(node);
+        let before = issues.len();
+
+        let allocatable_gi = node
+            .status
+            .as_ref()
+            .and_then(|s| s.allocatable.as_ref())
+            .and_then(|a| a.get("ephemeral-storage"))
+            .and_then(|q| parse_memory_str(&q.0))
+            .map(|b| b as f64 / GIB);
+        match allocatable_gi {
+            None => issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "Node".to_string(),
+                description: format!(
+                    "Node {} reports no allocatable ephemeral-storage",
+                    node_name
+                ),
+                resource: Some(node_name.to_string()),
+                recommendation: "Check kubelet root directory and LocalStorageCapacityIsolation so ephemeral-storage can be accounted and enforced.".to_string(),
+                rule_id: Some("NODE-011".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }),
+            Some(gi) if gi < MIN_ALLOCATABLE_EPHEMERAL_GI => issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "Node".to_string(),
+                description: format!(
+                    "Node {} has only {:.1} GiB allocatable ephemeral-storage",
+                    node_name, gi
+                ),
+                resource: Some(node_name.to_string()),
+                recommendation: "Grow the kubelet root partition or lower ephemeral-storage reservations (kube-reserved/system-reserved).".to_string(),
+                rule_id: Some("NODE-011".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }),
+            _ => {}
+        }
+
+        if let Some(parts) = results
+            .and_then(|rs| rs.iter().find(|r| r.node_name == node_name))
+            .and_then(|r| r.storage_partitions.as_ref())
+        {
+            if let Some(imagefs) = &parts.imagefs {
+                issues.extend(partition_issue(
+                    node_name,
+                    "imagefs",
+                    imagefs,
+                    IMAGEFS_GC_HIGH_PCT,
+                    disk_pressure,
+                    "NODE-012",
+                ));
+            }
+            if let Some(nodefs) = &parts.nodefs {
+                // Runtime root on the kubelet partition: imagefs issue already covers it.
+                let shared = parts
+                    .imagefs
+                    .as_ref()
+                    .map(|i| !i.device.is_empty() && i.device == nodefs.device)
+                    .unwrap_or(false);
+                if !shared {
+                    issues.extend(partition_issue(
+                        node_name,
+                        "nodefs",
+                        nodefs,
+                        NODEFS_EVICTION_PCT,
+                        disk_pressure,
+                        "NODE-013",
+                    ));
+                }
+            }
+        }
+
+        if issues.len() > before || disk_pressure {
+            at_risk += 1;
+        }
+    }
+
+    let total = nodes.len();
+    let score = ((total - at_risk) as f64 / total as f64) * 100.0;
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if at_risk > 0 {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let check = CheckResult {
+        name: "Ephemeral Storage".to_string(),
+        description:
+            "Checks allocatable ephemeral-storage, DiskPressure and nodefs/imagefs usage per node"
+                .to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} nodes without ephemeral storage risk{}",
+            total - at_risk,
+            total,
+            if results.is_none() {
+                " (partition usage unavailable: node inspector not deployed)"
+            } else {
+                ""
+            }
+        )),
+        recommendations: if at_risk > 0 {
+            vec![
+                "Free image and log space on affected nodes before image GC or evictions start"
+                    .to_string(),
+            ]
+        } else {
+            vec![]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_inspection::NodeStoragePartitions;
+    use k8s_openapi::api::core::v1::NodeStatus;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use std::collections::BTreeMap;
+
+    fn node(name: &str, ephemeral: &str) -> Node {
+        let mut allocatable = BTreeMap::new();
+        allocatable.insert(
+            "ephemeral-storage".to_string(),
+            Quantity(ephemeral.to_string()),
+        );
+        let mut n = Node::default();
+        n.metadata.name = Some(name.to_string());
+        n.status = Some(NodeStatus {
+            allocatable: Some(allocatable),
+            ..Default::default()
+        });
+        n
+    }
+
+    fn part(path: &str, device: &str, pct: f64) -> NodePartitionUsage {
+        NodePartitionUsage {
+            path: path.to_string(),
+            device: device.to_string(),
+            total_g: Some(100.0),
+            used_g: Some(pct),
+            used_pct: Some(pct),
+        }
+    }
+
+    #[test]
+    fn small_allocatable_is_flagged() {
+        let (_, issues) = evaluate_ephemeral_storage(&[node("n1", "5Gi")], None).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NODE-011"));
+    }
+
+    #[test]
+    fn imagefs_above_gc_threshold_is_critical() {
+        let mut r = NodeInspectionResult {
+            node_name: "n1".to_string(),
+            ..Default::default()
+        };
+        r.storage_partitions = Some(NodeStoragePartitions {
+            nodefs: Some(part("/var/lib/kubelet", "/dev/sda1", 87.0)),
+            imagefs: Some(part("/var/lib/containerd", "/dev/sdb1", 88.0)),
+        });
+        let (check, issues) =
+            evaluate_ephemeral_storage(&[node("n1", "50Gi")], Some(&[r])).unwrap();
+        assert!(matches!(check.status, CheckStatus::Critical));
+        let codes: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["NODE-012", "NODE-013"]);
+        assert_eq!(issues[1].severity, IssueSeverity::Warning);
+    }
+}
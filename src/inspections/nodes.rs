@@ -3,23 +3,32 @@ use chrono::Utc;
 use kube::api::ListParams;
 use log::{info, warn};
 
+use crate::inspections::node_heartbeat;
+use crate::inspections::node_hygiene;
+use crate::inspections::node_images;
+use crate::inspections::options::InspectionOptions;
 use crate::inspections::types::*;
+use crate::inspections::windows;
 use crate::k8s::K8sClient;
 
 pub struct NodeInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
 }
 
 impl<'a> NodeInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
     }
 
     pub async fn inspect(&self) -> Result<InspectionResult> {
         info!("Starting node health inspection");
 
         let nodes_api = self.client.nodes();
-        let nodes = nodes_api.list(&ListParams::default()).await?;
+        let nodes = self
+            .client
+            .list_all(&nodes_api, &ListParams::default())
+            .await?;
 
         let mut checks = Vec::new();
         let mut issues = Vec::new();
@@ -48,27 +57,38 @@ impl<'a> NodeInspector<'a> {
                                         recommendation: "Check node logs and system resources"
                                             .to_string(),
                                         rule_id: Some("NODE-001".to_string()),
+                                        team: None,
+                                        fingerprint: None,
+                                        key_attributes: Vec::new(),
+                                        remediation_commands: vec![format!(
+                                            "kubectl describe node {}",
+                                            node_name
+                                        )],
                                     });
                                 }
                             }
-                            "MemoryPressure" | "DiskPressure" | "PIDPressure" => {
-                                if condition.status == "True" {
-                                    nodes_with_pressure += 1;
-                                    issues.push(Issue {
-                                        severity: IssueSeverity::Warning,
-                                        category: "Node".to_string(),
-                                        description: format!(
-                                            "Node {} has {}",
-                                            node_name, condition.type_
-                                        ),
-                                        resource: Some(node_name.to_string()),
-                                        recommendation: format!(
-                                            "Investigate {} on node",
-                                            condition.type_
-                                        ),
-                                        rule_id: Some("NODE-002".to_string()),
-                                    });
-                                }
+                            "MemoryPressure" | "DiskPressure" | "PIDPressure"
+                                if condition.status == "True" =>
+                            {
+                                nodes_with_pressure += 1;
+                                issues.push(Issue {
+                                    severity: IssueSeverity::Warning,
+                                    category: "Node".to_string(),
+                                    description: format!(
+                                        "Node {} has {}",
+                                        node_name, condition.type_
+                                    ),
+                                    resource: Some(node_name.to_string()),
+                                    recommendation: format!(
+                                        "Investigate {} on node",
+                                        condition.type_
+                                    ),
+                                    rule_id: Some("NODE-002".to_string()),
+                                    team: None,
+                                    fingerprint: None,
+                                    key_attributes: Vec::new(),
+                                    remediation_commands: Vec::new(),
+                                });
                             }
                             _ => {}
                         }
@@ -147,6 +167,100 @@ impl<'a> NodeInspector<'a> {
             },
         });
 
+        // Image age and kernel patch level
+        let ages = node_images::image_ages(&nodes.items, Utc::now());
+        checks.push(node_images::image_age_check(
+            &ages,
+            self.options.max_node_image_age_days,
+            &mut issues,
+        ));
+        checks.push(node_images::kernel_denylist_check(
+            &nodes.items,
+            &self.options.kernel_denylist,
+            &mut issues,
+        ));
+
+        // Cordon/drain hygiene: only pods that are not Running or Succeeded (evicted and Pending
+        // DaemonSet pods) and NodeNotSchedulable events are listed. Missing access skips the data.
+        let pods = self
+            .client
+            .list_unselected(
+                &self.client.pods(None),
+                &ListParams::default().fields("status.phase!=Running,status.phase!=Succeeded"),
+            )
+            .await
+            .map(|l| l.items)
+            .unwrap_or_else(|e| {
+                warn!("Listing pods for node hygiene failed: {:#}", e);
+                Vec::new()
+            });
+        let cordon_events = self
+            .client
+            .list_all(
+                &self.client.events(None),
+                &ListParams::default().fields(&format!(
+                    "reason={}",
+                    node_hygiene::NODE_NOT_SCHEDULABLE_REASON
+                )),
+            )
+            .await
+            .map(|l| l.items)
+            .unwrap_or_default();
+        checks.push(node_hygiene::cordon_check(
+            &nodes.items,
+            &cordon_events,
+            Utc::now(),
+            self.options.max_cordon_hours,
+            &mut issues,
+        ));
+        checks.push(node_hygiene::evicted_pods_check(
+            &nodes.items,
+            &pods,
+            &mut issues,
+        ));
+        checks.push(node_hygiene::daemonset_scheduling_check(
+            &nodes.items,
+            &pods,
+            &mut issues,
+        ));
+
+        // Heartbeats: node Leases (missing access skips NODE-034) and NodeNotReady events
+        let node_leases = self
+            .client
+            .list_unselected(
+                &self
+                    .client
+                    .leases(Some(node_heartbeat::NODE_LEASE_NAMESPACE)),
+                &ListParams::default(),
+            )
+            .await
+            .map(|l| l.items)
+            .map_err(|e| warn!("Listing node Leases failed: {:#}", e))
+            .ok();
+        let not_ready_events = self
+            .client
+            .list_all(
+                &self.client.events(None),
+                &ListParams::default()
+                    .fields(&format!("reason={}", node_heartbeat::NODE_NOT_READY_REASON)),
+            )
+            .await
+            .map(|l| l.items)
+            .unwrap_or_default();
+        checks.push(node_heartbeat::node_heartbeat_check(
+            &nodes.items,
+            node_leases.as_deref(),
+            &not_ready_events,
+            Utc::now(),
+            &mut issues,
+        ));
+
+        // Windows nodes: supported runtime and pause image
+        if !windows::windows_node_names(&nodes.items).is_empty() {
+            checks.push(windows::runtime_check(&nodes.items, &mut issues));
+            checks.push(windows::pause_image_check(&nodes.items, &mut issues));
+        }
+
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
         let summary = self.create_summary(&checks, issues);
@@ -160,6 +274,8 @@ impl<'a> NodeInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
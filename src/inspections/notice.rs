@@ -0,0 +1,26 @@
+//! Messages for the person running an inspection (waiting for node inspector pods, a module that
+//! failed, node data that is missing), apart from the report itself. Library callers get them
+//! through `log` unless they install their own [`Notify`]; the CLI prints them.
+
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeLevel {
+    /// Still working, e.g. waiting for node inspector logs.
+    Progress,
+    /// Something is not set up and was skipped.
+    Info,
+    /// Something failed; the report is incomplete.
+    Warning,
+}
+
+/// Receiver of notices, see [`InspectionRunner::with_notify`](super::InspectionRunner::with_notify).
+pub type Notify = Arc<dyn Fn(NoticeLevel, &str) + Send + Sync>;
+
+/// Default [`Notify`]: `log` at warn level for warnings, info otherwise.
+pub fn log_notify() -> Notify {
+    Arc::new(|level, message| match level {
+        NoticeLevel::Warning => log::warn!("{}", message),
+        NoticeLevel::Progress | NoticeLevel::Info => log::info!("{}", message),
+    })
+}
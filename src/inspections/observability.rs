@@ -21,6 +21,29 @@ impl<'a> ObservabilityInspector<'a> {
         Self { client }
     }
 
+    /// Whether any of `namespaces` (listed concurrently; unreadable ones are skipped) has a ready
+    /// pod whose name contains one of `identifiers`.
+    async fn ready_pod_in_any(&self, namespaces: &[&str], identifiers: &[&str]) -> bool {
+        let lists = self
+            .client
+            .fan_out(namespaces.iter().copied(), |ns| async move {
+                let api = self.client.pods(Some(ns));
+                self.client
+                    .list_unselected(&api, &ListParams::default())
+                    .await
+            })
+            .await;
+        lists.into_iter().flatten().any(|list| {
+            list.items.iter().any(|pod| {
+                pod.metadata
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| identifiers.iter().any(|id| name.contains(id)))
+                    && is_pod_ready(pod)
+            })
+        })
+    }
+
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
         let mut checks = Vec::new();
         let mut issues = Vec::new();
@@ -56,13 +79,18 @@ impl<'a> ObservabilityInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
     async fn inspect_metrics_components(&self, issues: &mut Vec<Issue>) -> Result<CheckResult> {
         // metrics-server: typically in kube-system
         let pods_api = self.client.pods(Some("kube-system"));
-        let pods = pods_api.list(&ListParams::default()).await?;
+        let pods = self
+            .client
+            .list_unselected(&pods_api, &ListParams::default())
+            .await?;
 
         let mut metrics_server_found = false;
         let mut kube_state_metrics_found = false;
@@ -88,26 +116,12 @@ impl<'a> ObservabilityInspector<'a> {
 
         // kube-state-metrics may run in prometheus or monitoring namespace
         if !kube_state_metrics_found {
-            for ns in &["prometheus", "monitoring"] {
-                let api = self.client.pods(Some(ns));
-                if let Ok(list) = api.list(&ListParams::default()).await {
-                    for pod in &list.items {
-                        if let Some(name) = pod.metadata.name.as_deref() {
-                            if KUBE_STATE_METRICS_IDENTIFIERS
-                                .iter()
-                                .any(|id| name.contains(id))
-                                && is_pod_ready(pod)
-                            {
-                                kube_state_metrics_found = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-                if kube_state_metrics_found {
-                    break;
-                }
-            }
+            kube_state_metrics_found = self
+                .ready_pod_in_any(
+                    &["prometheus", "monitoring"],
+                    &KUBE_STATE_METRICS_IDENTIFIERS,
+                )
+                .await;
         }
 
         let mut score: f64 = 100.0;
@@ -123,6 +137,10 @@ impl<'a> ObservabilityInspector<'a> {
                 recommendation: "Deploy metrics-server to enable HPA and kubectl top commands."
                     .to_string(),
                 rule_id: Some("OBS-001".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             recommendations.push("Install metrics-server for core metrics APIs.".to_string());
         }
@@ -137,6 +155,10 @@ impl<'a> ObservabilityInspector<'a> {
                 recommendation: "Deploy kube-state-metrics to expose Kubernetes object metrics."
                     .to_string(),
                 rule_id: Some("OBS-002".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             recommendations.push("Install kube-state-metrics for Prometheus scraping.".to_string());
         }
@@ -174,7 +196,10 @@ impl<'a> ObservabilityInspector<'a> {
 
     async fn inspect_coredns(&self, issues: &mut Vec<Issue>) -> Result<CheckResult> {
         let pods_api = self.client.pods(Some("kube-system"));
-        let pods = pods_api.list(&ListParams::default()).await?;
+        let pods = self
+            .client
+            .list_unselected(&pods_api, &ListParams::default())
+            .await?;
 
         let mut ready = 0u32;
         let mut total = 0u32;
@@ -198,6 +223,10 @@ impl<'a> ObservabilityInspector<'a> {
                 recommendation: "Ensure CoreDNS or kube-dns is deployed for cluster DNS."
                     .to_string(),
                 rule_id: Some("OBS-003".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             (CheckStatus::Critical, 0.0, "CoreDNS: not found".to_string())
         } else if ready < total {
@@ -232,7 +261,10 @@ impl<'a> ObservabilityInspector<'a> {
     ) -> Result<CheckResult> {
         let target_ns = namespace.unwrap_or("kube-system");
         let pods_api = self.client.pods(Some(target_ns));
-        let pods = pods_api.list(&ListParams::default()).await?;
+        let pods = self
+            .client
+            .list_unselected(&pods_api, &ListParams::default())
+            .await?;
 
         let mut logging_found = false;
         for pod in &pods.items {
@@ -266,6 +298,10 @@ impl<'a> ObservabilityInspector<'a> {
                 recommendation: "Deploy Fluentd/Vector/Logstash to aggregate cluster logs."
                     .to_string(),
                 rule_id: Some("OBS-003".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             Ok(CheckResult {
                 name: "Logging Stack".to_string(),
@@ -293,25 +329,9 @@ impl<'a> ObservabilityInspector<'a> {
             "kube-system",
         ];
 
-        let mut prometheus_found = false;
-        for ns in &potential_namespaces {
-            let pods_api = self.client.pods(Some(ns));
-            if let Ok(pods) = pods_api.list(&ListParams::default()).await {
-                for pod in pods.items {
-                    if let Some(name) = pod.metadata.name.as_deref() {
-                        if PROMETHEUS_IDENTIFIERS.iter().any(|id| name.contains(id))
-                            && is_pod_ready(&pod)
-                        {
-                            prometheus_found = true;
-                            break;
-                        }
-                    }
-                }
-            }
-            if prometheus_found {
-                break;
-            }
-        }
+        let prometheus_found = self
+            .ready_pod_in_any(&potential_namespaces, &PROMETHEUS_IDENTIFIERS)
+            .await;
 
         if prometheus_found {
             Ok(CheckResult {
@@ -332,6 +352,10 @@ impl<'a> ObservabilityInspector<'a> {
                 recommendation: "Deploy Prometheus/Thanos or integrate with managed monitoring."
                     .to_string(),
                 rule_id: Some("OBS-004".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             Ok(CheckResult {
                 name: "Monitoring & Alerting".to_string(),
@@ -0,0 +1,108 @@
+//! Tunables for inspections that users can override from the command line.
+
+use crate::cli::{ActiveProbe, NodeCollectMode};
+
+/// Options shared by inspectors. `Default` holds the built-in thresholds and patterns.
+#[derive(Debug, Clone)]
+pub struct InspectionOptions {
+    /// Glob patterns (comma-separated on the CLI) for namespaces treated as production tier.
+    pub prod_namespace_patterns: Vec<String>,
+    /// Glob patterns of `NAME=value` container env entries that indicate debug logging.
+    pub debug_env_patterns: Vec<String>,
+    /// Node label keys that name a node pool, in priority order.
+    pub node_pool_labels: Vec<String>,
+    /// Finished standalone Jobs older than this many days are reported as orphaned.
+    pub stale_job_days: u32,
+    /// Succeeded/Failed pods older than this many days are reported as orphaned.
+    pub stale_pod_days: u32,
+    /// Node images older than this many days are reported (NODE-025).
+    pub max_node_image_age_days: u32,
+    /// Glob patterns of node kernel versions with known regressions (NODE-026).
+    pub kernel_denylist: Vec<String>,
+    /// Hours a node may stay cordoned before it is reported (NODE-029).
+    pub max_cordon_hours: u32,
+    /// Memory limits, in percent of node allocatable, above which a node is reported (NODE-032).
+    pub max_memory_overcommit_pct: u32,
+    /// CPU limits, in percent of node allocatable, above which a node is reported (NODE-033).
+    pub max_cpu_overcommit_pct: u32,
+    /// Label keys every namespace outside `default` and `kube-*` must carry (POLICY-007).
+    pub required_namespace_labels: Vec<String>,
+    /// Namespaces without workloads older than this many days are reported (POLICY-006; 0 = off).
+    pub empty_namespace_days: u32,
+    /// Minutes after which an object with a `deletionTimestamp` is stuck (ORPH-013, POLICY-005).
+    pub stuck_deletion_minutes: u32,
+    /// Event analytics window in minutes.
+    pub event_window_minutes: u32,
+    /// Warning events per hour (one reason and kind) above which a reason is reported as noisy.
+    pub noisy_events_per_hour: u32,
+    /// How node inspector data is collected.
+    pub node_collect: NodeCollectMode,
+    /// Inspecting an exported dump (`--offline`): node inspector data is not collected.
+    pub offline: bool,
+    /// Module weights for the overall score.
+    pub scoring_profile: crate::scoring::profiles::ScoringProfile,
+    /// Issues listed per issue code by the pod, resource and security inspectors (0 = all).
+    /// A finding rolled up to a controller (`aggregate_by_controller`) is one listed issue.
+    pub max_issues_per_rule: usize,
+    /// Roll repeated pod findings up to the owning Deployment/StatefulSet/DaemonSet/Job/CronJob.
+    pub aggregate_by_controller: bool,
+    /// Opt-in active tests (`--active-probes`).
+    pub active_probes: Vec<ActiveProbe>,
+    /// `host:port` for the egress probe; None skips it.
+    pub probe_egress_target: Option<String>,
+    /// Read image manifest lists from public registries for the multi-arch check (HA-011).
+    pub registry_lookup: bool,
+    /// `--only-checks` / `--skip-checks`.
+    pub checks: crate::inspections::check_filter::CheckFilter,
+}
+
+/// Default production-tier namespace patterns.
+pub const DEFAULT_PROD_NAMESPACE_PATTERNS: &str = "prod,prod-*,*-prod,production*,*-production";
+/// Default debug env patterns (matched case-insensitively against `NAME=value`).
+pub const DEFAULT_DEBUG_ENV_PATTERNS: &str =
+    "*LOG_LEVEL=debug,*LOG_LEVEL=trace,*LOGLEVEL=debug,*LOGLEVEL=trace,DEBUG=true,DEBUG=1,*_DEBUG=true,*_DEBUG=1,VERBOSE=true";
+
+/// Default node-pool label keys (GKE, EKS, AKS, kOps, Karpenter, generic).
+pub const DEFAULT_NODE_POOL_LABELS: &str = "cloud.google.com/gke-nodepool,eks.amazonaws.com/nodegroup,kubernetes.azure.com/agentpool,agentpool,kops.k8s.io/instancegroup,karpenter.sh/nodepool,node.kubernetes.io/pool";
+
+/// Default age (days) after which finished Jobs / pods count as stale.
+pub const DEFAULT_STALE_DAYS: u32 = 7;
+
+impl Default for InspectionOptions {
+    fn default() -> Self {
+        use crate::utils::pattern::parse_pattern_list;
+        Self {
+            prod_namespace_patterns: parse_pattern_list(DEFAULT_PROD_NAMESPACE_PATTERNS),
+            debug_env_patterns: parse_pattern_list(DEFAULT_DEBUG_ENV_PATTERNS),
+            node_pool_labels: parse_pattern_list(DEFAULT_NODE_POOL_LABELS),
+            stale_job_days: DEFAULT_STALE_DAYS,
+            stale_pod_days: DEFAULT_STALE_DAYS,
+            max_node_image_age_days:
+                crate::inspections::node_images::DEFAULT_MAX_NODE_IMAGE_AGE_DAYS,
+            kernel_denylist: Vec::new(),
+            max_cordon_hours: crate::inspections::node_hygiene::DEFAULT_MAX_CORDON_HOURS,
+            max_memory_overcommit_pct:
+                crate::inspections::node_overcommit::DEFAULT_MAX_MEMORY_OVERCOMMIT_PCT,
+            max_cpu_overcommit_pct:
+                crate::inspections::node_overcommit::DEFAULT_MAX_CPU_OVERCOMMIT_PCT,
+            required_namespace_labels: Vec::new(),
+            empty_namespace_days:
+                crate::inspections::namespace_lifecycle::DEFAULT_EMPTY_NAMESPACE_DAYS,
+            stuck_deletion_minutes:
+                crate::inspections::stuck_deletions::DEFAULT_STUCK_DELETION_MINUTES,
+            event_window_minutes: crate::inspections::events::DEFAULT_EVENT_WINDOW_MINUTES,
+            noisy_events_per_hour: crate::inspections::events::DEFAULT_NOISY_EVENTS_PER_HOUR,
+            node_collect: NodeCollectMode::default(),
+            offline: false,
+            scoring_profile: Default::default(),
+            max_issues_per_rule: crate::inspections::sampling::DEFAULT_MAX_ISSUES_PER_RULE,
+            aggregate_by_controller: true,
+            active_probes: Vec::new(),
+            probe_egress_target: Some(
+                crate::inspections::connectivity_probes::DEFAULT_EGRESS_TARGET.to_string(),
+            ),
+            registry_lookup: false,
+            checks: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,1438 @@
+//! Orphaned resource detection: objects nothing references any more. ConfigMaps/Secrets not
+//! used by any pod, Services whose selector matches no pod, PVCs not mounted, finished Jobs and
+//! pods older than the configured age, and zero-replica ReplicaSets beyond revisionHistoryLimit.
+//! The cleanup advisor counts what keeps etcd growing: finished pods retained per namespace,
+//! finished Jobs without ttlSecondsAfterFinished and long Deployment revision histories, with the
+//! number of objects a cleanup would remove. The reverse direction, pod specs referencing
+//! ConfigMaps, Secrets or keys that do not exist, is checked with the same lists (`config_refs`).
+//! Pods, PVCs and custom resources held in deletion by finalizers are `stuck_deletions`.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{ConfigMap, PersistentVolumeClaim, Pod, PodSpec, Secret};
+use kube::api::ListParams;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::inspections::config_refs::{self, ConfigObjects};
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::stuck_deletions::{self, Deleting};
+use crate::inspections::types::*;
+use crate::k8s::K8sClient;
+
+/// Namespaces whose ConfigMaps/Secrets are read through the API by cluster components.
+const SYSTEM_NAMESPACES: [&str; 3] = ["kube-system", "kube-public", "kube-node-lease"];
+/// ConfigMap published into every namespace by the root CA publisher.
+const ROOT_CA_CONFIGMAP: &str = "kube-root-ca.crt";
+/// Secret types managed by Kubernetes or Helm rather than mounted by pods.
+const MANAGED_SECRET_TYPES: [&str; 3] = [
+    "kubernetes.io/service-account-token",
+    "bootstrap.kubernetes.io/token",
+    "helm.sh/release.v1",
+];
+/// Deployment default revisionHistoryLimit.
+const DEFAULT_REVISION_HISTORY_LIMIT: i32 = 10;
+/// Old ReplicaSets worth keeping for `kubectl rollout undo`; longer histories are ORPH-010.
+const RECOMMENDED_REVISION_HISTORY: usize = 3;
+/// Finished pods in one namespace from which ORPH-008 is reported, and from which it is a Warning.
+const FINISHED_PODS_INFO: usize = 20;
+const FINISHED_PODS_WARNING: usize = 200;
+
+type NsName = (String, String);
+
+/// ConfigMaps, Secrets and PVCs referenced by pod specs, keyed by (namespace, name).
+#[derive(Debug, Default)]
+pub struct PodReferences {
+    pub config_maps: HashSet<NsName>,
+    pub secrets: HashSet<NsName>,
+    pub pvcs: HashSet<NsName>,
+}
+
+impl PodReferences {
+    fn add_spec(&mut self, ns: &str, spec: &PodSpec) {
+        let key = |name: &str| (ns.to_string(), name.to_string());
+        for v in spec.volumes.as_deref().unwrap_or(&[]) {
+            if let Some(name) = v.config_map.as_ref().and_then(|c| c.name.as_deref()) {
+                self.config_maps.insert(key(name));
+            }
+            if let Some(name) = v.secret.as_ref().and_then(|s| s.secret_name.as_deref()) {
+                self.secrets.insert(key(name));
+            }
+            if let Some(pvc) = &v.persistent_volume_claim {
+                self.pvcs.insert(key(&pvc.claim_name));
+            }
+            for source in v
+                .projected
+                .as_ref()
+                .and_then(|p| p.sources.as_deref())
+                .unwrap_or(&[])
+            {
+                if let Some(name) = source.config_map.as_ref().and_then(|c| c.name.as_deref()) {
+                    self.config_maps.insert(key(name));
+                }
+                if let Some(name) = source.secret.as_ref().and_then(|s| s.name.as_deref()) {
+                    self.secrets.insert(key(name));
+                }
+            }
+        }
+        for s in spec.image_pull_secrets.as_deref().unwrap_or(&[]) {
+            if let Some(name) = s.name.as_deref() {
+                self.secrets.insert(key(name));
+            }
+        }
+        for c in spec
+            .init_containers
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .chain(spec.containers.iter())
+        {
+            for e in c.env.as_deref().unwrap_or(&[]) {
+                let Some(from) = &e.value_from else { continue };
+                if let Some(name) = from
+                    .config_map_key_ref
+                    .as_ref()
+                    .and_then(|r| r.name.as_deref())
+                {
+                    self.config_maps.insert(key(name));
+                }
+                if let Some(name) = from.secret_key_ref.as_ref().and_then(|r| r.name.as_deref()) {
+                    self.secrets.insert(key(name));
+                }
+            }
+            for ef in c.env_from.as_deref().unwrap_or(&[]) {
+                if let Some(name) = ef.config_map_ref.as_ref().and_then(|r| r.name.as_deref()) {
+                    self.config_maps.insert(key(name));
+                }
+                if let Some(name) = ef.secret_ref.as_ref().and_then(|r| r.name.as_deref()) {
+                    self.secrets.insert(key(name));
+                }
+            }
+        }
+    }
+}
+
+/// Collect references from all pods.
+pub fn pod_references(pods: &[Pod]) -> PodReferences {
+    let mut refs = PodReferences::default();
+    for pod in pods {
+        if let (Some(ns), Some(spec)) = (pod.metadata.namespace.as_deref(), pod.spec.as_ref()) {
+            refs.add_spec(ns, spec);
+        }
+    }
+    refs
+}
+
+/// True if every selector entry is present in the labels.
+pub fn selector_matches(
+    selector: &BTreeMap<String, String>,
+    labels: Option<&BTreeMap<String, String>>,
+) -> bool {
+    let Some(labels) = labels else {
+        return false;
+    };
+    selector.iter().all(|(k, v)| labels.get(k) == Some(v))
+}
+
+/// Deployments keeping more zero-replica ReplicaSets than their revisionHistoryLimit:
+/// (namespace, deployment, zero-replica count, limit).
+pub fn excess_replica_sets(
+    deployments: &[Deployment],
+    replica_sets: &[ReplicaSet],
+) -> Vec<(String, String, usize, i32)> {
+    let mut zero_by_owner: HashMap<NsName, usize> = HashMap::new();
+    for rs in replica_sets {
+        let replicas = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
+        if replicas != 0 {
+            continue;
+        }
+        let Some(owner) = rs
+            .metadata
+            .owner_references
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .find(|o| o.kind == "Deployment")
+        else {
+            continue;
+        };
+        let ns = rs.metadata.namespace.clone().unwrap_or_default();
+        *zero_by_owner.entry((ns, owner.name.clone())).or_insert(0) += 1;
+    }
+    let mut out = Vec::new();
+    for d in deployments {
+        let ns = d.metadata.namespace.clone().unwrap_or_default();
+        let name = d.metadata.name.clone().unwrap_or_default();
+        let limit = d
+            .spec
+            .as_ref()
+            .and_then(|s| s.revision_history_limit)
+            .unwrap_or(DEFAULT_REVISION_HISTORY_LIMIT);
+        let count = zero_by_owner
+            .get(&(ns.clone(), name.clone()))
+            .copied()
+            .unwrap_or(0);
+        if count > limit.max(0) as usize {
+            out.push((ns, name, count, limit));
+        }
+    }
+    out
+}
+
+/// Objects a cleanup would remove from etcd, as found by the cleanup advisor.
+#[derive(Debug, Default, PartialEq)]
+pub struct CleanupEstimate {
+    /// Namespace → Succeeded/Failed pods, Job pods included.
+    pub finished_pods: BTreeMap<String, usize>,
+    /// Namespace → (finished standalone Jobs without ttlSecondsAfterFinished, their pods).
+    pub jobs_without_ttl: BTreeMap<String, (usize, usize)>,
+    /// (namespace, deployment, old ReplicaSets kept, revisionHistoryLimit) for Deployments
+    /// keeping more than [`RECOMMENDED_REVISION_HISTORY`] old ReplicaSets.
+    pub long_histories: Vec<(String, String, usize, i32)>,
+}
+
+impl CleanupEstimate {
+    pub fn from_objects(
+        pods: &[Pod],
+        jobs: &[Job],
+        deployments: &[Deployment],
+        replica_sets: &[ReplicaSet],
+    ) -> Self {
+        let mut estimate = Self::default();
+        let mut pods_by_job: HashMap<NsName, usize> = HashMap::new();
+        for pod in pods {
+            let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+            if !matches!(phase, Some("Succeeded") | Some("Failed")) {
+                continue;
+            }
+            let ns = pod.metadata.namespace.clone().unwrap_or_default();
+            if let Some(job) = pod
+                .metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or(&[])
+                .iter()
+                .find(|o| o.kind == "Job")
+            {
+                *pods_by_job
+                    .entry((ns.clone(), job.name.clone()))
+                    .or_default() += 1;
+            }
+            *estimate.finished_pods.entry(ns).or_default() += 1;
+        }
+        for job in jobs {
+            let no_ttl = job
+                .spec
+                .as_ref()
+                .is_some_and(|s| s.ttl_seconds_after_finished.is_none());
+            // CronJob history limits clean these up already.
+            if !no_ttl || owned_by(&job.metadata, "CronJob") || job_finished_at(job).is_none() {
+                continue;
+            }
+            let ns = job.metadata.namespace.clone().unwrap_or_default();
+            let name = job.metadata.name.clone().unwrap_or_default();
+            let pods = pods_by_job.get(&(ns.clone(), name)).copied().unwrap_or(0);
+            let entry = estimate.jobs_without_ttl.entry(ns).or_default();
+            entry.0 += 1;
+            entry.1 += pods;
+        }
+        let mut old_by_owner: HashMap<NsName, usize> = HashMap::new();
+        for rs in replica_sets {
+            if rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0) != 0 {
+                continue;
+            }
+            if let Some(owner) = rs
+                .metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or(&[])
+                .iter()
+                .find(|o| o.kind == "Deployment")
+            {
+                let ns = rs.metadata.namespace.clone().unwrap_or_default();
+                *old_by_owner.entry((ns, owner.name.clone())).or_default() += 1;
+            }
+        }
+        for d in deployments {
+            let ns = d.metadata.namespace.clone().unwrap_or_default();
+            let name = d.metadata.name.clone().unwrap_or_default();
+            let limit = d
+                .spec
+                .as_ref()
+                .and_then(|s| s.revision_history_limit)
+                .unwrap_or(DEFAULT_REVISION_HISTORY_LIMIT);
+            let kept = old_by_owner
+                .get(&(ns.clone(), name.clone()))
+                .copied()
+                .unwrap_or(0);
+            if kept > RECOMMENDED_REVISION_HISTORY && limit as usize > RECOMMENDED_REVISION_HISTORY
+            {
+                estimate.long_histories.push((ns, name, kept, limit));
+            }
+        }
+        estimate
+    }
+
+    /// Objects removed by deleting the finished pods, the finished Jobs without TTL (their pods
+    /// are counted with the finished pods) and the old ReplicaSets beyond a history of 3.
+    pub fn total(&self) -> usize {
+        self.finished_pods.values().sum::<usize>()
+            + self
+                .jobs_without_ttl
+                .values()
+                .map(|(jobs, _)| jobs)
+                .sum::<usize>()
+            + self
+                .long_histories
+                .iter()
+                .map(|(_, _, kept, _)| kept - RECOMMENDED_REVISION_HISTORY)
+                .sum::<usize>()
+    }
+}
+
+fn is_system_namespace(ns: &str) -> bool {
+    SYSTEM_NAMESPACES.contains(&ns)
+}
+
+fn orphan_check(
+    name: &str,
+    description: &str,
+    evaluated: usize,
+    flagged: usize,
+    detail_suffix: &str,
+    recommendation: &str,
+) -> CheckResult {
+    let score = if evaluated == 0 {
+        100.0
+    } else {
+        ((evaluated - flagged) as f64 / evaluated as f64) * 100.0
+    };
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if score >= 80.0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Critical
+        },
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} {}",
+            evaluated - flagged,
+            evaluated,
+            detail_suffix
+        )),
+        recommendations: if flagged > 0 {
+            vec![recommendation.to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
+
+/// Time a finished pod stopped: latest container finishedAt, else start time, else creation.
+fn pod_finished_at(pod: &Pod) -> Option<DateTime<Utc>> {
+    let status = pod.status.as_ref();
+    status
+        .and_then(|s| s.container_statuses.as_ref())
+        .and_then(|cs| {
+            cs.iter()
+                .filter_map(|c| c.state.as_ref()?.terminated.as_ref()?.finished_at.as_ref())
+                .map(|t| t.0)
+                .max()
+        })
+        .or_else(|| status.and_then(|s| s.start_time.as_ref()).map(|t| t.0))
+        .or_else(|| pod.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+/// Time a Job completed or failed; None while it runs.
+fn job_finished_at(job: &Job) -> Option<DateTime<Utc>> {
+    let status = job.status.as_ref()?;
+    status.completion_time.as_ref().map(|t| t.0).or_else(|| {
+        status
+            .conditions
+            .as_deref()?
+            .iter()
+            .find(|c| c.type_ == "Failed" && c.status == "True")?
+            .last_transition_time
+            .as_ref()
+            .map(|t| t.0)
+    })
+}
+
+/// Pod specs checked for dangling references besides workload templates: Job templates (CronJob
+/// Jobs are covered by their CronJob) and unfinished pods not created from a checked template,
+/// one per owner. (label, namespace, spec) like the templates.
+fn reference_sources<'p>(pods: &'p [Pod], jobs: &'p [Job]) -> Vec<(String, String, &'p PodSpec)> {
+    let mut sources: BTreeMap<String, (String, &PodSpec)> = BTreeMap::new();
+    for job in jobs.iter().filter(|j| !owned_by(&j.metadata, "CronJob")) {
+        if let (Some(ns), Some(name), Some(spec)) = (
+            job.metadata.namespace.as_deref(),
+            job.metadata.name.as_deref(),
+            job.spec.as_ref().and_then(|s| s.template.spec.as_ref()),
+        ) {
+            sources.insert(format!("Job {}/{}", ns, name), (ns.to_string(), spec));
+        }
+    }
+    for pod in pods {
+        let finished = matches!(
+            pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+            Some("Succeeded" | "Failed")
+        );
+        if finished
+            || ["ReplicaSet", "StatefulSet", "DaemonSet", "Job"]
+                .iter()
+                .any(|k| owned_by(&pod.metadata, k))
+        {
+            continue;
+        }
+        let (Some(ns), Some(name), Some(spec)) = (
+            pod.metadata.namespace.as_deref(),
+            pod.metadata.name.as_deref(),
+            pod.spec.as_ref(),
+        ) else {
+            continue;
+        };
+        let label = match pod
+            .metadata
+            .owner_references
+            .as_deref()
+            .and_then(|o| o.first())
+        {
+            Some(owner) => format!("{} {}/{}", owner.kind, ns, owner.name),
+            None => format!("Pod {}/{}", ns, name),
+        };
+        sources.entry(label).or_insert((ns.to_string(), spec));
+    }
+    sources
+        .into_iter()
+        .map(|(label, (ns, spec))| (label, ns, spec))
+        .collect()
+}
+
+fn owned_by(meta: &kube::api::ObjectMeta, kind: &str) -> bool {
+    meta.owner_references
+        .as_deref()
+        .unwrap_or(&[])
+        .iter()
+        .any(|o| o.kind == kind)
+}
+
+pub struct OrphansInspector<'a> {
+    client: &'a K8sClient,
+    options: &'a InspectionOptions,
+}
+
+impl<'a> OrphansInspector<'a> {
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
+    }
+
+    pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        let mut checks = Vec::new();
+        let mut issues = Vec::new();
+
+        let lp = ListParams::default();
+        let pods = self
+            .client
+            .list_all(&self.client.pods(namespace), &lp)
+            .await?
+            .items;
+        let deployments = self
+            .client
+            .list_all(&self.client.deployments(namespace), &lp)
+            .await?
+            .items;
+        let jobs = self
+            .client
+            .list_all(&self.client.jobs(namespace), &lp)
+            .await?
+            .items;
+        let replica_sets = self
+            .client
+            .list_all(&self.client.replica_sets(namespace), &lp)
+            .await?
+            .items;
+        let stateful_sets = self
+            .client
+            .list_all(&self.client.stateful_sets(namespace), &lp)
+            .await?
+            .items;
+        let daemon_sets = self
+            .client
+            .list_all(&self.client.daemon_sets(namespace), &lp)
+            .await?
+            .items;
+        let cron_jobs = self
+            .client
+            .list_all(&self.client.cron_jobs(namespace), &lp)
+            .await?
+            .items;
+        // ConfigMaps and Secrets resolve the workloads' references, so they are listed whatever
+        // their labels.
+        let config_maps = self
+            .client
+            .list_unselected(&self.client.config_maps(namespace), &lp)
+            .await?
+            .items;
+        let secrets = self
+            .client
+            .list_unselected(&self.client.secrets(namespace), &lp)
+            .await?
+            .items;
+        let pvcs = self
+            .client
+            .list_all(&self.client.persistent_volume_claims(namespace), &lp)
+            .await?
+            .items;
+
+        // Workload templates count as references too (scaled-to-zero workloads, CronJobs between
+        // runs); (label, namespace, spec) of each template.
+        let mut templates: Vec<(String, String, &PodSpec)> = Vec::new();
+        for (kind, meta, spec) in deployments
+            .iter()
+            .map(|d| {
+                let spec = d.spec.as_ref().and_then(|s| s.template.spec.as_ref());
+                ("Deployment", &d.metadata, spec)
+            })
+            .chain(stateful_sets.iter().map(|s| {
+                let spec = s.spec.as_ref().and_then(|s| s.template.spec.as_ref());
+                ("StatefulSet", &s.metadata, spec)
+            }))
+            .chain(daemon_sets.iter().map(|d| {
+                let spec = d.spec.as_ref().and_then(|s| s.template.spec.as_ref());
+                ("DaemonSet", &d.metadata, spec)
+            }))
+            .chain(cron_jobs.iter().map(|c| {
+                let spec = c
+                    .spec
+                    .as_ref()
+                    .and_then(|s| s.job_template.spec.as_ref())
+                    .and_then(|j| j.template.spec.as_ref());
+                ("CronJob", &c.metadata, spec)
+            }))
+        {
+            if let (Some(ns), Some(name), Some(spec)) =
+                (meta.namespace.as_deref(), meta.name.as_deref(), spec)
+            {
+                templates.push((format!("{} {}/{}", kind, ns, name), ns.to_string(), spec));
+            }
+        }
+        let mut refs = pod_references(&pods);
+        for (_, ns, spec) in &templates {
+            refs.add_spec(ns, spec);
+        }
+
+        // Whether a ConfigMap, Secret, Service or PVC is unused depends on every workload in the
+        // namespace, not only those matching --selector, so these checks are left out with one.
+        let selected = self.client.label_selector().is_some();
+        if !selected {
+            checks.push(self.inspect_config_maps(&config_maps, &refs, &mut issues));
+            checks.extend(
+                self.inspect_secrets(namespace, &secrets, &refs, &mut issues)
+                    .await,
+            );
+        }
+        let objects = ConfigObjects::new(&config_maps, &secrets);
+        templates.extend(reference_sources(&pods, &jobs));
+        checks.push(config_refs::reference_check(
+            &templates,
+            &objects,
+            &mut issues,
+        ));
+        if !selected {
+            checks.push(self.inspect_services(namespace, &pods, &mut issues).await?);
+            checks.push(self.inspect_pvcs(&pvcs, &refs, &mut issues));
+        }
+        checks.push(self.inspect_finished_jobs(&jobs, &mut issues));
+        checks.push(self.inspect_finished_pods(&pods, &mut issues));
+        checks.push(self.inspect_replica_sets(&deployments, &replica_sets, &mut issues));
+        let estimate = CleanupEstimate::from_objects(&pods, &jobs, &deployments, &replica_sets);
+        checks.extend(self.inspect_cleanup(&estimate, &mut issues));
+        checks.push(
+            self.inspect_stuck_deletions(namespace, &pods, &pvcs, &mut issues)
+                .await,
+        );
+
+        let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
+        let summary = self.build_summary(&checks, issues);
+
+        Ok(InspectionResult {
+            inspection_type: "Orphaned Resources".to_string(),
+            timestamp: Utc::now(),
+            overall_score,
+            checks,
+            summary,
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        })
+    }
+
+    fn inspect_config_maps(
+        &self,
+        cms: &[ConfigMap],
+        refs: &PodReferences,
+        issues: &mut Vec<Issue>,
+    ) -> CheckResult {
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for cm in cms {
+            let ns = cm.metadata.namespace.as_deref().unwrap_or("default");
+            let name = cm.metadata.name.as_deref().unwrap_or("unknown");
+            if is_system_namespace(ns) || name == ROOT_CA_CONFIGMAP {
+                continue;
+            }
+            evaluated += 1;
+            if !refs
+                .config_maps
+                .contains(&(ns.to_string(), name.to_string()))
+            {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Info,
+                    category: "ConfigMap".to_string(),
+                    description: format!(
+                        "ConfigMap {}/{} is not mounted or env-referenced by any pod or workload",
+                        ns, name
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Delete it if unused; keep it only if an application reads it through the API.".to_string(),
+                    rule_id: Some("ORPH-001".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: vec![format!("kubectl -n {} delete configmap {}", ns, name)],
+                });
+            }
+        }
+        orphan_check(
+            "Unused ConfigMaps",
+            "ConfigMaps not referenced by any pod or workload template",
+            evaluated,
+            flagged,
+            "ConfigMaps referenced",
+            "Remove ConfigMaps no workload uses",
+        )
+    }
+
+    /// None when Ingresses or ServiceAccounts cannot be listed: without them every TLS and
+    /// image pull Secret would look unused.
+    async fn inspect_secrets(
+        &self,
+        namespace: Option<&str>,
+        secrets: &[Secret],
+        refs: &PodReferences,
+        issues: &mut Vec<Issue>,
+    ) -> Option<CheckResult> {
+        let lp = ListParams::default();
+        // Secrets used outside pod specs: Ingress TLS and ServiceAccount (image pull) secrets.
+        let mut used: HashSet<NsName> = HashSet::new();
+        let ingresses = match self
+            .client
+            .list_unselected(&self.client.ingresses(namespace), &lp)
+            .await
+        {
+            Ok(list) => list.items,
+            Err(e) => {
+                log::warn!(
+                    "Ingresses could not be listed, skipping unused Secrets: {}",
+                    e
+                );
+                return None;
+            }
+        };
+        let sas = match self
+            .client
+            .list_unselected(&self.client.service_accounts(namespace), &lp)
+            .await
+        {
+            Ok(list) => list.items,
+            Err(e) => {
+                log::warn!(
+                    "ServiceAccounts could not be listed, skipping unused Secrets: {}",
+                    e
+                );
+                return None;
+            }
+        };
+        for ing in ingresses {
+            let ns = ing.metadata.namespace.unwrap_or_default();
+            for tls in ing.spec.and_then(|s| s.tls).unwrap_or_default() {
+                if let Some(name) = tls.secret_name {
+                    used.insert((ns.clone(), name));
+                }
+            }
+        }
+        for sa in sas {
+            let ns = sa.metadata.namespace.unwrap_or_default();
+            for name in sa
+                .image_pull_secrets
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|r| r.name)
+                .chain(
+                    sa.secrets
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|r| r.name),
+                )
+            {
+                used.insert((ns.clone(), name));
+            }
+        }
+
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for secret in secrets {
+            let ns = secret.metadata.namespace.as_deref().unwrap_or("default");
+            let name = secret.metadata.name.as_deref().unwrap_or("unknown");
+            let type_ = secret.type_.as_deref().unwrap_or("Opaque");
+            if is_system_namespace(ns) || MANAGED_SECRET_TYPES.contains(&type_) {
+                continue;
+            }
+            evaluated += 1;
+            let key = (ns.to_string(), name.to_string());
+            if !refs.secrets.contains(&key) && !used.contains(&key) {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Info,
+                    category: "Secret".to_string(),
+                    description: format!(
+                        "Secret {}/{} ({}) is not referenced by any pod, workload, Ingress or ServiceAccount",
+                        ns, name, type_
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Delete unused Secrets to shrink the credential surface; keep it only if a controller reads it through the API.".to_string(),
+                    rule_id: Some("ORPH-002".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: vec![format!("kubectl -n {} delete secret {}", ns, name)],
+                });
+            }
+        }
+        Some(orphan_check(
+            "Unused Secrets",
+            "Secrets not referenced by pods, workload templates, Ingress TLS or ServiceAccounts",
+            evaluated,
+            flagged,
+            "Secrets referenced",
+            "Remove Secrets nothing references",
+        ))
+    }
+
+    async fn inspect_services(
+        &self,
+        namespace: Option<&str>,
+        pods: &[Pod],
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let services = self
+            .client
+            .list_all(&self.client.services(namespace), &ListParams::default())
+            .await?;
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for svc in &services.items {
+            let Some(selector) = svc
+                .spec
+                .as_ref()
+                .and_then(|s| s.selector.as_ref())
+                .filter(|s| !s.is_empty())
+            else {
+                // Selector-less Services (manual Endpoints, ExternalName) are covered by NET-003.
+                continue;
+            };
+            let ns = svc.metadata.namespace.as_deref().unwrap_or("default");
+            let name = svc.metadata.name.as_deref().unwrap_or("unknown");
+            evaluated += 1;
+            let matched = pods.iter().any(|p| {
+                p.metadata.namespace.as_deref() == Some(ns)
+                    && selector_matches(selector, p.metadata.labels.as_ref())
+            });
+            if !matched {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: "Service".to_string(),
+                    description: format!("Service {}/{} selector matches no pod", ns, name),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Fix the selector or delete the Service; clients get connection refused / no endpoints.".to_string(),
+                    rule_id: Some("ORPH-003".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+        Ok(orphan_check(
+            "Services Without Pods",
+            "Services whose selector matches no pod",
+            evaluated,
+            flagged,
+            "Services select at least one pod",
+            "Fix or remove Services that select no pods",
+        ))
+    }
+
+    fn inspect_pvcs(
+        &self,
+        pvcs: &[PersistentVolumeClaim],
+        refs: &PodReferences,
+        issues: &mut Vec<Issue>,
+    ) -> CheckResult {
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for pvc in pvcs {
+            let ns = pvc.metadata.namespace.as_deref().unwrap_or("default");
+            let name = pvc.metadata.name.as_deref().unwrap_or("unknown");
+            evaluated += 1;
+            if !refs.pvcs.contains(&(ns.to_string(), name.to_string())) {
+                flagged += 1;
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: "PersistentVolumeClaim".to_string(),
+                    description: format!("PVC {}/{} is not mounted by any pod or workload", ns, name),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Delete the PVC (check the PV reclaim policy first) or back up the data; unused volumes still cost storage.".to_string(),
+                    rule_id: Some("ORPH-004".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: vec![format!("kubectl -n {} delete pvc {}", ns, name)],
+                });
+            }
+        }
+        orphan_check(
+            "Unmounted PVCs",
+            "PVCs not mounted by any pod or workload template",
+            evaluated,
+            flagged,
+            "PVCs mounted",
+            "Clean up PVCs no workload mounts",
+        )
+    }
+
+    /// ORPH-013 over the pods and PVCs already listed and the custom resources of every CRD
+    /// (not read from offline dumps).
+    async fn inspect_stuck_deletions(
+        &self,
+        namespace: Option<&str>,
+        pods: &[Pod],
+        pvcs: &[PersistentVolumeClaim],
+        issues: &mut Vec<Issue>,
+    ) -> CheckResult {
+        let mut deleting: Vec<Deleting> = pods
+            .iter()
+            .filter_map(|p| {
+                let mut d = Deleting::from_meta("Pod", &p.metadata)?;
+                d.waiting_on = p
+                    .spec
+                    .as_ref()
+                    .and_then(|s| s.node_name.as_deref())
+                    .map(|node| format!("the kubelet on {} (no finalizer left)", node));
+                Some(d)
+            })
+            .collect();
+        deleting.extend(
+            pvcs.iter()
+                .filter_map(|p| Deleting::from_meta("PersistentVolumeClaim", &p.metadata)),
+        );
+        let mut unlisted = 0;
+        if !self.options.offline {
+            if let Some((custom, skipped)) =
+                stuck_deletions::custom_resources_deleting(self.client, namespace).await
+            {
+                deleting.extend(custom);
+                unlisted = skipped;
+            }
+        }
+        stuck_deletions::stuck_deletion_check(
+            &deleting,
+            self.options.stuck_deletion_minutes,
+            unlisted,
+            Utc::now(),
+            issues,
+        )
+    }
+
+    fn inspect_finished_jobs(&self, jobs: &[Job], issues: &mut Vec<Issue>) -> CheckResult {
+        let cutoff = Utc::now() - Duration::days(self.options.stale_job_days as i64);
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for job in jobs {
+            // CronJob history limits and TTL-after-finished clean these up already.
+            if owned_by(&job.metadata, "CronJob")
+                || job
+                    .spec
+                    .as_ref()
+                    .and_then(|s| s.ttl_seconds_after_finished)
+                    .is_some()
+            {
+                continue;
+            }
+            let Some(finished) = job_finished_at(job) else {
+                continue;
+            };
+            evaluated += 1;
+            if finished < cutoff {
+                flagged += 1;
+                let ns = job.metadata.namespace.as_deref().unwrap_or("default");
+                let name = job.metadata.name.as_deref().unwrap_or("unknown");
+                issues.push(Issue {
+                    severity: IssueSeverity::Info,
+                    category: "Job".to_string(),
+                    description: format!(
+                        "Job {}/{} finished {} days ago and is still kept",
+                        ns,
+                        name,
+                        (Utc::now() - finished).num_days()
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Delete finished Jobs or set spec.ttlSecondsAfterFinished so they are cleaned up automatically.".to_string(),
+                    rule_id: Some("ORPH-005".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: vec![format!("kubectl -n {} delete job {}", ns, name)],
+                });
+            }
+        }
+        orphan_check(
+            "Stale Finished Jobs",
+            &format!(
+                "Standalone Jobs finished more than {} days ago",
+                self.options.stale_job_days
+            ),
+            evaluated,
+            flagged,
+            "finished Jobs recent",
+            "Set ttlSecondsAfterFinished on Jobs",
+        )
+    }
+
+    fn inspect_finished_pods(&self, pods: &[Pod], issues: &mut Vec<Issue>) -> CheckResult {
+        let cutoff = Utc::now() - Duration::days(self.options.stale_pod_days as i64);
+        let mut evaluated = 0usize;
+        let mut flagged = 0usize;
+        for pod in pods {
+            let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+            if !matches!(phase, Some("Succeeded") | Some("Failed")) {
+                continue;
+            }
+            // Job pods go away with their Job (ORPH-005).
+            if owned_by(&pod.metadata, "Job") {
+                continue;
+            }
+            evaluated += 1;
+            let Some(finished) = pod_finished_at(pod) else {
+                continue;
+            };
+            if finished < cutoff {
+                flagged += 1;
+                let ns = pod.metadata.namespace.as_deref().unwrap_or("default");
+                let name = pod.metadata.name.as_deref().unwrap_or("unknown");
+                issues.push(Issue {
+                    severity: IssueSeverity::Info,
+                    category: "Pod".to_string(),
+                    description: format!(
+                        "Pod {}/{} is {} since {} days",
+                        ns,
+                        name,
+                        phase.unwrap_or("finished"),
+                        (Utc::now() - finished).num_days()
+                    ),
+                    resource: Some(format!("{}/{}", ns, name)),
+                    recommendation: "Delete completed/failed pods; the pod GC only removes them above terminated-pod-gc-threshold.".to_string(),
+                    rule_id: Some("ORPH-006".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: vec![format!("kubectl -n {} delete pod {}", ns, name)],
+                });
+            }
+        }
+        orphan_check(
+            "Stale Finished Pods",
+            &format!(
+                "Succeeded/Failed pods (not owned by Jobs) older than {} days",
+                self.options.stale_pod_days
+            ),
+            evaluated,
+            flagged,
+            "finished pods recent",
+            "Delete old completed and failed pods",
+        )
+    }
+
+    fn inspect_replica_sets(
+        &self,
+        deployments: &[Deployment],
+        replica_sets: &[ReplicaSet],
+        issues: &mut Vec<Issue>,
+    ) -> CheckResult {
+        let excess = excess_replica_sets(deployments, replica_sets);
+        for (ns, name, count, limit) in &excess {
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "Deployment".to_string(),
+                description: format!(
+                    "Deployment {}/{} keeps {} zero-replica ReplicaSets (revisionHistoryLimit {})",
+                    ns, name, count, limit
+                ),
+                resource: Some(format!("{}/{}", ns, name)),
+                recommendation: "Old ReplicaSets beyond the history limit are normally pruned by the deployment controller; check controller-manager health or delete them manually.".to_string(),
+                rule_id: Some("ORPH-007".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+        orphan_check(
+            "Old ReplicaSets",
+            "Zero-replica ReplicaSets kept beyond the Deployment revisionHistoryLimit",
+            deployments.len(),
+            excess.len(),
+            "Deployments within revision history limit",
+            "Prune old ReplicaSets",
+        )
+    }
+
+    /// Cleanup advisor: ORPH-008 to ORPH-010, each check's details ending with the number of
+    /// objects a cleanup would remove.
+    fn inspect_cleanup(
+        &self,
+        estimate: &CleanupEstimate,
+        issues: &mut Vec<Issue>,
+    ) -> Vec<CheckResult> {
+        let mut crowded = 0;
+        for (ns, &count) in &estimate.finished_pods {
+            if count < FINISHED_PODS_INFO {
+                continue;
+            }
+            crowded += 1;
+            issues.push(Issue {
+                severity: if count >= FINISHED_PODS_WARNING {
+                    IssueSeverity::Warning
+                } else {
+                    IssueSeverity::Info
+                },
+                category: "Namespace".to_string(),
+                description: format!(
+                    "Namespace {} keeps {} Succeeded/Failed pods; deleting them removes {} objects from etcd",
+                    ns, count, count
+                ),
+                resource: Some(ns.clone()),
+                recommendation: "Delete finished pods and let Jobs clean up after themselves (ttlSecondsAfterFinished, CronJob history limits); the pod GC only starts above terminated-pod-gc-threshold (12500 by default).".to_string(),
+                rule_id: Some("ORPH-008".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: vec![
+                    format!("kubectl -n {} delete pod --field-selector=status.phase==Succeeded", ns),
+                    format!("kubectl -n {} delete pod --field-selector=status.phase==Failed", ns),
+                ],
+            });
+        }
+        let finished_pods: usize = estimate.finished_pods.values().sum();
+        let mut pods_check = orphan_check(
+            "Finished Pods per Namespace",
+            &format!(
+                "Namespaces keeping {} or more Succeeded/Failed pods",
+                FINISHED_PODS_INFO
+            ),
+            estimate.finished_pods.len(),
+            crowded,
+            "namespaces with few finished pods",
+            "Delete finished pods and set Job TTLs",
+        );
+
+        for (ns, &(jobs, pods)) in &estimate.jobs_without_ttl {
+            issues.push(Issue {
+                severity: IssueSeverity::Info,
+                category: "Job".to_string(),
+                description: format!(
+                    "Namespace {} has {} finished Job(s) without ttlSecondsAfterFinished; deleting them removes {} Job(s) and {} pod(s) from etcd",
+                    ns, jobs, jobs, pods
+                ),
+                resource: Some(ns.clone()),
+                recommendation: "Set spec.ttlSecondsAfterFinished on Jobs (or their templates) so finished Jobs and their pods are deleted automatically.".to_string(),
+                rule_id: Some("ORPH-009".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+        let ttl_jobs: usize = estimate.jobs_without_ttl.values().map(|(j, _)| j).sum();
+        let mut jobs_check = CheckResult {
+            name: "Job TTL".to_string(),
+            description: "Finished standalone Jobs without ttlSecondsAfterFinished".to_string(),
+            status: if ttl_jobs == 0 {
+                CheckStatus::Pass
+            } else {
+                CheckStatus::Warning
+            },
+            score: if ttl_jobs == 0 { 100.0 } else { 90.0 },
+            max_score: 100.0,
+            details: Some(format!(
+                "{} finished Job(s) without TTL in {} namespace(s)",
+                ttl_jobs,
+                estimate.jobs_without_ttl.len()
+            )),
+            recommendations: if ttl_jobs > 0 {
+                vec!["Set ttlSecondsAfterFinished on Jobs".to_string()]
+            } else {
+                vec![]
+            },
+        };
+
+        for (ns, name, kept, limit) in &estimate.long_histories {
+            issues.push(Issue {
+                severity: IssueSeverity::Info,
+                category: "Deployment".to_string(),
+                description: format!(
+                    "Deployment {}/{} keeps {} old ReplicaSets (revisionHistoryLimit {}); a limit of {} removes {} objects from etcd",
+                    ns,
+                    name,
+                    kept,
+                    limit,
+                    RECOMMENDED_REVISION_HISTORY,
+                    kept - RECOMMENDED_REVISION_HISTORY
+                ),
+                resource: Some(format!("{}/{}", ns, name)),
+                recommendation: format!(
+                    "Set spec.revisionHistoryLimit to {}; older revisions are rarely rolled back to and each is a ReplicaSet in etcd.",
+                    RECOMMENDED_REVISION_HISTORY
+                ),
+                rule_id: Some("ORPH-010".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: vec![format!(
+                    "kubectl -n {} patch deployment {} -p '{{\"spec\":{{\"revisionHistoryLimit\":{}}}}}'",
+                    ns, name, RECOMMENDED_REVISION_HISTORY
+                )],
+            });
+        }
+        let mut history_check = CheckResult {
+            name: "Deployment Revision History".to_string(),
+            description: format!(
+                "Deployments keeping more than {} old ReplicaSets",
+                RECOMMENDED_REVISION_HISTORY
+            ),
+            status: if estimate.long_histories.is_empty() {
+                CheckStatus::Pass
+            } else {
+                CheckStatus::Warning
+            },
+            score: if estimate.long_histories.is_empty() {
+                100.0
+            } else {
+                90.0
+            },
+            max_score: 100.0,
+            details: Some(format!(
+                "{} Deployment(s) with a long revision history",
+                estimate.long_histories.len()
+            )),
+            recommendations: if estimate.long_histories.is_empty() {
+                vec![]
+            } else {
+                vec![format!(
+                    "Lower revisionHistoryLimit to {}",
+                    RECOMMENDED_REVISION_HISTORY
+                )]
+            },
+        };
+
+        let reclaimable = format!(
+            "; cleanup would remove ~{} objects from etcd in total ({} finished pods)",
+            estimate.total(),
+            finished_pods
+        );
+        for check in [&mut pods_check, &mut jobs_check, &mut history_check] {
+            if let Some(details) = check.details.as_mut() {
+                details.push_str(&reclaimable);
+            }
+        }
+        vec![pods_check, jobs_check, history_check]
+    }
+
+    fn build_summary(&self, checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
+        let total_checks = checks.len() as u32;
+        let mut passed_checks = 0;
+        let mut warning_checks = 0;
+        let mut critical_checks = 0;
+        let mut error_checks = 0;
+
+        for check in checks {
+            match check.status {
+                CheckStatus::Pass => passed_checks += 1,
+                CheckStatus::Warning => warning_checks += 1,
+                CheckStatus::Critical => critical_checks += 1,
+                CheckStatus::Error => error_checks += 1,
+            }
+        }
+
+        InspectionSummary {
+            total_checks,
+            passed_checks,
+            warning_checks,
+            critical_checks,
+            error_checks,
+            issues,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DeploymentSpec, ReplicaSetSpec};
+    use k8s_openapi::api::core::v1::{
+        ConfigMapVolumeSource, Container, EnvFromSource, PersistentVolumeClaimVolumeSource,
+        SecretEnvSource, Volume,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    #[test]
+    fn pod_references_cover_volumes_and_env_from() {
+        let mut pod = Pod::default();
+        pod.metadata.namespace = Some("app".to_string());
+        pod.spec = Some(PodSpec {
+            volumes: Some(vec![
+                Volume {
+                    name: "cfg".to_string(),
+                    config_map: Some(ConfigMapVolumeSource {
+                        name: Some("app-config".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Volume {
+                    name: "data".to_string(),
+                    persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                        claim_name: "data-0".to_string(),
+                        read_only: None,
+                    }),
+                    ..Default::default()
+                },
+            ]),
+            containers: vec![Container {
+                name: "app".to_string(),
+                env_from: Some(vec![EnvFromSource {
+                    secret_ref: Some(SecretEnvSource {
+                        name: Some("app-creds".to_string()),
+                        optional: None,
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let refs = pod_references(&[pod]);
+        let k = |n: &str| ("app".to_string(), n.to_string());
+        assert!(refs.config_maps.contains(&k("app-config")));
+        assert!(refs.secrets.contains(&k("app-creds")));
+        assert!(refs.pvcs.contains(&k("data-0")));
+    }
+
+    #[test]
+    fn counts_zero_replica_sets_beyond_history_limit() {
+        let mut d = Deployment::default();
+        d.metadata.namespace = Some("app".to_string());
+        d.metadata.name = Some("web".to_string());
+        d.spec = Some(DeploymentSpec {
+            revision_history_limit: Some(1),
+            ..Default::default()
+        });
+        let rs = |replicas: i32| {
+            let mut rs = ReplicaSet::default();
+            rs.metadata.namespace = Some("app".to_string());
+            rs.metadata.owner_references = Some(vec![OwnerReference {
+                kind: "Deployment".to_string(),
+                name: "web".to_string(),
+                ..Default::default()
+            }]);
+            rs.spec = Some(ReplicaSetSpec {
+                replicas: Some(replicas),
+                ..Default::default()
+            });
+            rs
+        };
+        let excess = excess_replica_sets(&[d.clone()], &[rs(0), rs(0), rs(3)]);
+        assert_eq!(excess, vec![("app".to_string(), "web".to_string(), 2, 1)]);
+        assert!(excess_replica_sets(&[d], &[rs(0), rs(3)]).is_empty());
+    }
+
+    #[test]
+    fn cleanup_estimate_counts_reclaimable_objects() {
+        use k8s_openapi::api::batch::v1::{JobSpec, JobStatus};
+        use k8s_openapi::api::core::v1::PodStatus;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+        let owner = |kind: &str, name: &str| {
+            Some(vec![OwnerReference {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            }])
+        };
+        let pod = |phase: &str, job: Option<&str>| {
+            let mut pod = Pod::default();
+            pod.metadata.namespace = Some("ci".to_string());
+            pod.metadata.owner_references = job.and_then(|j| owner("Job", j));
+            pod.status = Some(PodStatus {
+                phase: Some(phase.to_string()),
+                ..Default::default()
+            });
+            pod
+        };
+        let job = |name: &str, ttl: Option<i32>, cron: bool| {
+            let mut job = Job::default();
+            job.metadata.namespace = Some("ci".to_string());
+            job.metadata.name = Some(name.to_string());
+            if cron {
+                job.metadata.owner_references = owner("CronJob", "nightly");
+            }
+            job.spec = Some(JobSpec {
+                ttl_seconds_after_finished: ttl,
+                ..Default::default()
+            });
+            job.status = Some(JobStatus {
+                completion_time: Some(Time(Utc::now())),
+                ..Default::default()
+            });
+            job
+        };
+        let pods = vec![
+            pod("Succeeded", Some("build-1")),
+            pod("Failed", Some("build-1")),
+            pod("Succeeded", None),
+            pod("Running", None),
+        ];
+        let jobs = vec![
+            job("build-1", None, false),
+            job("build-2", Some(600), false),
+            job("nightly-1", None, true),
+        ];
+        let mut d = Deployment::default();
+        d.metadata.namespace = Some("ci".to_string());
+        d.metadata.name = Some("web".to_string());
+        let replica_sets: Vec<ReplicaSet> = (0..5)
+            .map(|_| {
+                let mut rs = ReplicaSet::default();
+                rs.metadata.namespace = Some("ci".to_string());
+                rs.metadata.owner_references = owner("Deployment", "web");
+                rs
+            })
+            .collect();
+
+        let estimate = CleanupEstimate::from_objects(&pods, &jobs, &[d], &replica_sets);
+        assert_eq!(estimate.finished_pods.get("ci"), Some(&3));
+        assert_eq!(estimate.jobs_without_ttl.get("ci"), Some(&(1, 2)));
+        assert_eq!(
+            estimate.long_histories,
+            vec![("ci".to_string(), "web".to_string(), 5, 10)]
+        );
+        // 3 finished pods + 1 Job + 2 ReplicaSets beyond a history of 3.
+        assert_eq!(estimate.total(), 6);
+    }
+
+    #[tokio::test]
+    async fn selector_does_not_hide_referenced_config_objects() {
+        use crate::offline::{offline_client, DumpStore};
+        use serde_json::json;
+        use std::sync::Arc;
+
+        let mut store = DumpStore::default();
+        store.add(json!({
+            "kind": "List", "apiVersion": "v1",
+            "items": [
+                { "kind": "Pod", "apiVersion": "v1",
+                  "metadata": { "name": "api-0", "namespace": "shop", "labels": { "app": "checkout" } },
+                  "spec": { "containers": [{ "name": "api", "envFrom": [
+                      { "configMapRef": { "name": "settings" } },
+                      { "secretRef": { "name": "db" } }
+                  ] }] },
+                  "status": { "phase": "Running" } },
+                { "kind": "ConfigMap", "apiVersion": "v1",
+                  "metadata": { "name": "settings", "namespace": "shop" } },
+                { "kind": "Secret", "apiVersion": "v1",
+                  "metadata": { "name": "db", "namespace": "shop" } }
+            ]
+        }));
+        let client = offline_client(Arc::new(store), None)
+            .with_label_selector(Some("app=checkout".to_string()));
+        let options = InspectionOptions {
+            offline: true,
+            ..Default::default()
+        };
+        let result = OrphansInspector::new(&client, &options)
+            .inspect(Some("shop"))
+            .await
+            .unwrap();
+        let codes: Vec<&str> = result
+            .summary
+            .issues
+            .iter()
+            .filter_map(|i| i.rule_id.as_deref())
+            .collect();
+        assert!(!codes.contains(&"ORPH-011"), "{:?}", codes);
+        let checks: Vec<&str> = result.checks.iter().map(|c| c.name.as_str()).collect();
+        assert!(checks.contains(&"Config References"), "{:?}", checks);
+        assert!(!checks.contains(&"Unused ConfigMaps"), "{:?}", checks);
+    }
+
+    #[tokio::test]
+    async fn daemon_set_templates_reference_secrets_without_pods() {
+        use crate::offline::{offline_client, DumpStore};
+        use serde_json::json;
+        use std::sync::Arc;
+
+        // A DaemonSet whose nodeSelector matches no node runs no pods.
+        let mut store = DumpStore::default();
+        store.add(json!({
+            "kind": "List", "apiVersion": "v1",
+            "items": [
+                { "kind": "DaemonSet", "apiVersion": "apps/v1",
+                  "metadata": { "name": "agent", "namespace": "ops" },
+                  "spec": { "selector": { "matchLabels": { "app": "agent" } },
+                            "template": { "spec": { "containers": [{ "name": "agent", "envFrom": [
+                                { "secretRef": { "name": "agent-token" } }
+                            ] }] } } } },
+                { "kind": "Secret", "apiVersion": "v1",
+                  "metadata": { "name": "agent-token", "namespace": "ops" } }
+            ]
+        }));
+        let client = offline_client(Arc::new(store), None);
+        let options = InspectionOptions {
+            offline: true,
+            ..Default::default()
+        };
+        let result = OrphansInspector::new(&client, &options)
+            .inspect(Some("ops"))
+            .await
+            .unwrap();
+        let codes: Vec<&str> = result
+            .summary
+            .issues
+            .iter()
+            .filter_map(|i| i.rule_id.as_deref())
+            .collect();
+        assert!(!codes.contains(&"ORPH-002"), "{:?}", codes);
+        let checks: Vec<&str> = result.checks.iter().map(|c| c.name.as_str()).collect();
+        assert!(checks.contains(&"Unused Secrets"), "{:?}", checks);
+    }
+}
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use chrono::Utc;
+use futures::TryStreamExt;
 use kube::api::ListParams;
 use log::info;
 
+use crate::inspections::controllers::PodOwners;
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::probes;
+use crate::inspections::sampling::IssueSampler;
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
@@ -27,23 +32,34 @@ fn container_state_reason_to_rule_id(state_kind: &str, reason: &str) -> &'static
 
 pub struct PodInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
+    owners: Option<&'a PodOwners>,
 }
 
 impl<'a> PodInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self {
+            client,
+            options,
+            owners: None,
+        }
+    }
+
+    /// Roll pod findings up to the controllers in `owners` while reading pods.
+    pub fn with_pod_owners(mut self, owners: Option<&'a PodOwners>) -> Self {
+        self.owners = owners;
+        self
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
         info!("Starting Pod status inspection");
 
         let pods_api = self.client.pods(namespace);
-        let pods = pods_api.list(&ListParams::default()).await?;
-
         let mut checks = Vec::new();
-        let mut issues = Vec::new();
+        let mut issues =
+            IssueSampler::new(self.options.max_issues_per_rule).with_owners(self.owners);
 
-        let total_pods = pods.items.len();
+        let mut total_pods = 0;
         let mut running_pods = 0;
         let mut failed_pods = 0;
         let mut pending_pods = 0;
@@ -51,206 +67,289 @@ impl<'a> PodInspector<'a> {
         let mut reason_counts: std::collections::HashMap<String, u32> =
             std::collections::HashMap::new();
         let mut pod_container_states: Vec<PodContainerStateRow> = Vec::new();
+        let mut pods_with_probes = 0;
+        let mut pods_with_probe_findings = 0;
 
-        for pod in &pods.items {
-            let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
-            let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+        let mut pages = self.client.list_pages(&pods_api, &ListParams::default());
+        while let Some(page) = pages.try_next().await? {
+            for pod in &page {
+                total_pods += 1;
+                let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+                let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
 
-            if let Some(status) = &pod.status {
-                // Check pod phase
-                match status.phase.as_deref() {
-                    Some("Running") => {
-                        running_pods += 1;
-                        // Running but not Ready (e.g. 0/1 Ready, readiness probe failing)
-                        if let Some(conditions) = &status.conditions {
-                            for condition in conditions {
-                                if condition.type_ == "Ready" && condition.status == "False" {
-                                    let reason = condition
-                                        .reason
-                                        .as_deref()
-                                        .unwrap_or("NotReady")
-                                        .to_string();
-                                    let message =
-                                        condition.message.as_deref().unwrap_or("").to_string();
-                                    let desc = if message.is_empty() {
-                                        format!(
-                                            "Pod {}/{} is Running but not Ready ({})",
-                                            pod_namespace, pod_name, reason
-                                        )
-                                    } else {
-                                        format!(
-                                            "Pod {}/{} is Running but not Ready ({}): {}",
-                                            pod_namespace, pod_name, reason, message
-                                        )
-                                    };
-                                    issues.push(Issue {
-                                        severity: IssueSeverity::Critical,
-                                        category: "Pod".to_string(),
-                                        description: desc,
-                                        resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                        recommendation: "Check readiness probes, container logs, and pod events (e.g. kubectl describe pod)".to_string(),
-                                        rule_id: Some("POD-012".to_string()),
-                                    });
-                                    break;
-                                }
-                            }
-                        }
+                let finished = matches!(
+                    pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+                    Some("Succeeded" | "Failed")
+                );
+                if let Some(findings) = probes::pod_probe_issues(pod).filter(|_| !finished) {
+                    pods_with_probes += 1;
+                    if findings.iter().any(|i| i.severity != IssueSeverity::Info) {
+                        pods_with_probe_findings += 1;
                     }
-                    Some("Failed") => {
-                        failed_pods += 1;
-                        issues.push(Issue {
-                            severity: IssueSeverity::Critical,
-                            category: "Pod".to_string(),
-                            description: format!(
-                                "Pod {}/{} is in Failed state",
-                                pod_namespace, pod_name
-                            ),
-                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                            recommendation: "Check pod logs and events".to_string(),
-                            rule_id: Some("POD-001".to_string()),
-                        });
+                    for issue in findings {
+                        issues.push(issue);
                     }
-                    Some("Pending") => {
-                        pending_pods += 1;
-                        if let Some(conditions) = &status.conditions {
-                            for condition in conditions {
-                                if condition.type_ == "PodScheduled" && condition.status == "False"
-                                {
-                                    issues.push(Issue {
-                                        severity: IssueSeverity::Warning,
-                                        category: "Pod".to_string(),
-                                        description: format!(
-                                            "Pod {}/{} cannot be scheduled",
-                                            pod_namespace, pod_name
-                                        ),
-                                        resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                        recommendation: "Check resource requests and node capacity"
-                                            .to_string(),
-                                        rule_id: Some("POD-002".to_string()),
-                                    });
+                }
+
+                if let Some(status) = &pod.status {
+                    // Check pod phase
+                    match status.phase.as_deref() {
+                        Some("Running") => {
+                            running_pods += 1;
+                            // Running but not Ready (e.g. 0/1 Ready, readiness probe failing)
+                            if let Some(conditions) = &status.conditions {
+                                for condition in conditions {
+                                    if condition.type_ == "Ready" && condition.status == "False" {
+                                        let reason = condition
+                                            .reason
+                                            .as_deref()
+                                            .unwrap_or("NotReady")
+                                            .to_string();
+                                        let message =
+                                            condition.message.as_deref().unwrap_or("").to_string();
+                                        let desc = if message.is_empty() {
+                                            format!(
+                                                "Pod {}/{} is Running but not Ready ({})",
+                                                pod_namespace, pod_name, reason
+                                            )
+                                        } else {
+                                            format!(
+                                                "Pod {}/{} is Running but not Ready ({}): {}",
+                                                pod_namespace, pod_name, reason, message
+                                            )
+                                        };
+                                        issues.push(Issue {
+                                            severity: IssueSeverity::Critical,
+                                            category: "Pod".to_string(),
+                                            description: desc,
+                                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                            recommendation: "Check readiness probes, container logs, and pod events (e.g. kubectl describe pod)".to_string(),
+                                            rule_id: Some("POD-012".to_string()),
+                                            team: None,
+                                            fingerprint: None,
+                                            key_attributes: Vec::new(),
+                                            remediation_commands: vec![format!("kubectl -n {} describe pod {}", pod_namespace, pod_name)],
+                                        });
+                                        break;
+                                    }
                                 }
                             }
                         }
-                    }
-                    _ => {}
-                }
-
-                // Collect all container statuses (init + main)
-                let all_container_statuses: Vec<_> = status
-                    .init_container_statuses
-                    .as_deref()
-                    .unwrap_or(&[])
-                    .iter()
-                    .chain(status.container_statuses.as_deref().unwrap_or(&[]).iter())
-                    .collect();
-
-                // Check container state: waiting (e.g. ImagePullBackOff, CrashLoopBackOff) and terminated (non-zero exit)
-                for container_status in &all_container_statuses {
-                    if let Some(state) = &container_status.state {
-                        if let Some(waiting) = &state.waiting {
-                            let reason = waiting.reason.as_deref().unwrap_or("Waiting").to_string();
-                            *reason_counts.entry(reason.clone()).or_insert(0) += 1;
-                            let message = waiting.message.as_deref().unwrap_or("").to_string();
-                            pod_container_states.push(PodContainerStateRow {
-                                pod_ref: format!("{}/{}", pod_namespace, pod_name),
-                                container_name: container_status.name.clone(),
-                                state_kind: "waiting".to_string(),
-                                reason: reason.clone(),
-                                detail: message.clone(),
-                            });
-                            let desc = if message.is_empty() {
-                                format!(
-                                    "Pod {}/{} has container {} in state {}",
-                                    pod_namespace, pod_name, container_status.name, reason
-                                )
-                            } else {
-                                format!(
-                                    "Pod {}/{} has container {} in state {}: {}",
-                                    pod_namespace, pod_name, container_status.name, reason, message
-                                )
-                            };
-                            let rule_id = container_state_reason_to_rule_id("waiting", &reason);
+                        Some("Failed") => {
+                            failed_pods += 1;
                             issues.push(Issue {
                                 severity: IssueSeverity::Critical,
-                                category: "Container".to_string(),
-                                description: desc,
+                                category: "Pod".to_string(),
+                                description: format!(
+                                    "Pod {}/{} is in Failed state",
+                                    pod_namespace, pod_name
+                                ),
                                 resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                recommendation: "Check image, pull secrets, and pod events (e.g. kubectl describe pod)".to_string(),
-                                rule_id: Some(rule_id.to_string()),
+                                recommendation: "Check pod logs and events".to_string(),
+                                rule_id: Some("POD-001".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: vec![
+                                    format!(
+                                        "kubectl -n {} describe pod {}",
+                                        pod_namespace, pod_name
+                                    ),
+                                    format!(
+                                        "kubectl -n {} logs {} --all-containers",
+                                        pod_namespace, pod_name
+                                    ),
+                                ],
                             });
                         }
-                        if let Some(terminated) = &state.terminated {
-                            if terminated.exit_code != 0 {
-                                let reason = terminated
-                                    .reason
-                                    .as_deref()
-                                    .unwrap_or("Terminated")
-                                    .to_string();
+                        Some("Pending") => {
+                            pending_pods += 1;
+                            if let Some(conditions) = &status.conditions {
+                                for condition in conditions {
+                                    if condition.type_ == "PodScheduled"
+                                        && condition.status == "False"
+                                    {
+                                        issues.push(Issue {
+                                            severity: IssueSeverity::Warning,
+                                            category: "Pod".to_string(),
+                                            description: format!(
+                                                "Pod {}/{} cannot be scheduled",
+                                                pod_namespace, pod_name
+                                            ),
+                                            resource: Some(format!(
+                                                "{}/{}",
+                                                pod_namespace, pod_name
+                                            )),
+                                            recommendation:
+                                                "Check resource requests and node capacity"
+                                                    .to_string(),
+                                            rule_id: Some("POD-002".to_string()),
+                                            team: None,
+                                            fingerprint: None,
+                                            key_attributes: Vec::new(),
+                                            remediation_commands: vec![format!(
+                                                "kubectl -n {} describe pod {}",
+                                                pod_namespace, pod_name
+                                            )],
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // Collect all container statuses (init + main)
+                    let all_container_statuses: Vec<_> = status
+                        .init_container_statuses
+                        .as_deref()
+                        .unwrap_or(&[])
+                        .iter()
+                        .chain(status.container_statuses.as_deref().unwrap_or(&[]).iter())
+                        .collect();
+
+                    // Check container state: waiting (e.g. ImagePullBackOff, CrashLoopBackOff) and terminated (non-zero exit)
+                    for container_status in &all_container_statuses {
+                        if let Some(state) = &container_status.state {
+                            if let Some(waiting) = &state.waiting {
+                                let reason =
+                                    waiting.reason.as_deref().unwrap_or("Waiting").to_string();
                                 *reason_counts.entry(reason.clone()).or_insert(0) += 1;
-                                let detail = format!("exit_code={}", terminated.exit_code);
+                                let message = waiting.message.as_deref().unwrap_or("").to_string();
                                 pod_container_states.push(PodContainerStateRow {
                                     pod_ref: format!("{}/{}", pod_namespace, pod_name),
                                     container_name: container_status.name.clone(),
-                                    state_kind: "terminated".to_string(),
+                                    state_kind: "waiting".to_string(),
                                     reason: reason.clone(),
-                                    detail,
+                                    detail: message.clone(),
                                 });
-                                let rule_id =
-                                    container_state_reason_to_rule_id("terminated", &reason);
-                                let desc = format!(
-                                    "Pod {}/{} container {} terminated: reason={}, exit_code={}",
-                                    pod_namespace,
-                                    pod_name,
-                                    container_status.name,
-                                    reason,
-                                    terminated.exit_code
-                                );
+                                let desc = if message.is_empty() {
+                                    format!(
+                                        "Pod {}/{} has container {} in state {}",
+                                        pod_namespace, pod_name, container_status.name, reason
+                                    )
+                                } else {
+                                    format!(
+                                        "Pod {}/{} has container {} in state {}: {}",
+                                        pod_namespace,
+                                        pod_name,
+                                        container_status.name,
+                                        reason,
+                                        message
+                                    )
+                                };
+                                let rule_id = container_state_reason_to_rule_id("waiting", &reason);
+                                let mut commands = vec![format!(
+                                    "kubectl -n {} describe pod {}",
+                                    pod_namespace, pod_name
+                                )];
+                                if reason == "CrashLoopBackOff" {
+                                    commands.push(format!(
+                                        "kubectl -n {} logs {} -c {} --previous",
+                                        pod_namespace, pod_name, container_status.name
+                                    ));
+                                }
                                 issues.push(Issue {
                                     severity: IssueSeverity::Critical,
                                     category: "Container".to_string(),
                                     description: desc,
                                     resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                    recommendation: "Check container logs and events".to_string(),
+                                    recommendation: "Check image, pull secrets, and pod events (e.g. kubectl describe pod)".to_string(),
                                     rule_id: Some(rule_id.to_string()),
+                                    team: None,
+                                    fingerprint: None,
+                                    key_attributes: container_key(&container_status.name),
+                                    remediation_commands: commands,
                                 });
                             }
+                            if let Some(terminated) = &state.terminated {
+                                if terminated.exit_code != 0 {
+                                    let reason = terminated
+                                        .reason
+                                        .as_deref()
+                                        .unwrap_or("Terminated")
+                                        .to_string();
+                                    *reason_counts.entry(reason.clone()).or_insert(0) += 1;
+                                    let detail = format!("exit_code={}", terminated.exit_code);
+                                    pod_container_states.push(PodContainerStateRow {
+                                        pod_ref: format!("{}/{}", pod_namespace, pod_name),
+                                        container_name: container_status.name.clone(),
+                                        state_kind: "terminated".to_string(),
+                                        reason: reason.clone(),
+                                        detail,
+                                    });
+                                    let rule_id =
+                                        container_state_reason_to_rule_id("terminated", &reason);
+                                    let desc = format!(
+                                        "Pod {}/{} container {} terminated: reason={}, exit_code={}",
+                                        pod_namespace,
+                                        pod_name,
+                                        container_status.name,
+                                        reason,
+                                        terminated.exit_code
+                                    );
+                                    issues.push(Issue {
+                                        severity: IssueSeverity::Critical,
+                                        category: "Container".to_string(),
+                                        description: desc,
+                                        resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                        recommendation: "Check container logs and events"
+                                            .to_string(),
+                                        rule_id: Some(rule_id.to_string()),
+                                        team: None,
+                                        fingerprint: None,
+                                        key_attributes: container_key(&container_status.name),
+                                        remediation_commands: vec![format!(
+                                            "kubectl -n {} logs {} -c {} --previous",
+                                            pod_namespace, pod_name, container_status.name
+                                        )],
+                                    });
+                                }
+                            }
                         }
                     }
-                }
 
-                // Check container statuses and restart counts: 0 → no issue; 1–3 → Info; 4–10 → Warning; >10 → Critical.
-                // Pod Stability score: count pods that have at least one container with restart_count > 3.
-                let mut pod_has_excessive_restarts = false;
-                for container_status in &all_container_statuses {
-                    let r = container_status.restart_count;
-                    if r > 3 {
-                        pod_has_excessive_restarts = true;
+                    // Check container statuses and restart counts: 0 → no issue; 1–3 → Info; 4–10 → Warning; >10 → Critical.
+                    // Pod Stability score: count pods that have at least one container with restart_count > 3.
+                    let mut pod_has_excessive_restarts = false;
+                    for container_status in &all_container_statuses {
+                        let r = container_status.restart_count;
+                        if r > 3 {
+                            pod_has_excessive_restarts = true;
+                        }
+                        if r == 0 {
+                            continue;
+                        }
+                        let severity = if r <= 3 {
+                            IssueSeverity::Info
+                        } else if r <= 10 {
+                            IssueSeverity::Warning
+                        } else {
+                            IssueSeverity::Critical
+                        };
+                        issues.push(Issue {
+                            severity,
+                            category: "Container".to_string(),
+                            description: format!(
+                                "Container {} in pod {}/{} has {} restarts",
+                                container_status.name, pod_namespace, pod_name, r
+                            ),
+                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                            recommendation: "Investigate container crashes and resource limits"
+                                .to_string(),
+                            rule_id: Some("POD-003".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: container_key(&container_status.name),
+                            remediation_commands: vec![format!(
+                                "kubectl -n {} logs {} -c {} --previous",
+                                pod_namespace, pod_name, container_status.name
+                            )],
+                        });
                     }
-                    if r == 0 {
-                        continue;
+                    if pod_has_excessive_restarts {
+                        pods_with_restarts += 1;
                     }
-                    let severity = if r <= 3 {
-                        IssueSeverity::Info
-                    } else if r <= 10 {
-                        IssueSeverity::Warning
-                    } else {
-                        IssueSeverity::Critical
-                    };
-                    issues.push(Issue {
-                        severity,
-                        category: "Container".to_string(),
-                        description: format!(
-                            "Container {} in pod {}/{} has {} restarts",
-                            container_status.name, pod_namespace, pod_name, r
-                        ),
-                        resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                        recommendation: "Investigate container crashes and resource limits"
-                            .to_string(),
-                        rule_id: Some("POD-003".to_string()),
-                    });
-                }
-                if pod_has_excessive_restarts {
-                    pods_with_restarts += 1;
                 }
             }
         }
@@ -331,9 +430,14 @@ impl<'a> PodInspector<'a> {
             },
         });
 
+        checks.push(probes::probe_check(
+            pods_with_probes,
+            pods_with_probe_findings,
+        ));
+
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
-        let summary = self.create_summary(&checks, issues);
+        let summary = self.create_summary(&checks, issues.into_issues());
 
         Ok(InspectionResult {
             inspection_type: "Pod Status".to_string(),
@@ -348,6 +452,8 @@ impl<'a> PodInspector<'a> {
                 Some(pod_container_states)
             },
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
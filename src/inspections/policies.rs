@@ -1,20 +1,26 @@
+use std::collections::BTreeSet;
+
 use anyhow::Result;
 use chrono::Utc;
 use k8s_openapi::api::core::v1::{LimitRange, ResourceQuota};
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use kube::api::ListParams;
-use kube::Api;
+use kube::{Api, ResourceExt};
+use serde::de::DeserializeOwned;
 
+use crate::inspections::namespace_lifecycle;
+use crate::inspections::options::InspectionOptions;
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
 pub struct PoliciesInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
 }
 
 impl<'a> PoliciesInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
@@ -28,6 +34,10 @@ impl<'a> PoliciesInspector<'a> {
         checks.push(quota_check);
         checks.push(limit_check);
         checks.push(pdb_check);
+        checks.push(
+            self.inspect_namespace_lifecycle(namespace, &mut issues)
+                .await?,
+        );
 
         let overall_score = if checks.is_empty() {
             0.0
@@ -46,6 +56,8 @@ impl<'a> PoliciesInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
@@ -58,7 +70,10 @@ impl<'a> PoliciesInspector<'a> {
             Some(ns) => Api::namespaced(self.client.client().clone(), ns),
             None => Api::all(self.client.client().clone()),
         };
-        let quotas = quota_api.list(&ListParams::default()).await?;
+        let quotas = self
+            .client
+            .list_unselected(&quota_api, &ListParams::default())
+            .await?;
 
         if namespace.is_some() {
             if quotas.items.is_empty() {
@@ -70,6 +85,10 @@ impl<'a> PoliciesInspector<'a> {
                     recommendation: "Define ResourceQuota to prevent resource exhaustion."
                         .to_string(),
                     rule_id: Some("POLICY-001".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
                 });
                 return Ok(CheckResult {
                     name: "Resource Quotas".to_string(),
@@ -117,7 +136,10 @@ impl<'a> PoliciesInspector<'a> {
             Some(ns) => Api::namespaced(self.client.client().clone(), ns),
             None => Api::all(self.client.client().clone()),
         };
-        let limits = limit_api.list(&ListParams::default()).await?;
+        let limits = self
+            .client
+            .list_unselected(&limit_api, &ListParams::default())
+            .await?;
 
         if limits.items.is_empty() {
             issues.push(Issue {
@@ -132,6 +154,10 @@ impl<'a> PoliciesInspector<'a> {
                 recommendation: "Define LimitRange to ensure pod resource defaults and limits."
                     .to_string(),
                 rule_id: Some("POLICY-002".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             return Ok(CheckResult {
                 name: "Limit Ranges".to_string(),
@@ -167,7 +193,10 @@ impl<'a> PoliciesInspector<'a> {
             Some(ns) => Api::namespaced(self.client.client().clone(), ns),
             None => Api::all(self.client.client().clone()),
         };
-        let pdbs = pdb_api.list(&ListParams::default()).await?;
+        let pdbs = self
+            .client
+            .list_unselected(&pdb_api, &ListParams::default())
+            .await?;
 
         if pdbs.items.is_empty() {
             issues.push(Issue {
@@ -177,6 +206,10 @@ impl<'a> PoliciesInspector<'a> {
                 resource: namespace.map(|ns| ns.to_string()),
                 recommendation: "Define PodDisruptionBudget for critical workloads to avoid voluntary eviction impact.".to_string(),
                 rule_id: Some("POLICY-003".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
             return Ok(CheckResult {
                 name: "Pod Disruption Budgets".to_string(),
@@ -205,6 +238,10 @@ impl<'a> PoliciesInspector<'a> {
                         recommendation: "Ensure enough replicas to satisfy PDB requirements."
                             .to_string(),
                         rule_id: Some("POLICY-004".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
             }
@@ -239,6 +276,71 @@ impl<'a> PoliciesInspector<'a> {
         })
     }
 
+    async fn inspect_namespace_lifecycle(
+        &self,
+        namespace: Option<&str>,
+        issues: &mut Vec<Issue>,
+    ) -> Result<CheckResult> {
+        let lp = ListParams::default();
+        let namespaces: Vec<_> = self
+            .client
+            .list_all(&self.client.namespaces(), &lp)
+            .await?
+            .items
+            .into_iter()
+            .filter(|ns| namespace.is_none_or(|n| ns.metadata.name.as_deref() == Some(n)))
+            .collect();
+        // With --selector the namespaced lists below only see matching objects, so every
+        // namespace could look empty; the empty-namespace finding is left out then.
+        let occupied =
+            if self.options.empty_namespace_days > 0 && self.client.label_selector().is_none() {
+                Some(self.occupied_namespaces(namespace).await?)
+            } else {
+                None
+            };
+        Ok(namespace_lifecycle::namespace_lifecycle(
+            &namespaces,
+            occupied.as_ref(),
+            &self.options.required_namespace_labels,
+            self.options.empty_namespace_days,
+            self.options.stuck_deletion_minutes,
+            Utc::now(),
+            issues,
+        ))
+    }
+
+    /// Namespaces holding pods, Deployments, StatefulSets, CronJobs, Services or PVCs.
+    async fn occupied_namespaces(&self, namespace: Option<&str>) -> Result<BTreeSet<String>> {
+        let mut occupied = self.namespaces_of(&self.client.pods(namespace)).await?;
+        occupied.extend(
+            self.namespaces_of(&self.client.deployments(namespace))
+                .await?,
+        );
+        occupied.extend(
+            self.namespaces_of(&self.client.stateful_sets(namespace))
+                .await?,
+        );
+        occupied.extend(
+            self.namespaces_of(&self.client.cron_jobs(namespace))
+                .await?,
+        );
+        occupied.extend(self.namespaces_of(&self.client.services(namespace)).await?);
+        occupied.extend(
+            self.namespaces_of(&self.client.persistent_volume_claims(namespace))
+                .await?,
+        );
+        Ok(occupied)
+    }
+
+    async fn namespaces_of<K>(&self, api: &Api<K>) -> Result<BTreeSet<String>>
+    where
+        K: kube::Resource<DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+        K::Scope: 'static,
+    {
+        let list = self.client.list_all(api, &ListParams::default()).await?;
+        Ok(list.items.iter().filter_map(|o| o.namespace()).collect())
+    }
+
     fn build_summary(&self, checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
         let total_checks = checks.len() as u32;
         let mut passed_checks = 0;
@@ -0,0 +1,310 @@
+//! PriorityClass usage. Priority decides which pods the scheduler preempts when the cluster is
+//! full: without PriorityClasses every workload is equal, add-ons the cluster cannot run without
+//! (CNI, DNS, CSI) should carry a `system-*` priority so they are never preempted, and user
+//! workloads on a `system-*` priority can evict those add-ons.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::scheduling::v1::PriorityClass;
+
+use crate::inspections::controllers::PodOwners;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity, PriorityRow};
+
+/// Built-in classes reserved for cluster components.
+pub const SYSTEM_PRIORITY_CLASSES: [&str; 2] = ["system-cluster-critical", "system-node-critical"];
+
+const NO_CLASS: &str = "(none)";
+
+/// Workload name fragments of CNI, DNS and CSI add-ons (HA-013).
+const CRITICAL_ADDONS: &[(&str, &str)] = &[
+    ("coredns", "DNS"),
+    ("kube-dns", "DNS"),
+    ("node-local-dns", "DNS"),
+    ("calico-node", "CNI"),
+    ("cilium", "CNI"),
+    ("aws-node", "CNI"),
+    ("flannel", "CNI"),
+    ("weave-net", "CNI"),
+    ("antrea-agent", "CNI"),
+    ("kube-router", "CNI"),
+    ("azure-cni", "CNI"),
+    ("csi", "CSI"),
+];
+
+fn is_system_class(name: &str) -> bool {
+    SYSTEM_PRIORITY_CLASSES.contains(&name)
+}
+
+/// Namespaces of platform components, where `system-*` priorities are expected.
+fn is_platform_namespace(namespace: &str) -> bool {
+    namespace.starts_with("kube-") || namespace.ends_with("-system")
+}
+
+fn class_of(pod: &Pod) -> Option<&str> {
+    pod.spec.as_ref()?.priority_class_name.as_deref()
+}
+
+fn is_running(pod: &Pod) -> bool {
+    !matches!(
+        pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+        Some("Succeeded" | "Failed")
+    )
+}
+
+/// `namespace/workload` of a pod: its controller, else the pod itself.
+fn workload(pod: &Pod, owners: &PodOwners) -> String {
+    let namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+    let name = pod.metadata.name.as_deref().unwrap_or("unknown");
+    match owners.controller_of(&format!("{}/{}", namespace, name)) {
+        Some(c) => format!("{}/{}", c.namespace, c.name),
+        None => format!("{}/{}", namespace, name),
+    }
+}
+
+/// Kind of critical add-on (CNI, DNS, CSI) a workload is, from its name.
+pub(crate) fn critical_addon(workload: &str) -> Option<&'static str> {
+    let name = workload.rsplit('/').next().unwrap_or(workload);
+    CRITICAL_ADDONS
+        .iter()
+        .find(|(fragment, _)| name.contains(fragment))
+        .map(|(_, kind)| *kind)
+}
+
+/// Running pods per PriorityClass, highest value first. Classes without pods are listed too, so
+/// the table shows every class defined.
+pub fn priority_distribution(classes: &[PriorityClass], pods: &[Pod]) -> Vec<PriorityRow> {
+    let mut rows: BTreeMap<String, PriorityRow> = classes
+        .iter()
+        .filter_map(|c| {
+            let name = c.metadata.name.clone()?;
+            Some((
+                name.clone(),
+                PriorityRow {
+                    priority_class: name,
+                    value: c.value,
+                    global_default: c.global_default.unwrap_or(false),
+                    pod_count: 0,
+                },
+            ))
+        })
+        .collect();
+    for pod in pods.iter().filter(|p| is_running(p)) {
+        let name = class_of(pod).unwrap_or(NO_CLASS);
+        let value = pod.spec.as_ref().and_then(|s| s.priority).unwrap_or(0);
+        rows.entry(name.to_string())
+            .or_insert_with(|| PriorityRow {
+                priority_class: name.to_string(),
+                value,
+                global_default: false,
+                pod_count: 0,
+            })
+            .pod_count += 1;
+    }
+    let mut rows: Vec<PriorityRow> = rows.into_values().collect();
+    rows.sort_by(|a, b| {
+        b.value
+            .cmp(&a.value)
+            .then_with(|| a.priority_class.cmp(&b.priority_class))
+    });
+    rows
+}
+
+/// "Priority Classes" check: HA-012 when only the built-in `system-*` classes exist, HA-013 per
+/// CNI/DNS/CSI add-on in `addon_pods` without a `system-*` priority, HA-014 per workload outside
+/// platform namespaces (`kube-*`, `*-system`) in `pods` that uses one.
+pub fn priority_check(
+    classes: &[PriorityClass],
+    pods: &[Pod],
+    addon_pods: &[Pod],
+    owners: &PodOwners,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let custom = classes
+        .iter()
+        .filter_map(|c| c.metadata.name.as_deref())
+        .filter(|n| !is_system_class(n))
+        .count();
+    let mut flagged = 0;
+    if custom == 0 {
+        flagged += 1;
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Workload",
+            "HA-012",
+            "No PriorityClass besides the built-in system classes; all workloads are preempted alike when the cluster is full".to_string(),
+            None,
+            "Define PriorityClasses for critical and best-effort workloads (see HA-012).",
+        ));
+    }
+
+    let mut addons: BTreeMap<String, (&str, bool)> = BTreeMap::new();
+    for pod in addon_pods.iter().filter(|p| is_running(p)) {
+        let name = workload(pod, owners);
+        let Some(kind) = critical_addon(&name) else {
+            continue;
+        };
+        let system = class_of(pod).is_some_and(is_system_class);
+        addons
+            .entry(name)
+            .and_modify(|(_, s)| *s &= system)
+            .or_insert((kind, system));
+    }
+    for (name, (kind, _)) in addons.iter().filter(|(_, (_, system))| !system) {
+        flagged += 1;
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Workload",
+            "HA-013",
+            format!(
+                "{} add-on {} does not use a system priority class and can be preempted",
+                kind, name
+            ),
+            Some(name.clone()),
+            "Set priorityClassName: system-node-critical (DaemonSets) or system-cluster-critical.",
+        ));
+    }
+
+    let mut misused: BTreeMap<String, &str> = BTreeMap::new();
+    for pod in pods.iter().filter(|p| is_running(p)) {
+        let namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+        let Some(class) = class_of(pod).filter(|c| is_system_class(c)) else {
+            continue;
+        };
+        let name = workload(pod, owners);
+        if is_platform_namespace(namespace) || critical_addon(&name).is_some() {
+            continue;
+        }
+        misused.insert(name, class);
+    }
+    for (name, class) in &misused {
+        flagged += 1;
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Workload",
+            "HA-014",
+            format!(
+                "Workload {} uses {}, which lets it preempt cluster add-ons",
+                name, class
+            ),
+            Some(name.clone()),
+            "Give application workloads a PriorityClass of their own, below the system classes.",
+        ));
+    }
+
+    let evaluated = 1 + addons.len() + misused.len();
+    let namespaces: BTreeSet<&str> = misused.keys().filter_map(|k| k.split('/').next()).collect();
+    CheckResult {
+        name: "Priority Classes".to_string(),
+        description: "PriorityClasses defined, system priorities on add-ons and not on workloads"
+            .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: (evaluated - flagged) as f64 / evaluated as f64 * 100.0,
+        max_score: 100.0,
+        details: Some(format!(
+            "{} custom PriorityClass(es); {}/{} critical add-ons on a system priority; {} workload(s) in {} namespace(s) misusing system priorities",
+            custom,
+            addons.values().filter(|(_, s)| *s).count(),
+            addons.len(),
+            misused.len(),
+            namespaces.len()
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See HA-012..HA-014; reserve system priorities for cluster add-ons.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::PodSpec;
+    use kube::api::ObjectMeta;
+
+    fn class(name: &str, value: i32) -> PriorityClass {
+        PriorityClass {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            value,
+            ..Default::default()
+        }
+    }
+
+    fn pod(namespace: &str, name: &str, class: Option<&str>, priority: i32) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                namespace: Some(namespace.to_string()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                priority_class_name: class.map(str::to_string),
+                priority: Some(priority),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_missing_classes_unprotected_addons_and_misused_system_priority() {
+        let classes = vec![
+            class("system-cluster-critical", 2_000_000_000),
+            class("system-node-critical", 2_000_001_000),
+        ];
+        let pods = vec![
+            pod(
+                "kube-system",
+                "coredns-abc",
+                Some("system-cluster-critical"),
+                2_000_000_000,
+            ),
+            pod("kube-system", "ebs-csi-node-x1", None, 0),
+            pod(
+                "shop",
+                "api-1",
+                Some("system-cluster-critical"),
+                2_000_000_000,
+            ),
+            pod("shop", "web-1", None, 0),
+        ];
+        let owners = PodOwners::default();
+        let mut issues = Vec::new();
+        let check = priority_check(&classes, &pods, &pods, &owners, &mut issues);
+        let found: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|i| (i.rule_id.as_deref().unwrap(), i.resource.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("HA-012", None),
+                ("HA-013", Some("kube-system/ebs-csi-node-x1")),
+                ("HA-014", Some("shop/api-1")),
+            ]
+        );
+        assert_eq!(check.status, CheckStatus::Warning);
+
+        let rows = priority_distribution(&classes, &pods);
+        let counts: Vec<(&str, u32)> = rows
+            .iter()
+            .map(|r| (r.priority_class.as_str(), r.pod_count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                ("system-node-critical", 0),
+                ("system-cluster-critical", 2),
+                ("(none)", 2),
+            ]
+        );
+    }
+}
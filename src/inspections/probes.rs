@@ -0,0 +1,279 @@
+//! Probe anti-patterns that turn a slow dependency or a busy node into a restart cascade: a
+//! liveness probe identical to the readiness probe or checking downstream dependencies restarts
+//! healthy containers when something else is down, a liveness probe without initial delay that
+//! fails on the first miss kills slow starters, and exec probes that start a JVM or a database
+//! client every few seconds eat the CPU they are meant to watch.
+
+use k8s_openapi::api::core::v1::{Container, Pod, Probe};
+
+use crate::inspections::types::{container_key, CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Liveness HTTP path fragments of endpoints that aggregate downstream checks (POD-014).
+const DEPENDENCY_PATHS: &[&str] = &["ready", "deep", "depend", "database", "/db", "/health/all"];
+
+/// Spring Boot's aggregate health endpoint; `/actuator/health/liveness` is the local one.
+const ACTUATOR_HEALTH: &str = "/actuator/health";
+
+/// Programs too heavy to start on every probe period (POD-016).
+const HEAVY_COMMANDS: &[&str] = &[
+    "java", "jcmd", "jstack", "jps", "python", "python3", "node", "ruby", "php", "kubectl", "psql",
+    "mysql", "mongosh", "mongo", "find", "du",
+];
+
+/// Same check (exec, httpGet, tcpSocket or grpc) regardless of timings.
+fn same_handler(a: &Probe, b: &Probe) -> bool {
+    a.exec == b.exec && a.http_get == b.http_get && a.tcp_socket == b.tcp_socket && a.grpc == b.grpc
+}
+
+/// Why a liveness probe depends on more than the container itself, if it does.
+fn external_dependency(liveness: &Probe) -> Option<String> {
+    let http = liveness.http_get.as_ref()?;
+    if let Some(host) = http.host.as_deref().filter(|h| !h.is_empty()) {
+        return Some(format!("calls host {}", host));
+    }
+    let path = http.path.as_deref().unwrap_or("/");
+    let lower = path.to_lowercase();
+    (lower.trim_end_matches('/') == ACTUATOR_HEALTH
+        || DEPENDENCY_PATHS.iter().any(|p| lower.contains(p)))
+    .then(|| format!("checks {}", path))
+}
+
+/// Liveness probe that restarts the container on its first failure right after start.
+fn aggressive(liveness: &Probe, has_startup_probe: bool) -> bool {
+    !has_startup_probe
+        && liveness.initial_delay_seconds.unwrap_or(0) == 0
+        && liveness.failure_threshold == Some(1)
+}
+
+/// First heavy program an exec probe starts, looking inside `sh -c` scripts.
+fn heavy_command(probe: &Probe) -> Option<String> {
+    let command = probe.exec.as_ref()?.command.as_ref()?;
+    command
+        .iter()
+        .flat_map(|arg| arg.split(|c: char| c.is_whitespace() || ";|&()`$".contains(c)))
+        .map(|word| word.rsplit('/').next().unwrap_or(word))
+        .find(|program| HEAVY_COMMANDS.contains(program))
+        .map(str::to_string)
+}
+
+fn container_issue(
+    severity: IssueSeverity,
+    code: &str,
+    resource: &str,
+    container: &str,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue {
+        key_attributes: container_key(container),
+        ..Issue::new(
+            severity,
+            "Container",
+            code,
+            description,
+            resource.to_string(),
+            recommendation,
+        )
+    }
+}
+
+fn container_findings(pod_ref: &str, c: &Container, out: &mut Vec<Issue>) {
+    let name = &c.name;
+    if let Some(liveness) = &c.liveness_probe {
+        if c.readiness_probe
+            .as_ref()
+            .is_some_and(|r| same_handler(liveness, r))
+        {
+            out.push(container_issue(
+                IssueSeverity::Warning,
+                "POD-013",
+                pod_ref,
+                name,
+                format!(
+                    "Container {} in pod {} uses the same check for liveness and readiness",
+                    name, pod_ref
+                ),
+                "Make the liveness probe check only that the process is alive; leave dependency and load checks to readiness.",
+            ));
+        }
+        if let Some(why) = external_dependency(liveness) {
+            out.push(container_issue(
+                IssueSeverity::Warning,
+                "POD-014",
+                pod_ref,
+                name,
+                format!(
+                    "Liveness probe of container {} in pod {} {}, which may depend on other services",
+                    name, pod_ref, why
+                ),
+                "Point the liveness probe at a local endpoint (e.g. /livez, /actuator/health/liveness) that does not call dependencies.",
+            ));
+        }
+        if aggressive(liveness, c.startup_probe.is_some()) {
+            out.push(container_issue(
+                IssueSeverity::Warning,
+                "POD-015",
+                pod_ref,
+                name,
+                format!(
+                    "Liveness probe of container {} in pod {} has no initial delay and failureThreshold 1",
+                    name, pod_ref
+                ),
+                "Add a startupProbe or an initialDelaySeconds, and raise failureThreshold to 3 or more.",
+            ));
+        }
+    }
+    let probes = [
+        ("Liveness", &c.liveness_probe),
+        ("Readiness", &c.readiness_probe),
+        ("Startup", &c.startup_probe),
+    ];
+    for (kind, probe) in probes {
+        if let Some(program) = probe.as_ref().and_then(heavy_command) {
+            out.push(container_issue(
+                IssueSeverity::Info,
+                "POD-016",
+                pod_ref,
+                name,
+                format!(
+                    "{} probe of container {} in pod {} runs {} on every period",
+                    kind, name, pod_ref, program
+                ),
+                "Use an httpGet, tcpSocket or grpc probe, or a lightweight command, instead of starting a runtime or client per probe.",
+            ));
+            break;
+        }
+    }
+}
+
+/// Probe findings (POD-013..POD-016) for the containers of `pod`; None when no container has a
+/// probe.
+pub fn pod_probe_issues(pod: &Pod) -> Option<Vec<Issue>> {
+    let spec = pod.spec.as_ref()?;
+    let has_probe = spec.containers.iter().any(|c| {
+        c.liveness_probe.is_some() || c.readiness_probe.is_some() || c.startup_probe.is_some()
+    });
+    if !has_probe {
+        return None;
+    }
+    let pod_ref = format!(
+        "{}/{}",
+        pod.metadata.namespace.as_deref().unwrap_or("default"),
+        pod.metadata.name.as_deref().unwrap_or("unknown")
+    );
+    let mut issues = Vec::new();
+    for c in &spec.containers {
+        container_findings(&pod_ref, c, &mut issues);
+    }
+    Some(issues)
+}
+
+/// "Probe Configuration" check over `evaluated` pods with probes, `flagged` of them with a
+/// Warning finding.
+pub fn probe_check(evaluated: usize, flagged: usize) -> CheckResult {
+    CheckResult {
+        name: "Probe Configuration".to_string(),
+        description: "Checks liveness, readiness and startup probes for restart-prone patterns"
+            .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} pods with probes free of liveness anti-patterns",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![
+                "See POD-013..POD-016; keep liveness probes local, cheap and tolerant.".to_string(),
+            ]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{ExecAction, HTTPGetAction, PodSpec};
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+    use kube::api::ObjectMeta;
+
+    fn http(path: &str) -> Probe {
+        Probe {
+            http_get: Some(HTTPGetAction {
+                path: Some(path.to_string()),
+                port: IntOrString::Int(8080),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(containers: Vec<Container>) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                namespace: Some("shop".to_string()),
+                name: Some("api-1".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_probe_anti_patterns() {
+        let shared = Container {
+            name: "api".to_string(),
+            liveness_probe: Some(Probe {
+                failure_threshold: Some(1),
+                ..http("/actuator/health")
+            }),
+            readiness_probe: Some(Probe {
+                period_seconds: Some(5),
+                ..http("/actuator/health")
+            }),
+            ..Default::default()
+        };
+        let heavy = Container {
+            name: "worker".to_string(),
+            readiness_probe: Some(Probe {
+                exec: Some(ExecAction {
+                    command: Some(vec![
+                        "/bin/sh".to_string(),
+                        "-c".to_string(),
+                        "/opt/jdk/bin/jcmd 1 VM.uptime".to_string(),
+                    ]),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let issues = pod_probe_issues(&pod(vec![shared, heavy])).unwrap();
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["POD-013", "POD-014", "POD-015", "POD-016"]);
+        assert!(issues[3].description.contains("runs jcmd"));
+
+        let fine = Container {
+            name: "api".to_string(),
+            liveness_probe: Some(http("/livez")),
+            readiness_probe: Some(http("/readyz")),
+            ..Default::default()
+        };
+        assert_eq!(pod_probe_issues(&pod(vec![fine])).map(|i| i.len()), Some(0));
+        assert!(pod_probe_issues(&pod(vec![Container::default()])).is_none());
+    }
+}
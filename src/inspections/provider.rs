@@ -0,0 +1,375 @@
+//! Managed control-plane providers (EKS, GKE, AKS), detected from node labels and the API server
+//! version string. On managed clusters the Control Plane inspection skips the static-pod checks
+//! (the provider runs the components and etcd) and runs one provider-specific check instead:
+//! kube-proxy add-on version on EKS (CTRL-017), node version lag on GKE (CTRL-018) and node image
+//! age on AKS (CTRL-019).
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::Node;
+
+use crate::inspections::control_plane::static_pod_check;
+use crate::inspections::types::*;
+
+/// AKS node images older than this are flagged (CTRL-019); AKS publishes new images weekly.
+const AKS_IMAGE_MAX_AGE_DAYS: i64 = 90;
+const EKS_NODEGROUP_LABEL: &str = "eks.amazonaws.com/nodegroup";
+const GKE_NODEPOOL_LABEL: &str = "cloud.google.com/gke-nodepool";
+const AKS_AGENTPOOL_LABEL: &str = "kubernetes.azure.com/agentpool";
+const AKS_CLUSTER_LABEL: &str = "kubernetes.azure.com/cluster";
+const AKS_NODE_IMAGE_LABEL: &str = "kubernetes.azure.com/node-image-version";
+
+fn has_label(node: &Node, key: &str) -> bool {
+    node.metadata
+        .labels
+        .as_ref()
+        .is_some_and(|l| l.contains_key(key))
+}
+
+fn node_label<'n>(node: &'n Node, key: &str) -> Option<&'n str> {
+    node.metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get(key))
+        .map(String::as_str)
+}
+
+/// Provider from the server version (`v1.29.4-eks-1a2b3c`, `v1.29.4-gke.1043002`) or, failing
+/// that, from provider node labels.
+pub fn detect(nodes: &[Node], server_version: Option<&str>) -> Option<ManagedProvider> {
+    if let Some(version) = server_version {
+        if version.contains("-eks-") {
+            return Some(ManagedProvider::Eks);
+        }
+        if version.contains("-gke.") {
+            return Some(ManagedProvider::Gke);
+        }
+    }
+    nodes.iter().find_map(|node| {
+        if has_label(node, EKS_NODEGROUP_LABEL) {
+            Some(ManagedProvider::Eks)
+        } else if has_label(node, GKE_NODEPOOL_LABEL) {
+            Some(ManagedProvider::Gke)
+        } else if has_label(node, AKS_CLUSTER_LABEL) || has_label(node, AKS_AGENTPOOL_LABEL) {
+            Some(ManagedProvider::Aks)
+        } else {
+            None
+        }
+    })
+}
+
+/// `v1.29.4-eks-1` → (1, 29).
+fn minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor: String = parts
+        .next()?
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    Some((major, minor.parse().ok()?))
+}
+
+/// The provider-specific check for `provider`.
+pub fn provider_check(
+    provider: ManagedProvider,
+    server_version: Option<&str>,
+    nodes: &[Node],
+    daemon_sets: &[DaemonSet],
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    match provider {
+        ManagedProvider::Eks => eks_addon_check(server_version, daemon_sets, issues),
+        ManagedProvider::Gke => gke_node_version_check(server_version, nodes, issues),
+        ManagedProvider::Aks => aks_node_image_check(nodes, now, issues),
+    }
+}
+
+/// CTRL-017: the kube-proxy add-on must match the control-plane minor on EKS; it is not upgraded
+/// with the cluster.
+fn eks_addon_check(
+    server_version: Option<&str>,
+    daemon_sets: &[DaemonSet],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut findings = Vec::new();
+    let image = daemon_sets
+        .iter()
+        .find(|ds| ds.metadata.name.as_deref() == Some("kube-proxy"))
+        .and_then(|ds| ds.spec.as_ref()?.template.spec.as_ref())
+        .and_then(|spec| spec.containers.first()?.image.clone());
+    let tag = image
+        .as_deref()
+        .and_then(|i| i.rsplit_once(':'))
+        .map(|(_, tag)| tag);
+    let details = match (server_version.and_then(minor), tag) {
+        (Some(cluster), Some(tag)) => {
+            if let Some(addon) = minor(tag).filter(|m| *m != cluster) {
+                findings.push(Issue::new(
+                    IssueSeverity::Warning,
+                    "ControlPlane",
+                    "CTRL-017",
+                    format!(
+                        "kube-proxy add-on {} is v{}.{} but the control plane is v{}.{}",
+                        tag, addon.0, addon.1, cluster.0, cluster.1
+                    ),
+                    "kube-system/kube-proxy".to_string(),
+                    "Update the kube-proxy add-on to the version matching the cluster minor.",
+                ));
+            }
+            format!(
+                "Amazon EKS v{}.{}; kube-proxy add-on {}",
+                cluster.0, cluster.1, tag
+            )
+        }
+        (_, None) => "Amazon EKS; kube-proxy DaemonSet not found".to_string(),
+        (None, Some(tag)) => format!("Amazon EKS; kube-proxy add-on {}", tag),
+    };
+    let check = static_pod_check(
+        "EKS Add-on Versions",
+        "Checks that the kube-proxy add-on matches the EKS control-plane minor version",
+        details,
+        &findings,
+        "Update EKS add-ons after each control-plane upgrade (see CTRL-017).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// CTRL-018: GKE node pools whose kubelet minor is behind the control plane (auto-upgrade off
+/// or held back by a maintenance exclusion). The release channel is not visible in the API.
+fn gke_node_version_check(
+    server_version: Option<&str>,
+    nodes: &[Node],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut findings = Vec::new();
+    let Some(cluster) = server_version.and_then(minor) else {
+        return static_pod_check(
+            "GKE Node Versions",
+            "Checks that GKE node pools run the control-plane minor version",
+            "Google GKE; server version unknown, node versions not compared".to_string(),
+            &findings,
+            "",
+        );
+    };
+    // pool → lowest kubelet minor
+    let mut pools: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+    for node in nodes {
+        let Some(kubelet) = node
+            .status
+            .as_ref()
+            .and_then(|s| s.node_info.as_ref())
+            .and_then(|i| minor(&i.kubelet_version))
+        else {
+            continue;
+        };
+        let pool = node_label(node, GKE_NODEPOOL_LABEL).unwrap_or("(unassigned)");
+        let lowest = pools.entry(pool).or_insert(kubelet);
+        *lowest = (*lowest).min(kubelet);
+    }
+    for (pool, kubelet) in &pools {
+        if *kubelet < cluster {
+            let behind = cluster.1.saturating_sub(kubelet.1);
+            findings.push(Issue::new(
+                if behind >= 2 {
+                    IssueSeverity::Critical
+                } else {
+                    IssueSeverity::Warning
+                },
+                "ControlPlane",
+                "CTRL-018",
+                format!(
+                    "GKE node pool {} runs v{}.{}, {} minor version(s) behind the control plane v{}.{}",
+                    pool, kubelet.0, kubelet.1, behind, cluster.0, cluster.1
+                ),
+                format!("nodepool/{}", pool),
+                "Upgrade the node pool or enable node auto-upgrade on a release channel.",
+            ));
+        }
+    }
+    let check = static_pod_check(
+        "GKE Node Versions",
+        "Checks that GKE node pools run the control-plane minor version",
+        format!(
+            "Google GKE v{}.{}; {} node pool(s), {} behind",
+            cluster.0,
+            cluster.1,
+            pools.len(),
+            findings.len()
+        ),
+        &findings,
+        "Upgrade lagging node pools (see CTRL-018).",
+    );
+    issues.extend(findings);
+    check
+}
+
+/// Build date of an AKS node image version (`AKSUbuntu-2204gen2containerd-202402.07.0` →
+/// 2024-02-07).
+pub(crate) fn aks_image_date(version: &str) -> Option<NaiveDate> {
+    let stamp = version.rsplit('-').next()?;
+    let mut parts = stamp.split('.');
+    let year_month = parts.next()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if year_month.len() != 6 {
+        return None;
+    }
+    NaiveDate::from_ymd_opt(
+        year_month[..4].parse().ok()?,
+        year_month[4..].parse().ok()?,
+        day,
+    )
+}
+
+/// CTRL-019: AKS node pools on a node image older than [`AKS_IMAGE_MAX_AGE_DAYS`].
+fn aks_node_image_check(
+    nodes: &[Node],
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut findings = Vec::new();
+    // pool → oldest (image date, image version)
+    let mut pools: BTreeMap<&str, (NaiveDate, &str)> = BTreeMap::new();
+    for node in nodes {
+        let Some(version) = node_label(node, AKS_NODE_IMAGE_LABEL) else {
+            continue;
+        };
+        let Some(date) = aks_image_date(version) else {
+            continue;
+        };
+        let pool = node_label(node, AKS_AGENTPOOL_LABEL).unwrap_or("(unassigned)");
+        let oldest = pools.entry(pool).or_insert((date, version));
+        if date < oldest.0 {
+            *oldest = (date, version);
+        }
+    }
+    for (pool, (date, version)) in &pools {
+        let age = (now.date_naive() - *date).num_days();
+        if age > AKS_IMAGE_MAX_AGE_DAYS {
+            findings.push(Issue::new(
+                IssueSeverity::Warning,
+                "ControlPlane",
+                "CTRL-019",
+                format!(
+                    "AKS node pool {} runs node image {} built {} days ago",
+                    pool, version, age
+                ),
+                format!("agentpool/{}", pool),
+                "Upgrade the node image (`az aks nodepool upgrade --node-image-only`) or enable the NodeImage auto-upgrade channel.",
+            ));
+        }
+    }
+    let check = static_pod_check(
+        "AKS Node Image Age",
+        "Checks that AKS node pools run a node image from the last 90 days",
+        if pools.is_empty() {
+            "Azure AKS; no node image version labels".to_string()
+        } else {
+            format!(
+                "Azure AKS; {} node pool(s), {} on an image older than {} days",
+                pools.len(),
+                findings.len(),
+                AKS_IMAGE_MAX_AGE_DAYS
+            )
+        },
+        &findings,
+        "Upgrade outdated node images (see CTRL-019).",
+    );
+    issues.extend(findings);
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{NodeStatus, NodeSystemInfo};
+    use kube::api::ObjectMeta;
+
+    fn node(labels: &[(&str, &str)], kubelet: &str) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some("n".to_string()),
+                labels: Some(
+                    labels
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            status: Some(NodeStatus {
+                node_info: Some(NodeSystemInfo {
+                    kubelet_version: kubelet.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detects_provider_from_version_and_labels() {
+        assert_eq!(
+            detect(&[], Some("v1.29.4-eks-1a2b3c")),
+            Some(ManagedProvider::Eks)
+        );
+        assert_eq!(
+            detect(&[], Some("v1.29.4-gke.1043002")),
+            Some(ManagedProvider::Gke)
+        );
+        let aks = node(&[(AKS_AGENTPOOL_LABEL, "system")], "v1.29.2");
+        assert_eq!(detect(&[aks], Some("v1.29.2")), Some(ManagedProvider::Aks));
+        assert_eq!(detect(&[node(&[], "v1.29.2")], Some("v1.29.2")), None);
+    }
+
+    #[test]
+    fn gke_lagging_pools_and_old_aks_images_are_flagged() {
+        let nodes = vec![
+            node(&[(GKE_NODEPOOL_LABEL, "default")], "v1.29.4-gke.1"),
+            node(&[(GKE_NODEPOOL_LABEL, "batch")], "v1.27.8-gke.1"),
+        ];
+        let mut issues = Vec::new();
+        let check = gke_node_version_check(Some("v1.29.4-gke.1"), &nodes, &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("nodepool/batch"));
+
+        assert_eq!(
+            aks_image_date("AKSUbuntu-2204gen2containerd-202402.07.0"),
+            NaiveDate::from_ymd_opt(2024, 2, 7)
+        );
+        let now = "2024-06-01T00:00:00Z".parse().unwrap();
+        let nodes = vec![
+            node(
+                &[
+                    (AKS_AGENTPOOL_LABEL, "system"),
+                    (
+                        AKS_NODE_IMAGE_LABEL,
+                        "AKSUbuntu-2204gen2containerd-202402.07.0",
+                    ),
+                ],
+                "v1.29.2",
+            ),
+            node(
+                &[
+                    (AKS_AGENTPOOL_LABEL, "user"),
+                    (
+                        AKS_NODE_IMAGE_LABEL,
+                        "AKSUbuntu-2204gen2containerd-202405.20.0",
+                    ),
+                ],
+                "v1.29.2",
+            ),
+        ];
+        let mut issues = Vec::new();
+        let check = aks_node_image_check(&nodes, now, &mut issues);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("CTRL-019"));
+    }
+}
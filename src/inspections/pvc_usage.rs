@@ -0,0 +1,191 @@
+//! PersistentVolumeClaim fill level from the kubelet summary API (`/stats/summary`). The kubelet
+//! reports usage for every mounted volume backed by a PVC; a claim mounted by several pods (RWX)
+//! appears once per pod and is counted once.
+
+use std::collections::BTreeMap;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::k8s::StatsSummary;
+
+/// PVC usage at or above this is a Warning (STO-011).
+pub const PVC_USAGE_WARNING_PCT: f64 = 80.0;
+/// PVC usage at or above this is Critical (STO-012).
+pub const PVC_USAGE_CRITICAL_PCT: f64 = 90.0;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Measured usage of one mounted PVC.
+#[derive(Debug, Clone)]
+pub struct PvcUsage {
+    pub namespace: String,
+    pub name: String,
+    pub used_bytes: u64,
+    pub capacity_bytes: u64,
+}
+
+impl PvcUsage {
+    pub fn used_pct(&self) -> f64 {
+        self.used_bytes as f64 / self.capacity_bytes as f64 * 100.0
+    }
+}
+
+/// Collect PVC volumes from node summaries, restricted to `namespace` when set.
+/// Volumes without capacity are skipped; duplicates (same claim on several pods) keep the highest usage.
+pub fn collect_pvc_usage(summaries: &[StatsSummary], namespace: Option<&str>) -> Vec<PvcUsage> {
+    let mut by_claim: BTreeMap<(String, String), PvcUsage> = BTreeMap::new();
+    for volume in summaries
+        .iter()
+        .flat_map(|s| s.pods.iter())
+        .flat_map(|p| p.volume.iter().flatten())
+    {
+        let Some(pvc) = volume.pvc_ref.as_ref() else {
+            continue;
+        };
+        if namespace.is_some_and(|ns| ns != pvc.namespace) {
+            continue;
+        }
+        let (Some(used_bytes), Some(capacity_bytes)) = (volume.used_bytes, volume.capacity_bytes)
+        else {
+            continue;
+        };
+        if capacity_bytes == 0 {
+            continue;
+        }
+        let usage = PvcUsage {
+            namespace: pvc.namespace.clone(),
+            name: pvc.name.clone(),
+            used_bytes,
+            capacity_bytes,
+        };
+        by_claim
+            .entry((usage.namespace.clone(), usage.name.clone()))
+            .and_modify(|u| {
+                if usage.used_bytes > u.used_bytes {
+                    *u = usage.clone();
+                }
+            })
+            .or_insert(usage);
+    }
+    by_claim.into_values().collect()
+}
+
+/// Build the "PVC Usage" check and STO-011/STO-012 issues. Returns None when no PVC usage was reported.
+pub fn evaluate_pvc_usage(usages: &[PvcUsage]) -> Option<(CheckResult, Vec<Issue>)> {
+    if usages.is_empty() {
+        return None;
+    }
+    let mut issues = Vec::new();
+    for u in usages {
+        let pct = u.used_pct();
+        let (severity, rule_id, recommendation) = if pct >= PVC_USAGE_CRITICAL_PCT {
+            (
+                IssueSeverity::Critical,
+                "STO-012",
+                "Expand the PVC now (allowVolumeExpansion on the StorageClass) or free space; writes will fail when the volume is full.",
+            )
+        } else if pct >= PVC_USAGE_WARNING_PCT {
+            (
+                IssueSeverity::Warning,
+                "STO-011",
+                "Plan a PVC expansion or data cleanup/retention before the volume fills up.",
+            )
+        } else {
+            continue;
+        };
+        issues.push(Issue {
+            severity,
+            category: "PersistentVolumeClaim".to_string(),
+            description: format!(
+                "PVC {}/{} is {:.1}% full ({:.1} / {:.1} GiB)",
+                u.namespace,
+                u.name,
+                pct,
+                u.used_bytes as f64 / GIB,
+                u.capacity_bytes as f64 / GIB
+            ),
+            resource: Some(format!("{}/{}", u.namespace, u.name)),
+            recommendation: recommendation.to_string(),
+            rule_id: Some(rule_id.to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+
+    let total = usages.len();
+    let score = ((total - issues.len()) as f64 / total as f64) * 100.0;
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if !issues.is_empty() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let check = CheckResult {
+        name: "PVC Usage".to_string(),
+        description: "Checks mounted PVC fill level reported by the kubelet".to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} mounted PVCs below {}% used",
+            total - issues.len(),
+            total,
+            PVC_USAGE_WARNING_PCT
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["Expand or clean up nearly full PVCs".to_string()]
+        },
+    };
+    Some((check, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(json: serde_json::Value) -> StatsSummary {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn collects_pvc_volumes_once_and_respects_namespace() {
+        let vol = |used: u64| {
+            serde_json::json!({
+                "name": "data",
+                "pvcRef": {"name": "db-data", "namespace": "prod"},
+                "capacityBytes": 100u64,
+                "usedBytes": used
+            })
+        };
+        let s = summary(serde_json::json!({
+            "node": {"fs": {"capacityBytes": 1000u64, "usedBytes": 100u64}},
+            "pods": [
+                {"podRef": {"name": "a", "namespace": "prod"}, "volume": [vol(50), {"name": "tmp", "usedBytes": 1u64, "capacityBytes": 10u64}]},
+                {"podRef": {"name": "b", "namespace": "prod"}, "volume": [vol(85)]}
+            ]
+        }));
+        let usages = collect_pvc_usage(std::slice::from_ref(&s), None);
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].used_bytes, 85);
+        assert!(collect_pvc_usage(&[s], Some("dev")).is_empty());
+    }
+
+    #[test]
+    fn thresholds_map_to_rule_ids() {
+        let usage = |name: &str, used: u64| PvcUsage {
+            namespace: "ns".to_string(),
+            name: name.to_string(),
+            used_bytes: used,
+            capacity_bytes: 100,
+        };
+        let (check, issues) =
+            evaluate_pvc_usage(&[usage("a", 10), usage("b", 82), usage("c", 95)]).unwrap();
+        assert_eq!(check.status, CheckStatus::Critical);
+        let ids: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(ids, vec!["STO-011", "STO-012"]);
+    }
+}
@@ -0,0 +1,229 @@
+//! Pod QoS classes (Guaranteed, Burstable, BestEffort). Under node pressure the kubelet evicts
+//! BestEffort pods first and the OOM killer prefers them, so BestEffort system components die
+//! before the workloads they serve, and a cluster made mostly of BestEffort pods has no
+//! predictable eviction order at all.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::{Container, Pod, PodSpec};
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity, QosRow};
+
+/// Namespaces of cluster components that should never be BestEffort (RES-006).
+pub const SYSTEM_NAMESPACES: [&str; 2] = ["kube-system", "kube-node-lease"];
+
+/// Share of BestEffort pods (percent) above which the cluster is reported (RES-007).
+pub const BEST_EFFORT_CLUSTER_PCT: f64 = 50.0;
+
+const GUARANTEED: &str = "Guaranteed";
+const BEST_EFFORT: &str = "BestEffort";
+
+/// QoS class the API server would assign to `spec`, for pods without `status.qosClass`.
+fn derived_qos(spec: &PodSpec) -> &'static str {
+    let containers = || {
+        spec.containers
+            .iter()
+            .chain(spec.init_containers.iter().flatten())
+    };
+    let resources = |c: &Container| {
+        let r = c.resources.as_ref();
+        (
+            r.and_then(|r| r.requests.clone()).unwrap_or_default(),
+            r.and_then(|r| r.limits.clone()).unwrap_or_default(),
+        )
+    };
+    if containers().all(|c| {
+        let (requests, limits) = resources(c);
+        requests.is_empty() && limits.is_empty()
+    }) {
+        return BEST_EFFORT;
+    }
+    let guaranteed = containers().all(|c| {
+        let (requests, limits) = resources(c);
+        ["cpu", "memory"].iter().all(|r| match limits.get(*r) {
+            Some(limit) => requests.get(*r).is_none_or(|req| req == limit),
+            None => false,
+        })
+    });
+    if guaranteed {
+        GUARANTEED
+    } else {
+        "Burstable"
+    }
+}
+
+/// QoS class of `pod`: `status.qosClass`, else derived from the container resources.
+pub fn qos_class(pod: &Pod) -> &str {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.qos_class.as_deref())
+        .or_else(|| pod.spec.as_ref().map(derived_qos))
+        .unwrap_or(BEST_EFFORT)
+}
+
+/// Pods per QoS class and namespace, skipping Succeeded and Failed pods; None without pods.
+pub fn qos_distribution(pods: &[Pod]) -> Option<Vec<QosRow>> {
+    let mut rows: BTreeMap<&str, QosRow> = BTreeMap::new();
+    for pod in pods {
+        if matches!(
+            pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+            Some("Succeeded" | "Failed")
+        ) {
+            continue;
+        }
+        let namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+        let row = rows.entry(namespace).or_insert_with(|| QosRow {
+            namespace: namespace.to_string(),
+            ..Default::default()
+        });
+        match qos_class(pod) {
+            GUARANTEED => row.guaranteed += 1,
+            BEST_EFFORT => row.best_effort += 1,
+            _ => row.burstable += 1,
+        }
+    }
+    (!rows.is_empty()).then(|| rows.into_values().collect())
+}
+
+/// "QoS Classes" check: RES-006 per system namespace with BestEffort pods, RES-007 when more than
+/// [`BEST_EFFORT_CLUSTER_PCT`] of all pods are BestEffort.
+pub fn evaluate_qos(rows: &[QosRow]) -> (CheckResult, Vec<Issue>) {
+    let total: u32 = rows
+        .iter()
+        .map(|r| r.guaranteed + r.burstable + r.best_effort)
+        .sum();
+    let best_effort: u32 = rows.iter().map(|r| r.best_effort).sum();
+    let guaranteed: u32 = rows.iter().map(|r| r.guaranteed).sum();
+    let mut issues = Vec::new();
+    for row in rows
+        .iter()
+        .filter(|r| r.best_effort > 0 && SYSTEM_NAMESPACES.contains(&r.namespace.as_str()))
+    {
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Resource",
+            "RES-006",
+            format!(
+                "System namespace {} runs {} BestEffort pod(s), evicted first under node pressure",
+                row.namespace, row.best_effort
+            ),
+            Some(row.namespace.clone()),
+            "Set CPU and memory requests on cluster components so they outlive application pods under pressure.",
+        ));
+    }
+    let best_effort_pct = if total == 0 {
+        0.0
+    } else {
+        best_effort as f64 / total as f64 * 100.0
+    };
+    if best_effort_pct > BEST_EFFORT_CLUSTER_PCT {
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Resource",
+            "RES-007",
+            format!(
+                "{} of {} pods ({:.0}%) are BestEffort; eviction under node pressure is unpredictable",
+                best_effort, total, best_effort_pct
+            ),
+            None,
+            "Set requests on workloads (LimitRange defaults help) so the kubelet can rank pods for eviction.",
+        ));
+    }
+    let check = CheckResult {
+        name: "QoS Classes".to_string(),
+        description: "Pod QoS classes, which decide the eviction order under node pressure"
+            .to_string(),
+        status: if issues.is_empty() {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: 100.0 - best_effort_pct,
+        max_score: 100.0,
+        details: Some(format!(
+            "{} pods: {} Guaranteed, {} Burstable, {} BestEffort",
+            total,
+            guaranteed,
+            total - guaranteed - best_effort,
+            best_effort
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["See RES-006/RES-007; set resource requests on BestEffort pods.".to_string()]
+        },
+    };
+    (check, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{PodStatus, ResourceRequirements};
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use kube::api::ObjectMeta;
+
+    fn pod(namespace: &str, requests: &[(&str, &str)], limits: &[(&str, &str)]) -> Pod {
+        let map = |v: &[(&str, &str)]| {
+            (!v.is_empty()).then(|| {
+                v.iter()
+                    .map(|(k, q)| (k.to_string(), Quantity(q.to_string())))
+                    .collect()
+            })
+        };
+        Pod {
+            metadata: ObjectMeta {
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    resources: Some(ResourceRequirements {
+                        requests: map(requests),
+                        limits: map(limits),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Running".to_string()),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn counts_qos_classes_and_flags_best_effort_pods() {
+        let full = [("cpu", "1"), ("memory", "1Gi")];
+        let pods = vec![
+            pod("kube-system", &[], &[]),
+            pod("kube-system", &full, &full),
+            pod("shop", &[], &[]),
+            pod("shop", &[], &[]),
+            pod("shop", &[("cpu", "100m")], &[]),
+        ];
+        let rows = qos_distribution(&pods).unwrap();
+        assert_eq!(
+            rows[0],
+            QosRow {
+                namespace: "kube-system".to_string(),
+                guaranteed: 1,
+                burstable: 0,
+                best_effort: 1,
+            }
+        );
+        assert_eq!((rows[1].burstable, rows[1].best_effort), (1, 2));
+
+        let (check, issues) = evaluate_qos(&rows);
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["RES-006", "RES-007"]);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("5 pods: 1 Guaranteed, 1 Burstable, 3 BestEffort")
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Anonymous OCI/Docker registry client for `--registry-lookup`: reads an image's manifest (list)
+//! to find the CPU architectures it is published for. Only public images can be resolved; the
+//! bearer token challenge (Docker Hub, GHCR, quay.io, ...) is followed without credentials.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use serde_json::Value;
+
+const DOCKER_HUB: &str = "registry-1.docker.io";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Images looked up concurrently.
+const CONCURRENT_LOOKUPS: usize = 8;
+
+const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, application/vnd.docker.distribution.manifest.list.v2+json, application/vnd.oci.image.manifest.v1+json, application/vnd.docker.distribution.manifest.v2+json";
+
+/// Image name (last path segment) and tag, ignoring registry and digest.
+pub fn image_name_tag(image: &str) -> (&str, &str) {
+    let image = image.split('@').next().unwrap_or(image);
+    let last = image.rsplit('/').next().unwrap_or(image);
+    match last.split_once(':') {
+        Some((name, tag)) => (name, tag),
+        None => (last, "latest"),
+    }
+}
+
+/// Registry host, repository and tag or digest of an image reference.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImageRef {
+    pub registry: String,
+    pub repository: String,
+    pub reference: String,
+}
+
+impl ImageRef {
+    /// `nginx` → registry-1.docker.io, library/nginx, latest; `ghcr.io/org/app@sha256:..` keeps
+    /// the digest.
+    pub fn parse(image: &str) -> Self {
+        let (name, reference) = match image.split_once('@') {
+            Some((name, digest)) => (name, digest.to_string()),
+            None => match image.rsplit_once(':') {
+                Some((name, tag)) if !tag.contains('/') => (name, tag.to_string()),
+                _ => (image, "latest".to_string()),
+            },
+        };
+        let (registry, repository) = match name.split_once('/') {
+            Some((host, rest))
+                if host.contains('.') || host.contains(':') || host == "localhost" =>
+            {
+                (host.to_string(), rest.to_string())
+            }
+            _ => (DOCKER_HUB.to_string(), name.to_string()),
+        };
+        let registry = if registry == "docker.io" {
+            DOCKER_HUB.to_string()
+        } else {
+            registry
+        };
+        let repository = if registry == DOCKER_HUB && !repository.contains('/') {
+            format!("library/{}", repository)
+        } else {
+            repository
+        };
+        Self {
+            registry,
+            repository,
+            reference,
+        }
+    }
+}
+
+/// `key="value"` parameters of a `WWW-Authenticate: Bearer ...` challenge.
+fn bearer_params(header: &str) -> BTreeMap<String, String> {
+    let params = header
+        .trim()
+        .strip_prefix("Bearer ")
+        .or_else(|| header.trim().strip_prefix("bearer "))
+        .unwrap_or_default();
+    let mut out = BTreeMap::new();
+    let mut rest = params;
+    while let Some((key, after)) = rest.split_once("=\"") {
+        let Some((value, tail)) = after.split_once('"') else {
+            break;
+        };
+        out.insert(
+            key.trim().trim_start_matches(',').trim().to_string(),
+            value.to_string(),
+        );
+        rest = tail;
+    }
+    out
+}
+
+/// Architectures listed in a manifest list / image index, skipping attestation entries.
+fn index_architectures(manifest: &Value) -> Option<BTreeSet<String>> {
+    let archs = manifest
+        .get("manifests")?
+        .as_array()?
+        .iter()
+        .filter_map(|m| m.pointer("/platform/architecture")?.as_str())
+        .filter(|a| *a != "unknown")
+        .map(str::to_string)
+        .collect();
+    Some(archs)
+}
+
+pub struct RegistryClient {
+    http: reqwest::Client,
+}
+
+impl RegistryClient {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent("kubeowler")
+                .build()?,
+        })
+    }
+
+    /// GET `url`, answering one bearer challenge with an anonymous token.
+    async fn get_json(&self, url: &str, accept: &str, token: &mut Option<String>) -> Result<Value> {
+        for _ in 0..2 {
+            let mut req = self.http.get(url).header("Accept", accept);
+            if let Some(t) = token.as_deref() {
+                req = req.bearer_auth(t);
+            }
+            let resp = req.send().await.with_context(|| format!("GET {}", url))?;
+            if resp.status() == reqwest::StatusCode::UNAUTHORIZED && token.is_none() {
+                let challenge = resp
+                    .headers()
+                    .get(reqwest::header::WWW_AUTHENTICATE)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default();
+                *token = Some(self.anonymous_token(challenge).await?);
+                continue;
+            }
+            let status = resp.status();
+            if !status.is_success() {
+                anyhow::bail!("GET {}: HTTP {}", url, status);
+            }
+            return resp.json().await.with_context(|| format!("GET {}", url));
+        }
+        anyhow::bail!("GET {}: authorization required", url)
+    }
+
+    async fn anonymous_token(&self, challenge: &str) -> Result<String> {
+        let params = bearer_params(challenge);
+        let realm = params
+            .get("realm")
+            .context("registry requires authentication")?;
+        let query: Vec<(&str, &str)> = ["service", "scope"]
+            .into_iter()
+            .filter_map(|k| Some((k, params.get(k)?.as_str())))
+            .collect();
+        let body: Value = self
+            .http
+            .get(realm)
+            .query(&query)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        body.get("token")
+            .or_else(|| body.get("access_token"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .context("token response without a token")
+    }
+
+    /// CPU architectures `image` is published for.
+    pub async fn architectures(&self, image: &str) -> Result<BTreeSet<String>> {
+        let r = ImageRef::parse(image);
+        let base = format!("https://{}/v2/{}", r.registry, r.repository);
+        let mut token = None;
+        let manifest = self
+            .get_json(
+                &format!("{}/manifests/{}", base, r.reference),
+                MANIFEST_ACCEPT,
+                &mut token,
+            )
+            .await?;
+        if let Some(archs) = index_architectures(&manifest) {
+            return Ok(archs);
+        }
+        // Single-platform manifest: the architecture is in the image config blob.
+        let digest = manifest
+            .pointer("/config/digest")
+            .and_then(Value::as_str)
+            .context("manifest without config digest")?;
+        let config = self
+            .get_json(&format!("{}/blobs/{}", base, digest), "*/*", &mut token)
+            .await?;
+        let arch = config
+            .get("architecture")
+            .and_then(Value::as_str)
+            .context("image config without architecture")?;
+        Ok(BTreeSet::from([arch.to_string()]))
+    }
+
+    /// Architectures of each of `images`; images that cannot be resolved (private, rate
+    /// limited, offline) are left out.
+    pub async fn lookup_all(&self, images: BTreeSet<String>) -> HashMap<String, BTreeSet<String>> {
+        futures::stream::iter(images)
+            .map(|image| async move {
+                match self.architectures(&image).await {
+                    Ok(archs) => Some((image, archs)),
+                    Err(e) => {
+                        log::debug!("Registry lookup for {} failed: {:#}", image, e);
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(CONCURRENT_LOOKUPS)
+            .filter_map(|r| async move { r })
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_image_references_and_manifest_lists() {
+        assert_eq!(
+            ImageRef::parse("nginx"),
+            ImageRef {
+                registry: DOCKER_HUB.to_string(),
+                repository: "library/nginx".to_string(),
+                reference: "latest".to_string(),
+            }
+        );
+        let r = ImageRef::parse("localhost:5000/team/app:1.2");
+        assert_eq!(
+            (
+                r.registry.as_str(),
+                r.repository.as_str(),
+                r.reference.as_str()
+            ),
+            ("localhost:5000", "team/app", "1.2")
+        );
+        let r = ImageRef::parse("ghcr.io/org/app@sha256:abc");
+        assert_eq!(r.reference, "sha256:abc");
+
+        let params = bearer_params(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/nginx:pull""#,
+        );
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["scope"], "repository:library/nginx:pull");
+
+        let index = serde_json::json!({"manifests": [
+            {"platform": {"architecture": "amd64", "os": "linux"}},
+            {"platform": {"architecture": "arm64", "os": "linux"}},
+            {"platform": {"architecture": "unknown", "os": "unknown"}}
+        ]});
+        assert_eq!(
+            index_architectures(&index).unwrap(),
+            BTreeSet::from(["amd64".to_string(), "arm64".to_string()])
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! HPA / workload replica alignment. A Deployment or StatefulSet scaled by an HPA must not have
+//! its `spec.replicas` re-asserted by another writer (GitOps sync, `kubectl apply`, Helm), and
+//! must not be driven by more than one autoscaler; both make rollouts flap between replica counts.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::k8s::VerticalPodAutoscaler;
+
+/// Field manager of the HPA controller when it writes the scale subresource.
+const HPA_FIELD_MANAGER: &str = "kube-controller-manager";
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// A workload that can be the scale target of an HPA.
+pub struct ScaledWorkload<'a> {
+    pub kind: &'a str,
+    pub meta: &'a ObjectMeta,
+    pub replicas: Option<i32>,
+}
+
+/// Writers other than the HPA controller that keep setting `spec.replicas`: server-side apply
+/// owners (they re-assert the value on every sync), update owners that wrote after the HPA last
+/// scaled, and client-side `kubectl apply` when the last-applied manifest carries replicas.
+pub fn replica_field_owners(meta: &ObjectMeta, last_scale: Option<DateTime<Utc>>) -> Vec<String> {
+    let mut owners = Vec::new();
+    for mf in meta.managed_fields.iter().flatten() {
+        let manager = mf.manager.as_deref().unwrap_or("unknown");
+        if manager == HPA_FIELD_MANAGER || mf.subresource.as_deref() == Some("scale") {
+            continue;
+        }
+        let owns_replicas = mf
+            .fields_v1
+            .as_ref()
+            .and_then(|f| f.0.get("f:spec"))
+            .and_then(|s| s.get("f:replicas"))
+            .is_some();
+        if !owns_replicas {
+            continue;
+        }
+        let fights = match mf.operation.as_deref() {
+            Some("Apply") => true,
+            _ => matches!((mf.time.as_ref(), last_scale), (Some(t), Some(ls)) if t.0 > ls),
+        };
+        if fights && !owners.iter().any(|o| o == manager) {
+            owners.push(manager.to_string());
+        }
+    }
+    let last_applied_replicas = meta
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(LAST_APPLIED_ANNOTATION))
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok())
+        .and_then(|v| v.get("spec").and_then(|s| s.get("replicas")).cloned())
+        .is_some();
+    if last_applied_replicas {
+        owners.push("kubectl apply (last-applied-configuration)".to_string());
+    }
+    owners
+}
+
+fn hpa_label(hpa: &HorizontalPodAutoscaler) -> String {
+    let name = hpa.metadata.name.as_deref().unwrap_or("unknown");
+    match hpa
+        .metadata
+        .owner_references
+        .as_ref()
+        .and_then(|o| o.first())
+    {
+        Some(owner) => format!("HPA {} (owned by {} {})", name, owner.kind, owner.name),
+        None => format!("HPA {}", name),
+    }
+}
+
+/// True if the HPA scales on CPU or memory (also when no metrics are set: the default is CPU).
+fn scales_on_cpu_or_memory(hpa: &HorizontalPodAutoscaler) -> bool {
+    let Some(metrics) = hpa.spec.as_ref().and_then(|s| s.metrics.as_ref()) else {
+        return true;
+    };
+    metrics.iter().any(|m| {
+        let resource = m
+            .resource
+            .as_ref()
+            .map(|r| r.name.as_str())
+            .or_else(|| m.container_resource.as_ref().map(|r| r.name.as_str()));
+        matches!(resource, Some("cpu") | Some("memory"))
+    })
+}
+
+/// "HPA Replica Alignment" check: AUTO-006 (replicas outside the HPA range), AUTO-007 (replicas
+/// owned by another writer) and AUTO-008 (workload driven by several autoscalers).
+pub fn evaluate_replica_conflicts(
+    hpas: &[HorizontalPodAutoscaler],
+    workloads: &[ScaledWorkload],
+    vpas: &[VerticalPodAutoscaler],
+) -> (CheckResult, Vec<Issue>) {
+    let mut by_target: HashMap<(&str, &str, &str), Vec<&HorizontalPodAutoscaler>> = HashMap::new();
+    for hpa in hpas {
+        let Some(spec) = hpa.spec.as_ref() else {
+            continue;
+        };
+        let ns = hpa.metadata.namespace.as_deref().unwrap_or("");
+        by_target
+            .entry((
+                ns,
+                spec.scale_target_ref.kind.as_str(),
+                spec.scale_target_ref.name.as_str(),
+            ))
+            .or_default()
+            .push(hpa);
+    }
+
+    let mut issues = Vec::new();
+    let mut scaled = 0usize;
+    let mut conflicting = 0usize;
+    for w in workloads {
+        let ns = w.meta.namespace.as_deref().unwrap_or("");
+        let name = w.meta.name.as_deref().unwrap_or("unknown");
+        let Some(targeting) = by_target.get(&(ns, w.kind, name)) else {
+            continue;
+        };
+        scaled += 1;
+        let before = issues.len();
+        let resource = format!("{}/{}", ns, name);
+
+        let mut autoscalers: Vec<String> = targeting.iter().map(|h| hpa_label(h)).collect();
+        if targeting.iter().any(|h| scales_on_cpu_or_memory(h)) {
+            autoscalers.extend(
+                vpas.iter()
+                    .filter(|v| v.is_active())
+                    .filter(|v| v.metadata.namespace.as_deref().unwrap_or("") == ns)
+                    .filter(|v| {
+                        v.spec
+                            .target_ref
+                            .as_ref()
+                            .is_some_and(|t| t.kind == w.kind && t.name == name)
+                    })
+                    .map(|v| format!("VPA {}", v.metadata.name.as_deref().unwrap_or("unknown"))),
+            );
+        }
+        if autoscalers.len() > 1 {
+            issues.push(Issue {
+                severity: IssueSeverity::Critical,
+                category: w.kind.to_string(),
+                description: format!(
+                    "{} {} is driven by {} autoscalers: {}",
+                    w.kind,
+                    resource,
+                    autoscalers.len(),
+                    autoscalers.join(", ")
+                ),
+                resource: Some(resource.clone()),
+                recommendation: "Keep exactly one autoscaler per workload: delete the duplicate HPA (or the one KEDA does not own), or set the VPA to updateMode Off / restrict it to resources the HPA does not scale on.".to_string(),
+                rule_id: Some("AUTO-008".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+
+        let hpa = targeting[0];
+        if let (Some(replicas), Some(spec)) = (w.replicas, hpa.spec.as_ref()) {
+            let min = spec.min_replicas.unwrap_or(1);
+            let max = spec.max_replicas;
+            if replicas < min || replicas > max {
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: w.kind.to_string(),
+                    description: format!(
+                        "{} {} has spec.replicas={} outside {} range {}-{}",
+                        w.kind,
+                        resource,
+                        replicas,
+                        hpa_label(hpa),
+                        min,
+                        max
+                    ),
+                    resource: Some(resource.clone()),
+                    recommendation: "Remove spec.replicas from the manifest of HPA-scaled workloads (or align it with minReplicas) so syncs do not override the HPA.".to_string(),
+                    rule_id: Some("AUTO-006".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+
+        let last_scale = hpa
+            .status
+            .as_ref()
+            .and_then(|s| s.last_scale_time.as_ref())
+            .map(|t| t.0);
+        let owners = replica_field_owners(w.meta, last_scale);
+        if !owners.is_empty() {
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: w.kind.to_string(),
+                description: format!(
+                    "{} {} spec.replicas is also set by {} while {} scales it",
+                    w.kind,
+                    resource,
+                    owners.join(", "),
+                    hpa_label(hpa)
+                ),
+                resource: Some(resource),
+                recommendation: "Drop replicas from the GitOps/Helm manifest (or ignore the field, e.g. Argo CD ignoreDifferences) so each sync does not reset the HPA's replica count.".to_string(),
+                rule_id: Some("AUTO-007".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+        if issues.len() > before {
+            conflicting += 1;
+        }
+    }
+
+    let status = if issues.iter().any(|i| i.severity == IssueSeverity::Critical) {
+        CheckStatus::Critical
+    } else if !issues.is_empty() {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Pass
+    };
+    let score = if scaled == 0 {
+        100.0
+    } else {
+        ((scaled - conflicting) as f64 / scaled as f64) * 100.0
+    };
+    let check = CheckResult {
+        name: "HPA Replica Alignment".to_string(),
+        description: "Checks that HPA-scaled workloads have a single autoscaler and no competing replica writer".to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} HPA-scaled Deployments/StatefulSets without replica conflicts",
+            scaled - conflicting,
+            scaled
+        )),
+        recommendations: if issues.is_empty() {
+            vec![]
+        } else {
+            vec!["Let the HPA own spec.replicas: remove it from manifests and keep one autoscaler per workload".to_string()]
+        },
+    };
+    (check, issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::autoscaling::v2::{
+        CrossVersionObjectReference, HorizontalPodAutoscalerSpec,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{FieldsV1, ManagedFieldsEntry};
+
+    fn hpa(name: &str, target: &str, min: i32, max: i32) -> HorizontalPodAutoscaler {
+        let mut h = HorizontalPodAutoscaler {
+            spec: Some(HorizontalPodAutoscalerSpec {
+                scale_target_ref: CrossVersionObjectReference {
+                    kind: "Deployment".to_string(),
+                    name: target.to_string(),
+                    api_version: Some("apps/v1".to_string()),
+                },
+                min_replicas: Some(min),
+                max_replicas: max,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        h.metadata.name = Some(name.to_string());
+        h.metadata.namespace = Some("app".to_string());
+        h
+    }
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some("app".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn server_side_apply_owner_of_replicas_fights_hpa() {
+        let mut m = meta("web");
+        m.managed_fields = Some(vec![
+            ManagedFieldsEntry {
+                manager: Some("argocd-controller".to_string()),
+                operation: Some("Apply".to_string()),
+                fields_v1: Some(FieldsV1(serde_json::json!({"f:spec": {"f:replicas": {}}}))),
+                ..Default::default()
+            },
+            ManagedFieldsEntry {
+                manager: Some(HPA_FIELD_MANAGER.to_string()),
+                operation: Some("Update".to_string()),
+                subresource: Some("scale".to_string()),
+                fields_v1: Some(FieldsV1(serde_json::json!({"f:spec": {"f:replicas": {}}}))),
+                ..Default::default()
+            },
+        ]);
+        assert_eq!(replica_field_owners(&m, None), vec!["argocd-controller"]);
+        assert!(replica_field_owners(&meta("api"), None).is_empty());
+    }
+
+    #[test]
+    fn flags_out_of_range_replicas_and_duplicate_hpas() {
+        let hpas = vec![
+            hpa("web", "web", 3, 10),
+            hpa("keda-hpa-web", "web", 1, 20),
+            hpa("api", "api", 2, 5),
+        ];
+        let (web, api) = (meta("web"), meta("api"));
+        let workloads = vec![
+            ScaledWorkload {
+                kind: "Deployment",
+                meta: &web,
+                replicas: Some(1),
+            },
+            ScaledWorkload {
+                kind: "Deployment",
+                meta: &api,
+                replicas: Some(3),
+            },
+        ];
+        let (check, issues) = evaluate_replica_conflicts(&hpas, &workloads, &[]);
+        let ids: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(ids, vec!["AUTO-008", "AUTO-006"]);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(check.score, 50.0);
+    }
+}
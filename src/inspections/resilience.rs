@@ -0,0 +1,1218 @@
+//! Zone and topology resilience: how nodes spread over `topology.kubernetes.io/zone`, whether
+//! multi-replica workloads actually run in more than one zone, and whether Deployments ask the
+//! scheduler to spread their replicas at all. System components every workload depends on
+//! (CoreDNS, ingress controllers, metrics-server, static control-plane pods) are also checked
+//! for single instances, which turn one pod or node failure into a cluster-wide outage.
+//! Workloads that depend entirely on spot/preemptible nodes, which the provider may reclaim at
+//! any time, are flagged as well, and so are workloads whose images may not run on every CPU
+//! architecture in a mixed amd64/arm64 cluster. StatefulSets get their own storage, update
+//! strategy, governing Service and zone spread checks (see [`statefulsets`]), and DaemonSets are
+//! checked for nodes they should cover but do not (see [`daemonsets`]).
+
+use anyhow::Result;
+use chrono::Utc;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::ListParams;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::inspections::controllers::{Controller, PodOwners};
+use crate::inspections::daemonsets;
+use crate::inspections::multi_arch;
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::orphans::selector_matches;
+use crate::inspections::priority;
+use crate::inspections::registry::RegistryClient;
+use crate::inspections::shutdown;
+use crate::inspections::statefulsets;
+use crate::inspections::types::*;
+use crate::k8s::K8sClient;
+
+/// Zone label set by cloud providers and expected by the scheduler.
+pub const ZONE_LABEL: &str = "topology.kubernetes.io/zone";
+/// Deprecated zone label still set by older clusters.
+const LEGACY_ZONE_LABEL: &str = "failure-domain.beta.kubernetes.io/zone";
+
+/// Ingress controllers, by `app.kubernetes.io/name`/`app` label or Deployment name.
+const INGRESS_CONTROLLERS: &[&str] = &[
+    "ingress-nginx",
+    "nginx-ingress",
+    "traefik",
+    "haproxy-ingress",
+    "kong",
+    "contour",
+    "emissary-ingress",
+];
+
+/// Static control-plane components expected once per control-plane node.
+const CONTROL_PLANE_COMPONENTS: &[&str] = &[
+    "kube-apiserver",
+    "kube-controller-manager",
+    "kube-scheduler",
+    "etcd",
+];
+
+/// Node labels (and values, compared case-insensitively) that mark spot/preemptible capacity:
+/// EKS managed node groups, Karpenter, GKE, AKS and the generic lifecycle label.
+const SPOT_LABELS: &[(&str, &str)] = &[
+    ("eks.amazonaws.com/capacityType", "spot"),
+    ("karpenter.sh/capacity-type", "spot"),
+    ("cloud.google.com/gke-spot", "true"),
+    ("cloud.google.com/gke-preemptible", "true"),
+    ("kubernetes.azure.com/scalesetpriority", "spot"),
+    ("node.kubernetes.io/lifecycle", "spot"),
+];
+
+/// Whether node labels mark spot/preemptible capacity.
+pub fn is_spot(labels: Option<&BTreeMap<String, String>>) -> bool {
+    labels.is_some_and(|labels| {
+        SPOT_LABELS.iter().any(|(key, value)| {
+            labels
+                .get(*key)
+                .is_some_and(|v| v.eq_ignore_ascii_case(value))
+        })
+    })
+}
+
+/// Zone of a node from its labels (current label first, then the deprecated beta label).
+pub fn zone_for_labels(labels: Option<&BTreeMap<String, String>>) -> Option<String> {
+    let labels = labels?;
+    labels
+        .get(ZONE_LABEL)
+        .or_else(|| labels.get(LEGACY_ZONE_LABEL))
+        .filter(|z| !z.is_empty())
+        .cloned()
+}
+
+/// Nodes and pods per zone for the overview. Returns None when no node carries a zone label.
+pub fn zone_distribution(node_list: &[NodeRow]) -> Option<Vec<ZoneRow>> {
+    let mut zones: BTreeMap<&str, ZoneRow> = BTreeMap::new();
+    for node in node_list {
+        let Some(zone) = node.zone.as_deref() else {
+            continue;
+        };
+        let row = zones.entry(zone).or_insert_with(|| ZoneRow {
+            zone: zone.to_string(),
+            node_count: 0,
+            ready_count: 0,
+            pod_count: 0,
+        });
+        row.node_count += 1;
+        row.ready_count += u32::from(node.ready);
+        row.pod_count += node.pod_count;
+    }
+    if zones.is_empty() {
+        None
+    } else {
+        Some(zones.into_values().collect())
+    }
+}
+
+fn ratio_check(
+    name: &str,
+    description: &str,
+    evaluated: usize,
+    flagged: usize,
+    detail_suffix: &str,
+    recommendation: &str,
+) -> CheckResult {
+    let score = if evaluated == 0 {
+        100.0
+    } else {
+        ((evaluated - flagged) as f64 / evaluated as f64) * 100.0
+    };
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else if score >= 80.0 {
+            CheckStatus::Warning
+        } else {
+            CheckStatus::Critical
+        },
+        score,
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} {}",
+            evaluated - flagged,
+            evaluated,
+            detail_suffix
+        )),
+        recommendations: if flagged > 0 {
+            vec![recommendation.to_string()]
+        } else {
+            vec![]
+        },
+    }
+}
+
+/// Zone of every node, by node name.
+fn node_zones(nodes: &[Node]) -> HashMap<String, String> {
+    nodes
+        .iter()
+        .filter_map(|n| {
+            Some((
+                n.metadata.name.clone()?,
+                zone_for_labels(n.metadata.labels.as_ref())?,
+            ))
+        })
+        .collect()
+}
+
+pub(crate) fn is_ready(node: &Node) -> bool {
+    node.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+}
+
+/// Distinct zones of Ready nodes.
+fn ready_zones(nodes: &[Node]) -> BTreeSet<String> {
+    nodes
+        .iter()
+        .filter(|n| is_ready(n))
+        .filter_map(|n| zone_for_labels(n.metadata.labels.as_ref()))
+        .collect()
+}
+
+/// HA-001: the cluster has fewer than two zones with Ready nodes.
+fn zone_coverage(nodes: &[Node], issues: &mut Vec<Issue>) -> CheckResult {
+    let zones = ready_zones(nodes);
+    let ready = nodes.iter().filter(|n| is_ready(n)).count();
+    // The finding's resource is the zone label, or the one zone all Ready nodes are in.
+    let (status, score, details, finding) = match zones.len() {
+        0 => (
+            CheckStatus::Warning,
+            80.0,
+            format!("No Ready node carries the {} label", ZONE_LABEL),
+            Some((
+                IssueSeverity::Info,
+                format!(
+                    "None of the {} Ready node(s) carries a {} label; zone resilience cannot be assessed",
+                    ready, ZONE_LABEL
+                ),
+                ZONE_LABEL.to_string(),
+            )),
+        ),
+        1 => {
+            let zone = zones.iter().next().cloned().unwrap_or_default();
+            (
+                CheckStatus::Warning,
+                50.0,
+                format!("{} Ready node(s) in 1 zone ({})", ready, zone),
+                Some((
+                    IssueSeverity::Warning,
+                    format!("All {} Ready node(s) are in zone {}", ready, zone),
+                    format!("zone {}", zone),
+                )),
+            )
+        }
+        n => (
+            CheckStatus::Pass,
+            100.0,
+            format!(
+                "{} Ready node(s) in {} zones ({})",
+                ready,
+                n,
+                zones.iter().cloned().collect::<Vec<_>>().join(", ")
+            ),
+            None,
+        ),
+    };
+    if let Some((severity, description, resource)) = finding {
+        issues.push(Issue {
+            severity,
+            category: "Node".to_string(),
+            description,
+            resource: Some(resource),
+            recommendation:
+                "Run nodes in at least two zones (or label nodes by rack/site on bare metal)."
+                    .to_string(),
+            rule_id: Some("HA-001".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+    let recommendations = if status == CheckStatus::Pass {
+        vec![]
+    } else {
+        vec!["Add nodes in more zones; see HA-001.".to_string()]
+    };
+    CheckResult {
+        name: "Zone Coverage".to_string(),
+        description: "Ready nodes spread over more than one availability zone".to_string(),
+        status,
+        score,
+        max_score: 100.0,
+        details: Some(details),
+        recommendations,
+    }
+}
+
+/// HA-002: Deployments/StatefulSets whose running pods (two or more) all sit in one zone.
+/// Only meaningful when the cluster has several zones; pods on unlabelled nodes are ignored.
+fn replica_zone_spread(
+    nodes: &[Node],
+    pods: &[Pod],
+    owners: &PodOwners,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let description =
+        "Running pods of multi-replica Deployments/StatefulSets span more than one zone";
+    let zones = node_zones(nodes);
+    if ready_zones(nodes).len() < 2 {
+        return CheckResult {
+            name: "Replica Zone Spread".to_string(),
+            description: description.to_string(),
+            status: CheckStatus::Pass,
+            score: 100.0,
+            max_score: 100.0,
+            details: Some("Cluster has fewer than two zones; not evaluated".to_string()),
+            recommendations: vec![],
+        };
+    }
+
+    let mut pod_zones: BTreeMap<&Controller, (usize, BTreeSet<&str>)> = BTreeMap::new();
+    for pod in pods {
+        let status = pod.status.as_ref();
+        if status.and_then(|s| s.phase.as_deref()) != Some("Running") {
+            continue;
+        }
+        let key = format!(
+            "{}/{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            pod.metadata.name.as_deref().unwrap_or_default()
+        );
+        let Some(controller) = owners
+            .controller_of(&key)
+            .filter(|c| matches!(c.kind.as_str(), "Deployment" | "StatefulSet"))
+        else {
+            continue;
+        };
+        let Some(zone) = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.as_deref())
+            .and_then(|n| zones.get(n))
+        else {
+            continue;
+        };
+        let entry = pod_zones.entry(controller).or_default();
+        entry.0 += 1;
+        entry.1.insert(zone.as_str());
+    }
+
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    for (controller, (count, zones)) in pod_zones.iter().filter(|(_, (n, _))| *n >= 2) {
+        evaluated += 1;
+        if zones.len() > 1 {
+            continue;
+        }
+        flagged += 1;
+        let zone = zones.iter().next().copied().unwrap_or_default();
+        issues.push(Issue {
+            severity: IssueSeverity::Warning,
+            category: controller.kind.clone(),
+            description: format!(
+                "{} {}/{} runs {} pod(s), all in zone {}",
+                controller.kind, controller.namespace, controller.name, count, zone
+            ),
+            resource: Some(format!("{}/{}", controller.namespace, controller.name)),
+            recommendation: "Add a topologySpreadConstraint on topology.kubernetes.io/zone and check nodeSelector/affinity pinning the pods to one zone.".to_string(),
+            rule_id: Some("HA-002".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+    ratio_check(
+        "Replica Zone Spread",
+        description,
+        evaluated,
+        flagged,
+        "multi-replica workloads spread over zones",
+        "Spread replicas across zones with topologySpreadConstraints",
+    )
+}
+
+/// HA-003: Deployments with two or more replicas and neither topologySpreadConstraints nor podAntiAffinity.
+fn topology_spread(deployments: &[Deployment], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    for deployment in deployments {
+        let Some(spec) = deployment.spec.as_ref() else {
+            continue;
+        };
+        let replicas = spec.replicas.unwrap_or(1);
+        if replicas < 2 {
+            continue;
+        }
+        evaluated += 1;
+        let pod_spec = spec.template.spec.as_ref();
+        let spread = pod_spec
+            .and_then(|s| s.topology_spread_constraints.as_ref())
+            .is_some_and(|c| !c.is_empty());
+        let anti_affinity = pod_spec
+            .and_then(|s| s.affinity.as_ref())
+            .and_then(|a| a.pod_anti_affinity.as_ref())
+            .is_some();
+        if spread || anti_affinity {
+            continue;
+        }
+        flagged += 1;
+        let ns = deployment
+            .metadata
+            .namespace
+            .as_deref()
+            .unwrap_or("default");
+        let name = deployment.metadata.name.as_deref().unwrap_or("unknown");
+        issues.push(Issue {
+            severity: IssueSeverity::Info,
+            category: "Deployment".to_string(),
+            description: format!(
+                "Deployment {}/{} has {} replicas and no topologySpreadConstraints or podAntiAffinity",
+                ns, name, replicas
+            ),
+            resource: Some(format!("{}/{}", ns, name)),
+            recommendation: "Add topologySpreadConstraints on kubernetes.io/hostname and topology.kubernetes.io/zone (whenUnsatisfiable: ScheduleAnyway).".to_string(),
+            rule_id: Some("HA-003".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+    ratio_check(
+        "Topology Spread Constraints",
+        "Multi-replica Deployments declare topologySpreadConstraints or podAntiAffinity",
+        evaluated,
+        flagged,
+        "multi-replica Deployments with spread rules",
+        "Add topologySpreadConstraints to multi-replica Deployments",
+    )
+}
+
+/// Cluster-wide singleton a Deployment provides, with its issue code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SystemComponent {
+    CoreDns,
+    IngressController,
+    MetricsServer,
+}
+
+impl SystemComponent {
+    fn label(self) -> &'static str {
+        match self {
+            Self::CoreDns => "CoreDNS",
+            Self::IngressController => "Ingress controller",
+            Self::MetricsServer => "metrics-server",
+        }
+    }
+
+    fn code(self) -> &'static str {
+        match self {
+            Self::CoreDns => "HA-004",
+            Self::IngressController => "HA-005",
+            Self::MetricsServer => "HA-006",
+        }
+    }
+}
+
+fn system_component(deployment: &Deployment) -> Option<SystemComponent> {
+    let meta = &deployment.metadata;
+    let name = meta.name.as_deref().unwrap_or_default();
+    let label = |key: &str| {
+        meta.labels
+            .as_ref()
+            .and_then(|l| l.get(key))
+            .map(String::as_str)
+    };
+    let app = label("app.kubernetes.io/name").or(label("app"));
+    if meta.namespace.as_deref() == Some("kube-system")
+        && (matches!(name, "coredns" | "kube-dns") || label("k8s-app") == Some("kube-dns"))
+    {
+        return Some(SystemComponent::CoreDns);
+    }
+    if name == "metrics-server"
+        || label("k8s-app") == Some("metrics-server")
+        || app == Some("metrics-server")
+    {
+        return Some(SystemComponent::MetricsServer);
+    }
+    // Charts ship helpers (default backends, admission webhooks) next to the controller.
+    let helper = label("app.kubernetes.io/component").is_some_and(|c| c != "controller");
+    let ingress = INGRESS_CONTROLLERS
+        .iter()
+        .any(|c| app == Some(*c) || name.contains(c));
+    (ingress && !helper).then_some(SystemComponent::IngressController)
+}
+
+/// True if a PDB in the Deployment's namespace selects its pod template.
+/// Running pods of one controller and how many of them are on spot nodes.
+#[derive(Default)]
+struct SpotPlacement<'a> {
+    running: usize,
+    on_spot: usize,
+    /// Labels of one pod, for PodDisruptionBudget matching.
+    labels: Option<&'a BTreeMap<String, String>>,
+}
+
+/// HA-008: StatefulSets, and Deployments without a PodDisruptionBudget, whose running pods are
+/// all on spot nodes; HA-009: single-replica Deployments/StatefulSets on a spot node.
+fn spot_exposure(
+    nodes: &[Node],
+    pods: &[Pod],
+    owners: &PodOwners,
+    pdbs: &[PodDisruptionBudget],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let name = "Spot Capacity Exposure";
+    let description = "Workloads do not depend only on spot/preemptible nodes";
+    let spot_nodes: BTreeSet<&str> = nodes
+        .iter()
+        .filter(|n| is_spot(n.metadata.labels.as_ref()))
+        .filter_map(|n| n.metadata.name.as_deref())
+        .collect();
+    if spot_nodes.is_empty() {
+        return CheckResult {
+            name: name.to_string(),
+            description: description.to_string(),
+            status: CheckStatus::Pass,
+            score: 100.0,
+            max_score: 100.0,
+            details: Some("No spot/preemptible nodes".to_string()),
+            recommendations: vec![],
+        };
+    }
+
+    let mut placement: BTreeMap<&Controller, SpotPlacement> = BTreeMap::new();
+    for pod in pods {
+        if pod.status.as_ref().and_then(|s| s.phase.as_deref()) != Some("Running") {
+            continue;
+        }
+        let key = format!(
+            "{}/{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            pod.metadata.name.as_deref().unwrap_or_default()
+        );
+        let Some(controller) = owners
+            .controller_of(&key)
+            .filter(|c| matches!(c.kind.as_str(), "Deployment" | "StatefulSet"))
+        else {
+            continue;
+        };
+        let on_spot = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.as_deref())
+            .is_some_and(|n| spot_nodes.contains(n));
+        let entry = placement.entry(controller).or_default();
+        entry.running += 1;
+        entry.on_spot += usize::from(on_spot);
+        entry.labels = entry.labels.or(pod.metadata.labels.as_ref());
+    }
+
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    for (controller, p) in &placement {
+        evaluated += 1;
+        if p.on_spot < p.running {
+            continue;
+        }
+        let workload = format!(
+            "{} {}/{}",
+            controller.kind, controller.namespace, controller.name
+        );
+        let issue = if p.running == 1 {
+            Issue {
+                severity: IssueSeverity::Warning,
+                category: controller.kind.clone(),
+                description: format!("{} runs a single replica on a spot node", workload),
+                resource: Some(format!("{}/{}", controller.namespace, controller.name)),
+                recommendation:
+                    "Run at least two replicas or schedule the workload on on-demand nodes."
+                        .to_string(),
+                rule_id: Some("HA-009".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }
+        } else {
+            let stateful = controller.kind == "StatefulSet";
+            let protected = pdbs
+                .iter()
+                .filter(|p| p.metadata.namespace.as_deref() == Some(controller.namespace.as_str()))
+                .filter_map(|p| p.spec.as_ref()?.selector.as_ref()?.match_labels.as_ref())
+                .any(|selector| !selector.is_empty() && selector_matches(selector, p.labels));
+            if !stateful && protected {
+                continue;
+            }
+            Issue {
+                severity: if stateful {
+                    IssueSeverity::Critical
+                } else {
+                    IssueSeverity::Warning
+                },
+                category: controller.kind.clone(),
+                description: format!(
+                    "{} runs all {} pod(s) on spot nodes{}",
+                    workload,
+                    p.running,
+                    if stateful { "" } else { " and has no PodDisruptionBudget" }
+                ),
+                resource: Some(format!("{}/{}", controller.namespace, controller.name)),
+                recommendation: "Keep part of the replicas on on-demand nodes (node affinity or a topologySpreadConstraint on the capacity-type label) and add a PodDisruptionBudget.".to_string(),
+                rule_id: Some("HA-008".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }
+        };
+        flagged += 1;
+        issues.push(issue);
+    }
+    let check = ratio_check(
+        name,
+        description,
+        evaluated,
+        flagged,
+        "workloads not exposed to spot reclamation",
+        "Move stateful and single-replica workloads off spot-only placement",
+    );
+    CheckResult {
+        details: check.details.map(|d| {
+            format!(
+                "{}; {}/{} nodes are spot/preemptible",
+                d,
+                spot_nodes.len(),
+                nodes.len()
+            )
+        }),
+        ..check
+    }
+}
+
+fn has_pdb(deployment: &Deployment, pdbs: &[PodDisruptionBudget]) -> bool {
+    let labels = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.template.metadata.as_ref())
+        .and_then(|m| m.labels.as_ref());
+    pdbs.iter()
+        .filter(|p| p.metadata.namespace == deployment.metadata.namespace)
+        .filter_map(|p| p.spec.as_ref()?.selector.as_ref()?.match_labels.as_ref())
+        .any(|selector| !selector.is_empty() && selector_matches(selector, labels))
+}
+
+fn control_plane_component(pod: &Pod) -> Option<&'static str> {
+    let label = pod
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("component"))
+        .map(String::as_str);
+    let name = pod.metadata.name.as_deref().unwrap_or_default();
+    CONTROL_PLANE_COMPONENTS
+        .iter()
+        .copied()
+        .find(|c| label == Some(*c) || (label.is_none() && name.starts_with(&format!("{}-", c))))
+}
+
+/// HA-004..HA-007: system components running a single instance. Control-plane components are
+/// only counted on self-managed clusters (static pods in kube-system); managed ones are skipped.
+fn system_component_redundancy(
+    deployments: &[Deployment],
+    pdbs: &[PodDisruptionBudget],
+    system_pods: &[Pod],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    let mut spof = |issue: Issue| {
+        flagged += 1;
+        issues.push(issue);
+    };
+
+    for deployment in deployments {
+        let Some(component) = system_component(deployment) else {
+            continue;
+        };
+        evaluated += 1;
+        let replicas = deployment
+            .spec
+            .as_ref()
+            .and_then(|s| s.replicas)
+            .unwrap_or(1);
+        if replicas >= 2
+            || (component == SystemComponent::MetricsServer && has_pdb(deployment, pdbs))
+        {
+            continue;
+        }
+        let ns = deployment
+            .metadata
+            .namespace
+            .as_deref()
+            .unwrap_or("default");
+        let name = deployment.metadata.name.as_deref().unwrap_or("unknown");
+        let (description, recommendation) = match component {
+            SystemComponent::MetricsServer => (
+                format!(
+                    "metrics-server Deployment {}/{} runs {} replica(s) and no PodDisruptionBudget",
+                    ns, name, replicas
+                ),
+                "Run 2 replicas (the chart's highAvailability mode) with a PodDisruptionBudget; HPA and kubectl top stop while it is down.",
+            ),
+            _ => (
+                format!(
+                    "{} Deployment {}/{} runs {} replica(s)",
+                    component.label(),
+                    ns,
+                    name,
+                    replicas
+                ),
+                if component == SystemComponent::CoreDns {
+                    "Run at least 2 CoreDNS replicas on different nodes (or enable the DNS autoscaler); all name resolution stops while it is down."
+                } else {
+                    "Run at least 2 ingress controller replicas on different nodes with a PodDisruptionBudget; all ingress traffic stops while it is down."
+                },
+            ),
+        };
+        spof(Issue {
+            severity: IssueSeverity::Critical,
+            category: "Deployment".to_string(),
+            description,
+            resource: Some(format!("{}/{}", ns, name)),
+            recommendation: recommendation.to_string(),
+            rule_id: Some(component.code().to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: vec![format!(
+                "kubectl -n {} scale deployment {} --replicas=2",
+                ns, name
+            )],
+        });
+    }
+
+    let mut instances: BTreeMap<&str, Vec<&Pod>> = BTreeMap::new();
+    for pod in system_pods {
+        if let Some(component) = control_plane_component(pod) {
+            instances.entry(component).or_default().push(pod);
+        }
+    }
+    for (component, pods) in &instances {
+        evaluated += 1;
+        if pods.len() > 1 {
+            continue;
+        }
+        let pod = pods[0].metadata.name.as_deref().unwrap_or(component);
+        spof(Issue {
+            severity: IssueSeverity::Critical,
+            category: "ControlPlane".to_string(),
+            description: format!(
+                "Control-plane component {} runs a single instance ({})",
+                component, pod
+            ),
+            resource: Some(format!("kube-system/{}", pod)),
+            recommendation: "Run at least 3 control-plane nodes (etcd needs an odd number for quorum); a single node failure stops the API server.".to_string(),
+            rule_id: Some("HA-007".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+
+    let check = ratio_check(
+        "System Component Redundancy",
+        "CoreDNS, ingress controllers, metrics-server and static control-plane components run more than one instance",
+        evaluated,
+        flagged,
+        "system components redundant",
+        "Scale single-instance system components to at least 2 replicas",
+    );
+    CheckResult {
+        status: if flagged > 0 {
+            CheckStatus::Critical
+        } else {
+            CheckStatus::Pass
+        },
+        details: if instances.is_empty() {
+            check.details.map(|d| {
+                format!(
+                    "{}; no static control-plane pods (managed control plane?)",
+                    d
+                )
+            })
+        } else {
+            check.details
+        },
+        ..check
+    }
+}
+
+pub struct ResilienceInspector<'a> {
+    client: &'a K8sClient,
+    options: &'a InspectionOptions,
+}
+
+impl<'a> ResilienceInspector<'a> {
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self { client, options }
+    }
+
+    pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        let lp = ListParams::default();
+        let nodes = self.client.list_all(&self.client.nodes(), &lp).await?.items;
+        let pods = self
+            .client
+            .list_all(&self.client.pods(namespace), &lp)
+            .await?
+            .items;
+        let replica_sets = self
+            .client
+            .list_unselected(&self.client.replica_sets(namespace), &lp)
+            .await?
+            .items;
+        let deployments = self
+            .client
+            .list_all(&self.client.deployments(namespace), &lp)
+            .await?
+            .items;
+        let stateful_sets = self
+            .client
+            .list_all(&self.client.stateful_sets(namespace), &lp)
+            .await?
+            .items;
+        let services = self
+            .client
+            .list_unselected(&self.client.services(namespace), &lp)
+            .await?
+            .items;
+        let daemon_sets = self
+            .client
+            .list_all(&self.client.daemon_sets(namespace), &lp)
+            .await?
+            .items;
+        // Only Deployment/StatefulSet ownership matters here, so Jobs are not listed.
+        let owners = PodOwners::from_objects(&pods, &replica_sets, &[]);
+        // System components live outside the inspected namespace and selector; look them up
+        // cluster-wide.
+        let scoped = namespace.is_some() || self.client.label_selector().is_some();
+        let system_deployments = if scoped {
+            self.client
+                .list_unselected(&self.client.deployments(None), &lp)
+                .await?
+                .items
+        } else {
+            deployments.clone()
+        };
+        let system_pods: Vec<Pod> = if scoped {
+            self.client
+                .list_unselected(&self.client.pods(Some("kube-system")), &lp)
+                .await?
+                .items
+        } else {
+            pods.iter()
+                .filter(|p| p.metadata.namespace.as_deref() == Some("kube-system"))
+                .cloned()
+                .collect()
+        };
+        let pdbs = self
+            .client
+            .list_unselected(&self.client.pod_disruption_budgets(None), &lp)
+            .await?
+            .items;
+        // PriorityClasses were added to the RBAC later; without them the check is left out.
+        let priority_classes = match self
+            .client
+            .list_all(&self.client.priority_classes(), &lp)
+            .await
+        {
+            Ok(list) => Some(list.items),
+            Err(e) => {
+                log::warn!("PriorityClasses could not be listed: {}", e);
+                None
+            }
+        };
+        // Without StorageClasses the StatefulSet storage checks are skipped.
+        let storage_classes = match self
+            .client
+            .list_all(&self.client.storage_classes(), &lp)
+            .await
+        {
+            Ok(list) => Some(list.items),
+            Err(e) => {
+                log::warn!("StorageClasses could not be listed: {}", e);
+                None
+            }
+        };
+
+        // Image platforms from registries, only on mixed-architecture clusters.
+        let registry_lookup = self.options.registry_lookup && !self.options.offline;
+        let images = multi_arch::images_to_resolve(&nodes, &pods, &owners);
+        let platforms = if registry_lookup && !images.is_empty() {
+            RegistryClient::new()?.lookup_all(images).await
+        } else {
+            HashMap::new()
+        };
+
+        let mut issues = Vec::new();
+        let mut checks = vec![
+            zone_coverage(&nodes, &mut issues),
+            replica_zone_spread(&nodes, &pods, &owners, &mut issues),
+            topology_spread(&deployments, &mut issues),
+            spot_exposure(&nodes, &pods, &owners, &pdbs, &mut issues),
+            multi_arch::arch_compatibility(
+                &nodes,
+                &pods,
+                &owners,
+                &platforms,
+                registry_lookup,
+                &mut issues,
+            ),
+            system_component_redundancy(&system_deployments, &pdbs, &system_pods, &mut issues),
+            shutdown::graceful_shutdown(&deployments, &stateful_sets, &services, &mut issues),
+            statefulsets::statefulset_check(
+                &stateful_sets,
+                &services,
+                storage_classes.as_deref(),
+                ready_zones(&nodes).len() > 1,
+                &mut issues,
+            ),
+            daemonsets::daemonset_coverage(&daemon_sets, &nodes, &pods, &mut issues),
+        ];
+        if let Some(classes) = &priority_classes {
+            let addon_pods = if namespace.is_some() {
+                &system_pods
+            } else {
+                &pods
+            };
+            checks.push(priority::priority_check(
+                classes,
+                &pods,
+                addon_pods,
+                &owners,
+                &mut issues,
+            ));
+        }
+
+        let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
+        let summary = build_summary(&checks, issues);
+
+        Ok(InspectionResult {
+            inspection_type: "Resilience".to_string(),
+            timestamp: Utc::now(),
+            overall_score,
+            checks,
+            summary,
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        })
+    }
+}
+
+fn build_summary(checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
+    let count = |status: CheckStatus| checks.iter().filter(|c| c.status == status).count() as u32;
+    InspectionSummary {
+        total_checks: checks.len() as u32,
+        passed_checks: count(CheckStatus::Pass),
+        warning_checks: count(CheckStatus::Warning),
+        critical_checks: count(CheckStatus::Critical),
+        error_checks: count(CheckStatus::Error),
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DeploymentSpec, ReplicaSet};
+    use k8s_openapi::api::core::v1::{
+        NodeCondition, NodeStatus, PodSpec, PodStatus, PodTemplateSpec, TopologySpreadConstraint,
+    };
+    use k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{
+        LabelSelector, ObjectMeta, OwnerReference,
+    };
+
+    fn node(name: &str, zone: Option<&str>) -> Node {
+        let mut n = Node::default();
+        n.metadata.name = Some(name.to_string());
+        n.metadata.labels = zone.map(|z| [(ZONE_LABEL.to_string(), z.to_string())].into());
+        n.status = Some(NodeStatus {
+            conditions: Some(vec![NodeCondition {
+                type_: "Ready".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+        n
+    }
+
+    fn pod(name: &str, owner: (&str, &str), node: &str) -> Pod {
+        let mut p = Pod::default();
+        p.metadata.namespace = Some("shop".to_string());
+        p.metadata.name = Some(name.to_string());
+        p.metadata.owner_references = Some(vec![OwnerReference {
+            kind: owner.0.to_string(),
+            name: owner.1.to_string(),
+            controller: Some(true),
+            ..Default::default()
+        }]);
+        p.spec = Some(PodSpec {
+            node_name: Some(node.to_string()),
+            ..Default::default()
+        });
+        p.status = Some(PodStatus {
+            phase: Some("Running".to_string()),
+            ..Default::default()
+        });
+        p
+    }
+
+    fn deployment(name: &str, replicas: i32, spec: PodSpec) -> Deployment {
+        let mut d = Deployment::default();
+        d.metadata.namespace = Some("shop".to_string());
+        d.metadata.name = Some(name.to_string());
+        d.spec = Some(DeploymentSpec {
+            replicas: Some(replicas),
+            template: PodTemplateSpec {
+                spec: Some(spec),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        d
+    }
+
+    #[test]
+    fn flags_single_zone_cluster_and_reports_distribution() {
+        let mut issues = Vec::new();
+        let check = zone_coverage(
+            &[node("a", Some("eu-1a")), node("b", Some("eu-1a"))],
+            &mut issues,
+        );
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("HA-001"));
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(
+            issues[0].description,
+            "All 2 Ready node(s) are in zone eu-1a"
+        );
+
+        issues.clear();
+        zone_coverage(&[node("a", None)], &mut issues);
+        assert_eq!(issues[0].severity, IssueSeverity::Info);
+
+        let row = |name: &str, zone: Option<&str>, pods: u32| NodeRow {
+            name: name.to_string(),
+            operating_system: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            kubelet_version: "v1.29.0".to_string(),
+            ready: true,
+            pod_count: pods,
+            allocatable_pods: None,
+            node_address: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime_version: None,
+            node_pool: None,
+            zone: zone.map(|z| z.to_string()),
+            spot: false,
+        };
+        let zones = zone_distribution(&[
+            row("a", Some("eu-1b"), 4),
+            row("b", Some("eu-1a"), 3),
+            row("c", Some("eu-1b"), 5),
+            row("d", None, 1),
+        ])
+        .unwrap();
+        let got: Vec<(&str, u32, u32)> = zones
+            .iter()
+            .map(|z| (z.zone.as_str(), z.node_count, z.pod_count))
+            .collect();
+        assert_eq!(got, vec![("eu-1a", 1, 3), ("eu-1b", 2, 9)]);
+        assert!(zone_distribution(&[row("d", None, 1)]).is_none());
+    }
+
+    #[test]
+    fn flags_replicas_in_one_zone_and_missing_spread() {
+        let nodes = [
+            node("a", Some("z1")),
+            node("b", Some("z1")),
+            node("c", Some("z2")),
+        ];
+        let rs = ReplicaSet {
+            metadata: pod("web-5c", ("Deployment", "web"), "a").metadata,
+            ..Default::default()
+        };
+        let pods = [
+            pod("web-5c-1", ("ReplicaSet", "web-5c"), "a"),
+            pod("web-5c-2", ("ReplicaSet", "web-5c"), "b"),
+            pod("db-0", ("StatefulSet", "db"), "a"),
+            pod("db-1", ("StatefulSet", "db"), "c"),
+        ];
+        let owners = PodOwners::from_objects(&pods, &[rs], &[]);
+        let mut issues = Vec::new();
+        let check = replica_zone_spread(&nodes, &pods, &owners, &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id.as_deref(), Some("HA-002"));
+        assert_eq!(
+            issues[0].description,
+            "Deployment shop/web runs 2 pod(s), all in zone z1"
+        );
+        assert_eq!(
+            check.details.as_deref(),
+            Some("1/2 multi-replica workloads spread over zones")
+        );
+
+        let spread = PodSpec {
+            topology_spread_constraints: Some(vec![TopologySpreadConstraint {
+                max_skew: 1,
+                topology_key: ZONE_LABEL.to_string(),
+                when_unsatisfiable: "ScheduleAnyway".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let mut issues = Vec::new();
+        let check = topology_spread(
+            &[
+                deployment("web", 3, PodSpec::default()),
+                deployment("api", 3, spread),
+                deployment("job", 1, PodSpec::default()),
+            ],
+            &mut issues,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("shop/web"));
+        assert_eq!(issues[0].severity, IssueSeverity::Info);
+        assert_eq!(check.score, 50.0);
+    }
+
+    #[test]
+    fn flags_workloads_only_on_spot_nodes() {
+        let mut spot = node("s1", Some("z1"));
+        spot.metadata.labels =
+            Some([("karpenter.sh/capacity-type".to_string(), "spot".to_string())].into());
+        let mut spot2 = spot.clone();
+        spot2.metadata.name = Some("s2".to_string());
+        let nodes = [spot, spot2, node("od", Some("z1"))];
+        let pods = [
+            pod("db-0", ("StatefulSet", "db"), "s1"),
+            pod("db-1", ("StatefulSet", "db"), "s2"),
+            pod("cache-0", ("StatefulSet", "cache"), "s1"),
+            pod("mixed-0", ("StatefulSet", "mixed"), "s1"),
+            pod("mixed-1", ("StatefulSet", "mixed"), "od"),
+        ];
+        let owners = PodOwners::from_objects(&pods, &[], &[]);
+        let mut issues = Vec::new();
+        let check = spot_exposure(&nodes, &pods, &owners, &[], &mut issues);
+        let got: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(got, vec![("HA-009", "shop/cache"), ("HA-008", "shop/db")]);
+        assert_eq!(issues[1].severity, IssueSeverity::Critical);
+        assert!(check
+            .details
+            .unwrap()
+            .ends_with("2/3 nodes are spot/preemptible"));
+    }
+
+    #[test]
+    fn flags_single_instance_system_components() {
+        let system = |ns: &str, name: &str, replicas: i32| {
+            let mut d = deployment(name, replicas, PodSpec::default());
+            d.metadata.namespace = Some(ns.to_string());
+            if let Some(spec) = d.spec.as_mut() {
+                spec.template.metadata = Some(ObjectMeta {
+                    labels: Some([("app".to_string(), name.to_string())].into()),
+                    ..Default::default()
+                });
+            }
+            d
+        };
+        let pdb = PodDisruptionBudget {
+            metadata: ObjectMeta {
+                namespace: Some("monitoring".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodDisruptionBudgetSpec {
+                selector: Some(LabelSelector {
+                    match_labels: Some([("app".to_string(), "metrics-server".to_string())].into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let static_pod = |name: &str, component: &str| {
+            let mut p = Pod::default();
+            p.metadata.namespace = Some("kube-system".to_string());
+            p.metadata.name = Some(name.to_string());
+            p.metadata.labels = Some([("component".to_string(), component.to_string())].into());
+            p
+        };
+        let deployments = [
+            system("kube-system", "coredns", 1),
+            system("ingress-nginx", "ingress-nginx-controller", 2),
+            system("kube-system", "metrics-server", 1),
+            system("monitoring", "metrics-server", 1),
+            system("shop", "web", 1),
+        ];
+        let pods = [
+            static_pod("kube-apiserver-cp1", "kube-apiserver"),
+            static_pod("etcd-cp1", "etcd"),
+            static_pod("etcd-cp2", "etcd"),
+        ];
+        let mut issues = Vec::new();
+        let check = system_component_redundancy(&deployments, &[pdb], &pods, &mut issues);
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("HA-004", "kube-system/coredns"),
+                ("HA-006", "kube-system/metrics-server"),
+                ("HA-007", "kube-system/kube-apiserver-cp1"),
+            ]
+        );
+        assert!(issues.iter().all(|i| i.severity == IssueSeverity::Critical));
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("3/6 system components redundant")
+        );
+
+        let mut issues = Vec::new();
+        let check = system_component_redundancy(&deployments[1..2], &[], &[], &mut issues);
+        assert!(issues.is_empty());
+        assert!(check.details.unwrap().contains("managed control plane"));
+    }
+}
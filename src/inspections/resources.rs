@@ -1,109 +1,135 @@
 use anyhow::Result;
 use chrono::Utc;
+use futures::TryStreamExt;
 use kube::api::ListParams;
 use log::info;
 
+use crate::inspections::controllers::PodOwners;
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::sampling::IssueSampler;
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 use crate::utils::resource_quantity::{parse_cpu_str, parse_memory_str};
 
 pub struct ResourceInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
+    owners: Option<&'a PodOwners>,
 }
 
 impl<'a> ResourceInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self {
+            client,
+            options,
+            owners: None,
+        }
+    }
+
+    /// Roll pod findings up to the controllers in `owners` while reading pods.
+    pub fn with_pod_owners(mut self, owners: Option<&'a PodOwners>) -> Self {
+        self.owners = owners;
+        self
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
         info!("Starting resource usage inspection");
 
         let mut checks = Vec::new();
-        let mut issues = Vec::new();
+        let mut issues =
+            IssueSampler::new(self.options.max_issues_per_rule).with_owners(self.owners);
 
         // Check pods for resource requests and limits
         let pods_api = self.client.pods(namespace);
-        let pods = pods_api.list(&ListParams::default()).await?;
-
         let mut total_containers = 0;
         let mut containers_with_requests = 0;
         let mut containers_with_limits = 0;
         let mut containers_with_both = 0;
 
-        for pod in &pods.items {
-            let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
-            let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
-
-            if let Some(spec) = &pod.spec {
-                for container in &spec.containers {
-                    total_containers += 1;
-
-                    let has_requests = container
-                        .resources
-                        .as_ref()
-                        .and_then(|r| r.requests.as_ref())
-                        .map(|requests| !requests.is_empty())
-                        .unwrap_or(false);
-
-                    let has_limits = container
-                        .resources
-                        .as_ref()
-                        .and_then(|r| r.limits.as_ref())
-                        .map(|limits| !limits.is_empty())
-                        .unwrap_or(false);
-
-                    if has_requests {
-                        containers_with_requests += 1;
-                    }
-
-                    if has_limits {
-                        containers_with_limits += 1;
-                    }
-
-                    if has_requests && has_limits {
-                        containers_with_both += 1;
-                    }
-
-                    // Check if requests and limits are reasonable
-                    if let Some(resources) = &container.resources {
-                        self.validate_resource_configuration(
-                            &format!("{}/{}", pod_namespace, pod_name),
-                            &container.name,
-                            resources,
-                            &mut issues,
-                        )?;
-                    }
-
-                    if !has_requests {
-                        issues.push(Issue {
-                            severity: IssueSeverity::Warning,
-                            category: "Container".to_string(),
-                            description: format!(
-                                "Container {} in pod {}/{} has no resource requests",
-                                container.name, pod_namespace, pod_name
-                            ),
-                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                            recommendation: "Set CPU and memory requests for better scheduling"
-                                .to_string(),
-                            rule_id: Some("RES-001".to_string()),
-                        });
-                    }
-
-                    if !has_limits {
-                        issues.push(Issue {
-                            severity: IssueSeverity::Warning,
-                            category: "Container".to_string(),
-                            description: format!(
-                                "Container {} in pod {}/{} has no resource limits",
-                                container.name, pod_namespace, pod_name
-                            ),
-                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                            recommendation:
-                                "Set CPU and memory limits to prevent resource exhaustion"
+        let mut pages = self.client.list_pages(&pods_api, &ListParams::default());
+        while let Some(page) = pages.try_next().await? {
+            for pod in &page {
+                let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+                let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+
+                if let Some(spec) = &pod.spec {
+                    for container in &spec.containers {
+                        total_containers += 1;
+
+                        let has_requests = container
+                            .resources
+                            .as_ref()
+                            .and_then(|r| r.requests.as_ref())
+                            .map(|requests| !requests.is_empty())
+                            .unwrap_or(false);
+
+                        let has_limits = container
+                            .resources
+                            .as_ref()
+                            .and_then(|r| r.limits.as_ref())
+                            .map(|limits| !limits.is_empty())
+                            .unwrap_or(false);
+
+                        if has_requests {
+                            containers_with_requests += 1;
+                        }
+
+                        if has_limits {
+                            containers_with_limits += 1;
+                        }
+
+                        if has_requests && has_limits {
+                            containers_with_both += 1;
+                        }
+
+                        // Check if requests and limits are reasonable
+                        if let Some(resources) = &container.resources {
+                            self.validate_resource_configuration(
+                                &format!("{}/{}", pod_namespace, pod_name),
+                                &container.name,
+                                resources,
+                                &mut issues,
+                            )?;
+                        }
+
+                        if !has_requests {
+                            issues.push(Issue {
+                                severity: IssueSeverity::Warning,
+                                category: "Container".to_string(),
+                                description: format!(
+                                    "Container {} in pod {}/{} has no resource requests",
+                                    container.name, pod_namespace, pod_name
+                                ),
+                                resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                recommendation: "Set CPU and memory requests for better scheduling"
                                     .to_string(),
-                            rule_id: Some("RES-002".to_string()),
-                        });
+                                rule_id: Some("RES-001".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: container_key(&container.name),
+                                remediation_commands: Vec::new(),
+                            });
+                        }
+
+                        if !has_limits {
+                            issues.push(Issue {
+                                severity: IssueSeverity::Warning,
+                                category: "Container".to_string(),
+                                description: format!(
+                                    "Container {} in pod {}/{} has no resource limits",
+                                    container.name, pod_namespace, pod_name
+                                ),
+                                resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                recommendation:
+                                    "Set CPU and memory limits to prevent resource exhaustion"
+                                        .to_string(),
+                                rule_id: Some("RES-002".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: container_key(&container.name),
+                                remediation_commands: Vec::new(),
+                            });
+                        }
                     }
                 }
             }
@@ -114,7 +140,10 @@ impl<'a> ResourceInspector<'a> {
             vec![ns.to_string()]
         } else {
             let ns_api = self.client.namespaces();
-            let ns_list = ns_api.list(&ListParams::default()).await?;
+            let ns_list = self
+                .client
+                .list_all(&ns_api, &ListParams::default())
+                .await?;
             ns_list
                 .items
                 .iter()
@@ -139,6 +168,10 @@ impl<'a> ResourceInspector<'a> {
                         recommendation: "Configure resource quotas to prevent resource exhaustion"
                             .to_string(),
                         rule_id: Some("RES-003".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
             }
@@ -234,7 +267,7 @@ impl<'a> ResourceInspector<'a> {
 
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
-        let summary = self.create_summary(&checks, issues);
+        let summary = self.create_summary(&checks, issues.into_issues());
 
         Ok(InspectionResult {
             inspection_type: "Resource Usage".to_string(),
@@ -245,6 +278,8 @@ impl<'a> ResourceInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
@@ -253,7 +288,7 @@ impl<'a> ResourceInspector<'a> {
         pod_name: &str,
         container_name: &str,
         resources: &k8s_openapi::api::core::v1::ResourceRequirements,
-        issues: &mut Vec<Issue>,
+        issues: &mut IssueSampler,
     ) -> Result<()> {
         // Check if limits are higher than requests
         if let (Some(requests), Some(limits)) = (&resources.requests, &resources.limits) {
@@ -273,6 +308,10 @@ impl<'a> ResourceInspector<'a> {
                             resource: Some(pod_name.to_string()),
                             recommendation: "Ensure CPU limits are higher than or equal to requests".to_string(),
                             rule_id: Some("RES-004".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: container_key(container_name),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
@@ -298,6 +337,10 @@ impl<'a> ResourceInspector<'a> {
                                 "Ensure memory limits are higher than or equal to requests"
                                     .to_string(),
                             rule_id: Some("RES-005".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: container_key(container_name),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
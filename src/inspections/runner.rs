@@ -1,30 +1,46 @@
 use anyhow::Result;
-use chrono::Utc;
-use colored::Colorize;
-use k8s_openapi::api::core::v1::Pod;
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use k8s_openapi::api::core::v1::{Event, Node, Pod};
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use kube::api::ListParams;
 use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
 use uuid::Uuid;
 
+use super::notice::{log_notify, NoticeLevel, Notify};
+use super::options::InspectionOptions;
 use super::types::{
-    CheckResult, CheckStatus, ClusterOverview, ClusterReport, ContainerUsageRow, EventRow,
-    ExecutiveSummary, HealthStatus, InspectionResult, InspectionSummary, Issue, IssueSeverity,
-    NodeConditionsRow, NodeResourceSummary, NodeRow, NodeUsageRow, PodPhaseBreakdown,
-    StorageSummary, WorkloadSummary,
+    CheckResult, CheckStatus, ClusterOverview, ClusterReport, CollectionWarning, ContainerUsageRow,
+    DataSourceUsage, EventReasonRow, EventRow, ExecutiveSummary, InspectionResult,
+    InspectionSummary, Issue, IssueSeverity, NodeConditionsRow, NodeResourceSummary, NodeRow,
+    NodeUsageRow, PodPhaseBreakdown, ReportMetadata, RunDiagnostics, StepTiming, StorageSummary,
+    WorkloadSummary, REPORT_SCHEMA_VERSION,
 };
 use super::{
-    autoscaling, batch, certificates, control_plane, namespace_summary, network, nodes,
-    observability, pods, policies, resources, security, storage, upgrade,
+    autoscaling, batch, certificates, connectivity_probes, control_plane, controllers,
+    debug_settings, dedup, events, gpu, kubelet_config, namespace_summary, network, node_capacity,
+    node_density, node_hardening, node_images, node_networking, node_overcommit, node_pools,
+    node_pressure, node_storage, nodes, observability, orphans, pods, policies, priority, provider,
+    qos, resilience, resources, security, storage, upgrade, windows,
 };
-use crate::cli::InspectionType;
+use crate::capacity;
+use crate::cli::{ActiveProbe, InspectionType, NodeCollectMode};
+use crate::k8s::throttle::ApiCounters;
 use crate::k8s::K8sClient;
 use crate::node_inspection::{
-    collect_node_inspections, ensure_node_inspector_ready, NodeInspectionResult,
-    NodeInspectorStatus,
+    collect_node_inspections, collect_node_inspections_exec, ensure_node_inspector_ready,
+    NodeInspectionResult, NodeInspectorStatus,
 };
+use crate::scoring::ScoringEngine;
 use crate::utils::resource_quantity::{parse_cpu_str, parse_memory_str};
 
+/// Source name of node inspector warnings in the Data completeness section.
+const NODE_INSPECTOR_SOURCE: &str = "Node inspector DaemonSet";
+const ACTIVE_PROBES_SOURCE: &str = "Active network probes";
+
 fn parse_cpu_quantity(q: Option<&Quantity>) -> Option<i64> {
     q.and_then(|q| parse_cpu_str(q.0.as_str()))
 }
@@ -75,15 +91,172 @@ fn format_memory_gi(bytes: i64) -> String {
     }
 }
 
+/// Keep a failed inspector from aborting the run: the error becomes a module with one `Error`
+/// check and an INSP-001 issue, plus a collection warning, and the remaining modules still run.
+fn isolate_failure(
+    inspection_type: &str,
+    result: Result<InspectionResult>,
+    collection_warnings: &mut Vec<CollectionWarning>,
+    notify: &Notify,
+) -> InspectionResult {
+    match result {
+        Ok(inspection) => inspection,
+        Err(e) => {
+            notify(
+                NoticeLevel::Warning,
+                &format!("{} inspection failed: {:#}", inspection_type, e),
+            );
+            collection_warnings.push(CollectionWarning::unavailable(
+                inspection_type,
+                format!(
+                    "Inspection failed ({:#}); its checks are missing and it is left out of the overall score.",
+                    e
+                ),
+            ));
+            failed_inspection(inspection_type, &e)
+        }
+    }
+}
+
+/// Module recording an inspector error as a single `Error` check with an explanatory issue.
+fn failed_inspection(inspection_type: &str, err: &anyhow::Error) -> InspectionResult {
+    let recommendation = match err.chain().find_map(|e| e.downcast_ref::<kube::Error>()) {
+        Some(kube::Error::Api(resp)) if resp.code == 403 => format!(
+            "Grant the kubeowler identity read access (get/list) to the resources this module needs: {}",
+            resp.message
+        ),
+        Some(kube::Error::Api(resp)) if resp.code == 401 => {
+            "Refresh the kubeconfig credentials and re-run the check".to_string()
+        }
+        Some(kube::Error::Api(resp)) if resp.code == 404 => {
+            "The API used by this module is not served by the cluster; check the installed API groups (kubectl api-resources)".to_string()
+        }
+        _ => "Check API server connectivity and RBAC for the kubeowler identity, then re-run the check"
+            .to_string(),
+    };
+    InspectionResult {
+        inspection_type: inspection_type.to_string(),
+        timestamp: Utc::now(),
+        overall_score: 0.0,
+        checks: vec![CheckResult {
+            name: format!("{} inspection", inspection_type),
+            description: "Run the inspection module against the cluster".to_string(),
+            status: CheckStatus::Error,
+            score: 0.0,
+            max_score: 100.0,
+            details: Some(format!("{:#}", err)),
+            recommendations: vec![recommendation.clone()],
+        }],
+        summary: InspectionSummary {
+            total_checks: 1,
+            passed_checks: 0,
+            warning_checks: 0,
+            critical_checks: 0,
+            error_checks: 1,
+            issues: vec![Issue {
+                severity: IssueSeverity::Warning,
+                category: "Inspection".to_string(),
+                description: format!("{} inspection failed: {:#}", inspection_type, err),
+                resource: Some(inspection_type.to_string()),
+                recommendation,
+                rule_id: Some("INSP-001".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            }],
+        },
+        certificate_expiries: None,
+        pod_container_states: None,
+        namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
+    }
+}
+
+/// Add a check (and its issues) to an existing inspection, keeping score and summary counts in sync.
+fn append_check(inspection: &mut InspectionResult, check: CheckResult, issues: Vec<Issue>) {
+    let summary = &mut inspection.summary;
+    summary.total_checks += 1;
+    match check.status {
+        CheckStatus::Pass => summary.passed_checks += 1,
+        CheckStatus::Warning => summary.warning_checks += 1,
+        CheckStatus::Critical => summary.critical_checks += 1,
+        CheckStatus::Error => summary.error_checks += 1,
+    }
+    summary.issues.extend(issues);
+    inspection.checks.push(check);
+    inspection.overall_score =
+        inspection.checks.iter().map(|c| c.score).sum::<f64>() / inspection.checks.len() as f64;
+}
+
+/// Wall time, API traffic and listed objects of one step of the run, from the client's counters.
+struct StepTimer<'a> {
+    counters: &'a ApiCounters,
+    started: Instant,
+    requests: u64,
+    errors: u64,
+    objects: u64,
+}
+
+impl<'a> StepTimer<'a> {
+    fn start(counters: &'a ApiCounters) -> Self {
+        Self {
+            counters,
+            started: Instant::now(),
+            requests: counters.requests(),
+            errors: counters.errors(),
+            objects: counters.objects_listed(),
+        }
+    }
+
+    fn finish(self, step: &str, failed: bool) -> StepTiming {
+        StepTiming {
+            step: step.to_string(),
+            duration_ms: self.started.elapsed().as_millis() as u64,
+            api_requests: self.counters.requests() - self.requests,
+            api_errors: self.counters.errors() - self.errors,
+            objects_listed: self.counters.objects_listed() - self.objects,
+            failed,
+        }
+    }
+}
+
+/// Runs inspection modules against a cluster and assembles the [`ClusterReport`]: module
+/// results, overall score, cluster overview, events and node inspector data.
 pub struct InspectionRunner {
     client: K8sClient,
+    options: InspectionOptions,
+    notify: Notify,
 }
 
 impl InspectionRunner {
+    /// Runner with default [`InspectionOptions`].
     pub fn new(client: K8sClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: InspectionOptions::default(),
+            notify: log_notify(),
+        }
+    }
+
+    /// Override inspection tunables (thresholds, patterns) from the command line.
+    pub fn with_options(mut self, options: InspectionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Send progress and status messages (node inspector waits, failed modules) to `notify`
+    /// instead of `log`.
+    pub fn with_notify(mut self, notify: Notify) -> Self {
+        self.notify = notify;
+        self
     }
 
+    /// Run `inspection_type` (one module or `All`), scoped to `namespace` when set. A module that
+    /// fails is recorded as an INSP-001 error instead of failing the run; `Err` is only returned
+    /// for errors outside the modules.
+    #[tracing::instrument(name = "inspection_run", skip_all)]
     pub async fn run_inspections(
         &self,
         inspection_type: InspectionType,
@@ -91,64 +264,411 @@ impl InspectionRunner {
         node_inspector_namespace: &str,
         cluster_name_override: Option<&str>,
     ) -> Result<ClusterReport> {
+        let run_started = Instant::now();
+        let mut steps: Vec<StepTiming> = Vec::new();
         let mut inspections = Vec::new();
+        // Data sources that could not be (fully) collected, shown as "Data completeness".
+        let mut collection_warnings: Vec<CollectionWarning> = Vec::new();
+        let timer = StepTimer::start(self.client.api_counters());
+        let warning_events = match self
+            .fetch_warning_events(namespace)
+            .instrument(tracing::info_span!("collect", source = "Kubernetes events"))
+            .await
+        {
+            Ok(events) => Some(events),
+            Err(e) => {
+                collection_warnings.push(CollectionWarning::unavailable(
+                    "Kubernetes events",
+                    format!(
+                        "Events could not be listed ({}); event analytics and recent events are missing.",
+                        e
+                    ),
+                ));
+                None
+            }
+        };
+        steps.push(timer.finish("Kubernetes events", warning_events.is_none()));
+        let (event_rows, event_inspection) =
+            self.analyze_events(warning_events.as_deref().unwrap_or(&[]), namespace);
+        let event_inspection =
+            Some(event_inspection).filter(|_| self.options.checks.runs_module("Event Analytics"));
+
+        // Resolved up front so the pod, resource and security inspectors roll findings up to
+        // controllers as they stream pods.
+        let pod_owners = if self.options.aggregate_by_controller {
+            let timer = StepTimer::start(self.client.api_counters());
+            let owners = self
+                .fetch_pod_owners(namespace)
+                .instrument(tracing::info_span!("collect", source = "Pod owners"))
+                .await;
+            if let Err(e) = &owners {
+                log::debug!("pod owners unavailable, findings stay per pod: {}", e);
+            }
+            steps.push(timer.finish("Pod owners", owners.is_err()));
+            owners.ok()
+        } else {
+            None
+        };
+        let owners = pod_owners.as_ref();
 
         match inspection_type {
             // Logical order: infrastructure → storage & resources → workloads → security & policy → operations
             InspectionType::All => {
-                inspections.push(self.run_node_inspection().await?);
-                inspections.push(self.run_control_plane_inspection().await?);
-                inspections.push(self.run_network_inspection(namespace).await?);
-                inspections.push(self.run_storage_inspection(namespace).await?);
-                inspections.push(self.run_resource_inspection(namespace).await?);
-                inspections.push(self.run_pod_inspection(namespace).await?);
-                inspections.push(self.run_autoscaling_inspection(namespace).await?);
-                inspections.push(self.run_batch_inspection(namespace).await?);
-                inspections.push(self.run_security_inspection(namespace).await?);
-                inspections.push(self.run_policy_inspection(namespace).await?);
-                inspections.push(self.run_observability_inspection(namespace).await?);
-                inspections.push(self.run_namespace_summary_inspection().await?);
-                inspections.push(self.run_certificate_inspection().await?);
-                inspections.push(self.run_upgrade_readiness_inspection().await?);
+                inspections.extend(
+                    self.run_module(
+                        "Node Health",
+                        self.run_node_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Control Plane",
+                        self.run_control_plane_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Network Connectivity",
+                        self.run_network_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Storage",
+                        self.run_storage_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Orphaned Resources",
+                        self.run_orphans_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Resilience",
+                        self.run_resilience_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Resource Usage",
+                        self.run_resource_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                // Optional: only reported when extended resources are in use.
+                inspections.extend(
+                    self.run_module(
+                        gpu::MODULE,
+                        self.run_gpu_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await
+                    .filter(gpu::found_extended_resources),
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Pod Status",
+                        self.run_pod_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Autoscaling",
+                        self.run_autoscaling_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Batch Workloads",
+                        self.run_batch_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Security Configuration",
+                        self.run_security_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Policy & Governance",
+                        self.run_policy_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Observability",
+                        self.run_observability_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(event_inspection);
+                inspections.extend(
+                    self.run_module(
+                        "Debug Settings",
+                        self.run_debug_settings_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Namespace",
+                        self.run_namespace_summary_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Certificates",
+                        self.run_certificate_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+                inspections.extend(
+                    self.run_module(
+                        "Upgrade Readiness",
+                        self.run_upgrade_readiness_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Nodes => {
-                inspections.push(self.run_node_inspection().await?);
+                inspections.extend(
+                    self.run_module(
+                        "Node Health",
+                        self.run_node_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Pods => {
-                inspections.push(self.run_pod_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Pod Status",
+                        self.run_pod_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Resources => {
-                inspections.push(self.run_resource_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Resource Usage",
+                        self.run_resource_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Network => {
-                inspections.push(self.run_network_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Network Connectivity",
+                        self.run_network_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Storage => {
-                inspections.push(self.run_storage_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Storage",
+                        self.run_storage_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Security => {
-                inspections.push(self.run_security_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Security Configuration",
+                        self.run_security_inspection(namespace, owners),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::ControlPlane => {
-                inspections.push(self.run_control_plane_inspection().await?);
+                inspections.extend(
+                    self.run_module(
+                        "Control Plane",
+                        self.run_control_plane_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Autoscaling => {
-                inspections.push(self.run_autoscaling_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Autoscaling",
+                        self.run_autoscaling_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Batch => {
-                inspections.push(self.run_batch_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Batch Workloads",
+                        self.run_batch_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Policies => {
-                inspections.push(self.run_policy_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Policy & Governance",
+                        self.run_policy_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Observability => {
-                inspections.push(self.run_observability_inspection(namespace).await?);
+                inspections.extend(
+                    self.run_module(
+                        "Observability",
+                        self.run_observability_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+            }
+            InspectionType::Orphans => {
+                inspections.extend(
+                    self.run_module(
+                        "Orphaned Resources",
+                        self.run_orphans_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+            }
+            InspectionType::Resilience => {
+                inspections.extend(
+                    self.run_module(
+                        "Resilience",
+                        self.run_resilience_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+            }
+            InspectionType::Events => {
+                inspections.extend(event_inspection);
+            }
+            InspectionType::DebugSettings => {
+                inspections.extend(
+                    self.run_module(
+                        "Debug Settings",
+                        self.run_debug_settings_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Upgrade => {
-                inspections.push(self.run_upgrade_readiness_inspection().await?);
+                inspections.extend(
+                    self.run_module(
+                        "Upgrade Readiness",
+                        self.run_upgrade_readiness_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
+            }
+            InspectionType::Gpu => {
+                inspections.extend(
+                    self.run_module(
+                        gpu::MODULE,
+                        self.run_gpu_inspection(namespace),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
             InspectionType::Certificates => {
-                inspections.push(self.run_certificate_inspection().await?);
+                inspections.extend(
+                    self.run_module(
+                        "Certificates",
+                        self.run_certificate_inspection(),
+                        &mut collection_warnings,
+                        &mut steps,
+                    )
+                    .await,
+                );
             }
         }
 
@@ -158,50 +678,416 @@ impl InspectionRunner {
             .map(|s| s.to_string())
             .unwrap_or_else(|| self.client.cluster_name().unwrap_or("default").to_string());
 
-        let cluster_overview = self.fetch_cluster_overview().await.ok();
-        let recent_events = self
-            .fetch_recent_events(50)
-            .await
-            .ok()
+        let timer = StepTimer::start(self.client.api_counters());
+        // Listed once for the overview and the node storage and capacity checks further down.
+        let (api_nodes, overview) = async {
+            let api_nodes = self
+                .client
+                .list_all(&self.client.nodes(), &ListParams::default())
+                .await
+                .map(|list| list.items);
+            let overview = match api_nodes.as_deref() {
+                Ok(nodes) => self.fetch_cluster_overview(nodes, namespace).await,
+                Err(e) => Err(anyhow::anyhow!("{}", e)),
+            };
+            (api_nodes, overview)
+        }
+        .instrument(tracing::info_span!("collect", source = "Cluster overview"))
+        .await;
+        let mut cluster_overview = match overview {
+            Ok(ov) => Some(ov),
+            Err(e) => {
+                collection_warnings.push(CollectionWarning::unavailable(
+                    "Cluster overview",
+                    format!("Nodes or pods could not be listed ({}); the overview, node usage and node pools are missing.", e),
+                ));
+                None
+            }
+        };
+        steps.push(timer.finish("Cluster overview", cluster_overview.is_none()));
+        if let Some(ov) = cluster_overview.as_ref() {
+            if ov.metrics_available == Some(false) {
+                collection_warnings.push(CollectionWarning::unavailable(
+                    "metrics-server (metrics.k8s.io)",
+                    "Live node and container usage is missing; Node Headroom (NODE-008..010) was not evaluated.",
+                ));
+            }
+            if let Some(rows) = ov.node_usage.as_deref() {
+                let missing = rows.iter().filter(|r| r.disk_usage_gi.is_none()).count();
+                if missing == rows.len() {
+                    collection_warnings.push(CollectionWarning::unavailable(
+                        "Kubelet summary API (nodes/proxy)",
+                        "Node disk usage and PVC usage are missing (needs get on nodes/proxy).",
+                    ));
+                } else if missing > 0 {
+                    collection_warnings.push(CollectionWarning::partial(
+                        "Kubelet summary API (nodes/proxy)",
+                        format!(
+                            "Disk usage missing for {} of {} node(s).",
+                            missing,
+                            rows.len()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // Node headroom from live metrics feeds the Node Health score (NODE-008/009/010).
+        if let Some(rows) = cluster_overview
+            .as_ref()
+            .and_then(|o| o.node_usage.as_deref())
+        {
+            if let (Some((check, issues)), Some(node_health)) = (
+                node_pressure::evaluate_node_headroom(rows),
+                inspections
+                    .iter_mut()
+                    .find(|i| i.inspection_type == "Node Health"),
+            ) {
+                append_check(node_health, check, issues);
+                overall_score = self.calculate_overall_score(&inspections);
+                executive_summary = self.generate_executive_summary(&inspections, overall_score);
+            }
+        }
+
+        // Pod density against allocatable pods feeds Node Health (NODE-023/024); with a namespace
+        // scope or `--selector` the per-node pod counts cover those pods only, so density is not
+        // judged.
+        if let Some(rows) = cluster_overview
+            .as_ref()
+            .and_then(|o| o.node_list.as_deref())
+            .filter(|_| namespace.is_none() && self.client.label_selector().is_none())
+        {
+            if let (Some((check, issues)), Some(node_health)) = (
+                node_density::evaluate_pod_density(rows),
+                inspections
+                    .iter_mut()
+                    .find(|i| i.inspection_type == "Node Health"),
+            ) {
+                append_check(node_health, check, issues);
+                overall_score = self.calculate_overall_score(&inspections);
+                executive_summary = self.generate_executive_summary(&inspections, overall_score);
+            }
+        }
+
+        // Limit overcommit per node feeds Node Health (NODE-032/033); the capacity section is only
+        // built without a namespace scope or `--selector`.
+        if let Some(capacity) = cluster_overview.as_ref().and_then(|o| o.capacity.as_ref()) {
+            if let (Some((check, issues)), Some(node_health)) = (
+                node_overcommit::evaluate_overcommit(
+                    capacity,
+                    self.options.max_cpu_overcommit_pct,
+                    self.options.max_memory_overcommit_pct,
+                ),
+                inspections
+                    .iter_mut()
+                    .find(|i| i.inspection_type == "Node Health"),
+            ) {
+                append_check(node_health, check, issues);
+                overall_score = self.calculate_overall_score(&inspections);
+                executive_summary = self.generate_executive_summary(&inspections, overall_score);
+            }
+        }
+
+        // Pod QoS classes from the overview pod list feed Resource Usage (RES-006/007).
+        if let Some(rows) = cluster_overview
+            .as_ref()
+            .and_then(|o| o.qos_distribution.as_deref())
+        {
+            if let Some(resources) = inspections
+                .iter_mut()
+                .find(|i| i.inspection_type == "Resource Usage")
+            {
+                let (check, issues) = qos::evaluate_qos(rows);
+                append_check(resources, check, issues);
+                overall_score = self.calculate_overall_score(&inspections);
+                executive_summary = self.generate_executive_summary(&inspections, overall_score);
+            }
+        }
+
+        // Kubelet effective configuration (/configz) feeds Node Health (NODE-019..022).
+        let mut kubelet_configs_read: Option<(usize, usize)> = None;
+        if let Some(node_rows) = cluster_overview
+            .as_ref()
+            .and_then(|o| o.node_list.as_deref())
+            .filter(|_| !self.options.offline)
+            .filter(|_| {
+                inspections
+                    .iter()
+                    .any(|i| i.inspection_type == "Node Health")
+            })
+        {
+            let timer = StepTimer::start(self.client.api_counters());
+            let configs: Vec<_> = self
+                .client
+                .fan_out(node_rows, |row| async move {
+                    match self.client.node_kubelet_config(&row.name).await {
+                        Ok(Some(config)) => Some((row.name.clone(), config)),
+                        _ => None,
+                    }
+                })
+                .await
+                .into_iter()
+                .flatten()
+                .collect();
+            steps.push(timer.finish("Kubelet configz", false));
+            kubelet_configs_read = Some((configs.len(), node_rows.len()));
+            if configs.is_empty() && !node_rows.is_empty() {
+                collection_warnings.push(CollectionWarning::unavailable(
+                    "Kubelet configz (nodes/proxy)",
+                    "Kubelet configuration was not readable (needs get on nodes/proxy); Kubelet Configuration (NODE-019..022) was not evaluated.",
+                ));
+            }
+            if let (Some((check, issues)), Some(node_health)) = (
+                kubelet_config::evaluate_kubelet_configs(&configs, node_rows),
+                inspections
+                    .iter_mut()
+                    .find(|i| i.inspection_type == "Node Health"),
+            ) {
+                append_check(node_health, check, issues);
+                overall_score = self.calculate_overall_score(&inspections);
+                executive_summary = self.generate_executive_summary(&inspections, overall_score);
+            }
+        }
+
+        let recent_events = warning_events
+            .as_deref()
+            .map(|evs| Self::recent_event_rows(evs, 50))
             .filter(|v| !v.is_empty());
+        let event_analytics = Some(event_rows).filter(|v| !v.is_empty());
 
         // Collect per-node inspection JSON from DaemonSet pods when doing full or node-only inspection.
         // DaemonSet is always looked up in node_inspector_namespace (e.g. kubeowler); inspection scope is namespace.
         // Pre-check: if data is stale (>24h), restart DaemonSet; if not deployed, skip with prompt.
+        let timer = StepTimer::start(self.client.api_counters());
         let node_inspection_results: Option<Vec<NodeInspectionResult>> = match inspection_type {
+            // Host-level data only feeds NODE-* checks.
+            _ if !self.options.checks.runs_module("Node Health") => None,
+            InspectionType::All | InspectionType::Nodes if self.options.offline => {
+                collection_warnings.push(CollectionWarning::unavailable(
+                    NODE_INSPECTOR_SOURCE,
+                    "Not available in offline mode; the Node Inspection section and host-level checks are missing.",
+                ));
+                None
+            }
+            // Exec mode reads fresh data on demand: no staleness check or restart.
+            InspectionType::All | InspectionType::Nodes
+                if self.options.node_collect == NodeCollectMode::Exec =>
+            {
+                match collect_node_inspections_exec(&self.client, node_inspector_namespace)
+                    .instrument(tracing::info_span!(
+                        "collect",
+                        source = NODE_INSPECTOR_SOURCE
+                    ))
+                    .await
+                {
+                    Ok(results) if results.is_empty() => {
+                        (self.notify)(
+                            NoticeLevel::Info,
+                            &format!("No node inspector pod in namespace '{}' returned data via exec. Node inspection skipped (install it with `kubeowler node-inspector install`).", node_inspector_namespace),
+                        );
+                        collection_warnings.push(CollectionWarning::unavailable(
+                            NODE_INSPECTOR_SOURCE,
+                            format!("No pod in namespace '{}' returned data via exec; the Node Inspection section and host-level checks are missing.", node_inspector_namespace),
+                        ));
+                        None
+                    }
+                    Ok(results) => Some(results),
+                    Err(e) => {
+                        (self.notify)(
+                            NoticeLevel::Warning,
+                            &format!(
+                                "Node inspection via exec failed: {}. Node inspection skipped.",
+                                e
+                            ),
+                        );
+                        collection_warnings.push(CollectionWarning::unavailable(
+                            NODE_INSPECTOR_SOURCE,
+                            format!("Exec collection failed ({}); the Node Inspection section and host-level checks are missing.", e),
+                        ));
+                        None
+                    }
+                }
+            }
             InspectionType::All | InspectionType::Nodes => {
-                let status =
-                    ensure_node_inspector_ready(&self.client, node_inspector_namespace, 24).await;
+                let status = ensure_node_inspector_ready(
+                    &self.client,
+                    node_inspector_namespace,
+                    24,
+                    &self.notify,
+                )
+                .instrument(tracing::info_span!("node_inspector_ready"))
+                .await;
                 match status {
                     NodeInspectorStatus::NotDeployed => {
-                        println!(
-                            "{}  Node inspector DaemonSet not deployed in namespace '{}'. Node inspection skipped.",
-                            "ℹ️".bright_blue(),
-                            node_inspector_namespace.bright_green()
+                        (self.notify)(
+                            NoticeLevel::Info,
+                            &format!("Node inspector DaemonSet not deployed in namespace '{}'. Node inspection skipped (install it with `kubeowler node-inspector install`).", node_inspector_namespace),
                         );
+                        collection_warnings.push(CollectionWarning::unavailable(
+                            NODE_INSPECTOR_SOURCE,
+                            format!("DaemonSet not deployed in namespace '{}'; the Node Inspection section and host-level checks are missing.", node_inspector_namespace),
+                        ));
                         None
                     }
-                    NodeInspectorStatus::RestartedAndReady => {
-                        println!(
-                            "{}  Node inspector data was stale (>24h). Restarted DaemonSet pods and refreshed.",
-                            "⚠️".bright_yellow()
-                        );
-                        collect_node_inspections(&self.client, Some(node_inspector_namespace))
-                            .await
-                            .ok()
-                    }
-                    NodeInspectorStatus::Ready | NodeInspectorStatus::ReadyPartial { .. } => {
-                        collect_node_inspections(&self.client, Some(node_inspector_namespace))
+                    status => {
+                        if status == NodeInspectorStatus::RestartedAndReady {
+                            (self.notify)(
+                                NoticeLevel::Warning,
+                                "Node inspector data was stale (>24h). Restarted DaemonSet pods and refreshed.",
+                            );
+                        }
+                        if let NodeInspectorStatus::ReadyPartial { ready, total } = status {
+                            collection_warnings.push(CollectionWarning::partial(
+                                NODE_INSPECTOR_SOURCE,
+                                format!("Only {} of {} pod(s) produced output before the 5 minute timeout.", ready, total),
+                            ));
+                        }
+                        match collect_node_inspections(&self.client, Some(node_inspector_namespace))
+                            .instrument(tracing::info_span!(
+                                "collect",
+                                source = NODE_INSPECTOR_SOURCE
+                            ))
                             .await
-                            .ok()
+                        {
+                            Ok(results) => Some(results),
+                            Err(e) => {
+                                collection_warnings.push(CollectionWarning::unavailable(
+                                    NODE_INSPECTOR_SOURCE,
+                                    format!("Pod logs could not be read or parsed ({}); the Node Inspection section and host-level checks are missing.", e),
+                                ));
+                                None
+                            }
+                        }
                     }
                 }
             }
             _ => None,
         };
+        if matches!(inspection_type, InspectionType::All | InspectionType::Nodes)
+            && !self.options.offline
+            && self.options.checks.runs_module("Node Health")
+        {
+            steps.push(timer.finish(NODE_INSPECTOR_SOURCE, node_inspection_results.is_none()));
+        }
+
+        if let (Some(results), Some(ov)) = (
+            node_inspection_results.as_deref(),
+            cluster_overview.as_ref(),
+        ) {
+            // The inspector only schedules on Linux nodes; Windows nodes are not expected to report.
+            let linux_nodes = ov
+                .node_list
+                .as_ref()
+                .map(|list| {
+                    list.iter()
+                        .filter(|n| n.operating_system != windows::WINDOWS)
+                        .count()
+                })
+                .unwrap_or(ov.node_count as usize);
+            if !results.is_empty() && results.len() < linux_nodes {
+                collection_warnings.push(CollectionWarning::partial(
+                    NODE_INSPECTOR_SOURCE,
+                    format!(
+                        "Host-level data for {} of {} Linux node(s); nodes without an inspector pod are not covered.",
+                        results.len(),
+                        linux_nodes
+                    ),
+                ));
+            }
+        }
+
+        // Ephemeral storage (allocatable, DiskPressure, nodefs/imagefs usage) feeds Node Health (NODE-011..013).
+        if inspections
+            .iter()
+            .any(|i| i.inspection_type == "Node Health")
+        {
+            if let Ok(api_nodes) = api_nodes.as_deref() {
+                if let (Some((check, issues)), Some(node_health)) = (
+                    node_storage::evaluate_ephemeral_storage(
+                        api_nodes,
+                        node_inspection_results.as_deref(),
+                    ),
+                    inspections
+                        .iter_mut()
+                        .find(|i| i.inspection_type == "Node Health"),
+                ) {
+                    append_check(node_health, check, issues);
+                    overall_score = self.calculate_overall_score(&inspections);
+                    executive_summary =
+                        self.generate_executive_summary(&inspections, overall_score);
+                }
+            }
+        }
+
+        // kube-proxy in ipvs mode vs the ip_vs module on each host feeds Network Connectivity (NET-012).
+        if let Some(nodes) = node_inspection_results.as_deref() {
+            if inspections
+                .iter()
+                .any(|i| i.inspection_type == "Network Connectivity")
+            {
+                if let Ok(mode) = node_networking::fetch_kube_proxy_mode(&self.client).await {
+                    if let (Some((check, issues)), Some(network)) = (
+                        node_networking::evaluate_proxy_mode(mode.as_deref(), nodes),
+                        inspections
+                            .iter_mut()
+                            .find(|i| i.inspection_type == "Network Connectivity"),
+                    ) {
+                        append_check(network, check, issues);
+                        overall_score = self.calculate_overall_score(&inspections);
+                        executive_summary =
+                            self.generate_executive_summary(&inspections, overall_score);
+                    }
+                }
+            }
+        }
+
+        // Opt-in active network probes from the node inspector pods (NET-015..NET-019).
+        if self.options.active_probes.contains(&ActiveProbe::Network)
+            && !self.options.offline
+            && inspections
+                .iter()
+                .any(|i| i.inspection_type == "Network Connectivity")
+        {
+            let timer = StepTimer::start(self.client.api_counters());
+            let probes = connectivity_probes::run_network_probes(
+                &self.client,
+                node_inspector_namespace,
+                self.options.probe_egress_target.as_deref(),
+            )
+            .instrument(tracing::info_span!(
+                "collect",
+                source = ACTIVE_PROBES_SOURCE
+            ))
+            .await;
+            steps.push(timer.finish(ACTIVE_PROBES_SOURCE, probes.is_err()));
+            match probes {
+                Ok(results) => {
+                    if let Some(network) = inspections
+                        .iter_mut()
+                        .find(|i| i.inspection_type == "Network Connectivity")
+                    {
+                        for (check, issues) in connectivity_probes::evaluate_probes(&results) {
+                            append_check(network, check, issues);
+                        }
+                    }
+                    overall_score = self.calculate_overall_score(&inspections);
+                    executive_summary =
+                        self.generate_executive_summary(&inspections, overall_score);
+                }
+                Err(e) => collection_warnings.push(CollectionWarning::unavailable(
+                    ACTIVE_PROBES_SOURCE,
+                    format!("Network probes did not run ({:#}); only passive network checks are reported.", e),
+                )),
+            }
+        }
 
-        // Synthetic Node Inspection result: issues for nodes with zombie processes (NODE-003).
+        // Synthetic Node Inspection result: zombie processes (NODE-003), OS hardening
+        // (NODE-014..NODE-018) and kubelet-declared capacity that disagrees with the hardware
+        // measured on the host (NODE-006/NODE-007).
         if let Some(ref nodes) = &node_inspection_results {
+            let mut node_checks: Vec<CheckResult> = Vec::new();
+            let mut node_issues: Vec<Issue> = Vec::new();
+
             let zombie_issues: Vec<Issue> = nodes
                 .iter()
                 .filter(|n| n.zombie_count.map(|c| c > 0).unwrap_or(false))
@@ -215,11 +1101,15 @@ impl InspectionRunner {
                         recommendation: "Identify parent processes and fix reaping; see NODE-003."
                             .to_string(),
                         rule_id: Some("NODE-003".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     }
                 })
                 .collect();
             if !zombie_issues.is_empty() {
-                let check = CheckResult {
+                node_checks.push(CheckResult {
                     name: "Node process health".to_string(),
                     description: "Zombie processes on nodes".to_string(),
                     status: CheckStatus::Warning,
@@ -232,30 +1122,128 @@ impl InspectionRunner {
                     recommendations: vec![
                         "See NODE-003 and fix parent process reaping.".to_string()
                     ],
+                });
+                node_issues.extend(zombie_issues);
+            }
+
+            let (hardened_evaluated, hardening_issues) =
+                node_hardening::evaluate_os_hardening(nodes);
+            if !hardening_issues.is_empty() {
+                let mut affected: Vec<&str> = hardening_issues
+                    .iter()
+                    .filter_map(|i| i.resource.as_deref())
+                    .collect();
+                affected.sort();
+                affected.dedup();
+                node_checks.push(CheckResult {
+                    name: "Node OS hardening".to_string(),
+                    description:
+                        "CIS node items: kubelet file permissions, kernel modules, sysctls, open ports, SSH"
+                            .to_string(),
+                    status: CheckStatus::Warning,
+                    score: ((hardened_evaluated - affected.len()) as f64
+                        / hardened_evaluated as f64)
+                        * 100.0,
+                    max_score: 100.0,
+                    details: Some(format!(
+                        "{} of {} node(s) with hardening findings",
+                        affected.len(),
+                        hardened_evaluated
+                    )),
+                    recommendations: vec![
+                        "See NODE-014..NODE-018 and harden the listed nodes.".to_string()
+                    ],
+                });
+                node_issues.extend(hardening_issues);
+            }
+
+            if let Ok(api_nodes) = api_nodes.as_deref() {
+                let mismatches = node_capacity::find_capacity_mismatches(
+                    api_nodes,
+                    nodes,
+                    node_capacity::CAPACITY_MISMATCH_TOLERANCE_PCT,
+                );
+                if !mismatches.is_empty() {
+                    let mut affected: Vec<&str> =
+                        mismatches.iter().map(|m| m.node_name.as_str()).collect();
+                    affected.sort();
+                    affected.dedup();
+                    let score = if nodes.is_empty() {
+                        100.0
+                    } else {
+                        ((nodes.len() - affected.len()) as f64 / nodes.len() as f64) * 100.0
+                    };
+                    node_checks.push(CheckResult {
+                        name: "Node capacity consistency".to_string(),
+                        description:
+                            "Kubelet-declared CPU/memory capacity vs hardware measured on the node"
+                                .to_string(),
+                        status: CheckStatus::Warning,
+                        score,
+                        max_score: 100.0,
+                        details: Some(format!(
+                            "{} node(s) with capacity mismatch above {}%",
+                            affected.len(),
+                            node_capacity::CAPACITY_MISMATCH_TOLERANCE_PCT
+                        )),
+                        recommendations: vec![
+                            "See NODE-006/NODE-007; restart kubelet so capacity matches the hardware."
+                                .to_string(),
+                        ],
+                    });
+                    node_issues.extend(node_capacity::capacity_mismatch_issues(&mismatches));
+                }
+            }
+
+            if !node_checks.is_empty() {
+                let synthetic_score =
+                    node_checks.iter().map(|c| c.score).sum::<f64>() / node_checks.len() as f64;
+                let count = |status: CheckStatus| {
+                    node_checks.iter().filter(|c| c.status == status).count() as u32
                 };
                 let summary = InspectionSummary {
-                    total_checks: 1,
-                    passed_checks: 0,
-                    warning_checks: zombie_issues.len() as u32,
-                    critical_checks: 0,
-                    error_checks: 0,
-                    issues: zombie_issues,
+                    total_checks: node_checks.len() as u32,
+                    passed_checks: count(CheckStatus::Pass),
+                    warning_checks: count(CheckStatus::Warning),
+                    critical_checks: count(CheckStatus::Critical),
+                    error_checks: count(CheckStatus::Error),
+                    issues: node_issues,
                 };
                 inspections.push(InspectionResult {
                     inspection_type: "Node Inspection".to_string(),
                     timestamp: Utc::now(),
-                    overall_score: 0.0,
-                    checks: vec![check],
+                    overall_score: synthetic_score,
+                    checks: node_checks,
                     summary,
                     certificate_expiries: None,
                     pod_container_states: None,
                     namespace_summary_rows: None,
+                    api_latency: None,
+                    etcd_objects: None,
                 });
                 overall_score = self.calculate_overall_score(&inspections);
                 executive_summary = self.generate_executive_summary(&inspections, overall_score);
             }
         }
 
+        if self.options.checks.apply(&mut inspections) > 0 {
+            overall_score = self.calculate_overall_score(&inspections);
+            executive_summary = self.generate_executive_summary(&inspections, overall_score);
+        }
+
+        if let Some(ov) = cluster_overview.as_mut() {
+            let issues: Vec<&Issue> = inspections
+                .iter()
+                .flat_map(|i| i.summary.issues.iter())
+                .collect();
+            ov.node_pools = node_pools::aggregate_node_pools(
+                ov.node_list.as_deref().unwrap_or(&[]),
+                ov.node_usage.as_deref(),
+                node_inspection_results.as_deref(),
+                &issues,
+            );
+        }
+
         let (display_timestamp, display_timestamp_filename) = node_inspection_results
             .as_ref()
             .and_then(|nodes| nodes.first())
@@ -273,7 +1261,33 @@ impl InspectionRunner {
             .map(|(h, f)| (Some(h), Some(f)))
             .unwrap_or((None, None));
 
+        if let Some(owners) = &pod_owners {
+            controllers::roll_up_pod_issues(&mut inspections, owners);
+        }
+
+        for issue in inspections
+            .iter_mut()
+            .flat_map(|i| i.summary.issues.iter_mut())
+        {
+            issue.fingerprint = Some(issue.compute_fingerprint());
+        }
+        let issue_counts = dedup::merge_duplicate_issues(&mut inspections);
+        if issue_counts.merged > 0 {
+            executive_summary = self.generate_executive_summary(&inspections, overall_score);
+        }
+
+        let namespace_ranking = Some(crate::scoring::namespace::namespace_scores(&inspections))
+            .filter(|r| !r.is_empty());
+
+        let data_sources = self.data_source_usage(
+            warning_events.as_deref(),
+            cluster_overview.as_ref(),
+            node_inspection_results.as_deref(),
+            kubelet_configs_read,
+        );
+
         Ok(ClusterReport {
+            schema_version: REPORT_SCHEMA_VERSION.to_string(),
             cluster_name,
             report_id: Uuid::new_v4().to_string(),
             timestamp: Utc::now(),
@@ -283,81 +1297,246 @@ impl InspectionRunner {
             cluster_overview,
             node_inspection_results,
             recent_events,
+            event_analytics,
+            namespace_ranking,
+            accepted_issues: None,
+            metadata: ReportMetadata {
+                collection_warnings,
+                scoring_profile: self.options.scoring_profile.clone(),
+                team: None,
+                baseline: None,
+                profile: None,
+                namespace_scope: namespace.map(str::to_string),
+                label_selector: self.client.label_selector().map(str::to_string),
+                check_selection: self.options.checks.describe(),
+                issue_counts: Some(issue_counts),
+                finding_ages: None,
+                run_diagnostics: Some(RunDiagnostics {
+                    duration_ms: run_started.elapsed().as_millis() as u64,
+                    steps,
+                    data_sources,
+                }),
+            },
             display_timestamp,
             display_timestamp_filename,
         })
     }
 
-    /// Fetch recent cluster events (Warning and Error only; Normal is excluded).
-    async fn fetch_recent_events(&self, limit: usize) -> Result<Vec<EventRow>> {
-        use k8s_openapi::api::core::v1::Event;
-        use kube::Api;
+    /// Controllers owning the pods in scope. Pods are read page by page and only their owner is
+    /// kept; ReplicaSets and Jobs are listed whatever their labels.
+    async fn fetch_pod_owners(&self, namespace: Option<&str>) -> Result<controllers::PodOwners> {
+        let lp = ListParams::default();
+        let replica_sets = self
+            .client
+            .list_unselected(&self.client.replica_sets(namespace), &lp)
+            .await?;
+        let jobs = self
+            .client
+            .list_unselected(&self.client.jobs(namespace), &lp)
+            .await?;
+        let mut owners = controllers::PodOwners::with_parents(&replica_sets.items, &jobs.items);
+        let pods_api = self.client.pods(namespace);
+        let mut pages = self.client.list_pages(&pods_api, &lp);
+        while let Some(page) = pages.try_next().await? {
+            owners.add_pods(&page);
+        }
+        Ok(owners)
+    }
+
+    /// Run one inspector in an `inspection` span, record its duration and API requests for
+    /// `--otel-endpoint`, and keep a failure from aborting the run (see `isolate_failure`).
+    /// Modules deselected by `--only-checks` / `--skip-checks` are not started (None).
+    async fn run_module(
+        &self,
+        inspection_type: &str,
+        inspector: impl Future<Output = Result<InspectionResult>>,
+        collection_warnings: &mut Vec<CollectionWarning>,
+        steps: &mut Vec<StepTiming>,
+    ) -> Option<InspectionResult> {
+        if !self.options.checks.runs_module(inspection_type) {
+            return None;
+        }
+        let span = tracing::info_span!(
+            "inspection",
+            module = inspection_type,
+            api_requests = tracing::field::Empty,
+            api_errors = tracing::field::Empty,
+            otel.status_code = tracing::field::Empty,
+        );
+        let timer = StepTimer::start(self.client.api_counters());
+        let result = inspector.instrument(span.clone()).await;
+        let step = timer.finish(inspection_type, result.is_err());
+        span.record("api_requests", step.api_requests);
+        span.record("api_errors", step.api_errors);
+        if step.failed {
+            span.record("otel.status_code", "ERROR");
+        }
+        crate::telemetry::record_module(
+            inspection_type,
+            Duration::from_millis(step.duration_ms),
+            step.api_requests,
+            step.failed,
+        );
+        steps.push(step);
+        Some(isolate_failure(
+            inspection_type,
+            result,
+            collection_warnings,
+            &self.notify,
+        ))
+    }
+
+    /// Optional data sources and whether this run could read them (the Run diagnostics section).
+    fn data_source_usage(
+        &self,
+        warning_events: Option<&[Event]>,
+        overview: Option<&ClusterOverview>,
+        node_results: Option<&[NodeInspectionResult]>,
+        kubelet_configs: Option<(usize, usize)>,
+    ) -> Vec<DataSourceUsage> {
+        let mut sources = vec![DataSourceUsage {
+            source: "Kubernetes events".to_string(),
+            used: warning_events.is_some(),
+            detail: warning_events
+                .map(|e| format!("{} Warning/Error event(s)", e.len()))
+                .unwrap_or_else(|| "Not listed".to_string()),
+        }];
+        let metrics = overview.and_then(|o| o.metrics_available) == Some(true);
+        sources.push(DataSourceUsage {
+            source: "metrics-server (metrics.k8s.io)".to_string(),
+            used: metrics,
+            detail: if metrics {
+                "Live node and container usage".to_string()
+            } else {
+                "Not available".to_string()
+            },
+        });
+        let rows = overview
+            .and_then(|o| o.node_usage.as_deref())
+            .unwrap_or(&[]);
+        let with_disk = rows.iter().filter(|r| r.disk_usage_gi.is_some()).count();
+        sources.push(DataSourceUsage {
+            source: "Kubelet summary API (nodes/proxy)".to_string(),
+            used: with_disk > 0,
+            detail: format!("Disk usage for {} of {} node(s)", with_disk, rows.len()),
+        });
+        sources.push(DataSourceUsage {
+            source: "Kubelet configz (nodes/proxy)".to_string(),
+            used: kubelet_configs.is_some_and(|(read, _)| read > 0),
+            detail: match kubelet_configs {
+                Some((read, total)) => format!("Kubelet config for {} of {} node(s)", read, total),
+                None => "Not collected".to_string(),
+            },
+        });
+        let mode = if self.options.offline {
+            "offline mode"
+        } else if self.options.node_collect == NodeCollectMode::Exec {
+            "exec"
+        } else {
+            "pod logs"
+        };
+        sources.push(DataSourceUsage {
+            source: NODE_INSPECTOR_SOURCE.to_string(),
+            used: node_results.is_some(),
+            detail: match node_results {
+                Some(nodes) => format!("{} node(s) via {}", nodes.len(), mode),
+                None => format!("Not collected ({})", mode),
+            },
+        });
+        sources
+    }
 
-        let ns_api = self.client.namespaces();
-        let ns_list = ns_api.list(&ListParams::default()).await?;
-        const MAX_NAMESPACES: usize = 20;
-        let ns_names: Vec<String> = ns_list
+    /// Fetch Warning and Error events in scope (all namespaces unless `--namespace`) with one LIST;
+    /// `type!=Normal` is filtered by the API server so routine events are never transferred.
+    async fn fetch_warning_events(&self, namespace: Option<&str>) -> Result<Vec<Event>> {
+        let lp = ListParams::default().fields("type!=Normal");
+        let events = self
+            .client
+            .list_all(&self.client.events(namespace), &lp)
+            .await?;
+        Ok(events
             .items
             .into_iter()
-            .filter_map(|n| n.metadata.name)
-            .take(MAX_NAMESPACES)
+            .filter(|ev| matches!(ev.type_.as_deref(), Some("Warning") | Some("Error")))
+            .collect())
+    }
+
+    /// Aggregate Warning events in scope into the report table and the Event Analytics inspection.
+    fn analyze_events(
+        &self,
+        events: &[Event],
+        namespace: Option<&str>,
+    ) -> (Vec<EventReasonRow>, InspectionResult) {
+        let warnings: Vec<events::WarningEvent> = events
+            .iter()
+            .filter_map(events::WarningEvent::from_event)
+            .filter(|ev| namespace.is_none_or(|ns| ev.namespace == ns))
             .collect();
+        let now = Utc::now();
+        let window = self.options.event_window_minutes;
+        let rows = events::aggregate_warning_events(
+            &warnings,
+            now,
+            window,
+            self.options.noisy_events_per_hour,
+        );
+        let (check, issues) = events::evaluate_event_patterns(&warnings, &rows, now, window);
+        (rows, events::event_inspection(check, issues))
+    }
 
-        let mut rows: Vec<EventRow> = Vec::new();
-        for ns in &ns_names {
-            let events_api: Api<Event> = Api::namespaced(self.client.client().clone(), ns);
-            let list_params = ListParams::default();
-            let events = match events_api.list(&list_params).await {
-                Ok(l) => l,
-                Err(_) => continue,
-            };
-            for ev in events.items {
-                let type_ = ev.type_.as_deref().unwrap_or("");
-                if type_ != "Warning" && type_ != "Error" {
-                    continue;
-                }
-                let namespace = ev.metadata.namespace.as_deref().unwrap_or("").to_string();
+    /// Latest `limit` events for the recent events table, newest first by their actual timestamp.
+    fn recent_event_rows(events: &[Event], limit: usize) -> Vec<EventRow> {
+        let mut events: Vec<(Option<DateTime<Utc>>, &Event)> = events
+            .iter()
+            .map(|ev| (events::event_last_seen(ev), ev))
+            .collect();
+        // Newest first; events without any timestamp last.
+        events.sort_by_key(|(seen, _)| std::cmp::Reverse(*seen));
+        events
+            .into_iter()
+            .take(limit)
+            .map(|(seen, ev)| {
                 let obj = &ev.involved_object;
-                let kind = obj.kind.as_deref().unwrap_or("").to_string();
+                let kind = obj.kind.as_deref().unwrap_or("");
                 let name = obj.name.as_deref().unwrap_or("").to_string();
                 let object_ref = if kind.is_empty() || name.is_empty() {
-                    name.clone()
+                    name
                 } else {
                     format!("{}/{}", kind, name)
                 };
-                let last_seen = ev
-                    .last_timestamp
-                    .as_ref()
-                    .or(ev.first_timestamp.as_ref())
-                    .map(|t| t.0.format("%Y-%m-%d %H:%M:%S").to_string())
-                    .unwrap_or_else(|| "-".to_string());
-                let message = ev.message.as_deref().unwrap_or("").to_string();
-                let message_trunc = if message.len() > 80 {
-                    format!("{}...", &message[..77])
+                let message = ev.message.as_deref().unwrap_or("");
+                let message = if message.chars().count() > 80 {
+                    format!("{}...", message.chars().take(77).collect::<String>())
                 } else {
-                    message
+                    message.to_string()
                 };
-                rows.push(EventRow {
-                    namespace,
+                EventRow {
+                    namespace: ev.metadata.namespace.clone().unwrap_or_default(),
                     object_ref,
-                    event_type: type_.to_string(),
-                    reason: ev.reason.as_deref().unwrap_or("").to_string(),
-                    message: message_trunc,
-                    last_seen,
-                });
-            }
-        }
-        rows.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
-        rows.truncate(limit);
-        Ok(rows)
+                    event_type: ev.type_.clone().unwrap_or_default(),
+                    reason: ev.reason.clone().unwrap_or_default(),
+                    message,
+                    last_seen: seen
+                        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                }
+            })
+            .collect()
     }
 
     /// Build cluster overview from node list (and optional server version). Used for report header.
-    async fn fetch_cluster_overview(&self) -> Result<ClusterOverview> {
-        let nodes_api = self.client.nodes();
-        let nodes = nodes_api.list(&ListParams::default()).await?;
-        let pods_api = self.client.pods(None);
-        let pods = pods_api.list(&ListParams::default()).await?;
+    /// `nodes` is the cluster-wide list; pods, workloads, PVCs and container usage are listed only
+    /// in `namespace` when the run is scoped to one.
+    async fn fetch_cluster_overview(
+        &self,
+        nodes: &[Node],
+        namespace: Option<&str>,
+    ) -> Result<ClusterOverview> {
+        let pods_api = self.client.pods(namespace);
+        let pods = self
+            .client
+            .list_all(&pods_api, &ListParams::default())
+            .await?;
         let mut pods_per_node: HashMap<String, u32> = HashMap::new();
         for pod in &pods.items {
             if let Some(ref name) = pod.spec.as_ref().and_then(|s| s.node_name.as_ref()) {
@@ -383,15 +1562,23 @@ impl InspectionRunner {
             }
         }
 
-        // Namespace count.
-        let ns_api = self.client.namespaces();
-        let ns_list = ns_api.list(&ListParams::default()).await?;
-        let namespace_count = ns_list.items.len() as u32;
+        // Namespace count (the scope itself when scoped).
+        let namespace_count = match namespace {
+            Some(_) => 1,
+            None => {
+                let ns_api = self.client.namespaces();
+                let ns_list = self
+                    .client
+                    .list_all(&ns_api, &ListParams::default())
+                    .await?;
+                ns_list.items.len() as u32
+            }
+        };
 
-        // Workload summary: Deployments, StatefulSets, DaemonSets (cluster-wide).
+        // Workload summary: Deployments, StatefulSets, DaemonSets (in scope).
         let mut workload = WorkloadSummary::default();
-        let dep_api = self.client.deployments(None);
-        if let Ok(list) = dep_api.list(&ListParams::default()).await {
+        let dep_api = self.client.deployments(namespace);
+        if let Ok(list) = self.client.list_all(&dep_api, &ListParams::default()).await {
             workload.deployments_total = list.items.len() as u32;
             for d in &list.items {
                 let desired = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1) as u32;
@@ -405,8 +1592,8 @@ impl InspectionRunner {
                 }
             }
         }
-        let sts_api = self.client.stateful_sets(None);
-        if let Ok(list) = sts_api.list(&ListParams::default()).await {
+        let sts_api = self.client.stateful_sets(namespace);
+        if let Ok(list) = self.client.list_all(&sts_api, &ListParams::default()).await {
             workload.statefulsets_total = list.items.len() as u32;
             for s in &list.items {
                 let desired = s.spec.as_ref().and_then(|sp| sp.replicas).unwrap_or(1) as u32;
@@ -420,8 +1607,8 @@ impl InspectionRunner {
                 }
             }
         }
-        let ds_api = self.client.daemon_sets(None);
-        if let Ok(list) = ds_api.list(&ListParams::default()).await {
+        let ds_api = self.client.daemon_sets(namespace);
+        if let Ok(list) = self.client.list_all(&ds_api, &ListParams::default()).await {
             workload.daemonsets_total = list.items.len() as u32;
             for d in &list.items {
                 let desired = d
@@ -436,14 +1623,14 @@ impl InspectionRunner {
             }
         }
 
-        // Storage summary: PV, PVC (all ns), StorageClass.
+        // Storage summary: PV and StorageClass (cluster-scoped), PVC (in scope).
         let mut storage = StorageSummary::default();
         let pv_api = self.client.persistent_volumes();
-        if let Ok(list) = pv_api.list(&ListParams::default()).await {
+        if let Ok(list) = self.client.list_all(&pv_api, &ListParams::default()).await {
             storage.pv_total = list.items.len() as u32;
         }
-        let pvc_api = self.client.persistent_volume_claims(None);
-        if let Ok(list) = pvc_api.list(&ListParams::default()).await {
+        let pvc_api = self.client.persistent_volume_claims(namespace);
+        if let Ok(list) = self.client.list_all(&pvc_api, &ListParams::default()).await {
             storage.pvc_total = list.items.len() as u32;
             for pvc in &list.items {
                 let phase = pvc
@@ -457,7 +1644,7 @@ impl InspectionRunner {
             }
         }
         let sc_api = self.client.storage_classes();
-        if let Ok(list) = sc_api.list(&ListParams::default()).await {
+        if let Ok(list) = self.client.list_all(&sc_api, &ListParams::default()).await {
             storage.storage_class_count = list.items.len() as u32;
             storage.has_default_storage_class = list.items.iter().any(|sc| {
                 sc.metadata
@@ -469,7 +1656,7 @@ impl InspectionRunner {
             });
         }
 
-        let total = nodes.items.len() as u32;
+        let total = nodes.len() as u32;
         let mut ready = 0u32;
         let mut os_arch: HashMap<(String, String), u32> = HashMap::new();
         let mut kubelet_versions: Vec<String> = Vec::new();
@@ -484,7 +1671,7 @@ impl InspectionRunner {
         const CONDITION_TYPES: &[&str] =
             &["Ready", "MemoryPressure", "DiskPressure", "PIDPressure"];
 
-        for node in &nodes.items {
+        for node in nodes {
             let name = node.metadata.name.as_deref().unwrap_or("").to_string();
             let mut os = "Unknown".to_string();
             let mut arch = "unknown".to_string();
@@ -542,6 +1729,12 @@ impl InspectionRunner {
             }
 
             let node_pod_count = pods_per_node.get(&name).copied().unwrap_or(0);
+            let allocatable_pods = node
+                .status
+                .as_ref()
+                .and_then(|s| s.allocatable.as_ref())
+                .and_then(|a| a.get("pods"))
+                .and_then(|q| q.0.parse::<u32>().ok());
             let node_address = node
                 .status
                 .as_ref()
@@ -559,10 +1752,17 @@ impl InspectionRunner {
                 kubelet_version,
                 ready: is_ready,
                 pod_count: node_pod_count,
+                allocatable_pods,
                 node_address,
                 os_image,
                 kernel_version,
                 container_runtime_version,
+                node_pool: node_pools::pool_for_labels(
+                    node.metadata.labels.as_ref(),
+                    &self.options.node_pool_labels,
+                ),
+                zone: resilience::zone_for_labels(node.metadata.labels.as_ref()),
+                spot: resilience::is_spot(node.metadata.labels.as_ref()),
             });
             node_conditions.push(NodeConditionsRow {
                 node_name: name,
@@ -634,9 +1834,9 @@ impl InspectionRunner {
         };
 
         let cluster_version = self.client.server_version().await.ok().flatten();
+        let managed_provider = provider::detect(nodes, cluster_version.as_deref());
 
         let cluster_age_days: Option<u64> = nodes
-            .items
             .iter()
             .filter_map(|n| n.metadata.creation_timestamp.as_ref())
             .min()
@@ -646,7 +1846,7 @@ impl InspectionRunner {
                 (now.signed_duration_since(creation).num_days()).max(0) as u64
             });
 
-        let (metrics_available, node_usage, total_usage_cpu_cores, total_usage_memory_gi) =
+        let (metrics_available, mut node_usage, total_usage_cpu_cores, total_usage_memory_gi) =
             match self.client.node_metrics().await.ok().flatten() {
                 Some(metrics) => {
                     let mut rows: Vec<NodeUsageRow> = Vec::new();
@@ -719,6 +1919,27 @@ impl InspectionRunner {
                 None => (Some(false), None, None, None),
             };
 
+        // Disk columns: nodefs usage from the kubelet summary API (best effort per node).
+        if let Some(rows) = node_usage.as_mut() {
+            let summaries = self
+                .client
+                .fan_out(
+                    rows.iter().map(|r| r.node_name.clone()),
+                    |node| async move { self.client.node_stats_summary(&node).await },
+                )
+                .await;
+            for (row, summary) in rows.iter_mut().zip(summaries) {
+                let fs = match summary {
+                    Ok(Some(summary)) => summary.node.fs,
+                    _ => None,
+                };
+                if let Some(fs) = fs {
+                    row.disk_usage_gi = fs.used_bytes.map(|b| b as f64 / GIB_BYTES);
+                    row.disk_pct = fs.used_pct();
+                }
+            }
+        }
+
         /// Top N containers by high usage (usage/limit >= 80%); only these are shown in the report.
         const CONTAINER_HIGH_USAGE_TOP_N: usize = 20;
         const HIGH_USAGE_PCT: f64 = 0.80;
@@ -728,7 +1949,7 @@ impl InspectionRunner {
         {
             None
         } else {
-            match self.client.pod_metrics().await.ok().flatten() {
+            match self.client.pod_metrics(namespace).await.ok().flatten() {
                 None => None,
                 Some(metrics_list) => {
                     let pod_lookup: HashMap<(String, String), &Pod> = pods
@@ -841,6 +2062,16 @@ impl InspectionRunner {
             }
         };
 
+        let zone_distribution = resilience::zone_distribution(&node_list);
+        let priority_distribution = self
+            .client
+            .list_all(&self.client.priority_classes(), &ListParams::default())
+            .await
+            .ok()
+            .map(|classes| priority::priority_distribution(&classes.items, &pods.items));
+        // Commitment ratios need every pod on the nodes, so not with a namespace or label filter.
+        let capacity = (namespace.is_none() && self.client.label_selector().is_none())
+            .then(|| capacity::commitments(nodes, &pods.items, &node_list));
         Ok(ClusterOverview {
             cluster_version,
             node_count: total,
@@ -868,27 +2099,48 @@ impl InspectionRunner {
             storage_summary: Some(storage),
             cluster_age_days,
             container_usage_notable,
+            node_pools: None,
+            zone_distribution,
+            managed_provider,
+            oldest_node_image: node_images::image_ages(nodes, Utc::now())
+                .into_iter()
+                .next(),
+            capacity,
+            qos_distribution: qos::qos_distribution(&pods.items),
+            priority_distribution,
         })
     }
 
     async fn run_node_inspection(&self) -> Result<InspectionResult> {
-        nodes::NodeInspector::new(&self.client).inspect().await
+        nodes::NodeInspector::new(&self.client, &self.options)
+            .inspect()
+            .await
     }
 
-    async fn run_pod_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
-        pods::PodInspector::new(&self.client)
+    async fn run_pod_inspection(
+        &self,
+        namespace: Option<&str>,
+        owners: Option<&controllers::PodOwners>,
+    ) -> Result<InspectionResult> {
+        pods::PodInspector::new(&self.client, &self.options)
+            .with_pod_owners(owners)
             .inspect(namespace)
             .await
     }
 
-    async fn run_resource_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
-        resources::ResourceInspector::new(&self.client)
+    async fn run_resource_inspection(
+        &self,
+        namespace: Option<&str>,
+        owners: Option<&controllers::PodOwners>,
+    ) -> Result<InspectionResult> {
+        resources::ResourceInspector::new(&self.client, &self.options)
+            .with_pod_owners(owners)
             .inspect(namespace)
             .await
     }
 
     async fn run_network_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
-        network::NetworkInspector::new(&self.client)
+        network::NetworkInspector::new(&self.client, &self.options)
             .inspect(namespace)
             .await
     }
@@ -899,8 +2151,13 @@ impl InspectionRunner {
             .await
     }
 
-    async fn run_security_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
-        security::SecurityInspector::new(&self.client)
+    async fn run_security_inspection(
+        &self,
+        namespace: Option<&str>,
+        owners: Option<&controllers::PodOwners>,
+    ) -> Result<InspectionResult> {
+        security::SecurityInspector::new(&self.client, &self.options)
+            .with_pod_owners(owners)
             .inspect(namespace)
             .await
     }
@@ -915,7 +2172,7 @@ impl InspectionRunner {
         &self,
         namespace: Option<&str>,
     ) -> Result<InspectionResult> {
-        autoscaling::AutoscalingInspector::new(&self.client)
+        autoscaling::AutoscalingInspector::new(&self.client, &self.options)
             .inspect(namespace)
             .await
     }
@@ -927,7 +2184,7 @@ impl InspectionRunner {
     }
 
     async fn run_policy_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
-        policies::PoliciesInspector::new(&self.client)
+        policies::PoliciesInspector::new(&self.client, &self.options)
             .inspect(namespace)
             .await
     }
@@ -941,6 +2198,31 @@ impl InspectionRunner {
             .await
     }
 
+    async fn run_orphans_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        let inspector = orphans::OrphansInspector::new(&self.client, &self.options);
+        inspector.inspect(namespace).await
+    }
+
+    async fn run_resilience_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        resilience::ResilienceInspector::new(&self.client, &self.options)
+            .inspect(namespace)
+            .await
+    }
+
+    async fn run_gpu_inspection(&self, namespace: Option<&str>) -> Result<InspectionResult> {
+        gpu::GpuInspector::new(&self.client)
+            .inspect(namespace)
+            .await
+    }
+
+    async fn run_debug_settings_inspection(
+        &self,
+        namespace: Option<&str>,
+    ) -> Result<InspectionResult> {
+        let inspector = debug_settings::DebugSettingsInspector::new(&self.client, &self.options);
+        inspector.inspect(namespace).await
+    }
+
     async fn run_namespace_summary_inspection(&self) -> Result<InspectionResult> {
         namespace_summary::NamespaceSummaryInspector::new(&self.client)
             .inspect()
@@ -957,13 +2239,10 @@ impl InspectionRunner {
             .await
     }
 
+    /// Weighted by the selected scoring profile; failed modules are excluded.
     fn calculate_overall_score(&self, inspections: &[InspectionResult]) -> f64 {
-        if inspections.is_empty() {
-            return 0.0;
-        }
-
-        let total_score: f64 = inspections.iter().map(|i| i.overall_score).sum();
-        total_score / inspections.len() as f64
+        ScoringEngine::with_profile(self.options.scoring_profile.clone())
+            .calculate_weighted_score(inspections)
     }
 
     fn generate_executive_summary(
@@ -971,13 +2250,8 @@ impl InspectionRunner {
         inspections: &[InspectionResult],
         overall_score: f64,
     ) -> ExecutiveSummary {
-        let health_status = match overall_score {
-            s if s >= 90.0 => HealthStatus::Excellent,
-            s if s >= 80.0 => HealthStatus::Good,
-            s if s >= 70.0 => HealthStatus::Fair,
-            s if s >= 60.0 => HealthStatus::Poor,
-            _ => HealthStatus::Critical,
-        };
+        let health_status = ScoringEngine::with_profile(self.options.scoring_profile.clone())
+            .get_health_status(overall_score);
 
         let mut key_findings = Vec::new();
         let mut priority_recommendations = Vec::new();
@@ -1003,6 +2277,7 @@ impl InspectionRunner {
         priority_recommendations.truncate(5);
 
         ExecutiveSummary {
+            grade: Some(health_status.grade()),
             health_status,
             key_findings,
             priority_recommendations,
@@ -0,0 +1,276 @@
+//! Bounded issue lists for inspectors that walk every pod: keep the first findings of each
+//! issue code and only count the rest, so memory and report size stay flat on huge clusters.
+//! With [`PodOwners`], pod findings are rolled up to their controller while streaming: each
+//! controller row keeps one sample finding and a count of its affected pods.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::inspections::controllers::{self, Controller, PodOwners};
+use crate::inspections::types::{Issue, IssueSeverity};
+
+/// Default `--max-issues-per-rule`.
+pub const DEFAULT_MAX_ISSUES_PER_RULE: usize = 200;
+
+/// Modules whose findings `--max-issues-per-rule` caps.
+pub const SAMPLED_MODULES: &[&str] = &["Pod Status", "Resource Usage", "Security Configuration"];
+
+/// Key attribute of the "N more" summary issue; its fingerprint then leaves out the count.
+const OMITTED_KEY: &str = "omitted";
+
+/// Remediation commands a controller row collects from its pods' findings.
+const MAX_ROLLED_UP_COMMANDS: usize = 10;
+
+/// Drop-in for the `Vec<Issue>` an inspector pushes into. Issues beyond `max_per_rule` for one
+/// issue code (or category when there is none) are counted, and [`IssueSampler::into_issues`]
+/// adds one summary issue per code saying how many were left out. A controller row counts
+/// once against the cap however many of its pods are affected.
+#[derive(Debug)]
+pub struct IssueSampler<'a> {
+    /// 0 keeps every issue.
+    max_per_rule: usize,
+    owners: Option<&'a PodOwners>,
+    issues: Vec<Issue>,
+    kept: BTreeMap<String, usize>,
+    omitted: BTreeMap<String, Omitted>,
+    groups: HashMap<GroupKey, Group>,
+}
+
+#[derive(Debug)]
+struct Omitted {
+    count: usize,
+    severity: IssueSeverity,
+    category: String,
+    recommendation: String,
+    rule_id: Option<String>,
+}
+
+/// Issue code, key attributes, severity and owning controller of a pod finding.
+type GroupKey = (String, Vec<String>, String, Controller);
+
+/// Pod findings of one [`GroupKey`].
+#[derive(Debug)]
+struct Group {
+    /// Index of the sample finding in `issues`; None when the cap left the row out.
+    row: Option<usize>,
+    pods: usize,
+    /// Findings of one pod arrive together, so a new pod is one different from the last.
+    last_pod: String,
+}
+
+impl<'a> IssueSampler<'a> {
+    pub fn new(max_per_rule: usize) -> Self {
+        Self {
+            max_per_rule,
+            owners: None,
+            issues: Vec::new(),
+            kept: BTreeMap::new(),
+            omitted: BTreeMap::new(),
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Roll pod findings up to the controllers in `owners` (None keeps them per pod).
+    pub fn with_owners(mut self, owners: Option<&'a PodOwners>) -> Self {
+        self.owners = owners;
+        self
+    }
+
+    pub fn push(&mut self, issue: Issue) {
+        let Some((key, pod)) = self.group_key(&issue) else {
+            self.keep(issue);
+            return;
+        };
+        if let Some(group) = self.groups.get_mut(&key) {
+            if group.last_pod != pod {
+                group.pods += 1;
+                group.last_pod = pod;
+            }
+            if let Some(row) = group.row {
+                let commands = &mut self.issues[row].remediation_commands;
+                for cmd in issue.remediation_commands {
+                    if commands.len() < MAX_ROLLED_UP_COMMANDS && !commands.contains(&cmd) {
+                        commands.push(cmd);
+                    }
+                }
+            }
+            return;
+        }
+        let row = self.keep(issue);
+        self.groups.insert(
+            key,
+            Group {
+                row,
+                pods: 1,
+                last_pod: pod,
+            },
+        );
+    }
+
+    fn group_key(&self, issue: &Issue) -> Option<(GroupKey, String)> {
+        let pod = issue.resource.as_deref()?;
+        let controller = self.owners?.controller_of(pod)?;
+        Some((
+            (
+                issue.rule_id.clone()?,
+                issue.key_attributes.clone(),
+                format!("{:?}", issue.severity),
+                controller.clone(),
+            ),
+            pod.to_string(),
+        ))
+    }
+
+    /// Keep `issue` unless its code is at the cap; returns where it was kept.
+    fn keep(&mut self, issue: Issue) -> Option<usize> {
+        let key = issue
+            .rule_id
+            .clone()
+            .unwrap_or_else(|| issue.category.clone());
+        let kept = self.kept.entry(key.clone()).or_default();
+        if self.max_per_rule == 0 || *kept < self.max_per_rule {
+            *kept += 1;
+            self.issues.push(issue);
+            return Some(self.issues.len() - 1);
+        }
+        let omitted = self.omitted.entry(key).or_insert_with(|| Omitted {
+            count: 0,
+            severity: issue.severity.clone(),
+            category: issue.category.clone(),
+            recommendation: issue.recommendation.clone(),
+            rule_id: issue.rule_id.clone(),
+        });
+        omitted.count += 1;
+        None
+    }
+
+    /// Kept issues, with findings on two or more pods of a controller as one controller row,
+    /// followed by one "N more" issue per code that hit the cap.
+    pub fn into_issues(self) -> Vec<Issue> {
+        let max = self.max_per_rule;
+        let mut issues = self.issues;
+        for ((_, _, _, controller), group) in &self.groups {
+            let Some(row) = group.row.filter(|_| group.pods > 1) else {
+                continue;
+            };
+            let total = self
+                .owners
+                .and_then(|o| o.pod_count(controller))
+                .unwrap_or(group.pods);
+            issues[row] =
+                controllers::controller_issue(&issues[row], controller, group.pods, total);
+        }
+        for (key, o) in self.omitted {
+            issues.push(Issue {
+                severity: o.severity,
+                category: o.category,
+                description: format!(
+                    "{} more {} finding(s) not listed (first {} shown; raise --max-issues-per-rule to see all)",
+                    o.count, key, max
+                ),
+                resource: None,
+                recommendation: o.recommendation,
+                rule_id: o.rule_id,
+                team: None,
+                fingerprint: None,
+                key_attributes: vec![OMITTED_KEY.to_string()],
+                remediation_commands: Vec::new(),
+            });
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(rule: &str, resource: &str) -> Issue {
+        Issue::new(
+            IssueSeverity::Warning,
+            "Container",
+            rule,
+            format!("{} on {}", rule, resource),
+            resource.to_string(),
+            "Fix it",
+        )
+    }
+
+    #[test]
+    fn caps_issues_per_rule_and_summarizes_the_rest() {
+        let mut sampler = IssueSampler::new(2);
+        for i in 0..5 {
+            sampler.push(issue("RES-001", &format!("ns/pod-{}", i)));
+        }
+        sampler.push(issue("RES-002", "ns/pod-0"));
+
+        let issues = sampler.into_issues();
+        assert_eq!(issues.len(), 4);
+        assert_eq!(issues[0].resource.as_deref(), Some("ns/pod-0"));
+        assert_eq!(issues[1].resource.as_deref(), Some("ns/pod-1"));
+        assert_eq!(issues[2].rule_id.as_deref(), Some("RES-002"));
+        let summary = &issues[3];
+        assert_eq!(summary.rule_id.as_deref(), Some("RES-001"));
+        assert!(summary.resource.is_none());
+        assert!(summary.description.starts_with("3 more RES-001 finding(s)"));
+
+        // The fingerprint stays the same when the number left out changes.
+        let mut sampler = IssueSampler::new(2);
+        for i in 0..9 {
+            sampler.push(issue("RES-001", &format!("ns/pod-{}", i)));
+        }
+        let other = sampler.into_issues().pop().unwrap();
+        assert_ne!(other.description, summary.description);
+        assert_eq!(other.compute_fingerprint(), summary.compute_fingerprint());
+    }
+
+    #[test]
+    fn rolls_up_to_controllers_while_staying_bounded() {
+        use k8s_openapi::api::core::v1::Pod;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+        let pods: Vec<Pod> = (0..500)
+            .map(|i| Pod {
+                metadata: ObjectMeta {
+                    namespace: Some("shop".to_string()),
+                    name: Some(format!("db-{}", i)),
+                    owner_references: Some(vec![OwnerReference {
+                        kind: "StatefulSet".to_string(),
+                        name: "db".to_string(),
+                        controller: Some(true),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect();
+        let owners = PodOwners::from_objects(&pods, &[], &[]);
+        let mut sampler = IssueSampler::new(2).with_owners(Some(&owners));
+        for i in 0..500 {
+            sampler.push(issue("RES-002", &format!("shop/db-{}", i)));
+            sampler.push(issue("RES-001", &format!("shop/debug-{}", i)));
+        }
+        // One controller row plus two bare pod findings are held, not 1000 findings.
+        assert_eq!(sampler.issues.len(), 3);
+
+        let issues = sampler.into_issues();
+        assert_eq!(issues.len(), 4);
+        assert_eq!(issues[0].resource.as_deref(), Some("shop/db"));
+        assert!(issues[0]
+            .description
+            .starts_with("500 of 500 pod(s) of StatefulSet shop/db, e.g. "));
+        assert_eq!(issues[1].resource.as_deref(), Some("shop/debug-0"));
+        assert!(issues[3]
+            .description
+            .starts_with("498 more RES-001 finding(s)"));
+    }
+
+    #[test]
+    fn zero_keeps_everything() {
+        let mut sampler = IssueSampler::new(0);
+        for i in 0..300 {
+            sampler.push(issue("POD-001", &format!("ns/pod-{}", i)));
+        }
+        assert_eq!(sampler.into_issues().len(), 300);
+    }
+}
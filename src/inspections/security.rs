@@ -1,25 +1,43 @@
 use anyhow::Result;
 use chrono::Utc;
+use futures::TryStreamExt;
 use kube::api::ListParams;
 use log::info;
 
+use crate::inspections::controllers::PodOwners;
+use crate::inspections::options::InspectionOptions;
+use crate::inspections::sampling::IssueSampler;
 use crate::inspections::types::*;
+use crate::inspections::windows;
 use crate::k8s::K8sClient;
 
 pub struct SecurityInspector<'a> {
     client: &'a K8sClient,
+    options: &'a InspectionOptions,
+    owners: Option<&'a PodOwners>,
 }
 
 impl<'a> SecurityInspector<'a> {
-    pub fn new(client: &'a K8sClient) -> Self {
-        Self { client }
+    pub fn new(client: &'a K8sClient, options: &'a InspectionOptions) -> Self {
+        Self {
+            client,
+            options,
+            owners: None,
+        }
+    }
+
+    /// Roll pod findings up to the controllers in `owners` while reading pods.
+    pub fn with_pod_owners(mut self, owners: Option<&'a PodOwners>) -> Self {
+        self.owners = owners;
+        self
     }
 
     pub async fn inspect(&self, namespace: Option<&str>) -> Result<InspectionResult> {
         info!("Starting security configuration inspection");
 
         let mut checks = Vec::new();
-        let mut issues = Vec::new();
+        let mut issues =
+            IssueSampler::new(self.options.max_issues_per_rule).with_owners(self.owners);
 
         // Check RBAC configuration
         self.check_rbac_configuration(&mut checks, &mut issues)
@@ -39,7 +57,7 @@ impl<'a> SecurityInspector<'a> {
 
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
-        let summary = self.create_summary(&checks, issues);
+        let summary = self.create_summary(&checks, issues.into_issues());
 
         Ok(InspectionResult {
             inspection_type: "Security Configuration".to_string(),
@@ -50,17 +68,22 @@ impl<'a> SecurityInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
     async fn check_rbac_configuration(
         &self,
         checks: &mut Vec<CheckResult>,
-        issues: &mut Vec<Issue>,
+        issues: &mut IssueSampler<'_>,
     ) -> Result<()> {
         // Check ClusterRoles
         let cluster_roles_api = self.client.cluster_roles();
-        let cluster_roles = cluster_roles_api.list(&ListParams::default()).await?;
+        let cluster_roles = self
+            .client
+            .list_all(&cluster_roles_api, &ListParams::default())
+            .await?;
 
         let mut dangerous_cluster_roles = 0;
         let total_cluster_roles = cluster_roles.items.len();
@@ -89,6 +112,10 @@ impl<'a> SecurityInspector<'a> {
                                 resource: Some(role_name.to_string()),
                                 recommendation: "Review and restrict ClusterRole permissions to minimum required".to_string(),
                                 rule_id: Some("SEC-001".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: Vec::new(),
                             });
                         }
                         break;
@@ -99,8 +126,9 @@ impl<'a> SecurityInspector<'a> {
 
         // Check ClusterRoleBindings
         let cluster_role_bindings_api = self.client.cluster_role_bindings();
-        let cluster_role_bindings = cluster_role_bindings_api
-            .list(&ListParams::default())
+        let cluster_role_bindings = self
+            .client
+            .list_all(&cluster_role_bindings_api, &ListParams::default())
             .await?;
 
         let mut risky_bindings = 0;
@@ -125,6 +153,10 @@ impl<'a> SecurityInspector<'a> {
                                     "Minimize cluster-admin privileges and use more specific roles"
                                         .to_string(),
                                 rule_id: Some("SEC-002".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: vec![format!("user={}", subject.name)],
+                                remediation_commands: Vec::new(),
                             });
                         }
                         if subject.kind == "ServiceAccount"
@@ -143,6 +175,14 @@ impl<'a> SecurityInspector<'a> {
                                 recommendation: "Review and restrict ServiceAccount permissions"
                                     .to_string(),
                                 rule_id: Some("SEC-003".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: vec![format!(
+                                    "serviceaccount={}/{}",
+                                    subject.namespace.as_deref().unwrap_or("default"),
+                                    subject.name
+                                )],
+                                remediation_commands: Vec::new(),
                             });
                         }
                     }
@@ -191,106 +231,170 @@ impl<'a> SecurityInspector<'a> {
         &self,
         namespace: Option<&str>,
         checks: &mut Vec<CheckResult>,
-        issues: &mut Vec<Issue>,
+        issues: &mut IssueSampler<'_>,
     ) -> Result<()> {
         let pods_api = self.client.pods(namespace);
-        let pods = pods_api.list(&ListParams::default()).await?;
-
         let mut total_pods = 0;
         let mut secure_pods = 0;
         let mut pods_running_as_root = 0;
         let mut pods_with_privileged_containers = 0;
-
-        for pod in &pods.items {
-            let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
-            let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
-            total_pods += 1;
-
-            let mut pod_is_secure = true;
-
-            if let Some(spec) = &pod.spec {
-                // Check security context
-                if let Some(security_context) = &spec.security_context {
-                    if security_context.run_as_user.is_some()
-                        && security_context.run_as_user != Some(0)
-                    {
-                        // Good - not running as root
-                    } else if security_context.run_as_user == Some(0) {
-                        pods_running_as_root += 1;
+        let mut windows_pods = 0;
+
+        // Windows pods are matched by spec.os or by the node they run on; without node access
+        // only spec.os and the nodeSelector are used.
+        let windows_nodes = self
+            .client
+            .list_all(&self.client.nodes(), &ListParams::default())
+            .await
+            .map(|nodes| windows::windows_node_names(&nodes.items))
+            .unwrap_or_default();
+
+        let mut pages = self.client.list_pages(&pods_api, &ListParams::default());
+        while let Some(page) = pages.try_next().await? {
+            for pod in &page {
+                let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+                let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+                total_pods += 1;
+
+                let mut pod_is_secure = true;
+
+                // runAsUser, privileged and allowPrivilegeEscalation do not apply on Windows;
+                // HostProcess containers are the Windows equivalent of privileged.
+                if windows::is_windows_pod(pod, &windows_nodes) {
+                    windows_pods += 1;
+                    let host_process = windows::host_process_containers(pod);
+                    if !host_process.is_empty() {
+                        pods_with_privileged_containers += 1;
                         pod_is_secure = false;
+                    }
+                    for container in host_process {
                         issues.push(Issue {
                             severity: IssueSeverity::Warning,
                             category: "Security".to_string(),
                             description: format!(
-                                "Pod {}/{} runs as root user",
-                                pod_namespace, pod_name
+                                "Container {} in pod {}/{} runs as a Windows HostProcess container",
+                                container, pod_namespace, pod_name
                             ),
                             resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                            recommendation: "Configure runAsUser to use non-root user".to_string(),
-                            rule_id: Some("SEC-004".to_string()),
+                            recommendation:
+                                "Remove hostProcess unless the container must manage the node"
+                                    .to_string(),
+                            rule_id: Some("SEC-005".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: container_key(container),
+                            remediation_commands: Vec::new(),
                         });
                     }
-                } else {
-                    // No security context - potentially insecure
-                    pod_is_secure = false;
+                    if pod_is_secure {
+                        secure_pods += 1;
+                    }
+                    continue;
                 }
 
-                // Check containers
-                for container in &spec.containers {
-                    if let Some(security_context) = &container.security_context {
-                        if security_context.privileged == Some(true) {
-                            pods_with_privileged_containers += 1;
-                            pod_is_secure = false;
-                            issues.push(Issue {
-                                severity: IssueSeverity::Warning,
-                                category: "Security".to_string(),
-                                description: format!(
-                                    "Container {} in pod {}/{} runs in privileged mode",
-                                    container.name, pod_namespace, pod_name
-                                ),
-                                resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                recommendation: "Remove privileged flag unless absolutely necessary".to_string(),
-                                rule_id: Some("SEC-005".to_string()),
-                            });
-                        }
-
-                        if security_context.run_as_user == Some(0) {
+                if let Some(spec) = &pod.spec {
+                    // Check security context
+                    if let Some(security_context) = &spec.security_context {
+                        if security_context.run_as_user.is_some()
+                            && security_context.run_as_user != Some(0)
+                        {
+                            // Good - not running as root
+                        } else if security_context.run_as_user == Some(0) {
                             pods_running_as_root += 1;
                             pod_is_secure = false;
                             issues.push(Issue {
                                 severity: IssueSeverity::Warning,
                                 category: "Security".to_string(),
                                 description: format!(
-                                    "Container {} in pod {}/{} runs as root",
-                                    container.name, pod_namespace, pod_name
+                                    "Pod {}/{} runs as root user",
+                                    pod_namespace, pod_name
                                 ),
                                 resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                recommendation: "Configure container to run as non-root user"
+                                recommendation: "Configure runAsUser to use non-root user"
                                     .to_string(),
-                                rule_id: Some("SEC-006".to_string()),
+                                rule_id: Some("SEC-004".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: Vec::new(),
                             });
                         }
+                    } else {
+                        // No security context - potentially insecure
+                        pod_is_secure = false;
+                    }
 
-                        if security_context.allow_privilege_escalation == Some(true) {
-                            pod_is_secure = false;
-                            issues.push(Issue {
-                                severity: IssueSeverity::Warning,
-                                category: "Security".to_string(),
-                                description: format!(
-                                    "Container {} in pod {}/{} allows privilege escalation",
-                                    container.name, pod_namespace, pod_name
-                                ),
-                                resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                                recommendation: "Disable allowPrivilegeEscalation".to_string(),
-                                rule_id: Some("SEC-007".to_string()),
-                            });
+                    // Check containers
+                    for container in &spec.containers {
+                        if let Some(security_context) = &container.security_context {
+                            if security_context.privileged == Some(true) {
+                                pods_with_privileged_containers += 1;
+                                pod_is_secure = false;
+                                issues.push(Issue {
+                                    severity: IssueSeverity::Warning,
+                                    category: "Security".to_string(),
+                                    description: format!(
+                                        "Container {} in pod {}/{} runs in privileged mode",
+                                        container.name, pod_namespace, pod_name
+                                    ),
+                                    resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                    recommendation:
+                                        "Remove privileged flag unless absolutely necessary"
+                                            .to_string(),
+                                    rule_id: Some("SEC-005".to_string()),
+                                    team: None,
+                                    fingerprint: None,
+                                    key_attributes: container_key(&container.name),
+                                    remediation_commands: Vec::new(),
+                                });
+                            }
+
+                            if security_context.run_as_user == Some(0) {
+                                pods_running_as_root += 1;
+                                pod_is_secure = false;
+                                issues.push(Issue {
+                                    severity: IssueSeverity::Warning,
+                                    category: "Security".to_string(),
+                                    description: format!(
+                                        "Container {} in pod {}/{} runs as root",
+                                        container.name, pod_namespace, pod_name
+                                    ),
+                                    resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                    recommendation: "Configure container to run as non-root user"
+                                        .to_string(),
+                                    rule_id: Some("SEC-006".to_string()),
+                                    team: None,
+                                    fingerprint: None,
+                                    key_attributes: container_key(&container.name),
+                                    remediation_commands: Vec::new(),
+                                });
+                            }
+
+                            if security_context.allow_privilege_escalation == Some(true) {
+                                pod_is_secure = false;
+                                issues.push(Issue {
+                                    severity: IssueSeverity::Warning,
+                                    category: "Security".to_string(),
+                                    description: format!(
+                                        "Container {} in pod {}/{} allows privilege escalation",
+                                        container.name, pod_namespace, pod_name
+                                    ),
+                                    resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                                    recommendation: "Disable allowPrivilegeEscalation".to_string(),
+                                    rule_id: Some("SEC-007".to_string()),
+                                    team: None,
+                                    fingerprint: None,
+                                    key_attributes: container_key(&container.name),
+                                    remediation_commands: Vec::new(),
+                                });
+                            }
                         }
                     }
                 }
-            }
 
-            if pod_is_secure {
-                secure_pods += 1;
+                if pod_is_secure {
+                    secure_pods += 1;
+                }
             }
         }
 
@@ -311,8 +415,12 @@ impl<'a> SecurityInspector<'a> {
             score: pod_security_score,
             max_score: 100.0,
             details: Some(format!(
-                "Secure pods: {}/{}, Running as root: {}, Privileged: {}",
-                secure_pods, total_pods, pods_running_as_root, pods_with_privileged_containers
+                "Secure pods: {}/{}, Running as root: {}, Privileged: {}, Windows: {}",
+                secure_pods,
+                total_pods,
+                pods_running_as_root,
+                pods_with_privileged_containers,
+                windows_pods
             )),
             recommendations: if pod_security_score < 90.0 {
                 vec!["Configure security contexts for better pod security".to_string()]
@@ -328,13 +436,19 @@ impl<'a> SecurityInspector<'a> {
         &self,
         namespace: Option<&str>,
         checks: &mut Vec<CheckResult>,
-        issues: &mut Vec<Issue>,
+        issues: &mut IssueSampler<'_>,
     ) -> Result<()> {
         let network_policies_api = self.client.network_policies(namespace);
-        let network_policies = network_policies_api.list(&ListParams::default()).await?;
+        let network_policies = self
+            .client
+            .list_unselected(&network_policies_api, &ListParams::default())
+            .await?;
 
         let namespaces_api = self.client.namespaces();
-        let namespaces_list = namespaces_api.list(&ListParams::default()).await?;
+        let namespaces_list = self
+            .client
+            .list_all(&namespaces_api, &ListParams::default())
+            .await?;
 
         let total_namespaces = namespaces_list.items.len();
         let mut namespaces_with_policies = std::collections::HashSet::new();
@@ -359,6 +473,10 @@ impl<'a> SecurityInspector<'a> {
                 resource: Some("cluster".to_string()),
                 recommendation: "Implement network policies for traffic segmentation".to_string(),
                 rule_id: Some("SEC-008".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
         }
 
@@ -392,40 +510,45 @@ impl<'a> SecurityInspector<'a> {
         &self,
         namespace: Option<&str>,
         checks: &mut Vec<CheckResult>,
-        issues: &mut Vec<Issue>,
+        issues: &mut IssueSampler<'_>,
     ) -> Result<()> {
         let pods_api = self.client.pods(namespace);
-        let pods = pods_api.list(&ListParams::default()).await?;
-
         let mut total_pods = 0;
         let mut pods_with_custom_sa = 0;
         let mut _pods_with_default_sa = 0;
 
-        for pod in &pods.items {
-            let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
-            let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
-            total_pods += 1;
-
-            if let Some(spec) = &pod.spec {
-                let service_account = spec.service_account_name.as_deref().unwrap_or("default");
-
-                if service_account == "default" {
-                    _pods_with_default_sa += 1;
-                    issues.push(Issue {
-                        severity: IssueSeverity::Warning,
-                        category: "ServiceAccount".to_string(),
-                        description: format!(
-                            "Pod {}/{} uses default service account",
-                            pod_namespace, pod_name
-                        ),
-                        resource: Some(format!("{}/{}", pod_namespace, pod_name)),
-                        recommendation:
-                            "Create and use dedicated service accounts with minimal permissions"
-                                .to_string(),
-                        rule_id: Some("SEC-009".to_string()),
-                    });
-                } else {
-                    pods_with_custom_sa += 1;
+        let mut pages = self.client.list_pages(&pods_api, &ListParams::default());
+        while let Some(page) = pages.try_next().await? {
+            for pod in &page {
+                let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+                let pod_namespace = pod.metadata.namespace.as_deref().unwrap_or("default");
+                total_pods += 1;
+
+                if let Some(spec) = &pod.spec {
+                    let service_account = spec.service_account_name.as_deref().unwrap_or("default");
+
+                    if service_account == "default" {
+                        _pods_with_default_sa += 1;
+                        issues.push(Issue {
+                            severity: IssueSeverity::Warning,
+                            category: "ServiceAccount".to_string(),
+                            description: format!(
+                                "Pod {}/{} uses default service account",
+                                pod_namespace, pod_name
+                            ),
+                            resource: Some(format!("{}/{}", pod_namespace, pod_name)),
+                            recommendation:
+                                "Create and use dedicated service accounts with minimal permissions"
+                                    .to_string(),
+                            rule_id: Some("SEC-009".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
+                        });
+                    } else {
+                        pods_with_custom_sa += 1;
+                    }
                 }
             }
         }
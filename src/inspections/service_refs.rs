@@ -0,0 +1,552 @@
+//! Reference integrity between Ingresses, Services and pods for the Network Connectivity module:
+//! Ingress backends naming a Service or port that does not exist, Service target ports no selected
+//! pod exposes, and ExternalName Services whose target does not resolve. Each of these answers
+//! with 503s or connection refused while every object involved looks healthy on its own.
+
+use std::collections::HashMap;
+
+use k8s_openapi::api::core::v1::{Pod, Service, ServicePort};
+use k8s_openapi::api::networking::v1::{Ingress, IngressBackend};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+use crate::inspections::orphans::selector_matches;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+fn reference_check(
+    name: &str,
+    description: &str,
+    evaluated: usize,
+    flagged: usize,
+    detail_suffix: &str,
+    recommendation: &str,
+) -> CheckResult {
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} {}",
+            evaluated - flagged,
+            evaluated,
+            detail_suffix
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![recommendation.to_string()]
+        },
+    }
+}
+
+fn key(namespace: Option<&str>, name: Option<&str>) -> (String, String) {
+    (
+        namespace.unwrap_or("default").to_string(),
+        name.unwrap_or("unknown").to_string(),
+    )
+}
+
+/// Service backends of an Ingress (default backend first), skipping resource backends.
+fn service_backends(ingress: &Ingress) -> Vec<&IngressBackend> {
+    let Some(spec) = &ingress.spec else {
+        return Vec::new();
+    };
+    spec.default_backend
+        .iter()
+        .chain(
+            spec.rules
+                .iter()
+                .flatten()
+                .filter_map(|r| r.http.as_ref())
+                .flat_map(|h| h.paths.iter().map(|p| &p.backend)),
+        )
+        .filter(|b| b.service.is_some())
+        .collect()
+}
+
+/// NET-020 for Ingress backends naming a Service that does not exist, NET-021 for backends
+/// naming a port (number or name) the Service does not expose.
+pub fn ingress_backends(
+    ingresses: &[Ingress],
+    services: &[Service],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let by_name: HashMap<(String, String), &[ServicePort]> = services
+        .iter()
+        .map(|s| {
+            let ports = s
+                .spec
+                .as_ref()
+                .and_then(|s| s.ports.as_deref())
+                .unwrap_or(&[]);
+            (
+                key(s.metadata.namespace.as_deref(), s.metadata.name.as_deref()),
+                ports,
+            )
+        })
+        .collect();
+    let mut evaluated = 0usize;
+    let mut flagged = 0usize;
+    for ingress in ingresses {
+        let (ns, name) = key(
+            ingress.metadata.namespace.as_deref(),
+            ingress.metadata.name.as_deref(),
+        );
+        let resource = format!("{}/{}", ns, name);
+        let mut dangling = false;
+        let mut seen = Vec::new();
+        for backend in service_backends(ingress) {
+            let Some(svc) = &backend.service else {
+                continue;
+            };
+            let port = svc.port.as_ref();
+            let port_label = match (
+                port.and_then(|p| p.name.as_deref()),
+                port.and_then(|p| p.number),
+            ) {
+                (Some(name), _) => name.to_string(),
+                (None, Some(number)) => number.to_string(),
+                (None, None) => "(none)".to_string(),
+            };
+            if seen.contains(&(svc.name.clone(), port_label.clone())) {
+                continue;
+            }
+            seen.push((svc.name.clone(), port_label.clone()));
+            match by_name.get(&(ns.clone(), svc.name.clone())) {
+                None => {
+                    dangling = true;
+                    issues.push(Issue {
+                        key_attributes: vec![format!("service={}", svc.name)],
+                        ..Issue::new(
+                            IssueSeverity::Warning,
+                            "Ingress",
+                            "NET-020",
+                            format!(
+                                "Ingress {} routes to Service {}/{}, which does not exist",
+                                resource, ns, svc.name
+                            ),
+                            resource.clone(),
+                            "Create the Service or point the Ingress path at an existing one; requests to it get 503.",
+                        )
+                    });
+                }
+                Some(ports) => {
+                    let exposed = ports.iter().any(|p| match port {
+                        Some(bp) if bp.name.is_some() => p.name == bp.name,
+                        Some(bp) => bp.number == Some(p.port),
+                        None => false,
+                    });
+                    if !exposed {
+                        dangling = true;
+                        let available: Vec<String> = ports
+                            .iter()
+                            .map(|p| match &p.name {
+                                Some(n) => format!("{} ({})", p.port, n),
+                                None => p.port.to_string(),
+                            })
+                            .collect();
+                        issues.push(Issue {
+                            key_attributes: vec![
+                                format!("service={}", svc.name),
+                                format!("port={}", port_label),
+                            ],
+                            ..Issue::new(
+                                IssueSeverity::Warning,
+                                "Ingress",
+                                "NET-021",
+                                format!(
+                                    "Ingress {} routes to port {} of Service {}/{}, which exposes {}",
+                                    resource,
+                                    port_label,
+                                    ns,
+                                    svc.name,
+                                    if available.is_empty() {
+                                        "no ports".to_string()
+                                    } else {
+                                        available.join(", ")
+                                    }
+                                ),
+                                resource.clone(),
+                                "Use a port number or name the Service exposes.",
+                            )
+                        });
+                    }
+                }
+            }
+        }
+        evaluated += 1;
+        if dangling {
+            flagged += 1;
+        }
+    }
+    reference_check(
+        "Ingress Backends",
+        "Ingress backends reference existing Services and Service ports",
+        evaluated,
+        flagged,
+        "Ingresses with resolvable backends",
+        "Fix Ingress backends pointing at missing Services or ports (NET-020, NET-021).",
+    )
+}
+
+fn is_running(pod: &Pod) -> bool {
+    !matches!(
+        pod.status.as_ref().and_then(|s| s.phase.as_deref()),
+        Some("Succeeded" | "Failed")
+    )
+}
+
+/// Container ports (number, name) a pod declares.
+fn container_ports(pod: &Pod) -> Vec<(i32, Option<&str>)> {
+    pod.spec
+        .iter()
+        .flat_map(|s| &s.containers)
+        .flat_map(|c| c.ports.iter().flatten())
+        .map(|p| (p.container_port, p.name.as_deref()))
+        .collect()
+}
+
+/// NET-022 for Service target ports no selected pod exposes: a named targetPort no container
+/// declares (Warning; no endpoints are created for it), or a numeric one missing from pods that
+/// all declare their ports (Info; declaring ports is optional). Services selecting no pods are
+/// left to ORPH-003.
+pub fn service_target_ports(
+    services: &[Service],
+    pods: &[Pod],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut evaluated = 0usize;
+    let mut flagged = 0usize;
+    for svc in services {
+        let Some(spec) = &svc.spec else { continue };
+        let Some(selector) = spec.selector.as_ref().filter(|s| !s.is_empty()) else {
+            continue;
+        };
+        let (ns, name) = key(
+            svc.metadata.namespace.as_deref(),
+            svc.metadata.name.as_deref(),
+        );
+        let selected: Vec<&Pod> = pods
+            .iter()
+            .filter(|p| p.metadata.namespace.as_deref() == Some(ns.as_str()) && is_running(p))
+            .filter(|p| selector_matches(selector, p.metadata.labels.as_ref()))
+            .collect();
+        if selected.is_empty() {
+            continue;
+        }
+        evaluated += 1;
+        let resource = format!("{}/{}", ns, name);
+        let pod_names: Vec<&str> = selected
+            .iter()
+            .take(3)
+            .filter_map(|p| p.metadata.name.as_deref())
+            .collect();
+        let mut dangling = false;
+        for port in spec.ports.iter().flatten() {
+            let target = port
+                .target_port
+                .clone()
+                .unwrap_or(IntOrString::Int(port.port));
+            let (missing, severity) = match &target {
+                IntOrString::String(named) => (
+                    !selected
+                        .iter()
+                        .any(|p| container_ports(p).iter().any(|(_, n)| *n == Some(named))),
+                    IssueSeverity::Warning,
+                ),
+                IntOrString::Int(number) => (
+                    selected.iter().all(|p| {
+                        let ports = container_ports(p);
+                        !ports.is_empty() && !ports.iter().any(|(n, _)| n == number)
+                    }),
+                    IssueSeverity::Info,
+                ),
+            };
+            if !missing {
+                continue;
+            }
+            dangling = true;
+            let target = match &target {
+                IntOrString::String(s) => s.clone(),
+                IntOrString::Int(i) => i.to_string(),
+            };
+            issues.push(Issue {
+                key_attributes: vec![format!("port={}", port.port)],
+                ..Issue::new(
+                    severity,
+                    "Service",
+                    "NET-022",
+                    format!(
+                        "Service {} port {} targets port {}, which none of its {} selected pod(s) ({}) exposes",
+                        resource,
+                        port.port,
+                        target,
+                        selected.len(),
+                        pod_names.join(", ")
+                    ),
+                    resource.clone(),
+                    "Set targetPort to a containerPort (number or name) the selected pods declare.",
+                )
+            });
+        }
+        if dangling {
+            flagged += 1;
+        }
+    }
+    reference_check(
+        "Service Target Ports",
+        "Service target ports are exposed by the pods the Service selects",
+        evaluated,
+        flagged,
+        "Services whose target ports match their pods",
+        "Fix Service targetPorts that no selected pod exposes (NET-022).",
+    )
+}
+
+/// `(namespace, service)` an ExternalName pointing inside the cluster refers to, from
+/// `<service>.<namespace>.svc[.<cluster domain>]`.
+pub fn cluster_service_target(external_name: &str) -> Option<(String, String)> {
+    let name = external_name.trim_end_matches('.');
+    let (service, rest) = name.split_once('.')?;
+    let (namespace, rest) = rest.split_once('.')?;
+    (rest == "svc" || rest.starts_with("svc."))
+        .then(|| (namespace.to_string(), service.to_string()))
+}
+
+/// ExternalName Services: (`namespace/name`, externalName).
+pub fn external_names(services: &[Service]) -> Vec<(String, String)> {
+    services
+        .iter()
+        .filter_map(|s| {
+            let spec = s.spec.as_ref()?;
+            if spec.type_.as_deref() != Some("ExternalName") {
+                return None;
+            }
+            let (ns, name) = key(s.metadata.namespace.as_deref(), s.metadata.name.as_deref());
+            Some((format!("{}/{}", ns, name), spec.external_name.clone()?))
+        })
+        .collect()
+}
+
+/// NET-023 for ExternalName Services whose target did not resolve. `resolved` holds, per
+/// (`namespace/name`, externalName), whether the target resolved; None when it was not checked.
+pub fn external_name_check(
+    resolved: &[(String, String, Option<bool>)],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut evaluated = 0usize;
+    let mut flagged = 0usize;
+    for (resource, target, ok) in resolved {
+        let Some(ok) = ok else { continue };
+        evaluated += 1;
+        if !ok {
+            flagged += 1;
+            issues.push(Issue {
+                key_attributes: Vec::new(),
+                ..Issue::new(
+                    IssueSeverity::Warning,
+                    "Service",
+                    "NET-023",
+                    format!(
+                        "ExternalName Service {} points to {}, which does not resolve",
+                        resource, target
+                    ),
+                    resource.clone(),
+                    "Fix spec.externalName or remove the Service; clients get DNS errors for it.",
+                )
+            });
+        }
+    }
+    reference_check(
+        "ExternalName Services",
+        "ExternalName Services point to names that resolve",
+        evaluated,
+        flagged,
+        "ExternalName targets resolve",
+        "Fix ExternalName Services whose target does not resolve (NET-023).",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{Container, ContainerPort, PodSpec, ServiceSpec};
+    use k8s_openapi::api::networking::v1::{
+        HTTPIngressPath, HTTPIngressRuleValue, IngressRule, IngressServiceBackend, IngressSpec,
+        ServiceBackendPort,
+    };
+    use kube::api::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some("shop".to_string()),
+            name: Some(name.to_string()),
+            labels: Some(BTreeMap::from([("app".to_string(), name.to_string())])),
+            ..Default::default()
+        }
+    }
+
+    fn service(name: &str, port: i32, target: IntOrString) -> Service {
+        Service {
+            metadata: meta(name),
+            spec: Some(ServiceSpec {
+                selector: Some(BTreeMap::from([("app".to_string(), name.to_string())])),
+                ports: Some(vec![ServicePort {
+                    name: Some("http".to_string()),
+                    port,
+                    target_port: Some(target),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn path(service: &str, port: ServiceBackendPort) -> HTTPIngressPath {
+        HTTPIngressPath {
+            path: Some("/".to_string()),
+            path_type: "Prefix".to_string(),
+            backend: IngressBackend {
+                service: Some(IngressServiceBackend {
+                    name: service.to_string(),
+                    port: Some(port),
+                }),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn flags_dangling_ingress_backends_and_target_ports() {
+        let services = vec![
+            service("api", 80, IntOrString::String("web".to_string())),
+            service("web", 80, IntOrString::Int(8080)),
+        ];
+        let ingress = Ingress {
+            metadata: meta("shop"),
+            spec: Some(IngressSpec {
+                rules: Some(vec![IngressRule {
+                    host: Some("shop.example.com".to_string()),
+                    http: Some(HTTPIngressRuleValue {
+                        paths: vec![
+                            path(
+                                "web",
+                                ServiceBackendPort {
+                                    name: Some("http".to_string()),
+                                    number: None,
+                                },
+                            ),
+                            path(
+                                "api",
+                                ServiceBackendPort {
+                                    name: None,
+                                    number: Some(8080),
+                                },
+                            ),
+                            path(
+                                "cart",
+                                ServiceBackendPort {
+                                    name: None,
+                                    number: Some(80),
+                                },
+                            ),
+                        ],
+                    }),
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut issues = Vec::new();
+        let check = ingress_backends(&[ingress], &services, &mut issues);
+        let codes: Vec<&str> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(codes, vec!["NET-021", "NET-020"]);
+        assert!(issues[0].description.contains("exposes 80 (http)"));
+        assert_eq!(check.score, 0.0);
+
+        let pod = |name: &str, port: ContainerPort| Pod {
+            metadata: meta(name),
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    ports: Some(vec![port]),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pods = vec![
+            pod(
+                "api",
+                ContainerPort {
+                    container_port: 8080,
+                    name: Some("http".to_string()),
+                    ..Default::default()
+                },
+            ),
+            pod(
+                "web",
+                ContainerPort {
+                    container_port: 8080,
+                    ..Default::default()
+                },
+            ),
+        ];
+        let mut issues = Vec::new();
+        let check = service_target_ports(&services, &pods, &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].resource.as_deref(), Some("shop/api"));
+        assert!(issues[0].description.contains("targets port web"));
+        assert_eq!(
+            check.details.as_deref(),
+            Some("1/2 Services whose target ports match their pods")
+        );
+    }
+
+    #[test]
+    fn recognises_cluster_local_external_names() {
+        assert_eq!(
+            cluster_service_target("db.data.svc.cluster.local"),
+            Some(("data".to_string(), "db".to_string()))
+        );
+        assert_eq!(
+            cluster_service_target("db.data.svc"),
+            Some(("data".to_string(), "db".to_string()))
+        );
+        assert_eq!(cluster_service_target("db.example.com"), None);
+
+        let mut issues = Vec::new();
+        let check = external_name_check(
+            &[
+                (
+                    "shop/db".to_string(),
+                    "db.example.com".to_string(),
+                    Some(false),
+                ),
+                (
+                    "shop/mail".to_string(),
+                    "smtp.example.com".to_string(),
+                    None,
+                ),
+            ],
+            &mut issues,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("0/1 ExternalName targets resolve")
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Graceful shutdown and rollout settings. A StatefulSet killed without a grace period cannot
+//! flush or hand over, pods behind long-lived connections (WebSocket, gRPC, sticky sessions)
+//! without a preStop hook drop their clients before the endpoints are updated, and a rolling
+//! update that may not surge has to take a pod down before its replacement is up.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{PodTemplateSpec, Service};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+use crate::inspections::orphans::selector_matches;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+/// Service port names / appProtocols that carry long-lived connections (HA-016).
+const LONG_LIVED_PROTOCOLS: &[&str] = &["grpc", "ws", "wss", "websocket", "h2c", "http2"];
+
+/// Rolling update defaults of a Deployment without an explicit strategy.
+const DEFAULT_MAX_SURGE: &str = "25%";
+const DEFAULT_MAX_UNAVAILABLE: &str = "25%";
+
+fn key(namespace: Option<&String>, name: Option<&String>) -> String {
+    format!(
+        "{}/{}",
+        namespace.map_or("default", String::as_str),
+        name.map_or("unknown", String::as_str)
+    )
+}
+
+/// Why `service` carries long-lived connections, if it does.
+fn long_lived(service: &Service) -> Option<String> {
+    let spec = service.spec.as_ref()?;
+    if spec.session_affinity.as_deref() == Some("ClientIP") {
+        return Some("session affinity ClientIP".to_string());
+    }
+    spec.ports.iter().flatten().find_map(|p| {
+        let protocol = p
+            .app_protocol
+            .as_deref()
+            .map(|a| a.trim_start_matches("kubernetes.io/"))
+            .or(p.name.as_deref())?
+            .to_lowercase();
+        LONG_LIVED_PROTOCOLS
+            .iter()
+            .any(|l| protocol == *l || protocol.starts_with(&format!("{}-", l)))
+            .then(|| format!("port {}", p.name.as_deref().unwrap_or(&protocol)))
+    })
+}
+
+fn has_pre_stop(template: &PodTemplateSpec) -> bool {
+    template.spec.as_ref().is_some_and(|s| {
+        s.containers
+            .iter()
+            .any(|c| c.lifecycle.as_ref().is_some_and(|l| l.pre_stop.is_some()))
+    })
+}
+
+/// Pods allowed by `value` out of `replicas`; percentages round up for maxSurge and down for
+/// maxUnavailable, like the Deployment controller.
+fn resolve(value: &IntOrString, replicas: i32, round_up: bool) -> i32 {
+    match value {
+        IntOrString::Int(n) => *n,
+        IntOrString::String(s) => {
+            let pct: f64 = s.trim_end_matches('%').parse().unwrap_or(0.0);
+            let pods = pct * replicas as f64 / 100.0;
+            if round_up {
+                pods.ceil() as i32
+            } else {
+                pods.floor() as i32
+            }
+        }
+    }
+}
+
+/// "Graceful Shutdown" check: HA-015 per StatefulSet with `terminationGracePeriodSeconds: 0`,
+/// HA-016 per Deployment/StatefulSet selected by a long-lived-connection Service without a
+/// preStop hook, HA-017 per Deployment whose rolling update cannot surge (Critical with one
+/// replica, where every rollout is an outage).
+pub fn graceful_shutdown(
+    deployments: &[Deployment],
+    stateful_sets: &[StatefulSet],
+    services: &[Service],
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut evaluated = 0;
+    let mut flagged = 0;
+    let mut status = CheckStatus::Pass;
+
+    // Templates of every workload, for the Service match.
+    let mut templates: Vec<(String, &str, &PodTemplateSpec)> = Vec::new();
+    for d in deployments {
+        if let Some(spec) = &d.spec {
+            let id = key(d.metadata.namespace.as_ref(), d.metadata.name.as_ref());
+            templates.push((id, "Deployment", &spec.template));
+        }
+    }
+    for s in stateful_sets {
+        let Some(spec) = &s.spec else {
+            continue;
+        };
+        let id = key(s.metadata.namespace.as_ref(), s.metadata.name.as_ref());
+        templates.push((id.clone(), "StatefulSet", &spec.template));
+        evaluated += 1;
+        let grace = spec
+            .template
+            .spec
+            .as_ref()
+            .and_then(|p| p.termination_grace_period_seconds);
+        if grace == Some(0) {
+            flagged += 1;
+            issues.push(Issue::new(
+                IssueSeverity::Warning,
+                "Workload",
+                "HA-015",
+                format!(
+                    "StatefulSet {} has terminationGracePeriodSeconds 0; its pods are killed without flushing data or leaving their cluster",
+                    id
+                ),
+                id.clone(),
+                "Remove terminationGracePeriodSeconds: 0 (default 30) or set it to the time the application needs to shut down cleanly.",
+            ));
+        }
+    }
+
+    let mut without_pre_stop: BTreeMap<&str, (&str, String)> = BTreeMap::new();
+    for service in services {
+        let Some(reason) = long_lived(service) else {
+            continue;
+        };
+        let Some(selector) = service.spec.as_ref().and_then(|s| s.selector.as_ref()) else {
+            continue;
+        };
+        let namespace = service.metadata.namespace.as_deref().unwrap_or("default");
+        let service_name = service.metadata.name.as_deref().unwrap_or("unknown");
+        for (id, kind, template) in &templates {
+            let same_namespace = id.split('/').next() == Some(namespace);
+            if same_namespace
+                && !selector.is_empty()
+                && selector_matches(
+                    selector,
+                    template.metadata.as_ref().and_then(|m| m.labels.as_ref()),
+                )
+                && !has_pre_stop(template)
+            {
+                without_pre_stop
+                    .entry(id.as_str())
+                    .or_insert((kind, format!("Service {} ({})", service_name, reason)));
+            }
+        }
+    }
+    for (id, (kind, service)) in &without_pre_stop {
+        evaluated += 1;
+        flagged += 1;
+        issues.push(Issue::new(
+            IssueSeverity::Warning,
+            "Workload",
+            "HA-016",
+            format!(
+                "{} {} serves long-lived connections through {} but has no preStop hook; clients are cut off on every rollout",
+                kind, id, service
+            ),
+            id.to_string(),
+            "Add a preStop hook (e.g. sleep 10-15s) so endpoints are removed before the container stops, and drain connections on SIGTERM.",
+        ));
+    }
+
+    let default_surge = IntOrString::String(DEFAULT_MAX_SURGE.to_string());
+    let default_unavailable = IntOrString::String(DEFAULT_MAX_UNAVAILABLE.to_string());
+    for d in deployments {
+        let Some(spec) = &d.spec else {
+            continue;
+        };
+        let strategy = spec.strategy.as_ref();
+        if strategy.and_then(|s| s.type_.as_deref()) == Some("Recreate") {
+            continue;
+        }
+        evaluated += 1;
+        let replicas = spec.replicas.unwrap_or(1);
+        let rolling = strategy.and_then(|s| s.rolling_update.as_ref());
+        let surge = rolling
+            .and_then(|r| r.max_surge.as_ref())
+            .unwrap_or(&default_surge);
+        let unavailable = rolling
+            .and_then(|r| r.max_unavailable.as_ref())
+            .unwrap_or(&default_unavailable);
+        if resolve(surge, replicas, true) > 0 || resolve(unavailable, replicas, false) < 1 {
+            continue;
+        }
+        flagged += 1;
+        let id = key(d.metadata.namespace.as_ref(), d.metadata.name.as_ref());
+        let severity = if replicas <= 1 {
+            status = CheckStatus::Critical;
+            IssueSeverity::Critical
+        } else {
+            IssueSeverity::Warning
+        };
+        issues.push(Issue::new(
+            severity,
+            "Workload",
+            "HA-017",
+            format!(
+                "Deployment {} rolls out with maxSurge 0 and {} replica(s); pods are stopped before their replacements are ready",
+                id, replicas
+            ),
+            id.clone(),
+            "Set maxSurge to 1 (or 25%) and maxUnavailable to 0 so new pods are ready before old ones stop.",
+        ));
+    }
+
+    if flagged > 0 && status == CheckStatus::Pass {
+        status = CheckStatus::Warning;
+    }
+    CheckResult {
+        name: "Graceful Shutdown".to_string(),
+        description: "Termination grace periods, preStop hooks and rollout surge settings"
+            .to_string(),
+        status,
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} workload settings allow a graceful shutdown",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See HA-015..HA-017; let pods finish in-flight work before they stop.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{
+        DeploymentSpec, DeploymentStrategy, RollingUpdateDeployment, StatefulSetSpec,
+    };
+    use k8s_openapi::api::core::v1::{PodSpec, ServicePort, ServiceSpec};
+    use kube::api::ObjectMeta;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some("shop".to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn template(app: &str, grace: Option<i64>) -> PodTemplateSpec {
+        PodTemplateSpec {
+            metadata: Some(ObjectMeta {
+                labels: Some([("app".to_string(), app.to_string())].into()),
+                ..Default::default()
+            }),
+            spec: Some(PodSpec {
+                termination_grace_period_seconds: grace,
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn deployment(name: &str, replicas: i32, surge: i32, unavailable: i32) -> Deployment {
+        Deployment {
+            metadata: meta(name),
+            spec: Some(DeploymentSpec {
+                replicas: Some(replicas),
+                template: template(name, None),
+                strategy: Some(DeploymentStrategy {
+                    type_: Some("RollingUpdate".to_string()),
+                    rolling_update: Some(RollingUpdateDeployment {
+                        max_surge: Some(IntOrString::Int(surge)),
+                        max_unavailable: Some(IntOrString::Int(unavailable)),
+                    }),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_hard_kills_missing_pre_stop_and_no_surge_rollouts() {
+        let deployments = vec![
+            deployment("chat", 3, 1, 0),
+            deployment("api", 1, 0, 1),
+            deployment("web", 4, 0, 1),
+        ];
+        let stateful_sets = vec![StatefulSet {
+            metadata: meta("db"),
+            spec: Some(StatefulSetSpec {
+                template: template("db", Some(0)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let services = vec![Service {
+            metadata: meta("chat"),
+            spec: Some(ServiceSpec {
+                selector: Some([("app".to_string(), "chat".to_string())].into()),
+                ports: Some(vec![ServicePort {
+                    name: Some("ws".to_string()),
+                    port: 80,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let mut issues = Vec::new();
+        let check = graceful_shutdown(&deployments, &stateful_sets, &services, &mut issues);
+        let found: Vec<(&str, &str, &IssueSeverity)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                    &i.severity,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("HA-015", "shop/db", &IssueSeverity::Warning),
+                ("HA-016", "shop/chat", &IssueSeverity::Warning),
+                ("HA-017", "shop/api", &IssueSeverity::Critical),
+                ("HA-017", "shop/web", &IssueSeverity::Warning),
+            ]
+        );
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(resolve(&IntOrString::String("25%".to_string()), 3, true), 1);
+        assert_eq!(
+            resolve(&IntOrString::String("25%".to_string()), 3, false),
+            0
+        );
+    }
+}
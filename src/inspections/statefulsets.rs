@@ -0,0 +1,429 @@
+//! StatefulSet-specific checks. A StatefulSet keeps identity and data per replica, so mistakes
+//! that a Deployment shrugs off stick: claims without a usable or durable StorageClass, an
+//! `OnDelete` update strategy that silently leaves pods on the old revision, a governing Service
+//! that is missing or not headless (no stable per-pod DNS), and replicas whose first placement
+//! pins their zonal volumes, and with them the pods, to one zone for good.
+
+use std::collections::HashMap;
+
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1::{PodSpec, Service};
+use k8s_openapi::api::storage::v1::StorageClass;
+
+use crate::inspections::resilience::ZONE_LABEL;
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+
+const DEFAULT_CLASS_ANNOTATIONS: [&str; 2] = [
+    "storageclass.kubernetes.io/is-default-class",
+    "storageclass.beta.kubernetes.io/is-default-class",
+];
+
+/// Provisioners whose volumes live on one node's disk: the data is gone with the node and the
+/// pod cannot move (STS-002).
+const NODE_LOCAL_PROVISIONERS: &[&str] = &[
+    "kubernetes.io/no-provisioner",
+    "rancher.io/local-path",
+    "openebs.io/local",
+    "k8s.io/minikube-hostpath",
+    "docker.io/hostpath",
+    "microk8s.io/hostpath",
+    "topolvm.io",
+    "topolvm.cybozu.com",
+    "local.csi.openebs.io",
+    "lvm.csi.metal-stack.io",
+];
+
+fn is_default(class: &StorageClass) -> bool {
+    class.metadata.annotations.as_ref().is_some_and(|a| {
+        DEFAULT_CLASS_ANNOTATIONS
+            .iter()
+            .any(|k| a.get(*k).map(String::as_str) == Some("true"))
+    })
+}
+
+/// True when the pod template spreads replicas over zones, with a topologySpreadConstraint or a
+/// (required or preferred) podAntiAffinity term on the zone label.
+fn spreads_over_zones(spec: &PodSpec) -> bool {
+    let constraint = spec
+        .topology_spread_constraints
+        .iter()
+        .flatten()
+        .any(|c| c.topology_key == ZONE_LABEL);
+    let anti_affinity = spec
+        .affinity
+        .as_ref()
+        .and_then(|a| a.pod_anti_affinity.as_ref())
+        .is_some_and(|aa| {
+            aa.required_during_scheduling_ignored_during_execution
+                .iter()
+                .flatten()
+                .any(|t| t.topology_key == ZONE_LABEL)
+                || aa
+                    .preferred_during_scheduling_ignored_during_execution
+                    .iter()
+                    .flatten()
+                    .any(|t| t.pod_affinity_term.topology_key == ZONE_LABEL)
+        });
+    constraint || anti_affinity
+}
+
+/// Findings for one StatefulSet; storage findings (STS-001, STS-002) only when `classes` is known.
+fn statefulset_findings(
+    sts: &StatefulSet,
+    services: &HashMap<(&str, &str), &Service>,
+    classes: Option<&[StorageClass]>,
+    multi_zone: bool,
+    out: &mut Vec<Issue>,
+) {
+    let Some(spec) = &sts.spec else { return };
+    let ns = sts.metadata.namespace.as_deref().unwrap_or("default");
+    let name = sts.metadata.name.as_deref().unwrap_or("unknown");
+    let resource = format!("{}/{}", ns, name);
+
+    if let Some(classes) = classes {
+        let default = classes.iter().find(|c| is_default(c));
+        for claim in spec.volume_claim_templates.iter().flatten() {
+            let claim_name = claim.metadata.name.as_deref().unwrap_or("unknown");
+            let requested = claim
+                .spec
+                .as_ref()
+                .and_then(|s| s.storage_class_name.as_deref());
+            let class = match requested {
+                // An empty class name asks for a pre-provisioned PV without class.
+                Some("") => continue,
+                Some(requested) => classes
+                    .iter()
+                    .find(|c| c.metadata.name.as_deref() == Some(requested)),
+                None => default,
+            };
+            let Some(class) = class else {
+                out.push(Issue {
+                    key_attributes: vec![format!("volume-claim-template={}", claim_name)],
+                    ..Issue::new(
+                        IssueSeverity::Warning,
+                        "StatefulSet",
+                        "STS-001",
+                        match requested {
+                            Some(requested) => format!(
+                                "StatefulSet {} volumeClaimTemplate {} requests StorageClass {}, which does not exist",
+                                resource, claim_name, requested
+                            ),
+                            None => format!(
+                                "StatefulSet {} volumeClaimTemplate {} sets no storageClassName and the cluster has no default StorageClass",
+                                resource, claim_name
+                            ),
+                        },
+                        resource.to_string(),
+                        "Set storageClassName to an existing StorageClass (or mark one as default); claims of new replicas stay Pending otherwise.",
+                    )
+                });
+                continue;
+            };
+            if NODE_LOCAL_PROVISIONERS.contains(&class.provisioner.as_str()) {
+                out.push(Issue {
+                    key_attributes: vec![format!("volume-claim-template={}", claim_name)],
+                    ..Issue::new(
+                        IssueSeverity::Warning,
+                        "StatefulSet",
+                        "STS-002",
+                        format!(
+                            "StatefulSet {} volumeClaimTemplate {} uses StorageClass {} ({}), whose volumes live on a single node",
+                            resource,
+                            claim_name,
+                            class.metadata.name.as_deref().unwrap_or("unknown"),
+                            class.provisioner
+                        ),
+                        resource.to_string(),
+                        "Use a network-attached or replicated StorageClass, or make sure the application replicates its data across replicas.",
+                    )
+                });
+            }
+        }
+    }
+
+    let strategy = spec
+        .update_strategy
+        .as_ref()
+        .and_then(|s| s.type_.as_deref());
+    if strategy == Some("OnDelete") {
+        let status = sts.status.as_ref();
+        let current = status.and_then(|s| s.current_revision.as_deref());
+        let update = status.and_then(|s| s.update_revision.as_deref());
+        let pending = matches!((current, update), (Some(c), Some(u)) if c != u);
+        let updated = status.and_then(|s| s.updated_replicas).unwrap_or(0);
+        let replicas = status.map(|s| s.replicas).unwrap_or(0);
+        out.push(Issue::new(
+            if pending {
+                IssueSeverity::Warning
+            } else {
+                IssueSeverity::Info
+            },
+            "StatefulSet",
+            "STS-003",
+            if pending {
+                format!(
+                    "StatefulSet {} uses the OnDelete update strategy and {}/{} pods run the latest revision",
+                    resource, updated, replicas
+                )
+            } else {
+                format!(
+                    "StatefulSet {} uses the OnDelete update strategy; template changes only apply to pods deleted by hand",
+                    resource
+                )
+            },
+            resource.to_string(),
+            "Use RollingUpdate (with partition for staged rollouts) unless pods are deliberately replaced by an operator.",
+        ));
+    }
+
+    let service_name = Some(spec.service_name.as_str()).filter(|s| !s.is_empty());
+    let governing = service_name.map(|s| services.get(&(ns, s)));
+    let headless =
+        |svc: &&Service| svc.spec.as_ref().and_then(|s| s.cluster_ip.as_deref()) == Some("None");
+    let problem = match governing {
+        None => Some("sets no serviceName".to_string()),
+        Some(None) => Some(format!(
+            "names governing Service {}, which does not exist",
+            service_name.unwrap_or_default()
+        )),
+        Some(Some(svc)) if !headless(svc) => Some(format!(
+            "names governing Service {}, which is not headless",
+            service_name.unwrap_or_default()
+        )),
+        Some(Some(_)) => None,
+    };
+    if let Some(problem) = problem {
+        out.push(Issue::new(
+            IssueSeverity::Warning,
+            "StatefulSet",
+            "STS-004",
+            format!("StatefulSet {} {}", resource, problem),
+            resource.to_string(),
+            "Create a headless Service (clusterIP: None) selecting the pods and set it as serviceName, so each replica gets a stable DNS name.",
+        ));
+    }
+
+    let replicas = spec.replicas.unwrap_or(1);
+    if multi_zone && replicas >= 2 && !spec.template.spec.as_ref().is_some_and(spreads_over_zones) {
+        out.push(Issue::new(
+            IssueSeverity::Warning,
+            "StatefulSet",
+            "STS-005",
+            format!(
+                "StatefulSet {} has {} replicas and no zone topologySpreadConstraint or podAntiAffinity",
+                resource, replicas
+            ),
+            resource.to_string(),
+            "Add a topologySpreadConstraint on topology.kubernetes.io/zone; zonal volumes keep replicas in the zone they were first placed in.",
+        ));
+    }
+}
+
+/// "StatefulSets" check: STS-001 (claim without a usable StorageClass), STS-002 (node-local
+/// storage), STS-003 (OnDelete updates), STS-004 (governing Service missing or not headless) and
+/// STS-005 (no zone spread on a multi-zone cluster). `classes` is None when StorageClasses could
+/// not be listed; the storage checks are skipped then.
+pub fn statefulset_check(
+    stateful_sets: &[StatefulSet],
+    services: &[Service],
+    classes: Option<&[StorageClass]>,
+    multi_zone: bool,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let services: HashMap<(&str, &str), &Service> = services
+        .iter()
+        .filter_map(|s| {
+            Some((
+                (
+                    s.metadata.namespace.as_deref()?,
+                    s.metadata.name.as_deref()?,
+                ),
+                s,
+            ))
+        })
+        .collect();
+    let mut flagged = 0usize;
+    for sts in stateful_sets {
+        let mut found = Vec::new();
+        statefulset_findings(sts, &services, classes, multi_zone, &mut found);
+        if found.iter().any(|i| i.severity != IssueSeverity::Info) {
+            flagged += 1;
+        }
+        issues.extend(found);
+    }
+    let evaluated = stateful_sets.len();
+    CheckResult {
+        name: "StatefulSets".to_string(),
+        description: "StatefulSet storage, update strategy, governing Service and zone spread"
+            .to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if evaluated == 0 {
+            100.0
+        } else {
+            (evaluated - flagged) as f64 / evaluated as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(format!(
+            "{}/{} StatefulSets without storage, update or Service findings",
+            evaluated - flagged,
+            evaluated
+        )),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec!["See STS-001..STS-005.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{
+        StatefulSetSpec, StatefulSetStatus, StatefulSetUpdateStrategy,
+    };
+    use k8s_openapi::api::core::v1::{
+        PersistentVolumeClaim, PersistentVolumeClaimSpec, PodTemplateSpec, ServiceSpec,
+    };
+    use kube::api::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            namespace: Some("data".to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn class(name: &str, provisioner: &str, default: bool) -> StorageClass {
+        StorageClass {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                annotations: default.then(|| {
+                    BTreeMap::from([(DEFAULT_CLASS_ANNOTATIONS[0].to_string(), "true".to_string())])
+                }),
+                ..Default::default()
+            },
+            provisioner: provisioner.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn sts(name: &str, service: &str, claim_class: Option<&str>) -> StatefulSet {
+        StatefulSet {
+            metadata: meta(name),
+            spec: Some(StatefulSetSpec {
+                replicas: Some(3),
+                service_name: service.to_string(),
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec::default()),
+                    ..Default::default()
+                },
+                volume_claim_templates: Some(vec![PersistentVolumeClaim {
+                    metadata: ObjectMeta {
+                        name: Some("data".to_string()),
+                        ..Default::default()
+                    },
+                    spec: Some(PersistentVolumeClaimSpec {
+                        storage_class_name: claim_class.map(str::to_string),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn flags_storage_update_service_and_zone_findings() {
+        let classes = vec![
+            class("gp3", "ebs.csi.aws.com", true),
+            class("local-path", "rancher.io/local-path", false),
+        ];
+        let services = vec![
+            Service {
+                metadata: meta("db"),
+                spec: Some(ServiceSpec {
+                    cluster_ip: Some("None".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            Service {
+                metadata: meta("cache"),
+                spec: Some(ServiceSpec {
+                    cluster_ip: Some("10.0.0.12".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ];
+        let mut on_delete = sts("db", "db", None);
+        if let Some(spec) = on_delete.spec.as_mut() {
+            spec.update_strategy = Some(StatefulSetUpdateStrategy {
+                type_: Some("OnDelete".to_string()),
+                rolling_update: None,
+            });
+            spec.template.spec = Some(PodSpec {
+                topology_spread_constraints: Some(vec![
+                    k8s_openapi::api::core::v1::TopologySpreadConstraint {
+                        topology_key: ZONE_LABEL.to_string(),
+                        max_skew: 1,
+                        when_unsatisfiable: "ScheduleAnyway".to_string(),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            });
+        }
+        on_delete.status = Some(StatefulSetStatus {
+            replicas: 3,
+            updated_replicas: Some(1),
+            current_revision: Some("db-1".to_string()),
+            update_revision: Some("db-2".to_string()),
+            ..Default::default()
+        });
+        let stateful_sets = vec![
+            on_delete,
+            sts("cache", "cache", Some("local-path")),
+            sts("queue", "queue", Some("fast")),
+        ];
+        let mut issues = Vec::new();
+        let check = statefulset_check(&stateful_sets, &services, Some(&classes), true, &mut issues);
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.rule_id.as_deref().unwrap(),
+                    i.resource.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("STS-003", "data/db"),
+                ("STS-002", "data/cache"),
+                ("STS-004", "data/cache"),
+                ("STS-005", "data/cache"),
+                ("STS-001", "data/queue"),
+                ("STS-004", "data/queue"),
+                ("STS-005", "data/queue"),
+            ]
+        );
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert!(issues[0].description.contains("1/3 pods"));
+        assert_eq!(check.score, 0.0);
+
+        let mut issues = Vec::new();
+        statefulset_check(&stateful_sets[..1], &services, None, false, &mut issues);
+        assert_eq!(issues.len(), 1);
+    }
+}
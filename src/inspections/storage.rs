@@ -1,8 +1,14 @@
 use anyhow::Result;
 use chrono::Utc;
+use k8s_openapi::api::core::v1::{Node, PersistentVolume};
+use k8s_openapi::api::storage::v1::StorageClass;
 use kube::api::ListParams;
-use log::info;
+use log::{info, warn};
 
+use std::collections::HashSet;
+
+use crate::inspections::pvc_usage::{collect_pvc_usage, evaluate_pvc_usage};
+use crate::inspections::storage_classes::{controller_running, validate_storage_classes};
 use crate::inspections::types::*;
 use crate::k8s::K8sClient;
 
@@ -23,7 +29,10 @@ impl<'a> StorageInspector<'a> {
 
         // Check Persistent Volumes
         let pv_api = self.client.persistent_volumes();
-        let pvs = pv_api.list(&ListParams::default()).await?;
+        let pvs = self
+            .client
+            .list_all(&pv_api, &ListParams::default())
+            .await?;
 
         let mut total_pvs = 0;
         let mut available_pvs = 0;
@@ -51,6 +60,10 @@ impl<'a> StorageInspector<'a> {
                             recommendation: "Check PV configuration and underlying storage"
                                 .to_string(),
                             rule_id: Some("STO-001".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                     Some("Released") => {
@@ -65,6 +78,10 @@ impl<'a> StorageInspector<'a> {
                             recommendation: "Check reclaim policy and clean up released PVs"
                                 .to_string(),
                             rule_id: Some("STO-002".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: vec![format!("kubectl delete pv {}", pv_name)],
                         });
                     }
                     _ => {}
@@ -91,6 +108,13 @@ impl<'a> StorageInspector<'a> {
                                 recommendation: "Monitor and clean up retained PVs manually"
                                     .to_string(),
                                 rule_id: Some("STO-003".to_string()),
+                                team: None,
+                                fingerprint: None,
+                                key_attributes: Vec::new(),
+                                remediation_commands: vec![format!(
+                                    "kubectl delete pv {}",
+                                    pv_name
+                                )],
                             });
                         }
                     }
@@ -103,6 +127,10 @@ impl<'a> StorageInspector<'a> {
                             recommendation: "Set explicit reclaim policy (Retain or Delete)"
                                 .to_string(),
                             rule_id: Some("STO-004".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                 }
@@ -111,7 +139,10 @@ impl<'a> StorageInspector<'a> {
 
         // Check Persistent Volume Claims
         let pvc_api = self.client.persistent_volume_claims(namespace);
-        let pvcs = pvc_api.list(&ListParams::default()).await?;
+        let pvcs = self
+            .client
+            .list_all(&pvc_api, &ListParams::default())
+            .await?;
 
         let mut total_pvcs = 0;
         let mut bound_pvcs = 0;
@@ -135,6 +166,10 @@ impl<'a> StorageInspector<'a> {
                             recommendation: "Check storage class availability and node capacity"
                                 .to_string(),
                             rule_id: Some("STO-005".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                     Some("Lost") => {
@@ -145,6 +180,10 @@ impl<'a> StorageInspector<'a> {
                             resource: Some(format!("{}/{}", pvc_namespace, pvc_name)),
                             recommendation: "Data may be lost, check backup and recovery procedures".to_string(),
                             rule_id: Some("STO-006".to_string()),
+                            team: None,
+                            fingerprint: None,
+                            key_attributes: Vec::new(),
+                            remediation_commands: Vec::new(),
                         });
                     }
                     _ => {}
@@ -165,6 +204,10 @@ impl<'a> StorageInspector<'a> {
                         recommendation: "Specify storage class for better provisioning control"
                             .to_string(),
                         rule_id: Some("STO-007".to_string()),
+                        team: None,
+                        fingerprint: None,
+                        key_attributes: Vec::new(),
+                        remediation_commands: Vec::new(),
                     });
                 }
             }
@@ -172,10 +215,14 @@ impl<'a> StorageInspector<'a> {
 
         // Check Storage Classes
         let sc_api = self.client.storage_classes();
-        let storage_classes = sc_api.list(&ListParams::default()).await?;
+        let storage_classes = self
+            .client
+            .list_all(&sc_api, &ListParams::default())
+            .await?;
 
         let mut total_storage_classes = 0;
         let mut default_storage_classes = 0;
+        let mut default_names: Vec<&str> = Vec::new();
 
         for sc in &storage_classes.items {
             let sc_name = sc.metadata.name.as_deref().unwrap_or("unknown");
@@ -186,6 +233,7 @@ impl<'a> StorageInspector<'a> {
                     == Some(&"true".to_string())
                 {
                     default_storage_classes += 1;
+                    default_names.push(sc_name);
                 }
             }
 
@@ -198,12 +246,22 @@ impl<'a> StorageInspector<'a> {
                     resource: Some(sc_name.to_string()),
                     recommendation: "Configure proper provisioner for storage class".to_string(),
                     rule_id: Some("STO-008".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
                 });
             }
         }
 
         // Check for proper default storage class configuration
         if default_storage_classes == 0 {
+            // Name the class to mark only when there is a single candidate.
+            let candidate = match storage_classes.items.as_slice() {
+                [only] => only.metadata.name.as_deref().unwrap_or("<name>"),
+                _ => "<name>",
+            };
+            let patch = r#"{"metadata":{"annotations":{"storageclass.kubernetes.io/is-default-class":"true"}}}"#;
             issues.push(Issue {
                 severity: IssueSeverity::Warning,
                 category: "StorageClass".to_string(),
@@ -212,6 +270,13 @@ impl<'a> StorageInspector<'a> {
                 recommendation: "Configure a default storage class for automatic PV provisioning"
                     .to_string(),
                 rule_id: Some("STO-009".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: vec![format!(
+                    "kubectl patch storageclass {} -p '{}'",
+                    candidate, patch
+                )],
             });
         } else if default_storage_classes > 1 {
             issues.push(Issue {
@@ -221,12 +286,33 @@ impl<'a> StorageInspector<'a> {
                     "{} default storage classes configured",
                     default_storage_classes
                 ),
-                resource: None,
+                resource: Some(default_names.join(", ")),
                 recommendation: "Only one storage class should be marked as default".to_string(),
                 rule_id: Some("STO-010".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
             });
         }
 
+        // CSI driver / binding mode / orphaned PV validation
+        let nodes = self
+            .client
+            .list_all(&self.client.nodes(), &ListParams::default())
+            .await?;
+        if let Some(check) = self
+            .validate_csi(
+                &storage_classes.items,
+                &pvs.items,
+                &nodes.items,
+                &mut issues,
+            )
+            .await
+        {
+            checks.push(check);
+        }
+
         // PV health check
         let pv_health_score = if total_pvs > 0 {
             ((total_pvs - failed_pvs) as f64 / total_pvs as f64) * 100.0
@@ -316,6 +402,27 @@ impl<'a> StorageInspector<'a> {
             },
         });
 
+        // PVC fill level from kubelet stats (skipped when node proxy is not permitted)
+        let summaries: Vec<_> = self
+            .client
+            .fan_out(
+                nodes
+                    .items
+                    .iter()
+                    .filter_map(|n| n.metadata.name.as_deref()),
+                |name| self.client.node_stats_summary(name),
+            )
+            .await
+            .into_iter()
+            .filter_map(|s| s.ok().flatten())
+            .collect();
+        if let Some((check, pvc_issues)) =
+            evaluate_pvc_usage(&collect_pvc_usage(&summaries, namespace))
+        {
+            checks.push(check);
+            issues.extend(pvc_issues);
+        }
+
         let overall_score = checks.iter().map(|c| c.score).sum::<f64>() / checks.len() as f64;
 
         let summary = self.create_summary(&checks, issues);
@@ -329,9 +436,104 @@ impl<'a> StorageInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
+    /// StorageClass/CSI validation (STO-013..016). Returns None when CSIDrivers cannot be listed.
+    async fn validate_csi(
+        &self,
+        storage_classes: &[StorageClass],
+        pvs: &[PersistentVolume],
+        nodes: &[Node],
+        issues: &mut Vec<Issue>,
+    ) -> Option<CheckResult> {
+        let csi_drivers: HashSet<String> = match self
+            .client
+            .list_all(&self.client.csi_drivers(), &ListParams::default())
+            .await
+        {
+            Ok(list) => list
+                .items
+                .into_iter()
+                .filter_map(|d| d.metadata.name)
+                .collect(),
+            Err(e) => {
+                warn!("Cannot list CSIDrivers, skipping CSI validation: {}", e);
+                return None;
+            }
+        };
+        let leases = self
+            .client
+            .list_unselected(&self.client.leases(None), &ListParams::default())
+            .await
+            .map(|l| l.items)
+            .unwrap_or_default();
+        let pods = self
+            .client
+            .list_unselected(&self.client.pods(None), &ListParams::default())
+            .await
+            .map(|l| l.items)
+            .unwrap_or_default();
+        let now = Utc::now();
+        let running_controllers: HashSet<String> = storage_classes
+            .iter()
+            .map(|sc| sc.provisioner.clone())
+            .filter(|p| controller_running(p, &leases, &pods, now))
+            .collect();
+        let zones: HashSet<&str> = nodes
+            .iter()
+            .filter_map(|n| n.metadata.labels.as_ref())
+            .filter_map(|l| l.get("topology.kubernetes.io/zone"))
+            .map(|z| z.as_str())
+            .collect();
+
+        let found = validate_storage_classes(
+            storage_classes,
+            &csi_drivers,
+            &running_controllers,
+            zones.len(),
+            pvs,
+        );
+        let affected: HashSet<&str> = found.iter().filter_map(|i| i.resource.as_deref()).collect();
+        let total = storage_classes.len() + pvs.len();
+        let score = if total > 0 {
+            ((total - affected.len()) as f64 / total as f64) * 100.0
+        } else {
+            100.0
+        };
+        let check = CheckResult {
+            name: "CSI Driver Validation".to_string(),
+            description:
+                "Checks StorageClass provisioners against CSIDriver objects and running controllers, binding mode and orphaned PVs"
+                    .to_string(),
+            status: if found.iter().any(|i| i.severity == IssueSeverity::Critical) {
+                CheckStatus::Critical
+            } else if !found.is_empty() {
+                CheckStatus::Warning
+            } else {
+                CheckStatus::Pass
+            },
+            score,
+            max_score: 100.0,
+            details: Some(format!(
+                "{} CSIDriver(s), {} zone(s), {} finding(s)",
+                csi_drivers.len(),
+                zones.len(),
+                found.len()
+            )),
+            recommendations: if found.is_empty() {
+                vec![]
+            } else {
+                vec!["Fix StorageClass provisioners and binding modes; clean up orphaned PVs"
+                    .to_string()]
+            },
+        };
+        issues.extend(found);
+        Some(check)
+    }
+
     fn create_summary(&self, checks: &[CheckResult], issues: Vec<Issue>) -> InspectionSummary {
         let total_checks = checks.len() as u32;
         let mut passed_checks = 0;
@@ -0,0 +1,224 @@
+//! StorageClass / CSI driver validation: every CSI provisioner needs a CSIDriver object and a
+//! running controller, `Immediate` binding in multi-zone clusters provisions volumes in a zone the
+//! pod may not be able to run in, and PVs whose StorageClass was deleted can no longer be resized
+//! or re-provisioned.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::coordination::v1::Lease;
+use k8s_openapi::api::core::v1::{PersistentVolume, Pod};
+use k8s_openapi::api::storage::v1::StorageClass;
+
+use crate::inspections::types::{Issue, IssueSeverity};
+
+/// A controller leader lease renewed within this window counts as a running controller.
+pub const LEASE_FRESH_MINUTES: i64 = 5;
+
+/// In-tree (non-CSI) provisioners, including `kubernetes.io/no-provisioner` for local PVs.
+pub fn is_in_tree_provisioner(provisioner: &str) -> bool {
+    provisioner.starts_with("kubernetes.io/")
+}
+
+/// Lease name used by external-provisioner leader election: the driver name with every
+/// character outside `[a-zA-Z0-9-]` replaced by `-` (e.g. `ebs.csi.aws.com` -> `ebs-csi-aws-com`).
+pub fn provisioner_lease_name(driver: &str) -> String {
+    driver
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// True when a fresh leader lease or a running pod referencing the driver name in its
+/// container command/args exists.
+pub fn controller_running(
+    driver: &str,
+    leases: &[Lease],
+    pods: &[Pod],
+    now: DateTime<Utc>,
+) -> bool {
+    let lease_name = provisioner_lease_name(driver);
+    let fresh_lease = leases.iter().any(|l| {
+        l.metadata.name.as_deref() == Some(lease_name.as_str())
+            && l.spec
+                .as_ref()
+                .and_then(|s| s.renew_time.as_ref())
+                .map(|t| now.signed_duration_since(t.0) < Duration::minutes(LEASE_FRESH_MINUTES))
+                .unwrap_or(false)
+    });
+    if fresh_lease {
+        return true;
+    }
+    pods.iter()
+        .filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running"))
+        .filter_map(|p| p.spec.as_ref())
+        .flat_map(|s| s.containers.iter())
+        .flat_map(|c| c.command.iter().flatten().chain(c.args.iter().flatten()))
+        .any(|a| a.contains(driver))
+}
+
+/// STO-013/014/015/016 issues. `zone_count` is the number of distinct
+/// `topology.kubernetes.io/zone` values across nodes.
+pub fn validate_storage_classes(
+    storage_classes: &[StorageClass],
+    csi_drivers: &HashSet<String>,
+    running_controllers: &HashSet<String>,
+    zone_count: usize,
+    pvs: &[PersistentVolume],
+) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for sc in storage_classes {
+        let sc_name = sc.metadata.name.as_deref().unwrap_or("unknown");
+        let provisioner = sc.provisioner.as_str();
+        if provisioner.is_empty() {
+            continue;
+        }
+        if !is_in_tree_provisioner(provisioner) {
+            if !csi_drivers.contains(provisioner) {
+                issues.push(Issue {
+                    severity: IssueSeverity::Warning,
+                    category: "StorageClass".to_string(),
+                    description: format!(
+                        "StorageClass {} uses provisioner {} but no CSIDriver object exists",
+                        sc_name, provisioner
+                    ),
+                    resource: Some(sc_name.to_string()),
+                    recommendation: "Install the CSI driver (it registers a CSIDriver object) or point the StorageClass at an installed provisioner.".to_string(),
+                    rule_id: Some("STO-013".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+            if !running_controllers.contains(provisioner) {
+                issues.push(Issue {
+                    severity: IssueSeverity::Critical,
+                    category: "StorageClass".to_string(),
+                    description: format!(
+                        "No running controller found for provisioner {} (StorageClass {})",
+                        provisioner, sc_name
+                    ),
+                    resource: Some(sc_name.to_string()),
+                    recommendation: "Check the CSI controller Deployment/StatefulSet and its csi-provisioner sidecar; new PVCs of this class stay Pending without it.".to_string(),
+                    rule_id: Some("STO-014".to_string()),
+                    team: None,
+                    fingerprint: None,
+                    key_attributes: Vec::new(),
+                    remediation_commands: Vec::new(),
+                });
+            }
+        }
+        let immediate = sc.volume_binding_mode.as_deref().unwrap_or("Immediate") == "Immediate";
+        if zone_count > 1 && immediate && provisioner != "kubernetes.io/no-provisioner" {
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "StorageClass".to_string(),
+                description: format!(
+                    "StorageClass {} uses volumeBindingMode Immediate in a {}-zone cluster",
+                    sc_name, zone_count
+                ),
+                resource: Some(sc_name.to_string()),
+                recommendation: "Use volumeBindingMode: WaitForFirstConsumer so volumes are provisioned in the zone where the pod is scheduled.".to_string(),
+                rule_id: Some("STO-015".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+    }
+
+    let sc_names: HashSet<&str> = storage_classes
+        .iter()
+        .filter_map(|sc| sc.metadata.name.as_deref())
+        .collect();
+    for pv in pvs {
+        let Some(class) = pv
+            .spec
+            .as_ref()
+            .and_then(|s| s.storage_class_name.as_deref())
+            .filter(|c| !c.is_empty())
+        else {
+            continue;
+        };
+        if !sc_names.contains(class) {
+            let pv_name = pv.metadata.name.as_deref().unwrap_or("unknown");
+            issues.push(Issue {
+                severity: IssueSeverity::Warning,
+                category: "PersistentVolume".to_string(),
+                description: format!(
+                    "PV {} references StorageClass {} which no longer exists",
+                    pv_name, class
+                ),
+                resource: Some(pv_name.to_string()),
+                recommendation: "Recreate the StorageClass (same name and provisioner) or migrate the data; expansion and reclaim of this PV depend on it.".to_string(),
+                rule_id: Some("STO-016".to_string()),
+                team: None,
+                fingerprint: None,
+                key_attributes: Vec::new(),
+                remediation_commands: Vec::new(),
+            });
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::coordination::v1::LeaseSpec;
+    use k8s_openapi::api::core::v1::PersistentVolumeSpec;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+
+    fn sc(name: &str, provisioner: &str, binding: Option<&str>) -> StorageClass {
+        let mut sc = StorageClass {
+            provisioner: provisioner.to_string(),
+            volume_binding_mode: binding.map(|b| b.to_string()),
+            ..Default::default()
+        };
+        sc.metadata.name = Some(name.to_string());
+        sc
+    }
+
+    #[test]
+    fn lease_marks_controller_running() {
+        let now = Utc::now();
+        let mut lease = Lease {
+            spec: Some(LeaseSpec {
+                renew_time: Some(MicroTime(now - Duration::seconds(30))),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        lease.metadata.name = Some("ebs-csi-aws-com".to_string());
+        assert!(controller_running("ebs.csi.aws.com", &[lease], &[], now));
+        assert!(!controller_running("pd.csi.storage.gke.io", &[], &[], now));
+    }
+
+    #[test]
+    fn flags_missing_driver_immediate_binding_and_orphaned_pv() {
+        let classes = vec![
+            sc("gp3", "ebs.csi.aws.com", None),
+            sc("local", "kubernetes.io/no-provisioner", None),
+        ];
+        let mut pv = PersistentVolume {
+            spec: Some(PersistentVolumeSpec {
+                storage_class_name: Some("old-class".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        pv.metadata.name = Some("pv-1".to_string());
+        let running: HashSet<String> = ["ebs.csi.aws.com".to_string()].into_iter().collect();
+        let issues = validate_storage_classes(&classes, &HashSet::new(), &running, 3, &[pv]);
+        let ids: Vec<_> = issues.iter().filter_map(|i| i.rule_id.as_deref()).collect();
+        assert_eq!(ids, vec!["STO-013", "STO-015", "STO-016"]);
+    }
+}
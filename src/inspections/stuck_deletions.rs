@@ -0,0 +1,282 @@
+//! Objects stuck deleting: a `deletionTimestamp` older than `--stuck-deletion-minutes` means the
+//! API server accepted the delete but something still holds the object, usually a finalizer whose
+//! controller is gone or broken, or, for pods without finalizers, a kubelet that never confirms
+//! termination. Such zombies keep names, volumes and quota in use and only surface when something
+//! else breaks. Pods, PVCs and the objects of every installed CRD are checked; namespaces are
+//! POLICY-005.
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{Api, ApiResource, DynamicObject, ListParams};
+
+use crate::inspections::types::{CheckResult, CheckStatus, Issue, IssueSeverity};
+use crate::k8s::K8sClient;
+
+/// Default age (minutes) of a `deletionTimestamp` after which an object counts as stuck.
+pub const DEFAULT_STUCK_DELETION_MINUTES: u32 = 10;
+
+/// An object with a `deletionTimestamp`.
+#[derive(Debug, Clone)]
+pub struct Deleting {
+    /// `Pod`, `PersistentVolumeClaim`, or `Kind.group` for custom resources.
+    pub kind: String,
+    /// `namespace/name`, or `name` for cluster-scoped objects.
+    pub resource: String,
+    pub deleted: DateTime<Utc>,
+    pub finalizers: Vec<String>,
+    /// What else the deletion waits for when no finalizer is left (a pod's kubelet).
+    pub waiting_on: Option<String>,
+}
+
+impl Deleting {
+    /// None unless `meta` has a `deletionTimestamp`.
+    pub fn from_meta(kind: &str, meta: &ObjectMeta) -> Option<Self> {
+        let deleted = meta.deletion_timestamp.as_ref()?.0;
+        let name = meta.name.as_deref().unwrap_or("unknown");
+        Some(Self {
+            kind: kind.to_string(),
+            resource: match meta.namespace.as_deref() {
+                Some(ns) => format!("{}/{}", ns, name),
+                None => name.to_string(),
+            },
+            deleted,
+            finalizers: meta.finalizers.clone().unwrap_or_default(),
+            waiting_on: None,
+        })
+    }
+}
+
+/// Custom resources with a `deletionTimestamp`, from one metadata-only LIST per CRD (the storage
+/// version, in `namespace` when set; cluster-scoped kinds only without `--namespace`). Returns
+/// the objects and the number of kinds that could not be listed (RBAC usually grants only the
+/// built-in kinds), or None when CRDs cannot be listed.
+pub async fn custom_resources_deleting(
+    client: &K8sClient,
+    namespace: Option<&str>,
+) -> Option<(Vec<Deleting>, usize)> {
+    let crds = match client
+        .list_all(
+            &Api::<CustomResourceDefinition>::all(client.client().clone()),
+            &ListParams::default(),
+        )
+        .await
+    {
+        Ok(list) => list.items,
+        Err(e) => {
+            log::warn!("CustomResourceDefinitions could not be listed: {}", e);
+            return None;
+        }
+    };
+    let kinds: Vec<(String, ApiResource, bool)> = crds
+        .iter()
+        .filter_map(|crd| {
+            let spec = &crd.spec;
+            let version = spec
+                .versions
+                .iter()
+                .find(|v| v.storage)
+                .or_else(|| spec.versions.iter().find(|v| v.served))?;
+            let namespaced = spec.scope == "Namespaced";
+            if namespace.is_some() && !namespaced {
+                return None;
+            }
+            let resource = ApiResource {
+                group: spec.group.clone(),
+                version: version.name.clone(),
+                api_version: format!("{}/{}", spec.group, version.name),
+                kind: spec.names.kind.clone(),
+                plural: spec.names.plural.clone(),
+            };
+            Some((
+                format!("{}.{}", spec.names.kind, spec.group),
+                resource,
+                namespaced,
+            ))
+        })
+        .collect();
+    let lists = client
+        .fan_out(kinds, |(kind, resource, namespaced)| async move {
+            let api: Api<DynamicObject> = match namespace {
+                Some(ns) if namespaced => {
+                    Api::namespaced_with(client.client().clone(), ns, &resource)
+                }
+                _ => Api::all_with(client.client().clone(), &resource),
+            };
+            let list = api.list_metadata(&ListParams::default()).await;
+            (kind, list)
+        })
+        .await;
+    let mut deleting = Vec::new();
+    let mut skipped = 0usize;
+    for (kind, list) in lists {
+        match list {
+            Ok(list) => deleting.extend(
+                list.items
+                    .iter()
+                    .filter_map(|o| Deleting::from_meta(&kind, &o.metadata)),
+            ),
+            Err(e) => {
+                log::debug!("{} could not be listed: {}", kind, e);
+                skipped += 1;
+            }
+        }
+    }
+    Some((deleting, skipped))
+}
+
+/// "Stuck Deletions" check: ORPH-013 per object whose `deletionTimestamp` is at least
+/// `max_minutes` old, naming the finalizers that hold it. `unlisted` is the number of custom
+/// resource kinds that could not be listed, shown in the details.
+pub fn stuck_deletion_check(
+    objects: &[Deleting],
+    max_minutes: u32,
+    unlisted: usize,
+    now: DateTime<Utc>,
+    issues: &mut Vec<Issue>,
+) -> CheckResult {
+    let mut stuck = 0usize;
+    for object in objects {
+        let minutes = (now - object.deleted).num_minutes();
+        if minutes < i64::from(max_minutes) {
+            continue;
+        }
+        stuck += 1;
+        let age = if minutes >= 120 {
+            format!("{} hours", minutes / 60)
+        } else {
+            format!("{} minutes", minutes)
+        };
+        let holder = if !object.finalizers.is_empty() {
+            format!("finalizer(s) {}", object.finalizers.join(", "))
+        } else {
+            object
+                .waiting_on
+                .clone()
+                .unwrap_or_else(|| "no finalizer left".to_string())
+        };
+        issues.push(Issue {
+            severity: IssueSeverity::Warning,
+            category: object.kind.clone(),
+            description: format!(
+                "{} {} has been deleting for {}; held by {}",
+                object.kind, object.resource, age, holder
+            ),
+            resource: Some(object.resource.clone()),
+            recommendation: "Fix or restore the controller that owns the finalizer so it can finish its cleanup; remove the finalizer by hand only once nothing needs that cleanup.".to_string(),
+            rule_id: Some("ORPH-013".to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        });
+    }
+    let mut details = format!(
+        "{} of {} object(s) with a deletionTimestamp stuck for {}+ minutes",
+        stuck,
+        objects.len(),
+        max_minutes
+    );
+    if unlisted > 0 {
+        details.push_str(&format!(
+            "; {} custom resource kind(s) not listable",
+            unlisted
+        ));
+    }
+    CheckResult {
+        name: "Stuck Deletions".to_string(),
+        description: "Pods, PVCs and custom resources are not held in deletion by finalizers"
+            .to_string(),
+        status: if stuck == 0 {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warning
+        },
+        score: if stuck == 0 { 100.0 } else { 80.0 },
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if stuck == 0 {
+            vec![]
+        } else {
+            vec!["See ORPH-013; clear stuck finalizers before they block re-creation or namespace deletion.".to_string()]
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    fn meta(
+        namespace: Option<&str>,
+        name: &str,
+        deleted: Option<DateTime<Utc>>,
+        finalizers: &[&str],
+    ) -> ObjectMeta {
+        ObjectMeta {
+            namespace: namespace.map(str::to_string),
+            name: Some(name.to_string()),
+            deletion_timestamp: deleted.map(Time),
+            finalizers: Some(finalizers.iter().map(|f| f.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reports_objects_deleting_past_the_threshold_with_their_finalizers() {
+        let now = Utc::now();
+        assert!(Deleting::from_meta("Pod", &meta(Some("shop"), "live", None, &[])).is_none());
+        let mut pod = Deleting::from_meta(
+            "Pod",
+            &meta(Some("shop"), "api-0", Some(now - Duration::hours(3)), &[]),
+        )
+        .unwrap();
+        pod.waiting_on = Some("the kubelet on node-1".to_string());
+        let objects = vec![
+            pod,
+            Deleting::from_meta(
+                "PersistentVolumeClaim",
+                &meta(
+                    Some("shop"),
+                    "data",
+                    Some(now - Duration::minutes(45)),
+                    &["kubernetes.io/pvc-protection"],
+                ),
+            )
+            .unwrap(),
+            Deleting::from_meta(
+                "Certificate.cert-manager.io",
+                &meta(
+                    Some("shop"),
+                    "tls",
+                    Some(now - Duration::minutes(2)),
+                    &["finalizer.acme"],
+                ),
+            )
+            .unwrap(),
+        ];
+        let mut issues = Vec::new();
+        let check = stuck_deletion_check(
+            &objects,
+            DEFAULT_STUCK_DELETION_MINUTES,
+            3,
+            now,
+            &mut issues,
+        );
+        let descriptions: Vec<&str> = issues.iter().map(|i| i.description.as_str()).collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Pod shop/api-0 has been deleting for 3 hours; held by the kubelet on node-1",
+                "PersistentVolumeClaim shop/data has been deleting for 45 minutes; held by finalizer(s) kubernetes.io/pvc-protection",
+            ]
+        );
+        assert_eq!(issues[1].category, "PersistentVolumeClaim");
+        assert_eq!(
+            check.details.as_deref(),
+            Some("2 of 3 object(s) with a deletionTimestamp stuck for 10+ minutes; 3 custom resource kind(s) not listable")
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::scoring::profiles::ScoringProfile;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct InspectionResult {
     pub inspection_type: String,
     pub timestamp: DateTime<Utc>,
@@ -18,10 +21,44 @@ pub struct InspectionResult {
     /// Namespace summary table (Namespace inspection). Rendered as a table.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub namespace_summary_rows: Option<Vec<NamespaceSummaryRow>>,
+    /// API server latency sampled by the Control Plane inspection; kept in the JSON for trending.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub api_latency: Option<ApiLatencySummary>,
+    /// etcd objects per kind (Control Plane inspection). Rendered as a table in the overview.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub etcd_objects: Option<EtcdObjectCounts>,
+}
+
+impl InspectionResult {
+    /// The inspector itself failed (every check is `Error`); such modules are left out of the overall score.
+    pub fn is_failed(&self) -> bool {
+        !self.checks.is_empty() && self.checks.iter().all(|c| c.status == CheckStatus::Error)
+    }
+}
+
+/// Objects stored in etcd per kind, with a rough size estimate.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EtcdObjectCounts {
+    /// `apiserver_storage_objects` (API server metrics) or `list` (common kinds only).
+    pub source: String,
+    /// etcd database size reported by the API server, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub db_size_bytes: Option<u64>,
+    /// Largest count first.
+    pub rows: Vec<ObjectCountRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ObjectCountRow {
+    /// Resource as in the API server metrics (`pods`, `jobs.batch`, `certificates.cert-manager.io`).
+    pub resource: String,
+    pub count: u64,
+    /// `count` × a typical object size of the kind.
+    pub estimated_bytes: u64,
 }
 
 /// One row for the namespace summary table.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NamespaceSummaryRow {
     pub name: String,
     pub pod_count: u32,
@@ -31,8 +68,21 @@ pub struct NamespaceSummaryRow {
     pub has_limit_range: bool,
 }
 
+/// Health of one namespace computed from the issues on its objects; see `scoring::namespace`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NamespaceScore {
+    pub namespace: String,
+    pub score: f64,
+    pub critical: u32,
+    pub warning: u32,
+    pub info: u32,
+    /// Most frequent issue codes in the namespace, most frequent first.
+    #[serde(default)]
+    pub top_rules: Vec<String>,
+}
+
 /// One row for the pod container state table (Pod, Container, State/Reason, Message or exit code).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PodContainerStateRow {
     pub pod_ref: String,
     pub container_name: String,
@@ -42,7 +92,7 @@ pub struct PodContainerStateRow {
 }
 
 /// One row for the TLS certificate expiry table (Secret, subject, expiry, days until expiry).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CertificateExpiryRow {
     pub secret_namespace: String,
     pub secret_name: String,
@@ -51,7 +101,31 @@ pub struct CertificateExpiryRow {
     pub days_until_expiry: i64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Latency of a short burst of cheap API server requests (percentiles over answered requests).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ApiLatencySummary {
+    /// Requests answered (any HTTP status), excluding endpoints the caller may not read.
+    pub samples: u32,
+    /// Answered with a non-2xx status or not answered at all.
+    pub errors: u32,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub p99_ms: u64,
+    pub max_ms: u64,
+    pub endpoints: Vec<ApiEndpointLatency>,
+}
+
+/// Latency of one sampled endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ApiEndpointLatency {
+    pub path: String,
+    pub samples: u32,
+    pub errors: u32,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CheckResult {
     pub name: String,
     pub description: String,
@@ -62,7 +136,7 @@ pub struct CheckResult {
     pub recommendations: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum CheckStatus {
     Pass,
     Warning,
@@ -70,7 +144,7 @@ pub enum CheckStatus {
     Error,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct InspectionSummary {
     pub total_checks: u32,
     pub passed_checks: u32,
@@ -80,7 +154,7 @@ pub struct InspectionSummary {
     pub issues: Vec<Issue>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Issue {
     pub severity: IssueSeverity,
     pub category: String,
@@ -90,9 +164,106 @@ pub struct Issue {
     /// Optional rule/check ID for grouping and documentation reference.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub rule_id: Option<String>,
+    /// Owning team, from the ownership file or namespace labels (set after inspection).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub team: Option<String>,
+    /// Stable ID across runs, set after inspection (see [`issue_fingerprint`]).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub fingerprint: Option<String>,
+    /// What tells findings of one rule on the same resource apart (`container=app`,
+    /// `configmap=db-config`, `module=dccp`); part of the fingerprint.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub key_attributes: Vec<String>,
+    /// Suggested commands that fix the finding (`kubectl -n x rollout restart deploy/y`); shown
+    /// collapsed under the issue tables in Markdown/HTML.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub remediation_commands: Vec<String>,
+}
+
+impl Issue {
+    /// Finding with an issue code; team, fingerprint, key attributes and remediation commands
+    /// start empty.
+    pub fn new(
+        severity: IssueSeverity,
+        category: impl Into<String>,
+        rule_id: &str,
+        description: impl Into<String>,
+        resource: impl Into<Option<String>>,
+        recommendation: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            category: category.into(),
+            description: description.into(),
+            resource: resource.into(),
+            recommendation: recommendation.into(),
+            rule_id: Some(rule_id.to_string()),
+            team: None,
+            fingerprint: None,
+            key_attributes: Vec::new(),
+            remediation_commands: Vec::new(),
+        }
+    }
+
+    /// Fingerprint of this issue from its current fields (see [`issue_fingerprint`]).
+    pub fn compute_fingerprint(&self) -> String {
+        issue_fingerprint(
+            self.rule_id.as_deref(),
+            &self.category,
+            self.resource.as_deref(),
+            &self.key_attributes,
+            &self.description,
+        )
+    }
+
+    /// Recorded fingerprint, or computed for reports saved before fingerprints were recorded.
+    pub fn stable_fingerprint(&self) -> String {
+        self.fingerprint
+            .clone()
+            .unwrap_or_else(|| self.compute_fingerprint())
+    }
+}
+
+/// Stable ID across runs: issue code (or category), trimmed resource, the key attributes, and the
+/// description for issues without a code, or without a resource and key attributes (it is then
+/// all that tells two findings apart). Severity and the wording of findings on a resource are
+/// left out so they may change. 16 hex characters (first 8 bytes of SHA-256).
+pub fn issue_fingerprint(
+    rule_id: Option<&str>,
+    category: &str,
+    resource: Option<&str>,
+    key_attributes: &[String],
+    description: &str,
+) -> String {
+    use sha2::{Digest, Sha256};
+    let resource = resource.map(str::trim).unwrap_or("");
+    let mut key = match rule_id {
+        Some(code) if !resource.is_empty() => format!("{}\n{}", code, resource),
+        // Key attributes identify a finding without a resource; its wording may then change.
+        Some(code) if !key_attributes.is_empty() => format!("{}\n", code),
+        Some(code) => format!("{}\n\n{}", code, description),
+        None => format!("{}\n{}\n{}", category, resource, description),
+    };
+    // Appended only when set, so fingerprints of findings without attributes stay as they were.
+    for attribute in key_attributes {
+        key.push('\n');
+        key.push_str(attribute);
+    }
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Key attributes of a finding about one container of a pod or pod template.
+pub fn container_key(container: &str) -> Vec<String> {
+    vec![format!("container={}", container)]
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, JsonSchema,
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum IssueSeverity {
     #[serde(alias = "Low")]
@@ -104,7 +275,7 @@ pub enum IssueSeverity {
 }
 
 /// One row for the recent cluster events table (Warning/Error).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EventRow {
     pub namespace: String,
     pub object_ref: String,
@@ -114,8 +285,23 @@ pub struct EventRow {
     pub last_seen: String,
 }
 
+/// Warning events in the collection window aggregated by reason and involved object kind.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EventReasonRow {
+    pub reason: String,
+    pub kind: String,
+    /// Occurrences inside the window (event series counts, prorated for older series).
+    pub occurrences: u64,
+    /// Distinct involved objects.
+    pub objects: u32,
+    pub namespaces: u32,
+    pub rate_per_hour: f64,
+    /// Rate is at or above the noisy-event threshold (EVT-001).
+    pub noisy: bool,
+}
+
 /// One row for the node conditions table: Node | Ready | MemoryPressure | DiskPressure | PIDPressure.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NodeConditionsRow {
     pub node_name: String,
     pub ready: String,
@@ -125,7 +311,7 @@ pub struct NodeConditionsRow {
 }
 
 /// One row for the node list table in the report (name, OS, arch, kubelet, ready, pod count).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NodeRow {
     pub name: String,
     pub operating_system: String,
@@ -134,6 +320,9 @@ pub struct NodeRow {
     pub ready: bool,
     /// Number of pods scheduled on this node.
     pub pod_count: u32,
+    /// Pod capacity from Node.status.allocatable `pods` (the kubelet's maxPods).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allocatable_pods: Option<u32>,
     /// Node InternalIP from status.addresses (for Node General Information table).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub node_address: Option<String>,
@@ -146,10 +335,126 @@ pub struct NodeRow {
     /// Container runtime from Node.status.nodeInfo (e.g. containerd://2.1.5).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub container_runtime_version: Option<String>,
+    /// Node pool (value of the first matching `--node-pool-label`), if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub node_pool: Option<String>,
+    /// Availability zone from the `topology.kubernetes.io/zone` label, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub zone: Option<String>,
+    /// Spot/preemptible capacity (EKS, Karpenter, GKE, AKS or `node.kubernetes.io/lifecycle` label).
+    #[serde(default)]
+    pub spot: bool,
+}
+
+/// Nodes and pods per availability zone (overview zone distribution table).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneRow {
+    pub zone: String,
+    pub node_count: u32,
+    pub ready_count: u32,
+    pub pod_count: u32,
+}
+
+/// Unfinished pods per QoS class in one namespace (overview QoS distribution table).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct QosRow {
+    pub namespace: String,
+    pub guaranteed: u32,
+    pub burstable: u32,
+    pub best_effort: u32,
+}
+
+/// Pods per PriorityClass (overview priority distribution table).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PriorityRow {
+    /// PriorityClass name; "(none)" for pods without `priorityClassName`.
+    pub priority_class: String,
+    pub value: i32,
+    #[serde(default)]
+    pub global_default: bool,
+    pub pod_count: u32,
+}
+
+/// Per-pool rollup of nodes, usage, node inspection and findings.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NodePoolRow {
+    pub pool: String,
+    pub node_count: u32,
+    pub ready_count: u32,
+    pub pod_count: u32,
+    /// Distinct kubelet versions in the pool (more than one means version skew).
+    pub kubelet_versions: Vec<String>,
+    /// Distinct kernel versions in the pool.
+    pub kernel_versions: Vec<String>,
+    /// Average CPU usage % of allocatable (metrics-server).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub avg_cpu_pct: Option<f64>,
+    /// Average memory usage % of allocatable (metrics-server).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub avg_memory_pct: Option<f64>,
+    /// Highest nodefs usage % in the pool (kubelet stats).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_disk_pct: Option<f64>,
+    /// Nodes with node inspector data.
+    pub inspected_nodes: u32,
+    /// Sum of node inspector issue counts.
+    pub node_inspection_issues: u32,
+    /// Critical / Warning findings whose resource is a node of this pool.
+    pub critical_findings: u32,
+    pub warning_findings: u32,
+}
+
+/// CPU and memory requests and limits of the pods bound to a set of nodes, against the nodes'
+/// allocatable. Pods that have finished (Succeeded/Failed) are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CapacityCommitment {
+    /// Node pool name, or `cluster` for all nodes.
+    pub scope: String,
+    pub node_count: u32,
+    pub allocatable_cpu_cores: f64,
+    pub cpu_requests_cores: f64,
+    pub cpu_limits_cores: f64,
+    pub allocatable_memory_gi: f64,
+    pub memory_requests_gi: f64,
+    pub memory_limits_gi: f64,
+}
+
+/// Linear trend of cluster requests over earlier reports (`--history-dir`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CapacityTrend {
+    /// Reports the trend is fitted over, this one included.
+    pub data_points: usize,
+    /// Days between the oldest report used and this one.
+    pub span_days: f64,
+    pub cpu_requests_cores_per_day: f64,
+    pub memory_requests_gi_per_day: f64,
+    /// Days until CPU requests exceed allocatable at this trend; None when requests are not
+    /// growing.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cpu_days_until_full: Option<f64>,
+    /// Days until memory requests exceed allocatable at this trend; None when requests are not
+    /// growing.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub memory_days_until_full: Option<f64>,
+}
+
+/// Capacity section: current commitment of the cluster and each node pool, and the requests
+/// trend when earlier reports are available.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CapacityForecast {
+    pub cluster: CapacityCommitment,
+    /// One entry per node pool; empty when no node carries a pool label.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub node_pools: Vec<CapacityCommitment>,
+    /// One entry per node, scope being the node name.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub nodes: Vec<CapacityCommitment>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub trend: Option<CapacityTrend>,
 }
 
 /// Pod phase counts for cluster overview (from List Pods).
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct PodPhaseBreakdown {
     pub running: u32,
     pub pending: u32,
@@ -159,7 +464,7 @@ pub struct PodPhaseBreakdown {
 }
 
 /// Workload controller counts and ready counts (Deployments, StatefulSets, DaemonSets).
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct WorkloadSummary {
     pub deployments_total: u32,
     pub deployments_ready: u32,
@@ -170,7 +475,7 @@ pub struct WorkloadSummary {
 }
 
 /// Storage summary: PV, PVC, StorageClass counts (from API).
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct StorageSummary {
     pub pv_total: u32,
     pub pvc_total: u32,
@@ -180,7 +485,7 @@ pub struct StorageSummary {
 }
 
 /// Cluster-level overview: version, node counts, OS/arch summary, and optional resource totals.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct ClusterOverview {
     /// API server version (e.g. "1.28.x"), if available.
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -234,10 +539,83 @@ pub struct ClusterOverview {
     /// Per-container usage vs requests/limits (notable rows only: high usage, low usage, or no request/limit). From metrics-server + Pod spec; omitted when metrics unavailable.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub container_usage_notable: Option<Vec<ContainerUsageRow>>,
+    /// Node-pool rollup; None when no node carries a pool label.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub node_pools: Option<Vec<NodePoolRow>>,
+    /// Nodes and pods per zone; None when no node carries a zone label.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub zone_distribution: Option<Vec<ZoneRow>>,
+    /// Managed control-plane provider detected from node labels and the server version; None on
+    /// self-managed clusters.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub managed_provider: Option<ManagedProvider>,
+    /// Node with the oldest image; None when no node has an image date or creation time.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub oldest_node_image: Option<NodeImageAge>,
+    /// Requests/limits against allocatable per node pool, with a forecast from `--history-dir`;
+    /// None when the run is scoped to a namespace.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub capacity: Option<CapacityForecast>,
+    /// Pods per QoS class and namespace; None when no pod is running.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub qos_distribution: Option<Vec<QosRow>>,
+    /// Pods per PriorityClass, highest value first; None when PriorityClasses cannot be listed.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub priority_distribution: Option<Vec<PriorityRow>>,
+}
+
+/// Age of the image a node runs.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NodeImageAge {
+    pub node: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub os_image: Option<String>,
+    pub age_days: u64,
+    pub source: ImageAgeSource,
+}
+
+/// Where a node image age comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageAgeSource {
+    /// AKS node image version label or a date stamp in the OS image name.
+    ImageBuildDate,
+    /// Node creation time (the image is at least this old on the node).
+    NodeCreation,
+}
+
+impl std::fmt::Display for ImageAgeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ImageBuildDate => "image build date",
+            Self::NodeCreation => "node creation",
+        })
+    }
+}
+
+/// Provider operating the control plane (and etcd) of a managed cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ManagedProvider {
+    #[serde(rename = "EKS")]
+    Eks,
+    #[serde(rename = "GKE")]
+    Gke,
+    #[serde(rename = "AKS")]
+    Aks,
+}
+
+impl std::fmt::Display for ManagedProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Eks => "Amazon EKS",
+            Self::Gke => "Google GKE",
+            Self::Aks => "Azure AKS",
+        })
+    }
 }
 
 /// One row for the container resource usage table (notable only: high usage, low usage, or no request/limit).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ContainerUsageRow {
     pub namespace: String,
     pub pod_name: String,
@@ -259,7 +637,7 @@ pub struct ContainerUsageRow {
 }
 
 /// Per-node resource usage from metrics-server (allocatable + usage + % for CPU/Memory/Disk per node).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NodeUsageRow {
     pub node_name: String,
     /// Allocatable CPU in cores (for this node).
@@ -279,15 +657,16 @@ pub struct NodeUsageRow {
     /// Allocatable ephemeral-storage in Gi (from node status; metrics-server does not provide disk usage).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub disk_allocatable_gi: Option<f64>,
-    /// Disk usage in Gi (N/A from metrics-server; reserved for future).
+    /// nodefs usage in Gi (from kubelet `/stats/summary`; None when the node proxy is not permitted).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub disk_usage_gi: Option<f64>,
+    /// nodefs usage as % of its capacity (kubelet `/stats/summary`).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub disk_pct: Option<f64>,
 }
 
 /// Aggregate node capacity and allocatable (CPU/memory as display strings).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct NodeResourceSummary {
     pub capacity_cpu: String,
     pub capacity_memory: String,
@@ -298,8 +677,18 @@ pub struct NodeResourceSummary {
     pub allocatable_disk_gi: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Version of the JSON report layout, written to `ClusterReport::schema_version`. A minor bump
+/// only adds optional fields; a major bump removes, renames or retypes a field.
+pub const REPORT_SCHEMA_VERSION: &str = "1.20";
+
+/// Result of one inspection run: what `check --format json` writes and every output format is
+/// rendered from. Its JSON Schema is `kubeowler schema` (docs/report-schema.json).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClusterReport {
+    /// [`REPORT_SCHEMA_VERSION`] of the kubeowler that wrote the report; empty in reports
+    /// written before the schema was versioned.
+    #[serde(default)]
+    pub schema_version: String,
     pub cluster_name: String,
     pub report_id: String,
     pub timestamp: DateTime<Utc>,
@@ -321,17 +710,178 @@ pub struct ClusterReport {
     /// Recent cluster events (Warning/Error), for report section.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub recent_events: Option<Vec<EventRow>>,
+    /// Warning events aggregated by (reason, kind) over the collection window.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub event_analytics: Option<Vec<EventReasonRow>>,
+    /// Namespaces with findings, worst score first.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub namespace_ranking: Option<Vec<NamespaceScore>>,
+    /// Issues matched by `--baseline` and taken out of the inspections (known/accepted findings).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub accepted_issues: Option<Vec<Issue>>,
+    /// How the report was produced, including data sources that were unavailable or partial.
+    #[serde(default)]
+    pub metadata: ReportMetadata,
+}
+
+/// Report provenance; `collection_warnings` lists data sources missing from this report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ReportMetadata {
+    #[serde(default)]
+    pub collection_warnings: Vec<CollectionWarning>,
+    /// Profile whose weights produced `overall_score` (reports without it used `balanced`).
+    #[serde(default)]
+    pub scoring_profile: ScoringProfile,
+    /// Set on per-team reports written by `--split-by-team`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub team: Option<String>,
+    /// Baseline applied with `--baseline`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub baseline: Option<BaselineInfo>,
+    /// Config file profile selected with `--profile`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub profile: Option<ProfileInfo>,
+    /// Namespace the run was limited to (`--namespace`); absent for cluster-wide runs.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub namespace_scope: Option<String>,
+    /// Label selector the namespaced LISTs were limited to (`--selector`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub label_selector: Option<String>,
+    /// `--only-checks` / `--skip-checks` in effect (`only POD-*; skip POD-004`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub check_selection: Option<String>,
+    /// Findings before and after merging duplicates (same code on the same resource).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub issue_counts: Option<IssueCounts>,
+    /// Age of the open findings, from the earlier reports in `--history-dir`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub finding_ages: Option<FindingAges>,
+    /// Timing and API traffic of this run; absent from offline renders of older reports.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub run_diagnostics: Option<RunDiagnostics>,
+}
+
+/// How the run went: where the time and API traffic went, and which optional data sources were
+/// read. Used to tune scope flags (`--namespace`, `--page-size`, `--qps`) on big clusters.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RunDiagnostics {
+    pub duration_ms: u64,
+    /// Collection steps and inspection modules in run order.
+    pub steps: Vec<StepTiming>,
+    pub data_sources: Vec<DataSourceUsage>,
+}
+
+/// One collection step or inspection module of the run.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StepTiming {
+    pub step: String,
+    pub duration_ms: u64,
+    /// Request attempts, retries included.
+    pub api_requests: u64,
+    pub api_errors: u64,
+    pub objects_listed: u64,
+    pub failed: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Whether an optional data source contributed to the report.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DataSourceUsage {
+    pub source: String,
+    pub used: bool,
+    pub detail: String,
+}
+
+/// Raw finding count of a run and how many duplicates were merged into other findings.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+pub struct IssueCounts {
+    /// Findings reported by the inspection modules, duplicates included.
+    pub raw: usize,
+    /// Findings merged into an earlier one with the same fingerprint.
+    pub merged: usize,
+}
+
+/// Since when each finding has been open, from the earlier reports of the same cluster.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindingAges {
+    pub history_dir: String,
+    /// Earlier reports of this cluster read from `history_dir`.
+    pub earlier_runs: usize,
+    /// Critical findings open longer than this are overdue.
+    pub critical_sla_days: u32,
+    /// Fingerprint → first run of the unbroken series of runs reporting the finding.
+    pub first_seen: BTreeMap<String, DateTime<Utc>>,
+    /// Critical findings open longer than `critical_sla_days`.
+    pub overdue: usize,
+}
+
+/// Outcome of applying a baseline file to a report.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaselineInfo {
+    pub file: String,
+    /// Entries in the baseline file.
+    pub entries: usize,
+    /// Issues of this run that matched an entry.
+    pub accepted: usize,
+    /// Entries that matched no issue (fixed since the baseline was taken).
+    pub resolved: usize,
+}
+
+/// Outcome of applying a config file profile to a report.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileInfo {
+    pub name: String,
+    /// Issues dropped because their namespace matched `exclude_namespaces`.
+    pub excluded: usize,
+    /// Issues whose severity the profile changed.
+    pub overridden: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum DataSourceStatus {
+    /// Not collected at all.
+    Unavailable,
+    /// Collected for only part of the cluster (e.g. some nodes).
+    Partial,
+}
+
+/// A data source that could not be fully collected, and what the report lacks because of it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CollectionWarning {
+    pub source: String,
+    pub status: DataSourceStatus,
+    pub detail: String,
+}
+
+impl CollectionWarning {
+    pub fn unavailable(source: &str, detail: impl Into<String>) -> Self {
+        Self {
+            source: source.to_string(),
+            status: DataSourceStatus::Unavailable,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn partial(source: &str, detail: impl Into<String>) -> Self {
+        Self {
+            source: source.to_string(),
+            status: DataSourceStatus::Partial,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExecutiveSummary {
     pub health_status: HealthStatus,
+    /// Letter grade of `health_status`; absent from reports written before grades existed.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub grade: Option<Grade>,
     pub key_findings: Vec<String>,
     pub priority_recommendations: Vec<String>,
     pub score_breakdown: HashMap<String, f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum HealthStatus {
     Excellent,
     Good,
@@ -339,3 +889,51 @@ pub enum HealthStatus {
     Poor,
     Critical,
 }
+
+/// A–F grade of a health status (see `HealthThresholds`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl std::fmt::Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl HealthStatus {
+    pub fn grade(&self) -> Grade {
+        match self {
+            HealthStatus::Excellent => Grade::A,
+            HealthStatus::Good => Grade::B,
+            HealthStatus::Fair => Grade::C,
+            HealthStatus::Poor => Grade::D,
+            HealthStatus::Critical => Grade::F,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthStatus::Excellent => "Excellent",
+            HealthStatus::Good => "Good",
+            HealthStatus::Fair => "Fair",
+            HealthStatus::Poor => "Poor",
+            HealthStatus::Critical => "Critical",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            HealthStatus::Excellent => "🟢",
+            HealthStatus::Good => "🟡",
+            HealthStatus::Fair => "🟠",
+            HealthStatus::Poor => "🔴",
+            HealthStatus::Critical => "🚨",
+        }
+    }
+}
@@ -41,12 +41,17 @@ impl<'a> UpgradeInspector<'a> {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         })
     }
 
     async fn inspect_versions(&self) -> Result<CheckResult> {
         let nodes_api: Api<Node> = Api::all(self.client.client().clone());
-        let nodes = nodes_api.list(&Default::default()).await?;
+        let nodes = self
+            .client
+            .list_all(&nodes_api, &Default::default())
+            .await?;
 
         if nodes.items.is_empty() {
             return Ok(CheckResult {
@@ -0,0 +1,382 @@
+//! Windows nodes and pods. Linux-only checks (node inspector sysctls/SELinux, runAsUser and
+//! allowPrivilegeEscalation) are skipped for them; instead Windows nodes are checked for a
+//! supported container runtime (NODE-027) and pause image (NODE-028), and HostProcess
+//! containers count as privileged (SEC-005).
+
+use std::collections::BTreeSet;
+
+use k8s_openapi::api::core::v1::{Node, Pod};
+
+use crate::inspections::types::*;
+
+/// `kubernetes.io/os` / `nodeInfo.operatingSystem` value of Windows nodes.
+pub const WINDOWS: &str = "windows";
+
+const OS_LABEL: &str = "kubernetes.io/os";
+
+/// Oldest containerd release supported on Windows (HostProcess containers, Windows Server 2022).
+const MIN_CONTAINERD: (u32, u32) = (1, 6);
+
+/// Oldest pause image with Windows Server 2022 variants.
+const MIN_PAUSE: (u32, u32) = (3, 6);
+
+/// Operating system of `node` from `nodeInfo`, falling back to the `kubernetes.io/os` label.
+pub fn node_os(node: &Node) -> &str {
+    node.status
+        .as_ref()
+        .and_then(|s| s.node_info.as_ref())
+        .map(|i| i.operating_system.as_str())
+        .filter(|os| !os.is_empty())
+        .or_else(|| {
+            node.metadata
+                .labels
+                .as_ref()
+                .and_then(|l| l.get(OS_LABEL))
+                .map(String::as_str)
+        })
+        .unwrap_or("linux")
+}
+
+/// Names of the Windows nodes in `nodes`.
+pub fn windows_node_names(nodes: &[Node]) -> BTreeSet<String> {
+    nodes
+        .iter()
+        .filter(|n| node_os(n) == WINDOWS)
+        .filter_map(|n| n.metadata.name.clone())
+        .collect()
+}
+
+/// True when `pod` declares `spec.os.name: windows`, selects Windows nodes, or is scheduled on
+/// one of `windows_nodes`.
+pub fn is_windows_pod(pod: &Pod, windows_nodes: &BTreeSet<String>) -> bool {
+    let Some(spec) = &pod.spec else {
+        return false;
+    };
+    spec.os.as_ref().is_some_and(|os| os.name == WINDOWS)
+        || spec
+            .node_selector
+            .as_ref()
+            .and_then(|s| s.get(OS_LABEL))
+            .is_some_and(|os| os == WINDOWS)
+        || spec
+            .node_name
+            .as_ref()
+            .is_some_and(|n| windows_nodes.contains(n))
+}
+
+/// Containers of `pod` that run as Windows HostProcess containers (set on the pod or container).
+pub fn host_process_containers(pod: &Pod) -> Vec<&str> {
+    let Some(spec) = &pod.spec else {
+        return Vec::new();
+    };
+    let pod_level = spec
+        .security_context
+        .as_ref()
+        .and_then(|sc| sc.windows_options.as_ref())
+        .and_then(|w| w.host_process)
+        .unwrap_or(false);
+    spec.containers
+        .iter()
+        .filter(|c| {
+            c.security_context
+                .as_ref()
+                .and_then(|sc| sc.windows_options.as_ref())
+                .and_then(|w| w.host_process)
+                .unwrap_or(pod_level)
+        })
+        .map(|c| c.name.as_str())
+        .collect()
+}
+
+/// `1.7.13` / `v3.9` → (1, 7) / (3, 9).
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor: String = parts
+        .next()?
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    Some((major, minor.parse().ok()?))
+}
+
+fn windows_nodes(nodes: &[Node]) -> impl Iterator<Item = (&str, &Node)> {
+    nodes
+        .iter()
+        .filter(|n| node_os(n) == WINDOWS)
+        .map(|n| (n.metadata.name.as_deref().unwrap_or("unknown"), n))
+}
+
+fn node_issue(
+    severity: IssueSeverity,
+    code: &str,
+    node: &str,
+    description: String,
+    recommendation: &str,
+) -> Issue {
+    Issue {
+        severity,
+        category: "Node".to_string(),
+        description,
+        resource: Some(node.to_string()),
+        recommendation: recommendation.to_string(),
+        rule_id: Some(code.to_string()),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
+    }
+}
+
+fn windows_check(
+    name: &str,
+    description: &str,
+    total: usize,
+    flagged: usize,
+    status: CheckStatus,
+    details: String,
+    recommendation: &str,
+) -> CheckResult {
+    CheckResult {
+        name: name.to_string(),
+        description: description.to_string(),
+        status: if flagged == 0 {
+            CheckStatus::Pass
+        } else {
+            status
+        },
+        score: if total == 0 {
+            100.0
+        } else {
+            (total - flagged) as f64 / total as f64 * 100.0
+        },
+        max_score: 100.0,
+        details: Some(details),
+        recommendations: if flagged == 0 {
+            vec![]
+        } else {
+            vec![recommendation.to_string()]
+        },
+    }
+}
+
+/// NODE-027 per Windows node not running containerd 1.6 or later. Docker (dockershim) is
+/// Critical since kubelet 1.24 can no longer use it.
+pub fn runtime_check(nodes: &[Node], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut total = 0;
+    let mut flagged = 0;
+    let mut status = CheckStatus::Warning;
+    for (name, node) in windows_nodes(nodes) {
+        total += 1;
+        let runtime = node
+            .status
+            .as_ref()
+            .and_then(|s| s.node_info.as_ref())
+            .map(|i| i.container_runtime_version.as_str())
+            .unwrap_or("");
+        let (kind, version) = runtime.split_once("://").unwrap_or((runtime, ""));
+        let severity = match kind {
+            "containerd" if major_minor(version).is_some_and(|v| v >= MIN_CONTAINERD) => continue,
+            "containerd" => IssueSeverity::Warning,
+            _ => {
+                status = CheckStatus::Critical;
+                IssueSeverity::Critical
+            }
+        };
+        flagged += 1;
+        issues.push(node_issue(
+            severity,
+            "NODE-027",
+            name,
+            format!(
+                "Windows node {} runs container runtime {}; containerd {}.{}+ is required",
+                name,
+                if runtime.is_empty() {
+                    "unknown"
+                } else {
+                    runtime
+                },
+                MIN_CONTAINERD.0,
+                MIN_CONTAINERD.1
+            ),
+            "Move the node to a Windows image that ships containerd 1.6 or later.",
+        ));
+    }
+    windows_check(
+        "Windows Container Runtime",
+        "Checks that Windows nodes run containerd 1.6 or later",
+        total,
+        flagged,
+        status,
+        format!(
+            "{}/{} Windows nodes on an unsupported runtime",
+            flagged, total
+        ),
+        "Upgrade the container runtime on Windows nodes (see NODE-027).",
+    )
+}
+
+/// Highest pause image `major.minor` present on `node`, from `status.images`.
+fn pause_version(node: &Node) -> Option<(u32, u32)> {
+    node.status
+        .as_ref()?
+        .images
+        .as_ref()?
+        .iter()
+        .flat_map(|i| i.names.iter().flatten())
+        .filter_map(|name| {
+            let (repo, tag) = name.rsplit_once(':')?;
+            repo.ends_with("/pause").then(|| major_minor(tag)).flatten()
+        })
+        .max()
+}
+
+/// NODE-028 per Windows node whose pause image is older than 3.6. Nodes whose image list
+/// does not include a pause image are not reported.
+pub fn pause_image_check(nodes: &[Node], issues: &mut Vec<Issue>) -> CheckResult {
+    let mut total = 0;
+    let mut flagged = 0;
+    for (name, node) in windows_nodes(nodes) {
+        let Some(version) = pause_version(node) else {
+            continue;
+        };
+        total += 1;
+        if version >= MIN_PAUSE {
+            continue;
+        }
+        flagged += 1;
+        issues.push(node_issue(
+            IssueSeverity::Warning,
+            "NODE-028",
+            name,
+            format!(
+                "Windows node {} uses pause image {}.{}, older than {}.{}",
+                name, version.0, version.1, MIN_PAUSE.0, MIN_PAUSE.1
+            ),
+            "Set the sandbox (pause) image in the containerd config to 3.6 or later.",
+        ));
+    }
+    windows_check(
+        "Windows Pause Image",
+        "Checks that Windows nodes use pause image 3.6 or later",
+        total,
+        flagged,
+        CheckStatus::Warning,
+        format!(
+            "{}/{} Windows nodes with an outdated pause image",
+            flagged, total
+        ),
+        "Update the pause image on Windows nodes (see NODE-028).",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Container, ContainerImage, NodeStatus, NodeSystemInfo, PodOS, PodSpec, SecurityContext,
+        WindowsSecurityContextOptions,
+    };
+    use kube::api::ObjectMeta;
+
+    fn node(name: &str, os: &str, runtime: &str, images: &[&str]) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(NodeStatus {
+                node_info: Some(NodeSystemInfo {
+                    operating_system: os.to_string(),
+                    container_runtime_version: runtime.to_string(),
+                    ..Default::default()
+                }),
+                images: Some(vec![ContainerImage {
+                    names: Some(images.iter().map(|i| i.to_string()).collect()),
+                    size_bytes: None,
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn checks_only_windows_nodes_for_runtime_and_pause_image() {
+        let nodes = vec![
+            node(
+                "linux-1",
+                "linux",
+                "docker://20.10.7",
+                &["registry.k8s.io/pause:3.2"],
+            ),
+            node(
+                "win-ok",
+                WINDOWS,
+                "containerd://1.7.13",
+                &["mcr.microsoft.com/oss/kubernetes/pause:3.9"],
+            ),
+            node(
+                "win-old",
+                WINDOWS,
+                "containerd://1.5.9",
+                &["mcr.microsoft.com/oss/kubernetes/pause:3.4.1"],
+            ),
+            node("win-docker", WINDOWS, "docker://20.10.9", &[]),
+        ];
+        assert_eq!(windows_node_names(&nodes).len(), 3);
+
+        let mut issues = Vec::new();
+        let check = runtime_check(&nodes, &mut issues);
+        assert_eq!(check.status, CheckStatus::Critical);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].resource.as_deref(), Some("win-old"));
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[1].severity, IssueSeverity::Critical);
+
+        let mut issues = Vec::new();
+        let check = pause_image_check(&nodes, &mut issues);
+        assert_eq!(
+            check.details.as_deref(),
+            Some("1/2 Windows nodes with an outdated pause image")
+        );
+        assert_eq!(issues[0].rule_id.as_deref(), Some("NODE-028"));
+    }
+
+    #[test]
+    fn detects_windows_pods_and_host_process_containers() {
+        let windows_nodes: BTreeSet<String> = ["win-1".to_string()].into();
+        let host_process = SecurityContext {
+            windows_options: Some(WindowsSecurityContextOptions {
+                host_process: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pod = |os: Option<&str>, node: Option<&str>| Pod {
+            spec: Some(PodSpec {
+                os: os.map(|name| PodOS {
+                    name: name.to_string(),
+                }),
+                node_name: node.map(str::to_string),
+                containers: vec![
+                    Container {
+                        name: "agent".to_string(),
+                        security_context: Some(host_process.clone()),
+                        ..Default::default()
+                    },
+                    Container {
+                        name: "app".to_string(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(is_windows_pod(&pod(Some(WINDOWS), None), &windows_nodes));
+        assert!(is_windows_pod(&pod(None, Some("win-1")), &windows_nodes));
+        assert!(!is_windows_pod(&pod(None, Some("linux-1")), &windows_nodes));
+        assert_eq!(host_process_containers(&pod(None, None)), vec!["agent"]);
+    }
+}
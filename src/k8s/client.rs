@@ -1,56 +1,290 @@
-use anyhow::Result;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
 use http::Request;
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
-use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::certificates::v1::CertificateSigningRequest;
+use k8s_openapi::api::coordination::v1::Lease;
 use k8s_openapi::api::core::v1::{
-    Event, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ConfigMap, Event, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret,
+    Service, ServiceAccount,
 };
-use k8s_openapi::api::networking::v1::NetworkPolicy;
+use k8s_openapi::api::networking::v1::{Ingress, IngressClass, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
-use k8s_openapi::api::storage::v1::StorageClass;
-use kube::config::Kubeconfig;
-use kube::{Api, Client, Config};
+use k8s_openapi::api::scheduling::v1::PriorityClass;
+use k8s_openapi::api::storage::v1::{CSIDriver, StorageClass};
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::{ListParams, ObjectList};
+use kube::client::ClientBuilder;
+use kube::config::{KubeConfigOptions, Kubeconfig};
+use kube::{Api, Client, Config, Resource};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
-fn infer_cluster_name() -> Option<String> {
-    let kubeconfig = Kubeconfig::read().ok()?;
-    let current = kubeconfig.current_context.as_ref()?;
-    let named = kubeconfig.contexts.iter().find(|nc| nc.name == *current)?;
+use crate::cli::ClusterAccess;
+
+use super::throttle::{ApiCounters, ApiLimits, ThrottleLayer};
+
+/// Service account token mounted into pods; with `KUBERNETES_SERVICE_HOST` it marks an in-cluster run.
+const SERVICE_ACCOUNT_TOKEN: &str = "/var/run/secrets/kubernetes.io/serviceaccount/token";
+
+/// Default objects per LIST page (`--page-size`).
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+
+/// Default requests in flight for per-namespace / per-node fan-out (`--api-concurrency`).
+pub const DEFAULT_API_CONCURRENCY: u32 = 8;
+
+/// Cluster of `context` (default: the current context) in the kubeconfig.
+fn cluster_for_context(kubeconfig: &Kubeconfig, context: Option<&str>) -> Option<String> {
+    let name = context.or(kubeconfig.current_context.as_deref())?;
+    let named = kubeconfig.contexts.iter().find(|nc| nc.name == name)?;
     let ctx = named.context.as_ref()?;
     Some(ctx.cluster.clone())
 }
 
+fn infer_cluster_name(context: Option<&str>) -> Option<String> {
+    cluster_for_context(&Kubeconfig::read().ok()?, context)
+}
+
+/// In a pod with a service account and no kubeconfig asked for (no `--config-file`, `--context`
+/// or `KUBECONFIG`), use the in-cluster config rather than looking for `~/.kube/config` first.
+fn use_in_cluster_config(config_file: Option<&str>, access: &ClusterAccess) -> bool {
+    config_file.is_none()
+        && access.context.is_none()
+        && std::env::var_os("KUBECONFIG").is_none()
+        && std::env::var_os("KUBERNETES_SERVICE_HOST").is_some()
+        && std::path::Path::new(SERVICE_ACCOUNT_TOKEN).exists()
+}
+
+/// How `K8sClient::list_all` pages through LIST responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPaging {
+    /// `limit` per request; 0 fetches everything in one response.
+    pub page_size: u32,
+    /// Send `resourceVersion=0` so the API server answers from its watch cache.
+    pub from_cache: bool,
+}
+
+impl Default for ListPaging {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            from_cache: false,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct K8sClient {
     client: Client,
     cluster_name: Option<String>,
+    paging: ListPaging,
+    api_counters: Arc<ApiCounters>,
+    concurrency: usize,
+    label_selector: Option<String>,
 }
 
 impl K8sClient {
-    pub async fn new(config_file: Option<&str>) -> Result<Self> {
+    /// Connect with the kubeconfig (`config_file`, else `KUBECONFIG` / `~/.kube/config`) context
+    /// from `access`, or the in-cluster service account when running in a pod; then apply
+    /// `access` impersonation and request limits (QPS/burst, retries, timeout).
+    pub async fn new(config_file: Option<&str>, access: &ClusterAccess) -> Result<Self> {
         if let Some(path) = config_file {
             std::env::set_var("KUBECONFIG", path);
         }
-        let cluster_name = infer_cluster_name();
-        let config = Config::infer().await?;
-        let client = Client::try_from(config)?;
+        let (mut config, cluster_name) = if use_in_cluster_config(config_file, access) {
+            (Config::incluster()?, None)
+        } else if let Some(context) = &access.context {
+            let options = KubeConfigOptions {
+                context: Some(context.clone()),
+                ..Default::default()
+            };
+            let config = Config::from_kubeconfig(&options)
+                .await
+                .with_context(|| format!("loading kubeconfig context '{}'", context))?;
+            (config, infer_cluster_name(Some(context)))
+        } else {
+            (Config::infer().await?, infer_cluster_name(None))
+        };
+        if let Some(user) = &access.impersonate {
+            config.auth_info.impersonate = Some(user.clone());
+            if !access.impersonate_group.is_empty() {
+                config.auth_info.impersonate_groups = Some(access.impersonate_group.clone());
+            }
+        }
+        let throttle = ThrottleLayer::new(ApiLimits::from_access(access));
+        let api_counters = throttle.counters();
+        let client = ClientBuilder::try_from(config)?
+            .with_layer(&throttle)
+            .build();
         Ok(Self {
             client,
             cluster_name,
+            paging: ListPaging {
+                page_size: access.page_size,
+                from_cache: access.list_from_cache,
+            },
+            api_counters,
+            concurrency: access.api_concurrency.max(1) as usize,
+            label_selector: None,
         })
     }
 
+    /// Wrap an existing client (e.g. the offline dump client).
+    pub fn from_client(client: Client, cluster_name: Option<String>) -> Self {
+        Self {
+            client,
+            cluster_name,
+            paging: ListPaging::default(),
+            api_counters: Arc::default(),
+            concurrency: DEFAULT_API_CONCURRENCY as usize,
+            label_selector: None,
+        }
+    }
+
+    /// Restrict LISTs of namespaced objects to `selector` (`check --selector`); see
+    /// [`K8sClient::list_all`].
+    pub fn with_label_selector(mut self, selector: Option<String>) -> Self {
+        self.label_selector = selector.filter(|s| !s.trim().is_empty());
+        self
+    }
+
+    /// The `--selector` in effect, if any.
+    pub fn label_selector(&self) -> Option<&str> {
+        self.label_selector.as_deref()
+    }
+
     pub fn client(&self) -> &Client {
         &self.client
     }
 
-    /// Cluster name from kubeconfig current context, or None if in-cluster or unset.
+    /// API requests sent and objects listed so far (no requests are counted for clients wrapped
+    /// with `from_client`).
+    pub fn api_counters(&self) -> &ApiCounters {
+        &self.api_counters
+    }
+
+    /// Run `f` for every item with at most `--api-concurrency` calls in flight (each request is
+    /// still throttled by `--qps`). Results come back in the order of `items`, so reports do not
+    /// depend on which response arrived first.
+    pub async fn fan_out<T, R, F, Fut>(&self, items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: std::future::Future<Output = R>,
+    {
+        futures::stream::iter(items)
+            .map(f)
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Cluster of the kubeconfig context in use, or None if in-cluster or unset.
     pub fn cluster_name(&self) -> Option<&str> {
         self.cluster_name.as_deref()
     }
 
+    /// `lp` with the `--page-size` limit and, with `--list-from-cache`, `resourceVersion=0`. For
+    /// namespaced kinds (but [`UNSELECTED_KINDS`]), the `--selector` is added when `selected` is
+    /// set and `lp` has no label selector of its own (e.g. the node inspector pod lookup).
+    fn paged_params<K>(&self, lp: &ListParams, selected: bool) -> ListParams
+    where
+        K: Resource<DynamicType = ()>,
+        K::Scope: 'static,
+    {
+        let mut lp = lp.clone();
+        if selected
+            && lp.label_selector.is_none()
+            && is_namespaced::<K>()
+            && !UNSELECTED_KINDS.contains(&K::kind(&()).as_ref())
+        {
+            lp.label_selector = self.label_selector.clone();
+        }
+        if self.paging.page_size > 0 {
+            lp.limit = Some(self.paging.page_size);
+        }
+        if self.paging.from_cache && lp.resource_version.is_none() {
+            lp = lp.match_any();
+        }
+        lp
+    }
+
+    /// LIST through `api` in pages of `--page-size` objects, following `continue` tokens, and
+    /// return all items in one list. Label/field selectors in `lp` apply to every page.
+    pub async fn list_all<K>(&self, api: &Api<K>, lp: &ListParams) -> kube::Result<ObjectList<K>>
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+        K::Scope: 'static,
+    {
+        self.list_paged(api, lp, true).await
+    }
+
+    /// Like [`K8sClient::list_all`], but without the `--selector`: for objects that the inspected
+    /// objects point at (owners, scale targets, referenced ConfigMaps and Secrets), for coverage
+    /// lookups (quotas, NetworkPolicies, PDBs) and for cluster components, which must be found
+    /// whatever their labels.
+    pub async fn list_unselected<K>(
+        &self,
+        api: &Api<K>,
+        lp: &ListParams,
+    ) -> kube::Result<ObjectList<K>>
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+        K::Scope: 'static,
+    {
+        self.list_paged(api, lp, false).await
+    }
+
+    async fn list_paged<K>(
+        &self,
+        api: &Api<K>,
+        lp: &ListParams,
+        selected: bool,
+    ) -> kube::Result<ObjectList<K>>
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+        K::Scope: 'static,
+    {
+        let mut lp = self.paged_params::<K>(lp, selected);
+        let mut list = api.list(&lp).await?;
+        self.api_counters.add_objects(list.items.len());
+        while let Some(lp_next) = next_page(&mut lp, list.metadata.continue_.take()) {
+            let page = api.list(lp_next).await?;
+            self.api_counters.add_objects(page.items.len());
+            list.items.extend(page.items);
+            list.metadata = page.metadata;
+        }
+        Ok(list)
+    }
+
+    /// Like [`K8sClient::list_all`], but yields one page at a time so callers can fold large
+    /// lists into aggregates without holding every object.
+    pub fn list_pages<'a, K>(
+        &'a self,
+        api: &'a Api<K>,
+        lp: &ListParams,
+    ) -> BoxStream<'a, kube::Result<Vec<K>>>
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug + Send + 'a,
+        K::Scope: 'static,
+    {
+        let first = self.paged_params::<K>(lp, true);
+        futures::stream::try_unfold(Some(first), move |lp| async move {
+            let Some(mut lp) = lp else {
+                return Ok(None);
+            };
+            let mut list = api.list(&lp).await?;
+            self.api_counters.add_objects(list.items.len());
+            let more = next_page(&mut lp, list.metadata.continue_.take()).is_some();
+            Ok(Some((list.items, more.then_some(lp))))
+        })
+        .boxed()
+    }
+
     // Node APIs
     pub fn nodes(&self) -> Api<Node> {
         Api::all(self.client.clone())
@@ -88,6 +322,23 @@ impl K8sClient {
         Api::all(self.client.clone())
     }
 
+    pub fn csi_drivers(&self) -> Api<CSIDriver> {
+        Api::all(self.client.clone())
+    }
+
+    // Scheduling API
+    pub fn priority_classes(&self) -> Api<PriorityClass> {
+        Api::all(self.client.clone())
+    }
+
+    // Coordination API (leader-election leases)
+    pub fn leases(&self, namespace: Option<&str>) -> Api<Lease> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
     // Service APIs
     pub fn services(&self, namespace: Option<&str>) -> Api<Service> {
         match namespace {
@@ -115,6 +366,14 @@ impl K8sClient {
         }
     }
 
+    // Policy APIs
+    pub fn pod_disruption_budgets(&self, namespace: Option<&str>) -> Api<PodDisruptionBudget> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
     // Batch APIs
     pub fn cron_jobs(&self, namespace: Option<&str>) -> Api<CronJob> {
         match namespace {
@@ -124,10 +383,42 @@ impl K8sClient {
     }
 
     // Certificates API (CSR)
+    pub fn jobs(&self, namespace: Option<&str>) -> Api<Job> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
     pub fn certificate_signing_requests(&self) -> Api<CertificateSigningRequest> {
         Api::all(self.client.clone())
     }
 
+    pub fn config_maps(&self, namespace: Option<&str>) -> Api<ConfigMap> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
+    pub fn service_accounts(&self, namespace: Option<&str>) -> Api<ServiceAccount> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
+    pub fn ingresses(&self, namespace: Option<&str>) -> Api<Ingress> {
+        match namespace {
+            Some(ns) => Api::namespaced(self.client.clone(), ns),
+            None => Api::all(self.client.clone()),
+        }
+    }
+
+    pub fn ingress_classes(&self) -> Api<IngressClass> {
+        Api::all(self.client.clone())
+    }
+
     pub fn secrets(&self, namespace: Option<&str>) -> Api<Secret> {
         match namespace {
             Some(ns) => Api::namespaced(self.client.clone(), ns),
@@ -166,7 +457,6 @@ impl K8sClient {
     }
 
     // Events API (namespaced)
-    #[allow(dead_code)]
     pub fn events(&self, namespace: Option<&str>) -> Api<Event> {
         match namespace {
             Some(ns) => Api::namespaced(self.client.clone(), ns),
@@ -237,15 +527,23 @@ impl K8sClient {
         Ok(Some(out))
     }
 
-    /// Fetches pod metrics from metrics.k8s.io/v1beta1 (requires metrics-server).
+    /// Fetches pod metrics from metrics.k8s.io/v1beta1 (requires metrics-server), restricted to
+    /// the `--selector` if one is set.
     /// Returns list of (namespace, pod_name, container_name, cpu_usage_str, memory_usage_str) or None if API unavailable.
     pub async fn pod_metrics(
         &self,
+        namespace: Option<&str>,
     ) -> Result<Option<Vec<(String, String, String, String, String)>>> {
-        let req = Request::builder()
-            .method("GET")
-            .uri("/apis/metrics.k8s.io/v1beta1/pods")
-            .body(vec![])
+        let uri = match namespace {
+            Some(ns) => format!("/apis/metrics.k8s.io/v1beta1/namespaces/{}/pods", ns),
+            None => "/apis/metrics.k8s.io/v1beta1/pods".to_string(),
+        };
+        let lp = ListParams {
+            label_selector: self.label_selector.clone(),
+            ..Default::default()
+        };
+        let req = kube::core::Request::new(uri)
+            .list(&lp)
             .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
         let list: PodMetricsList = match self.client.request(req).await {
             Ok(l) => l,
@@ -271,6 +569,265 @@ impl K8sClient {
         }
         Ok(Some(out))
     }
+
+    /// Fetches the kubelet summary API (`/api/v1/nodes/{node}/proxy/stats/summary`) for one node.
+    /// Returns None if the node proxy is not permitted or the kubelet does not answer.
+    pub async fn node_stats_summary(&self, node_name: &str) -> Result<Option<StatsSummary>> {
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/nodes/{}/proxy/stats/summary", node_name))
+            .body(vec![])
+            .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
+        match self.client.request::<StatsSummary>(req).await {
+            Ok(s) => Ok(Some(s)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Fetches the kubelet's effective configuration (`/api/v1/nodes/{node}/proxy/configz`),
+    /// i.e. the config file merged with command-line flags and defaults.
+    /// Returns None if the node proxy is not permitted or the kubelet does not answer.
+    pub async fn node_kubelet_config(&self, node_name: &str) -> Result<Option<KubeletConfig>> {
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!("/api/v1/nodes/{}/proxy/configz", node_name))
+            .body(vec![])
+            .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
+        match self.client.request::<Configz>(req).await {
+            Ok(c) => Ok(Some(c.kubeletconfig)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// GET `path` on the API server and return the HTTP status, whatever it is (the body is
+    /// discarded). Errors only when no response arrives.
+    pub async fn api_request_status(&self, path: &str) -> Result<u16> {
+        let req = Request::builder()
+            .method("GET")
+            .uri(path)
+            .body(hyper::Body::empty())
+            .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
+        let response = self.client.send(req).await?;
+        Ok(response.status().as_u16())
+    }
+
+    /// Raw Prometheus text from the API server `/metrics` endpoint.
+    /// Returns None when the endpoint is not readable (needs `get` on the `/metrics` non-resource URL).
+    pub async fn apiserver_metrics(&self) -> Result<Option<String>> {
+        let req = Request::builder()
+            .method("GET")
+            .uri("/metrics")
+            .body(vec![])
+            .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
+        match self.client.request_text(req).await {
+            Ok(text) => Ok(Some(text)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Lists VerticalPodAutoscalers (autoscaling.k8s.io/v1).
+    /// Returns None if the VPA CRD is not installed or not readable.
+    pub async fn vertical_pod_autoscalers(
+        &self,
+        namespace: Option<&str>,
+    ) -> Result<Option<Vec<VerticalPodAutoscaler>>> {
+        let uri = match namespace {
+            Some(ns) => format!(
+                "/apis/autoscaling.k8s.io/v1/namespaces/{}/verticalpodautoscalers",
+                ns
+            ),
+            None => "/apis/autoscaling.k8s.io/v1/verticalpodautoscalers".to_string(),
+        };
+        let req = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(vec![])
+            .map_err(|e| anyhow::anyhow!("build request: {}", e))?;
+        match self.client.request::<VpaList>(req).await {
+            Ok(l) => Ok(Some(l.items)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Namespaced kinds the `--selector` leaves alone: Events carry no labels of the objects they
+/// are about.
+const UNSELECTED_KINDS: &[&str] = &["Event"];
+
+/// True for namespaced kinds; cluster-scoped objects (Nodes, PVs, ClusterRoles) are never
+/// limited by the `--selector`.
+fn is_namespaced<K>() -> bool
+where
+    K: Resource,
+    K::Scope: 'static,
+{
+    std::any::TypeId::of::<K::Scope>() == std::any::TypeId::of::<NamespaceResourceScope>()
+}
+
+/// Point `lp` at the page after `continue_token`; None when the list is complete.
+fn next_page(lp: &mut ListParams, continue_token: Option<String>) -> Option<&ListParams> {
+    let token = continue_token.filter(|t| !t.is_empty())?;
+    // The continue token pins the snapshot; the API server rejects it with a resourceVersion.
+    lp.resource_version = None;
+    lp.version_match = None;
+    lp.continue_token = Some(token);
+    Some(lp)
+}
+
+/// Subset of a VerticalPodAutoscaler: target workload and update mode.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VerticalPodAutoscaler {
+    #[serde(default)]
+    pub metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+    #[serde(default)]
+    pub spec: VpaSpec,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpaSpec {
+    #[serde(default)]
+    pub target_ref: Option<VpaTargetRef>,
+    #[serde(default)]
+    pub update_policy: Option<VpaUpdatePolicy>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VpaTargetRef {
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpaUpdatePolicy {
+    /// Off, Initial, Recreate or Auto (default when unset).
+    #[serde(default)]
+    pub update_mode: Option<String>,
+}
+
+impl VerticalPodAutoscaler {
+    /// True when the VPA changes pod resources of running workloads (update mode other than Off/Initial).
+    pub fn is_active(&self) -> bool {
+        !matches!(
+            self.spec
+                .update_policy
+                .as_ref()
+                .and_then(|p| p.update_mode.as_deref()),
+            Some("Off") | Some("Initial")
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct VpaList {
+    items: Vec<VerticalPodAutoscaler>,
+}
+
+/// Subset of the kubelet stats summary: nodefs and per-pod volume usage.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StatsSummary {
+    #[serde(default)]
+    pub node: NodeStats,
+    #[serde(default)]
+    pub pods: Vec<PodStats>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NodeStats {
+    /// nodefs (kubelet root filesystem).
+    #[serde(default)]
+    pub fs: Option<FsStats>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FsStats {
+    #[serde(default)]
+    pub capacity_bytes: Option<u64>,
+    #[serde(default)]
+    pub used_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PodStats {
+    #[serde(default)]
+    pub volume: Option<Vec<VolumeStats>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PvcReference {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeStats {
+    /// Set when the volume is backed by a PersistentVolumeClaim.
+    #[serde(default)]
+    pub pvc_ref: Option<PvcReference>,
+    #[serde(default)]
+    pub capacity_bytes: Option<u64>,
+    #[serde(default)]
+    pub used_bytes: Option<u64>,
+}
+
+impl FsStats {
+    /// Used percentage of capacity, if both values are known.
+    pub fn used_pct(&self) -> Option<f64> {
+        match (self.used_bytes, self.capacity_bytes) {
+            (Some(u), Some(c)) if c > 0 => Some(u as f64 / c as f64 * 100.0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Configz {
+    kubeletconfig: KubeletConfig,
+}
+
+/// Subset of the kubelet `KubeletConfiguration` returned by `/configz`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KubeletConfig {
+    #[serde(default)]
+    pub authentication: KubeletAuthentication,
+    #[serde(default)]
+    pub authorization: KubeletAuthorization,
+    /// Hard eviction signals, e.g. `memory.available` → `100Mi`.
+    #[serde(default)]
+    pub eviction_hard: Option<std::collections::HashMap<String, String>>,
+    #[serde(default, rename = "imageGCHighThresholdPercent")]
+    pub image_gc_high_threshold_percent: Option<i32>,
+    #[serde(default, rename = "imageGCLowThresholdPercent")]
+    pub image_gc_low_threshold_percent: Option<i32>,
+    #[serde(default)]
+    pub max_pods: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeletAuthentication {
+    #[serde(default)]
+    pub anonymous: KubeletAnonymousAuthentication,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeletAnonymousAuthentication {
+    #[serde(default)]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct KubeletAuthorization {
+    /// `Webhook` or `AlwaysAllow`.
+    #[serde(default)]
+    pub mode: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -312,3 +869,124 @@ struct ContainerMetrics {
     name: String,
     usage: std::collections::HashMap<String, String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cluster_for_context_uses_named_or_current_context() {
+        let kubeconfig = Kubeconfig::from_yaml(
+            "apiVersion: v1
+kind: Config
+current-context: dev
+contexts:
+- name: dev
+  context: {cluster: dev-cluster, user: dev}
+- name: prod
+  context: {cluster: prod-cluster, user: admin}
+",
+        )
+        .unwrap();
+        assert_eq!(
+            cluster_for_context(&kubeconfig, None).as_deref(),
+            Some("dev-cluster")
+        );
+        assert_eq!(
+            cluster_for_context(&kubeconfig, Some("prod")).as_deref(),
+            Some("prod-cluster")
+        );
+        assert_eq!(cluster_for_context(&kubeconfig, Some("staging")), None);
+    }
+
+    #[tokio::test]
+    async fn list_all_follows_continue_tokens() {
+        let service = tower::service_fn(|req: http::Request<hyper::Body>| async move {
+            let query = req.uri().query().unwrap_or_default().to_string();
+            assert!(query.contains("limit=500"), "{}", query);
+            let (items, next) = if query.contains("continue=page2") {
+                (r#"[{"metadata":{"name":"c"}}]"#, "")
+            } else {
+                (
+                    r#"[{"metadata":{"name":"a"}},{"metadata":{"name":"b"}}]"#,
+                    "page2",
+                )
+            };
+            let body = format!(
+                r#"{{"kind":"NamespaceList","apiVersion":"v1","metadata":{{"continue":"{}"}},"items":{}}}"#,
+                next, items
+            );
+            Ok::<_, std::convert::Infallible>(http::Response::new(hyper::Body::from(body)))
+        });
+        let client = K8sClient::from_client(Client::new(service, "default"), None);
+        let list = client
+            .list_all(&client.namespaces(), &ListParams::default())
+            .await
+            .unwrap();
+        let names: Vec<_> = list
+            .items
+            .iter()
+            .filter_map(|n| n.metadata.name.as_deref())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn fan_out_is_bounded_and_keeps_order() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let service = tower::service_fn(|_: http::Request<hyper::Body>| async move {
+            Ok::<_, std::convert::Infallible>(http::Response::new(hyper::Body::empty()))
+        });
+        let client = K8sClient::from_client(Client::new(service, "default"), None);
+        let (in_flight, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let out = client
+            .fan_out(0..50u32, |i| {
+                let (in_flight, peak) = (&in_flight, &peak);
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    i * 2
+                }
+            })
+            .await;
+        assert_eq!(out, (0..50).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(
+            peak.load(Ordering::SeqCst),
+            DEFAULT_API_CONCURRENCY as usize
+        );
+    }
+
+    #[tokio::test]
+    async fn selector_applies_to_namespaced_lists_only() {
+        let service = tower::service_fn(|_: http::Request<hyper::Body>| async move {
+            Ok::<_, std::convert::Infallible>(http::Response::new(hyper::Body::empty()))
+        });
+        let client = K8sClient::from_client(Client::new(service, "default"), None)
+            .with_label_selector(Some("app=checkout".to_string()));
+        let lp = ListParams::default();
+        let selector = |lp: ListParams| lp.label_selector;
+        assert_eq!(
+            selector(client.paged_params::<Pod>(&lp, true)).as_deref(),
+            Some("app=checkout")
+        );
+        assert_eq!(
+            selector(client.paged_params::<Deployment>(&lp, true)).as_deref(),
+            Some("app=checkout")
+        );
+        assert_eq!(
+            selector(client.paged_params::<Service>(&lp, true)).as_deref(),
+            Some("app=checkout")
+        );
+        assert_eq!(selector(client.paged_params::<Pod>(&lp, false)), None);
+        assert_eq!(selector(client.paged_params::<ConfigMap>(&lp, false)), None);
+        assert_eq!(selector(client.paged_params::<Node>(&lp, true)), None);
+        assert_eq!(selector(client.paged_params::<Event>(&lp, true)), None);
+        let own = ListParams::default().labels("app=kubeowler-node-inspector");
+        assert_eq!(
+            selector(client.paged_params::<Pod>(&own, true)).as_deref(),
+            Some("app=kubeowler-node-inspector")
+        );
+    }
+}
@@ -1,3 +1,4 @@
 pub mod client;
+pub mod throttle;
 
-pub use client::K8sClient;
+pub use client::{K8sClient, KubeletConfig, StatsSummary, VerticalPodAutoscaler};
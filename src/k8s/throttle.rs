@@ -0,0 +1,394 @@
+//! Client-side rate limiting, retries and request timeout, layered under every `Api` call of a
+//! live `K8sClient` so a throttled or briefly unavailable API server does not abort the run.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use http::{Request, Response, StatusCode};
+use hyper::Body;
+use rand::Rng;
+use tower::{BoxError, Layer, Service};
+use tracing::Instrument;
+
+use crate::cli::ClusterAccess;
+
+/// Default sustained request rate (`--qps`).
+pub const DEFAULT_QPS: f64 = 50.0;
+/// Default number of requests allowed above the sustained rate (`--burst`).
+pub const DEFAULT_BURST: u32 = 100;
+/// Default retries of a throttled or failed request (`--max-retries`).
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default seconds to wait for a response (`--request-timeout`).
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
+
+/// First retry delay; doubled per attempt up to `MAX_BACKOFF`.
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Request limits taken from the global CLI options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApiLimits {
+    /// Sustained requests per second; 0 disables rate limiting.
+    pub qps: f64,
+    pub burst: u32,
+    pub max_retries: u32,
+    /// Time to wait for response headers per attempt; None waits indefinitely.
+    pub timeout: Option<Duration>,
+}
+
+impl ApiLimits {
+    pub fn from_access(access: &ClusterAccess) -> Self {
+        Self {
+            qps: access.qps,
+            burst: access.burst,
+            max_retries: access.max_retries,
+            timeout: (access.request_timeout > 0)
+                .then(|| Duration::from_secs(access.request_timeout)),
+        }
+    }
+}
+
+/// Requests sent through one client (every attempt counts) and objects it listed, read before
+/// and after each inspection module to attribute API traffic to it.
+#[derive(Debug, Default)]
+pub struct ApiCounters {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    objects: AtomicU64,
+}
+
+impl ApiCounters {
+    pub fn requests(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// Attempts answered with a 4xx/5xx status, timed out or failed in transport.
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// Objects returned by `K8sClient::list_all` and `list_pages`.
+    pub fn objects_listed(&self) -> u64 {
+        self.objects.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_objects(&self, count: usize) {
+        self.objects.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    fn record(&self, failed: bool) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Token bucket: `burst` requests at once, refilled at `qps` per second. Callers reserve a
+/// token and sleep for the returned delay, so waiting requests keep their order.
+struct TokenBucket {
+    qps: f64,
+    burst: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn new(qps: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            qps,
+            burst,
+            tokens: burst,
+            last: Instant::now(),
+        }
+    }
+
+    fn reserve(&mut self, now: Instant) -> Duration {
+        if self.qps <= 0.0 {
+            return Duration::ZERO;
+        }
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.qps).min(self.burst) - 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.qps)
+        }
+    }
+}
+
+/// Delay before retry `attempt` (0-based): the server's `Retry-After` seconds when given,
+/// else exponential backoff with up to 25% jitter.
+fn retry_delay(attempt: u32, retry_after: Option<u64>) -> Duration {
+    if let Some(secs) = retry_after {
+        return Duration::from_secs(secs).min(MAX_BACKOFF);
+    }
+    let backoff = BASE_BACKOFF
+        .saturating_mul(1u32 << attempt.min(16))
+        .min(MAX_BACKOFF);
+    let jitter = rand::thread_rng().gen_range(0.0..0.25);
+    backoff.mul_f64(1.0 + jitter)
+}
+
+/// 429 is retried for every method (the server did not process the request); 5xx and transport
+/// errors only for reads, so writes from `fix` or `deploy-cron` are never sent twice.
+fn is_retryable_status(status: StatusCode, idempotent: bool) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || (idempotent
+            && matches!(
+                status,
+                StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ))
+}
+
+fn retry_after_secs<B>(response: &Response<B>) -> Option<u64> {
+    response
+        .headers()
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Layer adding [`ApiLimits`] to a kube client service stack.
+#[derive(Clone)]
+pub struct ThrottleLayer {
+    limits: ApiLimits,
+    bucket: Arc<Mutex<TokenBucket>>,
+    counters: Arc<ApiCounters>,
+}
+
+impl ThrottleLayer {
+    pub fn new(limits: ApiLimits) -> Self {
+        Self {
+            limits,
+            bucket: Arc::new(Mutex::new(TokenBucket::new(limits.qps, limits.burst))),
+            counters: Arc::default(),
+        }
+    }
+
+    /// Counters updated by every service built from this layer.
+    pub fn counters(&self) -> Arc<ApiCounters> {
+        self.counters.clone()
+    }
+}
+
+impl<S> Layer<S> for ThrottleLayer {
+    type Service = Throttle<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Throttle {
+            inner: Arc::new(tokio::sync::Mutex::new(inner)),
+            limits: self.limits,
+            bucket: self.bucket.clone(),
+            counters: self.counters.clone(),
+        }
+    }
+}
+
+/// Service built by [`ThrottleLayer`]. The inner service is shared so a request can be re-sent.
+pub struct Throttle<S> {
+    inner: Arc<tokio::sync::Mutex<S>>,
+    limits: ApiLimits,
+    bucket: Arc<Mutex<TokenBucket>>,
+    counters: Arc<ApiCounters>,
+}
+
+impl<S, B> Service<Request<Body>> for Throttle<S>
+where
+    S: Service<Request<Body>, Response = Response<B>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: Send + 'static,
+{
+    type Response = Response<B>;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Response<B>, BoxError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Readiness of the inner service is awaited per attempt in `call`.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let inner = self.inner.clone();
+        let bucket = self.bucket.clone();
+        let counters = self.counters.clone();
+        let limits = self.limits;
+        // Created here, where the client's buffer worker has entered the caller's span, so the
+        // request shows up under the inspection module that sent it.
+        let span = tracing::info_span!(
+            "k8s.request",
+            http.method = %req.method(),
+            url.path = req.uri().path(),
+            http.status_code = tracing::field::Empty,
+            retries = tracing::field::Empty,
+        );
+        let request_span = span.clone();
+        Box::pin(
+            async move {
+                let (parts, body) = req.into_parts();
+                let body = hyper::body::to_bytes(body).await?;
+                let idempotent = matches!(parts.method, http::Method::GET | http::Method::HEAD);
+                let mut attempt = 0;
+                loop {
+                    let wait = bucket.lock().unwrap().reserve(Instant::now());
+                    if !wait.is_zero() {
+                        tokio::time::sleep(wait).await;
+                    }
+                    let mut request = Request::new(Body::from(body.clone()));
+                    *request.method_mut() = parts.method.clone();
+                    *request.uri_mut() = parts.uri.clone();
+                    *request.version_mut() = parts.version;
+                    *request.headers_mut() = parts.headers.clone();
+                    let future = {
+                        let mut svc = inner.lock().await;
+                        futures::future::poll_fn(|cx| svc.poll_ready(cx))
+                            .await
+                            .map_err(Into::into)?;
+                        svc.call(request)
+                    };
+                    let result = match limits.timeout {
+                        Some(t) => match tokio::time::timeout(t, future).await {
+                            Ok(r) => r.map_err(Into::into),
+                            Err(_) => Err(format!(
+                                "{} {} timed out after {}s",
+                                parts.method,
+                                parts.uri.path(),
+                                t.as_secs()
+                            )
+                            .into()),
+                        },
+                        None => future.await.map_err(Into::into),
+                    };
+                    let status = result.as_ref().ok().map(|r| r.status());
+                    counters
+                        .record(status.is_none_or(|s| s.is_client_error() || s.is_server_error()));
+                    crate::telemetry::record_api_request(parts.method.as_str(), status);
+                    if let Some(status) = status {
+                        request_span.record("http.status_code", status.as_u16());
+                    }
+                    request_span.record("retries", attempt);
+                    let retry_after = match &result {
+                        Ok(resp) if is_retryable_status(resp.status(), idempotent) => {
+                            retry_after_secs(resp)
+                        }
+                        Err(_) if idempotent => None,
+                        _ => return result,
+                    };
+                    if attempt >= limits.max_retries {
+                        return result;
+                    }
+                    let delay = retry_delay(attempt, retry_after);
+                    match &result {
+                        Ok(resp) => log::debug!(
+                            "{} {} returned {}; retrying in {:?}",
+                            parts.method,
+                            parts.uri.path(),
+                            resp.status(),
+                            delay
+                        ),
+                        Err(e) => log::debug!(
+                            "{} {} failed: {}; retrying in {:?}",
+                            parts.method,
+                            parts.uri.path(),
+                            e,
+                            delay
+                        ),
+                    }
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+            .instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn token_bucket_allows_burst_then_paces_at_qps() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(10.0, 2);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::from_millis(100));
+        assert_eq!(bucket.reserve(start), Duration::from_millis(200));
+        let mut unlimited = TokenBucket::new(0.0, 1);
+        assert_eq!(unlimited.reserve(start), Duration::ZERO);
+        assert_eq!(unlimited.reserve(start), Duration::ZERO);
+    }
+
+    #[test]
+    fn retry_delay_honors_retry_after_and_caps_backoff() {
+        assert_eq!(retry_delay(0, Some(2)), Duration::from_secs(2));
+        assert_eq!(retry_delay(0, Some(600)), MAX_BACKOFF);
+        let first = retry_delay(0, None);
+        assert!(first >= BASE_BACKOFF && first < BASE_BACKOFF * 2);
+        assert!(retry_delay(20, None) <= MAX_BACKOFF.mul_f64(1.25));
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS, false));
+        assert!(!is_retryable_status(StatusCode::SERVICE_UNAVAILABLE, false));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE, true));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND, true));
+    }
+
+    fn throttled_client(
+        calls: Arc<AtomicU32>,
+        max_retries: u32,
+    ) -> (kube::Client, Arc<ApiCounters>) {
+        let service = tower::service_fn(move |_req: Request<Body>| {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                let response = if n < 2 {
+                    Response::builder()
+                        .status(StatusCode::TOO_MANY_REQUESTS)
+                        .header(http::header::RETRY_AFTER, "0")
+                        .body(Body::from(r#"{"kind":"Status","code":429}"#))
+                } else {
+                    Response::builder().body(Body::from(
+                        r#"{"kind":"NamespaceList","apiVersion":"v1","metadata":{},"items":[]}"#,
+                    ))
+                };
+                Ok::<_, std::convert::Infallible>(response.unwrap())
+            }
+        });
+        let layer = ThrottleLayer::new(ApiLimits {
+            qps: 0.0,
+            burst: 1,
+            max_retries,
+            timeout: Some(Duration::from_secs(5)),
+        });
+        let counters = layer.counters();
+        (kube::Client::new(layer.layer(service), "default"), counters)
+    }
+
+    #[tokio::test]
+    async fn retries_throttled_requests() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let (client, counters) = throttled_client(calls.clone(), 3);
+        let api: kube::Api<k8s_openapi::api::core::v1::Namespace> = kube::Api::all(client);
+        assert!(api.list(&Default::default()).await.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!((counters.requests(), counters.errors()), (3, 2));
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let api: kube::Api<k8s_openapi::api::core::v1::Namespace> =
+            kube::Api::all(throttled_client(calls.clone(), 1).0);
+        assert!(api.list(&Default::default()).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
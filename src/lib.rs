@@ -1,7 +1,51 @@
+//! Library behind the `kubeowler` CLI: the inspection modules, report types, scoring and report
+//! rendering, for embedding inspections in other tools (an operator, a CI job) without shelling
+//! out to the binary.
+//!
+//! The entry points are re-exported at the crate root: connect a [`K8sClient`], run an
+//! [`InspectionRunner`] to get a [`ClusterReport`], and render it with a [`ReportGenerator`].
+//!
+//! ```no_run
+//! use kubeowler_core::cli::{ClusterAccess, InspectionType};
+//! use kubeowler_core::{ClusterReport, InspectionOptions, InspectionRunner, K8sClient};
+//! use kubeowler_core::ReportGenerator;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let client = K8sClient::new(None, &ClusterAccess::default()).await?;
+//! let report: ClusterReport = InspectionRunner::new(client)
+//!     .with_options(InspectionOptions::default())
+//!     .run_inspections(InspectionType::All, Some("payments"), "kubeowler", None)
+//!     .await?;
+//! println!("score {:.1}", report.overall_score);
+//! let markdown = ReportGenerator::new().generate_markdown_string(&report, None, None, None, None)?;
+//! # let _ = markdown;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The `cli` module holds the option types shared with the CLI (inspection types, languages,
+//! cluster access); the other modules back the individual subcommands. Argument parsing, the
+//! terminal UI, the dashboard server and the commands that change a cluster or file tickets
+//! live in the `kubeowler` CLI crate.
+
+pub mod baseline;
+pub mod benchmark;
+pub mod capacity;
 pub mod cli;
+pub mod config;
 pub mod inspections;
 pub mod k8s;
 pub mod node_inspection;
+pub mod offline;
+pub mod ownership;
+pub mod preflight;
 pub mod reporting;
 pub mod scoring;
+pub mod sla;
+pub mod telemetry;
 pub mod utils;
+
+pub use inspections::types::ClusterReport;
+pub use inspections::{InspectionOptions, InspectionRunner};
+pub use k8s::K8sClient;
+pub use reporting::ReportGenerator;
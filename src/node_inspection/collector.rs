@@ -1,33 +1,40 @@
 //! Collects node inspection JSON from kubeowler-node-inspector DaemonSet pods via Pod logs.
-//! Does not deploy the DaemonSet; only identifies and collects from existing pods.
+//! Does not deploy the DaemonSet (see `kubeowler node-inspector install`); only identifies and
+//! collects from existing pods.
 //! The container runs the script once at startup and writes JSON to stdout (Pod logs).
 //! Kubeowler fetches each pod's log and parses the JSON. Data is from container start time;
-//! restart DaemonSet pods to refresh. Container state counts are filled via Kubernetes API.
+//! restart DaemonSet pods to refresh. Alternatively (`--node-collect exec`) the script is exec'd
+//! in each pod on demand for fresh data. Container state counts are filled via Kubernetes API.
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use colored::Colorize;
 use k8s_openapi::api::apps::v1::DaemonSet;
 use k8s_openapi::api::core::v1::Pod;
-use kube::api::{ListParams, LogParams, Patch, PatchParams};
+use kube::api::{AttachParams, ListParams, LogParams, Patch, PatchParams};
 use kube::Api;
 use log::debug;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use tokio::time::sleep;
+use tokio::io::AsyncReadExt;
+use tokio::time::{sleep, timeout};
 
+use crate::inspections::notice::{NoticeLevel, Notify};
 use crate::k8s::K8sClient;
 use crate::node_inspection::NodeInspectionResult;
 
-const NODE_INSPECTOR_LABEL: &str = "app=kubeowler-node-inspector";
-const DEFAULT_NODE_INSPECTOR_NAMESPACE: &str = "kubeowler";
-const CONTAINER_NAME: &str = "inspector";
-const DAEMONSET_NAME: &str = "kubeowler-node-inspector";
+pub const NODE_INSPECTOR_LABEL: &str = "app=kubeowler-node-inspector";
+pub const DEFAULT_NODE_INSPECTOR_NAMESPACE: &str = "kubeowler";
+pub const CONTAINER_NAME: &str = "inspector";
+pub const DAEMONSET_NAME: &str = "kubeowler-node-inspector";
 #[allow(dead_code)]
 const STALENESS_THRESHOLD_HOURS: u64 = 24;
 const ROLLOUT_WAIT_TIMEOUT_SECS: u64 = 180;
 const LOG_POLL_INTERVAL_SECS: u64 = 6;
 const LOG_POLL_TIMEOUT_SECS: u64 = 300; // 5 minutes
+/// Inspection script inside the node inspector image.
+const NODE_SCRIPT_PATH: &str = "/node-check-universal.sh";
+/// Per-pod limit for one exec'd script run.
+const EXEC_TIMEOUT_SECS: u64 = 120;
 
 /// Status of node inspector pre-check before collection.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +54,7 @@ async fn poll_for_logs(
     pods_api: &Api<Pod>,
     running_pod_names: &[String],
     log_params: &LogParams,
+    notify: &Notify,
 ) -> (Vec<DateTime<Utc>>, usize, usize, bool) {
     let total = running_pod_names.len();
     let deadline = Instant::now() + Duration::from_secs(LOG_POLL_TIMEOUT_SECS);
@@ -85,12 +93,14 @@ async fn poll_for_logs(
             return (timestamps, ready_count, total, true);
         }
 
-        println!(
-            "   {}  ({}, {}/{} pods have logs)",
-            "Waiting for node inspector logs...".bright_yellow(),
-            format_duration(elapsed_secs),
-            ready_count,
-            total
+        notify(
+            NoticeLevel::Progress,
+            &format!(
+                "Waiting for node inspector logs... ({}, {}/{} pods have logs)",
+                format_duration(elapsed_secs),
+                ready_count,
+                total
+            ),
         );
         sleep(Duration::from_secs(LOG_POLL_INTERVAL_SECS)).await;
         elapsed_secs += LOG_POLL_INTERVAL_SECS;
@@ -105,7 +115,7 @@ fn format_duration(secs: u64) -> String {
     }
 }
 
-fn is_pod_running(pod: &Pod) -> bool {
+pub(crate) fn is_pod_running(pod: &Pod) -> bool {
     pod.status
         .as_ref()
         .and_then(|s| s.phase.as_deref())
@@ -116,15 +126,17 @@ fn is_pod_running(pod: &Pod) -> bool {
 /// Ensures node inspector data is fresh before collection.
 /// 1. No pods running → NotDeployed. 2. Pods running but no logs → poll (6s interval, 5 min timeout).
 /// 3. Has logs → check staleness; if >24h restart DaemonSet and poll again.
-/// On timeout: proceed with partial data (ReadyPartial).
+///
+/// On timeout: proceed with partial data (ReadyPartial). Waiting and timeouts are reported to `notify`.
 pub async fn ensure_node_inspector_ready(
     client: &K8sClient,
     namespace: &str,
     staleness_hours: u64,
+    notify: &Notify,
 ) -> NodeInspectorStatus {
     let pods_api: Api<Pod> = client.pods(Some(namespace));
     let list_params = ListParams::default().labels(NODE_INSPECTOR_LABEL);
-    let pods = match pods_api.list(&list_params).await {
+    let pods = match client.list_all(&pods_api, &list_params).await {
         Ok(l) => l,
         Err(e) => {
             debug!(
@@ -159,14 +171,15 @@ pub async fn ensure_node_inspector_ready(
 
     // Poll for logs (6s interval, 5 min timeout)
     let (timestamps, ready_count, total, timed_out) =
-        poll_for_logs(&pods_api, &running_pod_names, &log_params).await;
+        poll_for_logs(&pods_api, &running_pod_names, &log_params, notify).await;
 
     if timed_out {
-        println!(
-            "{}  Node inspector: {}/{} pods have logs (timeout 5 min). Proceeding with partial data.",
-            "⚠️".bright_yellow(),
-            ready_count,
-            total
+        notify(
+            NoticeLevel::Warning,
+            &format!(
+                "Node inspector: {}/{} pods have logs (timeout 5 min). Proceeding with partial data.",
+                ready_count, total
+            ),
         );
         return NodeInspectorStatus::ReadyPartial {
             ready: ready_count,
@@ -245,7 +258,7 @@ pub async fn ensure_node_inspector_ready(
     }
 
     // Re-list and poll for logs again (new pods after restart)
-    let pods2 = match pods_api.list(&list_params).await {
+    let pods2 = match client.list_all(&pods_api, &list_params).await {
         Ok(l) => l,
         Err(_) => return NodeInspectorStatus::NotDeployed,
     };
@@ -260,14 +273,15 @@ pub async fn ensure_node_inspector_ready(
     }
 
     let (_, ready_count2, total2, timed_out2) =
-        poll_for_logs(&pods_api, &running_pod_names2, &log_params).await;
+        poll_for_logs(&pods_api, &running_pod_names2, &log_params, notify).await;
 
     if timed_out2 {
-        println!(
-            "{}  Node inspector: restarted; {}/{} pods have logs (timeout 5 min). Proceeding with partial data.",
-            "⚠️".bright_yellow(),
-            ready_count2,
-            total2
+        notify(
+            NoticeLevel::Warning,
+            &format!(
+                "Node inspector: restarted; {}/{} pods have logs (timeout 5 min). Proceeding with partial data.",
+                ready_count2, total2
+            ),
         );
         return NodeInspectorStatus::ReadyPartial {
             ready: ready_count2,
@@ -290,7 +304,7 @@ pub async fn collect_node_inspections(
     let ns = namespace.unwrap_or(DEFAULT_NODE_INSPECTOR_NAMESPACE);
     let pods_api: Api<Pod> = client.pods(Some(ns));
     let list_params = ListParams::default().labels(NODE_INSPECTOR_LABEL);
-    let pods = match pods_api.list(&list_params).await {
+    let pods = match client.list_all(&pods_api, &list_params).await {
         Ok(l) => l,
         Err(e) => {
             debug!("Node inspector DaemonSet pods list failed in {}: {}", ns, e);
@@ -333,19 +347,7 @@ pub async fn collect_node_inspections(
         }
 
         // Script outputs a single JSON object to stdout at container start
-        let parsed: NodeInspectionResult = serde_json::from_str(trimmed).with_context(|| {
-            format!("Parse node inspection JSON from pod {}: {}", name, trimmed)
-        })?;
-
-        // Prefer node name from pod spec if script didn't set it
-        let mut result = parsed;
-        if result.node_name.is_empty() && !node_name.is_empty() {
-            result.node_name = node_name;
-        }
-        if result.hostname.is_empty() {
-            result.hostname = result.node_name.clone();
-        }
-        results.push(result);
+        results.push(parse_node_output(name, node_name, trimmed)?);
     }
 
     results.sort_by(|a, b| a.node_name.cmp(&b.node_name));
@@ -356,11 +358,112 @@ pub async fn collect_node_inspections(
     Ok(results)
 }
 
+/// Parse the script's JSON output; the node name falls back to the pod's node.
+fn parse_node_output(pod: &str, node_name: String, output: &str) -> Result<NodeInspectionResult> {
+    let mut result: NodeInspectionResult = serde_json::from_str(output)
+        .with_context(|| format!("Parse node inspection JSON from pod {}: {}", pod, output))?;
+    if result.node_name.is_empty() && !node_name.is_empty() {
+        result.node_name = node_name;
+    }
+    if result.hostname.is_empty() {
+        result.hostname = result.node_name.clone();
+    }
+    Ok(result)
+}
+
+/// Run the inspection script in one pod and return its stdout.
+async fn exec_script(pods_api: &Api<Pod>, pod: &str) -> Result<String> {
+    exec_in_inspector(pods_api, pod, vec![NODE_SCRIPT_PATH.to_string()]).await
+}
+
+/// Run a command in the inspector container of one pod and return its stdout.
+pub(crate) async fn exec_in_inspector(
+    pods_api: &Api<Pod>,
+    pod: &str,
+    command: Vec<String>,
+) -> Result<String> {
+    let params = AttachParams::default()
+        .container(CONTAINER_NAME)
+        .stdout(true)
+        .stderr(false);
+    let mut process = pods_api
+        .exec(pod, command, &params)
+        .await
+        .with_context(|| format!("exec in pod {}", pod))?;
+    let mut output = String::new();
+    if let Some(mut stdout) = process.stdout() {
+        stdout.read_to_string(&mut output).await?;
+    }
+    process.join().await?;
+    Ok(output)
+}
+
+/// Collects one NodeInspectionResult per node by exec'ing the inspection script in every
+/// Running DaemonSet pod, so the data is taken now rather than at pod start. No staleness check
+/// or DaemonSet restart is needed. At most `--api-concurrency` execs run at once; pods whose
+/// exec fails or times out are skipped. Requires `create` on `pods/exec` in the node inspector
+/// namespace.
+pub async fn collect_node_inspections_exec(
+    client: &K8sClient,
+    namespace: &str,
+) -> Result<Vec<NodeInspectionResult>> {
+    let pods_api: Api<Pod> = client.pods(Some(namespace));
+    let pods = client
+        .list_all(
+            &pods_api,
+            &ListParams::default().labels(NODE_INSPECTOR_LABEL),
+        )
+        .await
+        .with_context(|| format!("listing node inspector pods in {}", namespace))?;
+
+    let runs = pods.items.iter().filter(|p| is_pod_running(p));
+    let outputs = client.fan_out(runs, |pod| {
+        let pods_api = pods_api.clone();
+        async move {
+            let name = pod.metadata.name.as_deref().unwrap_or("unknown");
+            let node_name = pod
+                .spec
+                .as_ref()
+                .and_then(|s| s.node_name.clone())
+                .unwrap_or_default();
+            let output = match timeout(
+                Duration::from_secs(EXEC_TIMEOUT_SECS),
+                exec_script(&pods_api, name),
+            )
+            .await
+            {
+                Ok(Ok(out)) => out,
+                Ok(Err(e)) => {
+                    debug!("Node inspection exec failed for pod {}: {:#}", name, e);
+                    return None;
+                }
+                Err(_) => {
+                    debug!("Node inspection exec timed out for pod {}", name);
+                    return None;
+                }
+            };
+            match parse_node_output(name, node_name, output.trim()) {
+                Ok(r) => Some(r),
+                Err(e) => {
+                    debug!("{:#}", e);
+                    None
+                }
+            }
+        }
+    });
+    let mut results: Vec<NodeInspectionResult> = outputs.await.into_iter().flatten().collect();
+
+    results.sort_by(|a, b| a.node_name.cmp(&b.node_name));
+    fill_container_state_counts(client, &mut results).await;
+    Ok(results)
+}
+
 /// Lists all pods cluster-wide, aggregates container states per node, and sets container_state_counts on each result.
 async fn fill_container_state_counts(client: &K8sClient, results: &mut [NodeInspectionResult]) {
     let pods_api: Api<Pod> = client.pods(None);
     let list_params = ListParams::default();
-    let all_pods = match pods_api.list(&list_params).await {
+    // Node-wide counts: every pod on the node, whatever `--selector` limits the workloads to.
+    let all_pods = match client.list_unselected(&pods_api, &list_params).await {
         Ok(l) => l,
         Err(e) => {
             debug!("List all pods for container state counts failed: {}", e);
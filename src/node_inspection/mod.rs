@@ -3,8 +3,13 @@
 pub mod collector;
 pub mod types;
 
-pub use collector::{collect_node_inspections, ensure_node_inspector_ready, NodeInspectorStatus};
+pub use collector::{
+    collect_node_inspections, collect_node_inspections_exec, ensure_node_inspector_ready,
+    NodeInspectorStatus,
+};
 #[allow(unused_imports)]
 pub use types::{
-    NodeCertificate, NodeInspectionResult, NodeKernel, NodeResources, NodeSecurity, NodeServices,
+    NodeCertificate, NodeFilePermission, NodeHardening, NodeInspectionResult, NodeKernel,
+    NodeListeningPort, NodePartitionUsage, NodeResources, NodeSecurity, NodeServices,
+    NodeSshConfig, NodeStoragePartitions,
 };
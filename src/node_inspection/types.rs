@@ -1,11 +1,12 @@
 //! Types for node inspection (DaemonSet-collected) results.
 //! Schema aligns with the universal node script JSON output: resources, services, security, kernel.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Single node inspection result (one JSON object per node from the DaemonSet script).
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeInspectionResult {
     pub node_name: String,
     #[serde(default)]
@@ -49,10 +50,97 @@ pub struct NodeInspectionResult {
     /// Per-mount disk usage (from df); used for Node disk usage table and 80%/90% thresholds.
     #[serde(default)]
     pub node_disks: Option<Vec<NodeDiskMount>>,
+    /// Kubelet root (nodefs) and container runtime root (imagefs) partition usage.
+    #[serde(default)]
+    pub storage_partitions: Option<NodeStoragePartitions>,
+    /// OS hardening data (file permissions, kernel modules, sysctls, listening ports, sshd);
+    /// absent from older node inspector images.
+    #[serde(default)]
+    pub hardening: Option<NodeHardening>,
+}
+
+/// OS-level CIS inputs collected on the host; evaluated into NODE-014..NODE-018.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodeHardening {
+    /// Mode and owner of kubelet/kubeadm files that exist on the node.
+    #[serde(default)]
+    pub file_permissions: Vec<NodeFilePermission>,
+    /// Loaded modules from the script's list of filesystems/protocols CIS recommends disabling.
+    #[serde(default)]
+    pub loaded_kernel_modules: Vec<String>,
+    /// Hardening sysctls by dotted name (e.g. "net.ipv4.conf.all.send_redirects" → "0").
+    #[serde(default)]
+    pub sysctls: HashMap<String, String>,
+    /// TCP listening sockets from /proc/net/tcp and tcp6.
+    #[serde(default)]
+    pub listening_ports: Vec<NodeListeningPort>,
+    /// Effective sshd settings (first occurrence in sshd_config); None when sshd is not installed.
+    #[serde(default)]
+    pub ssh: Option<NodeSshConfig>,
+}
+
+/// Octal mode and numeric owner of one file, e.g. "644", "0:0".
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodeFilePermission {
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub mode: String,
+    #[serde(default)]
+    pub owner: String,
+}
+
+/// One listening TCP socket (local address and port).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodeListeningPort {
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub port: u16,
+}
+
+/// sshd_config values as written (lowercase keywords); unset keys are None (sshd default).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodeSshConfig {
+    #[serde(default)]
+    pub permit_root_login: Option<String>,
+    #[serde(default)]
+    pub password_authentication: Option<String>,
+    #[serde(default)]
+    pub permit_empty_passwords: Option<String>,
+    #[serde(default)]
+    pub x11_forwarding: Option<String>,
+    #[serde(default)]
+    pub max_auth_tries: Option<String>,
+}
+
+/// nodefs = partition holding /var/lib/kubelet; imagefs = partition holding the runtime root
+/// (/var/lib/containerd, /var/lib/docker or /var/lib/containers). Used for NODE-012/NODE-013.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodeStoragePartitions {
+    #[serde(default)]
+    pub nodefs: Option<NodePartitionUsage>,
+    #[serde(default)]
+    pub imagefs: Option<NodePartitionUsage>,
+}
+
+/// df result for the partition containing `path` (host perspective).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NodePartitionUsage {
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub device: String,
+    #[serde(default)]
+    pub total_g: Option<f64>,
+    #[serde(default)]
+    pub used_g: Option<f64>,
+    #[serde(default)]
+    pub used_pct: Option<f64>,
 }
 
 /// One mount point row: device, mount_point, fstype, total_g, used_g, used_pct (for report and NODE-004/NODE-005).
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeDiskMount {
     #[serde(default)]
     pub device: String,
@@ -69,7 +157,7 @@ pub struct NodeDiskMount {
 }
 
 /// One certificate entry from node (path, expiration, days remaining, status).
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeCertificate {
     #[serde(default)]
     pub path: String,
@@ -82,7 +170,7 @@ pub struct NodeCertificate {
 }
 
 /// Resource category: CPU, memory, disk, load.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeResources {
     #[serde(default)]
     pub cpu_cores: Option<u32>,
@@ -127,7 +215,7 @@ pub struct NodeResources {
 }
 
 /// Services: runtime, journald, crontab, ntp_synced, kubelet, container_runtime.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeServices {
     #[serde(default)]
     pub runtime: String,
@@ -148,7 +236,7 @@ pub struct NodeServices {
 }
 
 /// Security: SELinux, firewalld, IPVS, br_netfilter, overlay, nf_conntrack.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeSecurity {
     #[serde(default)]
     pub selinux: Option<String>,
@@ -173,7 +261,7 @@ pub struct NodeSecurity {
 }
 
 /// Network and stability: inode, OOM, file descriptors.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeStability {
     #[serde(default)]
     pub inode_used_pct: Option<f64>,
@@ -186,7 +274,7 @@ pub struct NodeStability {
 }
 
 /// Kernel: key sysctl values (2–3 keys).
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct NodeKernel {
     #[serde(default)]
     pub net_ipv4_ip_forward: Option<String>,
@@ -0,0 +1,451 @@
+//! Offline mode: load `kubectl get -o yaml|json` dumps or `kubectl cluster-info dump` output from
+//! a directory and serve them through a read-only, in-process API, so the inspectors run unchanged
+//! through a regular `kube::Client` without cluster access.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use http::{Request, Response, StatusCode};
+use hyper::Body;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::k8s::K8sClient;
+
+/// Objects loaded from a dump directory, indexed by (API group, plural resource).
+#[derive(Debug, Default)]
+pub struct DumpStore {
+    objects: BTreeMap<(String, String), Vec<Value>>,
+    /// `/version` payload, from a dumped `kubectl version -o json` (serverVersion) if present.
+    version: Option<Value>,
+    /// Resources the inspectors listed that the dump does not contain.
+    missing: Mutex<BTreeSet<String>>,
+}
+
+impl DumpStore {
+    /// Number of loaded objects per `resource[.group]`.
+    pub fn resource_counts(&self) -> Vec<(String, usize)> {
+        self.objects
+            .iter()
+            .map(|((group, plural), items)| (resource_label(group, plural), items.len()))
+            .collect()
+    }
+
+    pub fn object_count(&self) -> usize {
+        self.objects.values().map(Vec::len).sum()
+    }
+
+    /// Resources that were requested but not present in the dump (served as empty lists).
+    pub fn missing_resources(&self) -> Vec<String> {
+        self.missing
+            .lock()
+            .map(|m| m.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn add(&mut self, doc: Value) {
+        let kind = doc["kind"].as_str().unwrap_or_default().to_string();
+        let api_version = doc["apiVersion"].as_str().unwrap_or("v1").to_string();
+        if let Some(items) = doc["items"].as_array().filter(|_| kind.ends_with("List")) {
+            // Typed lists (PodList, as written by cluster-info dump) may omit kind on items.
+            let item_kind = kind.trim_end_matches("List").to_string();
+            for item in items {
+                let mut item = item.clone();
+                if item["kind"].as_str().is_none() && !item_kind.is_empty() {
+                    item["kind"] = json!(item_kind);
+                    item["apiVersion"] = json!(api_version);
+                }
+                self.add(item);
+            }
+            return;
+        }
+        if kind.is_empty() {
+            if let Some(v) = doc
+                .get("serverVersion")
+                .or(doc.get("gitVersion").map(|_| &doc))
+            {
+                self.version = Some(v.clone());
+            }
+            return;
+        }
+        let key = (group_of(&api_version).to_string(), plural_of(&kind));
+        self.objects.entry(key).or_default().push(doc);
+    }
+
+    fn handle(&self, req: &Request<Body>) -> Response<Body> {
+        if req.method() != http::Method::GET {
+            return status_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                "MethodNotAllowed",
+                "offline mode is read-only",
+            );
+        }
+        let path = req.uri().path();
+        if path == "/version" {
+            return match &self.version {
+                Some(v) => json_response(version_info(v)),
+                None => not_found("server version is not in the dump"),
+            };
+        }
+        let Some(target) = parse_path(path) else {
+            return not_found(&format!("{} is not served in offline mode", path));
+        };
+        if target.subresource.is_some() {
+            return not_found(&format!(
+                "{}/{} is not available in offline mode",
+                target.resource,
+                target.subresource.unwrap_or_default()
+            ));
+        }
+        let key = (target.group.to_string(), target.resource.to_string());
+        let items = match self.objects.get(&key) {
+            Some(items) => items.as_slice(),
+            None => {
+                if let Ok(mut missing) = self.missing.lock() {
+                    missing.insert(resource_label(target.group, target.resource));
+                }
+                &[]
+            }
+        };
+        let api_version = if target.group.is_empty() {
+            target.version.to_string()
+        } else {
+            format!("{}/{}", target.group, target.version)
+        };
+        let in_namespace = |o: &&Value| {
+            target
+                .namespace
+                .is_none_or(|ns| o["metadata"]["namespace"].as_str() == Some(ns))
+        };
+        // Serve every object under the requested version so typed deserialization accepts it.
+        let with_version = |o: &Value| {
+            let mut o = o.clone();
+            o["apiVersion"] = json!(api_version);
+            o
+        };
+
+        if let Some(name) = target.name {
+            return match items
+                .iter()
+                .filter(in_namespace)
+                .find(|o| o["metadata"]["name"].as_str() == Some(name))
+            {
+                Some(o) => json_response(with_version(o)),
+                None => not_found(&format!("{} \"{}\" not found", target.resource, name)),
+            };
+        }
+        let query = parse_query(req.uri().query().unwrap_or_default());
+        let labels = query.get("labelSelector").map(String::as_str);
+        let fields = query.get("fieldSelector").map(String::as_str);
+        let items: Vec<Value> = items
+            .iter()
+            .filter(in_namespace)
+            .filter(|o| labels.is_none_or(|s| matches_label_selector(o, s)))
+            .filter(|o| fields.is_none_or(|s| matches_field_selector(o, s)))
+            .map(with_version)
+            .collect();
+        json_response(json!({
+            "apiVersion": api_version,
+            "kind": "List",
+            "metadata": { "resourceVersion": "" },
+            "items": items,
+        }))
+    }
+}
+
+fn resource_label(group: &str, plural: &str) -> String {
+    if group.is_empty() {
+        plural.to_string()
+    } else {
+        format!("{}.{}", plural, group)
+    }
+}
+
+fn group_of(api_version: &str) -> &str {
+    api_version
+        .rsplit_once('/')
+        .map(|(g, _)| g)
+        .unwrap_or_default()
+}
+
+/// Plural resource name of a kind (`NetworkPolicy` → `networkpolicies`).
+fn plural_of(kind: &str) -> String {
+    let k = kind.to_lowercase();
+    match k.as_str() {
+        "endpoints" => k,
+        _ if k.ends_with('y') && !k.ends_with("ey") => format!("{}ies", &k[..k.len() - 1]),
+        _ if k.ends_with('s') => format!("{}es", k),
+        _ => format!("{}s", k),
+    }
+}
+
+/// Complete `version.Info` (all fields are required when deserialized).
+fn version_info(v: &Value) -> Value {
+    let field = |name: &str| json!(v[name].as_str().unwrap_or_default());
+    json!({
+        "major": field("major"),
+        "minor": field("minor"),
+        "gitVersion": field("gitVersion"),
+        "gitCommit": field("gitCommit"),
+        "gitTreeState": field("gitTreeState"),
+        "buildDate": field("buildDate"),
+        "goVersion": field("goVersion"),
+        "compiler": field("compiler"),
+        "platform": field("platform"),
+    })
+}
+
+#[derive(Debug, PartialEq)]
+struct Target<'a> {
+    group: &'a str,
+    version: &'a str,
+    namespace: Option<&'a str>,
+    resource: &'a str,
+    name: Option<&'a str>,
+    subresource: Option<&'a str>,
+}
+
+/// Parse `/api/v1/...` and `/apis/{group}/{version}/...` resource paths.
+fn parse_path(path: &str) -> Option<Target<'_>> {
+    let segs: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (group, version, rest) = match segs.as_slice() {
+        ["api", version, rest @ ..] => ("", *version, rest),
+        ["apis", group, version, rest @ ..] => (*group, *version, rest),
+        _ => return None,
+    };
+    let (namespace, rest) = match rest {
+        ["namespaces", ns, rest @ ..] if !rest.is_empty() => (Some(*ns), rest),
+        _ => (None, rest),
+    };
+    let (resource, name, subresource) = match rest {
+        [resource] => (*resource, None, None),
+        [resource, name] => (*resource, Some(*name), None),
+        [resource, name, sub, ..] => (*resource, Some(*name), Some(*sub)),
+        _ => return None,
+    };
+    Some(Target {
+        group,
+        version,
+        namespace,
+        resource,
+        name,
+        subresource,
+    })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => {
+                        out.push(b);
+                        i += 3;
+                        continue;
+                    }
+                    Err(_) => out.push(b'%'),
+                }
+            }
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn parse_query(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter_map(|kv| kv.split_once('='))
+        .map(|(k, v)| (percent_decode(k), percent_decode(v)))
+        .collect()
+}
+
+/// Equality-based selector terms (`a=b`, `a==b`, `a!=b`, `a`, `!a`) against `lookup`.
+/// Set-based terms (`a in (x,y)`) are not evaluated and match everything.
+fn matches_selector(selector: &str, lookup: impl Fn(&str) -> Option<String>) -> bool {
+    if selector.contains('(') {
+        return true;
+    }
+    selector
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .all(|term| {
+            if let Some((k, v)) = term.split_once("!=") {
+                lookup(k.trim()).as_deref() != Some(v.trim())
+            } else if let Some((k, v)) = term.split_once("==").or_else(|| term.split_once('=')) {
+                lookup(k.trim()).as_deref() == Some(v.trim())
+            } else if let Some(k) = term.strip_prefix('!') {
+                lookup(k.trim()).is_none()
+            } else {
+                lookup(term).is_some()
+            }
+        })
+}
+
+fn matches_label_selector(obj: &Value, selector: &str) -> bool {
+    matches_selector(selector, |k| {
+        obj["metadata"]["labels"][k].as_str().map(str::to_string)
+    })
+}
+
+/// Field selectors on any scalar field path (`spec.nodeName`, `involvedObject.kind`, `type`).
+fn matches_field_selector(obj: &Value, selector: &str) -> bool {
+    matches_selector(selector, |k| {
+        let pointer = format!("/{}", k.replace('.', "/"));
+        match obj.pointer(&pointer)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Null => None,
+            v => Some(v.to_string()),
+        }
+    })
+}
+
+fn json_response(body: Value) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap_or_default()
+}
+
+fn status_response(code: StatusCode, reason: &str, message: &str) -> Response<Body> {
+    let body = json!({
+        "kind": "Status", "apiVersion": "v1", "metadata": {},
+        "status": "Failure", "message": message, "reason": reason, "code": code.as_u16(),
+    });
+    let mut resp = json_response(body);
+    *resp.status_mut() = code;
+    resp
+}
+
+fn not_found(message: &str) -> Response<Body> {
+    status_response(StatusCode::NOT_FOUND, "NotFound", message)
+}
+
+fn load_file(store: &mut DumpStore, path: &Path) -> Result<()> {
+    let text = std::fs::read_to_string(path)?;
+    let is_json = path.extension().is_some_and(|e| e == "json");
+    if is_json {
+        // cluster-info dump writes several JSON documents back to back into one file.
+        for doc in serde_json::Deserializer::from_str(&text).into_iter::<Value>() {
+            store.add(doc?);
+        }
+    } else {
+        for doc in serde_yaml::Deserializer::from_str(&text) {
+            let value = Value::deserialize(doc)?;
+            if !value.is_null() {
+                store.add(value);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn walk(store: &mut DumpStore, dir: &Path) -> Result<()> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("reading {}", dir.display()))?
+        .collect::<std::io::Result<_>>()?;
+    entries.sort_by_key(|e| e.path());
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(store, &path)?;
+        } else if path
+            .extension()
+            .is_some_and(|e| e == "yaml" || e == "yml" || e == "json")
+        {
+            load_file(store, &path).with_context(|| format!("parsing {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Load every `.yaml`, `.yml` and `.json` file under `dir` (recursively). Other files, such as
+/// the pod logs of `kubectl cluster-info dump`, are ignored.
+pub fn load_dump_dir(dir: &Path) -> Result<DumpStore> {
+    let mut store = DumpStore::default();
+    walk(&mut store, dir)?;
+    if store.objects.is_empty() {
+        anyhow::bail!(
+            "no Kubernetes objects found in {} (expected `kubectl get -o yaml|json` or `kubectl cluster-info dump` output)",
+            dir.display()
+        );
+    }
+    Ok(store)
+}
+
+/// A client whose requests are answered from `store`.
+pub fn offline_client(store: Arc<DumpStore>, cluster_name: Option<String>) -> K8sClient {
+    let service = tower::service_fn(move |req: Request<Body>| {
+        let store = store.clone();
+        async move { Ok::<_, Infallible>(store.handle(&req)) }
+    });
+    K8sClient::from_client(kube::Client::new(service, "default"), cluster_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_resource_paths() {
+        assert_eq!(
+            parse_path("/apis/apps/v1/namespaces/prod/deployments/web"),
+            Some(Target {
+                group: "apps",
+                version: "v1",
+                namespace: Some("prod"),
+                resource: "deployments",
+                name: Some("web"),
+                subresource: None,
+            })
+        );
+        let ns = parse_path("/api/v1/namespaces/prod").unwrap();
+        assert_eq!((ns.resource, ns.name), ("namespaces", Some("prod")));
+        let log = parse_path("/api/v1/namespaces/a/pods/p/log").unwrap();
+        assert_eq!(log.subresource, Some("log"));
+        assert_eq!(plural_of("NetworkPolicy"), "networkpolicies");
+        assert_eq!(plural_of("Ingress"), "ingresses");
+    }
+
+    #[tokio::test]
+    async fn serves_dumped_objects_through_kube_client() {
+        let mut store = DumpStore::default();
+        store.add(json!({
+            "kind": "PodList", "apiVersion": "v1",
+            "items": [
+                { "metadata": { "name": "web-1", "namespace": "prod", "labels": { "app": "web" } } },
+                { "metadata": { "name": "db-1", "namespace": "prod", "labels": { "app": "db" } } },
+                { "metadata": { "name": "web-2", "namespace": "dev", "labels": { "app": "web" } } }
+            ]
+        }));
+        let store = Arc::new(store);
+        let client = offline_client(store.clone(), Some("dump".to_string()));
+
+        let lp = kube::api::ListParams::default().labels("app=web");
+        let prod = client.pods(Some("prod")).list(&lp).await.unwrap();
+        assert_eq!(prod.items.len(), 1);
+        let all = client.pods(None).list(&lp).await.unwrap();
+        assert_eq!(all.items.len(), 2);
+        assert!(client.pods(Some("prod")).get("db-1").await.is_ok());
+
+        let services = client
+            .services(None)
+            .list(&Default::default())
+            .await
+            .unwrap();
+        assert!(services.items.is_empty());
+        assert_eq!(store.missing_resources(), vec!["services"]);
+        assert!(client.server_version().await.is_err());
+    }
+}
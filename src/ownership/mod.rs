@@ -0,0 +1,182 @@
+//! Team ownership of findings. An owners file assigns namespaces and resources to teams by glob;
+//! namespaces not matched there fall back to a namespace label (default `team`). Issues get a
+//! `team`, and `check --split-by-team` writes one filtered report per team.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use kube::api::ListParams;
+use serde::Deserialize;
+
+use crate::inspections::types::ClusterReport;
+use crate::k8s::K8sClient;
+use crate::reporting::ReportGenerator;
+use crate::scoring::namespace::{issue_namespace, known_namespaces, namespace_scores};
+use crate::utils::pattern::matches_any;
+
+/// Namespace label read for ownership when no owners file entry matches.
+pub const DEFAULT_TEAM_LABEL: &str = "team";
+
+/// Owners file: `teams.<name>.namespaces` / `teams.<name>.resources` glob lists.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnersFile {
+    pub teams: BTreeMap<String, TeamRules>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TeamRules {
+    /// Namespace globs, e.g. `payments`, `billing-*`.
+    pub namespaces: Vec<String>,
+    /// `namespace/name` or cluster-scoped name globs; checked before namespace globs.
+    pub resources: Vec<String>,
+}
+
+pub fn load_owners_file(path: &Path) -> Result<OwnersFile> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("reading owners file {}", path.display()))?;
+    serde_yaml::from_str(&text).with_context(|| format!("parsing owners file {}", path.display()))
+}
+
+/// `namespace -> team` from the `label` of every namespace carrying it.
+pub async fn namespace_label_teams(
+    client: &K8sClient,
+    label: &str,
+) -> Result<BTreeMap<String, String>> {
+    let namespaces = client
+        .list_all(&client.namespaces(), &ListParams::default().labels(label))
+        .await
+        .with_context(|| format!("listing namespaces labelled {}", label))?;
+    Ok(namespaces
+        .items
+        .into_iter()
+        .filter_map(|ns| {
+            let team = ns.metadata.labels.as_ref()?.get(label)?.clone();
+            Some((ns.metadata.name?, team))
+        })
+        .filter(|(_, team)| !team.is_empty())
+        .collect())
+}
+
+/// Resolved ownership rules.
+#[derive(Debug, Default)]
+pub struct Ownership {
+    teams: BTreeMap<String, TeamRules>,
+    namespace_labels: BTreeMap<String, String>,
+}
+
+impl Ownership {
+    pub fn new(file: OwnersFile, namespace_labels: BTreeMap<String, String>) -> Self {
+        Self {
+            teams: file.teams,
+            namespace_labels,
+        }
+    }
+
+    /// Team for a resource: owners file resource globs, then namespace globs, then namespace label.
+    /// Teams are tried in name order, so overlapping globs resolve to the alphabetically first team.
+    pub fn team_for(&self, resource: Option<&str>, namespace: Option<&str>) -> Option<String> {
+        if let Some(r) = resource {
+            if let Some((team, _)) = self
+                .teams
+                .iter()
+                .find(|(_, t)| matches_any(&t.resources, r))
+            {
+                return Some(team.clone());
+            }
+        }
+        let ns = namespace?;
+        self.teams
+            .iter()
+            .find(|(_, t)| matches_any(&t.namespaces, ns))
+            .map(|(team, _)| team.clone())
+            .or_else(|| self.namespace_labels.get(ns).cloned())
+    }
+
+    /// Set `team` on every issue that has an owner; returns how many were assigned.
+    pub fn assign(&self, report: &mut ClusterReport) -> usize {
+        let mut known = known_namespaces(&report.inspections);
+        known.extend(self.namespace_labels.keys().cloned());
+        let mut assigned = 0;
+        for issue in report
+            .inspections
+            .iter_mut()
+            .flat_map(|i| i.summary.issues.iter_mut())
+        {
+            let ns = issue_namespace(issue, &known);
+            issue.team = self.team_for(issue.resource.as_deref(), ns.as_deref());
+            assigned += issue.team.is_some() as usize;
+        }
+        assigned
+    }
+
+    /// One report per team with findings, holding only that team's issues and events.
+    pub fn split_by_team(&self, report: &ClusterReport) -> BTreeMap<String, ClusterReport> {
+        let teams: BTreeSet<String> = report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+            .filter_map(|i| i.team.clone())
+            .collect();
+        teams
+            .into_iter()
+            .map(|team| {
+                let mut team_report = ReportGenerator::retain_issues(
+                    report,
+                    |iss| iss.team.as_deref() == Some(team.as_str()),
+                    None,
+                );
+                team_report.namespace_ranking =
+                    Some(namespace_scores(&team_report.inspections)).filter(|r| !r.is_empty());
+                if let Some(events) = team_report.recent_events.as_mut() {
+                    events.retain(|e| {
+                        self.team_for(None, Some(&e.namespace)).as_deref() == Some(team.as_str())
+                    });
+                }
+                team_report.metadata.team = Some(team.clone());
+                (team, team_report)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_file_rules_before_labels() {
+        let file: OwnersFile = serde_yaml::from_str(
+            "teams:\n  payments:\n    namespaces: [payments, billing-*]\n  platform:\n    resources: [kube-system/coredns*, node-*]\n",
+        )
+        .unwrap();
+        let labels = BTreeMap::from([
+            ("billing-eu".to_string(), "finance".to_string()),
+            ("web".to_string(), "frontend".to_string()),
+        ]);
+        let ownership = Ownership::new(file, labels);
+        assert_eq!(
+            ownership.team_for(Some("billing-eu/api"), Some("billing-eu")),
+            Some("payments".to_string())
+        );
+        assert_eq!(
+            ownership.team_for(Some("web/site"), Some("web")),
+            Some("frontend".to_string())
+        );
+        assert_eq!(
+            ownership.team_for(Some("kube-system/coredns-1"), Some("kube-system")),
+            Some("platform".to_string())
+        );
+        assert_eq!(
+            ownership.team_for(Some("node-3"), None),
+            Some("platform".to_string())
+        );
+        assert_eq!(
+            ownership.team_for(Some("kube-system/etcd"), Some("kube-system")),
+            None
+        );
+        assert!(serde_yaml::from_str::<OwnersFile>("teams:\n  a:\n    namespace: [x]\n").is_err());
+    }
+}
@@ -0,0 +1,375 @@
+//! RBAC preflight: one SelfSubjectAccessReview per permission kubeowler uses, so missing access
+//! is reported up front (with the checks it affects) instead of as 403s in the middle of a run.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use k8s_openapi::api::authorization::v1::{
+    NonResourceAttributes, ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec,
+};
+use kube::api::{Api, PostParams};
+
+use crate::cli::{ActiveProbe, NodeCollectMode};
+use crate::k8s::K8sClient;
+
+/// Where a permission is reviewed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// Cluster-scoped resource or non-resource URL.
+    Cluster,
+    /// The inspected namespace (all namespaces when `check` has no `--namespace`).
+    Inspected,
+    /// The node inspector namespace.
+    NodeInspector,
+}
+
+/// A permission kubeowler uses and the checks that depend on it.
+#[derive(Debug)]
+pub struct Permission {
+    pub verb: &'static str,
+    /// API group ("" for core).
+    pub group: &'static str,
+    /// `resource`, `resource/subresource`, or a non-resource URL starting with `/`.
+    pub resource: &'static str,
+    pub scope: Scope,
+    /// Optional permissions only enrich the report (metrics-server, VPA, kubelet stats).
+    pub optional: bool,
+    /// Inspection modules and report sections that are skipped or incomplete without it.
+    pub needed_by: &'static [&'static str],
+}
+
+impl Permission {
+    /// `verb resource[.group]`, e.g. `list deployments.apps`, `get /version`.
+    pub fn label(&self) -> String {
+        if self.group.is_empty() {
+            format!("{} {}", self.verb, self.resource)
+        } else {
+            format!("{} {}.{}", self.verb, self.resource, self.group)
+        }
+    }
+}
+
+const fn req(
+    verb: &'static str,
+    group: &'static str,
+    resource: &'static str,
+    needed_by: &'static [&'static str],
+) -> Permission {
+    Permission {
+        verb,
+        group,
+        resource,
+        scope: Scope::Inspected,
+        optional: false,
+        needed_by,
+    }
+}
+
+const fn cluster(
+    verb: &'static str,
+    group: &'static str,
+    resource: &'static str,
+    needed_by: &'static [&'static str],
+) -> Permission {
+    Permission {
+        verb,
+        group,
+        resource,
+        scope: Scope::Cluster,
+        optional: false,
+        needed_by,
+    }
+}
+
+const fn optional(mut p: Permission) -> Permission {
+    p.optional = true;
+    p
+}
+
+const fn node_inspector(mut p: Permission) -> Permission {
+    p.scope = Scope::NodeInspector;
+    p
+}
+
+/// Permissions used by `check`, in the order of the ClusterRole in docs/docker-and-kubernetes.md.
+#[rustfmt::skip]
+pub const PERMISSIONS: &[Permission] = &[
+    cluster("list", "", "nodes", &["Cluster Overview", "Node Health", "Storage", "Upgrade Readiness", "Resilience", "Control Plane (managed provider)", "GPU & Extended Resources"]),
+    req("list", "", "pods", &["Cluster Overview", "Pod Status", "Resource Usage", "Security Configuration", "Observability", "Namespace", "Orphaned Resources", "Control Plane", "Debug Settings", "Resilience", "Network Connectivity", "GPU & Extended Resources", "Node Health", "Policy & Governance"]),
+    req("list", "", "services", &["Network Connectivity", "Orphaned Resources", "Debug Settings", "Resilience", "Policy & Governance"]),
+    cluster("list", "", "namespaces", &["Cluster Overview", "Network Connectivity", "Resource Usage", "Security Configuration", "Namespace", "Policy & Governance"]),
+    cluster("list", "", "persistentvolumes", &["Storage"]),
+    req("list", "", "persistentvolumeclaims", &["Storage", "Orphaned Resources", "Policy & Governance"]),
+    req("list", "", "configmaps", &["Orphaned Resources", "Network Connectivity (ingress controllers, kube-proxy mode)"]),
+    req("list", "", "secrets", &["Certificates", "Orphaned Resources", "Network Connectivity (ingress controllers)"]),
+    req("list", "", "serviceaccounts", &["Orphaned Resources"]),
+    req("list", "", "events", &["Event Analytics", "Recent events", "Network Connectivity", "Node Health"]),
+    req("list", "", "resourcequotas", &["Policy & Governance", "Namespace"]),
+    req("list", "", "limitranges", &["Policy & Governance", "Namespace"]),
+    cluster("list", "", "componentstatuses", &["Control Plane"]),
+    optional(cluster("get", "", "pods/proxy", &["Control Plane (component /healthz)"])),
+    optional(cluster("get", "", "nodes/proxy", &["Node disk usage", "PVC Usage (STO-011/012)", "Kubelet Configuration (NODE-019..022)"])),
+    req("list", "apps", "deployments", &["Network Connectivity", "Autoscaling", "Namespace", "Orphaned Resources", "Debug Settings", "Resilience", "Policy & Governance"]),
+    req("list", "apps", "replicasets", &["Orphaned Resources", "Resilience", "Autoscaling"]),
+    req("list", "apps", "statefulsets", &["Autoscaling", "Orphaned Resources", "Resilience", "Policy & Governance"]),
+    req("list", "apps", "daemonsets", &["Network Connectivity", "Control Plane (EKS add-ons)", "GPU & Extended Resources", "Resilience"]),
+    cluster("list", "rbac.authorization.k8s.io", "clusterroles", &["Security Configuration"]),
+    cluster("list", "rbac.authorization.k8s.io", "clusterrolebindings", &["Security Configuration"]),
+    req("list", "networking.k8s.io", "networkpolicies", &["Network Connectivity", "Security Configuration", "Namespace"]),
+    req("list", "networking.k8s.io", "ingresses", &["Orphaned Resources", "Network Connectivity"]),
+    cluster("list", "networking.k8s.io", "ingressclasses", &["Network Connectivity"]),
+    req("list", "batch", "jobs", &["Batch Workloads", "Orphaned Resources"]),
+    req("list", "batch", "cronjobs", &["Batch Workloads", "Orphaned Resources", "Policy & Governance"]),
+    cluster("list", "storage.k8s.io", "storageclasses", &["Storage", "Resilience"]),
+    cluster("list", "storage.k8s.io", "csidrivers", &["Storage"]),
+    req("list", "coordination.k8s.io", "leases", &["Storage", "Control Plane (leader election)", "Node Health (NODE-034)"]),
+    optional(cluster("list", "scheduling.k8s.io", "priorityclasses", &["Resilience (HA-012..HA-014)"])),
+    req("list", "autoscaling", "horizontalpodautoscalers", &["Autoscaling"]),
+    optional(req("list", "autoscaling.k8s.io", "verticalpodautoscalers", &["Autoscaling (AUTO-008)"])),
+    req("list", "policy", "poddisruptionbudgets", &["Policy & Governance", "Resilience"]),
+    cluster("list", "certificates.k8s.io", "certificatesigningrequests", &["Certificates"]),
+    optional(cluster("list", "apiextensions.k8s.io", "customresourcedefinitions", &["Orphaned Resources (ORPH-013)"])),
+    optional(cluster("list", "metrics.k8s.io", "nodes", &["Node Headroom (NODE-008..010)", "Node resource usage"])),
+    optional(req("list", "metrics.k8s.io", "pods", &["Container usage"])),
+    cluster("get", "", "/version", &["Cluster Overview", "Upgrade Readiness", "Control Plane (API latency)"]),
+    optional(cluster("get", "", "/readyz", &["Control Plane (API latency)"])),
+    node_inspector(req("get", "apps", "daemonsets", &["Node Inspection"])),
+    node_inspector(req("list", "", "pods", &["Node Inspection"])),
+];
+
+/// Node inspector permissions that depend on `--node-collect`.
+#[rustfmt::skip]
+const NODE_COLLECT_LOGS: &[Permission] = &[
+    node_inspector(req("get", "", "pods/log", &["Node Inspection"])),
+    node_inspector(req("patch", "apps", "daemonsets", &["Node Inspection (stale data refresh)"])),
+];
+
+#[rustfmt::skip]
+const NODE_COLLECT_EXEC: &[Permission] = &[
+    node_inspector(req("create", "", "pods/exec", &["Node Inspection"])),
+];
+
+#[rustfmt::skip]
+const ACTIVE_NETWORK_PROBES: &[Permission] = &[
+    node_inspector(req("create", "", "pods/exec", &["Active network probes"])),
+];
+
+/// Permissions reviewed for a `check` with the given node collection mode and active probes.
+pub fn required_permissions(
+    node_collect: NodeCollectMode,
+    active_probes: &[ActiveProbe],
+) -> Vec<&'static Permission> {
+    let mode = match node_collect {
+        NodeCollectMode::Logs => NODE_COLLECT_LOGS,
+        NodeCollectMode::Exec => NODE_COLLECT_EXEC,
+    };
+    let probes = if active_probes.contains(&ActiveProbe::Network) {
+        ACTIVE_NETWORK_PROBES
+    } else {
+        &[]
+    };
+    PERMISSIONS
+        .iter()
+        .chain(mode.iter())
+        .chain(probes.iter())
+        .collect()
+}
+
+/// Outcome of one access review.
+#[derive(Debug)]
+pub struct PermissionCheck {
+    pub permission: &'static Permission,
+    pub allowed: bool,
+    /// Namespace the review was made in (None: cluster-wide).
+    pub namespace: Option<String>,
+    /// Reason or evaluation error returned by the API server, if any.
+    pub reason: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PreflightReport {
+    pub checks: Vec<PermissionCheck>,
+}
+
+impl PreflightReport {
+    pub fn denied(&self) -> impl Iterator<Item = &PermissionCheck> {
+        self.checks.iter().filter(|c| !c.allowed)
+    }
+
+    /// Denied permissions that are not optional.
+    pub fn denied_required(&self) -> usize {
+        self.denied().filter(|c| !c.permission.optional).count()
+    }
+
+    /// Checks that will be skipped or incomplete, with the denied permissions behind each.
+    pub fn affected_checks(&self) -> BTreeMap<&'static str, Vec<String>> {
+        let mut out: BTreeMap<&'static str, Vec<String>> = BTreeMap::new();
+        for c in self.denied() {
+            for check in c.permission.needed_by {
+                out.entry(check).or_default().push(c.permission.label());
+            }
+        }
+        out
+    }
+
+    /// Plain-text table of every reviewed permission.
+    pub fn render_table(&self) -> String {
+        let rows: Vec<(String, &str, String)> = self
+            .checks
+            .iter()
+            .map(|c| {
+                let status = match (c.allowed, c.permission.optional) {
+                    (true, _) => "granted",
+                    (false, false) => "DENIED",
+                    (false, true) => "denied (optional)",
+                };
+                let mut scope = match (&c.namespace, c.permission.scope) {
+                    (Some(ns), _) => ns.clone(),
+                    (None, Scope::Cluster) => "cluster".to_string(),
+                    (None, _) => "all namespaces".to_string(),
+                };
+                if let Some(reason) = c.reason.as_deref().filter(|_| !c.allowed) {
+                    scope.push_str(&format!(" ({})", reason));
+                }
+                (c.permission.label(), status, scope)
+            })
+            .collect();
+        let w0 = rows.iter().map(|r| r.0.len()).max().unwrap_or(0).max(10);
+        let w1 = rows.iter().map(|r| r.1.len()).max().unwrap_or(0).max(6);
+        let mut out = format!(
+            "{:<w0$}  {:<w1$}  {}\n",
+            "PERMISSION", "STATUS", "SCOPE (REASON)"
+        );
+        for (label, status, scope) in rows {
+            out.push_str(&format!("{:<w0$}  {:<w1$}  {}\n", label, status, scope));
+        }
+        out
+    }
+}
+
+fn access_review(p: &Permission, namespace: Option<&str>) -> SelfSubjectAccessReview {
+    let spec = if p.resource.starts_with('/') {
+        SelfSubjectAccessReviewSpec {
+            non_resource_attributes: Some(NonResourceAttributes {
+                path: Some(p.resource.to_string()),
+                verb: Some(p.verb.to_string()),
+            }),
+            ..Default::default()
+        }
+    } else {
+        let (resource, subresource) = match p.resource.split_once('/') {
+            Some((r, s)) => (r, Some(s.to_string())),
+            None => (p.resource, None),
+        };
+        SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                group: Some(p.group.to_string()),
+                resource: Some(resource.to_string()),
+                subresource,
+                verb: Some(p.verb.to_string()),
+                namespace: namespace.map(str::to_string),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    };
+    SelfSubjectAccessReview {
+        spec,
+        ..Default::default()
+    }
+}
+
+/// Review every permission `check` needs. `namespace` is the inspected namespace (None: all).
+pub async fn run_preflight(
+    client: &K8sClient,
+    namespace: Option<&str>,
+    node_inspector_namespace: &str,
+    node_collect: NodeCollectMode,
+    active_probes: &[ActiveProbe],
+) -> Result<PreflightReport> {
+    let api: Api<SelfSubjectAccessReview> = Api::all(client.client().clone());
+    let reviews = required_permissions(node_collect, active_probes)
+        .into_iter()
+        .map(|p| {
+            let ns = match p.scope {
+                Scope::Cluster => None,
+                Scope::Inspected => namespace,
+                Scope::NodeInspector => Some(node_inspector_namespace),
+            };
+            let api = api.clone();
+            async move {
+                let review = api
+                    .create(&PostParams::default(), &access_review(p, ns))
+                    .await
+                    .with_context(|| format!("SelfSubjectAccessReview for {}", p.label()))?;
+                let status = review.status.unwrap_or_default();
+                Ok::<_, anyhow::Error>(PermissionCheck {
+                    permission: p,
+                    allowed: status.allowed,
+                    namespace: ns.map(str::to_string),
+                    reason: status
+                        .reason
+                        .or(status.evaluation_error)
+                        .filter(|r| !r.is_empty()),
+                })
+            }
+        });
+    let checks = join_all(reviews)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PreflightReport { checks })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_resource_and_non_resource_reviews() {
+        let proxy = PERMISSIONS
+            .iter()
+            .find(|p| p.resource == "nodes/proxy")
+            .unwrap();
+        let attrs = access_review(proxy, None).spec.resource_attributes.unwrap();
+        assert_eq!(attrs.resource.as_deref(), Some("nodes"));
+        assert_eq!(attrs.subresource.as_deref(), Some("proxy"));
+        assert_eq!(attrs.namespace, None);
+
+        let version = PERMISSIONS
+            .iter()
+            .find(|p| p.resource == "/version")
+            .unwrap();
+        let spec = access_review(version, None).spec;
+        assert!(spec.resource_attributes.is_none());
+        assert_eq!(
+            spec.non_resource_attributes.unwrap().path.as_deref(),
+            Some("/version")
+        );
+    }
+
+    #[test]
+    fn groups_denied_permissions_by_check() {
+        let perms = required_permissions(NodeCollectMode::Exec, &[]);
+        assert!(perms.iter().any(|p| p.resource == "pods/exec"));
+        assert!(!perms.iter().any(|p| p.resource == "pods/log"));
+
+        let report = PreflightReport {
+            checks: perms
+                .into_iter()
+                .map(|p| PermissionCheck {
+                    permission: p,
+                    allowed: p.resource != "resourcequotas" && p.group != "metrics.k8s.io",
+                    namespace: None,
+                    reason: None,
+                })
+                .collect(),
+        };
+        assert_eq!(report.denied_required(), 1);
+        let affected = report.affected_checks();
+        assert_eq!(affected["Policy & Governance"], vec!["list resourcequotas"]);
+        assert!(affected.contains_key("Container usage"));
+        assert!(report.render_table().contains("list pods.metrics.k8s.io"));
+    }
+}
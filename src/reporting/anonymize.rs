@@ -0,0 +1,385 @@
+//! Name redaction for `--redact`: cluster, namespace, node, pod and other object names, certificate
+//! subjects and IP addresses are replaced by salted hashes (`ns-1a2b3c4d`, `node-…`, `name-…`,
+//! `ip-…`) in every string of the report, so it can be shared outside the organisation. Issue codes, severities,
+//! counts and scores are unchanged, and the same name maps to the same pseudonym throughout one
+//! report. Built-in namespaces (`kube-system`, ...) are kept.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::inspections::types::ClusterReport;
+
+/// Namespaces present in every cluster; hashing them would only make the report harder to read.
+const KEPT_NAMESPACES: &[&str] = &["default", "kube-system", "kube-public", "kube-node-lease"];
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+}
+
+fn is_ipv4(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('.').collect();
+    parts.len() == 4
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.len() <= 3 && p.parse::<u8>().is_ok())
+}
+
+fn is_ipv6(s: &str) -> bool {
+    s.contains(':') && s.parse::<std::net::Ipv6Addr>().is_ok()
+}
+
+/// A name that is also an ordinary word (`monitoring`, `logging`): in prose it is only replaced
+/// where it is written as a reference (`monitoring/prometheus-0`).
+fn is_plain_word(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_lowercase())
+}
+
+/// Report fields holding sentences rather than identifiers.
+const PROSE_FIELDS: &[&str] = &[
+    "description",
+    "recommendation",
+    "details",
+    "message",
+    "key_findings",
+    "priority_recommendations",
+];
+
+/// Name → pseudonym table built from the identifying fields of a report.
+struct Pseudonyms {
+    salt: String,
+    names: HashMap<String, String>,
+}
+
+impl Pseudonyms {
+    fn hash(&self, kind: &str, name: &str) -> String {
+        use sha2::{Digest, Sha256};
+        let digest = Sha256::digest(format!("{}\n{}", self.salt, name).as_bytes());
+        let hex: String = digest[..4].iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}-{}", kind, hex)
+    }
+
+    /// Register `name` under `kind` unless it is built in, already known or not a plain object name.
+    fn add(&mut self, kind: &str, name: &str) {
+        let name = name.trim();
+        if name.is_empty()
+            || KEPT_NAMESPACES.contains(&name)
+            || name.starts_with("system:")
+            || !name.chars().all(is_name_char)
+            || self.names.contains_key(name)
+        {
+            return;
+        }
+        let pseudonym = self.hash(kind, name);
+        self.names.insert(name.to_string(), pseudonym);
+    }
+
+    /// `namespace/name` (or `a → b`) references: every lower-case segment; PascalCase segments are kinds.
+    fn add_ref(&mut self, reference: &str) {
+        let segments: Vec<&str> = reference
+            .split(" → ")
+            .flat_map(|part| part.split('/'))
+            .map(str::trim)
+            .collect();
+        let last = segments.len().saturating_sub(1);
+        for (i, segment) in segments.iter().enumerate() {
+            if segment.starts_with(|c: char| c.is_ascii_uppercase()) {
+                continue;
+            }
+            self.add(if i < last { "ns" } else { "name" }, segment);
+        }
+    }
+
+    fn collect(&mut self, report: &ClusterReport) {
+        self.add("cluster", &report.cluster_name);
+        for row in report
+            .inspections
+            .iter()
+            .filter_map(|i| i.namespace_summary_rows.as_ref())
+            .flatten()
+        {
+            self.add("ns", &row.name);
+        }
+        for row in report.namespace_ranking.iter().flatten() {
+            self.add("ns", &row.namespace);
+        }
+        if let Some(overview) = &report.cluster_overview {
+            for node in overview.node_list.iter().flatten() {
+                self.add("node", &node.name);
+            }
+            for row in overview.node_usage.iter().flatten() {
+                self.add("node", &row.node_name);
+            }
+        }
+        for node in report.node_inspection_results.iter().flatten() {
+            self.add("node", &node.node_name);
+            self.add("node", &node.hostname);
+        }
+        for event in report.recent_events.iter().flatten() {
+            self.add("ns", &event.namespace);
+            self.add_ref(&event.object_ref);
+        }
+        for inspection in &report.inspections {
+            for row in inspection.pod_container_states.iter().flatten() {
+                self.add_ref(&row.pod_ref);
+            }
+            for row in inspection.certificate_expiries.iter().flatten() {
+                self.add("ns", &row.secret_namespace);
+                self.add("name", &row.secret_name);
+                for value in subject_common_names(&row.subject_or_cn) {
+                    self.add("host", value);
+                }
+            }
+        }
+        for issue in report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+            .chain(report.accepted_issues.iter().flatten())
+        {
+            if let Some(resource) = &issue.resource {
+                self.add_ref(resource);
+            }
+        }
+    }
+
+    /// `text` with every known name and IP address (as a whole token) replaced. In `prose`,
+    /// names that are ordinary words are left alone unless written as a `namespace/name` reference.
+    fn replace(&self, text: &str, prose: bool) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut token = String::new();
+        for c in text.chars().chain(std::iter::once('\0')) {
+            if is_name_char(c) || c == ':' {
+                token.push(c);
+                continue;
+            }
+            if !token.is_empty() {
+                // A sentence-ending period is not part of the name.
+                let trimmed = token.trim_end_matches('.');
+                let rest = &token[trimmed.len()..];
+                if is_ipv6(trimmed) {
+                    out.push_str(&self.hash("ip", trimmed));
+                } else {
+                    // `host:port` and `key:value` pairs: each side is a token of its own.
+                    let last = trimmed.split(':').count() - 1;
+                    for (i, part) in trimmed.split(':').enumerate() {
+                        if i > 0 {
+                            out.push(':');
+                        }
+                        let next = if i < last { ':' } else { c };
+                        self.replace_token(part, &mut out, next, prose);
+                    }
+                }
+                out.push_str(rest);
+                token.clear();
+            }
+            if c != '\0' {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// Append `token` (or its pseudonym) to `out`; `next` is the character following it.
+    fn replace_token(&self, token: &str, out: &mut String, next: char, prose: bool) {
+        match self.names.get(token) {
+            Some(p) if !prose || !is_plain_word(token) || out.ends_with('/') || next == '/' => {
+                out.push_str(p)
+            }
+            None if is_ipv4(token) => out.push_str(&self.hash("ip", token)),
+            _ => out.push_str(token),
+        }
+    }
+
+    /// Certificate subject with every attribute value pseudonymised (`CN=host-…, O=name-…`).
+    fn replace_subject(&self, subject: &str) -> String {
+        subject
+            .split(',')
+            .map(|rdn| match rdn.split_once('=') {
+                Some((key, value)) => {
+                    let value = value.trim().trim_end_matches("...");
+                    let (wildcard, host) = match value.strip_prefix("*.") {
+                        Some(host) => ("*.", host),
+                        None => ("", value),
+                    };
+                    let pseudonym = match self.names.get(host) {
+                        Some(p) => p.clone(),
+                        None => self.hash("name", host),
+                    };
+                    format!("{}={}{}", key.trim(), wildcard, pseudonym)
+                }
+                None => self.hash("name", rdn.trim()),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn replace_value(&self, value: &mut Value, prose: bool) {
+        match value {
+            Value::String(s) => *s = self.replace(s, prose),
+            Value::Array(items) => items.iter_mut().for_each(|v| self.replace_value(v, prose)),
+            // Keys are field names or module / state names, never object names.
+            Value::Object(map) => map
+                .iter_mut()
+                .for_each(|(key, v)| self.replace_value(v, PROSE_FIELDS.contains(&key.as_str()))),
+            _ => {}
+        }
+    }
+}
+
+/// Common names in a certificate subject (`CN=*.acme.io, O=Acme` → `acme.io`), which are host
+/// names that may also appear in Ingress findings.
+fn subject_common_names(subject: &str) -> impl Iterator<Item = &str> {
+    subject
+        .split(',')
+        .filter_map(|rdn| rdn.split_once('='))
+        .filter(|(key, _)| key.trim() == "CN")
+        .map(|(_, value)| {
+            let value = value.trim().trim_end_matches("...");
+            value.strip_prefix("*.").unwrap_or(value)
+        })
+}
+
+/// Copy of `report` with names and IPs replaced by pseudonyms. The salt is random, so pseudonyms
+/// cannot be matched against a dictionary of common names (nor across reports).
+pub fn anonymize_report(report: &ClusterReport) -> Result<ClusterReport> {
+    anonymize_with_salt(report, &uuid::Uuid::new_v4().to_string())
+}
+
+fn anonymize_with_salt(report: &ClusterReport, salt: &str) -> Result<ClusterReport> {
+    let mut pseudonyms = Pseudonyms {
+        salt: salt.to_string(),
+        names: HashMap::new(),
+    };
+    pseudonyms.collect(report);
+    let mut value = serde_json::to_value(report)?;
+    pseudonyms.replace_value(&mut value, false);
+    let mut anonymized: ClusterReport = serde_json::from_value(value)?;
+    // Subjects may contain organisation names and other words that are not object names.
+    for (inspection, original) in anonymized.inspections.iter_mut().zip(&report.inspections) {
+        let rows = inspection.certificate_expiries.iter_mut().flatten();
+        for (row, original) in rows.zip(original.certificate_expiries.iter().flatten()) {
+            row.subject_or_cn = pseudonyms.replace_subject(&original.subject_or_cn);
+        }
+    }
+    // Stored fingerprints hash the real resource names; recompute them from the pseudonyms.
+    for issue in anonymized
+        .inspections
+        .iter_mut()
+        .flat_map(|i| i.summary.issues.iter_mut())
+        .chain(anonymized.accepted_issues.iter_mut().flatten())
+    {
+        issue.fingerprint = None;
+    }
+    Ok(anonymized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_names_and_ips_but_keeps_codes() {
+        let json = serde_json::json!({
+            "cluster_name": "acme-prod-eu",
+            "report_id": "r",
+            "timestamp": "2026-01-01T00:00:00Z",
+            "overall_score": 80.0,
+            "inspections": [{
+                "inspection_type": "Pod Status",
+                "timestamp": "2026-01-01T00:00:00Z",
+                "overall_score": 80.0,
+                "checks": [],
+                "summary": {
+                    "total_checks": 1, "passed_checks": 0, "warning_checks": 1,
+                    "critical_checks": 0, "error_checks": 0,
+                    "issues": [{
+                        "severity": "Warning",
+                        "category": "Pod",
+                        "description": "Pod payments/billing-api on 10.20.0.7 restarted.",
+                        "resource": "payments/billing-api",
+                        "recommendation": "Check kube-system/coredns",
+                        "rule_id": "POD-002"
+                    }]
+                }
+            }],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        });
+        let report: ClusterReport = serde_json::from_value(json).unwrap();
+        let out = anonymize_with_salt(&report, "salt").unwrap();
+        let text = serde_json::to_string(&out).unwrap();
+        for leaked in ["acme-prod-eu", "payments", "billing-api", "10.20.0.7"] {
+            assert!(!text.contains(leaked), "{} leaked: {}", leaked, text);
+        }
+        let issue = &out.inspections[0].summary.issues[0];
+        assert_eq!(issue.rule_id.as_deref(), Some("POD-002"));
+        assert!(issue.description.ends_with("restarted."));
+        assert_eq!(issue.recommendation, "Check kube-system/coredns");
+        assert!(out.cluster_name.starts_with("cluster-"));
+        let resource = issue.resource.as_deref().unwrap();
+        assert!(resource.starts_with("ns-") && resource.contains("/name-"));
+        assert!(issue.description.contains(resource));
+    }
+
+    #[test]
+    fn redacts_certificates_and_ipv6_but_not_common_words() {
+        let json = serde_json::json!({
+            "cluster_name": "acme-prod-eu",
+            "report_id": "r",
+            "timestamp": "2026-01-01T00:00:00Z",
+            "overall_score": 80.0,
+            "inspections": [{
+                "inspection_type": "Certificates",
+                "timestamp": "2026-01-01T00:00:00Z",
+                "overall_score": 80.0,
+                "checks": [],
+                "summary": {
+                    "total_checks": 1, "passed_checks": 0, "warning_checks": 1,
+                    "critical_checks": 0, "error_checks": 0,
+                    "issues": [{
+                        "severity": "Warning",
+                        "category": "Pod",
+                        "description": "Pod monitoring/prometheus-0 on fd00:10::7 restarted; \
+                                        serves shop.acme.io.",
+                        "resource": "monitoring/prometheus-0",
+                        "recommendation": "Enable monitoring for the workload",
+                        "rule_id": "POD-002"
+                    }]
+                },
+                "certificate_expiries": [{
+                    "secret_namespace": "payments",
+                    "secret_name": "shop-tls",
+                    "subject_or_cn": "CN=*.shop.acme.io, O=Acme Payments",
+                    "expiry_utc": "2026-02-01",
+                    "days_until_expiry": 31
+                }]
+            }],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        });
+        let report: ClusterReport = serde_json::from_value(json).unwrap();
+        let out = anonymize_with_salt(&report, "salt").unwrap();
+        let text = serde_json::to_string(&out).unwrap();
+        for leaked in ["payments", "shop-tls", "shop.acme.io", "Acme", "fd00:10::7"] {
+            assert!(!text.contains(leaked), "{} leaked: {}", leaked, text);
+        }
+        let issue = &out.inspections[0].summary.issues[0];
+        assert_eq!(issue.recommendation, "Enable monitoring for the workload");
+        assert!(!issue.description.contains("monitoring/"));
+        assert!(issue.resource.as_deref().unwrap().starts_with("ns-"));
+        let row = &out.inspections[0].certificate_expiries.as_ref().unwrap()[0];
+        assert!(row.secret_name.starts_with("name-"));
+        assert!(row.subject_or_cn.starts_with("CN=*.host-"));
+        assert!(row.subject_or_cn.contains(", O=name-"));
+    }
+}
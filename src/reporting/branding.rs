@@ -0,0 +1,116 @@
+//! HTML report branding: company name, logo, accent color and light/dark theme, from the
+//! `branding` section of the config file and the `--brand-*` / `--theme` options.
+
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use serde::Deserialize;
+
+use crate::cli::{BrandOverrides, HtmlTheme};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Branding {
+    /// Shown above the report and in the page title.
+    pub company_name: Option<String>,
+    /// Local image file (embedded) or http(s) URL; replaces the kubeowler logo.
+    pub logo: Option<String>,
+    /// Accent color for headings, links and table headers, as `#rgb` or `#rrggbb`.
+    pub primary_color: Option<String>,
+    pub theme: Option<HtmlTheme>,
+}
+
+impl Branding {
+    /// Command-line values override the config file.
+    pub fn merged(mut self, args: &BrandOverrides) -> Result<Self> {
+        if args.brand_name.is_some() {
+            self.company_name = args.brand_name.clone();
+        }
+        if args.brand_logo.is_some() {
+            self.logo = args.brand_logo.clone();
+        }
+        if args.brand_color.is_some() {
+            self.primary_color = args.brand_color.clone();
+        }
+        if args.theme.is_some() {
+            self.theme = args.theme;
+        }
+        if let Some(color) = &self.primary_color {
+            if !is_hex_color(color) {
+                bail!("invalid brand color '{}': expected #rgb or #rrggbb", color);
+            }
+        }
+        Ok(self)
+    }
+
+    /// `src` of the logo image: URLs as given, files as a data URI; `None` keeps the default logo.
+    pub fn logo_src(&self) -> Result<Option<String>> {
+        let Some(logo) = &self.logo else {
+            return Ok(None);
+        };
+        if logo.starts_with("https://") || logo.starts_with("http://") {
+            return Ok(Some(logo.clone()));
+        }
+        let mime = match logo
+            .rsplit('.')
+            .next()
+            .map(|e| e.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("svg") => "image/svg+xml",
+            Some("webp") => "image/webp",
+            _ => bail!(
+                "unsupported logo file '{}': use .png, .jpg, .gif, .svg or .webp",
+                logo
+            ),
+        };
+        let bytes = std::fs::read(logo).with_context(|| format!("reading logo {}", logo))?;
+        Ok(Some(format!(
+            "data:{};base64,{}",
+            mime,
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        )))
+    }
+}
+
+fn is_hex_color(s: &str) -> bool {
+    s.strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Minimal escaping for text placed in HTML element content or attributes.
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_overrides_config_and_colors_are_validated() {
+        let file: Branding =
+            serde_yaml::from_str("company_name: Acme\nprimary_color: \"#123\"\ntheme: dark\n")
+                .unwrap();
+        let args = BrandOverrides {
+            brand_name: Some("Globex".to_string()),
+            ..Default::default()
+        };
+        let b = file.clone().merged(&args).unwrap();
+        assert_eq!(b.company_name.as_deref(), Some("Globex"));
+        assert_eq!(b.primary_color.as_deref(), Some("#123"));
+        assert_eq!(b.theme, Some(HtmlTheme::Dark));
+
+        let bad = BrandOverrides {
+            brand_color: Some("red;}body{display:none".to_string()),
+            ..Default::default()
+        };
+        assert!(file.merged(&bad).is_err());
+        assert!(is_hex_color("#a1B2c3") && !is_hex_color("#12345") && !is_hex_color("123"));
+    }
+}
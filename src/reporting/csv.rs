@@ -0,0 +1,89 @@
+//! Flat CSV layouts built from the report itself (`--csv-layout issues|checks`): one header row and
+//! one row per issue or per check, for spreadsheets and BI ingestion. The default `summary` layout
+//! is parsed from the Markdown report (see `md_export::md_to_csv`). The row builders also feed the
+//! Issues and Checks sheets of the XLSX report.
+
+use crate::inspections::types::{ClusterReport, IssueSeverity};
+use crate::reporting::generator::CheckLevelFilter;
+use crate::reporting::md_export::escape_csv;
+use crate::scoring::namespace::{issue_namespace, known_namespaces};
+
+#[rustfmt::skip]
+pub const ISSUE_COLUMNS: [&str; 10] = [
+    "cluster", "module", "rule_id", "severity", "category", "resource", "namespace",
+    "description", "recommendation", "fingerprint",
+];
+#[rustfmt::skip]
+pub const CHECK_COLUMNS: [&str; 8] = [
+    "cluster", "module", "check", "status", "score", "max_score", "details", "recommendations",
+];
+
+fn to_csv(columns: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut out = format!("{}\n", columns.join(","));
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|c| escape_csv(c)).collect();
+        out.push_str(&format!("{}\n", cells.join(",")));
+    }
+    out
+}
+
+/// One row per issue (see [`ISSUE_COLUMNS`]). Info issues are included only with `--level all`,
+/// as in the other formats.
+pub fn issue_rows(report: &ClusterReport, level: &CheckLevelFilter) -> Vec<Vec<String>> {
+    let known = known_namespaces(&report.inspections);
+    let mut rows = Vec::new();
+    for inspection in &report.inspections {
+        for issue in &inspection.summary.issues {
+            if issue.severity == IssueSeverity::Info && !matches!(level, CheckLevelFilter::All) {
+                continue;
+            }
+            rows.push(vec![
+                report.cluster_name.clone(),
+                inspection.inspection_type.clone(),
+                issue.rule_id.clone().unwrap_or_default(),
+                format!("{:?}", issue.severity),
+                issue.category.clone(),
+                issue.resource.clone().unwrap_or_default(),
+                issue_namespace(issue, &known).unwrap_or_default(),
+                issue.description.clone(),
+                issue.recommendation.clone(),
+                issue.stable_fingerprint(),
+            ]);
+        }
+    }
+    rows
+}
+
+/// One row per check whose status passes `level` (see [`CHECK_COLUMNS`]); recommendations are
+/// joined with "; ".
+pub fn check_rows(report: &ClusterReport, level: &CheckLevelFilter) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for inspection in &report.inspections {
+        for check in &inspection.checks {
+            if let CheckLevelFilter::Only(statuses) = level {
+                if !statuses.contains(&check.status) {
+                    continue;
+                }
+            }
+            rows.push(vec![
+                report.cluster_name.clone(),
+                inspection.inspection_type.clone(),
+                check.name.clone(),
+                format!("{:?}", check.status),
+                format!("{:.1}", check.score),
+                format!("{:.1}", check.max_score),
+                check.details.clone().unwrap_or_default(),
+                check.recommendations.join("; "),
+            ]);
+        }
+    }
+    rows
+}
+
+pub fn issues_csv(report: &ClusterReport, level: &CheckLevelFilter) -> String {
+    to_csv(&ISSUE_COLUMNS, issue_rows(report, level))
+}
+
+pub fn checks_csv(report: &ClusterReport, level: &CheckLevelFilter) -> String {
+    to_csv(&CHECK_COLUMNS, check_rows(report, level))
+}
@@ -0,0 +1,235 @@
+//! Export the manifests of objects affected by Critical findings (`--export-affected <dir>`).
+//! Layout: `<dir>/<RULE-ID>/<Kind>_<namespace>_<name>.yaml`. Objects are sanitized before writing:
+//! managedFields and status are dropped and Secret payloads are redacted, so the tree can be shared
+//! with reviewers who have no cluster access.
+
+use anyhow::{Context, Result};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ServiceAccount,
+};
+use k8s_openapi::api::networking::v1::NetworkPolicy;
+use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding};
+use k8s_openapi::api::storage::v1::StorageClass;
+use kube::api::{ApiResource, DynamicObject};
+use kube::Api;
+use log::warn;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::inspections::types::{ClusterReport, IssueSeverity};
+use crate::k8s::K8sClient;
+use crate::reporting::redact::{is_secret_key, REDACTED};
+use crate::reporting::report_resource::issue_to_resource_key;
+
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// ApiResource and scope (true = namespaced) for a report resource key.
+fn api_resource_for(resource_key: &str) -> Option<(ApiResource, bool)> {
+    let r = match resource_key {
+        "Pod" => (ApiResource::erase::<Pod>(&()), true),
+        "Service" => (ApiResource::erase::<Service>(&()), true),
+        "Deployment" => (ApiResource::erase::<Deployment>(&()), true),
+        "StatefulSet" => (ApiResource::erase::<StatefulSet>(&()), true),
+        "DaemonSet" => (ApiResource::erase::<DaemonSet>(&()), true),
+        "ConfigMap" => (ApiResource::erase::<ConfigMap>(&()), true),
+        "PersistentVolumeClaim" => (ApiResource::erase::<PersistentVolumeClaim>(&()), true),
+        "ServiceAccount" => (ApiResource::erase::<ServiceAccount>(&()), true),
+        "NetworkPolicy" => (ApiResource::erase::<NetworkPolicy>(&()), true),
+        "CronJob" => (ApiResource::erase::<CronJob>(&()), true),
+        "Job" => (ApiResource::erase::<Job>(&()), true),
+        "HPA" => (ApiResource::erase::<HorizontalPodAutoscaler>(&()), true),
+        "Secret" => (ApiResource::erase::<Secret>(&()), true),
+        "Node" => (ApiResource::erase::<Node>(&()), false),
+        "Namespace" => (ApiResource::erase::<Namespace>(&()), false),
+        "PersistentVolume" => (ApiResource::erase::<PersistentVolume>(&()), false),
+        "StorageClass" => (ApiResource::erase::<StorageClass>(&()), false),
+        "ClusterRole" => (ApiResource::erase::<ClusterRole>(&()), false),
+        "ClusterRoleBinding" => (ApiResource::erase::<ClusterRoleBinding>(&()), false),
+        _ => return None,
+    };
+    Some(r)
+}
+
+/// Replace literal `value`s of container env entries whose name looks like a credential.
+fn redact_env_literals(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(env)) = map.get_mut("env") {
+                for entry in env.iter_mut() {
+                    let secret = entry
+                        .get("name")
+                        .and_then(|n| n.as_str())
+                        .map(is_secret_key)
+                        .unwrap_or(false);
+                    if let (true, Some(v)) = (secret, entry.get_mut("value")) {
+                        *v = Value::String(REDACTED.to_string());
+                    }
+                }
+            }
+            map.values_mut().for_each(redact_env_literals);
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_env_literals),
+        _ => {}
+    }
+}
+
+/// Strip noise and sensitive data from an object before it is written to disk:
+/// managedFields, status and the last-applied annotation are removed; Secret `data`/`stringData`
+/// values and credential-looking env literals are replaced with a placeholder.
+pub fn sanitize_object(obj: &mut Value) {
+    redact_env_literals(obj);
+    let is_secret = obj.get("kind").and_then(|k| k.as_str()) == Some("Secret");
+    if let Some(map) = obj.as_object_mut() {
+        map.remove("status");
+        if let Some(meta) = map.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+            meta.remove("managedFields");
+            if let Some(ann) = meta.get_mut("annotations").and_then(|a| a.as_object_mut()) {
+                ann.remove(LAST_APPLIED_ANNOTATION);
+                if ann.is_empty() {
+                    meta.remove("annotations");
+                }
+            }
+        }
+        if is_secret {
+            for field in ["data", "stringData"] {
+                if let Some(values) = map.get_mut(field).and_then(|d| d.as_object_mut()) {
+                    for v in values.values_mut() {
+                        *v = Value::String(REDACTED.to_string());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn file_component(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Fetch and write sanitized YAML for each object referenced by a Critical issue.
+/// Returns the number of files written; objects that cannot be resolved are skipped with a warning.
+pub async fn export_affected(
+    client: &K8sClient,
+    report: &ClusterReport,
+    dir: &str,
+) -> Result<usize> {
+    let root = Path::new(dir);
+    std::fs::create_dir_all(root).with_context(|| format!("create {}", dir))?;
+
+    let mut seen: HashSet<(String, String, String)> = HashSet::new();
+    let mut written = 0usize;
+
+    for issue in report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+        .filter(|i| i.severity == IssueSeverity::Critical)
+    {
+        let Some(resource) = issue.resource.as_deref() else {
+            continue;
+        };
+        let rule_id = issue
+            .rule_id
+            .clone()
+            .unwrap_or_else(|| "UNCODED".to_string());
+        let key = issue_to_resource_key(issue);
+        let Some((ar, namespaced)) = api_resource_for(&key) else {
+            continue;
+        };
+        let (namespace, name) = if namespaced {
+            match resource.split_once('/') {
+                Some((ns, name)) => (Some(ns), name),
+                None => {
+                    warn!(
+                        "export-affected: no namespace in resource '{}' ({})",
+                        resource, key
+                    );
+                    continue;
+                }
+            }
+        } else {
+            (None, resource)
+        };
+        if !seen.insert((rule_id.clone(), key.clone(), resource.to_string())) {
+            continue;
+        }
+
+        let api: Api<DynamicObject> = match namespace {
+            Some(ns) => Api::namespaced_with(client.client().clone(), ns, &ar),
+            None => Api::all_with(client.client().clone(), &ar),
+        };
+        let obj = match api.get(name).await {
+            Ok(o) => o,
+            Err(e) => {
+                warn!("export-affected: cannot fetch {} {}: {}", key, resource, e);
+                continue;
+            }
+        };
+        let mut value = serde_json::to_value(&obj)?;
+        sanitize_object(&mut value);
+        let yaml = serde_yaml::to_string(&value)?;
+
+        let rule_dir = root.join(file_component(&rule_id));
+        std::fs::create_dir_all(&rule_dir)?;
+        let file_name = format!(
+            "{}_{}_{}.yaml",
+            key,
+            file_component(namespace.unwrap_or("cluster")),
+            file_component(name)
+        );
+        std::fs::write(rule_dir.join(file_name), yaml)?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sanitize_strips_managed_fields_and_redacts_secrets() {
+        let mut obj = json!({
+            "apiVersion": "v1",
+            "kind": "Secret",
+            "metadata": {
+                "name": "db",
+                "managedFields": [{"manager": "kubectl"}],
+                "annotations": {LAST_APPLIED_ANNOTATION: "{\"data\":{\"password\":\"cGFzcw==\"}}"}
+            },
+            "data": {"password": "cGFzcw=="},
+            "stringData": {"user": "admin"}
+        });
+        sanitize_object(&mut obj);
+        assert!(obj["metadata"].get("managedFields").is_none());
+        assert!(obj["metadata"].get("annotations").is_none());
+        assert_eq!(obj["data"]["password"], REDACTED);
+        assert_eq!(obj["stringData"]["user"], REDACTED);
+
+        let mut pod = json!({
+            "kind": "Pod",
+            "spec": {"containers": [{"name": "app", "env": [
+                {"name": "DB_PASSWORD", "value": "hunter2"},
+                {"name": "LOG_LEVEL", "value": "info"}
+            ]}]}
+        });
+        sanitize_object(&mut pod);
+        let env = &pod["spec"]["containers"][0]["env"];
+        assert_eq!(env[0]["value"], REDACTED);
+        assert_eq!(env[1]["value"], "info");
+    }
+}
@@ -2,12 +2,19 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::fs;
 
+use crate::cli::{Lang, ReportSection};
 use crate::inspections::issue_codes;
 use crate::inspections::types::*;
 use crate::node_inspection::NodeInspectionResult;
+use crate::reporting::i18n;
 use crate::reporting::report_resource::{issue_to_resource_key, REPORT_RESOURCE_ORDER};
+use crate::reporting::resource_appendix::DEFAULT_MAX_RESOURCES_PER_ISSUE;
+use crate::reporting::template::ReportTemplate;
+use crate::scoring::namespace::{
+    namespace_scores, CRITICAL_PENALTY, INFO_PENALTY, WARNING_PENALTY,
+};
 use crate::scoring::scoring_engine::ScoringEngine;
-use crate::utils::format::truncate_string;
+use crate::utils::format::{format_duration, truncate_string};
 
 const DEFAULT_MAX_RECOMMENDATIONS: usize = 5;
 
@@ -71,6 +78,11 @@ fn inspection_type_to_resource(inspection_type: &str) -> &'static str {
         "Security Configuration" => "NetworkPolicy",
         "Policy & Governance" => "ResourceQuota",
         "Observability" => "Observability",
+        "Event Analytics" => "Event",
+        "Debug Settings" => "Deployment",
+        "Orphaned Resources" => "ConfigMap",
+        "Resilience" => "Deployment",
+        "GPU & Extended Resources" => "Node",
         "Namespace" => "Namespace",
         "Certificates" => "Certificate",
         "Upgrade Readiness" => "Node",
@@ -92,8 +104,8 @@ fn host_path_display(path: &str) -> String {
     if path == "/host" {
         return "/".to_string();
     }
-    if path.starts_with("/host/") {
-        return path["/host/".len()..].to_string();
+    if let Some(rest) = path.strip_prefix("/host/") {
+        return rest.to_string();
     }
     path.to_string()
 }
@@ -116,15 +128,457 @@ fn slugify(s: &str) -> String {
     out.trim_matches('-').to_string()
 }
 
+/// Renders a [`ClusterReport`] as the Markdown report (HTML, CSV and XLSX are derived from it).
 pub struct ReportGenerator {
     #[allow(dead_code)]
     scoring_engine: ScoringEngine,
+    lang: Lang,
+    docs_base: Option<String>,
+    template: Option<ReportTemplate>,
+    /// Empty: every section.
+    sections: Vec<ReportSection>,
+    /// 0 lists every resource.
+    max_resources_per_issue: usize,
+    /// `--resource-appendix` file named in overflow rows.
+    resource_appendix: Option<String>,
+}
+
+/// Seconds with one decimal below 10s, else minutes/hours as in `format_duration`.
+/// Splits node table `rows` into one group per operating system (from the node list) when the
+/// cluster mixes operating systems; otherwise returns a single unlabelled group.
+fn group_by_os<'a, T>(
+    rows: &'a [T],
+    node_name: impl Fn(&T) -> &str,
+    overview: &ClusterOverview,
+) -> Vec<(Option<String>, Vec<&'a T>)> {
+    let os_of: HashMap<&str, &str> = overview
+        .node_list
+        .iter()
+        .flatten()
+        .map(|n| (n.name.as_str(), n.operating_system.as_str()))
+        .collect();
+    let mut groups: std::collections::BTreeMap<&str, Vec<&T>> = Default::default();
+    for row in rows {
+        let os = os_of.get(node_name(row)).copied().unwrap_or("unknown");
+        groups.entry(os).or_default().push(row);
+    }
+    if groups.len() <= 1 {
+        return vec![(None, rows.iter().collect())];
+    }
+    groups
+        .into_iter()
+        .map(|(os, group)| (Some(os.to_string()), group))
+        .collect()
+}
+
+fn format_step_duration(ms: u64) -> String {
+    if ms < 10_000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format_duration(ms / 1000)
+    }
+}
+
+/// "etcd objects by kind": the largest kinds with their estimated size, and the database size
+/// when the API server reports it.
+fn etcd_objects_table(counts: &EtcdObjectCounts) -> String {
+    let mib = |bytes: u64| format!("{:.1}", bytes as f64 / (1024.0 * 1024.0));
+    let mut out = String::from("### etcd objects by kind\n\n");
+    out.push_str(&format!(
+        "Source: `{}`{}. Sizes are estimated from typical object sizes per kind.\n\n",
+        counts.source,
+        counts
+            .db_size_bytes
+            .map(|b| format!("; etcd database size {} MiB", mib(b)))
+            .unwrap_or_default()
+    ));
+    out.push_str("| Resource | Objects | Est. size (MiB) |\n");
+    out.push_str("|----------|---------|-----------------|\n");
+    let top = crate::inspections::etcd_objects::top_rows(counts);
+    for row in top {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            row.resource,
+            row.count,
+            mib(row.estimated_bytes)
+        ));
+    }
+    if counts.rows.len() > top.len() {
+        out.push_str(&format!(
+            "\n*{} more kind(s) in the JSON output.*\n",
+            counts.rows.len() - top.len()
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// Resources listed in the "Top riskiest resources" table.
+const TOP_RISK_RESOURCES: usize = 10;
+
+/// "Top riskiest resources": findings summed per resource, weighted by severity with the
+/// namespace ranking penalties (Critical 10, Warning 3, Info 0.5), highest risk first. Findings
+/// without a resource are left out. Empty when no finding names a resource.
+fn top_risk_resources_section(report: &ClusterReport) -> String {
+    struct Risk<'a> {
+        kind: String,
+        resource: &'a str,
+        risk: f64,
+        counts: [usize; 3],
+        codes: Vec<&'a str>,
+    }
+    let mut by_resource: HashMap<(String, &str), Risk> = HashMap::new();
+    for issue in report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+    {
+        let Some(resource) = issue.resource.as_deref().map(str::trim) else {
+            continue;
+        };
+        if resource.is_empty() {
+            continue;
+        }
+        let kind = issue_to_resource_key(issue);
+        let entry = by_resource
+            .entry((kind.clone(), resource))
+            .or_insert_with(|| Risk {
+                kind,
+                resource,
+                risk: 0.0,
+                counts: [0; 3],
+                codes: Vec::new(),
+            });
+        let (penalty, slot) = match issue.severity {
+            IssueSeverity::Critical => (CRITICAL_PENALTY, 0),
+            IssueSeverity::Warning => (WARNING_PENALTY, 1),
+            IssueSeverity::Info => (INFO_PENALTY, 2),
+        };
+        entry.risk += penalty;
+        entry.counts[slot] += 1;
+        if let Some(code) = issue.rule_id.as_deref() {
+            if !entry.codes.contains(&code) {
+                entry.codes.push(code);
+            }
+        }
+    }
+    if by_resource.is_empty() {
+        return String::new();
+    }
+    let mut ranked: Vec<Risk> = by_resource.into_values().collect();
+    ranked.sort_by(|a, b| {
+        b.risk
+            .total_cmp(&a.risk)
+            .then(b.counts.cmp(&a.counts))
+            .then(a.resource.cmp(b.resource))
+            .then(a.kind.cmp(&b.kind))
+    });
+    let mut out = format!(
+        "## Top {} riskiest resources\n\n\
+         Findings per resource weighted by severity (Critical 10, Warning 3, Info 0.5).\n\n",
+        TOP_RISK_RESOURCES.min(ranked.len())
+    );
+    out.push_str("| Rank | Kind | Resource | Risk | Critical | Warning | Info | Issue codes |\n");
+    out.push_str("|------|------|----------|------|----------|---------|------|-------------|\n");
+    for (i, r) in ranked.iter().take(TOP_RISK_RESOURCES).enumerate() {
+        let mut codes = r.codes.clone();
+        codes.sort_unstable();
+        out.push_str(&format!(
+            "| {} | {} | `{}` | {:.1} | {} | {} | {} | {} |\n",
+            i + 1,
+            r.kind,
+            r.resource,
+            r.risk,
+            r.counts[0],
+            r.counts[1],
+            r.counts[2],
+            if codes.is_empty() {
+                "-".to_string()
+            } else {
+                codes.join(", ")
+            }
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// "Overdue findings": Critical findings open longer than the SLA of `--critical-sla-days`,
+/// oldest first. Empty without `--history-dir`.
+fn overdue_findings_section(report: &ClusterReport) -> String {
+    let Some(ages) = &report.metadata.finding_ages else {
+        return String::new();
+    };
+    let mut out = String::from("## Overdue findings\n\n");
+    out.push_str(&format!(
+        "Critical findings open for more than {} day(s), from {} earlier report(s) in `{}`.\n\n",
+        ages.critical_sla_days, ages.earlier_runs, ages.history_dir
+    ));
+    let overdue = crate::sla::overdue_findings(report);
+    if overdue.is_empty() {
+        out.push_str("No Critical finding is past its SLA.\n\n");
+        return out;
+    }
+    out.push_str("| Resource | Issue Code | Title | Open since | Days open |\n");
+    out.push_str("|----------|------------|-------|------------|-----------|\n");
+    for (issue, since) in overdue {
+        let code_link = issue
+            .rule_id
+            .as_ref()
+            .map(|c| format!("[{}]({})", c, issue_codes::doc_path(c)))
+            .unwrap_or_else(|| "-".to_string());
+        let title = issue
+            .rule_id
+            .as_deref()
+            .and_then(issue_codes::short_title)
+            .map(String::from)
+            .unwrap_or_else(|| issue.description.clone());
+        out.push_str(&format!(
+            "| `{}` | {} | {} | {} | {} |\n",
+            issue.resource.as_deref().unwrap_or("-"),
+            code_link,
+            title,
+            since.format("%Y-%m-%d"),
+            (report.timestamp - since).num_days()
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// "Score methodology" appendix: how each module weighs into the overall score, and how each
+/// check weighs into its module, with the points lost at both levels, worst first.
+fn score_methodology_section(report: &ClusterReport) -> String {
+    let profile = &report.metadata.scoring_profile;
+    let breakdown =
+        ScoringEngine::with_profile(profile.clone()).generate_score_breakdown(&report.inspections);
+    let scored: Vec<(&InspectionResult, f64)> = report
+        .inspections
+        .iter()
+        .filter(|i| !i.is_failed())
+        .map(|i| {
+            (
+                i,
+                breakdown.get(&i.inspection_type).map_or(0.0, |d| d.weight),
+            )
+        })
+        .collect();
+    let total_weight: f64 = scored.iter().map(|(_, w)| w).sum();
+    let mut out = String::from(
+        "## Appendix: Score methodology
+
+",
+    );
+    out.push_str(&format!(
+        "The overall score ({:.1}) is the weighted mean of the module scores (scoring profile `{}`): \
+         each module contributes `score × weight / {:.2}`, the sum of the weights of the modules that ran. \
+         A module score is the mean of its check scores, so each of its n checks weighs 1/n. \
+         \"Points lost\" is what a module or check would add to the overall score at 100.\n\n",
+        report.overall_score, profile.name, total_weight
+    ));
+    let failed: Vec<&str> = report
+        .inspections
+        .iter()
+        .filter(|i| i.is_failed())
+        .map(|i| i.inspection_type.as_str())
+        .collect();
+    if !failed.is_empty() {
+        out.push_str(&format!(
+            "Failed modules are left out of the mean: {}.\n\n",
+            failed.join(", ")
+        ));
+    }
+    if total_weight <= 0.0 {
+        return out;
+    }
+    let share = |weight: f64| weight / total_weight;
+    let mut modules = scored.clone();
+    modules.sort_by(|a, b| {
+        let lost = |(i, w): &(&InspectionResult, f64)| (100.0 - i.overall_score) * share(*w);
+        lost(b).total_cmp(&lost(a))
+    });
+
+    out.push_str("| Module | Score | Weight | Share | Contribution | Points lost |\n");
+    out.push_str("|--------|-------|--------|-------|--------------|-------------|\n");
+    for (inspection, weight) in &modules {
+        out.push_str(&format!(
+            "| {} | {:.1} | {:.2} | {:.1}% | {:.2} | {:.2} |\n",
+            inspection.inspection_type,
+            inspection.overall_score,
+            weight,
+            share(*weight) * 100.0,
+            inspection.overall_score * share(*weight),
+            (100.0 - inspection.overall_score) * share(*weight)
+        ));
+    }
+    out.push('\n');
+
+    for (inspection, weight) in modules.iter().filter(|(i, _)| !i.checks.is_empty()) {
+        let n = inspection.checks.len() as f64;
+        out.push_str(&format!("### {} score\n\n", inspection.inspection_type));
+        let mean = inspection.checks.iter().map(|c| c.score).sum::<f64>() / n;
+        if (mean - inspection.overall_score).abs() > 0.05 {
+            out.push_str(&format!(
+                "The check mean is {:.1}; the module score was adjusted to {:.1} after findings were \
+                 accepted (baseline), excluded (profile) or not selected (check filter).\n\n",
+                mean, inspection.overall_score
+            ));
+        }
+        let mut checks: Vec<&CheckResult> = inspection.checks.iter().collect();
+        checks.sort_by(|a, b| a.score.total_cmp(&b.score));
+        out.push_str("| Check | Status | Score | Weight in module | Points lost (module) | Points lost (overall) |\n");
+        out.push_str("|-------|--------|-------|------------------|----------------------|-----------------------|\n");
+        for check in checks {
+            let lost = (100.0 - check.score).max(0.0) / n;
+            out.push_str(&format!(
+                "| {} | {:?} | {:.1}/{:.1} | {:.1}% | {:.2} | {:.2} |\n",
+                check.name,
+                check.status,
+                check.score,
+                check.max_score,
+                100.0 / n,
+                lost,
+                lost * share(*weight)
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// "Run diagnostics": time, API requests and listed objects per step, and optional data sources.
+fn run_diagnostics_section(diagnostics: &RunDiagnostics) -> String {
+    let mut out = String::from("## Run diagnostics\n\n");
+    out.push_str(&format!(
+        "**Run duration**: {}\n\n",
+        format_step_duration(diagnostics.duration_ms)
+    ));
+    if let Some(slowest) = diagnostics.steps.iter().max_by_key(|s| s.duration_ms) {
+        if diagnostics.duration_ms > 0 {
+            out.push_str(&format!(
+                "**Slowest step**: {} ({:.0}% of the run)\n\n",
+                slowest.step,
+                slowest.duration_ms as f64 / diagnostics.duration_ms as f64 * 100.0
+            ));
+        }
+    }
+    if !diagnostics.steps.is_empty() {
+        out.push_str("| Step | Duration | API requests | API errors | Objects listed | Status |\n");
+        out.push_str("|------|----------|--------------|------------|----------------|--------|\n");
+        for step in &diagnostics.steps {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                step.step,
+                format_step_duration(step.duration_ms),
+                step.api_requests,
+                step.api_errors,
+                step.objects_listed,
+                if step.failed { "Error" } else { "Pass" }
+            ));
+        }
+        out.push('\n');
+    }
+    if !diagnostics.data_sources.is_empty() {
+        out.push_str("| Optional data source | Used | Details |\n");
+        out.push_str("|----------------------|------|---------|\n");
+        for source in &diagnostics.data_sources {
+            out.push_str(&format!(
+                "| {} | {} | {} |\n",
+                source.source,
+                if source.used { "Yes" } else { "No" },
+                source.detail
+            ));
+        }
+        out.push('\n');
+    }
+    out
 }
 
 impl ReportGenerator {
     pub fn new() -> Self {
         Self {
             scoring_engine: ScoringEngine::new(),
+            lang: Lang::En,
+            docs_base: None,
+            template: None,
+            sections: Vec::new(),
+            max_resources_per_issue: DEFAULT_MAX_RESOURCES_PER_ISSUE,
+            resource_appendix: None,
+        }
+    }
+
+    /// Localize Markdown output (and HTML rendered from it) to `lang`; see `reporting::i18n`.
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// Link issue codes to `<base>/<CODE>.md` (a `kubeowler docs generate` bundle) instead of GitHub.
+    pub fn with_docs_base(mut self, base: Option<String>) -> Self {
+        self.docs_base = base.map(|b| b.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Render the main report with a user `--template` instead of the built-in layout.
+    pub fn with_template(mut self, template: Option<ReportTemplate>) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// Only write these sections of the built-in layout (`--sections`); empty keeps them all.
+    pub fn with_sections(mut self, sections: Vec<ReportSection>) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    fn shows(&self, section: ReportSection) -> bool {
+        self.sections.is_empty() || self.sections.contains(&section)
+    }
+
+    /// List at most `max` resources per issue row group (0: all); the rest become one
+    /// "… and N more" row pointing at `appendix` when given.
+    pub fn with_resource_limit(mut self, max: usize, appendix: Option<String>) -> Self {
+        self.max_resources_per_issue = max;
+        self.resource_appendix = appendix;
+        self
+    }
+
+    /// `--resource-appendix` file, if any.
+    pub fn resource_appendix(&self) -> Option<&str> {
+        self.resource_appendix.as_deref()
+    }
+
+    /// Resources to list of one issue row group, and how many were left out.
+    fn listed_resources<'a>(&self, resources: &'a [String]) -> (&'a [String], usize) {
+        let max = self.max_resources_per_issue;
+        if max == 0 || resources.len() <= max {
+            (resources, 0)
+        } else {
+            (&resources[..max], resources.len() - max)
+        }
+    }
+
+    fn overflow_cell(&self, more: usize) -> String {
+        match &self.resource_appendix {
+            Some(file) => format!("… and {} more (see `{}`)", more, file),
+            None => format!("… and {} more", more),
+        }
+    }
+
+    /// True when the main report is a complete HTML page from an HTML `--template`.
+    pub fn renders_html(&self) -> bool {
+        self.template.as_ref().is_some_and(|t| t.is_html())
+    }
+
+    /// Localization and doc-link rewriting applied to every Markdown page.
+    fn finish_markdown(&self, md: &str) -> String {
+        let md = i18n::localize_markdown(md, self.lang);
+        match &self.docs_base {
+            Some(base) => md.replace(
+                &format!("]({}/", issue_codes::DOCS_BASE_URL),
+                &format!("]({}/", base),
+            ),
+            None => md,
         }
     }
 
@@ -147,6 +601,7 @@ impl ReportGenerator {
     }
 
     /// Returns the main report as Markdown string (same filtering as generate_report_with_filters, no disk write).
+    /// With an HTML `--template` this is the rendered HTML page (see [`Self::renders_html`]).
     pub fn generate_markdown_string(
         &self,
         cluster_report: &ClusterReport,
@@ -165,7 +620,17 @@ impl ReportGenerator {
         } else {
             filtered
         };
-        self.generate_main_report(&filtered, max_recommendations, check_level_filter)
+        if let Some(template) = &self.template {
+            let check_filter = check_level_filter.unwrap_or_else(|| parse_check_level_filter(""));
+            let out = template.render(&filtered, &check_filter)?;
+            return Ok(if template.is_html() {
+                out
+            } else {
+                self.finish_markdown(&out)
+            });
+        }
+        let md = self.generate_main_report(&filtered, max_recommendations, check_level_filter)?;
+        Ok(self.finish_markdown(&md))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -199,7 +664,7 @@ impl ReportGenerator {
             } else {
                 filtered
             };
-            let summary_report = self.generate_summary_report(&filtered)?;
+            let summary_report = self.finish_markdown(&self.generate_summary_report(&filtered)?);
             let summary_path = output_path.replace(".md", "-summary.md");
             fs::write(summary_path, summary_report)?;
         }
@@ -226,7 +691,7 @@ impl ReportGenerator {
             })
             .collect();
 
-        let engine = ScoringEngine::new();
+        let engine = ScoringEngine::with_profile(report.metadata.scoring_profile.clone());
         let overall = engine.calculate_weighted_score(&new_report.inspections);
         let health = engine.get_health_status(overall);
         let score_breakdown_details = engine.generate_score_breakdown(&new_report.inspections);
@@ -240,6 +705,7 @@ impl ReportGenerator {
         let priority_recommendations = Self::build_aggregated_recommendations(&new_report, max_r);
         new_report.overall_score = overall;
         new_report.executive_summary = ExecutiveSummary {
+            grade: Some(health.grade()),
             health_status: health,
             key_findings,
             priority_recommendations,
@@ -254,8 +720,25 @@ impl ReportGenerator {
         max_recommendations: Option<usize>,
     ) -> ClusterReport {
         let lower: Vec<String> = filters.iter().map(|s| s.to_lowercase()).collect();
+        Self::retain_issues(
+            report,
+            |iss| {
+                lower
+                    .iter()
+                    .any(|f| iss.category.to_lowercase().contains(f))
+            },
+            max_recommendations,
+        )
+    }
+
+    /// Copy of `report` with only the issues matching `keep`; modules left without issues are dropped
+    /// and the overall score and executive summary are rebuilt from the remaining modules.
+    pub fn retain_issues(
+        report: &ClusterReport,
+        keep: impl Fn(&Issue) -> bool,
+        max_recommendations: Option<usize>,
+    ) -> ClusterReport {
         let mut new_report = report.clone();
-        // Keep only inspection modules that have issues matching the category filter; recalc scores and summary.
         new_report.inspections = report
             .inspections
             .iter()
@@ -265,11 +748,7 @@ impl ReportGenerator {
                     .summary
                     .issues
                     .iter()
-                    .filter(|iss| {
-                        lower
-                            .iter()
-                            .any(|f| iss.category.to_lowercase().contains(f))
-                    })
+                    .filter(|iss| keep(iss))
                     .cloned()
                     .collect();
 
@@ -290,7 +769,7 @@ impl ReportGenerator {
             .collect();
 
         // Rebuild executive summary from remaining modules.
-        let engine = ScoringEngine::new();
+        let engine = ScoringEngine::with_profile(report.metadata.scoring_profile.clone());
         let overall = engine.calculate_weighted_score(&new_report.inspections);
         let health = engine.get_health_status(overall);
         let score_breakdown_details = engine.generate_score_breakdown(&new_report.inspections);
@@ -306,6 +785,7 @@ impl ReportGenerator {
 
         new_report.overall_score = overall;
         new_report.executive_summary = ExecutiveSummary {
+            grade: Some(health.grade()),
             health_status: health,
             key_findings,
             priority_recommendations,
@@ -450,7 +930,7 @@ impl ReportGenerator {
             .into_iter()
             .map(|((rid, _cat, _rec), (title, rec, resources))| (rid, title, rec, resources))
             .collect();
-        rows_vec.sort_by(|a, b| b.3.len().cmp(&a.3.len()));
+        rows_vec.sort_by_key(|b| std::cmp::Reverse(b.3.len()));
         for (rule_id, title, rec, resources) in rows_vec {
             let n = resources.len();
             let resource_list = format_affected_resources(&resources);
@@ -526,6 +1006,32 @@ impl ReportGenerator {
         out
     }
 
+    /// Distinct `remediation_commands` of the issues shown under `check_filter` (Info only with
+    /// `all`), Critical first.
+    fn remediation_commands<'a>(
+        issues: &'a [Issue],
+        check_filter: &CheckLevelFilter,
+    ) -> Vec<&'a str> {
+        let mut ordered: Vec<&Issue> = issues
+            .iter()
+            .filter(|i| {
+                i.severity != IssueSeverity::Info || matches!(check_filter, CheckLevelFilter::All)
+            })
+            .collect();
+        ordered.sort_by_key(|i| match i.severity {
+            IssueSeverity::Critical => 0,
+            IssueSeverity::Warning => 1,
+            IssueSeverity::Info => 2,
+        });
+        let mut commands: Vec<&str> = Vec::new();
+        for cmd in ordered.iter().flat_map(|i| i.remediation_commands.iter()) {
+            if !commands.contains(&cmd.as_str()) {
+                commands.push(cmd);
+            }
+        }
+        commands
+    }
+
     /// Group issues by severity; within severity, group by rule_id when present, else by (category, recommendation).
     /// Each group yields (rule_id, title, recommendation, resources). Title is short_title(code) or first description.
     #[allow(clippy::type_complexity)]
@@ -584,7 +1090,7 @@ impl ReportGenerator {
             }
         }
         let mut rows: Vec<(String, usize)> = rec_counts.into_iter().collect();
-        rows.sort_by(|a, b| b.1.cmp(&a.1));
+        rows.sort_by_key(|b| std::cmp::Reverse(b.1));
         rows.truncate(max_items);
         rows.into_iter().map(|(rec, _)| rec).collect()
     }
@@ -668,7 +1174,7 @@ impl ReportGenerator {
 
         if !category_counts.is_empty() {
             let mut top_categories: Vec<(String, u32)> = category_counts.into_iter().collect();
-            top_categories.sort_by(|a, b| b.1.cmp(&a.1));
+            top_categories.sort_by_key(|b| std::cmp::Reverse(b.1));
             top_categories.truncate(5);
             content.push_str("**Top Resource Categories by Issue Count (Top 5)**\n\n");
             for (category, count) in top_categories {
@@ -1177,365 +1683,843 @@ impl ReportGenerator {
             .unwrap_or_else(|| report.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string());
         content.push_str(&format!("**Generated At**: {}\n\n", generated_at));
 
-        // Cluster Overview: always output section (placeholder if no data); core metrics in table
-        content.push_str("## 🖥️ Cluster Overview\n\n");
-        if let Some(ref overview) = report.cluster_overview {
-            content.push_str("| Metric | Value |\n");
-            content.push_str("|--------|-------|\n");
-            if let Some(ref v) = overview.cluster_version {
-                content.push_str(&format!("| Cluster Version | {} |\n", v));
-            }
-            content.push_str(&format!("| Node Count | {} |\n", overview.node_count));
+        content.push_str(&format!(
+            "**Scoring Profile**: {}\n\n",
+            report.metadata.scoring_profile.name
+        ));
+
+        if let Some(ns) = &report.metadata.namespace_scope {
             content.push_str(&format!(
-                "| Ready Nodes | {} |\n",
-                overview.ready_node_count
+                "**Scope**: namespace `{}` (nodes and other cluster-scoped objects are cluster-wide)\n\n",
+                ns
             ));
-            if let Some(pc) = overview.pod_count {
-                content.push_str(&format!("| Pod Count | {} |\n", pc));
-            }
-            if let Some(nc) = overview.namespace_count {
-                content.push_str(&format!("| Namespace Count | {} |\n", nc));
-            }
-            if let Some(age) = overview.cluster_age_days {
-                content.push_str(&format!("| Cluster Age (days) | {} |\n", age));
-            }
-            if let Some(ref node_list) = overview.node_list {
-                let runtimes: std::collections::HashSet<&str> = node_list
-                    .iter()
-                    .filter_map(|r| r.container_runtime_version.as_deref())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                if !runtimes.is_empty() {
-                    let rt_str: Vec<&str> = runtimes.into_iter().collect();
-                    content.push_str(&format!("| Container Runtime | {} |\n", rt_str.join(", ")));
-                }
-            }
-            let health_emoji = match report.executive_summary.health_status {
-                HealthStatus::Excellent => "🟢",
-                HealthStatus::Good => "🟡",
-                HealthStatus::Fair => "🟠",
-                HealthStatus::Poor => "🔴",
-                HealthStatus::Critical => "🚨",
-            };
-            let health_text = match report.executive_summary.health_status {
-                HealthStatus::Excellent => "Excellent",
-                HealthStatus::Good => "Good",
-                HealthStatus::Fair => "Fair",
-                HealthStatus::Poor => "Poor",
-                HealthStatus::Critical => "Critical",
-            };
+        }
+
+        if let Some(selector) = &report.metadata.label_selector {
             content.push_str(&format!(
-                "| Overall Health | {} {} (Score: {:.1}) |\n",
-                health_emoji, health_text, report.overall_score
+                "**Label Selector**: `{}` (namespaced objects other than Events and Leases)\n\n",
+                selector
             ));
-            content.push('\n');
-            if let Some(ref conds) = overview.node_conditions {
-                if !conds.is_empty() {
-                    content.push_str("### Node conditions\n\n");
-                    content.push_str(
-                        "| Node | Ready | MemoryPressure | DiskPressure | PIDPressure |\n",
-                    );
-                    content.push_str(
-                        "|------|-------|----------------|--------------|-------------|\n",
-                    );
-                    for r in conds {
-                        content.push_str(&format!(
-                            "| {} | {} | {} | {} | {} |\n",
-                            r.node_name,
-                            r.ready,
-                            r.memory_pressure,
-                            r.disk_pressure,
-                            r.pid_pressure
-                        ));
-                    }
-                    content.push('\n');
+        }
+
+        if let Some(selection) = &report.metadata.check_selection {
+            content.push_str(&format!(
+                "**Checks**: {} (other issue codes were not evaluated)\n\n",
+                selection
+            ));
+        }
+
+        if let Some(profile) = &report.metadata.profile {
+            content.push_str(&format!(
+                "**Profile**: {} ({} finding(s) in excluded namespaces dropped, {} severity override(s))\n\n",
+                profile.name, profile.excluded, profile.overridden
+            ));
+        }
+
+        if let Some(team) = &report.metadata.team {
+            content.push_str(&format!("**Team**: {}\n\n", team));
+        }
+
+        if self.shows(ReportSection::Risks) {
+            content.push_str(&top_risk_resources_section(report));
+        }
+
+        if self.shows(ReportSection::Overdue) {
+            content.push_str(&overdue_findings_section(report));
+        }
+
+        if self.shows(ReportSection::Overview) {
+            // Data completeness: sources that failed or were only partly collected
+            content.push_str("## Data completeness\n\n");
+            if report.metadata.collection_warnings.is_empty() {
+                content.push_str("All data sources were collected.\n\n");
+            } else {
+                content.push_str("| Data source | Status | Impact |\n");
+                content.push_str("|-------------|--------|--------|\n");
+                for w in &report.metadata.collection_warnings {
+                    let status = match w.status {
+                        DataSourceStatus::Unavailable => "Unavailable",
+                        DataSourceStatus::Partial => "Partial",
+                    };
+                    content.push_str(&format!(
+                        "| {} | {} | {} |\n",
+                        w.source,
+                        status,
+                        w.detail.replace('|', "\\|")
+                    ));
                 }
+                content.push_str("\nSections and checks that depend on these sources are incomplete; scores cover only the data collected.\n\n");
             }
-            // Workload summary
-            if let Some(ref wl) = overview.workload_summary {
-                content.push_str("### Workload summary\n\n");
-                content.push_str("| Controller | Total | Ready |\n");
-                content.push_str("|------------|-------|-------|\n");
-                content.push_str(&format!(
-                    "| Deployment | {} | {} |\n",
-                    wl.deployments_total, wl.deployments_ready
-                ));
-                content.push_str(&format!(
-                    "| StatefulSet | {} | {} |\n",
-                    wl.statefulsets_total, wl.statefulsets_ready
-                ));
-                content.push_str(&format!(
-                    "| DaemonSet | {} | {} |\n\n",
-                    wl.daemonsets_total, wl.daemonsets_ready
-                ));
-            }
-            // Storage summary
-            if let Some(ref st) = overview.storage_summary {
-                content.push_str("### Storage summary\n\n");
+
+            // Cluster Overview: always output section (placeholder if no data); core metrics in table
+            content.push_str("## 🖥️ Cluster Overview\n\n");
+            if let Some(ref overview) = report.cluster_overview {
                 content.push_str("| Metric | Value |\n");
                 content.push_str("|--------|-------|\n");
-                content.push_str(&format!("| PV total | {} |\n", st.pv_total));
-                content.push_str(&format!("| PVC total | {} |\n", st.pvc_total));
-                content.push_str(&format!("| PVC Bound | {} |\n", st.pvc_bound));
+                if let Some(ref v) = overview.cluster_version {
+                    content.push_str(&format!("| Cluster Version | {} |\n", v));
+                }
+                if let Some(provider) = overview.managed_provider {
+                    content.push_str(&format!("| Managed Provider | {} |\n", provider));
+                }
+                content.push_str(&format!("| Node Count | {} |\n", overview.node_count));
                 content.push_str(&format!(
-                    "| StorageClass count | {} |\n",
-                    st.storage_class_count
+                    "| Ready Nodes | {} |\n",
+                    overview.ready_node_count
                 ));
-                content.push_str(&format!(
-                    "| Default StorageClass | {} |\n\n",
-                    if st.has_default_storage_class {
-                        "Yes"
-                    } else {
-                        "No"
+                if let Some(pc) = overview.pod_count {
+                    content.push_str(&format!("| Pod Count | {} |\n", pc));
+                }
+                if let Some(nc) = overview.namespace_count {
+                    content.push_str(&format!("| Namespace Count | {} |\n", nc));
+                }
+                if let Some(age) = overview.cluster_age_days {
+                    content.push_str(&format!("| Cluster Age (days) | {} |\n", age));
+                }
+                if let Some(ref node_list) = overview.node_list {
+                    let spot = node_list.iter().filter(|n| n.spot).count();
+                    if spot > 0 {
+                        content.push_str(&format!(
+                            "| Spot / On-demand Nodes | {} / {} |\n",
+                            spot,
+                            node_list.len() - spot
+                        ));
                     }
+                }
+                if let Some(ref oldest) = overview.oldest_node_image {
+                    content.push_str(&format!(
+                        "| Oldest Node Image | {} days ({}, {}) |\n",
+                        oldest.age_days, oldest.node, oldest.source
+                    ));
+                }
+                if let Some(ref node_list) = overview.node_list {
+                    let runtimes: std::collections::HashSet<&str> = node_list
+                        .iter()
+                        .filter_map(|r| r.container_runtime_version.as_deref())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                    if !runtimes.is_empty() {
+                        let rt_str: Vec<&str> = runtimes.into_iter().collect();
+                        content
+                            .push_str(&format!("| Container Runtime | {} |\n", rt_str.join(", ")));
+                    }
+                }
+                let health = &report.executive_summary.health_status;
+                content.push_str(&format!(
+                    "| Overall Health | {} {} (Score: {:.1}, Grade: {}) |\n",
+                    health.emoji(),
+                    health.label(),
+                    report.overall_score,
+                    health.grade()
                 ));
-            }
-            // Container resource usage: top 20 high usage (usage/limit >= 80%); shown only when metrics available
-            if overview.metrics_available == Some(true) {
-                if let Some(ref rows) = overview.container_usage_notable {
-                    if !rows.is_empty() {
-                        content.push_str("### Container resource usage (top 20 high usage)\n\n");
-                        content.push_str("Top 20 containers by usage vs limit (CPU or memory ≥ 80% of limit). Data from **metrics-server** (Pod metrics API) and **Pod spec** (limits). This section is **omitted when metrics-server is unavailable**.\n\n");
-                        content.push_str("| Namespace | Pod | Container | CPU used (m) | CPU request (m) | CPU limit (m) | Mem used (Mi) | Mem request (Mi) | Mem limit (Mi) | Note |\n");
-                        content.push_str("|-----------|-----|-----------|--------------|-----------------|---------------|---------------|------------------|----------------|------|\n");
-                        for r in rows {
-                            let note = match r.notable_reason.as_str() {
-                                "high_usage" => "High usage",
-                                "low_usage" => "Low usage",
-                                "no_request_no_limit" => "No request",
-                                _ => r.notable_reason.as_str(),
-                            };
+                content.push('\n');
+                if let Some(ref pools) = overview.node_pools {
+                    let pct = |v: Option<f64>| {
+                        v.map(|x| format!("{:.1}%", x))
+                            .unwrap_or_else(|| "-".to_string())
+                    };
+                    let list = |v: &[String]| {
+                        if v.is_empty() {
+                            "-".to_string()
+                        } else {
+                            v.join(", ")
+                        }
+                    };
+                    content.push_str("### Node pools\n\n");
+                    content.push_str("Nodes grouped by pool label (see `--node-pool-label`). More than one kubelet or kernel version in a pool indicates an incomplete rollout.\n\n");
+                    content.push_str("| Pool | Nodes | Ready | Pods | Kubelet versions | Kernel versions | Avg CPU % | Avg Mem % | Max Disk % |\n");
+                    content.push_str("|------|-------|-------|------|------------------|-----------------|-----------|-----------|------------|\n");
+                    for p in pools {
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                            p.pool,
+                            p.node_count,
+                            p.ready_count,
+                            p.pod_count,
+                            list(&p.kubelet_versions),
+                            list(&p.kernel_versions),
+                            pct(p.avg_cpu_pct),
+                            pct(p.avg_memory_pct),
+                            pct(p.max_disk_pct)
+                        ));
+                    }
+                    content.push('\n');
+                    content.push_str("### Node pool findings\n\n");
+                    content.push_str("| Pool | Inspected nodes | Node inspector issues | Critical findings | Warning findings |\n");
+                    content.push_str("|------|-----------------|-----------------------|-------------------|------------------|\n");
+                    for p in pools {
+                        content.push_str(&format!(
+                            "| {} | {}/{} | {} | {} | {} |\n",
+                            p.pool,
+                            p.inspected_nodes,
+                            p.node_count,
+                            p.node_inspection_issues,
+                            p.critical_findings,
+                            p.warning_findings
+                        ));
+                    }
+                    content.push('\n');
+                }
+                if let Some(ref zones) = overview.zone_distribution {
+                    content.push_str("### Zone distribution\n\n");
+                    content.push_str("Nodes grouped by `topology.kubernetes.io/zone`. A single zone, or most pods in one zone, means a zone outage takes the cluster down with it.\n\n");
+                    content.push_str("| Zone | Nodes | Ready | Pods |\n");
+                    content.push_str("|------|-------|-------|------|\n");
+                    for z in zones {
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} |\n",
+                            z.zone, z.node_count, z.ready_count, z.pod_count
+                        ));
+                    }
+                    content.push('\n');
+                }
+                if let Some(ref qos) = overview.qos_distribution {
+                    content.push_str("### QoS distribution\n\n");
+                    content.push_str("Running pods per QoS class. Under node pressure BestEffort pods are evicted first, then Burstable pods above their requests.\n\n");
+                    content.push_str("| Namespace | Guaranteed | Burstable | BestEffort |\n");
+                    content.push_str("|-----------|------------|-----------|------------|\n");
+                    for r in qos {
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} |\n",
+                            r.namespace, r.guaranteed, r.burstable, r.best_effort
+                        ));
+                    }
+                    content.push('\n');
+                }
+                if let Some(ref priorities) = overview.priority_distribution {
+                    content.push_str("### Priority distribution\n\n");
+                    content.push_str("Running pods per PriorityClass, highest priority first. When the cluster is full, the scheduler preempts lower-priority pods to place higher ones.\n\n");
+                    content.push_str("| PriorityClass | Value | Global default | Pods |\n");
+                    content.push_str("|---------------|-------|----------------|------|\n");
+                    for p in priorities {
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} |\n",
+                            p.priority_class,
+                            p.value,
+                            if p.global_default { "yes" } else { "-" },
+                            p.pod_count
+                        ));
+                    }
+                    content.push('\n');
+                }
+                if let Some(ref capacity) = overview.capacity {
+                    let ratio = |used: f64, alloc: f64| {
+                        if alloc > 0.0 {
+                            format!("{:.0}%", used / alloc * 100.0)
+                        } else {
+                            "-".to_string()
+                        }
+                    };
+                    content.push_str("### Capacity forecast\n\n");
+                    content.push_str("Requests and limits of scheduled pods against node allocatable. Requests above 100% of allocatable leave new pods Pending; limits above 100% are overcommitment.\n\n");
+                    content.push_str("| Scope | Nodes | CPU allocatable | CPU requests | CPU limits | Memory allocatable | Memory requests | Memory limits |\n");
+                    content.push_str("|-------|-------|-----------------|--------------|------------|--------------------|-----------------|---------------|\n");
+                    for c in std::iter::once(&capacity.cluster).chain(&capacity.node_pools) {
+                        content.push_str(&format!(
+                            "| {} | {} | {:.1} cores | {:.1} ({}) | {:.1} ({}) | {:.1} Gi | {:.1} ({}) | {:.1} ({}) |\n",
+                            c.scope,
+                            c.node_count,
+                            c.allocatable_cpu_cores,
+                            c.cpu_requests_cores,
+                            ratio(c.cpu_requests_cores, c.allocatable_cpu_cores),
+                            c.cpu_limits_cores,
+                            ratio(c.cpu_limits_cores, c.allocatable_cpu_cores),
+                            c.allocatable_memory_gi,
+                            c.memory_requests_gi,
+                            ratio(c.memory_requests_gi, c.allocatable_memory_gi),
+                            c.memory_limits_gi,
+                            ratio(c.memory_limits_gi, c.allocatable_memory_gi)
+                        ));
+                    }
+                    content.push('\n');
+                    if !capacity.nodes.is_empty() {
+                        content.push_str("#### Per-node commitment\n\n");
+                        content.push_str("| Node | CPU requests | CPU limits | Memory requests | Memory limits |\n");
+                        content.push_str("|------|--------------|------------|-----------------|---------------|\n");
+                        for c in &capacity.nodes {
                             content.push_str(&format!(
-                                "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
-                                r.namespace,
-                                r.pod_name,
-                                r.container_name,
-                                r.cpu_used_m,
-                                r.cpu_request_m,
-                                r.cpu_limit_m,
-                                r.mem_used_mib,
-                                r.mem_request_mib,
-                                r.mem_limit_mib,
-                                note
+                                "| {} | {} | {} | {} | {} |\n",
+                                c.scope,
+                                ratio(c.cpu_requests_cores, c.allocatable_cpu_cores),
+                                ratio(c.cpu_limits_cores, c.allocatable_cpu_cores),
+                                ratio(c.memory_requests_gi, c.allocatable_memory_gi),
+                                ratio(c.memory_limits_gi, c.allocatable_memory_gi)
                             ));
                         }
                         content.push('\n');
                     }
+                    let days = |d: Option<f64>| match d {
+                        Some(d) if d <= 0.0 => "already exceeded".to_string(),
+                        Some(d) => format!("about {:.0} days", d),
+                        None => "not growing".to_string(),
+                    };
+                    match &capacity.trend {
+                        Some(t) => content.push_str(&format!(
+                            "Trend over {} reports ({:.0} days): CPU requests {:+.2} cores/day, memory requests {:+.2} Gi/day. Days until requests exceed allocatable: CPU {}, memory {}.\n\n",
+                            t.data_points,
+                            t.span_days,
+                            t.cpu_requests_cores_per_day,
+                            t.memory_requests_gi_per_day,
+                            days(t.cpu_days_until_full),
+                            days(t.memory_days_until_full)
+                        )),
+                        None => content.push_str("No forecast: run `check --history-dir` with earlier JSON reports of this cluster to estimate the days until requests exceed allocatable.\n\n"),
+                    }
+                }
+                if let Some(ref conds) = overview.node_conditions {
+                    if !conds.is_empty() {
+                        content.push_str("### Node conditions\n\n");
+                        for (os, group) in group_by_os(conds, |r| &r.node_name, overview) {
+                            if let Some(os) = os {
+                                content.push_str(&format!("#### {} nodes\n\n", os));
+                            }
+                            content.push_str(
+                                "| Node | Ready | MemoryPressure | DiskPressure | PIDPressure |\n",
+                            );
+                            content.push_str(
+                                "|------|-------|----------------|--------------|-------------|\n",
+                            );
+                            for r in group {
+                                content.push_str(&format!(
+                                    "| {} | {} | {} | {} | {} |\n",
+                                    r.node_name,
+                                    r.ready,
+                                    r.memory_pressure,
+                                    r.disk_pressure,
+                                    r.pid_pressure
+                                ));
+                            }
+                            content.push('\n');
+                        }
+                    }
+                }
+                // Workload summary
+                if let Some(ref wl) = overview.workload_summary {
+                    content.push_str("### Workload summary\n\n");
+                    content.push_str("| Controller | Total | Ready |\n");
+                    content.push_str("|------------|-------|-------|\n");
+                    content.push_str(&format!(
+                        "| Deployment | {} | {} |\n",
+                        wl.deployments_total, wl.deployments_ready
+                    ));
+                    content.push_str(&format!(
+                        "| StatefulSet | {} | {} |\n",
+                        wl.statefulsets_total, wl.statefulsets_ready
+                    ));
+                    content.push_str(&format!(
+                        "| DaemonSet | {} | {} |\n\n",
+                        wl.daemonsets_total, wl.daemonsets_ready
+                    ));
+                }
+                // Storage summary
+                if let Some(ref st) = overview.storage_summary {
+                    content.push_str("### Storage summary\n\n");
+                    content.push_str("| Metric | Value |\n");
+                    content.push_str("|--------|-------|\n");
+                    content.push_str(&format!("| PV total | {} |\n", st.pv_total));
+                    content.push_str(&format!("| PVC total | {} |\n", st.pvc_total));
+                    content.push_str(&format!("| PVC Bound | {} |\n", st.pvc_bound));
+                    content.push_str(&format!(
+                        "| StorageClass count | {} |\n",
+                        st.storage_class_count
+                    ));
+                    content.push_str(&format!(
+                        "| Default StorageClass | {} |\n\n",
+                        if st.has_default_storage_class {
+                            "Yes"
+                        } else {
+                            "No"
+                        }
+                    ));
+                }
+                // etcd objects per kind (Control Plane inspection)
+                if let Some(counts) = report
+                    .inspections
+                    .iter()
+                    .find_map(|i| i.etcd_objects.as_ref())
+                {
+                    content.push_str(&etcd_objects_table(counts));
+                }
+                // Container resource usage: top 20 high usage (usage/limit >= 80%); shown only when metrics available
+                if overview.metrics_available == Some(true) {
+                    if let Some(ref rows) = overview.node_usage {
+                        if !rows.is_empty() {
+                            let opt = |v: Option<f64>, suffix: &str| {
+                                v.map(|x| format!("{:.1}{}", x, suffix))
+                                    .unwrap_or_else(|| "-".to_string())
+                            };
+                            content.push_str(
+                                "### Node resource usage
+
+",
+                            );
+                            content.push_str("CPU and memory from **metrics-server**; disk is the kubelet root filesystem (nodefs) from the kubelet **/stats/summary** API (`-` when node proxy access is not permitted).
+
+");
+                            for (os, group) in group_by_os(rows, |r| &r.node_name, overview) {
+                                if let Some(os) = os {
+                                    content.push_str(&format!("#### {} nodes\n\n", os));
+                                }
+                                content.push_str("| Node | CPU used | CPU % | Mem used | Mem % | Disk allocatable (Gi) | Disk used (Gi) | Disk % |
+");
+                                content.push_str("|------|----------|-------|----------|-------|-----------------------|----------------|--------|
+");
+                                for r in group {
+                                    content.push_str(&format!(
+                                        "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                                        r.node_name,
+                                        r.cpu_usage,
+                                        opt(r.cpu_pct, "%"),
+                                        r.memory_usage,
+                                        opt(r.memory_pct, "%"),
+                                        opt(r.disk_allocatable_gi, ""),
+                                        opt(r.disk_usage_gi, ""),
+                                        opt(r.disk_pct, "%")
+                                    ));
+                                }
+                                content.push('\n');
+                            }
+                        }
+                    }
                 }
+            } else {
+                content.push_str("Cluster overview is not available (ensure cluster is reachable and the tool has been rebuilt).\n\n");
             }
-        } else {
-            content.push_str("Cluster overview is not available (ensure cluster is reachable and the tool has been rebuilt).\n\n");
         }
 
-        // Node Inspection (from DaemonSet): Summary + category tables, or placeholder when no data
-        match report.node_inspection_results.as_deref() {
-            Some(nodes) if !nodes.is_empty() => {
-                content.push_str(&Self::format_node_inspection_section(report));
+        if self.shows(ReportSection::Containers) {
+            if let Some(rows) = report
+                .cluster_overview
+                .as_ref()
+                .and_then(|o| o.container_usage_notable.as_ref())
+            {
+                if !rows.is_empty() {
+                    content.push_str("### Container resource usage (top 20 high usage)\n\n");
+                    content.push_str("Top 20 containers by usage vs limit (CPU or memory ≥ 80% of limit). Data from **metrics-server** (Pod metrics API) and **Pod spec** (limits). This section is **omitted when metrics-server is unavailable**.\n\n");
+                    content.push_str("| Namespace | Pod | Container | CPU used (m) | CPU request (m) | CPU limit (m) | Mem used (Mi) | Mem request (Mi) | Mem limit (Mi) | Note |\n");
+                    content.push_str("|-----------|-----|-----------|--------------|-----------------|---------------|---------------|------------------|----------------|------|\n");
+                    for r in rows {
+                        let note = match r.notable_reason.as_str() {
+                            "high_usage" => "High usage",
+                            "low_usage" => "Low usage",
+                            "no_request_no_limit" => "No request",
+                            _ => r.notable_reason.as_str(),
+                        };
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                            r.namespace,
+                            r.pod_name,
+                            r.container_name,
+                            r.cpu_used_m,
+                            r.cpu_request_m,
+                            r.cpu_limit_m,
+                            r.mem_used_mib,
+                            r.mem_request_mib,
+                            r.mem_limit_mib,
+                            note
+                        ));
+                    }
+                    content.push('\n');
+                }
             }
-            _ => {
-                content.push_str("## Node Inspection\n\n");
-                content.push_str("No data (kubeowler-node-inspector DaemonSet not deployed or log fetch failed / no pods ready).\n\n");
+        }
+
+        if self.shows(ReportSection::Nodes) {
+            // Node Inspection (from DaemonSet): Summary + category tables, or placeholder when no data
+            match report.node_inspection_results.as_deref() {
+                Some(nodes) if !nodes.is_empty() => {
+                    content.push_str(&Self::format_node_inspection_section(report));
+                }
+                _ => {
+                    content.push_str("## Node Inspection\n\n");
+                    content.push_str("No data (kubeowler-node-inspector DaemonSet not deployed or log fetch failed / no pods ready).\n\n");
+                }
             }
         }
+        if self.shows(ReportSection::Events) {
+            // Warning events aggregated by reason and kind (EVT-001 marks noisy reasons)
+            if let Some(rows) = report.event_analytics.as_deref().filter(|r| !r.is_empty()) {
+                const MAX_EVENT_REASON_ROWS: usize = 20;
+                content.push_str("## Warning event analytics\n\n");
+                content.push_str(
+                    "| Reason | Kind | Events | Rate (/h) | Objects | Namespaces | Noisy |\n",
+                );
+                content.push_str(
+                    "|--------|------|--------|-----------|---------|------------|-------|\n",
+                );
+                for r in rows.iter().take(MAX_EVENT_REASON_ROWS) {
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {:.0} | {} | {} | {} |\n",
+                        r.reason,
+                        r.kind,
+                        r.occurrences,
+                        r.rate_per_hour,
+                        r.objects,
+                        r.namespaces,
+                        if r.noisy { "Yes" } else { "No" }
+                    ));
+                }
+                if rows.len() > MAX_EVENT_REASON_ROWS {
+                    content.push_str(&format!(
+                        "\n*{} more reason(s) omitted; see JSON output for the full list.*\n",
+                        rows.len() - MAX_EVENT_REASON_ROWS
+                    ));
+                }
+                content.push('\n');
+            }
 
-        // Recent cluster events (Warning / Error only)
-        if let Some(ref events) = report.recent_events {
-            if !events.is_empty() {
-                content.push_str("## Recent cluster events (Warning / Error)\n\n");
-                content.push_str("| Namespace | Object | Level | Reason | Message | Last seen |\n");
-                content.push_str("|-----------|--------|-------|--------|---------|----------|\n");
-                for e in events {
-                    let level = match e.event_type.as_str() {
-                        "Error" => "Critical",
-                        "Warning" => "Warning",
-                        "Normal" => "Info",
-                        _ => e.event_type.as_str(),
-                    };
+            // Recent cluster events (Warning / Error only)
+            if let Some(ref events) = report.recent_events {
+                if !events.is_empty() {
+                    content.push_str("## Recent cluster events (Warning / Error)\n\n");
+                    content.push_str(
+                        "| Namespace | Object | Level | Reason | Message | Last seen |\n",
+                    );
+                    content
+                        .push_str("|-----------|--------|-------|--------|---------|----------|\n");
+                    for e in events {
+                        let level = match e.event_type.as_str() {
+                            "Error" => "Critical",
+                            "Warning" => "Warning",
+                            "Normal" => "Info",
+                            _ => e.event_type.as_str(),
+                        };
+                        content.push_str(&format!(
+                            "| {} | {} | {} | {} | {} | {} |\n",
+                            e.namespace,
+                            e.object_ref,
+                            level,
+                            e.reason,
+                            truncate_string(&e.message, 60),
+                            e.last_seen
+                        ));
+                    }
+                    content.push('\n');
+                }
+            }
+        }
+        if self.shows(ReportSection::Namespaces) {
+            // Namespace ranking: issues attributed to their namespace, worst score first
+            let ranking = namespace_scores(&report.inspections);
+            if !ranking.is_empty() {
+                const MAX_NAMESPACE_ROWS: usize = 20;
+                content.push_str("## Namespace ranking\n\n");
+                content.push_str(
+                    "| Rank | Namespace | Score | Critical | Warning | Info | Top issue codes |\n",
+                );
+                content.push_str(
+                    "|------|-----------|-------|----------|---------|------|-----------------|\n",
+                );
+                for (i, ns) in ranking.iter().take(MAX_NAMESPACE_ROWS).enumerate() {
                     content.push_str(&format!(
-                        "| {} | {} | {} | {} | {} | {} |\n",
-                        e.namespace,
-                        e.object_ref,
-                        level,
-                        e.reason,
-                        truncate_string(&e.message, 60),
-                        e.last_seen
+                        "| {} | {} | {:.1} | {} | {} | {} | {} |\n",
+                        i + 1,
+                        ns.namespace,
+                        ns.score,
+                        ns.critical,
+                        ns.warning,
+                        ns.info,
+                        if ns.top_rules.is_empty() {
+                            "-".to_string()
+                        } else {
+                            ns.top_rules.join(", ")
+                        }
+                    ));
+                }
+                if ranking.len() > MAX_NAMESPACE_ROWS {
+                    content.push_str(&format!(
+                        "\n*{} more namespace(s) omitted; see JSON output for the full ranking.*\n",
+                        ranking.len() - MAX_NAMESPACE_ROWS
                     ));
                 }
                 content.push('\n');
             }
         }
-
         // Detailed results grouped by Kubernetes resource object
-        content.push_str("## 📋 Detailed Results\n\n");
+        if self.shows(ReportSection::Checks) || self.shows(ReportSection::Issues) {
+            content.push_str("## 📋 Detailed Results\n\n");
+        }
 
-        // Check Results: first column = cluster resource object; filter by check level (default: exclude Pass)
-        content.push_str("### Check Results\n\n");
-        content.push_str("| Resource | Check Item | Status | Score | Details |\n");
-        content.push_str("|----------|------------|--------|-------|----------|\n");
-        const DETAILS_MAX_LEN: usize = 60;
-        for inspection in &report.inspections {
-            let resource = inspection_type_to_resource(&inspection.inspection_type);
-            for check in &inspection.checks {
-                let include = match &check_filter {
-                    CheckLevelFilter::All => true,
-                    CheckLevelFilter::Only(list) => list.contains(&check.status),
-                };
-                if !include {
-                    continue;
+        if self.shows(ReportSection::Checks) {
+            // Check Results: first column = cluster resource object; filter by check level (default: exclude Pass)
+            content.push_str("### Check Results\n\n");
+            content.push_str("| Resource | Check Item | Status | Score | Details |\n");
+            content.push_str("|----------|------------|--------|-------|----------|\n");
+            const DETAILS_MAX_LEN: usize = 60;
+            for inspection in &report.inspections {
+                let resource = inspection_type_to_resource(&inspection.inspection_type);
+                for check in &inspection.checks {
+                    let include = match &check_filter {
+                        CheckLevelFilter::All => true,
+                        CheckLevelFilter::Only(list) => list.contains(&check.status),
+                    };
+                    if !include {
+                        continue;
+                    }
+                    let status_text = match check.status {
+                        CheckStatus::Pass => "✅ Pass",
+                        CheckStatus::Warning => "⚠️ Warning",
+                        CheckStatus::Critical => "❌ Critical",
+                        CheckStatus::Error => "💥 Error",
+                    };
+                    let details_str = check.details.as_deref().unwrap_or("-");
+                    let details_short = truncate_string(details_str, DETAILS_MAX_LEN);
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {:.1}/{:.1} | {} |\n",
+                        resource,
+                        check.name,
+                        status_text,
+                        check.score,
+                        check.max_score,
+                        details_short
+                    ));
                 }
-                let status_text = match check.status {
-                    CheckStatus::Pass => "✅ Pass",
-                    CheckStatus::Warning => "⚠️ Warning",
-                    CheckStatus::Critical => "❌ Critical",
-                    CheckStatus::Error => "💥 Error",
-                };
-                let details_str = check.details.as_deref().unwrap_or("-");
-                let details_short = truncate_string(details_str, DETAILS_MAX_LEN);
-                content.push_str(&format!(
-                    "| {} | {} | {} | {:.1}/{:.1} | {} |\n",
-                    resource, check.name, status_text, check.score, check.max_score, details_short
-                ));
             }
+            content.push('\n');
         }
-        content.push('\n');
-
-        // Namespace summary table (from Namespace inspection)
-        if let Some(rows) = report
-            .inspections
-            .iter()
-            .find_map(|i| i.namespace_summary_rows.as_ref().filter(|v| !v.is_empty()))
-        {
-            content.push_str("### Namespace summary\n\n");
-            content.push_str(
+        if self.shows(ReportSection::Namespaces) {
+            // Namespace summary table (from Namespace inspection)
+            if let Some(rows) = report
+                .inspections
+                .iter()
+                .find_map(|i| i.namespace_summary_rows.as_ref().filter(|v| !v.is_empty()))
+            {
+                content.push_str("### Namespace summary\n\n");
+                content.push_str(
                 "| Namespace | Pods | Deployments | NetworkPolicy | ResourceQuota | LimitRange |\n",
             );
-            content.push_str(
+                content.push_str(
                 "|-----------|------|-------------|---------------|---------------|------------|\n",
             );
-            for r in rows {
-                content.push_str(&format!(
-                    "| {} | {} | {} | {} | {} | {} |\n",
-                    r.name,
-                    r.pod_count,
-                    r.deployment_count,
-                    if r.has_network_policy { "Yes" } else { "No" },
-                    if r.has_resource_quota { "Yes" } else { "No" },
-                    if r.has_limit_range { "Yes" } else { "No" },
-                ));
+                for r in rows {
+                    content.push_str(&format!(
+                        "| {} | {} | {} | {} | {} | {} |\n",
+                        r.name,
+                        r.pod_count,
+                        r.deployment_count,
+                        if r.has_network_policy { "Yes" } else { "No" },
+                        if r.has_resource_quota { "Yes" } else { "No" },
+                        if r.has_limit_range { "Yes" } else { "No" },
+                    ));
+                }
+                content.push('\n');
             }
-            content.push('\n');
         }
-
-        // Per-resource sections: only emit if at least one issue or one detail block (Pod container state table omitted)
-        let by_resource = group_issues_by_resource(report);
-        let cert_expiries = report.inspections.iter().find_map(|i| {
-            i.certificate_expiries
-                .as_ref()
-                .filter(|v| !v.is_empty())
-                .map(|v| v.as_slice())
-        });
-
-        for &resource in REPORT_RESOURCE_ORDER {
-            let issues = by_resource
-                .get(resource)
-                .map(|v| v.as_slice())
-                .unwrap_or(&[]);
-            let has_cert_expiries = resource == "Certificate" && cert_expiries.is_some();
-            if issues.is_empty() && !has_cert_expiries {
-                continue;
-            }
-            let slug = slugify(resource);
-            content.push_str(&format!("<a id=\"{}\"></a>\n\n", slug));
-            content.push_str(&format!("### {}\n\n", resource));
-            if has_cert_expiries {
-                if let Some(expiries) = cert_expiries {
-                    content.push_str("#### TLS Certificate Expiry\n\n");
-                    content.push_str("| Secret (namespace/name) | Expired | Expiry (UTC) | Days to Expiry | Level | Issue Code |\n");
-                    content.push_str("|--------------------------|---------|--------------|----------------|-------|------------|\n");
-                    for row in expiries {
-                        let expired = if row.days_until_expiry < 0 {
-                            "Yes"
-                        } else {
-                            "No"
-                        };
-                        let (level, code_link) = if row.days_until_expiry < 0 {
-                            (
-                                "Critical",
-                                format!("[CERT-003]({})", issue_codes::doc_path("CERT-003")),
-                            )
-                        } else if row.days_until_expiry <= 30 {
-                            (
-                                "Warning",
-                                format!("[CERT-002]({})", issue_codes::doc_path("CERT-002")),
-                            )
-                        } else {
-                            (
-                                "Info",
-                                format!("[CERT-002]({})", issue_codes::doc_path("CERT-002")),
-                            )
-                        };
-                        let secret_cell = format!("{}/{}", row.secret_namespace, row.secret_name);
-                        content.push_str(&format!(
-                            "| {} | {} | {} | {} | {} | {} |\n",
-                            secret_cell,
-                            expired,
-                            row.expiry_utc,
-                            row.days_until_expiry,
-                            level,
-                            code_link
-                        ));
-                    }
-                    content.push('\n');
+        if self.shows(ReportSection::Issues) {
+            // Per-resource sections: only emit if at least one issue or one detail block (Pod container state table omitted)
+            let by_resource = group_issues_by_resource(report);
+            // Owning team per affected resource; adds a Team column when ownership was resolved
+            let resource_teams: HashMap<&str, &str> = report
+                .inspections
+                .iter()
+                .flat_map(|i| i.summary.issues.iter())
+                .filter_map(|i| Some((i.resource.as_deref()?, i.team.as_deref()?)))
+                .collect();
+            let cert_expiries = report.inspections.iter().find_map(|i| {
+                i.certificate_expiries
+                    .as_ref()
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.as_slice())
+            });
+
+            for &resource in REPORT_RESOURCE_ORDER {
+                let issues = by_resource
+                    .get(resource)
+                    .map(|v| v.as_slice())
+                    .unwrap_or(&[]);
+                let has_cert_expiries = resource == "Certificate" && cert_expiries.is_some();
+                if issues.is_empty() && !has_cert_expiries {
+                    continue;
                 }
-            }
-            if !issues.is_empty() {
-                content.push_str("| Resource | Level | Issue Code | Short Title |\n");
-                content.push_str("|----------|-------|------------|-------------|\n");
-                let grouped = Self::group_issues_by_severity_and_type(issues);
-                let severity_to_level = |s: &IssueSeverity| -> &'static str {
-                    match s {
-                        IssueSeverity::Critical => "Critical",
-                        IssueSeverity::Warning => "Warning",
-                        IssueSeverity::Info => "Info",
+                let slug = slugify(resource);
+                content.push_str(&format!("<a id=\"{}\"></a>\n\n", slug));
+                content.push_str(&format!("### {}\n\n", resource));
+                if has_cert_expiries {
+                    if let Some(expiries) = cert_expiries {
+                        content.push_str("#### TLS Certificate Expiry\n\n");
+                        content.push_str("| Secret (namespace/name) | Expired | Expiry (UTC) | Days to Expiry | Level | Issue Code |\n");
+                        content.push_str("|--------------------------|---------|--------------|----------------|-------|------------|\n");
+                        for row in expiries {
+                            let expired = if row.days_until_expiry < 0 {
+                                "Yes"
+                            } else {
+                                "No"
+                            };
+                            let (level, code_link) = if row.days_until_expiry < 0 {
+                                (
+                                    "Critical",
+                                    format!("[CERT-003]({})", issue_codes::doc_path("CERT-003")),
+                                )
+                            } else if row.days_until_expiry <= 30 {
+                                (
+                                    "Warning",
+                                    format!("[CERT-002]({})", issue_codes::doc_path("CERT-002")),
+                                )
+                            } else {
+                                (
+                                    "Info",
+                                    format!("[CERT-002]({})", issue_codes::doc_path("CERT-002")),
+                                )
+                            };
+                            let secret_cell =
+                                format!("{}/{}", row.secret_namespace, row.secret_name);
+                            content.push_str(&format!(
+                                "| {} | {} | {} | {} | {} | {} |\n",
+                                secret_cell,
+                                expired,
+                                row.expiry_utc,
+                                row.days_until_expiry,
+                                level,
+                                code_link
+                            ));
+                        }
+                        content.push('\n');
                     }
-                };
-                for sev in &[
-                    IssueSeverity::Critical,
-                    IssueSeverity::Warning,
-                    IssueSeverity::Info,
-                ] {
-                    // Default: only Warning and Critical (exclude Info). With --check-level all, show Info too.
-                    if matches!(sev, IssueSeverity::Info)
-                        && !matches!(&check_filter, CheckLevelFilter::All)
-                    {
-                        continue;
+                }
+                if !issues.is_empty() {
+                    if resource_teams.is_empty() {
+                        content.push_str("| Resource | Level | Issue Code | Short Title |\n");
+                        content.push_str("|----------|-------|------------|-------------|\n");
+                    } else {
+                        content
+                            .push_str("| Resource | Level | Issue Code | Short Title | Team |\n");
+                        content
+                            .push_str("|----------|-------|------------|-------------|------|\n");
                     }
-                    let level = severity_to_level(sev);
-                    if let Some(groups) = grouped.get(sev) {
-                        for (rule_id, title, _rec, resources) in groups {
-                            let code_link = rule_id
-                                .as_ref()
-                                .map(|c| format!("[{}]({})", c, issue_codes::doc_path(c)))
-                                .unwrap_or_else(|| "-".to_string());
-                            if resources.is_empty() {
-                                content.push_str(&format!(
-                                    "| {} | {} | {} | {} |\n",
-                                    resource, level, code_link, title
-                                ));
-                            } else {
-                                for r in resources {
+                    let team_cell = |r: &str| -> String {
+                        if resource_teams.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" {} |", resource_teams.get(r).copied().unwrap_or("-"))
+                        }
+                    };
+                    let grouped = Self::group_issues_by_severity_and_type(issues);
+                    let severity_to_level = |s: &IssueSeverity| -> &'static str {
+                        match s {
+                            IssueSeverity::Critical => "Critical",
+                            IssueSeverity::Warning => "Warning",
+                            IssueSeverity::Info => "Info",
+                        }
+                    };
+                    for sev in &[
+                        IssueSeverity::Critical,
+                        IssueSeverity::Warning,
+                        IssueSeverity::Info,
+                    ] {
+                        // Default: only Warning and Critical (exclude Info). With --check-level all, show Info too.
+                        if matches!(sev, IssueSeverity::Info)
+                            && !matches!(&check_filter, CheckLevelFilter::All)
+                        {
+                            continue;
+                        }
+                        let level = severity_to_level(sev);
+                        if let Some(groups) = grouped.get(sev) {
+                            for (rule_id, title, _rec, resources) in groups {
+                                let code_link = rule_id
+                                    .as_ref()
+                                    .map(|c| format!("[{}]({})", c, issue_codes::doc_path(c)))
+                                    .unwrap_or_else(|| "-".to_string());
+                                if resources.is_empty() {
                                     content.push_str(&format!(
-                                        "| `{}` | {} | {} | {} |\n",
-                                        r, level, code_link, title
+                                        "| {} | {} | {} | {} |{}\n",
+                                        resource,
+                                        level,
+                                        code_link,
+                                        title,
+                                        team_cell("")
                                     ));
+                                } else {
+                                    let (listed, more) = self.listed_resources(resources);
+                                    for r in listed {
+                                        content.push_str(&format!(
+                                            "| `{}` | {} | {} | {} |{}\n",
+                                            r,
+                                            level,
+                                            code_link,
+                                            title,
+                                            team_cell(r)
+                                        ));
+                                    }
+                                    if more > 0 {
+                                        content.push_str(&format!(
+                                            "| {} | {} | {} | {} |{}\n",
+                                            self.overflow_cell(more),
+                                            level,
+                                            code_link,
+                                            title,
+                                            team_cell("")
+                                        ));
+                                    }
                                 }
                             }
                         }
                     }
+                    content.push('\n');
+                    let commands = Self::remediation_commands(issues, &check_filter);
+                    if !commands.is_empty() {
+                        content.push_str("<details>\n");
+                        content.push_str(&format!(
+                            "<summary>Remediation commands ({})</summary>\n\n```bash\n",
+                            commands.len()
+                        ));
+                        for cmd in commands {
+                            content.push_str(cmd);
+                            content.push('\n');
+                        }
+                        content.push_str("```\n\n</details>\n\n");
+                    }
                 }
-                content.push('\n');
+                content.push_str("---\n\n");
             }
-            content.push_str("---\n\n");
+        }
+        if self.shows(ReportSection::Scoring) {
+            content.push_str(&score_methodology_section(report));
+        }
+        if self.shows(ReportSection::Baseline) {
+            // Appendix: findings accepted by --baseline (not scored, not in the tables above)
+            if let Some(info) = &report.metadata.baseline {
+                content.push_str("## Appendix: Accepted findings (baseline)\n\n");
+                content.push_str(&format!(
+                "Baseline `{}` ({} entries): {} finding(s) accepted, {} baseline finding(s) no longer present. \
+                 Accepted findings are excluded from scores.\n\n",
+                info.file, info.entries, info.accepted, info.resolved
+            ));
+                let accepted = report.accepted_issues.as_deref().unwrap_or_default();
+                if !accepted.is_empty() {
+                    // Not "Level"/"Short Title": md_to_csv would read these rows as open issues.
+                    content.push_str("| Resource | Severity | Issue Code | Title |\n");
+                    content.push_str("|----------|----------|------------|-------|\n");
+                    for issue in accepted {
+                        let code_link = issue
+                            .rule_id
+                            .as_ref()
+                            .map(|c| format!("[{}]({})", c, issue_codes::doc_path(c)))
+                            .unwrap_or_else(|| "-".to_string());
+                        let title = issue
+                            .rule_id
+                            .as_deref()
+                            .and_then(issue_codes::short_title)
+                            .map(String::from)
+                            .unwrap_or_else(|| issue.description.clone());
+                        content.push_str(&format!(
+                            "| `{}` | {:?} | {} | {} |\n",
+                            issue.resource.as_deref().unwrap_or("-"),
+                            issue.severity,
+                            code_link,
+                            title
+                        ));
+                    }
+                    content.push('\n');
+                }
+            }
+        }
+        if let Some(diagnostics) = report
+            .metadata
+            .run_diagnostics
+            .as_ref()
+            .filter(|_| self.shows(ReportSection::Diagnostics))
+        {
+            content.push_str(&run_diagnostics_section(diagnostics));
         }
 
         // Footer
@@ -1601,6 +2585,10 @@ impl ReportGenerator {
         }
         content.push('\n');
 
+        if self.shows(ReportSection::Risks) {
+            content.push_str(&top_risk_resources_section(report));
+        }
+
         // Critical: one table
         let critical_flat: Vec<_> = critical_issues.iter().map(|(_, i)| (*i).clone()).collect();
         let critical_grouped = Self::group_issues_by_severity_and_type(&critical_flat);
@@ -1618,9 +2606,18 @@ impl ReportGenerator {
                 if resources.is_empty() {
                     content.push_str(&format!("| - | {} | {} |\n", code_link, title));
                 } else {
-                    for r in resources {
+                    let (listed, more) = self.listed_resources(resources);
+                    for r in listed {
                         content.push_str(&format!("| `{}` | {} | {} |\n", r, code_link, title));
                     }
+                    if more > 0 {
+                        content.push_str(&format!(
+                            "| {} | {} | {} |\n",
+                            self.overflow_cell(more),
+                            code_link,
+                            title
+                        ));
+                    }
                 }
             }
             content.push('\n');
@@ -1679,13 +2676,13 @@ impl ReportGenerator {
             .iter()
             .map(|(cat, rec_map)| (cat.clone(), rec_map.values().sum()))
             .collect();
-        category_totals.sort_by(|a, b| b.1.cmp(&a.1));
+        category_totals.sort_by_key(|b| std::cmp::Reverse(b.1));
 
         for (category, _total) in category_totals {
             if let Some(rec_map) = category_rec_counts.get(&category) {
                 let mut rec_list: Vec<(String, usize)> =
                     rec_map.iter().map(|(r, c)| (r.clone(), *c)).collect();
-                rec_list.sort_by(|a, b| b.1.cmp(&a.1));
+                rec_list.sort_by_key(|b| std::cmp::Reverse(b.1));
                 content.push_str(&format!("### {}\n\n", category));
                 for (recommendation, count) in rec_list {
                     content.push_str(&format!("- {} ({} issues)\n", recommendation, count));
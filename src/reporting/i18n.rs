@@ -0,0 +1,447 @@
+//! Report language (`--lang`). The Markdown/HTML report is generated in English and then localized
+//! line by line: headings, table headers and label cells, bold labels, severity words and issue-code
+//! short titles are looked up in the catalogs below. Free text from inspectors (descriptions,
+//! recommendations, check details) stays in English. CSV, JSON and XLSX are always English so that
+//! their column names and values stay stable for tooling.
+
+use crate::cli::Lang;
+use crate::inspections::issue_codes;
+
+/// English text → (zh-CN, ja). Matched against whole headings, whole table cells, `**bold**`
+/// labels and whole paragraph lines.
+#[rustfmt::skip]
+const UI: &[(&str, &str, &str)] = &[
+    // Headings
+    ("Cluster Inspection – Exception Summary", "集群巡检 – 异常汇总", "クラスタ点検 – 異常サマリー"),
+    ("Appendix: Accepted findings (baseline)", "附录：已接受的问题（基线）", "付録：受け入れ済みの指摘（ベースライン）"),
+    ("Critical Issues", "严重问题", "重大な問題"),
+    ("Data completeness", "数据完整性", "データの完全性"),
+    ("Run diagnostics", "运行诊断", "実行診断"),
+    ("Issue Statistics", "问题统计", "問題の統計"),
+    ("Namespace ranking", "命名空间排名", "名前空間ランキング"),
+    ("Node Inspection", "节点巡检", "ノード点検"),
+    ("Node component and service status", "节点组件与服务状态", "ノードのコンポーネントとサービスの状態"),
+    ("Other Issues", "其他问题", "その他の問題"),
+    ("Recent cluster events (Warning / Error)", "近期集群事件（Warning / Error）", "最近のクラスタイベント（Warning / Error）"),
+    ("Warning event analytics", "Warning 事件分析", "Warning イベント分析"),
+    ("🎯 Recommendations by Category", "🎯 分类建议", "🎯 カテゴリ別の推奨事項"),
+    ("📋 Detailed Results", "📋 详细结果", "📋 詳細結果"),
+    ("🖥️ Cluster Overview", "🖥️ 集群概览", "🖥️ クラスタ概要"),
+    ("📈 Cluster Statistics", "📈 集群统计", "📈 クラスタ統計"),
+    ("Check Results", "检查结果", "チェック結果"),
+    ("Container resource usage (top 20 high usage)", "容器资源使用（使用率最高的 20 个）", "コンテナのリソース使用量（使用率上位 20）"),
+    ("Namespace summary", "命名空间汇总", "名前空間サマリー"),
+    ("Node Certificate Status", "节点证书状态", "ノード証明書の状態"),
+    ("Node General Information", "节点基本信息", "ノードの基本情報"),
+    ("Node conditions", "节点状况", "ノードの状態"),
+    ("Node container state counts", "节点容器状态统计", "ノードのコンテナ状態数"),
+    ("Node disk usage", "节点磁盘使用", "ノードのディスク使用量"),
+    ("Node kernel parameters", "节点内核参数", "ノードのカーネルパラメータ"),
+    ("Node network and stability", "节点网络与稳定性", "ノードのネットワークと安定性"),
+    ("Node pool findings", "节点池问题", "ノードプールの指摘"),
+    ("Node pools", "节点池", "ノードプール"),
+    ("Node resource usage", "节点资源使用", "ノードのリソース使用量"),
+    ("Node resources", "节点资源", "ノードのリソース"),
+    ("Node security and kernel modules", "节点安全与内核模块", "ノードのセキュリティとカーネルモジュール"),
+    ("Storage summary", "存储汇总", "ストレージサマリー"),
+    ("Workload summary", "工作负载汇总", "ワークロードサマリー"),
+    ("Zone distribution", "可用区分布", "ゾーン分布"),
+    ("Capacity forecast", "容量预测", "キャパシティ予測"),
+    ("Per-node commitment", "各节点资源承诺", "ノードごとのコミットメント"),
+    ("QoS distribution", "QoS 分布", "QoS 分布"),
+    ("Priority distribution", "优先级分布", "優先度の分布"),
+    ("TLS Certificate Expiry", "TLS 证书到期", "TLS 証明書の有効期限"),
+    // Table headers and label cells
+    ("Check Item", "检查项", "チェック項目"),
+    ("Status", "状态", "状態"),
+    ("Score", "得分", "スコア"),
+    ("Details", "详情", "詳細"),
+    ("Metric", "指标", "指標"),
+    ("Value", "值", "値"),
+    ("Resource", "资源", "リソース"),
+    ("Level", "级别", "レベル"),
+    ("Severity", "严重程度", "重大度"),
+    ("Issue Code", "问题代码", "問題コード"),
+    ("Short Title", "简述", "概要"),
+    ("Title", "标题", "タイトル"),
+    ("Team", "团队", "チーム"),
+    ("Code", "代码", "コード"),
+    ("Category", "类别", "カテゴリ"),
+    ("Count", "数量", "件数"),
+    ("Ratio", "占比", "割合"),
+    ("Sample Resource", "示例资源", "リソース例"),
+    ("Recommendation", "建议", "推奨事項"),
+    ("Data source", "数据源", "データソース"),
+    ("Impact", "影响", "影響"),
+    ("Step", "步骤", "ステップ"),
+    ("Duration", "耗时", "所要時間"),
+    ("API requests", "API 请求数", "API リクエスト数"),
+    ("API errors", "API 错误数", "API エラー数"),
+    ("Objects listed", "列出对象数", "取得オブジェクト数"),
+    ("Optional data source", "可选数据源", "任意のデータソース"),
+    ("Used", "已使用", "使用"),
+    ("Yes", "是", "はい"),
+    ("No", "否", "いいえ"),
+    ("Rank", "排名", "順位"),
+    ("Namespace", "命名空间", "名前空間"),
+    ("Top issue codes", "主要问题代码", "主な問題コード"),
+    ("Cluster Version", "集群版本", "クラスタバージョン"),
+    ("Managed Provider", "托管服务商", "マネージドプロバイダー"),
+    ("Oldest Node Image", "最旧节点镜像", "最も古いノードイメージ"),
+    ("Spot / On-demand Nodes", "Spot / 按需节点", "スポット / オンデマンドノード"),
+    ("Cluster Age (days)", "集群运行天数", "クラスタ稼働日数"),
+    ("Container Runtime", "容器运行时", "コンテナランタイム"),
+    ("Default StorageClass", "默认 StorageClass", "デフォルト StorageClass"),
+    ("Distinct Resource Categories", "资源类别数", "リソースカテゴリ数"),
+    ("Modules Checked", "已检查模块", "チェック済みモジュール"),
+    ("Namespace Count", "命名空间数", "名前空間数"),
+    ("Node Count", "节点数", "ノード数"),
+    ("Ready Nodes", "就绪节点", "Ready ノード"),
+    ("Pod Count", "Pod 数", "Pod 数"),
+    ("PV total", "PV 总数", "PV 合計"),
+    ("PVC total", "PVC 总数", "PVC 合計"),
+    ("PVC Bound", "已绑定 PVC", "バインド済み PVC"),
+    ("StorageClass count", "StorageClass 数", "StorageClass 数"),
+    ("Total Checks", "检查总数", "チェック総数"),
+    ("Total Issues", "问题总数", "問題総数"),
+    ("Overall Health", "整体健康度", "総合ヘルス"),
+    ("Controller", "控制器", "コントローラ"),
+    ("Total", "总数", "合計"),
+    ("Ready", "就绪", "Ready"),
+    ("Node", "节点", "ノード"),
+    ("Pool", "节点池", "プール"),
+    ("Zone", "可用区", "ゾーン"),
+    ("Nodes", "节点", "ノード"),
+    ("Pods", "Pod", "Pod"),
+    ("Scope", "范围", "範囲"),
+    ("CPU allocatable", "CPU 可分配", "CPU 割り当て可能量"),
+    ("CPU requests", "CPU requests", "CPU requests"),
+    ("CPU limits", "CPU limits", "CPU limits"),
+    ("Memory allocatable", "内存可分配", "メモリ割り当て可能量"),
+    ("Memory requests", "内存 requests", "メモリ requests"),
+    ("Memory limits", "内存 limits", "メモリ limits"),
+    ("Global default", "全局默认", "グローバルデフォルト"),
+    ("Object", "对象", "オブジェクト"),
+    ("Reason", "原因", "理由"),
+    ("Message", "消息", "メッセージ"),
+    ("Last seen", "最近发生", "最終発生"),
+    ("Events", "事件数", "イベント数"),
+    ("Objects", "对象数", "オブジェクト数"),
+    ("Namespaces", "命名空间", "名前空間"),
+    ("Noisy", "频繁", "多発"),
+    ("Kind", "类型", "種類"),
+    ("Expired", "已过期", "期限切れ"),
+    ("Days to Expiry", "剩余天数", "残り日数"),
+    ("Expiry (UTC)", "到期时间（UTC）", "有効期限（UTC）"),
+    ("Secret (namespace/name)", "Secret（命名空间/名称）", "Secret（名前空間/名前）"),
+    ("OS Version", "操作系统版本", "OS バージョン"),
+    ("IP Address", "IP 地址", "IP アドレス"),
+    ("Kernel Version", "内核版本", "カーネルバージョン"),
+    ("Uptime", "运行时间", "稼働時間"),
+    ("Collection time", "采集时间", "収集時刻"),
+    ("Mount Point", "挂载点", "マウントポイント"),
+    ("Device", "设备", "デバイス"),
+    ("Path", "路径", "パス"),
+    // Levels and bold labels
+    ("Critical", "严重", "重大"),
+    ("Warning", "警告", "警告"),
+    ("Info", "提示", "情報"),
+    ("Pass", "通过", "合格"),
+    ("Error", "错误", "エラー"),
+    ("Cluster", "集群", "クラスタ"),
+    ("Generated At", "生成时间", "生成日時"),
+    ("Report ID", "报告 ID", "レポート ID"),
+    ("Scoring Profile", "评分配置", "スコアリングプロファイル"),
+    ("Run duration", "运行耗时", "実行時間"),
+    ("Slowest step", "最慢步骤", "最も遅いステップ"),
+    ("Best Module", "最佳模块", "最良モジュール"),
+    ("Worst Module", "最差模块", "最悪モジュール"),
+    ("Check Items", "检查项", "チェック項目"),
+    // Paragraph lines
+    ("All data sources were collected.", "所有数据源均已采集。", "すべてのデータソースを収集しました。"),
+    ("Per-node checks from kubeowler-node-inspector DaemonSet.", "来自 kubeowler-node-inspector DaemonSet 的节点级检查。", "kubeowler-node-inspector DaemonSet によるノードごとのチェック。"),
+    ("*Report generated by [kubeowler](https://github.com/Ghostwritten/kubeowler).*", "*本报告由 [kubeowler](https://github.com/Ghostwritten/kubeowler) 生成。*", "*このレポートは [kubeowler](https://github.com/Ghostwritten/kubeowler) により生成されました。*"),
+];
+
+/// Issue code → (zh-CN, ja) short titles; English titles live in `issue_codes::short_title`.
+#[rustfmt::skip]
+const SHORT_TITLES: &[(&str, &str, &str)] = &[
+    ("NODE-001", "节点未就绪", "ノードが Ready ではない"),
+    ("NODE-002", "节点存在资源压力", "ノードにリソース圧迫がある"),
+    ("NODE-003", "节点存在僵尸进程", "ノードにゾンビプロセスがある"),
+    ("NODE-004", "节点磁盘使用率高（警告）", "ノードのディスク使用率が高い（警告）"),
+    ("NODE-005", "节点磁盘使用率严重", "ノードのディスク使用率が危険"),
+    ("NODE-006", "节点 CPU 容量不一致", "ノードの CPU 容量の不一致"),
+    ("NODE-007", "节点内存容量不一致", "ノードのメモリ容量の不一致"),
+    ("NODE-008", "节点资源使用率高", "ノードのリソース使用率が高い"),
+    ("NODE-009", "节点资源使用率严重", "ノードのリソース使用率が危険"),
+    ("NODE-010", "节点利用率不均衡", "ノード使用率の偏り"),
+    ("NODE-011", "节点 ephemeral-storage 可分配量低", "ノードの ephemeral-storage 割り当て可能量が少ない"),
+    ("NODE-012", "节点 imagefs 接近镜像回收阈值", "ノードの imagefs がイメージ GC しきい値に近い"),
+    ("NODE-013", "节点 nodefs 接近驱逐阈值", "ノードの nodefs が退避しきい値に近い"),
+    ("POD-001", "Pod 处于 Failed 状态", "Pod が Failed 状態"),
+    ("POD-002", "Pod 无法调度", "Pod をスケジュールできない"),
+    ("POD-003", "容器重启次数过多", "コンテナの再起動回数が多すぎる"),
+    ("POD-004", "容器状态异常", "コンテナの状態が異常"),
+    ("POD-005", "ImagePullBackOff", "ImagePullBackOff"),
+    ("POD-006", "ErrImagePull", "ErrImagePull"),
+    ("POD-007", "CrashLoopBackOff", "CrashLoopBackOff"),
+    ("POD-008", "ContainerCreating", "ContainerCreating"),
+    ("POD-009", "CreateContainerConfigError", "CreateContainerConfigError"),
+    ("POD-010", "OOMKilled", "OOMKilled"),
+    ("POD-011", "容器异常退出（非零退出码）", "コンテナが異常終了（非ゼロ終了コード）"),
+    ("POD-012", "Pod 运行中但未就绪", "Pod は Running だが Ready ではない"),
+    ("RES-001", "容器未设置资源 requests", "コンテナにリソース requests がない"),
+    ("RES-002", "容器未设置资源 limits", "コンテナにリソース limits がない"),
+    ("RES-003", "命名空间未设置资源配额", "名前空間にリソースクォータがない"),
+    ("RES-004", "CPU limit 小于 request", "CPU limit が request より小さい"),
+    ("RES-005", "内存 limit 小于 request", "メモリ limit が request より小さい"),
+    ("NET-001", "LoadBalancer 没有外部 IP", "LoadBalancer に外部 IP がない"),
+    ("NET-002", "NodePort 超出推荐范围", "NodePort が推奨範囲外"),
+    ("NET-003", "Service 没有 selector 或 endpoints", "Service に selector または endpoints がない"),
+    ("NET-004", "DNS Deployment 未就绪", "DNS Deployment が Ready ではない"),
+    ("NET-005", "未找到 DNS Service", "DNS Service が見つからない"),
+    ("STO-001", "PV 配置或后端存储问题", "PV の設定またはバックエンドストレージの問題"),
+    ("STO-002", "PV 已 Released，需要清理", "PV が Released、クリーンアップが必要"),
+    ("STO-003", "PV 已 Retained，需要人工处理", "PV が Retained、手動対応が必要"),
+    ("STO-004", "PV 未设置回收策略", "PV に回収ポリシーがない"),
+    ("STO-005", "PVC 存储类或容量问题", "PVC のストレージクラスまたは容量の問題"),
+    ("STO-006", "PVC 存在数据丢失风险", "PVC にデータ損失のリスクがある"),
+    ("STO-007", "PVC 未指定存储类", "PVC にストレージクラスがない"),
+    ("STO-008", "StorageClass 没有 provisioner", "StorageClass に provisioner がない"),
+    ("STO-009", "没有默认 StorageClass", "デフォルト StorageClass がない"),
+    ("STO-010", "多个 StorageClass 被标记为默认", "複数の StorageClass がデフォルトに設定されている"),
+    ("STO-011", "PVC 使用率高", "PVC の使用率が高い"),
+    ("STO-012", "PVC 即将写满", "PVC がほぼ満杯"),
+    ("STO-013", "StorageClass provisioner 没有 CSIDriver", "StorageClass の provisioner に CSIDriver がない"),
+    ("STO-014", "CSI 控制器未运行", "CSI コントローラが動作していない"),
+    ("STO-015", "多可用区集群中使用 Immediate 绑定", "マルチゾーンクラスタで Immediate バインディング"),
+    ("STO-016", "PV 引用了不存在的 StorageClass", "PV が存在しない StorageClass を参照"),
+    ("SEC-001", "ClusterRole 权限过大", "ClusterRole の権限が過剰"),
+    ("SEC-002", "用户拥有 cluster-admin", "ユーザーが cluster-admin を持つ"),
+    ("SEC-003", "ServiceAccount 拥有 cluster-admin", "ServiceAccount が cluster-admin を持つ"),
+    ("SEC-004", "Pod 以 root 运行", "Pod が root で実行されている"),
+    ("SEC-005", "容器以特权模式运行", "コンテナが特権モードで実行されている"),
+    ("SEC-006", "容器以 root 运行", "コンテナが root で実行されている"),
+    ("SEC-007", "容器允许权限提升", "コンテナが権限昇格を許可している"),
+    ("SEC-008", "网络策略覆盖不足", "ネットワークポリシーのカバレッジが不十分"),
+    ("SEC-009", "使用默认 ServiceAccount", "デフォルト ServiceAccount を使用"),
+    ("CTRL-001", "控制平面组件未就绪", "コントロールプレーンのコンポーネントが Ready ではない"),
+    ("CTRL-002", "静态 Pod 未就绪", "静的 Pod が Ready ではない"),
+    ("AUTO-001", "HPA 副本范围过窄", "HPA のレプリカ範囲が狭すぎる"),
+    ("AUTO-002", "HPA 未配置指标", "HPA にメトリクスが設定されていない"),
+    ("AUTO-003", "HPA 目标工作负载或指标问题", "HPA の対象ワークロードまたはメトリクスの問題"),
+    ("AUTO-004", "HPA behavior 限制了扩缩容", "HPA の behavior がスケーリングを制限"),
+    ("AUTO-005", "HPA 指标目标未配置", "HPA のメトリクス目標が未設定"),
+    ("AUTO-006", "工作负载副本数超出 HPA 范围", "ワークロードのレプリカ数が HPA の範囲外"),
+    ("AUTO-007", "工作负载副本数被其他写入方设置", "ワークロードのレプリカ数が別の書き込み元により設定されている"),
+    ("AUTO-008", "工作负载受多个自动扩缩器控制", "ワークロードが複数のオートスケーラに制御されている"),
+    ("BATCH-001", "CronJob 已暂停", "CronJob が一時停止中"),
+    ("BATCH-002", "CronJob 的 Job 失败", "CronJob の Job が失敗"),
+    ("BATCH-003", "CronJob 调度或控制器问题", "CronJob のスケジュールまたはコントローラの問題"),
+    ("BATCH-004", "Job 需检查 backoffLimit 或资源", "Job の backoffLimit またはリソースの確認が必要"),
+    ("BATCH-005", "Job Pod 卡住或需调整超时", "Job の Pod が停滞、またはタイムアウトの調整が必要"),
+    ("POLICY-001", "未配置 ResourceQuota", "ResourceQuota が設定されていない"),
+    ("POLICY-002", "未配置 LimitRange", "LimitRange が設定されていない"),
+    ("POLICY-003", "关键工作负载没有 PDB", "重要なワークロードに PDB がない"),
+    ("POLICY-004", "副本数不满足 PDB", "レプリカ数が PDB を満たさない"),
+    ("OBS-001", "未部署 metrics-server", "metrics-server がデプロイされていない"),
+    ("OBS-002", "未部署 kube-state-metrics", "kube-state-metrics がデプロイされていない"),
+    ("OBS-003", "未部署日志聚合", "ログ集約がデプロイされていない"),
+    ("OBS-004", "未部署 Prometheus/监控", "Prometheus/監視がデプロイされていない"),
+    ("DBG-001", "生产环境使用调试日志级别", "本番環境でデバッグログレベル"),
+    ("DBG-002", "暴露了调试/性能分析端口", "デバッグ/プロファイリングポートが公開されている"),
+    ("DBG-003", "副本数被缩减为 1", "レプリカ数が 1 に縮小されている"),
+    ("ORPH-001", "未使用的 ConfigMap", "未使用の ConfigMap"),
+    ("ORPH-002", "未使用的 Secret", "未使用の Secret"),
+    ("ORPH-003", "Service 未选中任何 Pod", "Service がどの Pod も選択していない"),
+    ("ORPH-004", "PVC 未被挂载", "PVC がマウントされていない"),
+    ("ORPH-005", "陈旧的已完成 Job", "古い完了済み Job"),
+    ("ORPH-006", "陈旧的已结束 Pod", "古い終了済み Pod"),
+    ("ORPH-007", "旧 ReplicaSet 超出历史保留数", "古い ReplicaSet が履歴上限を超えている"),
+    ("ORPH-008", "命名空间保留大量已结束 Pod", "名前空間に終了済み Pod が多数残っている"),
+    ("ORPH-009", "已完成 Job 未设置 ttlSecondsAfterFinished", "完了済み Job に ttlSecondsAfterFinished がない"),
+    ("ORPH-010", "Deployment 修订历史过长", "Deployment のリビジョン履歴が長い"),
+    ("EVT-001", "频繁出现的 Warning 事件原因", "多発している Warning イベントの理由"),
+    ("EVT-002", "持续的 FailedScheduling 事件", "継続的な FailedScheduling イベント"),
+    ("EVT-003", "持续的卷挂载/附加失败", "継続的なボリュームのマウント/アタッチ失敗"),
+    ("EVT-004", "持续的退避或探针失败", "継続的なバックオフまたはプローブ失敗"),
+    ("EVT-005", "持续的 Warning 事件模式", "継続的な Warning イベントのパターン"),
+    ("CERT-001", "CSR 长时间 Pending 或异常", "CSR が長時間 Pending または異常"),
+    ("CERT-002", "证书即将过期", "証明書の有効期限が近い"),
+    ("CERT-003", "证书已过期", "証明書の有効期限切れ"),
+    ("INSP-001", "巡检模块失败", "点検モジュールが失敗"),
+];
+
+fn pick(lang: Lang, entry: &(&'static str, &'static str, &'static str)) -> &'static str {
+    match lang {
+        Lang::En => entry.0,
+        Lang::ZhCn => entry.1,
+        Lang::Ja => entry.2,
+    }
+}
+
+/// Localized UI text, or `None` when `text` is not in the catalog (or `lang` is English).
+pub fn translate(text: &str, lang: Lang) -> Option<&'static str> {
+    if lang == Lang::En {
+        return None;
+    }
+    UI.iter().find(|e| e.0 == text).map(|e| pick(lang, e))
+}
+
+/// Short title of an issue code in `lang`, falling back to English.
+pub fn short_title(code: &str, lang: Lang) -> Option<&'static str> {
+    SHORT_TITLES
+        .iter()
+        .find(|e| e.0 == code && lang != Lang::En)
+        .map(|e| pick(lang, e))
+        .or_else(|| issue_codes::short_title(code))
+}
+
+/// Issue code in a cell like `[POD-003](https://...)`.
+fn linked_code(cell: &str) -> Option<&str> {
+    let rest = cell.strip_prefix('[')?;
+    Some(&rest[..rest.find(']')?])
+}
+
+/// Cells of a Markdown table row; `\|` inside a cell is not a separator.
+fn split_cells(line: &str) -> Vec<&str> {
+    let inner = line.trim();
+    let inner = inner.strip_prefix('|').unwrap_or(inner);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let bytes = inner.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'|' && (i == 0 || bytes[i - 1] != b'\\') {
+            cells.push(&inner[start..i]);
+            start = i + 1;
+        }
+    }
+    cells.push(&inner[start..]);
+    cells
+}
+
+fn localize_table_row(line: &str, lang: Lang) -> String {
+    let cells = split_cells(line);
+    let mut out: Vec<String> = Vec::with_capacity(cells.len());
+    let mut previous_code: Option<&str> = None;
+    for cell in cells {
+        let text = cell.trim();
+        let localized = match previous_code.and_then(|code| {
+            (issue_codes::short_title(code) == Some(text)).then(|| short_title(code, lang))
+        }) {
+            Some(Some(title)) => title.to_string(),
+            _ => translate(text, lang)
+                .map(String::from)
+                .unwrap_or_else(|| text.to_string()),
+        };
+        previous_code = linked_code(text);
+        out.push(localized);
+    }
+    format!("| {} |", out.join(" | "))
+}
+
+fn localize_bold_labels(line: &str, lang: Lang) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("**") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("**") else {
+            break;
+        };
+        let label = &after[..end];
+        out.push_str(&rest[..start]);
+        out.push_str("**");
+        out.push_str(translate(label, lang).unwrap_or(label));
+        out.push_str("**");
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Localize a generated Markdown report; English is returned unchanged.
+pub fn localize_markdown(md: &str, lang: Lang) -> String {
+    if lang == Lang::En {
+        return md.to_string();
+    }
+    let score = match lang {
+        Lang::ZhCn => "(得分: ",
+        _ => "(スコア: ",
+    };
+    let report_suffix = pick(
+        lang,
+        &(
+            " Kubernetes Cluster Check Report",
+            " Kubernetes 集群巡检报告",
+            " Kubernetes クラスタ点検レポート",
+        ),
+    );
+    let mut out = String::with_capacity(md.len() + md.len() / 4);
+    for line in md.lines() {
+        let line = line.replace("(Score: ", score);
+        let localized = if line.starts_with('#') {
+            let hashes = line.len() - line.trim_start_matches('#').len();
+            let text = line[hashes..].trim();
+            let text = match text.strip_suffix(" Kubernetes Cluster Check Report") {
+                Some(cluster) => format!("{}{}", cluster, report_suffix),
+                None => translate(text, lang)
+                    .map(String::from)
+                    .unwrap_or_else(|| text.to_string()),
+            };
+            format!("{} {}", &line[..hashes], text)
+        } else if line.starts_with('|') && !line.contains("---") {
+            localize_table_row(&line, lang)
+        } else if let Some(t) = translate(line.trim(), lang) {
+            t.to_string()
+        } else if line.contains("**") {
+            localize_bold_labels(&line, lang)
+        } else {
+            line
+        };
+        out.push_str(&localized);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn localizes_headings_tables_and_titles() {
+        let md = "# prod Kubernetes Cluster Check Report\n\n**Cluster**: prod\n\n## Namespace ranking\n\n\
+                  | Resource | Level | Issue Code | Short Title |\n|---|---|---|---|\n\
+                  | `web/a` | Critical | [POD-007](http://x) | CrashLoopBackOff |\n\
+                  | `web/b` | Warning | [SEC-004](http://x) | Pod runs as root |\n\
+                  ### Pod Status (Score: 80.0/100)\n";
+        let zh = localize_markdown(md, Lang::ZhCn);
+        assert!(zh.starts_with("# prod Kubernetes 集群巡检报告\n"));
+        assert!(zh.contains("**集群**: prod"));
+        assert!(zh.contains("## 命名空间排名"));
+        assert!(zh.contains("| 资源 | 级别 | 问题代码 | 简述 |"));
+        assert!(zh.contains("| `web/b` | 警告 | [SEC-004](http://x) | Pod 以 root 运行 |"));
+        assert!(zh.contains("### Pod Status (得分: 80.0/100)"));
+        assert_eq!(localize_markdown(md, Lang::En), md);
+        assert_eq!(
+            short_title("SEC-004", Lang::Ja),
+            Some("Pod が root で実行されている")
+        );
+        assert_eq!(split_cells("| a \\| b | c |"), vec![" a \\| b ", " c "]);
+    }
+
+    #[test]
+    fn translated_codes_exist_and_are_unique() {
+        for (code, _, _) in SHORT_TITLES {
+            assert!(
+                issue_codes::short_title(code).is_some(),
+                "{} is not an issue code",
+                code
+            );
+        }
+        let codes: std::collections::HashSet<&str> = SHORT_TITLES.iter().map(|e| e.0).collect();
+        assert_eq!(codes.len(), SHORT_TITLES.len());
+    }
+}
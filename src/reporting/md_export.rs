@@ -4,6 +4,10 @@ use anyhow::Result;
 use base64::Engine;
 use comrak::{markdown_to_html, ComrakOptions};
 
+use crate::cli::HtmlTheme;
+use crate::inspections::types::issue_fingerprint;
+use crate::reporting::branding::{escape_html, Branding};
+
 /// Logo image embedded at compile time; encoded as data URI so HTML report is self-contained.
 fn embedded_logo_data_uri() -> String {
     let bytes = include_bytes!("../../assets/logo.png");
@@ -13,38 +17,125 @@ fn embedded_logo_data_uri() -> String {
     )
 }
 
-/// Convert Markdown string to a full HTML document.
-pub fn md_to_html(md: &str) -> Result<String> {
+/// Placeholders for the `<details>` / `<summary>` lines of collapsible blocks. comrak drops raw
+/// HTML (report text comes from the cluster and is never trusted as markup), so these lines are
+/// rendered as marker paragraphs and swapped back after conversion.
+const DETAILS_OPEN: &str = "KUBEOWLER-DETAILS-OPEN";
+const DETAILS_CLOSE: &str = "KUBEOWLER-DETAILS-CLOSE";
+const SUMMARY: &str = "KUBEOWLER-SUMMARY:";
+
+fn markdown_body_to_html(md: &str) -> String {
+    let mut prepared = String::with_capacity(md.len());
+    let mut in_code = false;
+    for line in md.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        }
+        let summary = line
+            .strip_prefix("<summary>")
+            .and_then(|l| l.strip_suffix("</summary>"));
+        let marker = match (in_code, line, summary) {
+            (false, "<details>", _) => Some(DETAILS_OPEN.to_string()),
+            (false, "</details>", _) => Some(DETAILS_CLOSE.to_string()),
+            (false, _, Some(text)) => Some(format!("{} {}", SUMMARY, text)),
+            _ => None,
+        };
+        match marker {
+            Some(m) => {
+                prepared.push_str(&format!("\n{}\n\n", m));
+            }
+            None => {
+                prepared.push_str(line);
+                prepared.push('\n');
+            }
+        }
+    }
     let mut opts = ComrakOptions::default();
     opts.extension.table = true;
-    let body = markdown_to_html(md, &opts);
-    let logo_src = embedded_logo_data_uri();
+    let html = markdown_to_html(&prepared, &opts);
+    html.lines()
+        .map(|l| {
+            if l == format!("<p>{}</p>", DETAILS_OPEN) {
+                "<details>".to_string()
+            } else if l == format!("<p>{}</p>", DETAILS_CLOSE) {
+                "</details>".to_string()
+            } else if let Some(text) = l
+                .strip_prefix(&format!("<p>{} ", SUMMARY))
+                .and_then(|t| t.strip_suffix("</p>"))
+            {
+                format!("<summary>{}</summary>", text)
+            } else {
+                l.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Colors of the light and dark themes: background, text, borders, table header background, and
+/// the accent used when no brand color is set.
+fn theme_colors(theme: HtmlTheme) -> [&'static str; 5] {
+    match theme {
+        HtmlTheme::Light => ["#ffffff", "#1f2328", "#333333", "#f5f5f5", "#333333"],
+        HtmlTheme::Dark => ["#0d1117", "#e6edf3", "#8b949e", "#161b22", "#58a6ff"],
+    }
+}
+
+/// Convert Markdown string to a full HTML document with `branding` (logo, company name, colors, theme).
+pub fn md_to_html(md: &str, branding: &Branding) -> Result<String> {
+    let body = markdown_body_to_html(md);
+    let logo_src = branding.logo_src()?.unwrap_or_else(embedded_logo_data_uri);
+    let [background, text, border, header_background, default_accent] =
+        theme_colors(branding.theme.unwrap_or_default());
+    let accent = branding.primary_color.as_deref().unwrap_or(default_accent);
+    let company = branding.company_name.as_deref().map(escape_html);
+    let title = match &company {
+        Some(c) => format!("{} – Kubeowler Report", c),
+        None => "Kubeowler Report".to_string(),
+    };
+    let banner = company
+        .as_ref()
+        .map(|c| format!("<div class=\"report-brand\">{}</div>\n", c))
+        .unwrap_or_default();
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
 <head>
 <meta charset="UTF-8"/>
-<title>Kubeowler Report</title>
+<title>{title}</title>
 <style>
 :root {{
   --cell-padding-vertical: 0.25em;
   --cell-padding-horizontal: 0.25em;
   --font-family-sans: system-ui, -apple-system, sans-serif;
+  --color-background: {background};
+  --color-text: {text};
+  --color-border: {border};
+  --color-header-background: {header_background};
+  --color-accent: {accent};
 }}
 body {{
   max-width: 60em;
   margin: auto;
   font-family: var(--font-family-sans);
+  background: var(--color-background);
+  color: var(--color-text);
+}}
+a {{
+  color: var(--color-accent);
+}}
+h1, h2 {{
+  border-bottom: 0.1em solid var(--color-accent);
 }}
 table {{
   width: 100%;
   border-collapse: collapse;
   margin: 1em 0;
-  border-top: 0.1em solid #333;
-  border-bottom: 0.1em solid #333;
+  border-top: 0.1em solid var(--color-border);
+  border-bottom: 0.1em solid var(--color-border);
 }}
 thead {{
-  border-bottom: 0.1em solid #333;
+  border-bottom: 0.1em solid var(--color-border);
 }}
 th, td {{
   padding-top: var(--cell-padding-vertical);
@@ -55,7 +146,8 @@ th, td {{
   vertical-align: top;
 }}
 th {{
-  background: #f5f5f5;
+  background: var(--color-header-background);
+  border-bottom: 0.15em solid var(--color-accent);
 }}
 td > p {{
   margin: 0;
@@ -71,19 +163,25 @@ td {{
   max-width: 200px;
   float: right;
 }}
+.report-brand {{
+  font-size: 1.4em;
+  font-weight: bold;
+  color: var(--color-accent);
+  padding: 0.5em 0;
+}}
 </style>
 </head>
 <body>
-<img class="report-logo" src="{}" alt="Kubeowler"/>
-{}
+{banner}<img class="report-logo" src="{logo_src}" alt="{logo_alt}"/>
+{body}
 </body>
 </html>"#,
-        logo_src, body
+        logo_alt = company.as_deref().unwrap_or("Kubeowler"),
     );
     Ok(html)
 }
 
-fn escape_csv(s: &str) -> String {
+pub(crate) fn escape_csv(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
         format!("\"{}\"", s.replace('"', "\"\""))
     } else {
@@ -113,7 +211,9 @@ pub fn md_to_csv(md: &str) -> Result<String> {
     let mut seen_cluster_overview = false;
     let mut in_overview_table = false;
     let mut current_section = String::new();
-    let mut issue_rows: Vec<(String, String, String, String, String)> = Vec::new(); // section, resource, level, rule_id, short_title
+    let mut issue_rows: Vec<[String; 7]> = Vec::new(); // section, resource, level, rule_id, short_title, team, fingerprint
+    let mut collection_warnings: Vec<(String, String, String)> = Vec::new(); // source, status, impact
+    let mut namespace_rows: Vec<Vec<String>> = Vec::new(); // rank, namespace, score, critical, warning, info, top codes
 
     let mut i = 0;
     while i < lines.len() {
@@ -161,6 +261,48 @@ pub fn md_to_csv(md: &str) -> Result<String> {
             current_section = line.trim_start_matches("### ").trim().to_string();
         }
 
+        if line.starts_with("| Data source | Status | Impact |") {
+            i += 1;
+            if i < lines.len() && lines[i].contains("---") {
+                i += 1;
+            }
+            while i < lines.len() && lines[i].starts_with('|') {
+                let cells: Vec<&str> = lines[i]
+                    .trim_matches('|')
+                    .split(" | ")
+                    .map(|c| c.trim())
+                    .collect();
+                if cells.len() >= 3 {
+                    collection_warnings.push((
+                        cells[0].to_string(),
+                        cells[1].to_string(),
+                        cells[2..].join(" | ").replace("\\|", "|"),
+                    ));
+                }
+                i += 1;
+            }
+            continue;
+        }
+
+        if line.starts_with("| Rank | Namespace | Score |") {
+            i += 1;
+            if i < lines.len() && lines[i].contains("---") {
+                i += 1;
+            }
+            while i < lines.len() && lines[i].starts_with('|') {
+                let cells: Vec<String> = lines[i]
+                    .trim_matches('|')
+                    .split(" | ")
+                    .map(|c| c.trim().to_string())
+                    .collect();
+                if cells.len() >= 7 {
+                    namespace_rows.push(cells);
+                }
+                i += 1;
+            }
+            continue;
+        }
+
         if line.starts_with('|')
             && (line.contains("Resource")
                 && line.contains("Level")
@@ -179,17 +321,33 @@ pub fn md_to_csv(md: &str) -> Result<String> {
                     .filter(|c| !c.is_empty())
                     .collect();
                 if cells.len() >= 4 && !cells[0].chars().all(|c| c == '-') {
+                    // Backticked cells name a resource; plain cells are the table's resource kind.
+                    let named = cells[0].starts_with('`');
                     let resource = cells[0].trim_matches('`').to_string();
                     let level = cells[1].to_string();
                     let rule_id = extract_rule_id(cells.get(2).unwrap_or(&""));
                     let short_title = cells.get(3).unwrap_or(&"").to_string();
-                    issue_rows.push((
+                    let team = cells
+                        .get(4)
+                        .filter(|t| **t != "-")
+                        .unwrap_or(&"")
+                        .to_string();
+                    // Coded issues are fingerprinted by code and resource; the table carries no
+                    // key attributes, so rows match only findings recorded without them.
+                    let fingerprint = if rule_id.is_empty() {
+                        String::new()
+                    } else {
+                        issue_fingerprint(Some(&rule_id), "", named.then_some(&*resource), &[], "")
+                    };
+                    issue_rows.push([
                         current_section.clone(),
                         resource,
                         level,
                         rule_id,
                         short_title,
-                    ));
+                        team,
+                        fingerprint,
+                    ]);
                 }
                 i += 1;
             }
@@ -220,12 +378,32 @@ pub fn md_to_csv(md: &str) -> Result<String> {
         escape_csv(&age)
     ));
 
+    if !collection_warnings.is_empty() {
+        out.push_str("section,source,status,impact\n");
+        for (source, status, impact) in collection_warnings {
+            out.push_str(&format!(
+                "collection_warning,{},{},{}\n",
+                escape_csv(&source),
+                escape_csv(&status),
+                escape_csv(&impact)
+            ));
+        }
+    }
+
+    if !namespace_rows.is_empty() {
+        out.push_str("section,rank,namespace,score,critical,warning,info,top_issue_codes\n");
+        for cells in namespace_rows {
+            let row: Vec<String> = cells.iter().take(7).map(|c| escape_csv(c)).collect();
+            out.push_str(&format!("namespace_rank,{}\n", row.join(",")));
+        }
+    }
+
     out.push_str(
-        "section,inspection_type,severity,category,description,resource,recommendation,rule_id\n",
+        "section,inspection_type,severity,category,description,resource,recommendation,rule_id,team,fingerprint\n",
     );
-    for (section, resource, level, rule_id, short_title) in issue_rows {
+    for [section, resource, level, rule_id, short_title, team, fingerprint] in issue_rows {
         out.push_str(&format!(
-            "issue,{0},{1},{2},{3},{4},{5},{6},{7}\n",
+            "issue,{0},{1},{2},{3},{4},{5},{6},{7},{8},{9}\n",
             escape_csv(&section),
             escape_csv(&section),
             escape_csv(&level),
@@ -234,6 +412,8 @@ pub fn md_to_csv(md: &str) -> Result<String> {
             escape_csv(&resource),
             escape_csv(""),
             escape_csv(&rule_id),
+            escape_csv(&team),
+            escape_csv(&fingerprint),
         ));
     }
 
@@ -286,6 +466,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn md_to_csv_includes_collection_warnings() {
+        let md = r#"# Report
+**Cluster**: c1
+## Data completeness
+| Data source | Status | Impact |
+|-------------|--------|--------|
+| metrics-server (metrics.k8s.io) | Unavailable | Live usage is missing, a \| b |
+## Cluster Overview
+| Metric | Value |
+|--------|-------|
+| Node Count | 2 |
+"#;
+        let csv = md_to_csv(md).unwrap();
+        assert!(csv.contains("section,source,status,impact"));
+        assert!(csv.contains(
+            "collection_warning,metrics-server (metrics.k8s.io),Unavailable,\"Live usage is missing, a | b\""
+        ));
+    }
+
+    #[test]
+    fn md_to_csv_includes_team_column() {
+        let md = r#"# Report
+### Pod
+| Resource | Level | Issue Code | Short Title | Team |
+|----------|-------|------------|-------------|------|
+| `payments/api-1` | Critical | [POD-003](http://x) | Restart count high | payments |
+| `kube-system/etcd-0` | Warning | [POD-001](http://x) | Pending | - |
+"#;
+        let csv = md_to_csv(md).unwrap();
+        assert!(csv.contains("rule_id,team,fingerprint\n"));
+        let fp = issue_fingerprint(
+            Some("POD-001"),
+            "Pod",
+            Some("kube-system/etcd-0"),
+            &[],
+            "Pending",
+        );
+        assert_eq!(fp.len(), 16);
+        assert!(csv.contains("payments/api-1,,POD-003,payments,"));
+        assert!(csv.ends_with(&format!(
+            "issue,Pod,Pod,Warning,Pod,Pending,kube-system/etcd-0,,POD-001,,{}\n",
+            fp
+        )));
+    }
+
+    #[test]
+    fn md_to_csv_includes_namespace_ranking() {
+        let md = r#"# Report
+## Namespace ranking
+| Rank | Namespace | Score | Critical | Warning | Info | Top issue codes |
+|------|-----------|-------|----------|---------|------|-----------------|
+| 1 | payments | 77.0 | 2 | 1 | 0 | POD-003, POLICY-002 |
+"#;
+        let csv = md_to_csv(md).unwrap();
+        assert!(csv.contains("section,rank,namespace,score,critical,warning,info,top_issue_codes"));
+        assert!(csv.contains("namespace_rank,1,payments,77.0,2,1,0,\"POD-003, POLICY-002\""));
+    }
+
     #[test]
     fn md_to_html_renders_tables() {
         let md = r#"# Report
@@ -294,7 +533,7 @@ mod tests {
 |--------|-------|
 | Nodes | 4 |
 "#;
-        let html = md_to_html(md).unwrap();
+        let html = md_to_html(md, &Branding::default()).unwrap();
         assert!(
             html.contains("<table>"),
             "HTML should contain <table> when extension.table is enabled"
@@ -306,4 +545,21 @@ mod tests {
             "HTML should embed logo as data URI for standalone report"
         );
     }
+
+    #[test]
+    fn md_to_html_applies_branding() {
+        let branding = Branding {
+            company_name: Some("Acme <Ops>".to_string()),
+            logo: Some("https://example.com/logo.svg".to_string()),
+            primary_color: Some("#ff6600".to_string()),
+            theme: Some(HtmlTheme::Dark),
+        };
+        let html = md_to_html("# Report\n", &branding).unwrap();
+        assert!(html.contains("<title>Acme &lt;Ops&gt; – Kubeowler Report</title>"));
+        assert!(html.contains("<div class=\"report-brand\">Acme &lt;Ops&gt;</div>"));
+        assert!(html.contains("src=\"https://example.com/logo.svg\""));
+        assert!(!html.contains("data:image/png;base64,"));
+        assert!(html.contains("--color-accent: #ff6600;"));
+        assert!(html.contains("--color-background: #0d1117;"));
+    }
 }
@@ -1,7 +1,29 @@
+pub mod anonymize;
+pub mod branding;
+pub mod csv;
+pub mod export_affected;
 pub mod generator;
+pub mod i18n;
 pub mod md_export;
+pub mod redact;
 pub mod report_resource;
+pub mod resource_appendix;
+pub mod schema;
+pub mod template;
+pub mod xlsx;
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::inspections::types::ClusterReport;
 
 pub use generator::ReportGenerator;
 #[allow(unused_imports)]
 pub use report_resource::{issue_to_resource_key, REPORT_RESOURCE_ORDER};
+
+/// Saved JSON report at `path`.
+pub fn read_report(path: &Path) -> Result<ClusterReport> {
+    let file = std::fs::File::open(path)?;
+    Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+}
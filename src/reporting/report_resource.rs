@@ -9,10 +9,14 @@ pub const REPORT_RESOURCE_ORDER: &[&str] = &[
     "Pod",
     "Service",
     "Deployment",
+    "StatefulSet",
+    "DaemonSet",
     "Namespace",
     "PersistentVolume",
     "PersistentVolumeClaim",
     "StorageClass",
+    "ConfigMap",
+    "Secret",
     "ClusterRole",
     "ClusterRoleBinding",
     "ServiceAccount",
@@ -24,8 +28,10 @@ pub const REPORT_RESOURCE_ORDER: &[&str] = &[
     "Policy",
     "Control Plane",
     "Observability",
+    "Event",
     "Security",
     "Resource Management",
+    "Inspection",
 ];
 
 /// Maps an issue's category (and optionally rule_id) to the canonical resource object key used for grouping and scoring.
@@ -46,7 +52,11 @@ pub fn issue_to_resource_key(issue: &Issue) -> String {
         "Certificates" => "Certificate".to_string(),
         "ControlPlane" => "Control Plane".to_string(),
         "Observability" => "Observability".to_string(),
-        "Node" | "Service" | "Deployment" | "Namespace" => cat.to_string(),
+        "Event" => "Event".to_string(),
+        "Node" | "Service" | "Deployment" | "StatefulSet" | "DaemonSet" | "Namespace" => {
+            cat.to_string()
+        }
+        "ConfigMap" | "Secret" => cat.to_string(),
         "PersistentVolume" | "PersistentVolumeClaim" | "StorageClass" => cat.to_string(),
         "ClusterRole" | "ClusterRoleBinding" | "ServiceAccount" | "NetworkPolicy" => {
             cat.to_string()
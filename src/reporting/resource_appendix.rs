@@ -0,0 +1,147 @@
+//! Machine-readable appendix of the full affected-resource lists (`--resource-appendix FILE`),
+//! for reports that cut long lists with `--max-resources-per-issue`.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::inspections::issue_codes;
+use crate::inspections::types::{ClusterReport, IssueSeverity};
+use crate::reporting::report_resource::{issue_to_resource_key, REPORT_RESOURCE_ORDER};
+
+/// Default `--max-resources-per-issue`: list every resource.
+pub const DEFAULT_MAX_RESOURCES_PER_ISSUE: usize = 0;
+
+#[derive(Debug, Serialize)]
+pub struct ResourceAppendix {
+    pub cluster_name: String,
+    pub report_id: String,
+    pub issues: Vec<IssueResources>,
+}
+
+/// Every resource of one issue row group of the report (resource kind, severity, issue code).
+#[derive(Debug, Serialize)]
+pub struct IssueResources {
+    pub resource_kind: String,
+    pub severity: IssueSeverity,
+    pub rule_id: Option<String>,
+    pub title: String,
+    pub count: usize,
+    pub resources: Vec<String>,
+}
+
+fn severity_rank(s: &IssueSeverity) -> u8 {
+    match s {
+        IssueSeverity::Critical => 0,
+        IssueSeverity::Warning => 1,
+        IssueSeverity::Info => 2,
+    }
+}
+
+/// Groups in report order: resource kind as in the per-resource tables, then severity, then code.
+pub fn build(report: &ClusterReport) -> ResourceAppendix {
+    let kind_rank = |kind: &str| {
+        REPORT_RESOURCE_ORDER
+            .iter()
+            .position(|k| *k == kind)
+            .unwrap_or(REPORT_RESOURCE_ORDER.len())
+    };
+    let mut groups: BTreeMap<(usize, u8, String), IssueResources> = BTreeMap::new();
+    for issue in report.inspections.iter().flat_map(|i| &i.summary.issues) {
+        let kind = issue_to_resource_key(issue);
+        let code = issue
+            .rule_id
+            .clone()
+            .unwrap_or_else(|| issue.category.clone());
+        let group = groups
+            .entry((kind_rank(&kind), severity_rank(&issue.severity), code))
+            .or_insert_with(|| IssueResources {
+                resource_kind: kind,
+                severity: issue.severity.clone(),
+                rule_id: issue.rule_id.clone(),
+                title: issue
+                    .rule_id
+                    .as_deref()
+                    .and_then(issue_codes::short_title)
+                    .map(String::from)
+                    .unwrap_or_else(|| issue.description.clone()),
+                count: 0,
+                resources: Vec::new(),
+            });
+        if let Some(r) = &issue.resource {
+            group.resources.push(r.clone());
+        }
+    }
+    let issues = groups
+        .into_values()
+        .map(|mut g| {
+            g.count = g.resources.len();
+            g
+        })
+        .collect();
+    ResourceAppendix {
+        cluster_name: report.cluster_name.clone(),
+        report_id: report.report_id.clone(),
+        issues,
+    }
+}
+
+/// Write the appendix of `report` to `path` as pretty JSON.
+pub fn write(report: &ClusterReport, path: &str) -> Result<()> {
+    let file = std::fs::File::create(path).with_context(|| format!("cannot create {}", path))?;
+    serde_json::to_writer_pretty(file, &build(report))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::Issue;
+
+    fn issue(severity: IssueSeverity, rule: &str, resource: &str) -> Issue {
+        Issue::new(severity, "Pod", rule, "d", resource.to_string(), "")
+    }
+
+    #[test]
+    fn groups_full_resource_lists_by_kind_severity_and_code() {
+        let mut report: ClusterReport = serde_json::from_value(serde_json::json!({
+            "cluster_name": "prod",
+            "report_id": "r1",
+            "timestamp": "2026-03-01T00:00:00Z",
+            "overall_score": 50.0,
+            "inspections": [{
+                "inspection_type": "Pod Status",
+                "timestamp": "2026-03-01T00:00:00Z",
+                "overall_score": 50.0,
+                "checks": [],
+                "summary": {
+                    "total_checks": 0, "passed_checks": 0, "warning_checks": 0,
+                    "critical_checks": 0, "error_checks": 0, "issues": []
+                }
+            }],
+            "executive_summary": {
+                "health_status": "Poor", "key_findings": [],
+                "priority_recommendations": [], "score_breakdown": {}
+            }
+        }))
+        .unwrap();
+        let issues = &mut report.inspections[0].summary.issues;
+        for i in 0..3 {
+            issues.push(issue(
+                IssueSeverity::Warning,
+                "POD-003",
+                &format!("ns/p{}", i),
+            ));
+        }
+        issues.push(issue(IssueSeverity::Critical, "POD-002", "ns/x"));
+
+        let appendix = build(&report);
+        assert_eq!(appendix.issues.len(), 2);
+        assert_eq!(appendix.issues[0].rule_id.as_deref(), Some("POD-002"));
+        let warn = &appendix.issues[1];
+        assert_eq!(warn.resource_kind, "Pod");
+        assert_eq!(warn.count, 3);
+        assert_eq!(warn.resources, vec!["ns/p0", "ns/p1", "ns/p2"]);
+    }
+}
@@ -0,0 +1,18 @@
+//! JSON Schema of the `check --format json` report, generated from the `ClusterReport` types and
+//! published as docs/report-schema.json (`kubeowler schema`).
+
+use anyhow::Result;
+
+use crate::inspections::types::{ClusterReport, REPORT_SCHEMA_VERSION};
+
+/// Pretty-printed schema with a trailing newline, as committed to docs/report-schema.json.
+pub fn report_schema() -> Result<String> {
+    let mut schema = schemars::schema_for!(ClusterReport);
+    let metadata = schema.schema.metadata();
+    metadata.title = Some("kubeowler ClusterReport".to_string());
+    metadata.description = Some(format!(
+        "kubeowler JSON report, schema_version {}. Minor versions only add optional fields.",
+        REPORT_SCHEMA_VERSION
+    ));
+    Ok(serde_json::to_string_pretty(&schema)? + "\n")
+}
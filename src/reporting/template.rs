@@ -0,0 +1,168 @@
+//! User-supplied report templates (`--template FILE`): a Handlebars template rendered against
+//! the JSON form of [`ClusterReport`] (the fields documented in `docs/report-schema.json`)
+//! instead of the built-in Markdown layout.
+//!
+//! `*.html` / `*.html.hbs` templates produce a complete HTML page and are HTML-escaped; any
+//! other template produces Markdown, which goes through the usual localization, doc-link and
+//! (for `--format html`) HTML conversion steps.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use handlebars::{handlebars_helper, no_escape, Handlebars};
+use serde_json::Value;
+
+use crate::inspections::issue_codes;
+use crate::inspections::types::ClusterReport;
+use crate::reporting::generator::CheckLevelFilter;
+
+const TEMPLATE_NAME: &str = "report";
+
+handlebars_helper!(fixed: |value: f64, digits: u64| format!("{:.*}", digits as usize, value));
+handlebars_helper!(cell: |value: str| value.replace('|', "\\|").replace('\n', " "));
+handlebars_helper!(doc_url: |code: str| issue_codes::doc_path(code));
+
+/// A compiled `--template`.
+pub struct ReportTemplate {
+    registry: Handlebars<'static>,
+    html: bool,
+}
+
+impl ReportTemplate {
+    /// Read and compile `path`; syntax errors are reported before any cluster call.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read template {}", path))?;
+        let html = is_html_template(Path::new(path));
+        Self::compile(&source, html).with_context(|| format!("invalid template {}", path))
+    }
+
+    /// Compile `source`; `html` selects HTML escaping of substituted values.
+    pub fn compile(source: &str, html: bool) -> Result<Self> {
+        let mut registry = Handlebars::new();
+        if !html {
+            registry.register_escape_fn(no_escape);
+        }
+        registry.register_helper("fixed", Box::new(fixed));
+        registry.register_helper("cell", Box::new(cell));
+        registry.register_helper("doc_url", Box::new(doc_url));
+        registry.register_template_string(TEMPLATE_NAME, source)?;
+        Ok(Self { registry, html })
+    }
+
+    /// True when the template renders a complete HTML page rather than Markdown.
+    pub fn is_html(&self) -> bool {
+        self.html
+    }
+
+    /// Render against `report`; checks whose status `check_filter` excludes are left out, as in
+    /// the built-in Check Results table.
+    pub fn render(
+        &self,
+        report: &ClusterReport,
+        check_filter: &CheckLevelFilter,
+    ) -> Result<String> {
+        let mut data = serde_json::to_value(report)?;
+        if let (CheckLevelFilter::Only(statuses), Some(inspections)) =
+            (check_filter, data["inspections"].as_array_mut())
+        {
+            let keep: Vec<Value> = statuses
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<_, _>>()?;
+            for inspection in inspections {
+                if let Some(checks) = inspection["checks"].as_array_mut() {
+                    checks.retain(|c| keep.contains(&c["status"]));
+                }
+            }
+        }
+        Ok(self.registry.render(TEMPLATE_NAME, &data)?)
+    }
+}
+
+fn is_html_template(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let name = name.strip_suffix(".hbs").unwrap_or(&name);
+    name.ends_with(".html") || name.ends_with(".htm")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::CheckStatus;
+
+    fn report() -> ClusterReport {
+        serde_json::from_value(serde_json::json!({
+            "cluster_name": "prod <eu>",
+            "report_id": "r1",
+            "timestamp": "2026-03-01T00:00:00Z",
+            "overall_score": 87.26,
+            "inspections": [{
+                "inspection_type": "Pod Status",
+                "timestamp": "2026-03-01T00:00:00Z",
+                "overall_score": 80.0,
+                "checks": [
+                    {"name": "Restarts", "description": "", "status": "Warning", "score": 50.0, "max_score": 100.0, "recommendations": []},
+                    {"name": "Phase", "description": "", "status": "Pass", "score": 100.0, "max_score": 100.0, "recommendations": []}
+                ],
+                "summary": {
+                    "total_checks": 2, "passed_checks": 1, "warning_checks": 1,
+                    "critical_checks": 0, "error_checks": 0,
+                    "issues": [{
+                        "severity": "Warning", "category": "Pod",
+                        "description": "Pod a|b restarted", "resource": "ns/x",
+                        "recommendation": "Check logs", "rule_id": "POD-003"
+                    }]
+                }
+            }],
+            "executive_summary": {
+                "health_status": "Good",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_markdown_against_the_report_model() {
+        let template = ReportTemplate::compile(
+            "# {{cluster_name}} ({{fixed overall_score 1}})\n\
+             {{#each inspections}}{{#each checks}}- {{name}}\n{{/each}}\
+             {{#each summary.issues}}| {{cell description}} | [{{rule_id}}]({{doc_url rule_id}}) |\n{{/each}}{{/each}}",
+            false,
+        )
+        .unwrap();
+        let filter = CheckLevelFilter::Only(vec![CheckStatus::Warning]);
+        let out = template.render(&report(), &filter).unwrap();
+        assert!(out.starts_with("# prod <eu> (87.3)\n"));
+        assert!(out.contains("- Restarts\n"));
+        assert!(!out.contains("- Phase"));
+        assert!(out.contains("| Pod a\\|b restarted | [POD-003]("));
+        assert!(out.contains("/POD-003.md) |"));
+
+        let all = template.render(&report(), &CheckLevelFilter::All).unwrap();
+        assert!(all.contains("- Phase\n"));
+    }
+
+    #[test]
+    fn html_templates_escape_values() {
+        let template = ReportTemplate::compile("<h1>{{cluster_name}}</h1>", true).unwrap();
+        assert!(template.is_html());
+        let out = template.render(&report(), &CheckLevelFilter::All).unwrap();
+        assert_eq!(out, "<h1>prod &lt;eu&gt;</h1>");
+    }
+
+    #[test]
+    fn detects_template_kind_and_syntax_errors() {
+        assert!(is_html_template(Path::new("t/report.html.hbs")));
+        assert!(is_html_template(Path::new("REPORT.HTML")));
+        assert!(!is_html_template(Path::new("report.md.hbs")));
+        assert!(!is_html_template(Path::new("report.hbs")));
+        assert!(ReportTemplate::compile("{{#each inspections}}", false).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Excel workbook (`--format xlsx`): Overview, Issues, Checks, Nodes and Certificates sheets.
+//! Issue and check rows are the same as `--csv-layout issues|checks`.
+
+use anyhow::Result;
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use crate::inspections::types::ClusterReport;
+use crate::reporting::csv::{check_rows, issue_rows, CHECK_COLUMNS, ISSUE_COLUMNS};
+use crate::reporting::generator::CheckLevelFilter;
+
+const NODE_COLUMNS: [&str; 9] = [
+    "node",
+    "ready",
+    "pods",
+    "os_image",
+    "architecture",
+    "kernel",
+    "kubelet",
+    "container_runtime",
+    "address",
+];
+const CERTIFICATE_COLUMNS: [&str; 5] = [
+    "namespace",
+    "secret",
+    "subject_or_cn",
+    "expiry_utc",
+    "days_until_expiry",
+];
+/// Columns are sized to their longest cell, up to this many characters.
+const MAX_COLUMN_WIDTH: usize = 60;
+
+/// Header row, frozen with an autofilter, then `rows`. Cells in `numeric` columns are written as
+/// numbers when they parse, so spreadsheets can sort and sum them.
+fn write_table(
+    sheet: &mut Worksheet,
+    columns: &[&str],
+    rows: &[Vec<String>],
+    numeric: &[usize],
+) -> Result<()> {
+    let bold = Format::new().set_bold();
+    for (c, name) in columns.iter().enumerate() {
+        sheet.write_string_with_format(0, c as u16, *name, &bold)?;
+    }
+    for (r, row) in rows.iter().enumerate() {
+        let r = r as u32 + 1;
+        for (c, cell) in row.iter().enumerate() {
+            match cell.parse::<f64>() {
+                Ok(n) if numeric.contains(&c) => sheet.write_number(r, c as u16, n)?,
+                _ => sheet.write_string(r, c as u16, cell)?,
+            };
+        }
+    }
+    for (c, name) in columns.iter().enumerate() {
+        let width = rows
+            .iter()
+            .filter_map(|row| row.get(c))
+            .map(|cell| cell.chars().count())
+            .chain(std::iter::once(name.len()))
+            .max()
+            .unwrap_or(0)
+            .min(MAX_COLUMN_WIDTH);
+        sheet.set_column_width(c as u16, width as f64 + 2.0)?;
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    sheet.autofilter(
+        0,
+        0,
+        rows.len() as u32,
+        columns.len().saturating_sub(1) as u16,
+    )?;
+    Ok(())
+}
+
+fn overview_rows(report: &ClusterReport) -> Vec<Vec<String>> {
+    let generated_at = report
+        .display_timestamp
+        .clone()
+        .unwrap_or_else(|| report.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string());
+    let mut rows = vec![
+        vec!["Cluster".to_string(), report.cluster_name.clone()],
+        vec!["Report ID".to_string(), report.report_id.clone()],
+        vec!["Generated At".to_string(), generated_at],
+        vec![
+            "Overall Score".to_string(),
+            format!("{:.1}", report.overall_score),
+        ],
+        vec![
+            "Health Status".to_string(),
+            format!("{:?}", report.executive_summary.health_status),
+        ],
+        vec![
+            "Grade".to_string(),
+            report.executive_summary.health_status.grade().to_string(),
+        ],
+        vec![
+            "Scoring Profile".to_string(),
+            report.metadata.scoring_profile.name.clone(),
+        ],
+    ];
+    if let Some(ov) = &report.cluster_overview {
+        if let Some(v) = &ov.cluster_version {
+            rows.push(vec!["Cluster Version".to_string(), v.clone()]);
+        }
+        if let Some(provider) = ov.managed_provider {
+            rows.push(vec!["Managed Provider".to_string(), provider.to_string()]);
+        }
+        if let Some(node_list) = &ov.node_list {
+            let spot = node_list.iter().filter(|n| n.spot).count();
+            if spot > 0 {
+                rows.push(vec![
+                    "Spot / On-demand Nodes".to_string(),
+                    format!("{} / {}", spot, node_list.len() - spot),
+                ]);
+            }
+        }
+        if let Some(oldest) = &ov.oldest_node_image {
+            rows.push(vec![
+                "Oldest Node Image".to_string(),
+                format!(
+                    "{} days ({}, {})",
+                    oldest.age_days, oldest.node, oldest.source
+                ),
+            ]);
+        }
+        rows.push(vec!["Node Count".to_string(), ov.node_count.to_string()]);
+        rows.push(vec![
+            "Ready Nodes".to_string(),
+            ov.ready_node_count.to_string(),
+        ]);
+        if let Some(p) = ov.pod_count {
+            rows.push(vec!["Pod Count".to_string(), p.to_string()]);
+        }
+    }
+    for inspection in &report.inspections {
+        rows.push(vec![
+            format!("Score: {}", inspection.inspection_type),
+            format!("{:.1}", inspection.overall_score),
+        ]);
+    }
+    rows
+}
+
+fn node_rows(report: &ClusterReport) -> Vec<Vec<String>> {
+    let nodes = report
+        .cluster_overview
+        .as_ref()
+        .and_then(|ov| ov.node_list.as_deref())
+        .unwrap_or_default();
+    nodes
+        .iter()
+        .map(|n| {
+            vec![
+                n.name.clone(),
+                if n.ready { "Ready" } else { "NotReady" }.to_string(),
+                n.pod_count.to_string(),
+                n.os_image
+                    .clone()
+                    .unwrap_or_else(|| n.operating_system.clone()),
+                n.architecture.clone(),
+                n.kernel_version.clone().unwrap_or_default(),
+                n.kubelet_version.clone(),
+                n.container_runtime_version.clone().unwrap_or_default(),
+                n.node_address.clone().unwrap_or_default(),
+            ]
+        })
+        .collect()
+}
+
+fn certificate_rows(report: &ClusterReport) -> Vec<Vec<String>> {
+    report
+        .inspections
+        .iter()
+        .filter_map(|i| i.certificate_expiries.as_ref())
+        .flatten()
+        .map(|c| {
+            vec![
+                c.secret_namespace.clone(),
+                c.secret_name.clone(),
+                c.subject_or_cn.clone(),
+                c.expiry_utc.clone(),
+                c.days_until_expiry.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Build the workbook in memory; `level` filters issue and check rows as in the other formats.
+pub fn report_to_xlsx(report: &ClusterReport, level: &CheckLevelFilter) -> Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let mut add = |name: &str, columns: &[&str], rows: Vec<Vec<String>>, numeric: &[usize]| {
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(name)?;
+        write_table(sheet, columns, &rows, numeric)
+    };
+    add("Overview", &["item", "value"], overview_rows(report), &[1])?;
+    add("Issues", &ISSUE_COLUMNS, issue_rows(report, level), &[])?;
+    add("Checks", &CHECK_COLUMNS, check_rows(report, level), &[4, 5])?;
+    add("Nodes", &NODE_COLUMNS, node_rows(report), &[2])?;
+    add(
+        "Certificates",
+        &CERTIFICATE_COLUMNS,
+        certificate_rows(report),
+        &[4],
+    )?;
+    Ok(workbook.save_to_buffer()?)
+}
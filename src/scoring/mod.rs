@@ -1,4 +1,46 @@
+pub mod namespace;
+pub mod profiles;
 pub mod scoring_engine;
 
+#[allow(unused_imports)]
+pub use profiles::{HealthThresholds, ScoringProfile};
 #[allow(unused_imports)]
 pub use scoring_engine::{PriorityRecommendation, ScoreDetails, ScoringEngine};
+
+use crate::inspections::types::{ClusterReport, InspectionResult, Issue, IssueSeverity};
+
+/// Points an issue costs in the namespace ranking, also used to re-scale module scores.
+pub fn issue_penalty(issue: &Issue) -> f64 {
+    match issue.severity {
+        IssueSeverity::Critical => namespace::CRITICAL_PENALTY,
+        IssueSeverity::Warning => namespace::WARNING_PENALTY,
+        IssueSeverity::Info => namespace::INFO_PENALTY,
+    }
+}
+
+/// After issues of `inspection` were dropped or re-graded, shrink its lost points in proportion
+/// to its issue penalties (module scores come from checks, not issues):
+/// `100 - (100 - score) * penalties_after / penalties_before`.
+pub fn rescale_module_score(inspection: &mut InspectionResult, penalties_before: f64) {
+    let after: f64 = inspection.summary.issues.iter().map(issue_penalty).sum();
+    if penalties_before > 0.0 && after != penalties_before && !inspection.is_failed() {
+        let lost = (100.0 - inspection.overall_score) * after / penalties_before;
+        inspection.overall_score = (100.0 - lost).clamp(0.0, 100.0);
+    }
+}
+
+/// Recompute the overall score, health status, score breakdown and namespace ranking of `report`
+/// from its module scores and issues, after a post-processing step changed them.
+pub fn rescore_report(report: &mut ClusterReport) {
+    let engine = ScoringEngine::with_profile(report.metadata.scoring_profile.clone());
+    report.overall_score = engine.calculate_weighted_score(&report.inspections);
+    report.executive_summary.health_status = engine.get_health_status(report.overall_score);
+    report.executive_summary.grade = Some(report.executive_summary.health_status.grade());
+    report.executive_summary.score_breakdown = engine
+        .generate_score_breakdown(&report.inspections)
+        .into_iter()
+        .map(|(k, v)| (k, v.score))
+        .collect();
+    report.namespace_ranking =
+        Some(namespace::namespace_scores(&report.inspections)).filter(|r| !r.is_empty());
+}
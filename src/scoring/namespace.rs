@@ -0,0 +1,114 @@
+//! Per-namespace health: issues attributed to the namespace of their resource, scored and ranked.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::inspections::types::{InspectionResult, Issue, IssueSeverity, NamespaceScore};
+
+/// Points deducted from 100 per issue, by severity.
+pub const CRITICAL_PENALTY: f64 = 10.0;
+pub const WARNING_PENALTY: f64 = 3.0;
+pub const INFO_PENALTY: f64 = 0.5;
+/// Issue codes listed per namespace in the ranking.
+const TOP_RULES: usize = 3;
+
+fn is_namespace_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 63
+        && s.bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !s.starts_with('-')
+        && !s.ends_with('-')
+}
+
+/// Namespace of a `namespace/name` resource.
+fn resource_namespace(resource: &str) -> Option<&str> {
+    let (ns, name) = resource.trim().split_once('/')?;
+    (!name.is_empty() && is_namespace_name(ns)).then_some(ns)
+}
+
+/// Namespace an issue belongs to: the prefix of a `namespace/name` resource, or a bare resource
+/// that is itself a known namespace (namespace-level findings such as a missing LimitRange).
+pub fn issue_namespace(issue: &Issue, known: &BTreeSet<String>) -> Option<String> {
+    let resource = issue.resource.as_deref()?.trim();
+    if let Some(ns) = resource_namespace(resource) {
+        return Some(ns.to_string());
+    }
+    known.contains(resource).then(|| resource.to_string())
+}
+
+/// Namespaces listed by the Namespace inspection or appearing in `namespace/name` issue resources.
+pub fn known_namespaces(inspections: &[InspectionResult]) -> BTreeSet<String> {
+    let mut known: BTreeSet<String> = inspections
+        .iter()
+        .filter_map(|i| i.namespace_summary_rows.as_ref())
+        .flatten()
+        .map(|r| r.name.clone())
+        .collect();
+    known.extend(
+        inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+            .filter_map(|i| resource_namespace(i.resource.as_deref()?))
+            .map(str::to_string),
+    );
+    known
+}
+
+/// Namespaces with at least one issue, worst score first (ties: more critical, more warning, name).
+pub fn namespace_scores(inspections: &[InspectionResult]) -> Vec<NamespaceScore> {
+    let issues: Vec<&Issue> = inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+        .collect();
+    let known = known_namespaces(inspections);
+
+    let mut by_ns: BTreeMap<String, (NamespaceScore, HashMap<String, usize>)> = BTreeMap::new();
+    for issue in issues {
+        let Some(ns) = issue_namespace(issue, &known) else {
+            continue;
+        };
+        let (entry, rules) = by_ns.entry(ns.clone()).or_insert_with(|| {
+            (
+                NamespaceScore {
+                    namespace: ns,
+                    score: 100.0,
+                    critical: 0,
+                    warning: 0,
+                    info: 0,
+                    top_rules: Vec::new(),
+                },
+                HashMap::new(),
+            )
+        });
+        match issue.severity {
+            IssueSeverity::Critical => entry.critical += 1,
+            IssueSeverity::Warning => entry.warning += 1,
+            IssueSeverity::Info => entry.info += 1,
+        }
+        if let Some(rule) = &issue.rule_id {
+            *rules.entry(rule.clone()).or_default() += 1;
+        }
+    }
+
+    let mut ranking: Vec<NamespaceScore> = by_ns
+        .into_values()
+        .map(|(mut entry, rules)| {
+            let penalty = entry.critical as f64 * CRITICAL_PENALTY
+                + entry.warning as f64 * WARNING_PENALTY
+                + entry.info as f64 * INFO_PENALTY;
+            entry.score = (100.0 - penalty).max(0.0);
+            let mut rules: Vec<(String, usize)> = rules.into_iter().collect();
+            rules.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            entry.top_rules = rules.into_iter().take(TOP_RULES).map(|(r, _)| r).collect();
+            entry
+        })
+        .collect();
+    ranking.sort_by(|a, b| {
+        a.score
+            .total_cmp(&b.score)
+            .then_with(|| b.critical.cmp(&a.critical))
+            .then_with(|| b.warning.cmp(&a.warning))
+            .then_with(|| a.namespace.cmp(&b.namespace))
+    });
+    ranking
+}
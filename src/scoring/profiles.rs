@@ -0,0 +1,262 @@
+//! Named scoring profiles: per-module weights used for the overall score, and the score
+//! thresholds that map a score to a health status and A–F grade.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::ScoringProfileName;
+use crate::inspections::types::HealthStatus;
+
+/// Weight of modules a profile does not list.
+pub const DEFAULT_WEIGHT: f64 = 1.0;
+
+/// Inspection module names that can be weighted.
+pub const MODULES: &[&str] = &[
+    "Node Health",
+    "Node Inspection",
+    "Control Plane",
+    "Network Connectivity",
+    "Storage",
+    "Orphaned Resources",
+    "Resilience",
+    "Resource Usage",
+    "GPU & Extended Resources",
+    "Pod Status",
+    "Autoscaling",
+    "Batch Workloads",
+    "Security Configuration",
+    "Policy & Governance",
+    "Observability",
+    "Event Analytics",
+    "Debug Settings",
+    "Namespace",
+    "Certificates",
+    "Upgrade Readiness",
+];
+
+#[rustfmt::skip]
+const BALANCED: &[(&str, f64)] = &[
+    ("Node Health", 2.0), ("Pod Status", 2.5), ("Security Configuration", 2.2),
+    ("Resource Usage", 1.8), ("Network Connectivity", 1.8), ("Storage", 1.5),
+    ("Control Plane", 2.5), ("Autoscaling", 1.8), ("Batch Workloads", 1.2),
+    ("Policy & Governance", 1.6), ("Observability", 1.4), ("Upgrade Readiness", 1.7),
+    ("Orphaned Resources", 0.8), ("Resilience", 1.8),
+];
+
+#[rustfmt::skip]
+const SECURITY: &[(&str, f64)] = &[
+    ("Security Configuration", 4.0), ("Policy & Governance", 3.0), ("Certificates", 2.5),
+    ("Debug Settings", 2.5), ("Network Connectivity", 2.0), ("Control Plane", 2.0),
+    ("Upgrade Readiness", 1.5), ("Node Health", 1.0), ("Pod Status", 1.0),
+    ("Resource Usage", 0.8), ("Storage", 0.8), ("Autoscaling", 0.5),
+    ("Batch Workloads", 0.5), ("Observability", 0.8), ("Orphaned Resources", 0.5),
+    ("Event Analytics", 0.5), ("Resilience", 0.5),
+];
+
+#[rustfmt::skip]
+const RELIABILITY: &[(&str, f64)] = &[
+    ("Node Health", 3.0), ("Node Inspection", 2.0), ("Pod Status", 3.0),
+    ("Control Plane", 3.0), ("Autoscaling", 2.5), ("Storage", 2.2),
+    ("Resource Usage", 2.2), ("Event Analytics", 2.0), ("Upgrade Readiness", 2.0),
+    ("Observability", 1.8), ("Network Connectivity", 1.8), ("Batch Workloads", 1.2),
+    ("Certificates", 1.5), ("Security Configuration", 1.0), ("Policy & Governance", 1.0),
+    ("Orphaned Resources", 0.5), ("Resilience", 3.0),
+];
+
+/// Lowest score of each health status (`scoring.thresholds` in the config file); below `poor`
+/// is Critical. Statuses map to grades A (Excellent) to D (Poor) and F (Critical).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HealthThresholds {
+    pub excellent: f64,
+    pub good: f64,
+    pub fair: f64,
+    pub poor: f64,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            excellent: 90.0,
+            good: 80.0,
+            fair: 70.0,
+            poor: 60.0,
+        }
+    }
+}
+
+impl HealthThresholds {
+    /// The one score → status mapping used by the console summary, reports, JSON and TUI.
+    pub fn status(&self, score: f64) -> HealthStatus {
+        match score {
+            s if s >= self.excellent => HealthStatus::Excellent,
+            s if s >= self.good => HealthStatus::Good,
+            s if s >= self.fair => HealthStatus::Fair,
+            s if s >= self.poor => HealthStatus::Poor,
+            _ => HealthStatus::Critical,
+        }
+    }
+
+    /// Thresholds must lie in 0..=100 and decrease from `excellent` to `poor`.
+    pub fn validate(&self) -> Result<()> {
+        let levels = [self.excellent, self.good, self.fair, self.poor];
+        if levels.iter().any(|t| !(0.0..=100.0).contains(t)) {
+            bail!("scoring.thresholds: every threshold must be between 0 and 100");
+        }
+        if levels.windows(2).any(|w| w[0] <= w[1]) {
+            bail!(
+                "scoring.thresholds: excellent > good > fair > poor is required (got {} / {} / {} / {})",
+                self.excellent,
+                self.good,
+                self.fair,
+                self.poor
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Profile name, module weights and health thresholds; stored in the report so re-rendering
+/// scores and grades the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ScoringProfile {
+    pub name: String,
+    /// Weight per inspection module; modules not listed use [`DEFAULT_WEIGHT`].
+    pub weights: BTreeMap<String, f64>,
+    /// Reports without thresholds used the defaults (90/80/70/60).
+    #[serde(default)]
+    pub thresholds: HealthThresholds,
+}
+
+impl Default for ScoringProfile {
+    fn default() -> Self {
+        Self::from_table("balanced", BALANCED)
+    }
+}
+
+impl ScoringProfile {
+    fn from_table(name: &str, table: &[(&str, f64)]) -> Self {
+        Self {
+            name: name.to_string(),
+            weights: table.iter().map(|(m, w)| (m.to_string(), *w)).collect(),
+            thresholds: HealthThresholds::default(),
+        }
+    }
+
+    /// Replace the default thresholds with `scoring.thresholds` from the config file.
+    pub fn with_thresholds(mut self, thresholds: Option<&HealthThresholds>) -> Result<Self> {
+        if let Some(thresholds) = thresholds {
+            thresholds.validate()?;
+            self.thresholds = thresholds.clone();
+        }
+        Ok(self)
+    }
+
+    /// Built-in profile, or `custom`: balanced weights overridden by `custom_weights`
+    /// (the `scoring.weights` section of the config file).
+    pub fn resolve(
+        name: ScoringProfileName,
+        custom_weights: &BTreeMap<String, f64>,
+    ) -> Result<Self> {
+        Ok(match name {
+            ScoringProfileName::Balanced => Self::default(),
+            ScoringProfileName::Security => Self::from_table("security", SECURITY),
+            ScoringProfileName::Reliability => Self::from_table("reliability", RELIABILITY),
+            ScoringProfileName::Custom => {
+                if custom_weights.is_empty() {
+                    bail!(
+                        "scoring profile 'custom' needs a `scoring.weights` map in the config file"
+                    );
+                }
+                let mut profile = Self::from_table("custom", BALANCED);
+                for (module, weight) in custom_weights {
+                    if !MODULES.contains(&module.as_str()) {
+                        bail!(
+                            "unknown module '{}' in scoring.weights (known: {})",
+                            module,
+                            MODULES.join(", ")
+                        );
+                    }
+                    if !weight.is_finite() || *weight < 0.0 {
+                        bail!(
+                            "invalid weight {} for '{}': must be 0 or greater",
+                            weight,
+                            module
+                        );
+                    }
+                    profile.weights.insert(module.clone(), *weight);
+                }
+                profile
+            }
+        })
+    }
+
+    pub fn weight(&self, inspection_type: &str) -> f64 {
+        self.weights
+            .get(inspection_type)
+            .copied()
+            .unwrap_or(DEFAULT_WEIGHT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspections::types::Grade;
+
+    #[test]
+    fn resolves_profiles() {
+        let none = BTreeMap::new();
+        let security = ScoringProfile::resolve(ScoringProfileName::Security, &none).unwrap();
+        let reliability = ScoringProfile::resolve(ScoringProfileName::Reliability, &none).unwrap();
+        assert!(
+            security.weight("Security Configuration")
+                > reliability.weight("Security Configuration")
+        );
+        assert_eq!(
+            ScoringProfile::default().weight("Namespace"),
+            DEFAULT_WEIGHT
+        );
+        assert!(ScoringProfile::resolve(ScoringProfileName::Custom, &none).is_err());
+
+        let weights = BTreeMap::from([("Storage".to_string(), 5.0)]);
+        let custom = ScoringProfile::resolve(ScoringProfileName::Custom, &weights).unwrap();
+        assert_eq!(custom.name, "custom");
+        assert_eq!(custom.weight("Storage"), 5.0);
+        assert_eq!(custom.weight("Pod Status"), 2.5);
+
+        let typo = BTreeMap::from([("Storages".to_string(), 1.0)]);
+        assert!(ScoringProfile::resolve(ScoringProfileName::Custom, &typo).is_err());
+    }
+
+    #[test]
+    fn thresholds_map_scores_to_statuses_and_grades() {
+        let defaults = HealthThresholds::default();
+        assert!(matches!(defaults.status(90.0), HealthStatus::Excellent));
+        assert_eq!(defaults.status(73.4).grade(), Grade::C);
+        assert_eq!(defaults.status(12.0).grade(), Grade::F);
+
+        let strict = HealthThresholds {
+            excellent: 95.0,
+            good: 90.0,
+            fair: 80.0,
+            poor: 70.0,
+        };
+        let profile = ScoringProfile::default()
+            .with_thresholds(Some(&strict))
+            .unwrap();
+        assert_eq!(profile.thresholds.status(92.0).grade(), Grade::B);
+        assert_eq!(profile.thresholds.status(65.0).grade(), Grade::F);
+
+        let unordered = HealthThresholds {
+            good: 95.0,
+            ..strict
+        };
+        assert!(ScoringProfile::default()
+            .with_thresholds(Some(&unordered))
+            .is_err());
+    }
+}
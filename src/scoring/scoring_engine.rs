@@ -1,10 +1,18 @@
 use crate::inspections::types::*;
+use crate::scoring::profiles::ScoringProfile;
 
-pub struct ScoringEngine;
+pub struct ScoringEngine {
+    profile: ScoringProfile,
+}
 
 impl ScoringEngine {
+    /// Engine using the `balanced` profile.
     pub fn new() -> Self {
-        Self
+        Self::with_profile(ScoringProfile::default())
+    }
+
+    pub fn with_profile(profile: ScoringProfile) -> Self {
+        Self { profile }
     }
 
     pub fn calculate_weighted_score(&self, inspections: &[InspectionResult]) -> f64 {
@@ -15,7 +23,7 @@ impl ScoringEngine {
         let mut total_weighted_score = 0.0;
         let mut total_weight = 0.0;
 
-        for inspection in inspections {
+        for inspection in inspections.iter().filter(|i| !i.is_failed()) {
             let weight = self.get_inspection_weight(&inspection.inspection_type);
             total_weighted_score += inspection.overall_score * weight;
             total_weight += weight;
@@ -28,14 +36,9 @@ impl ScoringEngine {
         }
     }
 
+    /// Health status of `score` under the profile's thresholds.
     pub fn get_health_status(&self, score: f64) -> HealthStatus {
-        match score {
-            s if s >= 90.0 => HealthStatus::Excellent,
-            s if s >= 80.0 => HealthStatus::Good,
-            s if s >= 70.0 => HealthStatus::Fair,
-            s if s >= 60.0 => HealthStatus::Poor,
-            _ => HealthStatus::Critical,
-        }
+        self.profile.thresholds.status(score)
     }
 
     #[allow(dead_code)]
@@ -71,21 +74,7 @@ impl ScoringEngine {
     }
 
     fn get_inspection_weight(&self, inspection_type: &str) -> f64 {
-        match inspection_type {
-            "Node Health" => 2.0,
-            "Pod Status" => 2.5,
-            "Security Configuration" => 2.2,
-            "Resource Usage" => 1.8,
-            "Network Connectivity" => 1.8,
-            "Storage" => 1.5,
-            "Control Plane" => 2.5,
-            "Autoscaling" => 1.8,
-            "Batch Workloads" => 1.2,
-            "Policy & Governance" => 1.6,
-            "Observability" => 1.4,
-            "Upgrade Readiness" => 1.7,
-            _ => 1.0,
-        }
+        self.profile.weight(inspection_type)
     }
 
     #[allow(dead_code)]
@@ -0,0 +1,199 @@
+//! Age of open findings. `check --history-dir` reads the earlier JSON reports of the same cluster
+//! (e.g. the report PVC of `deploy-cron`) and records, per fingerprint, since when a finding has
+//! been reported without interruption. Critical findings open longer than the SLA
+//! (`--critical-sla-days`) are listed as overdue.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+
+use crate::inspections::types::{ClusterReport, FindingAges, Issue, IssueSeverity};
+use crate::reporting::read_report;
+
+pub const DEFAULT_CRITICAL_SLA_DAYS: u32 = 7;
+
+/// Earlier runs of `cluster_name` in `dir` as (timestamp, fingerprints), newest first. Files that
+/// are not kubeowler reports are skipped.
+fn earlier_runs(
+    dir: &Path,
+    cluster_name: &str,
+    before: DateTime<Utc>,
+) -> Result<Vec<(DateTime<Utc>, HashSet<String>)>> {
+    let mut runs = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "json") {
+            continue;
+        }
+        let report = match read_report(&path) {
+            Ok(report) => report,
+            Err(e) => {
+                log::debug!("skipping {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        if report.cluster_name != cluster_name || report.timestamp >= before {
+            continue;
+        }
+        let fingerprints = report
+            .inspections
+            .iter()
+            .flat_map(|i| i.summary.issues.iter())
+            .map(Issue::stable_fingerprint)
+            .collect();
+        runs.push((report.timestamp, fingerprints));
+    }
+    runs.sort_by_key(|r| std::cmp::Reverse(r.0));
+    Ok(runs)
+}
+
+/// Set `report.metadata.finding_ages` from the reports in `history_dir`. A finding is open since
+/// the oldest run of the unbroken series of runs reporting it up to this one; a finding missing
+/// from one run starts over when it comes back.
+pub fn apply(
+    report: &mut ClusterReport,
+    history_dir: &Path,
+    critical_sla_days: u32,
+) -> Result<FindingAges> {
+    let runs = earlier_runs(history_dir, &report.cluster_name, report.timestamp)?;
+    let mut first_seen = BTreeMap::new();
+    for issue in report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+    {
+        let fingerprint = issue.stable_fingerprint();
+        let since = runs
+            .iter()
+            .take_while(|(_, fps)| fps.contains(&fingerprint))
+            .last()
+            .map_or(report.timestamp, |(ts, _)| *ts);
+        first_seen.insert(fingerprint, since);
+    }
+    report.metadata.finding_ages = Some(FindingAges {
+        history_dir: history_dir.display().to_string(),
+        earlier_runs: runs.len(),
+        critical_sla_days,
+        first_seen,
+        overdue: 0,
+    });
+    let overdue = overdue_findings(report).len();
+    let ages = report
+        .metadata
+        .finding_ages
+        .as_mut()
+        .expect("finding ages set above");
+    ages.overdue = overdue;
+    Ok(ages.clone())
+}
+
+/// Critical findings open longer than the SLA, with the time they were first seen; oldest first.
+pub fn overdue_findings(report: &ClusterReport) -> Vec<(&Issue, DateTime<Utc>)> {
+    let Some(ages) = &report.metadata.finding_ages else {
+        return Vec::new();
+    };
+    let deadline = report.timestamp - Duration::days(i64::from(ages.critical_sla_days));
+    let mut overdue: Vec<(&Issue, DateTime<Utc>)> = report
+        .inspections
+        .iter()
+        .flat_map(|i| i.summary.issues.iter())
+        .filter(|i| i.severity == IssueSeverity::Critical)
+        .filter_map(|i| {
+            let since = *ages.first_seen.get(&i.stable_fingerprint())?;
+            (since < deadline).then_some((i, since))
+        })
+        .collect();
+    overdue.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.resource.cmp(&b.0.resource)));
+    overdue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report(timestamp: &str, issues: serde_json::Value) -> serde_json::Value {
+        json!({
+            "cluster_name": "prod",
+            "report_id": timestamp,
+            "timestamp": timestamp,
+            "overall_score": 50.0,
+            "inspections": [{
+                "inspection_type": "Pod Status",
+                "timestamp": timestamp,
+                "overall_score": 50.0,
+                "checks": [],
+                "summary": {
+                    "total_checks": 0, "passed_checks": 0, "warning_checks": 0,
+                    "critical_checks": 0, "error_checks": 0,
+                    "issues": issues
+                }
+            }],
+            "executive_summary": {
+                "health_status": "Poor",
+                "key_findings": [],
+                "priority_recommendations": [],
+                "score_breakdown": {}
+            }
+        })
+    }
+
+    fn issue(code: &str, resource: &str, severity: &str) -> serde_json::Value {
+        json!({
+            "severity": severity,
+            "category": "Pod",
+            "description": code,
+            "resource": resource,
+            "recommendation": "",
+            "rule_id": code
+        })
+    }
+
+    #[test]
+    fn ages_follow_unbroken_runs() {
+        let dir = std::env::temp_dir().join(format!("kubeowler-sla-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let old = issue("POD-002", "shop/web", "Critical");
+        let back = issue("POD-003", "shop/db", "Critical");
+        let runs = [
+            ("2026-01-01T00:00:00Z", json!([old, back])),
+            ("2026-01-05T00:00:00Z", json!([old])),
+            ("2026-01-09T00:00:00Z", json!([old, back])),
+        ];
+        for (ts, issues) in runs {
+            std::fs::write(
+                dir.join(format!("{}.json", &ts[..10])),
+                report(ts, issues).to_string(),
+            )
+            .unwrap();
+        }
+        let mut current: ClusterReport = serde_json::from_value(report(
+            "2026-01-10T00:00:00Z",
+            json!([old, back, issue("POD-004", "shop/api", "Critical")]),
+        ))
+        .unwrap();
+        let ages = apply(&mut current, &dir, 7).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(ages.earlier_runs, 3);
+        let overdue = overdue_findings(&current);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].0.rule_id.as_deref(), Some("POD-002"));
+        assert_eq!(overdue[0].1.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        // POD-003 was missing on Jan 5, so it is open since Jan 9.
+        let since = |code: &str| {
+            let issue = current.inspections[0]
+                .summary
+                .issues
+                .iter()
+                .find(|i| i.rule_id.as_deref() == Some(code))
+                .unwrap();
+            ages.first_seen[&issue.stable_fingerprint()].to_rfc3339()
+        };
+        assert_eq!(since("POD-003"), "2026-01-09T00:00:00+00:00");
+        assert_eq!(since("POD-004"), "2026-01-10T00:00:00+00:00");
+        assert_eq!(ages.overdue, 1);
+    }
+}
@@ -0,0 +1,136 @@
+//! Traces and metrics of the kubeowler run itself (`--otel-endpoint`): a span per inspection
+//! module and per API request, plus module duration, API request and error counters, exported
+//! over OTLP/HTTP so a slow run can be attributed to the inspector behind it. Without an
+//! endpoint no subscriber or meter provider is installed and everything here is a no-op.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use http::StatusCode;
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::metrics::MeterProvider;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use tracing_subscriber::layer::SubscriberExt;
+
+const SERVICE_NAME: &str = "kubeowler";
+
+/// Installed exporters; [`Telemetry::shutdown`] flushes what is still buffered.
+pub struct Telemetry {
+    meter_provider: MeterProvider,
+}
+
+/// Export traces and metrics to the OTLP/HTTP collector at `endpoint` (e.g.
+/// `http://otel-collector:4318`; `/v1/traces` and `/v1/metrics` are appended).
+pub fn init(endpoint: &str) -> Result<Telemetry> {
+    let endpoint = endpoint.trim_end_matches('/');
+    let resource = Resource::new(vec![
+        KeyValue::new("service.name", SERVICE_NAME),
+        KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+    ]);
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(trace::config().with_resource(resource.clone()))
+        .install_batch(runtime::Tokio)
+        .context("starting the OTLP trace exporter")?;
+    let meter_provider = opentelemetry_otlp::new_pipeline()
+        .metrics(runtime::Tokio)
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(endpoint),
+        )
+        .with_resource(resource)
+        .build()
+        .context("starting the OTLP metrics exporter")?;
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+    tracing::subscriber::set_global_default(subscriber)
+        .context("installing the tracing subscriber")?;
+    Ok(Telemetry { meter_provider })
+}
+
+impl Telemetry {
+    pub async fn shutdown(self) {
+        // Both providers flush with blocking calls served by tasks on the runtime.
+        let flushed = tokio::task::spawn_blocking(move || {
+            global::shutdown_tracer_provider();
+            self.meter_provider.shutdown()
+        })
+        .await;
+        if let Ok(Err(e)) = flushed {
+            log::warn!("flushing OTLP metrics failed: {}", e);
+        }
+    }
+}
+
+struct Instruments {
+    module_duration: Histogram<f64>,
+    module_api_requests: Counter<u64>,
+    module_errors: Counter<u64>,
+    api_requests: Counter<u64>,
+    api_errors: Counter<u64>,
+}
+
+/// Created on first use, i.e. after `init` installed the meter provider (if any).
+fn instruments() -> &'static Instruments {
+    static INSTRUMENTS: OnceLock<Instruments> = OnceLock::new();
+    INSTRUMENTS.get_or_init(|| {
+        let meter = global::meter(SERVICE_NAME);
+        Instruments {
+            module_duration: meter
+                .f64_histogram("kubeowler.inspection.duration")
+                .with_unit(opentelemetry::metrics::Unit::new("s"))
+                .with_description("Wall time of one inspection module")
+                .init(),
+            module_api_requests: meter
+                .u64_counter("kubeowler.inspection.api_requests")
+                .with_description("API requests sent by one inspection module")
+                .init(),
+            module_errors: meter
+                .u64_counter("kubeowler.inspection.errors")
+                .with_description("Inspection modules that failed")
+                .init(),
+            api_requests: meter
+                .u64_counter("kubeowler.api.requests")
+                .with_description("Kubernetes API requests, one per attempt")
+                .init(),
+            api_errors: meter
+                .u64_counter("kubeowler.api.errors")
+                .with_description("Kubernetes API requests answered with 4xx/5xx or failed")
+                .init(),
+        }
+    })
+}
+
+/// One inspection module finished after `elapsed`, having sent `api_requests`.
+pub fn record_module(module: &str, elapsed: Duration, api_requests: u64, failed: bool) {
+    let i = instruments();
+    let attrs = [KeyValue::new("module", module.to_string())];
+    i.module_duration.record(elapsed.as_secs_f64(), &attrs);
+    i.module_api_requests.add(api_requests, &attrs);
+    if failed {
+        i.module_errors.add(1, &attrs);
+    }
+}
+
+/// One API request attempt; `status` is None when it timed out or failed in transport.
+pub fn record_api_request(method: &str, status: Option<StatusCode>) {
+    let i = instruments();
+    let status_label = status.map_or_else(|| "error".to_string(), |s| s.as_u16().to_string());
+    let attrs = [
+        KeyValue::new("method", method.to_string()),
+        KeyValue::new("status", status_label),
+    ];
+    i.api_requests.add(1, &attrs);
+    if status.is_none_or(|s| s.is_client_error() || s.is_server_error()) {
+        i.api_errors.add(1, &attrs);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod format;
 pub mod metrics;
+pub mod pattern;
 pub mod resource_quantity;
@@ -0,0 +1,59 @@
+//! Simple glob patterns (`*` and `?`, case-insensitive) for user-configurable name matching.
+
+/// Match `text` against a glob `pattern` where `*` matches any run of characters and `?` one character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.to_lowercase().chars().collect();
+    let t: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut pi, mut ti) = (0usize, 0usize);
+    let mut star: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+/// True if `text` matches any of the patterns.
+pub fn matches_any(patterns: &[String], text: &str) -> bool {
+    patterns.iter().any(|p| glob_match(p, text))
+}
+
+/// Split a comma-separated CLI value into trimmed, non-empty patterns.
+pub fn parse_pattern_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_matches_wildcards_case_insensitively() {
+        assert!(glob_match("prod*", "production"));
+        assert!(glob_match("*-prod", "payments-PROD"));
+        assert!(glob_match("LOG_LEVEL=debug", "log_level=DEBUG"));
+        assert!(glob_match("ns-?", "ns-1"));
+        assert!(!glob_match("prod*", "staging"));
+        assert!(!glob_match("ns-?", "ns-10"));
+    }
+
+    #[test]
+    fn pattern_list_is_trimmed() {
+        assert_eq!(parse_pattern_list(" a, b ,,c"), vec!["a", "b", "c"]);
+    }
+}
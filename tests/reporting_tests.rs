@@ -1,6 +1,8 @@
 use chrono::Utc;
-use kubeowler::inspections::types::*;
-use kubeowler::reporting::{issue_to_resource_key, ReportGenerator, REPORT_RESOURCE_ORDER};
+use kubeowler_core::cli::ReportSection;
+use kubeowler_core::inspections::types::*;
+use kubeowler_core::reporting::template::ReportTemplate;
+use kubeowler_core::reporting::{issue_to_resource_key, ReportGenerator, REPORT_RESOURCE_ORDER};
 use std::collections::HashMap;
 use tempfile::tempdir;
 
@@ -12,6 +14,10 @@ fn make_issue(category: &str, rule_id: Option<&str>) -> Issue {
         resource: None,
         recommendation: String::new(),
         rule_id: rule_id.map(String::from),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
     }
 }
 
@@ -89,6 +95,7 @@ async fn test_report_generation() {
 
     // Create test data
     let cluster_report = ClusterReport {
+        schema_version: REPORT_SCHEMA_VERSION.to_string(),
         cluster_name: "test-cluster".to_string(),
         report_id: "test-123".to_string(),
         timestamp: Utc::now(),
@@ -117,8 +124,11 @@ async fn test_report_generation() {
             certificate_expiries: None,
             pod_container_states: None,
             namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
         }],
         executive_summary: ExecutiveSummary {
+            grade: None,
             health_status: HealthStatus::Good,
             key_findings: vec!["Test finding".to_string()],
             priority_recommendations: vec!["Test recommendation".to_string()],
@@ -137,6 +147,10 @@ async fn test_report_generation() {
         display_timestamp: None,
         display_timestamp_filename: None,
         recent_events: None,
+        event_analytics: None,
+        namespace_ranking: None,
+        accepted_issues: None,
+        metadata: Default::default(),
     };
 
     // Test report generation
@@ -162,6 +176,9 @@ async fn test_report_generation() {
     assert!(content.contains("test-cluster"));
     assert!(content.contains("85.5"));
     assert!(!content.contains("Executive Summary"));
+    assert!(content.contains("## Data completeness"));
+    assert!(content.contains("All data sources were collected."));
+    assert!(content.contains("**Scoring Profile**: balanced"));
 }
 
 #[test]
@@ -169,10 +186,435 @@ fn test_report_formatting() {
     let _generator = ReportGenerator::new();
 
     // Test that the generator can be created
-    assert!(true); // Basic test for now
 
     // Test scoring integration
-    let scoring_engine = kubeowler::scoring::scoring_engine::ScoringEngine::new();
+    let scoring_engine = kubeowler_core::scoring::scoring_engine::ScoringEngine::new();
     let health_status = scoring_engine.get_health_status(85.0);
     assert!(matches!(health_status, HealthStatus::Good));
 }
+
+fn warning_issue(rule_id: &str, resource: &str) -> Issue {
+    Issue {
+        severity: IssueSeverity::Warning,
+        category: "Pod".to_string(),
+        description: "test".to_string(),
+        resource: Some(resource.to_string()),
+        recommendation: String::new(),
+        rule_id: Some(rule_id.to_string()),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
+    }
+}
+
+/// Report with one "Pod Status" module scored 60 holding `issues`.
+fn report_with_issues(issues: Vec<Issue>) -> ClusterReport {
+    ClusterReport {
+        schema_version: REPORT_SCHEMA_VERSION.to_string(),
+        cluster_name: "test-cluster".to_string(),
+        report_id: "test-123".to_string(),
+        timestamp: Utc::now(),
+        overall_score: 60.0,
+        inspections: vec![InspectionResult {
+            inspection_type: "Pod Status".to_string(),
+            timestamp: Utc::now(),
+            overall_score: 60.0,
+            checks: vec![],
+            summary: InspectionSummary {
+                total_checks: 0,
+                passed_checks: 0,
+                warning_checks: 0,
+                critical_checks: 0,
+                error_checks: 0,
+                issues,
+            },
+            certificate_expiries: None,
+            pod_container_states: None,
+            namespace_summary_rows: None,
+            api_latency: None,
+            etcd_objects: None,
+        }],
+        executive_summary: ExecutiveSummary {
+            grade: None,
+            health_status: HealthStatus::Poor,
+            key_findings: vec![],
+            priority_recommendations: vec![],
+            score_breakdown: HashMap::new(),
+        },
+        cluster_overview: None,
+        node_inspection_results: None,
+        display_timestamp: None,
+        display_timestamp_filename: None,
+        recent_events: None,
+        event_analytics: None,
+        namespace_ranking: None,
+        accepted_issues: None,
+        metadata: Default::default(),
+    }
+}
+
+#[test]
+fn test_baseline_accepts_known_issues() {
+    let old = report_with_issues(vec![
+        warning_issue("POD-003", "web/a"),
+        warning_issue("POD-004", "web/gone"),
+    ]);
+    let baseline = kubeowler_core::baseline::Baseline::from_report(&old);
+    assert_eq!(baseline.entries.len(), 2);
+
+    let mut current = report_with_issues(vec![
+        warning_issue("POD-003", "web/a"),
+        warning_issue("POD-003", "web/b"),
+    ]);
+    let info = baseline.apply(&mut current, "b.json");
+    assert_eq!((info.accepted, info.resolved), (1, 1));
+    let remaining = &current.inspections[0].summary.issues;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].resource.as_deref(), Some("web/b"));
+    assert_eq!(current.accepted_issues.as_ref().map(Vec::len), Some(1));
+    assert_eq!(current.inspections[0].overall_score, 80.0);
+    assert!(current.overall_score > 60.0);
+
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&current, None, None, None, None)
+        .unwrap();
+    assert!(md.contains("## Appendix: Accepted findings (baseline)"));
+    assert!(md.contains("| `web/a` | Warning |"));
+}
+
+#[test]
+fn test_issue_fingerprint_is_stable() {
+    let mut a = make_issue("Pod", Some("POD-003"));
+    a.resource = Some("web/a".to_string());
+    let mut b = a.clone();
+    b.severity = IssueSeverity::Critical;
+    b.description = "different wording".to_string();
+    b.resource = Some(" web/a ".to_string());
+    assert_eq!(a.compute_fingerprint(), b.compute_fingerprint());
+    assert_eq!(a.compute_fingerprint().len(), 16);
+
+    b.resource = Some("web/b".to_string());
+    assert_ne!(a.compute_fingerprint(), b.compute_fingerprint());
+
+    // Key attributes tell findings of one code on the same resource apart.
+    let mut e = a.clone();
+    e.key_attributes = vec!["container=sidecar".to_string()];
+    assert_ne!(a.compute_fingerprint(), e.compute_fingerprint());
+
+    // Issues without a code also key on category and description.
+    let mut c = make_issue("Pod", None);
+    let d = c.clone();
+    c.description = "other".to_string();
+    assert_ne!(c.compute_fingerprint(), d.compute_fingerprint());
+
+    a.fingerprint = Some("0123456789abcdef".to_string());
+    assert_eq!(a.stable_fingerprint(), "0123456789abcdef");
+}
+
+#[test]
+fn test_csv_issue_and_check_layouts() {
+    use kubeowler_core::reporting::csv::{checks_csv, issues_csv};
+    use kubeowler_core::reporting::generator::CheckLevelFilter;
+
+    let mut info = warning_issue("POD-001", "web/b");
+    info.severity = IssueSeverity::Info;
+    let mut report = report_with_issues(vec![warning_issue("POD-003", "web/a, b"), info]);
+    report.inspections[0].checks.push(CheckResult {
+        name: "Pod Readiness".to_string(),
+        description: String::new(),
+        status: CheckStatus::Warning,
+        score: 60.0,
+        max_score: 100.0,
+        details: None,
+        recommendations: vec!["a".to_string(), "b".to_string()],
+    });
+
+    let only_warnings = CheckLevelFilter::Only(vec![CheckStatus::Warning, CheckStatus::Critical]);
+    let csv = issues_csv(&report, &only_warnings);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "cluster,module,rule_id,severity,category,resource,namespace,description,recommendation,fingerprint");
+    assert_eq!(lines.len(), 2);
+    let fp = report.inspections[0].summary.issues[0].compute_fingerprint();
+    assert_eq!(
+        lines[1],
+        format!(
+            "test-cluster,Pod Status,POD-003,Warning,Pod,\"web/a, b\",web,test,,{}",
+            fp
+        )
+    );
+    assert_eq!(
+        issues_csv(&report, &CheckLevelFilter::All).lines().count(),
+        3
+    );
+
+    let csv = checks_csv(&report, &only_warnings);
+    assert!(csv.ends_with("test-cluster,Pod Status,Pod Readiness,Warning,60.0,100.0,,a; b\n"));
+    assert_eq!(
+        checks_csv(
+            &report,
+            &CheckLevelFilter::Only(vec![CheckStatus::Critical])
+        )
+        .lines()
+        .count(),
+        1
+    );
+}
+
+#[test]
+fn test_xlsx_report_has_all_sheets() {
+    use kubeowler_core::reporting::generator::CheckLevelFilter;
+    use kubeowler_core::reporting::xlsx::report_to_xlsx;
+
+    let report = report_with_issues(vec![warning_issue("POD-003", "web/a")]);
+    let bytes = report_to_xlsx(&report, &CheckLevelFilter::All).unwrap();
+    assert!(bytes.starts_with(b"PK"));
+    // Zip entry names are stored uncompressed; one worksheet part per sheet.
+    let text = String::from_utf8_lossy(&bytes);
+    for n in 1..=5 {
+        assert!(text.contains(&format!("xl/worksheets/sheet{}.xml", n)));
+    }
+    assert!(!text.contains("xl/worksheets/sheet6.xml"));
+}
+
+#[test]
+fn test_offline_docs_links() {
+    let report = report_with_issues(vec![warning_issue("POD-003", "web/a")]);
+    let online = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(online.contains(
+        "[POD-003](https://github.com/Ghostwritten/kubeowler/blob/main/docs/issues/POD-003.md)"
+    ));
+
+    let offline = ReportGenerator::new()
+        .with_docs_base(Some("kb/issues/".to_string()))
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(offline.contains("[POD-003](kb/issues/POD-003.md)"));
+    assert!(!offline.contains("/docs/issues/POD-003.md"));
+}
+
+#[test]
+fn test_report_sections() {
+    let report = report_with_issues(vec![warning_issue("POD-003", "web/a")]);
+    let full = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(full.contains("## Data completeness"));
+    assert!(full.contains("## Node Inspection"));
+    assert!(full.contains("### Check Results"));
+
+    let exec = ReportGenerator::new()
+        .with_sections(vec![ReportSection::Overview, ReportSection::Issues])
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(exec.starts_with("# test-cluster Kubernetes Cluster Check Report"));
+    assert!(exec.contains("## 🖥️ Cluster Overview"));
+    assert!(exec.contains("[POD-003]"));
+    assert!(!exec.contains("## Node Inspection"));
+    assert!(!exec.contains("### Check Results"));
+    assert!(!exec.contains("## Namespace ranking"));
+    assert!(exec.contains("*Report generated by"));
+}
+
+#[test]
+fn test_max_resources_per_issue() {
+    let issues = (0..5)
+        .map(|i| warning_issue("POD-003", &format!("web/p{}", i)))
+        .collect();
+    let report = report_with_issues(issues);
+    // The risk ranking lists every resource; only the issue tables are limited.
+    let md = ReportGenerator::new()
+        .with_resource_limit(2, Some("appendix.json".to_string()))
+        .with_sections(vec![ReportSection::Issues])
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.contains("| `web/p1` | Warning |"));
+    assert!(!md.contains("`web/p2`"));
+    assert!(md.contains("| … and 3 more (see `appendix.json`) | Warning | [POD-003]("));
+
+    let full = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(full.contains("| `web/p4` | Warning |"));
+    assert!(!full.contains("more (see"));
+}
+
+#[test]
+fn test_example_template_renders() {
+    let mut issue = warning_issue("POD-003", "web/a");
+    issue.severity = IssueSeverity::Critical;
+    let report = report_with_issues(vec![issue, warning_issue("POD-004", "web/b")]);
+    let template = ReportTemplate::from_file("example/templates/executive-summary.md.hbs").unwrap();
+    assert!(!template.is_html());
+    let md = ReportGenerator::new()
+        .with_docs_base(Some("kb".to_string()))
+        .with_template(Some(template))
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.starts_with("# test-cluster — health summary"));
+    assert!(md.contains("**Overall score**: 60.0 / 100 (Poor)"));
+    assert!(md.contains("| Pod Status | 60.0 |"));
+    assert!(md.contains("- **[POD-003](kb/POD-003.md)** web/a: test"));
+    assert!(!md.contains("POD-004"));
+}
+
+#[test]
+fn test_remediation_commands_in_reports() {
+    use kubeowler_core::reporting::branding::Branding;
+    use kubeowler_core::reporting::md_export::md_to_html;
+
+    let mut a = warning_issue("POD-003", "web/a");
+    a.remediation_commands = vec!["kubectl -n web logs a -c app --previous".to_string()];
+    let mut b = warning_issue("POD-003", "web/b <script>");
+    b.remediation_commands = a.remediation_commands.clone();
+    let mut info = make_issue("Pod", Some("POD-003"));
+    info.remediation_commands = vec!["kubectl -n web delete pod c".to_string()];
+    let report = report_with_issues(vec![a, b, info, warning_issue("POD-001", "web/d")]);
+
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.contains(
+        "<details>\n<summary>Remediation commands (1)</summary>\n\n```bash\nkubectl -n web logs a -c app --previous\n```\n\n</details>"
+    ));
+    assert!(!md.contains("delete pod c"));
+
+    let html = md_to_html(&md, &Branding::default()).unwrap();
+    assert!(html.contains("<details>\n<summary>Remediation commands (1)</summary>"));
+    assert!(html.contains("</details>"));
+    assert!(!html.contains("<script>"));
+
+    let json = serde_json::to_value(&report).unwrap();
+    let issues = &json["inspections"][0]["summary"]["issues"];
+    assert_eq!(
+        issues[0]["remediation_commands"][0],
+        "kubectl -n web logs a -c app --previous"
+    );
+    assert!(issues[3].get("remediation_commands").is_none());
+}
+
+#[test]
+fn test_run_diagnostics_section() {
+    let mut report = report_with_issues(vec![]);
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(!md.contains("## Run diagnostics"));
+
+    report.metadata.run_diagnostics = Some(RunDiagnostics {
+        duration_ms: 90_000,
+        steps: vec![
+            StepTiming {
+                step: "Kubernetes events".to_string(),
+                duration_ms: 1_500,
+                api_requests: 3,
+                api_errors: 0,
+                objects_listed: 1_200,
+                failed: false,
+            },
+            StepTiming {
+                step: "Pod Status".to_string(),
+                duration_ms: 72_000,
+                api_requests: 40,
+                api_errors: 2,
+                objects_listed: 20_000,
+                failed: true,
+            },
+        ],
+        data_sources: vec![DataSourceUsage {
+            source: "metrics-server (metrics.k8s.io)".to_string(),
+            used: false,
+            detail: "Not available".to_string(),
+        }],
+    });
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.contains("**Run duration**: 1m 30s"));
+    assert!(md.contains("**Slowest step**: Pod Status (80% of the run)"));
+    assert!(md.contains("| Kubernetes events | 1.5s | 3 | 0 | 1200 | Pass |"));
+    assert!(md.contains("| Pod Status | 1m 12s | 40 | 2 | 20000 | Error |"));
+    assert!(md.contains("| metrics-server (metrics.k8s.io) | No | Not available |"));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["metadata"]["run_diagnostics"]["steps"][1]["objects_listed"],
+        20_000
+    );
+}
+
+#[test]
+fn test_score_methodology_appendix() {
+    let mut report = report_with_issues(vec![]);
+    let check = |name: &str, score: f64, status: CheckStatus| CheckResult {
+        name: name.to_string(),
+        description: String::new(),
+        status,
+        score,
+        max_score: 100.0,
+        details: None,
+        recommendations: vec![],
+    };
+    report.inspections[0].checks = vec![
+        check("Pod Health", 100.0, CheckStatus::Pass),
+        check("Pod Stability", 20.0, CheckStatus::Critical),
+    ];
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.contains("## Appendix: Score methodology"));
+    // One module: all of the weight, 40 points lost.
+    assert!(md.contains("| Pod Status | 60.0 | 2.50 | 100.0% | 60.00 | 40.00 |"));
+    assert!(md.contains("| Pod Stability | Critical | 20.0/100.0 | 50.0% | 40.00 | 40.00 |"));
+    assert!(md.find("| Pod Stability |").unwrap() < md.find("| Pod Health | Pass |").unwrap());
+
+    let md = ReportGenerator::new()
+        .with_sections(vec![ReportSection::Issues])
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(!md.contains("Score methodology"));
+}
+
+#[test]
+fn test_report_schema_matches_published_file() {
+    let schema = kubeowler_core::reporting::schema::report_schema().unwrap();
+    assert_eq!(
+        schema,
+        include_str!("../docs/report-schema.json"),
+        "ClusterReport changed: regenerate with `kubeowler schema -o docs/report-schema.json` and bump REPORT_SCHEMA_VERSION (minor for added optional fields, major otherwise)"
+    );
+    assert!(schema.contains(&format!("schema_version {}", REPORT_SCHEMA_VERSION)));
+
+    // Reports written before versioning still load.
+    let mut json = serde_json::to_value(report_with_issues(vec![])).unwrap();
+    json.as_object_mut().unwrap().remove("schema_version");
+    let old: ClusterReport = serde_json::from_value(json).unwrap();
+    assert_eq!(old.schema_version, "");
+}
+
+#[test]
+fn test_top_risk_resources_ranking() {
+    let mut critical = warning_issue("POD-002", "payments/api-gw");
+    critical.severity = IssueSeverity::Critical;
+    let report = report_with_issues(vec![
+        warning_issue("POD-003", "shop/web"),
+        warning_issue("POD-004", "shop/web"),
+        critical,
+        warning_issue("POD-003", "payments/api-gw"),
+        make_issue("Pod", Some("POD-001")),
+    ]);
+    let md = ReportGenerator::new()
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(md.contains("## Top 2 riskiest resources"));
+    assert!(md.contains("| 1 | Pod | `payments/api-gw` | 13.0 | 1 | 1 | 0 | POD-002, POD-003 |"));
+    assert!(md.contains("| 2 | Pod | `shop/web` | 6.0 | 0 | 2 | 0 | POD-003, POD-004 |"));
+
+    let md = ReportGenerator::new()
+        .with_sections(vec![ReportSection::Issues])
+        .generate_markdown_string(&report, None, None, None, None)
+        .unwrap();
+    assert!(!md.contains("riskiest resources"));
+}
@@ -1,6 +1,9 @@
 use chrono::Utc;
-use kubeowler::inspections::types::*;
-use kubeowler::scoring::scoring_engine::ScoringEngine;
+use kubeowler_core::cli::ScoringProfileName;
+use kubeowler_core::inspections::types::*;
+use kubeowler_core::scoring::namespace::namespace_scores;
+use kubeowler_core::scoring::profiles::ScoringProfile;
+use kubeowler_core::scoring::scoring_engine::ScoringEngine;
 
 #[test]
 fn test_scoring_engine_calculation() {
@@ -31,6 +34,8 @@ fn test_scoring_engine_calculation() {
         certificate_expiries: None,
         pod_container_states: None,
         namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
     };
 
     let inspections = vec![inspection];
@@ -86,3 +91,129 @@ fn test_check_scoring() {
     assert!(score > 0.0);
     assert!(score < 100.0); // Should be less than 100 due to warning
 }
+
+#[test]
+fn test_failed_inspection_excluded_from_weighted_score() {
+    let engine = ScoringEngine::new();
+    let module = |inspection_type: &str, status: CheckStatus, score: f64| InspectionResult {
+        inspection_type: inspection_type.to_string(),
+        timestamp: Utc::now(),
+        overall_score: score,
+        checks: vec![CheckResult {
+            name: format!("{} check", inspection_type),
+            description: "Test".to_string(),
+            status,
+            score,
+            max_score: 100.0,
+            details: None,
+            recommendations: vec![],
+        }],
+        summary: InspectionSummary {
+            total_checks: 1,
+            passed_checks: 0,
+            warning_checks: 0,
+            critical_checks: 0,
+            error_checks: 0,
+            issues: vec![],
+        },
+        certificate_expiries: None,
+        pod_container_states: None,
+        namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
+    };
+
+    let failed = module("Security Configuration", CheckStatus::Error, 0.0);
+    assert!(failed.is_failed());
+    let inspections = vec![module("Node Health", CheckStatus::Pass, 90.0), failed];
+    assert_eq!(engine.calculate_weighted_score(&inspections), 90.0);
+}
+
+#[test]
+fn test_scoring_profiles_change_weighted_score() {
+    let module = |inspection_type: &str, score: f64| InspectionResult {
+        inspection_type: inspection_type.to_string(),
+        timestamp: Utc::now(),
+        overall_score: score,
+        checks: vec![],
+        summary: InspectionSummary {
+            total_checks: 0,
+            passed_checks: 0,
+            warning_checks: 0,
+            critical_checks: 0,
+            error_checks: 0,
+            issues: vec![],
+        },
+        certificate_expiries: None,
+        pod_container_states: None,
+        namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
+    };
+    let inspections = vec![
+        module("Security Configuration", 40.0),
+        module("Node Health", 100.0),
+    ];
+    let none = Default::default();
+    let score = |name| {
+        ScoringEngine::with_profile(ScoringProfile::resolve(name, &none).unwrap())
+            .calculate_weighted_score(&inspections)
+    };
+    let balanced = score(ScoringProfileName::Balanced);
+    assert_eq!(
+        balanced,
+        ScoringEngine::new().calculate_weighted_score(&inspections)
+    );
+    assert!(score(ScoringProfileName::Security) < balanced);
+    assert!(score(ScoringProfileName::Reliability) > balanced);
+}
+
+#[test]
+fn test_namespace_scores_rank_worst_first() {
+    let issue = |severity: IssueSeverity, resource: &str, rule: &str| Issue {
+        severity,
+        category: "Pod".to_string(),
+        description: String::new(),
+        resource: Some(resource.to_string()),
+        recommendation: String::new(),
+        rule_id: Some(rule.to_string()),
+        team: None,
+        fingerprint: None,
+        key_attributes: Vec::new(),
+        remediation_commands: Vec::new(),
+    };
+    let inspection = InspectionResult {
+        inspection_type: "Pod Status".to_string(),
+        timestamp: Utc::now(),
+        overall_score: 50.0,
+        checks: vec![],
+        summary: InspectionSummary {
+            total_checks: 0,
+            passed_checks: 0,
+            warning_checks: 0,
+            critical_checks: 0,
+            error_checks: 0,
+            issues: vec![
+                issue(IssueSeverity::Warning, "web/frontend-1", "POD-001"),
+                issue(IssueSeverity::Critical, "payments/api-1", "POD-003"),
+                issue(IssueSeverity::Critical, "payments/api-2", "POD-003"),
+                issue(IssueSeverity::Warning, "payments", "POLICY-002"),
+                // Cluster-scoped resources are not attributed to a namespace.
+                issue(IssueSeverity::Critical, "node-1", "NODE-001"),
+            ],
+        },
+        certificate_expiries: None,
+        pod_container_states: None,
+        namespace_summary_rows: None,
+        api_latency: None,
+        etcd_objects: None,
+    };
+
+    let ranking = namespace_scores(&[inspection]);
+    assert_eq!(ranking.len(), 2);
+    assert_eq!(ranking[0].namespace, "payments");
+    assert_eq!((ranking[0].critical, ranking[0].warning), (2, 1));
+    assert_eq!(ranking[0].top_rules, vec!["POD-003", "POLICY-002"]);
+    assert!(ranking[0].score < ranking[1].score);
+    assert_eq!(ranking[1].namespace, "web");
+}